@@ -33,7 +33,7 @@ fuzz_target!(
                 )
             })
             .collect(),
-        deceased_candidates: HashMap::new(),
+        excluded_candidates: HashMap::new(),
         lists_drawn: Vec::new(),
         candidates_drawn: Vec::new(),
     };
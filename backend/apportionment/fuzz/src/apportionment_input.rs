@@ -101,7 +101,7 @@ impl apportionment::CandidateDrawn<u32, u32> for SimpleCandidateDrawn {
 pub struct FuzzedApportionmentInput {
     pub seats: u32,
     pub list_votes: Vec<SimpleListVotes>,
-    pub deceased_candidates: HashMap<u32, HashSet<u32>>,
+    pub excluded_candidates: HashMap<u32, HashSet<u32>>,
     pub lists_drawn: Vec<SimpleListDrawn>,
     pub candidates_drawn: Vec<SimpleCandidateDrawn>,
 }
@@ -155,7 +155,7 @@ impl<'a> Arbitrary<'a> for FuzzedApportionmentInput {
         Ok(FuzzedApportionmentInput {
             seats,
             list_votes,
-            deceased_candidates: HashMap::new(),
+            excluded_candidates: HashMap::new(),
             lists_drawn: Vec::new(),
             candidates_drawn: Vec::new(),
         })
@@ -175,8 +175,8 @@ impl apportionment::ApportionmentInput for FuzzedApportionmentInput {
         &self.list_votes
     }
 
-    fn deceased_candidates(&self) -> &HashMap<u32, HashSet<u32>> {
-        &self.deceased_candidates
+    fn excluded_candidates(&self) -> &HashMap<u32, HashSet<u32>> {
+        &self.excluded_candidates
     }
 
     fn lists_drawn(&self) -> impl Iterator<Item = &Self::ListDrawn> {
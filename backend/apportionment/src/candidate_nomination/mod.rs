@@ -10,7 +10,7 @@ use crate::{
     ApportionmentError, CandidateDrawn, CandidateVotes, ListVotes,
     fraction::Fraction,
     structs::{
-        CandidateDrawingLotsVariant, CandidateNominationInput, CandidateNumber, DeceasedCandidates,
+        CandidateDrawingLotsVariant, CandidateNominationInput, CandidateNumber, ExcludedCandidates,
         LARGE_COUNCIL_THRESHOLD, ListNumber,
     },
 };
@@ -105,14 +105,14 @@ fn all_chosen_candidates<T: ListVotes>(
         .collect()
 }
 
-fn filter_out_deceased_candidates<'a, T: ListVotes>(
+pub(crate) fn filter_out_excluded_candidates<'a, T: ListVotes>(
     list: &'a T,
-    deceased_candidates: &'a DeceasedCandidates<T>,
+    excluded_candidates: &'a ExcludedCandidates<T>,
 ) -> Vec<&'a T::Cv> {
     list.candidate_votes()
         .iter()
         .filter(|cv| {
-            !deceased_candidates
+            !excluded_candidates
                 .get(&list.number())
                 .is_some_and(|candidates| candidates.contains(&cv.number()))
         })
@@ -144,12 +144,12 @@ fn candidate_nomination_per_list<'a, LV: ListVotes>(
             .to_owned();
 
         info!(
-            "Deceased candidates: {:?} will be filtered out for list {:?}",
-            input.deceased_candidates.get(&list.number()),
+            "Excluded candidates: {:?} will be filtered out for list {:?}",
+            input.excluded_candidates.get(&list.number()),
             list.number()
         );
 
-        let candidate_votes = &filter_out_deceased_candidates(list, input.deceased_candidates);
+        let candidate_votes = &filter_out_excluded_candidates(list, input.excluded_candidates);
 
         let candidate_votes_meeting_preference_threshold =
             candidate_votes_meeting_preference_threshold(preference_threshold, candidate_votes);
@@ -181,7 +181,7 @@ fn candidate_nomination_per_list<'a, LV: ListVotes>(
             .collect::<Vec<_>>();
 
         // [Artikel P 19 Kieswet](https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukP_Paragraaf3_ArtikelP19)
-        let candidate_ranking = if input.deceased_candidates.get(&list.number()).is_none()
+        let candidate_ranking = if input.excluded_candidates.get(&list.number()).is_none()
             && (candidate_votes_meeting_preference_threshold.is_empty()
                 || (input.number_of_seats >= LARGE_COUNCIL_THRESHOLD && total_seats == 0))
         {
@@ -195,7 +195,7 @@ fn candidate_nomination_per_list<'a, LV: ListVotes>(
 
             // If the updated candidate ranking is the same as the original candidate list,
             // return an empty list, otherwise return the updated list
-            // Note: we base this on the original list, so if there are deceased candidates, the ranking is always updated
+            // Note: we base this on the original list, so if there are excluded candidates, the ranking is always updated
             if updated_ranking == original_ranking {
                 CandidateRanking::Original(original_ranking)
             } else {
@@ -487,11 +487,11 @@ mod tests {
     }
 
     #[test]
-    fn test_filter_out_deceased_candidates() {
-        let deceased_candidates: DeceasedCandidates<ListVotesMock> =
+    fn test_filter_out_excluded_candidates() {
+        let excluded_candidates: ExcludedCandidates<ListVotesMock> =
             HashMap::from([(1, HashSet::from([1, 3])), (2, HashSet::from([2]))]);
         let list = ListVotesMock::from_test_data_auto(1, vec![100, 80, 60, 40, 20]);
-        let filtered_candidate_votes = filter_out_deceased_candidates(&list, &deceased_candidates);
+        let filtered_candidate_votes = filter_out_excluded_candidates(&list, &excluded_candidates);
         assert_eq!(
             filtered_candidate_votes,
             vec![
@@ -620,7 +620,7 @@ mod tests {
             let preferential_nomination = vec![candidate_votes[0], candidate_votes[1]];
 
             let other = other_candidate_nomination(&preferential_nomination, &candidate_votes, 2);
-            assert_eq!(candidate_votes_numbers(&other), vec![]);
+            assert!(candidate_votes_numbers(&other).is_empty());
         }
 
         #[test]
@@ -916,7 +916,7 @@ mod tests {
                 ],
             );
             seat_assignment_input
-                .deceased_candidates
+                .excluded_candidates
                 .insert(2, HashSet::from([3]));
             let input = candidate_nomination_fixture_with_given_number_of_seats(
                 quota,
@@ -1088,7 +1088,7 @@ mod tests {
                 ],
             );
             seat_assignment_input
-                .deceased_candidates
+                .excluded_candidates
                 .insert(1, HashSet::from([1]));
 
             let input = candidate_nomination_fixture_with_given_number_of_seats(
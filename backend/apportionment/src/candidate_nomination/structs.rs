@@ -6,13 +6,14 @@ use crate::{
 /// Indicates what the ranking of candidates in the list is.
 ///
 /// This is either the original list as defined in the candidate lists, or the
-/// updated ranking if preferential votes or deceased candidates have changed
+/// updated ranking if preferential votes or excluded candidates have changed
 /// the ranking of candidates.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CandidateRanking<T> {
     /// The ranking of candidates was updated.
     ///
-    /// This could happen because of deceased candidates or because of preferential votes.
+    /// This could happen because of excluded candidates or because of preferential votes.
     Updated(Vec<T>),
     /// The ranking of candidates was not updated.
     Original(Vec<T>),
@@ -93,6 +94,7 @@ impl<'a, T: ListVotes> ListCandidateNomination<'a, T> {
 
 /// Contains the preference threshold as a percentage and as a fraction of the number of votes.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PreferenceThreshold {
     /// Preference threshold as a percentage (0 to 100)
     pub percentage: u64,
@@ -137,13 +139,13 @@ pub(crate) mod tests {
         assert_eq!(original_candidate_ranking.as_slice(), &[1, 2, 3]);
         assert_eq!(updated_candidate_ranking.as_slice(), &[1, 3, 2]);
 
-        assert_eq!(original_candidate_ranking.as_updated_slice(), []);
+        assert!(original_candidate_ranking.as_updated_slice().is_empty());
         assert_eq!(updated_candidate_ranking.as_updated_slice(), &[1, 3, 2]);
 
         assert!(original_candidate_ranking.iter().eq([1, 2, 3].iter()));
         assert!(updated_candidate_ranking.iter().eq([1, 3, 2].iter()));
 
-        assert!(original_candidate_ranking.iter_updated().eq([].iter()));
+        assert!(original_candidate_ranking.iter_updated().next().is_none());
         assert!(
             updated_candidate_ranking
                 .iter_updated()
@@ -0,0 +1,704 @@
+use std::{collections::HashSet, fmt::Debug, ops::RangeInclusive};
+
+mod structs;
+
+pub use structs::{
+    DistrictDistributionDetails, DistrictLargestRemainderAssignedSeat, DistrictListStanding,
+    DistrictSeatChangeStep, ListDistrictDistribution,
+};
+use tracing::{debug, info};
+
+use crate::{
+    ApportionmentError, ApportionmentInput, DistrictApportionmentInput, ListVotes,
+    fraction::Fraction,
+    seat_assignment::SeatAssignmentDetails,
+    structs::{
+        CandidateNominationInput, CandidateVotes, DistrictListDrawingLotsVariant,
+        DistrictListDrawn, DistrictListVotes, ExcludedCandidates, ListNumber,
+    },
+};
+
+pub enum DistrictDistribution<LN, DLN> {
+    Completed(DistrictDistributionDetails<LN, DLN>),
+    DrawingLotsRequired(DistrictListDrawingLotsVariant<LN, DLN>),
+}
+
+type DistrictDistributionResult<T> = Result<
+    DistrictDistribution<
+        ListNumber<<T as ApportionmentInput>::List>,
+        ListNumber<<T as DistrictApportionmentInput>::DistrictList>,
+    >,
+    ApportionmentError,
+>;
+
+/// District distribution
+///
+/// Distributes the seats assigned to each list over the district lists of that list,
+/// for elections with multiple districts (kieskringen).
+pub(crate) fn district_distribution<T: DistrictApportionmentInput>(
+    input: &T,
+    seat_assignment: &SeatAssignmentDetails<ListNumber<T::List>>,
+) -> DistrictDistributionResult<T> {
+    info!("District distribution");
+
+    if let Some(district_list) = input.district_list_votes().iter().find(|district_list| {
+        !seat_assignment
+            .standings
+            .iter()
+            .any(|standing| standing.list_number == district_list.list_number())
+    }) {
+        return Err(ApportionmentError::InvalidState(format!(
+            "District list {:?} belongs to unknown list {:?}",
+            district_list.number(),
+            district_list.list_number()
+        )));
+    }
+
+    validate_excluded_candidates(
+        input.district_list_votes(),
+        input.district_excluded_candidates(),
+    )?;
+
+    let mut district_lists_drawn = input.district_lists_drawn();
+    let mut list_distributions = vec![];
+    for standing in &seat_assignment.standings {
+        let district_lists: Vec<&T::DistrictList> = input
+            .district_list_votes()
+            .iter()
+            .filter(|district_list| district_list.list_number() == standing.list_number)
+            .collect();
+        validate_district_lists(
+            standing.list_number,
+            standing.total_seats,
+            standing.votes_cast,
+            &district_lists,
+        )?;
+
+        match distribute_list_seats(
+            standing.list_number,
+            standing.total_seats,
+            &district_lists,
+            input.district_excluded_candidates(),
+            &mut district_lists_drawn,
+        )? {
+            ListDistribution::Completed(list_distribution) => {
+                list_distributions.push(list_distribution)
+            }
+            ListDistribution::DrawingLotsRequired(variant) => {
+                return Ok(DistrictDistribution::DrawingLotsRequired(variant));
+            }
+        }
+    }
+
+    Ok(DistrictDistribution::Completed(
+        DistrictDistributionDetails { list_distributions },
+    ))
+}
+
+/// Returns candidate nomination input for the district lists, created from the initial
+/// apportionment input and the district distribution result.
+pub fn as_district_candidate_nomination_input<'a, T: DistrictApportionmentInput>(
+    input: &'a T,
+    seat_assignment: &SeatAssignmentDetails<ListNumber<T::List>>,
+    district_distribution: &DistrictDistributionDetails<
+        ListNumber<T::List>,
+        ListNumber<T::DistrictList>,
+    >,
+) -> CandidateNominationInput<'a, T::DistrictList> {
+    CandidateNominationInput {
+        number_of_seats: input.number_of_seats(),
+        list_votes: input.district_list_votes(),
+        excluded_candidates: input.district_excluded_candidates(),
+        quota: seat_assignment.quota,
+        total_seats_per_list: district_distribution.total_seats_per_district_list(),
+    }
+}
+
+/// Check that the district lists of a list match the list they belong to: the votes
+/// of the district lists add up to the votes of the list, and every district
+/// has at most one district list for this list.
+fn validate_district_lists<LN: Debug, DL: DistrictListVotes<LN>>(
+    list_number: LN,
+    seats: u32,
+    votes_cast: u64,
+    district_lists: &[&DL],
+) -> Result<(), ApportionmentError> {
+    if seats > 0 && district_lists.is_empty() {
+        return Err(ApportionmentError::InvalidState(format!(
+            "List {list_number:?} was assigned {seats} seat(s) but has no district lists"
+        )));
+    }
+
+    let district_votes_cast: u64 = district_lists
+        .iter()
+        .map(|district_list| u64::from(district_list.total_votes()))
+        .sum();
+    if !district_lists.is_empty() && district_votes_cast != votes_cast {
+        return Err(ApportionmentError::InvalidState(format!(
+            "Votes of the district lists of list {list_number:?} ({district_votes_cast}) \
+             do not match the votes of the list ({votes_cast})"
+        )));
+    }
+
+    let mut districts = HashSet::new();
+    for district_list in district_lists {
+        if !district_list
+            .districts()
+            .iter()
+            .all(|district| districts.insert(*district))
+        {
+            return Err(ApportionmentError::InvalidState(format!(
+                "List {list_number:?} has more than one district list in a district"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that every excluded candidate is a candidate on an existing district list.
+fn validate_excluded_candidates<DL: ListVotes>(
+    district_lists: &[DL],
+    excluded_candidates: &ExcludedCandidates<DL>,
+) -> Result<(), ApportionmentError> {
+    for (district_list_number, candidates) in excluded_candidates {
+        let Some(district_list) = district_lists
+            .iter()
+            .find(|district_list| district_list.number() == *district_list_number)
+        else {
+            return Err(ApportionmentError::InvalidState(format!(
+                "Excluded candidates belong to unknown district list {district_list_number:?}"
+            )));
+        };
+        if let Some(candidate_number) = candidates.iter().find(|&&candidate_number| {
+            !district_list
+                .candidate_votes()
+                .iter()
+                .any(|candidate| candidate.number() == candidate_number)
+        }) {
+            return Err(ApportionmentError::InvalidState(format!(
+                "Excluded candidate {candidate_number:?} is not on district list {district_list_number:?}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the number of candidates of a district list that can be assigned a seat.
+fn number_of_candidates<DL: ListVotes>(
+    district_list: &DL,
+    excluded_candidates: &ExcludedCandidates<DL>,
+) -> u32 {
+    let excluded_count = excluded_candidates
+        .get(&district_list.number())
+        .map_or(0, |candidates| candidates.len());
+    u32::try_from(district_list.candidate_votes().len() - excluded_count)
+        .expect("Number of candidates fits in u32")
+}
+
+/// Create the initial standing of a district list, computing the number of full seats
+/// with the quota of the list. A district list is never assigned more full seats
+/// than it has candidates.
+fn district_list_standing<LN, DL: DistrictListVotes<LN>>(
+    district_list: &DL,
+    quota: Fraction,
+    excluded_candidates: &ExcludedCandidates<DL>,
+) -> DistrictListStanding<ListNumber<DL>> {
+    let votes_cast = u64::from(district_list.total_votes());
+    let (full_seats, remainder_votes) = if quota > Fraction::ZERO {
+        let full_seats = (Fraction::from(votes_cast) / quota).integer_part();
+        (
+            u32::try_from(full_seats).expect("full_seats fit in u32"),
+            Fraction::from(votes_cast) - Fraction::from(full_seats) * quota,
+        )
+    } else {
+        (0, Fraction::ZERO)
+    };
+    let number_of_candidates = number_of_candidates(district_list, excluded_candidates);
+
+    debug!(
+        "District list {:?} has {full_seats} full seats with {votes_cast} votes",
+        district_list.number()
+    );
+    DistrictListStanding {
+        district_list_number: district_list.number(),
+        combined: district_list.is_combined(),
+        votes_cast,
+        remainder_votes,
+        number_of_candidates,
+        full_seats: full_seats.min(number_of_candidates),
+        residual_seats: 0,
+    }
+}
+
+enum ListDistribution<LN, DLN> {
+    Completed(ListDistrictDistribution<LN, DLN>),
+    DrawingLotsRequired(DistrictListDrawingLotsVariant<LN, DLN>),
+}
+
+/// Distribute the seats of a list over its district lists.
+///
+/// Each district list gets a full seat for every time the quota of the list fits in its
+/// votes, the residual seats are assigned to the district lists with the largest remainders.
+/// A stel gelijkluidende lijsten counts as a single district list.
+/// [Artikel P 11 Kieswet](https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukP_Paragraaf2_ArtikelP11)
+fn distribute_list_seats<'b, LN: Copy + Debug + Eq, DL: DistrictListVotes<LN>>(
+    list_number: LN,
+    seats: u32,
+    district_lists: &[&DL],
+    excluded_candidates: &ExcludedCandidates<DL>,
+    district_lists_drawn: &mut impl Iterator<
+        Item = &'b (impl DistrictListDrawn<LN, ListNumber<DL>> + 'b),
+    >,
+) -> Result<ListDistribution<LN, ListNumber<DL>>, ApportionmentError> {
+    let votes_cast: u64 = district_lists
+        .iter()
+        .map(|district_list| u64::from(district_list.total_votes()))
+        .sum();
+
+    // A quota can only be calculated when there are seats and votes to distribute
+    let quota = if seats > 0 && votes_cast > 0 {
+        Fraction::from(votes_cast) / Fraction::from(seats)
+    } else {
+        Fraction::ZERO
+    };
+    info!("List {list_number:?} has {seats} seat(s) to distribute with quota {quota}");
+
+    let initial_standings: Vec<_> = district_lists
+        .iter()
+        .map(|district_list| district_list_standing(*district_list, quota, excluded_candidates))
+        .collect();
+    let full_seats = initial_standings
+        .iter()
+        .map(|standing| standing.full_seats)
+        .sum::<u32>();
+    let residual_seats = seats - full_seats;
+
+    let (steps, standings) = match assign_residual_seats(
+        list_number,
+        initial_standings,
+        residual_seats,
+        district_lists_drawn,
+    )? {
+        ResidualSeats::Completed(steps, standings) => (steps, standings),
+        ResidualSeats::DrawingLotsRequired(variant) => {
+            return Ok(ListDistribution::DrawingLotsRequired(variant));
+        }
+    };
+
+    Ok(ListDistribution::Completed(ListDistrictDistribution {
+        list_number,
+        seats,
+        votes_cast,
+        quota,
+        full_seats,
+        residual_seats,
+        steps,
+        standings,
+    }))
+}
+
+enum ResidualSeats<LN, DLN> {
+    Completed(
+        Vec<DistrictSeatChangeStep<LN, DLN>>,
+        Vec<DistrictListStanding<DLN>>,
+    ),
+    DrawingLotsRequired(DistrictListDrawingLotsVariant<LN, DLN>),
+}
+
+/// Assign the residual seats of a list to the district lists with the largest remainders.
+///
+/// Every district list is assigned at most one residual seat, unless all district lists
+/// that are not exhausted already got one. Fails if all district lists are exhausted before
+/// every residual seat is assigned, the seat assignment should not have assigned these seats
+/// to the list (Artikel P 10 Kieswet).
+fn assign_residual_seats<'b, LN: Copy + Debug + Eq, DLN: Copy + Debug + Eq>(
+    list_number: LN,
+    mut standings: Vec<DistrictListStanding<DLN>>,
+    residual_seats: u32,
+    district_lists_drawn: &mut impl Iterator<Item = &'b (impl DistrictListDrawn<LN, DLN> + 'b)>,
+) -> Result<ResidualSeats<LN, DLN>, ApportionmentError> {
+    let mut steps = vec![];
+    for residual_seat_number in 1..=residual_seats {
+        let change = match district_list_with_largest_remainder(
+            list_number,
+            &standings,
+            residual_seat_number..=residual_seats,
+            district_lists_drawn,
+        )? {
+            LargestRemainder::Assigned(change) => change,
+            LargestRemainder::AllExhausted => {
+                return Err(ApportionmentError::InvalidState(format!(
+                    "All district lists of list {list_number:?} are exhausted, {} seat(s) cannot be assigned",
+                    residual_seats - residual_seat_number + 1
+                )));
+            }
+            LargestRemainder::DrawingLotsRequired(variant) => {
+                return Ok(ResidualSeats::DrawingLotsRequired(variant));
+            }
+        };
+
+        let selected_district_list_number = change.selected_district_list_number;
+        info!("Adding residual seat to district list {selected_district_list_number:?}");
+        steps.push(DistrictSeatChangeStep {
+            residual_seat_number,
+            change,
+            standings: standings.clone(),
+        });
+        standings
+            .iter_mut()
+            .find(|standing| standing.district_list_number == selected_district_list_number)
+            .expect("District list standing should exist")
+            .residual_seats += 1;
+    }
+
+    Ok(ResidualSeats::Completed(steps, standings))
+}
+
+enum LargestRemainder<LN, DLN> {
+    Assigned(DistrictLargestRemainderAssignedSeat<LN, DLN>),
+    AllExhausted,
+    DrawingLotsRequired(DistrictListDrawingLotsVariant<LN, DLN>),
+}
+
+/// Select the district list with the largest remainder for the next residual seat.
+///
+/// Drawing lots is required when more district lists have the same largest remainder
+/// than there are residual seats left.
+fn district_list_with_largest_remainder<'b, LN: Copy + Debug + Eq, DLN: Copy + Debug + Eq>(
+    list_number: LN,
+    standings: &[DistrictListStanding<DLN>],
+    residual_seat_numbers: RangeInclusive<u32>,
+    district_lists_drawn: &mut impl Iterator<Item = &'b (impl DistrictListDrawn<LN, DLN> + 'b)>,
+) -> Result<LargestRemainder<LN, DLN>, ApportionmentError> {
+    let Some(round) = standings
+        .iter()
+        .filter(|standing| !standing.is_exhausted())
+        .map(|standing| standing.residual_seats)
+        .min()
+    else {
+        return Ok(LargestRemainder::AllExhausted);
+    };
+
+    let qualifying: Vec<&DistrictListStanding<DLN>> = standings
+        .iter()
+        .filter(|standing| !standing.is_exhausted() && standing.residual_seats == round)
+        .collect();
+    let max_remainder = qualifying
+        .iter()
+        .map(|standing| standing.remainder_votes)
+        .max()
+        .expect("At least one district list qualifies");
+    let options: Vec<DLN> = qualifying
+        .iter()
+        .filter(|standing| standing.remainder_votes == max_remainder)
+        .map(|standing| standing.district_list_number)
+        .collect();
+    debug!("Found {max_remainder} remainder votes as the maximum for district lists: {options:?}");
+
+    if options.len() <= residual_seat_numbers.clone().count() {
+        return Ok(LargestRemainder::Assigned(
+            DistrictLargestRemainderAssignedSeat {
+                selected_district_list_number: options[0],
+                list_options: options,
+                remainder_votes: max_remainder,
+                drawing_lots: None,
+            },
+        ));
+    }
+
+    info!("Drawing of lots is required for district lists: {options:?}");
+    let variant = DistrictListDrawingLotsVariant {
+        list: list_number,
+        max_remainder,
+        residual_seat_numbers: residual_seat_numbers.collect(),
+        options: options.clone(),
+        list_remainders: qualifying
+            .iter()
+            .map(|standing| (standing.district_list_number, standing.remainder_votes))
+            .collect(),
+    };
+
+    let Some(district_list_drawn) = district_lists_drawn.next() else {
+        return Ok(LargestRemainder::DrawingLotsRequired(variant));
+    };
+    variant.validate(district_list_drawn)?;
+
+    Ok(LargestRemainder::Assigned(
+        DistrictLargestRemainderAssignedSeat {
+            selected_district_list_number: *district_list_drawn.drawn(),
+            list_options: options,
+            remainder_votes: max_remainder,
+            drawing_lots: Some(variant),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        assert_matches,
+        collections::{HashMap, HashSet},
+    };
+
+    use test_log::test;
+
+    use crate::{
+        ApportionmentError, DistrictApportionmentOutput, DistrictListDrawingLotsVariant, Fraction,
+        process_districts,
+        test_helpers::{
+            DistrictApportionmentInputMock, DistrictListDrawnMock, district_apportionment_fixture,
+        },
+    };
+
+    /// 10 seats, quota = 1000 / 10 = 100.
+    /// List 1 (700 votes) gets 7 seats, list 2 (300 votes) gets 3 seats.
+    /// List 2 is a stel gelijkluidende lijsten, submitted in all three districts.
+    fn fixture(list_1_candidate_votes: [Vec<u32>; 3]) -> DistrictApportionmentInputMock {
+        let [district_1, district_2, district_3] = list_1_candidate_votes;
+        district_apportionment_fixture(
+            10,
+            vec![
+                (1, 11, vec![1], district_1),
+                (1, 12, vec![2], district_2),
+                (1, 13, vec![3], district_3),
+                (2, 21, vec![1, 2, 3], vec![250, 20, 10, 10, 10]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_distribution_with_largest_remainder() {
+        let input = fixture([
+            vec![300, 20, 20, 10, 10],
+            vec![200, 20, 10, 5, 5],
+            vec![80, 10, 10, 0, 0],
+        ]);
+        let Ok(DistrictApportionmentOutput::Completed(result)) = process_districts(&input) else {
+            panic!("should be Completed")
+        };
+
+        let distribution = &result.district_distribution;
+        assert_eq!(
+            distribution.total_seats_per_district_list(),
+            HashMap::from([(11, 4), (12, 2), (13, 1), (21, 3)])
+        );
+
+        let list_1 = &distribution.list_distributions[0];
+        assert_eq!(list_1.list_number, 1);
+        assert_eq!(list_1.seats, 7);
+        assert_eq!(list_1.votes_cast, 700);
+        assert_eq!(list_1.quota, Fraction::new(100, 1));
+        assert_eq!(list_1.full_seats, 6);
+        assert_eq!(list_1.residual_seats, 1);
+        assert_eq!(list_1.steps.len(), 1);
+        assert_eq!(list_1.steps[0].residual_seat_number, 1);
+        assert_eq!(list_1.steps[0].change.selected_district_list_number, 11);
+        assert_eq!(list_1.steps[0].change.remainder_votes, Fraction::new(60, 1));
+        assert_eq!(list_1.steps[0].standings[0].residual_seats, 0);
+
+        let list_2 = &distribution.list_distributions[1];
+        assert_eq!(list_2.full_seats, 3);
+        assert!(list_2.steps.is_empty());
+        assert!(list_2.standings[0].combined);
+        assert!(!list_1.standings[0].combined);
+
+        // candidates are nominated per district list
+        let nominations = &result.candidate_nomination.list_candidate_nomination;
+        assert_eq!(
+            nominations
+                .iter()
+                .map(|nomination| (nomination.list_number, nomination.list_seats))
+                .collect::<Vec<_>>(),
+            vec![(11, 4), (12, 2), (13, 1), (21, 3)]
+        );
+        assert_eq!(result.candidate_nomination.chosen_candidates.len(), 10);
+    }
+
+    #[test]
+    fn test_distribution_drawing_lots() {
+        let mut input = fixture([
+            vec![300, 20, 20, 10],
+            vec![200, 20, 20, 10],
+            vec![80, 10, 10, 0],
+        ]);
+        let variant = DistrictListDrawingLotsVariant {
+            list: 1,
+            max_remainder: Fraction::new(50, 1),
+            residual_seat_numbers: vec![1],
+            options: vec![11, 12],
+            list_remainders: vec![
+                (11, Fraction::new(50, 1)),
+                (12, Fraction::new(50, 1)),
+                (13, Fraction::ZERO),
+            ],
+        };
+        let result = process_districts(&input);
+        let Ok(DistrictApportionmentOutput::DistrictListDrawingLotsRequired(drawing_lots, _)) =
+            result
+        else {
+            panic!("should require drawing lots, got {result:?}")
+        };
+        assert_eq!(drawing_lots, variant);
+
+        input.district_lists_drawn = vec![DistrictListDrawnMock {
+            variant: variant.clone(),
+            drawn: 12,
+        }];
+        let Ok(DistrictApportionmentOutput::Completed(result)) = process_districts(&input) else {
+            panic!("should be Completed")
+        };
+        let list_1 = &result.district_distribution.list_distributions[0];
+        assert_eq!(list_1.steps[0].change.selected_district_list_number, 12);
+        assert_eq!(list_1.steps[0].change.drawing_lots, Some(variant));
+        assert_eq!(
+            result
+                .district_distribution
+                .total_seats_per_district_list()
+                .get(&12),
+            Some(&3)
+        );
+    }
+
+    #[test]
+    fn test_distribution_invalid_drawn_district_list() {
+        let mut input = fixture([
+            vec![300, 20, 20, 10],
+            vec![200, 20, 20, 10],
+            vec![80, 10, 10, 0],
+        ]);
+        let Ok(DistrictApportionmentOutput::DistrictListDrawingLotsRequired(variant, _)) =
+            process_districts(&input)
+        else {
+            panic!("should require drawing lots")
+        };
+        input.district_lists_drawn = vec![DistrictListDrawnMock { variant, drawn: 13 }];
+        assert_matches!(
+            process_districts(&input),
+            Err(ApportionmentError::InvalidLotDrawing(message)) if message == "Invalid number drawn"
+        );
+    }
+
+    #[test]
+    fn test_distribution_exhausted_district_list() {
+        // District list 13 has 2 full seats worth of votes, but only one candidate
+        let input = fixture([vec![300, 10, 10, 0, 0], vec![150, 10, 10, 10, 0], vec![200]]);
+        let Ok(DistrictApportionmentOutput::Completed(result)) = process_districts(&input) else {
+            panic!("should be Completed")
+        };
+
+        let list_1 = &result.district_distribution.list_distributions[0];
+        assert_eq!(list_1.full_seats, 5);
+        assert_eq!(
+            list_1
+                .steps
+                .iter()
+                .map(|step| step.change.selected_district_list_number)
+                .collect::<Vec<_>>(),
+            vec![12, 11]
+        );
+        assert_eq!(
+            list_1
+                .standings
+                .iter()
+                .map(|standing| standing.total_seats())
+                .collect::<Vec<_>>(),
+            vec![4, 2, 1]
+        );
+        assert!(list_1.standings[2].is_exhausted());
+        // the seat district list 13 has no candidate for is assigned to another district list
+        assert_eq!(list_1.full_seats + list_1.residual_seats, list_1.seats);
+    }
+
+    #[test]
+    fn test_distribution_all_district_lists_exhausted_fails() {
+        // List 1 gets 7 seats, but its district lists only have 4 candidates that are not excluded
+        let mut input = fixture([
+            vec![300, 20, 20, 10, 10],
+            vec![200, 20, 10, 5, 5],
+            vec![80, 10, 10, 0, 0],
+        ]);
+        input.district_excluded_candidates = HashMap::from([
+            (11, HashSet::from([2, 3, 4, 5])),
+            (12, HashSet::from([2, 3, 4, 5])),
+            (13, HashSet::from([3, 4, 5])),
+        ]);
+        assert_matches!(
+            process_districts(&input),
+            Err(ApportionmentError::InvalidState(message))
+                if message == "All district lists of list 1 are exhausted, 3 seat(s) cannot be assigned"
+        );
+    }
+
+    #[test]
+    fn test_distribution_votes_mismatch_fails() {
+        let mut input = fixture([
+            vec![300, 20, 20, 10, 10],
+            vec![200, 20, 10, 5, 5],
+            vec![80, 10, 10, 0, 0],
+        ]);
+        input.district_list_votes[0].candidate_votes[0].1 = 10;
+        assert_matches!(
+            process_districts(&input),
+            Err(ApportionmentError::InvalidState(_))
+        );
+    }
+
+    #[test]
+    fn test_distribution_multiple_district_lists_in_district_fails() {
+        let mut input = fixture([
+            vec![300, 20, 20, 10, 10],
+            vec![200, 20, 10, 5, 5],
+            vec![80, 10, 10, 0, 0],
+        ]);
+        input.district_list_votes[1].districts = vec![1];
+        assert_matches!(
+            process_districts(&input),
+            Err(ApportionmentError::InvalidState(_))
+        );
+    }
+
+    #[test]
+    fn test_distribution_unknown_excluded_candidate_fails() {
+        let mut input = fixture([
+            vec![300, 20, 20, 10, 10],
+            vec![200, 20, 10, 5, 5],
+            vec![80, 10, 10, 0, 0],
+        ]);
+        input.district_excluded_candidates = HashMap::from([(13, HashSet::from([5, 6]))]);
+        assert_matches!(
+            process_districts(&input),
+            Err(ApportionmentError::InvalidState(message))
+                if message == "Excluded candidate 6 is not on district list 13"
+        );
+    }
+
+    #[test]
+    fn test_distribution_excluded_candidates_of_unknown_district_list_fails() {
+        let mut input = fixture([
+            vec![300, 20, 20, 10, 10],
+            vec![200, 20, 10, 5, 5],
+            vec![80, 10, 10, 0, 0],
+        ]);
+        input.district_excluded_candidates = HashMap::from([(14, HashSet::from([1]))]);
+        assert_matches!(
+            process_districts(&input),
+            Err(ApportionmentError::InvalidState(_))
+        );
+    }
+
+    #[test]
+    fn test_distribution_unsorted_district_lists_fails() {
+        let mut input = fixture([
+            vec![300, 20, 20, 10, 10],
+            vec![200, 20, 10, 5, 5],
+            vec![80, 10, 10, 0, 0],
+        ]);
+        input.district_list_votes.swap(0, 1);
+        assert_matches!(
+            process_districts(&input),
+            Err(ApportionmentError::UnsortedInput)
+        );
+    }
+}
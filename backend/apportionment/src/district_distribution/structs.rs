@@ -0,0 +1,100 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{Fraction, structs::DistrictListDrawingLotsVariant};
+
+/// The result of the district distribution procedure. This contains the distribution
+/// of the seats of each list over its district lists.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistrictDistributionDetails<LN, DLN> {
+    pub list_distributions: Vec<ListDistrictDistribution<LN, DLN>>,
+}
+
+impl<LN, DLN: Copy + Eq + Hash> DistrictDistributionDetails<LN, DLN> {
+    /// Returns the total number of seats each district list received
+    pub fn total_seats_per_district_list(&self) -> HashMap<DLN, u32> {
+        self.list_distributions
+            .iter()
+            .flat_map(|distribution| &distribution.standings)
+            .map(|standing| (standing.district_list_number, standing.total_seats()))
+            .collect()
+    }
+}
+
+/// Contains the distribution of the seats of a list over its district lists. This contains
+/// the group quota that was used, the initial standing after full seats were assigned,
+/// and each of the residual seat changes with the standings before that change.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListDistrictDistribution<LN, DLN> {
+    /// List number for which this distribution applies
+    pub list_number: LN,
+    /// The number of seats assigned to this list
+    pub seats: u32,
+    /// The number of votes cast for all district lists of this list
+    pub votes_cast: u64,
+    /// The number of votes per seat for this list (groepsquotiënt)
+    pub quota: Fraction,
+    /// The number of seats assigned to the district lists as full seats
+    pub full_seats: u32,
+    /// The number of seats assigned to the district lists as residual seats
+    pub residual_seats: u32,
+    pub steps: Vec<DistrictSeatChangeStep<LN, DLN>>,
+    pub standings: Vec<DistrictListStanding<DLN>>,
+}
+
+/// Contains the standing for a specific district list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistrictListStanding<DLN> {
+    /// District list number for which this standing applies
+    pub district_list_number: DLN,
+    /// Whether this district list was submitted in more than one district
+    pub combined: bool,
+    /// The number of votes cast for this district list
+    pub votes_cast: u64,
+    /// The remainder of votes that was not used to get full seats
+    pub remainder_votes: Fraction,
+    /// The number of candidates on this district list that can be assigned a seat
+    pub number_of_candidates: u32,
+    /// The number of full seats this district list got assigned
+    pub full_seats: u32,
+    /// The current number of residual seats this district list got assigned
+    pub residual_seats: u32,
+}
+
+impl<DLN> DistrictListStanding<DLN> {
+    /// Returns the total number of seats assigned to this district list
+    pub fn total_seats(&self) -> u32 {
+        self.full_seats + self.residual_seats
+    }
+
+    /// Returns true if every candidate on this district list has been assigned a seat
+    pub fn is_exhausted(&self) -> bool {
+        self.total_seats() >= self.number_of_candidates
+    }
+}
+
+/// Records the residual seat that was assigned to a district list and how
+/// the standings were before that seat was assigned
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistrictSeatChangeStep<LN, DLN> {
+    pub residual_seat_number: u32,
+    pub change: DistrictLargestRemainderAssignedSeat<LN, DLN>,
+    pub standings: Vec<DistrictListStanding<DLN>>,
+}
+
+/// Contains the details for a residual seat, assigned through the largest remainder method.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistrictLargestRemainderAssignedSeat<LN, DLN> {
+    /// The district list that was selected for this seat has this number
+    pub selected_district_list_number: DLN,
+    /// Collection of district lists with the same remainder, that have not been assigned a seat
+    pub list_options: Vec<DLN>,
+    /// The number of remainder votes achieved by the selected district list
+    pub remainder_votes: Fraction,
+    /// Details for drawing lots if required
+    pub drawing_lots: Option<DistrictListDrawingLotsVariant<LN, DLN>>,
+}
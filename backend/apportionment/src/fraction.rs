@@ -6,6 +6,8 @@ use std::{
 
 /// Implementation of a fraction with a numerator and a denominator.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerializedFraction"))]
 // type invariant: denominator has to be nonzero
 pub struct Fraction {
     pub numerator: u64,
@@ -36,6 +38,26 @@ impl Fraction {
     }
 }
 
+/// Fraction as it is deserialized, before checking that the denominator is nonzero
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedFraction {
+    numerator: u64,
+    denominator: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerializedFraction> for Fraction {
+    type Error = &'static str;
+
+    fn try_from(fraction: SerializedFraction) -> std::result::Result<Self, Self::Error> {
+        if fraction.denominator == 0 {
+            return Err("a Fraction denominator cannot be zero");
+        }
+        Ok(Self::new(fraction.numerator, fraction.denominator))
+    }
+}
+
 impl From<u32> for Fraction {
     fn from(numerator: u32) -> Self {
         Self::new(u64::from(numerator), 1)
@@ -1,20 +1,37 @@
 //! Apportionment calculation with two parts, the seat assignment and the candidate nomination.
+//! For elections with multiple districts, the seats of each list are distributed over its
+//! district lists in between these two parts. After the result is determined, the `vacancy`
+//! module designates successors for seats that become vacant and the `seat_margin` module
+//! determines how close the result was to a different outcome.
 //!
 //! Crate also contains a definition of a fraction, which is used in all calculations.
+//!
+//! With the `serde` feature, the output can be serialized and deserialized, see the `record`
+//! module for the versioned schema.
 
 #![cfg_attr(test, allow(clippy::too_many_lines, clippy::cognitive_complexity))]
 
 mod candidate_nomination;
+mod district_distribution;
 mod fraction;
+#[cfg(test)]
+mod property_tests;
+#[cfg(feature = "serde")]
+pub mod record;
 mod seat_assignment;
+mod seat_margin;
 mod structs;
 #[cfg(test)]
 mod test_helpers;
+mod vacancy;
 
 use structs::{CandidateNumber, ListNumber};
 
 use self::{
     candidate_nomination::candidate_nomination,
+    district_distribution::{
+        DistrictDistribution, as_district_candidate_nomination_input, district_distribution,
+    },
     seat_assignment::{as_candidate_nomination_input, seat_assignment},
 };
 pub use self::{
@@ -22,17 +39,28 @@ pub use self::{
         Candidate, CandidateNomination, CandidateNominationDetails, CandidateRanking,
         ListCandidateNomination, PreferenceThreshold,
     },
+    district_distribution::{
+        DistrictDistributionDetails, DistrictLargestRemainderAssignedSeat, DistrictListStanding,
+        DistrictSeatChangeStep, ListDistrictDistribution,
+    },
     fraction::Fraction,
     seat_assignment::{
-        ApportionmentWarning, HighestAverageAssignedSeat, SeatAssignment, SeatAssignmentDetails,
-        SeatChange, SeatChangeStep,
+        ApportionmentWarning, HighestAverageAssignedSeat, KieswetArticle, ResidualSeatMethod,
+        SeatAssignment, SeatAssignmentDetails, SeatChange, SeatChangeExplanation, SeatChangeReason,
+        SeatChangeStep,
+    },
+    seat_margin::{
+        CandidatePreferenceMargin, ListPreferenceMargins, ListSeatMargin, OtherListMargin,
+        SeatMarginDetails, seat_margins,
     },
     structs::{
         AbsoluteMajorityDrawingLots, ApportionmentDetails, ApportionmentError, ApportionmentInput,
-        CandidateDrawingLotsVariant, CandidateDrawn, CandidateVotes,
-        HighestAverageResidualSeatDrawingLots, LargestRemainderResidualSeatDrawingLots,
-        ListDrawingLotsVariant, ListDrawn, ListVotes,
+        CandidateDrawingLotsVariant, CandidateDrawn, CandidateVotes, DistrictApportionmentDetails,
+        DistrictApportionmentInput, DistrictListDrawingLotsVariant, DistrictListDrawn,
+        DistrictListVotes, HighestAverageResidualSeatDrawingLots,
+        LargestRemainderResidualSeatDrawingLots, ListDrawingLotsVariant, ListDrawn, ListVotes,
     },
+    vacancy::{SkippedCandidate, SuccessorSkipReason, VacancySuccession, vacancy_successor},
 };
 
 #[derive(Debug, PartialEq)]
@@ -52,19 +80,7 @@ pub enum ApportionmentOutput<'a, T: ApportionmentInput> {
 pub fn process<T: ApportionmentInput>(
     input: &T,
 ) -> Result<ApportionmentOutput<'_, T>, ApportionmentError> {
-    // Check if all list votes are sorted by number
-    if !input.list_votes().is_sorted_by_key(|lv| lv.number()) {
-        return Err(ApportionmentError::UnsortedInput);
-    }
-
-    // Check if all candidate votes are sorted by number
-    if input
-        .list_votes()
-        .iter()
-        .any(|lv| !lv.candidate_votes().is_sorted_by_key(|cv| cv.number()))
-    {
-        return Err(ApportionmentError::UnsortedInput);
-    }
+    check_sorted(input.list_votes())?;
 
     let seat_assignment = match seat_assignment(input)? {
         SeatAssignment::Completed(seat_assignment) => seat_assignment,
@@ -94,6 +110,98 @@ pub fn process<T: ApportionmentInput>(
     }))
 }
 
+#[derive(Debug, PartialEq)]
+pub enum DistrictApportionmentOutput<'a, T: DistrictApportionmentInput> {
+    Completed(DistrictApportionmentDetails<'a, T::List, T::DistrictList>),
+    ListDrawingLotsRequired(
+        ListDrawingLotsVariant<ListNumber<T::List>>,
+        SeatAssignmentDetails<ListNumber<T::List>>,
+    ),
+    DistrictListDrawingLotsRequired(
+        DistrictListDrawingLotsVariant<ListNumber<T::List>, ListNumber<T::DistrictList>>,
+        SeatAssignmentDetails<ListNumber<T::List>>,
+    ),
+    CandidateDrawingLotsRequired(
+        CandidateDrawingLotsVariant<ListNumber<T::DistrictList>, CandidateNumber<T::DistrictList>>,
+        SeatAssignmentDetails<ListNumber<T::List>>,
+        DistrictDistributionDetails<ListNumber<T::List>, ListNumber<T::DistrictList>>,
+    ),
+}
+
+/// Perform seat assignment, district distribution and candidate nomination on
+/// apportionment input for an election with multiple districts.
+///
+/// The seats are assigned to the lists, then the seats of each list are distributed
+/// over its district lists and finally the candidates of the district lists are nominated.
+pub fn process_districts<T: DistrictApportionmentInput>(
+    input: &T,
+) -> Result<DistrictApportionmentOutput<'_, T>, ApportionmentError> {
+    check_sorted(input.list_votes())?;
+    check_sorted(input.district_list_votes())?;
+
+    let seat_assignment = match seat_assignment(input)? {
+        SeatAssignment::Completed(seat_assignment) => seat_assignment,
+        SeatAssignment::DrawingLotsRequired(variant, seat_assignment) => {
+            return Ok(DistrictApportionmentOutput::ListDrawingLotsRequired(
+                variant,
+                seat_assignment,
+            ));
+        }
+    };
+
+    let district_distribution = match district_distribution(input, &seat_assignment)? {
+        DistrictDistribution::Completed(district_distribution) => district_distribution,
+        DistrictDistribution::DrawingLotsRequired(variant) => {
+            return Ok(
+                DistrictApportionmentOutput::DistrictListDrawingLotsRequired(
+                    variant,
+                    seat_assignment,
+                ),
+            );
+        }
+    };
+
+    let candidate_nomination_input =
+        as_district_candidate_nomination_input(input, &seat_assignment, &district_distribution);
+    let candidate_nomination = match candidate_nomination(
+        &candidate_nomination_input,
+        input.district_candidates_drawn(),
+    )? {
+        CandidateNomination::Completed(candidate_nomination) => candidate_nomination,
+        CandidateNomination::DrawingLotsRequired(variant) => {
+            return Ok(DistrictApportionmentOutput::CandidateDrawingLotsRequired(
+                variant,
+                seat_assignment,
+                district_distribution,
+            ));
+        }
+    };
+
+    Ok(DistrictApportionmentOutput::Completed(
+        DistrictApportionmentDetails {
+            seat_assignment,
+            district_distribution,
+            candidate_nomination,
+        },
+    ))
+}
+
+/// Check if all lists are sorted by number, and all candidates within a list as well
+fn check_sorted<L: ListVotes>(list_votes: &[L]) -> Result<(), ApportionmentError> {
+    if !list_votes.is_sorted_by_key(|lv| lv.number()) {
+        return Err(ApportionmentError::UnsortedInput);
+    }
+
+    if list_votes
+        .iter()
+        .any(|lv| !lv.candidate_votes().is_sorted_by_key(|cv| cv.number()))
+    {
+        return Err(ApportionmentError::UnsortedInput);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -262,7 +370,7 @@ mod tests {
             15,
             vec![540, 160, 160, 80, 80, 80, 60, 40],
         );
-        input.deceased_candidates = HashMap::from([(1, HashSet::from([1, 50]))]);
+        input.excluded_candidates = HashMap::from([(1, HashSet::from([1, 50]))]);
 
         let Ok(ApportionmentOutput::Completed(result)) = process(&input) else {
             panic!("should be Completed")
@@ -404,7 +512,7 @@ mod tests {
                 5,
                 vec![vec![300, 0, 0], vec![100, 0], vec![80, 0]],
             );
-            input.deceased_candidates = case.deceased;
+            input.excluded_candidates = case.deceased;
 
             let result =
                 process(&input).unwrap_or_else(|e| panic!("case `{}` failed: {e:?}", case.name));
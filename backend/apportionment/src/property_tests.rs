@@ -0,0 +1,419 @@
+//! Deterministic property tests for the seat assignment.
+//!
+//! These check the invariants of the `anonymity`, `house_monotonicity`,
+//! `population_monotonicity` and `differential_osv2020` fuzz targets on inputs from a seeded
+//! generator, so they run as part of the normal test suite. Instead of the external OSV2020
+//! implementation, the differential test compares against an independent seat assignment
+//! using arbitrary-precision rationals.
+//!
+//! A failing case reports its seed, which can be used to reproduce it with [generate_input].
+
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use rand::{RngExt, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+use crate::{
+    ListVotes,
+    seat_assignment::{SeatAssignment, seat_assignment},
+    structs::LARGE_COUNCIL_THRESHOLD,
+    test_helpers::{
+        ApportionmentInputMock, ListVotesMock, get_total_seats_from_apportionment_result,
+    },
+};
+
+/// Number of generated inputs per property
+const CASES: u64 = 300;
+
+/// Seed of the first generated input, the next inputs use the subsequent seeds
+const FIRST_SEED: u64 = 2_026_101;
+
+/// Run the property for every case, with a generator seeded for that case.
+fn for_each_case(mut property: impl FnMut(u64, &mut StdRng)) {
+    for seed in FIRST_SEED..FIRST_SEED + CASES {
+        property(seed, &mut StdRng::seed_from_u64(seed));
+    }
+}
+
+fn input_mock(number_of_seats: u32, list_votes: Vec<ListVotesMock>) -> ApportionmentInputMock {
+    ApportionmentInputMock {
+        number_of_seats,
+        list_votes,
+        excluded_candidates: HashMap::new(),
+        lists_drawn: vec![],
+        candidates_drawn: vec![],
+    }
+}
+
+/// Generate an input with the number of seats in the given range.
+///
+/// Like the fuzzer, votes are biased towards ties: a candidate sometimes copies the votes of an
+/// earlier candidate and a list sometimes copies the votes of an earlier list. With
+/// `enough_candidates`, every list has as many candidates as there are seats, so no list can
+/// be exhausted (Artikel P 10 Kieswet).
+fn generate_input(
+    rng: &mut StdRng,
+    seats: RangeInclusive<u32>,
+    enough_candidates: bool,
+) -> ApportionmentInputMock {
+    let number_of_seats = rng.random_range(seats);
+    let number_of_lists = rng.random_range(1..=12);
+    let mut list_votes: Vec<ListVotesMock> = vec![];
+    let mut all_votes: Vec<u32> = vec![];
+
+    for number in 1..=number_of_lists {
+        // Copy the votes of an earlier list, producing a tie between lists
+        if !list_votes.is_empty() && rng.random_range(0..5) == 0 {
+            let index = rng.random_range(0..list_votes.len());
+            let candidate_votes = list_votes[index].candidate_votes.clone();
+            list_votes.push(ListVotesMock {
+                number,
+                candidate_votes,
+            });
+            continue;
+        }
+
+        let number_of_candidates = if enough_candidates {
+            number_of_seats
+        } else {
+            rng.random_range(1..=20)
+        };
+        let mut candidate_votes = vec![];
+        for _ in 0..number_of_candidates {
+            let votes = match rng.random_range(0..4) {
+                // Copy the votes of an earlier candidate
+                0 if !all_votes.is_empty() => all_votes[rng.random_range(0..all_votes.len())],
+                1 => rng.random_range(0..=100),
+                2 => 0,
+                _ => rng.random_range(0..=5_000),
+            };
+            all_votes.push(votes);
+            candidate_votes.push(votes);
+        }
+        list_votes.push(ListVotesMock::from_test_data_auto(number, candidate_votes));
+    }
+
+    input_mock(number_of_seats, list_votes)
+}
+
+/// Returns the total number of seats per list, or None if drawing of lots is required.
+fn total_seats(input: &ApportionmentInputMock) -> Option<Vec<u32>> {
+    match seat_assignment(input).expect("seat assignment should not fail") {
+        SeatAssignment::Completed(details) => {
+            Some(get_total_seats_from_apportionment_result(&details))
+        }
+        SeatAssignment::DrawingLotsRequired(..) => None,
+    }
+}
+
+fn number_of_candidates(list: &ListVotesMock) -> u32 {
+    u32::try_from(list.candidate_votes.len()).expect("number of candidates fits in u32")
+}
+
+/// Every seat is assigned, unless the lists with votes have fewer candidates than there are
+/// seats. No list gets more seats than it has candidates, and lists without votes get no seats.
+#[test]
+fn test_property_all_seats_assigned() {
+    for_each_case(|seed, rng| {
+        let input = generate_input(rng, 9..=50, false);
+        let Some(seats) = total_seats(&input) else {
+            return;
+        };
+
+        let candidates_with_votes: u32 = input
+            .list_votes
+            .iter()
+            .filter(|list| list.total_votes() > 0)
+            .map(number_of_candidates)
+            .sum();
+        assert_eq!(
+            seats.iter().sum::<u32>(),
+            input.number_of_seats.min(candidates_with_votes),
+            "seed {seed}: {seats:?}\n{input:?}"
+        );
+        for (list, list_seats) in input.list_votes.iter().zip(&seats) {
+            assert!(
+                *list_seats <= number_of_candidates(list),
+                "seed {seed}: {seats:?}\n{input:?}"
+            );
+            if list.total_votes() == 0 {
+                assert_eq!(*list_seats, 0, "seed {seed}: {seats:?}\n{input:?}");
+            }
+        }
+    });
+}
+
+/// Reordering and renumbering the lists does not change the seats of any list.
+#[test]
+fn test_property_anonymity() {
+    for_each_case(|seed, rng| {
+        let input = generate_input(rng, 9..=50, false);
+        let mut order: Vec<usize> = (0..input.list_votes.len()).collect();
+        order.shuffle(rng);
+        let reordered_input = input_mock(
+            input.number_of_seats,
+            order
+                .iter()
+                .zip(1..)
+                .map(|(&index, number)| ListVotesMock {
+                    number,
+                    candidate_votes: input.list_votes[index].candidate_votes.clone(),
+                })
+                .collect(),
+        );
+
+        if let (Some(seats), Some(reordered_seats)) =
+            (total_seats(&input), total_seats(&reordered_input))
+        {
+            let expected: Vec<u32> = order.iter().map(|&index| seats[index]).collect();
+            assert_eq!(
+                reordered_seats, expected,
+                "seed {seed}: order {order:?}\n{input:?}"
+            );
+        }
+    });
+}
+
+/// Adding seats never decreases the seats of a list. This only holds for the highest averages
+/// method, used for councils of 19 seats or more.
+#[test]
+fn test_property_house_monotonicity() {
+    for_each_case(|seed, rng| {
+        let mut input = generate_input(rng, LARGE_COUNCIL_THRESHOLD..=50, false);
+        let added_seats = rng.random_range(1..=25);
+        let Some(seats) = total_seats(&input) else {
+            return;
+        };
+
+        input.number_of_seats += added_seats;
+        if let Some(new_seats) = total_seats(&input) {
+            assert!(
+                new_seats.iter().zip(&seats).all(|(new, old)| new >= old),
+                "seed {seed}: {seats:?} -> {new_seats:?} with {added_seats} added seats\n{input:?}"
+            );
+        }
+    });
+}
+
+/// Adding votes to a list never decreases the seats of that list.
+#[test]
+fn test_property_population_monotonicity() {
+    for_each_case(|seed, rng| {
+        let mut input = generate_input(rng, 9..=50, false);
+        let added_votes = rng.random_range(1..=1_000);
+        let Some(seats) = total_seats(&input) else {
+            return;
+        };
+
+        input.list_votes[0].candidate_votes[0].1 += added_votes;
+        if let Some(new_seats) = total_seats(&input) {
+            assert!(
+                new_seats[0] >= seats[0],
+                "seed {seed}: {seats:?} -> {new_seats:?} with {added_votes} added votes\n{input:?}"
+            );
+        }
+    });
+}
+
+/// The seat assignment matches the rational oracle, both in the assigned seats and in whether
+/// drawing of lots is required.
+#[test]
+fn test_property_differential_rational_oracle() {
+    for_each_case(|seed, rng| {
+        let input = generate_input(rng, 9..=50, true);
+        let seats = match seat_assignment(&input).expect("seat assignment should not fail") {
+            SeatAssignment::Completed(details) => Some(
+                details
+                    .standings
+                    .iter()
+                    .map(|standing| (standing.full_seats, standing.total_seats))
+                    .collect::<Vec<_>>(),
+            ),
+            SeatAssignment::DrawingLotsRequired(..) => None,
+        };
+
+        assert_eq!(
+            seats,
+            oracle_seat_assignment(&input),
+            "seed {seed}\n{input:?}"
+        );
+    });
+}
+
+fn rational(value: impl Into<BigInt>) -> BigRational {
+    BigRational::from_integer(value.into())
+}
+
+/// Residual seat assignment methods of the oracle
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OracleMethod {
+    LargestRemainder,
+    UniqueHighestAverage,
+    HighestAverage,
+}
+
+struct OracleStep {
+    method: OracleMethod,
+    selected: usize,
+    /// Lists with the same largest remainder or highest average as the selected list
+    options: Vec<usize>,
+    /// Lists that got a seat in consecutive steps with the same method and options,
+    /// the candidates for retracting a seat under Artikel P 9 Kieswet
+    assigned: Vec<usize>,
+}
+
+struct OracleStanding {
+    votes_cast: u32,
+    votes: BigRational,
+    remainder: BigRational,
+    meets_remainder_threshold: bool,
+    full_seats: u32,
+    residual_seats: u32,
+}
+
+impl OracleStanding {
+    fn total_seats(&self) -> u32 {
+        self.full_seats + self.residual_seats
+    }
+
+    fn next_average(&self) -> BigRational {
+        &self.votes / rational(self.total_seats() + 1)
+    }
+}
+
+/// Seat assignment recomputed with arbitrary-precision rationals, independent of [Fraction].
+/// Only supports inputs where no list can be exhausted.
+///
+/// Returns the full and total seats per list, or None if drawing of lots is required.
+///
+/// [Fraction]: crate::Fraction
+fn oracle_seat_assignment(input: &ApportionmentInputMock) -> Option<Vec<(u32, u32)>> {
+    let seats = input.number_of_seats;
+    let total_votes = rational(
+        input
+            .list_votes
+            .iter()
+            .map(|list| u64::from(list.total_votes()))
+            .sum::<u64>(),
+    );
+    if total_votes == rational(0) {
+        return Some(vec![(0, 0); input.list_votes.len()]);
+    }
+
+    // Artikel P 5 and P 6 Kieswet
+    let quota = &total_votes / rational(seats);
+    let mut standings: Vec<OracleStanding> = input
+        .list_votes
+        .iter()
+        .map(|list| {
+            let votes = rational(list.total_votes());
+            let full_seats =
+                u32::try_from((&votes / &quota).to_integer()).expect("full seats fit in u32");
+            OracleStanding {
+                votes_cast: list.total_votes(),
+                remainder: &votes - &quota * rational(full_seats),
+                meets_remainder_threshold: votes >= &quota * BigRational::new(3.into(), 4.into()),
+                votes,
+                full_seats,
+                residual_seats: 0,
+            }
+        })
+        .collect();
+
+    // Artikel P 7 and P 8 Kieswet
+    let residual_seats = seats - standings.iter().map(|s| s.full_seats).sum::<u32>();
+    let mut steps: Vec<OracleStep> = vec![];
+    for assigned_residual_seats in 0..residual_seats {
+        let (method, qualifying) = oracle_qualifying_lists(seats, &standings, &steps);
+        let value = |index: usize| {
+            if method == OracleMethod::LargestRemainder {
+                standings[index].remainder.clone()
+            } else {
+                standings[index].next_average()
+            }
+        };
+        let max = qualifying.iter().map(|&index| value(index)).max()?;
+        let options: Vec<usize> = qualifying
+            .into_iter()
+            .filter(|&index| value(index) == max)
+            .collect();
+        if options.len() > (residual_seats - assigned_residual_seats) as usize {
+            return None;
+        }
+
+        let selected = options[0];
+        let mut assigned = match steps.last() {
+            Some(last) if last.method == method && last.options.contains(&selected) => {
+                last.assigned.clone()
+            }
+            _ => vec![],
+        };
+        assigned.push(selected);
+        standings[selected].residual_seats += 1;
+        steps.push(OracleStep {
+            method,
+            selected,
+            options,
+            assigned,
+        });
+    }
+
+    // Artikel P 9 Kieswet
+    if let Some(last) = steps.last()
+        && let Some(majority) = standings
+            .iter()
+            .position(|s| &s.votes * rational(2) > total_votes)
+        && standings[majority].total_seats() * 2 <= seats
+    {
+        let [retracted] = last.assigned[..] else {
+            return None;
+        };
+        standings[retracted].residual_seats -= 1;
+        standings[majority].residual_seats += 1;
+    }
+
+    Some(
+        standings
+            .iter()
+            .map(|s| (s.full_seats, s.total_seats()))
+            .collect(),
+    )
+}
+
+/// Returns the method for the next residual seat and the lists that qualify for it.
+fn oracle_qualifying_lists(
+    seats: u32,
+    standings: &[OracleStanding],
+    steps: &[OracleStep],
+) -> (OracleMethod, Vec<usize>) {
+    let received_seat = |method: OracleMethod, index: usize| {
+        steps
+            .iter()
+            .any(|step| step.method == method && step.selected == index)
+    };
+    let with_votes = (0..standings.len()).filter(|&index| standings[index].votes_cast > 0);
+
+    if seats < LARGE_COUNCIL_THRESHOLD {
+        // At most one largest remainder seat per list that meets the threshold
+        let largest_remainder: Vec<usize> = with_votes
+            .clone()
+            .filter(|&index| standings[index].meets_remainder_threshold)
+            .filter(|&index| !received_seat(OracleMethod::LargestRemainder, index))
+            .collect();
+        if !largest_remainder.is_empty() {
+            return (OracleMethod::LargestRemainder, largest_remainder);
+        }
+
+        // Then at most one highest average seat per list, until every list got one
+        let unique_highest_average: Vec<usize> = with_votes
+            .clone()
+            .filter(|&index| !received_seat(OracleMethod::UniqueHighestAverage, index))
+            .collect();
+        if !unique_highest_average.is_empty() {
+            return (OracleMethod::UniqueHighestAverage, unique_highest_average);
+        }
+    }
+
+    (OracleMethod::HighestAverage, with_votes.collect())
+}
@@ -0,0 +1,352 @@
+//! Owned and versioned records of the output of the apportionment, to archive a calculation,
+//! compare it with another run or read it in other tools. Only available with the `serde`
+//! feature.
+//!
+//! Most output types derive `Serialize` and `Deserialize` directly. The candidate nomination
+//! refers to the candidate votes of the input, so it is serialized as a record containing the
+//! number and votes of the candidates instead, and can be deserialized as such a record.
+//! [`ApportionmentDetails`] and [`DistrictApportionmentDetails`] serialize as
+//! [`ApportionmentRecord`] and [`DistrictApportionmentRecord`], which contain the
+//! [`SchemaVersion`] the output was written with.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    ApportionmentDetails, Candidate, CandidateNominationDetails, CandidateRanking, CandidateVotes,
+    DistrictApportionmentDetails, DistrictDistributionDetails, ListCandidateNomination, ListVotes,
+    PreferenceThreshold, SeatAssignmentDetails,
+    structs::{CandidateNumber, ListNumber},
+};
+
+/// Version of the schema of the records. It is increased on every change to the serialized
+/// output that existing readers cannot handle, such as removing or renaming a field or
+/// changing its meaning. Adding a variant or an optional field does not change the version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Version of the schema a record was written with, only the current version can be read
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct SchemaVersion(u32);
+
+impl SchemaVersion {
+    pub const CURRENT: Self = Self(SCHEMA_VERSION);
+}
+
+impl From<SchemaVersion> for u32 {
+    fn from(version: SchemaVersion) -> Self {
+        version.0
+    }
+}
+
+impl TryFrom<u32> for SchemaVersion {
+    type Error = UnsupportedSchemaVersion;
+
+    fn try_from(version: u32) -> Result<Self, Self::Error> {
+        if version == SCHEMA_VERSION {
+            Ok(Self(version))
+        } else {
+            Err(UnsupportedSchemaVersion(version))
+        }
+    }
+}
+
+/// Error when reading a record written with a different schema version
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnsupportedSchemaVersion(pub u32);
+
+impl fmt::Display for UnsupportedSchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unsupported schema version {}, expected version {SCHEMA_VERSION}",
+            self.0
+        )
+    }
+}
+
+/// Record of a completed apportionment, see [`ApportionmentDetails`]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApportionmentRecord<LN, CN> {
+    pub schema_version: SchemaVersion,
+    pub seat_assignment: SeatAssignmentDetails<LN>,
+    pub candidate_nomination: CandidateNominationRecord<LN, CN>,
+}
+
+/// Record of a completed apportionment for multiple districts,
+/// see [`DistrictApportionmentDetails`]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DistrictApportionmentRecord<LN, DLN, CN> {
+    pub schema_version: SchemaVersion,
+    pub seat_assignment: SeatAssignmentDetails<LN>,
+    pub district_distribution: DistrictDistributionDetails<LN, DLN>,
+    pub candidate_nomination: CandidateNominationRecord<DLN, CN>,
+}
+
+/// Record of the candidate nomination, see [`CandidateNominationDetails`]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CandidateNominationRecord<LN, CN> {
+    pub preference_threshold: PreferenceThreshold,
+    pub chosen_candidates: Vec<CandidateRecord<LN, CN>>,
+    pub list_candidate_nomination: Vec<ListCandidateNominationRecord<LN, CN>>,
+}
+
+/// Record of a chosen candidate, see [`Candidate`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CandidateRecord<LN, CN> {
+    pub list_number: LN,
+    pub candidate_number: CN,
+}
+
+/// Record of the candidate nomination of a list, see [`ListCandidateNomination`]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListCandidateNominationRecord<LN, CN> {
+    pub list_number: LN,
+    pub list_seats: u32,
+    pub preferential_candidate_nomination: Vec<CandidateVotesRecord<CN>>,
+    pub other_candidate_nomination: Vec<CandidateVotesRecord<CN>>,
+    pub candidate_ranking: CandidateRanking<CN>,
+}
+
+/// Number and votes of a nominated candidate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CandidateVotesRecord<CN> {
+    pub number: CN,
+    pub votes: u32,
+}
+
+impl<CV: CandidateVotes> From<&CV> for CandidateVotesRecord<CV::CandidateNumber> {
+    fn from(candidate_votes: &CV) -> Self {
+        Self {
+            number: candidate_votes.number(),
+            votes: candidate_votes.votes(),
+        }
+    }
+}
+
+impl<T: ListVotes> From<&Candidate<T>> for CandidateRecord<ListNumber<T>, CandidateNumber<T>> {
+    fn from(candidate: &Candidate<T>) -> Self {
+        Self {
+            list_number: candidate.list_number,
+            candidate_number: candidate.candidate_number,
+        }
+    }
+}
+
+impl<T: ListVotes> From<&ListCandidateNomination<'_, T>>
+    for ListCandidateNominationRecord<ListNumber<T>, CandidateNumber<T>>
+{
+    fn from(nomination: &ListCandidateNomination<'_, T>) -> Self {
+        Self {
+            list_number: nomination.list_number,
+            list_seats: nomination.list_seats,
+            preferential_candidate_nomination: nomination
+                .preferential_candidate_nomination
+                .iter()
+                .map(|&cv| cv.into())
+                .collect(),
+            other_candidate_nomination: nomination
+                .other_candidate_nomination
+                .iter()
+                .map(|&cv| cv.into())
+                .collect(),
+            candidate_ranking: nomination.candidate_ranking.clone(),
+        }
+    }
+}
+
+impl<T: ListVotes> From<&CandidateNominationDetails<'_, T>>
+    for CandidateNominationRecord<ListNumber<T>, CandidateNumber<T>>
+{
+    fn from(nomination: &CandidateNominationDetails<'_, T>) -> Self {
+        Self {
+            preference_threshold: nomination.preference_threshold,
+            chosen_candidates: nomination
+                .chosen_candidates
+                .iter()
+                .map(CandidateRecord::from)
+                .collect(),
+            list_candidate_nomination: nomination
+                .list_candidate_nomination
+                .iter()
+                .map(ListCandidateNominationRecord::from)
+                .collect(),
+        }
+    }
+}
+
+impl<T: ListVotes> From<&ApportionmentDetails<'_, T>>
+    for ApportionmentRecord<ListNumber<T>, CandidateNumber<T>>
+{
+    fn from(details: &ApportionmentDetails<'_, T>) -> Self {
+        Self {
+            schema_version: SchemaVersion::CURRENT,
+            seat_assignment: details.seat_assignment.clone(),
+            candidate_nomination: (&details.candidate_nomination).into(),
+        }
+    }
+}
+
+impl<T: ListVotes, D: ListVotes> From<&DistrictApportionmentDetails<'_, T, D>>
+    for DistrictApportionmentRecord<ListNumber<T>, ListNumber<D>, CandidateNumber<D>>
+{
+    fn from(details: &DistrictApportionmentDetails<'_, T, D>) -> Self {
+        Self {
+            schema_version: SchemaVersion::CURRENT,
+            seat_assignment: details.seat_assignment.clone(),
+            district_distribution: details.district_distribution.clone(),
+            candidate_nomination: (&details.candidate_nomination).into(),
+        }
+    }
+}
+
+impl<T: ListVotes> Serialize for ApportionmentDetails<'_, T>
+where
+    ListNumber<T>: Serialize,
+    CandidateNumber<T>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ApportionmentRecord::from(self).serialize(serializer)
+    }
+}
+
+impl<T: ListVotes, D: ListVotes> Serialize for DistrictApportionmentDetails<'_, T, D>
+where
+    ListNumber<T>: Serialize,
+    ListNumber<D>: Serialize,
+    CandidateNumber<D>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DistrictApportionmentRecord::from(self).serialize(serializer)
+    }
+}
+
+impl<T: ListVotes> Serialize for CandidateNominationDetails<'_, T>
+where
+    ListNumber<T>: Serialize,
+    CandidateNumber<T>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CandidateNominationRecord::from(self).serialize(serializer)
+    }
+}
+
+impl<T: ListVotes> Serialize for ListCandidateNomination<'_, T>
+where
+    ListNumber<T>: Serialize,
+    CandidateNumber<T>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ListCandidateNominationRecord::from(self).serialize(serializer)
+    }
+}
+
+impl<T: ListVotes> Serialize for Candidate<T>
+where
+    ListNumber<T>: Serialize,
+    CandidateNumber<T>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CandidateRecord::from(self).serialize(serializer)
+    }
+}
+
+impl<'de, T: ListVotes> Deserialize<'de> for Candidate<T>
+where
+    ListNumber<T>: Deserialize<'de>,
+    CandidateNumber<T>: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let record = CandidateRecord::deserialize(deserializer)?;
+        Ok(Self {
+            list_number: record.list_number,
+            candidate_number: record.candidate_number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        ApportionmentOutput, DistrictApportionmentOutput, Fraction, process, process_districts,
+        test_helpers::{
+            district_apportionment_fixture, seat_assignment_fixture_with_default_50_candidates,
+        },
+    };
+
+    #[test]
+    fn test_apportionment_record_round_trip() {
+        let input = seat_assignment_fixture_with_default_50_candidates(
+            15,
+            vec![540, 160, 160, 80, 80, 80, 60, 40],
+        );
+        let Ok(ApportionmentOutput::Completed(details)) = process(&input) else {
+            panic!("should be Completed")
+        };
+
+        let value = serde_json::to_value(&details).unwrap();
+        assert_eq!(value["schema_version"], json!(SCHEMA_VERSION));
+        assert_eq!(
+            value["seat_assignment"]["quota"],
+            json!({"numerator": 1200, "denominator": 15})
+        );
+        assert_eq!(
+            value["candidate_nomination"]["list_candidate_nomination"][0]["preferential_candidate_nomination"]
+                [0],
+            json!({"number": 1, "votes": 540})
+        );
+
+        let record: ApportionmentRecord<u32, u32> = serde_json::from_value(value).unwrap();
+        assert_eq!(record, ApportionmentRecord::from(&details));
+        assert_eq!(record.seat_assignment, details.seat_assignment);
+    }
+
+    #[test]
+    fn test_district_apportionment_record_round_trip() {
+        let input = district_apportionment_fixture(
+            10,
+            vec![
+                (1, 11, vec![1], vec![300, 100]),
+                (1, 12, vec![2], vec![200, 100]),
+                (2, 21, vec![1, 2], vec![250, 50]),
+            ],
+        );
+        let Ok(DistrictApportionmentOutput::Completed(details)) = process_districts(&input) else {
+            panic!("should be Completed")
+        };
+
+        let value = serde_json::to_value(&details).unwrap();
+        let record: DistrictApportionmentRecord<u32, u32, u32> =
+            serde_json::from_value(value).unwrap();
+        assert_eq!(record, DistrictApportionmentRecord::from(&details));
+        assert_eq!(record.district_distribution, details.district_distribution);
+    }
+
+    #[test]
+    fn test_unsupported_schema_version() {
+        let err = serde_json::from_value::<SchemaVersion>(json!(SCHEMA_VERSION + 1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("unsupported schema version 2, expected version {SCHEMA_VERSION}")
+        );
+        assert_eq!(
+            serde_json::from_value::<SchemaVersion>(json!(SCHEMA_VERSION)).unwrap(),
+            SchemaVersion::CURRENT
+        );
+    }
+
+    #[test]
+    fn test_fraction_with_zero_denominator() {
+        assert!(
+            serde_json::from_value::<Fraction>(json!({"numerator": 1, "denominator": 0})).is_err()
+        );
+        assert_eq!(
+            serde_json::from_value::<Fraction>(json!({"numerator": 3, "denominator": 2})).unwrap(),
+            Fraction::new(3, 2)
+        );
+    }
+}
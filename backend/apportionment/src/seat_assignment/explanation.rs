@@ -0,0 +1,419 @@
+use std::fmt::Debug;
+
+use super::{ListStanding, SeatAssignmentDetails, SeatChange, SeatChangeStep};
+use crate::{ApportionmentError, fraction::Fraction, structs::LARGE_COUNCIL_THRESHOLD};
+
+/// Article of the Kieswet that a seat change is based on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KieswetArticle {
+    /// Assignment of residual seats with the highest averages method, for 19 seats or more
+    P7,
+    /// Assignment of residual seats with the largest remainders and unique highest averages
+    /// methods, for less than 19 seats
+    P8,
+    /// Reassignment of a residual seat to a list with an absolute majority of the votes
+    P9,
+    /// Removal and reassignment of seats of lists with fewer candidates than seats
+    P10,
+}
+
+impl KieswetArticle {
+    /// Returns the title of the article, e.g. "Artikel P 9 Kieswet"
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::P7 => "Artikel P 7 Kieswet",
+            Self::P8 => "Artikel P 8 Kieswet",
+            Self::P9 => "Artikel P 9 Kieswet",
+            Self::P10 => "Artikel P 10 Kieswet",
+        }
+    }
+
+    /// Returns the link to the article in the Kieswet
+    pub fn url(self) -> &'static str {
+        match self {
+            Self::P7 => {
+                "https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukP_Paragraaf2_ArtikelP7"
+            }
+            Self::P8 => {
+                "https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukP_Paragraaf2_ArtikelP8"
+            }
+            Self::P9 => {
+                "https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukP_Paragraaf2_ArtikelP9"
+            }
+            Self::P10 => {
+                "https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukP_Paragraaf2_ArtikelP10"
+            }
+        }
+    }
+}
+
+/// Method with which a residual seat was assigned
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResidualSeatMethod {
+    HighestAverage,
+    UniqueHighestAverage,
+    LargestRemainder,
+}
+
+/// The reason for a seat change
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SeatChangeReason {
+    /// The list had the highest average number of votes per seat
+    HighestAverage { votes_per_seat: Fraction },
+    /// The list had the highest average number of votes per seat of the lists that did not
+    /// get a seat with this method yet
+    UniqueHighestAverage { votes_per_seat: Fraction },
+    /// The list had the largest remainder of the lists that meet the threshold of 75% of the
+    /// quota and did not get a seat with this method yet
+    LargestRemainder { remainder_votes: Fraction },
+    /// The list got more than half of the votes, but not more than half of the seats.
+    /// The last assigned residual seat is reassigned to this list.
+    AbsoluteMajority {
+        /// The number of votes cast for the list
+        votes_cast: u64,
+        /// The number of votes cast for all lists
+        total_votes_cast: u64,
+        /// The number of seats of the list before the reassignment
+        list_seats: u32,
+        /// The number of seats in the election
+        seats: u32,
+        /// The method with which the retracted residual seat was assigned
+        retracted_seat_method: ResidualSeatMethod,
+    },
+    /// The list got more seats than it has candidates, the seat will be assigned to another list
+    ListExhaustion {
+        /// Whether the removed seat was a full seat
+        full_seat: bool,
+    },
+}
+
+/// Explanation of a single step of the seat assignment: which seat moved where, why,
+/// and on which articles of the Kieswet this is based.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeatChangeExplanation<LN> {
+    /// The residual seat that was assigned in this step, if any
+    pub residual_seat_number: Option<u32>,
+    /// The list that got the seat, if any
+    pub list_assigned_seat: Option<LN>,
+    /// The list the seat was taken from, if any
+    pub list_retracted_seat: Option<LN>,
+    /// The lists that had an equal claim in this step, including the selected list.
+    /// Empty if no other list had an equal claim.
+    pub tied_lists: Vec<LN>,
+    /// Whether lots were drawn between the tied lists
+    pub drawn_lots: bool,
+    pub reason: SeatChangeReason,
+    /// The articles of the Kieswet this seat change is based on
+    pub articles: Vec<KieswetArticle>,
+}
+
+impl<LN: Copy + Debug + PartialEq> SeatAssignmentDetails<LN> {
+    /// Explain every step of the seat assignment, in the order of the steps.
+    /// Returns an error if the steps are not a valid seat assignment.
+    pub fn explanations(&self) -> Result<Vec<SeatChangeExplanation<LN>>, ApportionmentError> {
+        let mut explanations = vec![];
+        let mut residual_seat_method = None;
+        let mut after_list_exhaustion = false;
+
+        for step in &self.steps {
+            let explanation = match &step.change {
+                SeatChange::AbsoluteMajorityReassignment(seat) => SeatChangeExplanation {
+                    residual_seat_number: step.residual_seat_number,
+                    list_assigned_seat: Some(seat.list_assigned_seat),
+                    list_retracted_seat: Some(seat.list_retracted_seat),
+                    tied_lists: seat
+                        .drawing_lots
+                        .as_ref()
+                        .map(|variant| tied_lists(variant.options()))
+                        .unwrap_or_default(),
+                    drawn_lots: seat.drawing_lots.is_some(),
+                    reason: absolute_majority_reason(
+                        step,
+                        seat.list_assigned_seat,
+                        self.seats,
+                        residual_seat_method.ok_or_else(|| {
+                            ApportionmentError::InvalidState(
+                                "Absolute majority reassignment without a preceding residual seat"
+                                    .to_string(),
+                            )
+                        })?,
+                    )?,
+                    articles: vec![KieswetArticle::P9],
+                },
+                SeatChange::ListExhaustionRemoval(seat) => {
+                    after_list_exhaustion = true;
+                    SeatChangeExplanation {
+                        residual_seat_number: step.residual_seat_number,
+                        list_assigned_seat: None,
+                        list_retracted_seat: Some(seat.list_retracted_seat),
+                        tied_lists: vec![],
+                        drawn_lots: false,
+                        reason: SeatChangeReason::ListExhaustion {
+                            full_seat: seat.full_seat,
+                        },
+                        articles: vec![KieswetArticle::P10],
+                    }
+                }
+                _ => {
+                    // Residual seats assigned after list exhaustion replace the removed seats
+                    let (method, explanation) =
+                        self.residual_seat_explanation(step, after_list_exhaustion);
+                    residual_seat_method = Some(method);
+                    explanation
+                }
+            };
+            explanations.push(explanation);
+        }
+
+        Ok(explanations)
+    }
+
+    /// Explain a step that assigns a residual seat, returning the method used as well
+    fn residual_seat_explanation(
+        &self,
+        step: &SeatChangeStep<LN>,
+        replaces_removed_seat: bool,
+    ) -> (ResidualSeatMethod, SeatChangeExplanation<LN>) {
+        let (method, list_number, list_options, drawn_lots, reason) = match &step.change {
+            SeatChange::HighestAverageAssignment(seat) => (
+                ResidualSeatMethod::HighestAverage,
+                seat.selected_list_number,
+                &seat.list_options,
+                seat.drawing_lots.is_some(),
+                SeatChangeReason::HighestAverage {
+                    votes_per_seat: seat.votes_per_seat,
+                },
+            ),
+            SeatChange::UniqueHighestAverageAssignment(seat) => (
+                ResidualSeatMethod::UniqueHighestAverage,
+                seat.selected_list_number,
+                &seat.list_options,
+                seat.drawing_lots.is_some(),
+                SeatChangeReason::UniqueHighestAverage {
+                    votes_per_seat: seat.votes_per_seat,
+                },
+            ),
+            SeatChange::LargestRemainderAssignment(seat) => (
+                ResidualSeatMethod::LargestRemainder,
+                seat.selected_list_number,
+                &seat.list_options,
+                seat.drawing_lots.is_some(),
+                SeatChangeReason::LargestRemainder {
+                    remainder_votes: seat.remainder_votes,
+                },
+            ),
+            SeatChange::AbsoluteMajorityReassignment(_) | SeatChange::ListExhaustionRemoval(_) => {
+                unreachable!("step does not assign a residual seat")
+            }
+        };
+
+        let article = match method {
+            ResidualSeatMethod::HighestAverage if self.seats >= LARGE_COUNCIL_THRESHOLD => {
+                KieswetArticle::P7
+            }
+            _ => KieswetArticle::P8,
+        };
+        let explanation = SeatChangeExplanation {
+            residual_seat_number: step.residual_seat_number,
+            list_assigned_seat: Some(list_number),
+            list_retracted_seat: None,
+            tied_lists: tied_lists(list_options),
+            drawn_lots,
+            reason,
+            articles: if replaces_removed_seat {
+                vec![KieswetArticle::P10, article]
+            } else {
+                vec![article]
+            },
+        };
+        (method, explanation)
+    }
+}
+
+/// Returns the lists with an equal claim, or no lists if there was only one
+fn tied_lists<LN: Copy>(list_options: &[LN]) -> Vec<LN> {
+    if list_options.len() > 1 {
+        list_options.to_vec()
+    } else {
+        vec![]
+    }
+}
+
+/// Returns the reason for an absolute majority reassignment, based on the
+/// standings before the reassignment
+fn absolute_majority_reason<LN: Copy + Debug + PartialEq>(
+    step: &SeatChangeStep<LN>,
+    list_number: LN,
+    seats: u32,
+    retracted_seat_method: ResidualSeatMethod,
+) -> Result<SeatChangeReason, ApportionmentError> {
+    let standing: &ListStanding<LN> = step
+        .standings
+        .iter()
+        .find(|s| s.list_number() == list_number)
+        .ok_or_else(|| {
+            ApportionmentError::InvalidState(format!("No standing for list {list_number:?}"))
+        })?;
+
+    Ok(SeatChangeReason::AbsoluteMajority {
+        votes_cast: standing.votes_cast(),
+        total_votes_cast: step.standings.iter().map(|s| s.votes_cast()).sum(),
+        list_seats: standing.total_seats(),
+        seats,
+        retracted_seat_method,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        seat_assignment::{SeatAssignment, seat_assignment},
+        structs::ListDrawingLotsVariant,
+        test_helpers::{
+            ListDrawnMock, seat_assignment_fixture_with_default_50_candidates,
+            seat_assignment_fixture_with_given_candidate_votes,
+        },
+    };
+
+    /// Residual seats with largest remainders, followed by the reassignment of Artikel P 9
+    /// and the removal and reassignment of Artikel P 10
+    #[test]
+    fn test_explanations_with_absolute_majority_and_list_exhaustion() {
+        let input = seat_assignment_fixture_with_given_candidate_votes(
+            15,
+            vec![
+                vec![2571, 0, 0, 0, 0, 0, 0],
+                vec![977, 0, 0, 0],
+                vec![567, 0],
+                vec![536, 0],
+                vec![453, 0],
+            ],
+        );
+        let SeatAssignment::Completed(result) = seat_assignment(&input).unwrap() else {
+            panic!("should be Completed");
+        };
+
+        let explanations = result.explanations().unwrap();
+        assert_eq!(explanations.len(), 6);
+        assert_eq!(
+            explanations[0],
+            SeatChangeExplanation {
+                residual_seat_number: Some(1),
+                list_assigned_seat: Some(2),
+                list_retracted_seat: None,
+                tied_lists: vec![],
+                drawn_lots: false,
+                reason: SeatChangeReason::LargestRemainder {
+                    remainder_votes: Fraction::new(4447, 15),
+                },
+                articles: vec![KieswetArticle::P8],
+            }
+        );
+        assert_eq!(
+            explanations[3],
+            SeatChangeExplanation {
+                residual_seat_number: None,
+                list_assigned_seat: Some(1),
+                list_retracted_seat: Some(4),
+                tied_lists: vec![],
+                drawn_lots: false,
+                reason: SeatChangeReason::AbsoluteMajority {
+                    votes_cast: 2571,
+                    total_votes_cast: 5104,
+                    list_seats: 7,
+                    seats: 15,
+                    retracted_seat_method: ResidualSeatMethod::LargestRemainder,
+                },
+                articles: vec![KieswetArticle::P9],
+            }
+        );
+        assert_eq!(
+            explanations[4],
+            SeatChangeExplanation {
+                residual_seat_number: None,
+                list_assigned_seat: None,
+                list_retracted_seat: Some(1),
+                tied_lists: vec![],
+                drawn_lots: false,
+                reason: SeatChangeReason::ListExhaustion { full_seat: false },
+                articles: vec![KieswetArticle::P10],
+            }
+        );
+        assert_eq!(explanations[5].list_assigned_seat, Some(4));
+        assert_eq!(
+            explanations[5].articles,
+            vec![KieswetArticle::P10, KieswetArticle::P8]
+        );
+    }
+
+    /// An absolute majority reassignment without a preceding residual seat cannot be explained
+    #[test]
+    fn test_explanations_with_invalid_steps_fails() {
+        let input = seat_assignment_fixture_with_given_candidate_votes(
+            15,
+            vec![
+                vec![2571, 0, 0, 0, 0, 0, 0],
+                vec![977, 0, 0, 0],
+                vec![567, 0],
+                vec![536, 0],
+                vec![453, 0],
+            ],
+        );
+        let SeatAssignment::Completed(mut result) = seat_assignment(&input).unwrap() else {
+            panic!("should be Completed");
+        };
+        assert!(matches!(
+            result.steps[3].change,
+            SeatChange::AbsoluteMajorityReassignment(_)
+        ));
+        result.steps.drain(..3);
+
+        assert!(matches!(
+            result.explanations(),
+            Err(ApportionmentError::InvalidState(_))
+        ));
+    }
+
+    /// Residual seats with highest averages, where lots are drawn between lists with the
+    /// same average
+    #[test]
+    fn test_explanations_with_highest_averages_and_drawing_lots() {
+        let mut input = seat_assignment_fixture_with_default_50_candidates(19, vec![600, 600, 200]);
+        let SeatAssignment::DrawingLotsRequired(variant, _) = seat_assignment(&input).unwrap()
+        else {
+            panic!("should be DrawingLotsRequired");
+        };
+        let ListDrawingLotsVariant::HighestAverageResidualSeat(_) = &variant else {
+            panic!("should be HighestAverageResidualSeat");
+        };
+        input.lists_drawn.push(ListDrawnMock::new(&variant, 2));
+        let SeatAssignment::Completed(result) = seat_assignment(&input).unwrap() else {
+            panic!("should be Completed");
+        };
+
+        let explanations = result.explanations().unwrap();
+        assert_eq!(
+            explanations,
+            vec![SeatChangeExplanation {
+                residual_seat_number: Some(1),
+                list_assigned_seat: Some(2),
+                list_retracted_seat: None,
+                tied_lists: vec![1, 2, 3],
+                drawn_lots: true,
+                reason: SeatChangeReason::HighestAverage {
+                    votes_per_seat: Fraction::new(200, 3),
+                },
+                articles: vec![KieswetArticle::P7],
+            }]
+        );
+        assert_eq!(KieswetArticle::P7.title(), "Artikel P 7 Kieswet");
+        assert!(KieswetArticle::P7.url().ends_with("ArtikelP7"));
+    }
+}
@@ -1,12 +1,17 @@
 use std::{cmp::Ordering, collections::HashMap, hash::Hash};
 
+mod explanation;
 mod residual_seat_assignment;
 mod structs;
+pub use explanation::{
+    KieswetArticle, ResidualSeatMethod, SeatChangeExplanation, SeatChangeReason,
+};
 #[cfg(test)]
 pub use structs::LargestRemainderAssignedSeat;
 pub use structs::{
     AbsoluteMajorityReassignedSeat, ApportionmentWarning, HighestAverageAssignedSeat,
-    ListExhaustionRemovedSeat, ListStanding, SeatAssignmentDetails, SeatChange, SeatChangeStep,
+    ListExhaustionRemovedSeat, ListSeatAssignment, ListStanding, SeatAssignmentDetails, SeatChange,
+    SeatChangeStep,
 };
 use tracing::info;
 
@@ -15,11 +20,10 @@ use crate::{
     ApportionmentError, ApportionmentInput, ListDrawn, ListVotes,
     fraction::Fraction,
     seat_assignment::structs::{
-        AbsoluteMajority, AbsoluteMajorityResult, GetListStandingByNumber, ListSeatAssignment,
-        RemainderAssignment,
+        AbsoluteMajority, AbsoluteMajorityResult, GetListStandingByNumber, RemainderAssignment,
     },
     structs::{
-        AbsoluteMajorityDrawingLots, CandidateNominationInput, DeceasedCandidates,
+        AbsoluteMajorityDrawingLots, CandidateNominationInput, ExcludedCandidates,
         ListDrawingLotsVariant, ListNumber,
     },
 };
@@ -145,11 +149,11 @@ pub(crate) fn seat_assignment<T: ApportionmentInput>(input: &T) -> SeatAssignmen
     }
 
     // [Artikel P 19a Kieswet](https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukP_Paragraaf3_ArtikelP19a)
-    for (list_number, list_deceased) in input.deceased_candidates() {
+    for (list_number, list_excluded) in input.excluded_candidates() {
         info!(
-            "Following deceased candidates will be taken into account for list {:?}: {}",
+            "Following excluded candidates will be taken into account for list {:?}: {}",
             list_number,
-            list_deceased
+            list_excluded
                 .iter()
                 .map(|c| format!("{:?}", c))
                 .collect::<Vec<_>>()
@@ -162,7 +166,7 @@ pub(crate) fn seat_assignment<T: ApportionmentInput>(input: &T) -> SeatAssignmen
         cumulative_standings,
         input.number_of_seats(),
         input.list_votes(),
-        input.deceased_candidates(),
+        input.excluded_candidates(),
         residual_seats,
         steps.clone(),
         &mut lists_drawn,
@@ -230,7 +234,7 @@ pub fn as_candidate_nomination_input<'a, T: ApportionmentInput>(
     CandidateNominationInput {
         number_of_seats: input.number_of_seats(),
         list_votes: input.list_votes(),
-        deceased_candidates: input.deceased_candidates(),
+        excluded_candidates: input.excluded_candidates(),
         quota: seat_assignment.quota,
         total_seats_per_list: get_total_seats_per_list_number_from_seat_assignments(
             &seat_assignment.standings,
@@ -247,15 +251,15 @@ fn list_numbers<LN: Copy>(standing: &[&ListStanding<LN>]) -> Vec<LN> {
 fn get_number_of_candidates<T: ListVotes>(
     input_list_votes: &[T],
     list_number: T::ListNumber,
-    deceased_candidates: &DeceasedCandidates<T>,
+    excluded_candidates: &ExcludedCandidates<T>,
 ) -> u32 {
     let list_votes = input_list_votes
         .iter()
         .find(|list_votes| list_votes.number() == list_number)
         .expect("List votes exists");
 
-    let deceased_count = deceased_candidates.get(&list_number).map_or(0, |v| v.len());
-    u32::try_from(list_votes.candidate_votes().len() - deceased_count)
+    let excluded_count = excluded_candidates.get(&list_number).map_or(0, |v| v.len());
+    u32::try_from(list_votes.candidate_votes().len() - excluded_count)
         .expect("Number of candidates fits in u32")
 }
 
@@ -264,13 +268,13 @@ fn get_number_of_candidates<T: ListVotes>(
 fn list_numbers_with_exhausted_seats<T: ListVotes>(
     standings: &[ListStanding<T::ListNumber>],
     input_list_votes: &[T],
-    deceased_candidates: &DeceasedCandidates<T>,
+    excluded_candidates: &ExcludedCandidates<T>,
 ) -> Vec<(T::ListNumber, u32)> {
     standings
         .iter()
         .fold(vec![], |mut exhausted_list_numbers_and_seats, s| {
             let number_of_candidates =
-                get_number_of_candidates(input_list_votes, s.list_number(), deceased_candidates);
+                get_number_of_candidates(input_list_votes, s.list_number(), excluded_candidates);
             if number_of_candidates.cmp(&s.total_seats()) == Ordering::Less {
                 exhausted_list_numbers_and_seats.push((
                     s.list_number(),
@@ -375,13 +379,13 @@ fn reassign_residual_seats_for_exhausted_lists<'a, T: ListVotes>(
     previous_standings: Vec<ListStanding<T::ListNumber>>,
     seats: u32,
     list_votes: &[T],
-    deceased_candidates: &DeceasedCandidates<T>,
+    excluded_candidates: &ExcludedCandidates<T>,
     assigned_residual_seats: u32,
     previous_steps: Vec<SeatChangeStep<T::ListNumber>>,
     lists_drawn: &mut impl Iterator<Item = &'a (impl ListDrawn<T::ListNumber> + 'a)>,
 ) -> RemainderAssignmentResult<T::ListNumber> {
     let exhausted_lists =
-        list_numbers_with_exhausted_seats(&previous_standings, list_votes, deceased_candidates);
+        list_numbers_with_exhausted_seats(&previous_standings, list_votes, excluded_candidates);
     if !exhausted_lists.is_empty() {
         let mut current_standings = previous_standings.clone();
         let mut seats_to_reassign = 0;
@@ -437,7 +441,7 @@ fn reassign_residual_seats_for_exhausted_lists<'a, T: ListVotes>(
             assigned_residual_seats + seats_to_reassign,
             assigned_residual_seats,
             &current_steps,
-            Some((list_votes, deceased_candidates)),
+            Some((list_votes, excluded_candidates)),
             lists_drawn,
         )?)
     } else {
@@ -495,38 +499,38 @@ pub(crate) mod tests {
         use crate::{seat_assignment::get_number_of_candidates, test_helpers::ListVotesMock};
 
         #[test]
-        fn test_without_deceased_candidates() {
+        fn test_without_excluded_candidates() {
             let list_votes = [
                 ListVotesMock::from_test_data_auto(1, vec![100, 80, 60]),
                 ListVotesMock::from_test_data_auto(2, vec![50, 40]),
             ];
-            let deceased_candidates = HashMap::new();
+            let excluded_candidates = HashMap::new();
 
             assert_eq!(
-                get_number_of_candidates(&list_votes, 1, &deceased_candidates),
+                get_number_of_candidates(&list_votes, 1, &excluded_candidates),
                 3
             );
             assert_eq!(
-                get_number_of_candidates(&list_votes, 2, &deceased_candidates),
+                get_number_of_candidates(&list_votes, 2, &excluded_candidates),
                 2
             );
         }
 
         #[test]
-        fn test_subtracts_deceased_candidates() {
+        fn test_subtracts_excluded_candidates() {
             let list_votes = [
                 ListVotesMock::from_test_data_auto(1, vec![100, 80, 60]),
                 ListVotesMock::from_test_data_auto(2, vec![50, 40]),
             ];
             // deceased candidates of list 1 do not affect list 2
-            let deceased_candidates = HashMap::from([(1, HashSet::from([2, 3]))]);
+            let excluded_candidates = HashMap::from([(1, HashSet::from([2, 3]))]);
 
             assert_eq!(
-                get_number_of_candidates(&list_votes, 1, &deceased_candidates),
+                get_number_of_candidates(&list_votes, 1, &excluded_candidates),
                 1
             );
             assert_eq!(
-                get_number_of_candidates(&list_votes, 2, &deceased_candidates),
+                get_number_of_candidates(&list_votes, 2, &excluded_candidates),
                 2
             );
         }
@@ -534,10 +538,10 @@ pub(crate) mod tests {
         #[test]
         fn test_with_all_candidates_deceased() {
             let list_votes = [ListVotesMock::from_test_data_auto(1, vec![100, 80])];
-            let deceased_candidates = HashMap::from([(1, HashSet::from([1, 2]))]);
+            let excluded_candidates = HashMap::from([(1, HashSet::from([1, 2]))]);
 
             assert_eq!(
-                get_number_of_candidates(&list_votes, 1, &deceased_candidates),
+                get_number_of_candidates(&list_votes, 1, &excluded_candidates),
                 0
             );
         }
@@ -630,15 +634,15 @@ pub(crate) mod tests {
         }
 
         #[test]
-        fn test_deceased_candidates_can_exhaust_a_list() {
+        fn test_excluded_candidates_can_exhaust_a_list() {
             // 2 seats, 2 candidates
             let list_votes = [ListVotesMock::from_test_data_auto(1, vec![100, 100])];
             let standings: Vec<_> = list_votes.iter().map(standing).collect();
             // candidate 2 of list 1 is deceased, leaving 1 candidate for 2 seats
-            let deceased_candidates = HashMap::from([(1, HashSet::from([2]))]);
+            let excluded_candidates = HashMap::from([(1, HashSet::from([2]))]);
 
             assert_eq!(
-                list_numbers_with_exhausted_seats(&standings, &list_votes, &deceased_candidates),
+                list_numbers_with_exhausted_seats(&standings, &list_votes, &excluded_candidates),
                 vec![(1, 1)]
             );
         }
@@ -2103,7 +2107,7 @@ pub(crate) mod tests {
                         vec![370, 0, 0, 0, 0],
                     ],
                 );
-                input.deceased_candidates = HashMap::from([(1, HashSet::from([6]))]);
+                input.excluded_candidates = HashMap::from([(1, HashSet::from([6]))]);
 
                 let SeatAssignment::Completed(result) = seat_assignment(&input).unwrap() else {
                     panic!("should be Completed");
@@ -3127,7 +3131,7 @@ pub(crate) mod tests {
                         vec![570, 0, 0, 0, 0, 0, 0],
                     ],
                 );
-                input.deceased_candidates = HashMap::from([(1, HashSet::from([8]))]);
+                input.excluded_candidates = HashMap::from([(1, HashSet::from([8]))]);
 
                 let SeatAssignment::Completed(result) = seat_assignment(&input).unwrap() else {
                     panic!("should be Completed");
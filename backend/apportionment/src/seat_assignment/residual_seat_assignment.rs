@@ -14,7 +14,7 @@ use crate::{
     fraction::Fraction,
     seat_assignment::structs::RemainderAssignment,
     structs::{
-        DeceasedCandidates, HighestAverageResidualSeatDrawingLots, LARGE_COUNCIL_THRESHOLD,
+        ExcludedCandidates, HighestAverageResidualSeatDrawingLots, LARGE_COUNCIL_THRESHOLD,
         LargestRemainderResidualSeatDrawingLots, ListDrawingLotsVariant, ListVotes,
     },
 };
@@ -28,7 +28,7 @@ pub fn assign_remainder<'b, T: ListVotes>(
     total_residual_seats: u32,
     current_residual_seat_number: u32,
     previous_steps: &[SeatChangeStep<T::ListNumber>],
-    exclude_exhausted_lists: Option<(&[T], &DeceasedCandidates<T>)>,
+    exclude_exhausted_lists: Option<(&[T], &ExcludedCandidates<T>)>,
     lists_drawn: &mut impl Iterator<Item = &'b (impl ListDrawn<T::ListNumber> + 'b)>,
 ) -> RemainderAssignmentResult<T::ListNumber> {
     let mut steps: Vec<SeatChangeStep<T::ListNumber>> = previous_steps.to_vec();
@@ -37,8 +37,8 @@ pub fn assign_remainder<'b, T: ListVotes>(
 
     while residual_seat_number != total_residual_seats {
         let exhausted_list_numbers =
-            if let Some((list_votes, deceased_candidates)) = exclude_exhausted_lists {
-                exhausted_list_numbers(&current_standings, list_votes, deceased_candidates)
+            if let Some((list_votes, excluded_candidates)) = exclude_exhausted_lists {
+                exhausted_list_numbers(&current_standings, list_votes, excluded_candidates)
             } else {
                 vec![]
             };
@@ -147,12 +147,12 @@ fn steps_of_type_assigning_to_list<LN: Copy + Eq>(
 fn exhausted_list_numbers<T: ListVotes>(
     standings: &[ListStanding<T::ListNumber>],
     list_votes: &[T],
-    deceased_candidates: &DeceasedCandidates<T>,
+    excluded_candidates: &ExcludedCandidates<T>,
 ) -> Vec<T::ListNumber> {
     let exhausted: Vec<T::ListNumber> = standings
         .iter()
         .filter(|s| {
-            get_number_of_candidates(list_votes, s.list_number(), deceased_candidates)
+            get_number_of_candidates(list_votes, s.list_number(), excluded_candidates)
                 <= s.total_seats()
         })
         .map(|s| s.list_number())
@@ -1106,10 +1106,7 @@ mod tests {
 
         #[test]
         fn test_returns_empty_when_there_are_no_standings() {
-            assert_eq!(
-                exhausted_list_numbers::<ListVotesMock>(&[], &[], &HashMap::new()),
-                vec![]
-            );
+            assert!(exhausted_list_numbers::<ListVotesMock>(&[], &[], &HashMap::new()).is_empty());
         }
 
         #[test]
@@ -1144,7 +1141,7 @@ mod tests {
         }
 
         #[test]
-        fn test_deceased_candidates_reduce_the_number_of_candidates() {
+        fn test_excluded_candidates_reduce_the_number_of_candidates() {
             // 1 full seat and 2 candidates, but candidate 2 is deceased
             let lists = [ListVotesMock::from_test_data_auto(1, vec![100, 0])];
             let standings = [standing(1, 100)];
@@ -9,7 +9,8 @@ use crate::{ApportionmentError, ListDrawingLotsVariant, ListVotes};
 /// that was used. It then contains the initial standing after full seats were assigned,
 /// and each of the changes and intermediate standings. The final standing contains the
 /// number of seats per list that was assigned after all seats were assigned.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SeatAssignmentDetails<LN> {
     pub seats: u32,
     pub full_seats: u32,
@@ -42,6 +43,7 @@ impl<LN: Copy> SeatAssignmentDetails<LN> {
 
 /// Warnings derived from a completed seat assignment
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ApportionmentWarning {
     /// Both an absolute-majority reassignment (P9) and a list-exhaustion
     /// removal (P10) occurred in the same apportionment.
@@ -51,7 +53,8 @@ pub enum ApportionmentWarning {
 }
 
 /// Contains information about the final assignment of seats for a specific list.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListSeatAssignment<LN> {
     /// List number for which this assignment applies
     pub list_number: LN,
@@ -87,6 +90,7 @@ impl<LN: Copy + Debug> From<ListStanding<LN>> for ListSeatAssignment<LN> {
 /// Contains the standing for a specific list. This is all the information
 /// that is needed to compute the apportionment for that specific list.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListStanding<LN> {
     /// List number for which this standing applies
     list_number: LN,
@@ -223,6 +227,7 @@ impl<LN: Copy + PartialEq> GetListStandingByNumber for [ListStanding<LN>] {
 /// Records the change for a specific seat and how the standing were
 /// before that change was applied
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SeatChangeStep<LN> {
     pub residual_seat_number: Option<u32>,
     pub change: SeatChange<LN>,
@@ -231,6 +236,7 @@ pub struct SeatChangeStep<LN> {
 
 /// Records the list and specific change for a specific residual seat
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SeatChange<LN> {
     HighestAverageAssignment(HighestAverageAssignedSeat<LN>),
     UniqueHighestAverageAssignment(HighestAverageAssignedSeat<LN>),
@@ -338,6 +344,7 @@ impl<LN: Copy> SeatChange<LN> {
 
 /// Contains the details for an assigned seat, assigned through the highest average method.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HighestAverageAssignedSeat<LN> {
     /// The list that was selected for this seat has this list number
     pub selected_list_number: LN,
@@ -355,6 +362,7 @@ pub struct HighestAverageAssignedSeat<LN> {
 
 /// Contains the details for an assigned seat, assigned through the largest remainder method.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LargestRemainderAssignedSeat<LN> {
     /// The list that was selected for this seat has this list number
     pub selected_list_number: LN,
@@ -370,6 +378,7 @@ pub struct LargestRemainderAssignedSeat<LN> {
 
 /// Contains information about the enactment of article P 9 of the Kieswet.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbsoluteMajorityReassignedSeat<LN> {
     /// List number which the residual seat is retracted from
     pub list_retracted_seat: LN,
@@ -381,6 +390,7 @@ pub struct AbsoluteMajorityReassignedSeat<LN> {
 
 /// Contains information about the enactment of article P 10 of the Kieswet.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListExhaustionRemovedSeat<LN> {
     /// List number which the seat is retracted from
     pub list_retracted_seat: LN,
@@ -0,0 +1,818 @@
+mod structs;
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
+};
+
+pub use structs::{
+    CandidatePreferenceMargin, ListPreferenceMargins, ListSeatMargin, OtherListMargin,
+    SeatMarginDetails,
+};
+use tracing::{info, subscriber::NoSubscriber};
+
+use crate::{
+    ApportionmentDetails, ApportionmentError, ApportionmentInput, CandidateVotes, Fraction,
+    ListVotes,
+    candidate_nomination::filter_out_excluded_candidates,
+    seat_assignment::{
+        ListSeatAssignment, SeatAssignment, get_total_seats_per_list_number_from_seat_assignments,
+        seat_assignment,
+    },
+    structs::{CandidateNumber, ExcludedCandidates, LARGE_COUNCIL_THRESHOLD, ListNumber},
+};
+
+type SeatsPerList<T> = HashMap<ListNumber<<T as ApportionmentInput>::List>, u32>;
+
+type SeatMarginResult<T> = Result<
+    SeatMarginDetails<
+        ListNumber<<T as ApportionmentInput>::List>,
+        CandidateNumber<<T as ApportionmentInput>::List>,
+    >,
+    ApportionmentError,
+>;
+
+/// List votes where the total number of votes is replaced, used to redo the seat assignment
+/// as if the list had received a different number of votes
+#[derive(Debug, PartialEq)]
+struct AdjustedListVotes<'a, L: ListVotes> {
+    list: &'a L,
+    total_votes: u32,
+}
+
+impl<L: ListVotes> ListVotes for AdjustedListVotes<'_, L> {
+    type Cv = L::Cv;
+    type ListNumber = L::ListNumber;
+
+    fn number(&self) -> Self::ListNumber {
+        self.list.number()
+    }
+
+    fn total_votes(&self) -> u32 {
+        self.total_votes
+    }
+
+    fn candidate_votes(&self) -> &[Self::Cv] {
+        self.list.candidate_votes()
+    }
+}
+
+/// Apportionment input where the votes of one list are adjusted and no lots are drawn
+struct AdjustedInput<'a, T: ApportionmentInput> {
+    input: &'a T,
+    list_votes: Vec<AdjustedListVotes<'a, T::List>>,
+}
+
+impl<'a, T: ApportionmentInput> AdjustedInput<'a, T> {
+    fn new(input: &'a T, list_index: usize, votes: u32) -> Self {
+        let list_votes = input
+            .list_votes()
+            .iter()
+            .enumerate()
+            .map(|(index, list)| AdjustedListVotes {
+                list,
+                total_votes: if index == list_index {
+                    votes
+                } else {
+                    list.total_votes()
+                },
+            })
+            .collect();
+        Self { input, list_votes }
+    }
+}
+
+impl<'a, T: ApportionmentInput> ApportionmentInput for AdjustedInput<'a, T> {
+    type List = AdjustedListVotes<'a, T::List>;
+    type ListDrawn = T::ListDrawn;
+    type CandidateDrawn = T::CandidateDrawn;
+
+    fn number_of_seats(&self) -> u32 {
+        self.input.number_of_seats()
+    }
+
+    fn list_votes(&self) -> &[Self::List] {
+        &self.list_votes
+    }
+
+    fn excluded_candidates(&self) -> &ExcludedCandidates<Self::List> {
+        self.input.excluded_candidates()
+    }
+
+    fn lists_drawn(&self) -> impl Iterator<Item = &Self::ListDrawn> {
+        std::iter::empty()
+    }
+
+    fn candidates_drawn(&self) -> impl Iterator<Item = &Self::CandidateDrawn> {
+        std::iter::empty()
+    }
+}
+
+/// Seat assignments with a different number of votes for one list. These are kept, because
+/// the same numbers of votes are tried for the margins of different lists.
+struct AdjustedSeats<'a, T: ApportionmentInput> {
+    input: &'a T,
+    seats: HashMap<(usize, u32), Option<SeatsPerList<T>>>,
+}
+
+impl<'a, T: ApportionmentInput> AdjustedSeats<'a, T> {
+    fn new(input: &'a T) -> Self {
+        Self {
+            input,
+            seats: HashMap::new(),
+        }
+    }
+
+    /// Redo the seat assignment with a different number of votes for one list.
+    /// Returns the total number of seats per list, or `None` if drawing lots is required.
+    fn with_votes(
+        &mut self,
+        list_index: usize,
+        votes: u32,
+    ) -> Result<Option<&SeatsPerList<T>>, ApportionmentError> {
+        if !self.seats.contains_key(&(list_index, votes)) {
+            let seats = match seat_assignment(&AdjustedInput::new(self.input, list_index, votes))? {
+                SeatAssignment::Completed(details) => Some(
+                    get_total_seats_per_list_number_from_seat_assignments(&details.standings),
+                ),
+                SeatAssignment::DrawingLotsRequired(..) => None,
+            };
+            self.seats.insert((list_index, votes), seats);
+        }
+        Ok(self.seats[&(list_index, votes)].as_ref())
+    }
+}
+
+/// Number of votes on either side of a threshold that are tried. The seat assignment changes
+/// at or right after a threshold, the window also covers ties that require drawing lots.
+const THRESHOLD_WINDOW: u64 = 1;
+
+/// Votes and seats of a list in the seat assignment
+#[derive(Clone, Copy, Debug)]
+struct ListSeats {
+    votes: u64,
+    full_seats: u64,
+    total_seats: u64,
+}
+
+impl ListSeats {
+    fn from_standings<LN: Copy + Eq>(
+        list_votes: &[impl ListVotes<ListNumber = LN>],
+        standings: &[ListSeatAssignment<LN>],
+    ) -> Result<Vec<Self>, ApportionmentError> {
+        list_votes
+            .iter()
+            .map(|list| {
+                let standing = standings
+                    .iter()
+                    .find(|standing| standing.list_number == list.number())
+                    .ok_or_else(|| {
+                        ApportionmentError::InvalidState("No seat assignment for list".to_string())
+                    })?;
+                Ok(Self {
+                    votes: u64::from(list.total_votes()),
+                    full_seats: u64::from(standing.full_seats),
+                    total_seats: u64::from(standing.total_seats),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Numbers of votes of a list around which its seats can change
+#[derive(Default)]
+struct VoteThresholds(BTreeSet<u32>);
+
+impl VoteThresholds {
+    /// Add the numbers of votes around `numerator / denominator`, if that is not negative
+    fn add(&mut self, numerator: i128, denominator: i128) {
+        if denominator <= 0 || numerator < 0 {
+            return;
+        }
+        let votes = u64::try_from(numerator / denominator).unwrap_or(u64::MAX);
+        let window =
+            votes.saturating_sub(THRESHOLD_WINDOW)..=votes.saturating_add(THRESHOLD_WINDOW);
+        self.0
+            .extend(window.filter_map(|votes| u32::try_from(votes).ok()));
+    }
+}
+
+/// Collect the numbers of votes of the list at `list_index` around which its seats can change,
+/// while the votes of all other lists remain the same.
+///
+/// With `x` votes for the list and `R` votes for all other lists, these are the numbers of
+/// votes at which:
+/// - the average of the list equals the average of another list (highest averages)
+/// - the list or another list has an absolute majority of the votes (Artikel P 9 Kieswet)
+///
+/// For councils with fewer than 19 seats, also the numbers of votes at which:
+/// - the number of full seats of any list changes, because the quota `(x + R) / S` changes
+/// - the remainder of any list equals the remainder of another list (largest remainders)
+/// - the list or another list meets 3/4 of the quota (Artikel P 7 Kieswet)
+///
+/// List exhaustion (Artikel P 10 Kieswet) only depends on the seats, so it changes the seat
+/// assignment at the same thresholds.
+fn vote_thresholds(lists: &[ListSeats], number_of_seats: u32, list_index: usize) -> BTreeSet<u32> {
+    let mut thresholds = VoteThresholds::default();
+    let mut add = |numerator, denominator| thresholds.add(numerator, denominator);
+
+    let large_council = number_of_seats >= LARGE_COUNCIL_THRESHOLD;
+    let seats = i128::from(number_of_seats);
+    let all_seats = 0..=u64::from(number_of_seats);
+    let others = lists
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != list_index)
+        .map(|(_, other)| *other);
+    let rest = others
+        .clone()
+        .map(|other| i128::from(other.votes))
+        .sum::<i128>();
+    let total_votes = rest + i128::from(lists[list_index].votes);
+
+    // Lots can be required between lists with the same number of votes for a range of votes
+    // of the list, after which its seats can change by more than one seat at once
+    let mut votes: Vec<_> = lists.iter().map(|list| list.votes).collect();
+    votes.sort_unstable();
+    let lists_tied = votes.windows(2).any(|pair| pair[0] == pair[1]);
+
+    add(0, 1);
+    // The list has an absolute majority of the votes: x = R
+    add(rest, 1);
+    if !large_council {
+        // The list gets `k` full seats: x * S / (x + R) = k
+        for k in 1..seats {
+            add(k * rest, seats - k);
+        }
+        // The list meets 3/4 of the quota: x = 3 / 4 * (x + R) / S
+        add(3 * rest, 4 * seats - 3);
+    }
+
+    for other in others.clone() {
+        let other_votes = i128::from(other.votes);
+        // The other list has an absolute majority of the votes: v = x + R - v
+        add(2 * other_votes - rest, 1);
+
+        // The other list has between its full seats and its total seats while the residual
+        // seats are assigned. For smaller councils, or if the other list ran out of
+        // candidates and had more seats before, its seats can change as well.
+        let exhausted = other_votes * seats > i128::from(other.total_seats + 1) * total_votes;
+        let other_seats = if large_council && !exhausted {
+            other.full_seats.saturating_sub(1)..=other.total_seats + 1
+        } else {
+            all_seats.clone()
+        };
+        // The seats of the list change one by one as its votes change, unless lots are
+        // required in between or a seat of the other list is worth less than a vote
+        let list_seats = if lists_tied || other.votes <= other.total_seats + 1 {
+            all_seats.clone()
+        } else {
+            0..=lists[list_index].total_seats + 1
+        };
+        for list_seats in list_seats {
+            for other_seats in other_seats.clone() {
+                // Equal averages: x / (a + 1) = v / (b + 1)
+                add(
+                    other_votes * i128::from(list_seats + 1),
+                    i128::from(other_seats + 1),
+                );
+            }
+        }
+        if !large_council {
+            add_largest_remainder_thresholds(&mut add, seats, rest, other_votes, others.clone());
+        }
+    }
+
+    thresholds.0
+}
+
+/// Add the numbers of votes of the list at which the full seats or the remainder of the other
+/// list with `other_votes` votes change compared to the list and the remaining other lists.
+/// See [vote_thresholds] for the notation.
+fn add_largest_remainder_thresholds(
+    add: &mut impl FnMut(i128, i128),
+    seats: i128,
+    rest: i128,
+    other_votes: i128,
+    others: impl Iterator<Item = ListSeats>,
+) {
+    // The other list gets `k` full seats: v * S / (x + R) = k
+    for k in 1..=seats {
+        add(other_votes * seats - k * rest, k);
+    }
+    // The other list meets 3/4 of the quota: v = 3 / 4 * (x + R) / S
+    add(4 * seats * other_votes - 3 * rest, 3);
+    for a in 0..=seats {
+        for b in 0..=seats {
+            // Equal remainders: x - a * (x + R) / S = v - b * (x + R) / S
+            add(other_votes * seats + (a - b) * rest, seats - a + b);
+        }
+    }
+    for second in others {
+        // Equal remainders of two other lists, with `d` more full seats for the first:
+        // v - (b + d) * (x + R) / S = w - b * (x + R) / S
+        let difference = other_votes - i128::from(second.votes);
+        for d in 1..=seats {
+            add(difference * seats - d * rest, d);
+        }
+    }
+}
+
+/// Find the first number of votes of `votes` for which `is_changed` holds
+fn first_change(
+    votes: impl IntoIterator<Item = u32>,
+    mut is_changed: impl FnMut(u32) -> Result<bool, ApportionmentError>,
+) -> Result<Option<u32>, ApportionmentError> {
+    for votes in votes {
+        if is_changed(votes)? {
+            return Ok(Some(votes));
+        }
+    }
+    Ok(None)
+}
+
+/// Find the other list that needs to lose the fewest votes for `gains_seat` to hold
+fn other_list_votes_to_gain_seat<T: ApportionmentInput>(
+    adjusted: &mut AdjustedSeats<'_, T>,
+    thresholds: &[BTreeSet<u32>],
+    list_index: usize,
+    gains_seat: impl Fn(Option<&SeatsPerList<T>>) -> bool,
+) -> Result<Option<OtherListMargin<ListNumber<T::List>>>, ApportionmentError> {
+    let mut other_list_votes_to_gain_seat: Option<OtherListMargin<_>> = None;
+    for (other_index, other_list) in adjusted.input.list_votes().iter().enumerate() {
+        if other_index == list_index {
+            continue;
+        }
+        // Only fewer votes than the smallest margin so far can improve on it
+        let other_votes = other_list.total_votes();
+        let max_loss = other_list_votes_to_gain_seat
+            .map_or(other_votes, |margin| other_votes.min(margin.votes - 1));
+        let losses = thresholds[other_index]
+            .range(other_votes - max_loss..other_votes)
+            .rev()
+            .map(|votes| other_votes - votes);
+        if let Some(loss) = first_change(losses, |loss| {
+            Ok(gains_seat(
+                adjusted.with_votes(other_index, other_votes - loss)?,
+            ))
+        })? {
+            other_list_votes_to_gain_seat = Some(OtherListMargin {
+                list_number: other_list.number(),
+                votes: loss,
+            });
+        }
+    }
+    Ok(other_list_votes_to_gain_seat)
+}
+
+/// Determine the seat margins for the list at `list_index`, trying the numbers of votes in
+/// `thresholds` for each list
+fn list_seat_margin<T: ApportionmentInput>(
+    adjusted: &mut AdjustedSeats<'_, T>,
+    seats: &SeatsPerList<T>,
+    thresholds: &[BTreeSet<u32>],
+    list_index: usize,
+) -> Result<ListSeatMargin<ListNumber<T::List>>, ApportionmentError> {
+    let input = adjusted.input;
+    let list_votes = input.list_votes();
+    let list_number = list_votes[list_index].number();
+    let votes = list_votes[list_index].total_votes();
+    let total_seats = seats.get(&list_number).copied().ok_or_else(|| {
+        ApportionmentError::InvalidState(format!("No seat assignment for list {list_number:?}"))
+    })?;
+    let gains_seat = |seats: Option<&HashMap<_, u32>>| {
+        seats.is_some_and(|seats| seats[&list_number] > total_seats)
+    };
+
+    // A list that has all seats, or a seat for each of its candidates, cannot gain a seat
+    let number_of_candidates =
+        filter_out_excluded_candidates(&list_votes[list_index], input.excluded_candidates()).len();
+    let can_gain_seat = total_seats < input.number_of_seats()
+        && usize::try_from(total_seats).is_ok_and(|seats| seats < number_of_candidates);
+
+    // With more votes than all other lists combined times twice the number of seats,
+    // the list gets all seats it has candidates for
+    let total_votes: u32 = list_votes.iter().map(ListVotes::total_votes).sum();
+    let max_gain = (u64::from(total_votes - votes) * (2 * u64::from(input.number_of_seats()) + 1))
+        .min(u64::from(u32::MAX - total_votes));
+    let max_gain = u32::try_from(max_gain).expect("max_gain fits in u32");
+    let votes_to_gain_seat = if can_gain_seat {
+        let gains = thresholds[list_index]
+            .range(votes + 1..)
+            .map(|gained_votes| gained_votes - votes)
+            .take_while(|&gain| gain < max_gain)
+            .chain([max_gain]);
+        first_change(gains, |gain| {
+            Ok(gains_seat(adjusted.with_votes(list_index, votes + gain)?))
+        })?
+    } else {
+        None
+    };
+
+    // A list without seats cannot lose a seat
+    let votes_to_lose_seat = if total_seats > 0 {
+        let losses = thresholds[list_index]
+            .range(..votes)
+            .rev()
+            .map(|remaining_votes| votes - remaining_votes);
+        first_change(losses, |loss| {
+            Ok(adjusted
+                .with_votes(list_index, votes - loss)?
+                .is_some_and(|seats| seats[&list_number] < total_seats))
+        })?
+    } else {
+        None
+    };
+
+    let other_list_votes_to_gain_seat = if can_gain_seat {
+        other_list_votes_to_gain_seat(adjusted, thresholds, list_index, gains_seat)?
+    } else {
+        None
+    };
+
+    Ok(ListSeatMargin {
+        list_number,
+        total_seats,
+        votes_to_gain_seat,
+        other_list_votes_to_gain_seat,
+        votes_to_lose_seat,
+    })
+}
+
+/// Determine the preference threshold margins for the candidates of a list.
+///
+/// A candidate with `v` votes is above the preference threshold if `v > p * T / (100 * S)`,
+/// with percentage `p`, total votes `T` and number of seats `S`. Votes gained or lost by the
+/// candidate change `T` as well, so the margin `x` is the smallest whole number for which
+/// `100 * S * (v + x) > p * (T + x)` holds when gaining, or for which
+/// `100 * S * (v - x) <= p * (T - x)` holds when losing.
+fn list_preference_margins<L: ListVotes>(
+    list: &L,
+    excluded_candidates: &ExcludedCandidates<L>,
+    number_of_seats: u32,
+    total_votes: u32,
+    percentage: u64,
+    preference_threshold: Fraction,
+) -> ListPreferenceMargins<ListNumber<L>, CandidateNumber<L>> {
+    let seats_factor = 100 * u64::from(number_of_seats);
+    let threshold_votes = percentage * u64::from(total_votes);
+    let divisor = seats_factor - percentage;
+
+    let mut candidates: Vec<_> = filter_out_excluded_candidates(list, excluded_candidates)
+        .into_iter()
+        .map(|candidate| {
+            let candidate_votes = seats_factor * u64::from(candidate.votes());
+            let meets_preference_threshold =
+                Fraction::from(candidate.votes()) > preference_threshold;
+            let margin = if meets_preference_threshold {
+                (candidate_votes - threshold_votes).div_ceil(divisor)
+            } else {
+                (threshold_votes - candidate_votes) / divisor + 1
+            };
+            CandidatePreferenceMargin {
+                candidate_number: candidate.number(),
+                votes: candidate.votes(),
+                meets_preference_threshold,
+                margin: u32::try_from(margin).expect("margin fits in u32"),
+            }
+        })
+        .collect();
+
+    // Keep the candidates above the threshold and the first candidate below it
+    candidates.sort_by_key(|candidate| Reverse(candidate.votes));
+    let below_threshold = candidates
+        .iter()
+        .position(|candidate| !candidate.meets_preference_threshold);
+    if let Some(index) = below_threshold {
+        candidates.truncate(index + 1);
+    }
+
+    ListPreferenceMargins {
+        list_number: list.number(),
+        candidates,
+    }
+}
+
+/// Determine how close the apportionment result was to a different outcome.
+///
+/// For every list this is the smallest number of votes the list needs to gain to get an
+/// additional seat, the smallest number of votes another list needs to lose for that and
+/// the smallest number of votes the list needs to lose to lose a seat. Only the numbers of
+/// votes around the thresholds at which the seats can change are tried, see
+/// `vote_thresholds`. The seat assignment is redone for each of them, so all rules of the
+/// seat assignment are taken into account.
+///
+/// For the lists that were assigned seats, the margins to the preference threshold are
+/// determined for the candidates that were above the threshold and the candidate that was
+/// closest to it.
+pub fn seat_margins<T: ApportionmentInput>(
+    input: &T,
+    apportionment: &ApportionmentDetails<'_, T::List>,
+) -> SeatMarginResult<T> {
+    info!("Seat margins");
+
+    let seats = get_total_seats_per_list_number_from_seat_assignments(
+        &apportionment.seat_assignment.standings,
+    );
+
+    let lists =
+        ListSeats::from_standings(input.list_votes(), &apportionment.seat_assignment.standings)?;
+    let thresholds: Vec<_> = (0..lists.len())
+        .map(|list_index| vote_thresholds(&lists, input.number_of_seats(), list_index))
+        .collect();
+
+    // The seat assignment is redone many times, do not log every attempt
+    let mut adjusted = AdjustedSeats::new(input);
+    let list_seat_margins = tracing::subscriber::with_default(NoSubscriber::default(), || {
+        (0..input.list_votes().len())
+            .map(|list_index| list_seat_margin(&mut adjusted, &seats, &thresholds, list_index))
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    let total_votes = input.list_votes().iter().map(ListVotes::total_votes).sum();
+    let preference_threshold = &apportionment.candidate_nomination.preference_threshold;
+    let list_preference_margins = input
+        .list_votes()
+        .iter()
+        .filter(|list| seats.get(&list.number()).is_some_and(|&seats| seats > 0))
+        .map(|list| {
+            list_preference_margins(
+                list,
+                input.excluded_candidates(),
+                input.number_of_seats(),
+                total_votes,
+                preference_threshold.percentage,
+                preference_threshold.number_of_votes,
+            )
+        })
+        .collect();
+
+    Ok(SeatMarginDetails {
+        list_seat_margins,
+        list_preference_margins,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        time::{Duration, Instant},
+    };
+
+    use rand::{RngExt, SeedableRng, rngs::StdRng};
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        ApportionmentOutput, process,
+        test_helpers::{
+            ApportionmentInputMock, ListVotesMock,
+            seat_assignment_fixture_with_default_50_candidates,
+            seat_assignment_fixture_with_given_candidate_votes,
+        },
+    };
+
+    fn margins_for(input: &ApportionmentInputMock) -> SeatMarginDetails<u32, u32> {
+        let Ok(ApportionmentOutput::Completed(result)) = process(input) else {
+            panic!("should be Completed")
+        };
+        seat_margins(input, &result).expect("should determine seat margins")
+    }
+
+    /// 5 seats, quota = 480 / 5 = 96, initial distribution [3, 1, 1]
+    /// List 3 gets the residual seat with remainder 80, which meets the threshold of 72.
+    fn small_council_input() -> ApportionmentInputMock {
+        seat_assignment_fixture_with_default_50_candidates(5, vec![300, 100, 80])
+    }
+
+    #[test]
+    fn test_votes_to_lose_seat() {
+        let margins = margins_for(&small_council_input());
+        let list_3 = &margins.list_seat_margins[2];
+        assert_eq!(list_3.total_seats, 1);
+        // With 70 votes the list no longer meets 3/4 of the quota of 470 / 5 = 94
+        assert_eq!(list_3.votes_to_lose_seat, Some(10));
+    }
+
+    #[test]
+    fn test_votes_to_gain_seat() {
+        let margins = margins_for(&small_council_input());
+        let list_1 = &margins.list_seat_margins[0];
+        assert_eq!(list_1.total_seats, 3);
+        // With 354 votes the quota is 534 / 5 = 106.8 and list 3 no longer meets 3/4 of it,
+        // so the residual seats go to list 2 and list 1
+        assert_eq!(list_1.votes_to_gain_seat, Some(54));
+    }
+
+    /// 16 seats, list 1 has an absolute majority of the votes but not of the seats, so it gets
+    /// an additional seat (Artikel P 9 Kieswet). With 17 votes that seat is taken from list 2,
+    /// with 20 votes it is taken from list 3. With 25 votes lots need to be drawn, so the seats
+    /// of list 2 change back and forth as its votes increase.
+    #[test]
+    fn test_votes_to_gain_seat_absolute_majority() {
+        let input = seat_assignment_fixture_with_default_50_candidates(16, vec![40, 17, 19]);
+        let margins = margins_for(&input);
+        let list_2 = &margins.list_seat_margins[1];
+        assert_eq!(list_2.total_seats, 3);
+        assert_eq!(list_2.votes_to_gain_seat, Some(3));
+    }
+
+    #[test]
+    fn test_no_margin_to_gain_seat_without_candidates() {
+        let input = seat_assignment_fixture_with_given_candidate_votes(
+            5,
+            vec![vec![300], vec![100, 0], vec![80, 0]],
+        );
+        let margins = margins_for(&input);
+        let list_1 = &margins.list_seat_margins[0];
+        assert_eq!(list_1.total_seats, 1);
+        assert_eq!(list_1.votes_to_gain_seat, None);
+        assert_eq!(list_1.other_list_votes_to_gain_seat, None);
+    }
+
+    #[test]
+    fn test_other_list_votes_to_gain_seat() {
+        let margins = margins_for(&small_council_input());
+        // List 3 loses its seat at 10 votes less, list 1 then gets the residual seat
+        assert_eq!(
+            margins.list_seat_margins[0].other_list_votes_to_gain_seat,
+            Some(OtherListMargin {
+                list_number: 3,
+                votes: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_margin_when_seats_cannot_change() {
+        let input = seat_assignment_fixture_with_default_50_candidates(5, vec![480, 0]);
+        let margins = margins_for(&input);
+        assert_eq!(
+            margins.list_seat_margins[0],
+            ListSeatMargin {
+                list_number: 1,
+                total_seats: 5,
+                votes_to_gain_seat: None,
+                other_list_votes_to_gain_seat: None,
+                votes_to_lose_seat: Some(480),
+            }
+        );
+        assert_eq!(margins.list_seat_margins[1].votes_to_lose_seat, None);
+    }
+
+    #[test]
+    fn test_preference_margins() {
+        let margins = margins_for(&small_council_input());
+        // Only lists with seats are included
+        assert_eq!(margins.list_preference_margins.len(), 3);
+        // Preference threshold is 50% of 96 = 48
+        assert_eq!(
+            margins.list_preference_margins[1],
+            ListPreferenceMargins {
+                list_number: 2,
+                candidates: vec![
+                    // With 42 votes the threshold is 42.2
+                    CandidatePreferenceMargin {
+                        candidate_number: 1,
+                        votes: 100,
+                        meets_preference_threshold: true,
+                        margin: 58,
+                    },
+                    // With 54 votes the threshold is 53.4
+                    CandidatePreferenceMargin {
+                        candidate_number: 2,
+                        votes: 0,
+                        meets_preference_threshold: false,
+                        margin: 54,
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_preference_margins_skip_excluded_candidates() {
+        let mut input = small_council_input();
+        input.excluded_candidates = HashMap::from([(2, HashSet::from([1]))]);
+        let margins = margins_for(&input);
+        assert_eq!(
+            margins.list_preference_margins[1].candidates,
+            vec![CandidatePreferenceMargin {
+                candidate_number: 2,
+                votes: 0,
+                meets_preference_threshold: false,
+                margin: 54,
+            }]
+        );
+    }
+
+    /// Determine the seat margins of all lists, trying the numbers of votes returned by
+    /// `thresholds` for each list. Returns None if drawing lots is required.
+    fn list_seat_margins_with(
+        input: &ApportionmentInputMock,
+        thresholds: impl Fn(&[ListSeats], usize) -> BTreeSet<u32>,
+    ) -> Option<Vec<ListSeatMargin<u32>>> {
+        let SeatAssignment::Completed(details) =
+            seat_assignment(input).expect("seat assignment should not fail")
+        else {
+            return None;
+        };
+        let seats = get_total_seats_per_list_number_from_seat_assignments(&details.standings);
+        let lists = ListSeats::from_standings(&input.list_votes, &details.standings)
+            .expect("should find the standings");
+        let thresholds: Vec<_> = (0..lists.len())
+            .map(|list_index| thresholds(&lists, list_index))
+            .collect();
+        let mut adjusted = AdjustedSeats::new(input);
+        let margins = tracing::subscriber::with_default(NoSubscriber::default(), || {
+            (0..lists.len())
+                .map(|list_index| list_seat_margin(&mut adjusted, &seats, &thresholds, list_index))
+                .collect::<Result<Vec<_>, _>>()
+        });
+        Some(margins.expect("should determine seat margins"))
+    }
+
+    /// Generate an input with a few lists with few votes, where lists sometimes have fewer
+    /// candidates than seats and sometimes have the same votes as an earlier list
+    fn generate_small_input(rng: &mut StdRng) -> ApportionmentInputMock {
+        let number_of_seats = rng.random_range(3..=25);
+        let mut list_votes: Vec<ListVotesMock> = vec![];
+        for number in 1..=rng.random_range(2..=5) {
+            let votes = match list_votes.last() {
+                Some(list) if rng.random_range(0..4) == 0 => list.total_votes(),
+                _ => rng.random_range(0..=100),
+            };
+            let number_of_candidates = rng.random_range(1..=number_of_seats);
+            let mut candidate_votes = vec![0; number_of_candidates as usize];
+            candidate_votes[0] = votes;
+            list_votes.push(ListVotesMock::from_test_data_auto(number, candidate_votes));
+        }
+        ApportionmentInputMock {
+            number_of_seats,
+            list_votes,
+            excluded_candidates: HashMap::new(),
+            lists_drawn: vec![],
+            candidates_drawn: vec![],
+        }
+    }
+
+    /// Trying only the numbers of votes around the thresholds gives the same margins as trying
+    /// every number of votes
+    #[test]
+    fn test_thresholds_give_same_margins_as_every_number_of_votes() {
+        for seed in 0..100 {
+            let input = generate_small_input(&mut StdRng::seed_from_u64(seed));
+            let number_of_seats = input.number_of_seats;
+            let Some(margins) = list_seat_margins_with(&input, |lists, list_index| {
+                vote_thresholds(lists, number_of_seats, list_index)
+            }) else {
+                continue;
+            };
+
+            // Beyond twice the total number of votes only the thresholds are tried
+            let max_votes = 2 * input
+                .list_votes
+                .iter()
+                .map(ListVotes::total_votes)
+                .sum::<u32>();
+            let every_number_of_votes = list_seat_margins_with(&input, |lists, list_index| {
+                let mut votes = vote_thresholds(lists, number_of_seats, list_index);
+                votes.extend(0..=max_votes);
+                votes
+            });
+            assert_eq!(
+                Some(margins),
+                every_number_of_votes,
+                "seed {seed}\n{input:?}"
+            );
+        }
+    }
+
+    /// 37 seats and 15 lists with 211.180 votes in total
+    #[test]
+    fn test_seat_margins_for_large_council_are_fast() {
+        let input = seat_assignment_fixture_with_default_50_candidates(
+            37,
+            vec![
+                48_210, 36_904, 27_513, 21_877, 17_402, 14_968, 11_035, 9_241, 7_716, 5_530, 4_108,
+                2_950, 1_877, 1_203, 646,
+            ],
+        );
+        let start = Instant::now();
+        let margins = margins_for(&input);
+        let elapsed = start.elapsed();
+
+        assert_eq!(margins.list_seat_margins.len(), 15);
+        assert!(
+            margins
+                .list_seat_margins
+                .iter()
+                .all(|list| list.votes_to_gain_seat.is_some()),
+        );
+        assert!(
+            elapsed < Duration::from_secs(5),
+            "determining the seat margins took {elapsed:?}"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+/// How close a list was to gaining or losing a seat in the seat assignment.
+///
+/// All margins are the smallest number of votes with which the number of seats of the list
+/// changes without drawing lots, while all other votes remain the same.
+/// A margin is `None` if no number of votes changes the number of seats of the list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListSeatMargin<LN> {
+    /// List number for which this margin applies
+    pub list_number: LN,
+    /// The total number of seats assigned to this list
+    pub total_seats: u32,
+    /// The number of votes the list needs to gain to get an additional seat
+    pub votes_to_gain_seat: Option<u32>,
+    /// The other list that needs to lose the fewest votes for this list to get an
+    /// additional seat, with that number of votes
+    pub other_list_votes_to_gain_seat: Option<OtherListMargin<LN>>,
+    /// The number of votes the list needs to lose to lose one of its seats
+    pub votes_to_lose_seat: Option<u32>,
+}
+
+/// Number of votes another list needs to lose
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OtherListMargin<LN> {
+    pub list_number: LN,
+    pub votes: u32,
+}
+
+/// How close a candidate was to the preference threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandidatePreferenceMargin<CN> {
+    pub candidate_number: CN,
+    /// The number of votes cast for this candidate
+    pub votes: u32,
+    /// Whether the votes of the candidate are above the preference threshold
+    pub meets_preference_threshold: bool,
+    /// The number of votes the candidate needs to gain to get above the preference threshold,
+    /// or to lose to no longer be above it. The preference threshold changes along with
+    /// the total number of votes.
+    pub margin: u32,
+}
+
+/// Preference threshold margins of the candidates of a list that was assigned seats.
+///
+/// Contains all candidates above the preference threshold, followed by the candidate
+/// below the threshold with the most votes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListPreferenceMargins<LN, CN> {
+    pub list_number: LN,
+    pub candidates: Vec<CandidatePreferenceMargin<CN>>,
+}
+
+/// The result of the seat margin analysis of a completed apportionment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeatMarginDetails<LN, CN> {
+    pub list_seat_margins: Vec<ListSeatMargin<LN>>,
+    pub list_preference_margins: Vec<ListPreferenceMargins<LN, CN>>,
+}
@@ -5,7 +5,8 @@ use std::{
 };
 
 use super::{
-    SeatChange, candidate_nomination::CandidateNominationDetails, fraction::Fraction,
+    SeatChange, candidate_nomination::CandidateNominationDetails,
+    district_distribution::DistrictDistributionDetails, fraction::Fraction,
     seat_assignment::SeatAssignmentDetails,
 };
 
@@ -26,6 +27,7 @@ pub enum ApportionmentError {
 
 /// Different variants of drawing lots for lists, with all the information needed to do the drawing
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ListDrawingLotsVariant<LN> {
     /// Draw lots for assigning a highest average residual seat
     HighestAverageResidualSeat(HighestAverageResidualSeatDrawingLots<LN>),
@@ -95,6 +97,7 @@ impl<LN> ListDrawingLotsVariant<LN> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HighestAverageResidualSeatDrawingLots<LN> {
     pub max_average: Fraction,
     pub residual_seat_numbers: Vec<u32>,
@@ -103,6 +106,7 @@ pub struct HighestAverageResidualSeatDrawingLots<LN> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LargestRemainderResidualSeatDrawingLots<LN> {
     pub max_remainder: Fraction,
     pub residual_seat_numbers: Vec<u32>,
@@ -111,6 +115,7 @@ pub struct LargestRemainderResidualSeatDrawingLots<LN> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbsoluteMajorityDrawingLots<LN> {
     /// The list where the reassigned residual seat will go to
     pub assign_to: LN,
@@ -128,6 +133,7 @@ pub trait ListDrawn<LN> {
 
 /// Variant of drawing lots for candidates, with all the information needed to do the drawing
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CandidateDrawingLotsVariant<LN: PartialEq, CN: PartialEq> {
     pub list: LN,
     pub total_seats: u32,
@@ -169,9 +175,58 @@ pub trait CandidateDrawn<LN: PartialEq, CN: PartialEq> {
     fn drawn(&self) -> &CN;
 }
 
-/// [HashMap] of a list number to a [HashSet] of candidate numbers that are deceased,
+/// Variant of drawing lots for distributing the seats of a list over its district lists,
+/// with all the information needed to do the drawing
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistrictListDrawingLotsVariant<LN, DLN> {
+    /// The list of which the seats are distributed over its district lists
+    pub list: LN,
+    pub max_remainder: Fraction,
+    pub residual_seat_numbers: Vec<u32>,
+    pub options: Vec<DLN>,
+    pub list_remainders: Vec<(DLN, Fraction)>,
+}
+
+impl<LN: PartialEq, DLN: PartialEq> DistrictListDrawingLotsVariant<LN, DLN> {
+    /// Validate the district list drawn against this variant.
+    ///
+    /// Return [[ApportionmentError::InvalidLotDrawing]] if the variant is different
+    /// or the district list drawn is not one of the options.
+    pub fn validate(
+        &self,
+        district_list_drawn: &impl DistrictListDrawn<LN, DLN>,
+    ) -> Result<(), ApportionmentError> {
+        if district_list_drawn.variant() != *self {
+            return Err(ApportionmentError::InvalidLotDrawing(
+                "Variant mismatch".to_string(),
+            ));
+        }
+
+        if !self.options.contains(district_list_drawn.drawn()) {
+            return Err(ApportionmentError::InvalidLotDrawing(
+                "Invalid number drawn".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// The district list that has been drawn and the variant with all information about the drawing
+pub trait DistrictListDrawn<LN, DLN> {
+    /// The type and information for drawing lots
+    fn variant(&self) -> DistrictListDrawingLotsVariant<LN, DLN>;
+    /// The district list that the lot was drawn for
+    fn drawn(&self) -> &DLN;
+}
+
+/// [HashMap] of a list number to a [HashSet] of candidate numbers that are excluded,
 /// to enforce that they are unique and easily retrievable.
-pub type DeceasedCandidates<LV> = HashMap<ListNumber<LV>, HashSet<CandidateNumber<LV>>>;
+///
+/// Candidates are excluded if they are deceased, declined or are ineligible. Their votes
+/// still count for the list, but they are skipped in list exhaustion and candidate nomination.
+pub type ExcludedCandidates<LV> = HashMap<ListNumber<LV>, HashSet<CandidateNumber<LV>>>;
 
 pub trait ApportionmentInput {
     type List: ListVotes;
@@ -180,17 +235,58 @@ pub trait ApportionmentInput {
 
     fn number_of_seats(&self) -> u32;
     fn list_votes(&self) -> &[Self::List];
-    fn deceased_candidates(&self) -> &DeceasedCandidates<Self::List>;
+    fn excluded_candidates(&self) -> &ExcludedCandidates<Self::List>;
     fn lists_drawn(&self) -> impl Iterator<Item = &Self::ListDrawn>;
     fn candidates_drawn(&self) -> impl Iterator<Item = &Self::CandidateDrawn>;
 }
 
+/// Apportionment input for elections with multiple districts (kieskringen), where the
+/// seats assigned to a list are distributed over the lists with the same designation
+/// (lijsten met gelijke aanduiding) that were submitted in the districts.
+///
+/// The list votes of [ApportionmentInput] contain the combined votes of all district lists
+/// of a list, the district list votes contain the votes per district list.
+pub trait DistrictApportionmentInput: ApportionmentInput {
+    type DistrictList: DistrictListVotes<ListNumber<Self::List>>;
+    type DistrictListDrawn: DistrictListDrawn<ListNumber<Self::List>, ListNumber<Self::DistrictList>>;
+    type DistrictCandidateDrawn: CandidateDrawn<ListNumber<Self::DistrictList>, CandidateNumber<Self::DistrictList>>;
+
+    fn district_list_votes(&self) -> &[Self::DistrictList];
+    fn district_excluded_candidates(&self) -> &ExcludedCandidates<Self::DistrictList>;
+    fn district_lists_drawn(&self) -> impl Iterator<Item = &Self::DistrictListDrawn>;
+    fn district_candidates_drawn(&self) -> impl Iterator<Item = &Self::DistrictCandidateDrawn>;
+}
+
+/// Votes for a list that was submitted in one or more districts.
+///
+/// A list that was submitted in more than one district with the same candidates
+/// (stel gelijkluidende lijsten) is a single district list with multiple districts.
+pub trait DistrictListVotes<LN>: ListVotes {
+    type District: Copy + Debug + Eq + Hash;
+
+    /// The number of the list this district list belongs to
+    fn list_number(&self) -> LN;
+    /// The districts this list was submitted in
+    fn districts(&self) -> &[Self::District];
+    /// Whether this list was submitted in more than one district
+    fn is_combined(&self) -> bool {
+        self.districts().len() > 1
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ApportionmentDetails<'a, T: ListVotes> {
     pub seat_assignment: SeatAssignmentDetails<ListNumber<T>>,
     pub candidate_nomination: CandidateNominationDetails<'a, T>,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct DistrictApportionmentDetails<'a, T: ListVotes, D: ListVotes> {
+    pub seat_assignment: SeatAssignmentDetails<ListNumber<T>>,
+    pub district_distribution: DistrictDistributionDetails<ListNumber<T>, ListNumber<D>>,
+    pub candidate_nomination: CandidateNominationDetails<'a, D>,
+}
+
 pub trait ListVotes: PartialEq + Debug {
     type Cv: CandidateVotes;
     type ListNumber: Copy + Debug + Eq + PartialOrd + Hash;
@@ -216,7 +312,7 @@ pub trait CandidateVotes: PartialEq + Debug {
 pub(crate) struct CandidateNominationInput<'a, L: ListVotes> {
     pub number_of_seats: u32,
     pub list_votes: &'a [L],
-    pub deceased_candidates: &'a DeceasedCandidates<L>,
+    pub excluded_candidates: &'a ExcludedCandidates<L>,
     pub quota: Fraction,
     pub total_seats_per_list: HashMap<L::ListNumber, u32>,
 }
@@ -228,7 +324,7 @@ mod tests {
     use super::*;
     use crate::{
         seat_assignment::{LargestRemainderAssignedSeat, ListExhaustionRemovedSeat},
-        test_helpers::{CandidateDrawnMock, ListDrawnMock},
+        test_helpers::{CandidateDrawnMock, DistrictListDrawnMock, ListDrawnMock},
     };
 
     #[test]
@@ -352,4 +448,48 @@ mod tests {
             Ok(())
         );
     }
+
+    #[test]
+    fn test_district_list_drawing_lots_variant_validate() {
+        let variant = DistrictListDrawingLotsVariant {
+            list: 1,
+            max_remainder: Fraction::new(50, 1),
+            residual_seat_numbers: vec![1],
+            options: vec![11, 12],
+            list_remainders: vec![(11, Fraction::new(50, 1)), (12, Fraction::new(50, 1))],
+        };
+
+        let another_variant = DistrictListDrawingLotsVariant {
+            list: 2,
+            ..variant.clone()
+        };
+
+        assert_eq!(
+            variant.validate(&DistrictListDrawnMock {
+                variant: another_variant,
+                drawn: 11
+            }),
+            Err(ApportionmentError::InvalidLotDrawing(
+                "Variant mismatch".to_string()
+            ))
+        );
+
+        assert_eq!(
+            variant.validate(&DistrictListDrawnMock {
+                variant: variant.clone(),
+                drawn: 13
+            }),
+            Err(ApportionmentError::InvalidLotDrawing(
+                "Invalid number drawn".to_string()
+            ))
+        );
+
+        assert_eq!(
+            variant.validate(&DistrictListDrawnMock {
+                variant: variant.clone(),
+                drawn: 12
+            }),
+            Ok(())
+        );
+    }
 }
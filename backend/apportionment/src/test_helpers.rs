@@ -4,18 +4,22 @@ use super::{
     CandidateDrawingLotsVariant, ListVotes, fraction::Fraction, structs::CandidateNominationInput,
 };
 use crate::{
-    ApportionmentInput, CandidateVotes, SeatAssignmentDetails,
+    ApportionmentInput, CandidateVotes, DistrictApportionmentInput, DistrictListVotes,
+    SeatAssignmentDetails,
     candidate_nomination::{
         Candidate, CandidateRanking, ListCandidateNomination, candidate_votes_numbers,
     },
-    structs::{CandidateDrawn, ListDrawingLotsVariant, ListDrawn},
+    structs::{
+        CandidateDrawn, DistrictListDrawingLotsVariant, DistrictListDrawn, ListDrawingLotsVariant,
+        ListDrawn,
+    },
 };
 
 #[derive(Debug)]
 pub struct ApportionmentInputMock {
     pub number_of_seats: u32,
     pub list_votes: Vec<ListVotesMock>,
-    pub deceased_candidates: HashMap<u32, HashSet<u32>>,
+    pub excluded_candidates: HashMap<u32, HashSet<u32>>,
     pub lists_drawn: Vec<ListDrawnMock>,
     pub candidates_drawn: Vec<CandidateDrawnMock>,
 }
@@ -33,8 +37,8 @@ impl ApportionmentInput for ApportionmentInputMock {
         &self.list_votes
     }
 
-    fn deceased_candidates(&self) -> &HashMap<u32, HashSet<u32>> {
-        &self.deceased_candidates
+    fn excluded_candidates(&self) -> &HashMap<u32, HashSet<u32>> {
+        &self.excluded_candidates
     }
 
     fn lists_drawn(&self) -> impl Iterator<Item = &Self::ListDrawn> {
@@ -134,6 +138,112 @@ impl CandidateDrawn<u32, u32> for CandidateDrawnMock {
     }
 }
 
+#[derive(Debug)]
+pub struct DistrictApportionmentInputMock {
+    pub apportionment_input: ApportionmentInputMock,
+    pub district_list_votes: Vec<DistrictListVotesMock>,
+    pub district_excluded_candidates: HashMap<u32, HashSet<u32>>,
+    pub district_lists_drawn: Vec<DistrictListDrawnMock>,
+    pub district_candidates_drawn: Vec<CandidateDrawnMock>,
+}
+
+impl ApportionmentInput for DistrictApportionmentInputMock {
+    type List = ListVotesMock;
+    type ListDrawn = ListDrawnMock;
+    type CandidateDrawn = CandidateDrawnMock;
+
+    fn number_of_seats(&self) -> u32 {
+        self.apportionment_input.number_of_seats()
+    }
+
+    fn list_votes(&self) -> &[Self::List] {
+        self.apportionment_input.list_votes()
+    }
+
+    fn excluded_candidates(&self) -> &HashMap<u32, HashSet<u32>> {
+        self.apportionment_input.excluded_candidates()
+    }
+
+    fn lists_drawn(&self) -> impl Iterator<Item = &Self::ListDrawn> {
+        self.apportionment_input.lists_drawn()
+    }
+
+    fn candidates_drawn(&self) -> impl Iterator<Item = &Self::CandidateDrawn> {
+        self.apportionment_input.candidates_drawn()
+    }
+}
+
+impl DistrictApportionmentInput for DistrictApportionmentInputMock {
+    type DistrictList = DistrictListVotesMock;
+    type DistrictListDrawn = DistrictListDrawnMock;
+    type DistrictCandidateDrawn = CandidateDrawnMock;
+
+    fn district_list_votes(&self) -> &[Self::DistrictList] {
+        &self.district_list_votes
+    }
+
+    fn district_excluded_candidates(&self) -> &HashMap<u32, HashSet<u32>> {
+        &self.district_excluded_candidates
+    }
+
+    fn district_lists_drawn(&self) -> impl Iterator<Item = &Self::DistrictListDrawn> {
+        self.district_lists_drawn.iter()
+    }
+
+    fn district_candidates_drawn(&self) -> impl Iterator<Item = &Self::DistrictCandidateDrawn> {
+        self.district_candidates_drawn.iter()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DistrictListVotesMock {
+    pub number: u32,
+    pub list_number: u32,
+    pub districts: Vec<u32>,
+    pub candidate_votes: Vec<CandidateVotesMock>,
+}
+
+impl ListVotes for DistrictListVotesMock {
+    type Cv = CandidateVotesMock;
+    type ListNumber = u32;
+
+    fn number(&self) -> Self::ListNumber {
+        self.number
+    }
+
+    fn candidate_votes(&self) -> &[Self::Cv] {
+        &self.candidate_votes
+    }
+}
+
+impl DistrictListVotes<u32> for DistrictListVotesMock {
+    type District = u32;
+
+    fn list_number(&self) -> u32 {
+        self.list_number
+    }
+
+    fn districts(&self) -> &[Self::District] {
+        &self.districts
+    }
+}
+
+#[derive(Debug)]
+pub struct DistrictListDrawnMock {
+    pub variant: DistrictListDrawingLotsVariant<u32, u32>,
+    pub drawn: u32,
+}
+
+impl DistrictListDrawn<u32, u32> for DistrictListDrawnMock {
+    fn variant(&self) -> DistrictListDrawingLotsVariant<u32, u32> {
+        self.variant.clone()
+    }
+
+    fn drawn(&self) -> &u32 {
+        &self.drawn
+    }
+}
+
 pub fn get_total_seats_from_apportionment_result(result: &SeatAssignmentDetails<u32>) -> Vec<u32> {
     result.standings.iter().map(|p| p.total_seats).collect()
 }
@@ -232,7 +342,7 @@ pub fn candidate_nomination_fixture_with_given_number_of_seats(
     CandidateNominationInput {
         number_of_seats: seat_assignment_input.number_of_seats,
         list_votes: &seat_assignment_input.list_votes,
-        deceased_candidates: &seat_assignment_input.deceased_candidates,
+        excluded_candidates: &seat_assignment_input.excluded_candidates,
         quota,
         total_seats_per_list: total_seats_per_list
             .into_iter()
@@ -252,7 +362,7 @@ pub fn candidate_nomination_fixture_with_given_list_numbers_and_number_of_seats(
     CandidateNominationInput {
         number_of_seats: seat_assignment_input.number_of_seats,
         list_votes: &seat_assignment_input.list_votes,
-        deceased_candidates: &seat_assignment_input.deceased_candidates,
+        excluded_candidates: &seat_assignment_input.excluded_candidates,
         quota,
         total_seats_per_list: total_seats_per_list_number,
     }
@@ -279,7 +389,7 @@ pub fn seat_assignment_fixture_with_default_50_candidates(
     ApportionmentInputMock {
         number_of_seats,
         list_votes,
-        deceased_candidates: HashMap::new(),
+        excluded_candidates: HashMap::new(),
         lists_drawn: Vec::new(),
         candidates_drawn: Vec::new(),
     }
@@ -301,7 +411,7 @@ pub fn seat_assignment_fixture_with_given_list_numbers_and_candidate_votes(
     ApportionmentInputMock {
         number_of_seats,
         list_votes,
-        deceased_candidates: HashMap::new(),
+        excluded_candidates: HashMap::new(),
         lists_drawn: Vec::new(),
         candidates_drawn: Vec::new(),
     }
@@ -325,7 +435,7 @@ pub fn seat_assignment_fixture_with_given_candidate_votes(
     ApportionmentInputMock {
         number_of_seats,
         list_votes,
-        deceased_candidates: HashMap::new(),
+        excluded_candidates: HashMap::new(),
         lists_drawn: Vec::new(),
         candidates_drawn: Vec::new(),
     }
@@ -352,8 +462,48 @@ pub fn seat_assignment_fixture_with_given_list_numbers_candidate_numbers_and_vot
     ApportionmentInputMock {
         number_of_seats,
         list_votes,
-        deceased_candidates: HashMap::new(),
+        excluded_candidates: HashMap::new(),
         lists_drawn: Vec::new(),
         candidates_drawn: Vec::new(),
     }
 }
+
+/// Create a DistrictApportionmentInputMock with given number of seats and district lists.
+/// Each district list is given as a tuple of list number, district list number, districts
+/// and candidate votes. The list votes contain the candidates of all district lists of a list.
+pub fn district_apportionment_fixture(
+    number_of_seats: u32,
+    district_lists: Vec<(u32, u32, Vec<u32>, Vec<u32>)>,
+) -> DistrictApportionmentInputMock {
+    let mut list_candidate_votes: Vec<(u32, Vec<u32>)> = vec![];
+    let mut district_list_votes: Vec<DistrictListVotesMock> = vec![];
+    for (list_number, number, districts, candidate_votes) in district_lists {
+        match list_candidate_votes
+            .iter_mut()
+            .find(|(n, _)| *n == list_number)
+        {
+            Some((_, votes)) => votes.extend(&candidate_votes),
+            None => list_candidate_votes.push((list_number, candidate_votes.clone())),
+        }
+        let ListVotesMock {
+            candidate_votes, ..
+        } = ListVotesMock::from_test_data_auto(number, candidate_votes);
+        district_list_votes.push(DistrictListVotesMock {
+            number,
+            list_number,
+            districts,
+            candidate_votes,
+        });
+    }
+
+    DistrictApportionmentInputMock {
+        apportionment_input: seat_assignment_fixture_with_given_list_numbers_and_candidate_votes(
+            number_of_seats,
+            list_candidate_votes,
+        ),
+        district_list_votes,
+        district_excluded_candidates: HashMap::new(),
+        district_lists_drawn: Vec::new(),
+        district_candidates_drawn: Vec::new(),
+    }
+}
@@ -0,0 +1,181 @@
+mod structs;
+
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+pub use structs::{SkippedCandidate, SuccessorSkipReason, VacancySuccession};
+use tracing::{debug, info};
+
+use crate::{ApportionmentError, CandidateRanking};
+
+/// Determine the successor for a vacated seat on a list.
+///
+/// The successor is the highest ranked candidate on the list that is not listed in
+/// `unavailable_candidates`. The candidate ranking should be the (updated) ranking that
+/// resulted from the candidate nomination, so preferential votes are taken into account.
+/// Every candidate that ranks before the successor and cannot be designated is returned
+/// as skipped candidate, together with the reason.
+pub fn vacancy_successor<CN: Copy + Debug + Eq + Hash>(
+    candidate_ranking: &CandidateRanking<CN>,
+    vacated_candidate: CN,
+    unavailable_candidates: &HashMap<CN, SuccessorSkipReason>,
+) -> Result<VacancySuccession<CN>, ApportionmentError> {
+    info!("Vacancy succession for candidate {vacated_candidate:?}");
+
+    if !candidate_ranking.iter().any(|&c| c == vacated_candidate) {
+        return Err(ApportionmentError::InvalidState(format!(
+            "Vacated candidate {vacated_candidate:?} is not in the candidate ranking"
+        )));
+    }
+
+    // [Artikel W 2 Kieswet](https://wetten.overheid.nl/BWBR0004627/2026-01-01/#AfdelingII_HoofdstukW_ArtikelW2)
+    // The seat is assigned to the candidate that is next in the ranking of the list
+    let (successor, skipped_candidates) =
+        next_available_candidate(candidate_ranking, vacated_candidate, unavailable_candidates);
+    info!("Successor: {successor:?}");
+
+    Ok(VacancySuccession {
+        vacated_candidate,
+        successor,
+        skipped_candidates,
+    })
+}
+
+/// Find the highest ranked candidate other than the vacated candidate that is not
+/// unavailable, together with the unavailable candidates that were skipped.
+fn next_available_candidate<CN: Copy + Debug + Eq + Hash>(
+    candidate_ranking: &CandidateRanking<CN>,
+    vacated_candidate: CN,
+    unavailable_candidates: &HashMap<CN, SuccessorSkipReason>,
+) -> (Option<CN>, Vec<SkippedCandidate<CN>>) {
+    let mut skipped_candidates = vec![];
+    let successor = candidate_ranking
+        .iter()
+        .copied()
+        .filter(|&candidate_number| candidate_number != vacated_candidate)
+        .find(
+            |candidate_number| match unavailable_candidates.get(candidate_number) {
+                Some(&reason) => {
+                    skipped_candidates.push(SkippedCandidate {
+                        candidate_number: *candidate_number,
+                        reason,
+                    });
+                    false
+                }
+                None => true,
+            },
+        );
+    debug!("Skipped candidates: {skipped_candidates:?}");
+    (successor, skipped_candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use test_log::test;
+
+    use super::*;
+
+    fn unavailable(candidates: &[(u32, SuccessorSkipReason)]) -> HashMap<u32, SuccessorSkipReason> {
+        candidates.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_next_candidate_in_ranking_succeeds() {
+        use SuccessorSkipReason::*;
+        let ranking = CandidateRanking::Original(vec![1, 2, 3, 4, 5]);
+        let result = vacancy_successor(
+            &ranking,
+            2,
+            &unavailable(&[(1, SeatHolder), (2, SeatHolder), (3, SeatHolder)]),
+        )
+        .unwrap();
+
+        assert_eq!(result.vacated_candidate, 2);
+        assert_eq!(result.successor, Some(4));
+        assert_eq!(
+            result.skipped_candidates,
+            vec![
+                SkippedCandidate {
+                    candidate_number: 1,
+                    reason: SeatHolder
+                },
+                SkippedCandidate {
+                    candidate_number: 3,
+                    reason: SeatHolder
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_updated_ranking_is_used() {
+        use SuccessorSkipReason::*;
+        // candidate 5 was nominated with preferential votes and ranks second
+        let ranking = CandidateRanking::Updated(vec![1, 5, 2, 3, 4]);
+        let result = vacancy_successor(
+            &ranking,
+            1,
+            &unavailable(&[(1, SeatHolder), (5, SeatHolder)]),
+        )
+        .unwrap();
+
+        assert_eq!(result.successor, Some(2));
+    }
+
+    #[test]
+    fn test_declined_deceased_and_vacated_candidates_are_skipped() {
+        use SuccessorSkipReason::*;
+        let ranking = CandidateRanking::Original(vec![1, 2, 3, 4, 5, 6]);
+        let result = vacancy_successor(
+            &ranking,
+            2,
+            &unavailable(&[
+                (1, Vacated),
+                (2, SeatHolder),
+                (3, Declined),
+                (4, Deceased),
+                (5, SeatHolder),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(result.successor, Some(6));
+        assert_eq!(
+            result
+                .skipped_candidates
+                .iter()
+                .map(|c| (c.candidate_number, c.reason))
+                .collect::<Vec<_>>(),
+            vec![(1, Vacated), (3, Declined), (4, Deceased), (5, SeatHolder)]
+        );
+    }
+
+    #[test]
+    fn test_no_eligible_successor() {
+        use SuccessorSkipReason::*;
+        let ranking = CandidateRanking::Original(vec![1, 2, 3]);
+        let result = vacancy_successor(
+            &ranking,
+            1,
+            &unavailable(&[(1, SeatHolder), (2, SeatHolder), (3, Declined)]),
+        )
+        .unwrap();
+
+        assert_eq!(result.successor, None);
+        assert_eq!(result.skipped_candidates.len(), 2);
+    }
+
+    #[test]
+    fn test_vacated_candidate_not_in_ranking() {
+        let ranking = CandidateRanking::Original(vec![1, 2, 3]);
+        let result = vacancy_successor(&ranking, 4, &HashMap::new());
+
+        assert_eq!(
+            result,
+            Err(ApportionmentError::InvalidState(
+                "Vacated candidate 4 is not in the candidate ranking".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,38 @@
+/// The reason a candidate cannot be designated as successor for a vacancy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SuccessorSkipReason {
+    /// The candidate currently holds a seat
+    SeatHolder,
+    /// The candidate held a seat that was vacated before
+    Vacated,
+    /// The candidate declined the appointment for this vacancy
+    Declined,
+    /// The candidate is deceased
+    Deceased,
+    /// The candidate was struck from the list because they are ineligible
+    Ineligible,
+}
+
+/// A candidate that was skipped while looking for the successor, with the reason
+/// why the candidate could not be designated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkippedCandidate<CN> {
+    pub candidate_number: CN,
+    pub reason: SuccessorSkipReason,
+}
+
+/// The result of filling a vacancy on a list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VacancySuccession<CN> {
+    /// The candidate whose seat was vacated
+    pub vacated_candidate: CN,
+    /// The candidate that is designated as successor, `None` if no candidate on
+    /// the list is eligible anymore and the seat remains vacant
+    pub successor: Option<CN>,
+    /// The candidates that rank before the successor (or all candidates if there
+    /// is no successor) and could not be designated, in ranking order
+    pub skipped_candidates: Vec<SkippedCandidate<CN>>,
+}
@@ -151,6 +151,7 @@ fn election() -> ElectionWithPoliticalGroups {
         authority_id: "0000".to_string(),
         authority_name: "Test".to_string(),
         authority_region: "Test".to_string(),
+        frisian_export_allowed: false,
         district: CommitteeDistrict::None,
         domain: Some(ElectionDomain {
             id: Some("0000".to_string()),
@@ -0,0 +1,3 @@
+-- Hash of each event, chained to the hash of the previous event, so changes to the audit log can be detected.
+-- Events that were logged before this migration have no hash.
+ALTER TABLE audit_log ADD COLUMN hash TEXT;
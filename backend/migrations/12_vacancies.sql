@@ -0,0 +1,13 @@
+CREATE TABLE vacancies
+(
+    id                         INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    committee_session_id       INTEGER                           NOT NULL,
+    pg_number                  INTEGER                           NOT NULL,
+    candidate_number           INTEGER                           NOT NULL,
+    reason                     TEXT                              NOT NULL,
+    successor_candidate_number INTEGER,
+    skipped_candidates         TEXT                              NOT NULL,
+    created_at                 TEXT                              NOT NULL,
+
+    FOREIGN KEY (committee_session_id) REFERENCES committee_sessions (id)
+) STRICT;
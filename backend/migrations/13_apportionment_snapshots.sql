@@ -0,0 +1,13 @@
+-- Note: we do not want a foreign key constraint on the created_by field, as we want to keep the snapshot even if the user is deleted
+CREATE TABLE apportionment_snapshots
+(
+    id                   INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    committee_session_id INTEGER                           NOT NULL,
+    version              INTEGER                           NOT NULL,
+    input                TEXT                              NOT NULL,
+    output               TEXT                              NOT NULL,
+    created_by           INTEGER                           NOT NULL,
+    created_at           TEXT                              NOT NULL,
+
+    FOREIGN KEY (committee_session_id) REFERENCES committee_sessions (id)
+) STRICT;
@@ -0,0 +1,2 @@
+-- Whether the region of the committee allows its models to be produced in Frisian
+ALTER TABLE elections ADD COLUMN frisian_export_allowed INTEGER NOT NULL DEFAULT 0;
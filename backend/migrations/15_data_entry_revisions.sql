@@ -0,0 +1,15 @@
+-- Note: we do not want a foreign key constraint on the created_by field, as we want to keep the revision even if the user is deleted
+CREATE TABLE data_entry_revisions
+(
+    id            INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    data_entry_id INTEGER                           NOT NULL,
+    revision      INTEGER                           NOT NULL,
+    action        TEXT                              NOT NULL,
+    status        TEXT                              NOT NULL,
+    state         TEXT                              NOT NULL,
+    created_by    INTEGER                           NOT NULL,
+    created_at    TEXT                              NOT NULL,
+
+    FOREIGN KEY (data_entry_id) REFERENCES data_entries (id) ON DELETE CASCADE,
+    CONSTRAINT data_entry_revision UNIQUE (data_entry_id, revision)
+) STRICT;
@@ -0,0 +1,13 @@
+-- Note: we do not want a foreign key constraint on the assigned_by field, as we want to keep the assignment even if the coordinator is deleted
+CREATE TABLE data_entry_assignments
+(
+    data_entry_id INTEGER NOT NULL,
+    entry_number  INTEGER NOT NULL,
+    user_id       INTEGER NOT NULL,
+    assigned_by   INTEGER NOT NULL,
+    assigned_at   TEXT    NOT NULL,
+
+    PRIMARY KEY (data_entry_id, entry_number),
+    FOREIGN KEY (data_entry_id) REFERENCES data_entries (id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+) STRICT;
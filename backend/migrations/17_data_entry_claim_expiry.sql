@@ -0,0 +1,2 @@
+-- When the abandoned claim on the entry in progress was logged, cleared when the data entry is updated
+ALTER TABLE data_entries ADD COLUMN claim_expired_at TEXT;
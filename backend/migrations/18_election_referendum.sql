@@ -0,0 +1,2 @@
+-- Question and answer options of a referendum, as JSON, NULL for other elections
+ALTER TABLE elections ADD COLUMN referendum TEXT;
@@ -0,0 +1,15 @@
+-- Id of the first event in the hash chain. Every event from this id onwards must have a hash,
+-- so removing the hashes of chained events is detected as well.
+CREATE TABLE audit_log_chain
+(
+    id                 INTEGER         PRIMARY KEY CHECK (id = 1) NOT NULL,
+    first_event_id     INTEGER         NOT NULL
+) STRICT;
+
+-- The chain starts at the first event that was logged with a hash, or at the next event
+INSERT INTO audit_log_chain (id, first_event_id)
+SELECT 1, COALESCE(
+    (SELECT MIN(id) FROM audit_log WHERE hash IS NOT NULL),
+    (SELECT seq FROM sqlite_sequence WHERE name = 'audit_log') + 1,
+    1
+);
@@ -0,0 +1,2 @@
+-- Language the models of the election are produced in, Frisian can only be chosen when allowed
+ALTER TABLE elections ADD COLUMN model_language TEXT NOT NULL DEFAULT 'nl';
@@ -0,0 +1,23 @@
+-- Note: we do not want foreign key constraints on the data_entry_id and created_by fields, as we want to keep the revision
+-- even if the data entry or the user is deleted. SQLite cannot drop a constraint, so the table is recreated without it.
+CREATE TABLE data_entry_revisions_new
+(
+    id            INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    data_entry_id INTEGER                           NOT NULL,
+    revision      INTEGER                           NOT NULL,
+    action        TEXT                              NOT NULL,
+    status        TEXT                              NOT NULL,
+    state         TEXT                              NOT NULL,
+    created_by    INTEGER                           NOT NULL,
+    created_at    TEXT                              NOT NULL,
+
+    CONSTRAINT data_entry_revision UNIQUE (data_entry_id, revision)
+) STRICT;
+
+INSERT INTO data_entry_revisions_new (id, data_entry_id, revision, action, status, state, created_by, created_at)
+SELECT id, data_entry_id, revision, action, status, state, created_by, created_at
+FROM data_entry_revisions;
+
+DROP TABLE data_entry_revisions;
+
+ALTER TABLE data_entry_revisions_new RENAME TO data_entry_revisions;
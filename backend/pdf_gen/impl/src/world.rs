@@ -1,7 +1,7 @@
 use typst::{
     Library, LibraryExt, World,
     diag::{FileError, FileResult},
-    foundations::{Bytes, Datetime, Duration},
+    foundations::{Bytes, Datetime, Dict, Duration, IntoValue},
     syntax::{FileId, RootedPath, Source, VirtualPath, VirtualRoot},
     text::{Font, FontBook},
     utils::LazyHash,
@@ -62,11 +62,13 @@ impl PdfWorld {
             Bytes::from_string(input.input_json()),
         );
 
+        let inputs = Dict::from_iter([("language".into(), input.language().into_value())]);
+
         Ok(PdfWorld {
             sources,
             fontbook: LazyHash::new(fontbook),
             fonts,
-            library: LazyHash::new(Library::builder().build()),
+            library: LazyHash::new(Library::builder().with_inputs(inputs).build()),
             main_source,
             input_data,
         })
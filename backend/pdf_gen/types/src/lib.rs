@@ -25,6 +25,8 @@ pub trait PdfGenInput: Send + Sync + 'static {
     fn input_json(&self) -> String;
     /// PDF output file name
     fn output_file_name(&self) -> &str;
+    /// Language code (e.g., "nl" or "fy"), available in the templates as `sys.inputs.language`
+    fn language(&self) -> &str;
 }
 
 /// Result of PDF generation
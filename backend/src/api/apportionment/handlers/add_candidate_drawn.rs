@@ -101,7 +101,7 @@ mod tests {
             id,
             &ApportionmentState::DrawingLots {
                 drawing_lots_required: drawing_lots_required.clone(),
-                deceased_candidates: vec![],
+                excluded_candidates: vec![],
                 lists_drawn: vec![],
                 candidates_drawn: vec![],
             },
@@ -133,7 +133,7 @@ mod tests {
         assert_eq!(
             state.0,
             ApportionmentState::Finalised {
-                deceased_candidates: vec![],
+                excluded_candidates: vec![],
                 lists_drawn: vec![],
                 candidates_drawn: vec![candidate_drawn],
             }
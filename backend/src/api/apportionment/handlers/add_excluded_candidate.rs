@@ -7,21 +7,23 @@ use sqlx::SqlitePool;
 use crate::{
     APIError, ErrorResponse, SqlitePoolExt,
     domain::{
-        apportionment_state::{ApportionmentState, DeceasedCandidate},
+        apportionment_state::{ApportionmentState, ExcludedCandidate},
         election::ElectionId,
     },
     infra::audit_log::AuditService,
     repository::{election_repo, user_repo::User},
-    service::update_apportionment_state,
+    service::{
+        ExcludedCandidateAddedEvent, ExcludedCandidateAuditData, update_apportionment_state,
+    },
 };
 
-/// Add deceased candidate
+/// Add excluded candidate
 #[utoipa::path(
     post,
-    path = "/api/elections/{election_id}/apportionment/add_deceased_candidate",
-    request_body = DeceasedCandidate,
+    path = "/api/elections/{election_id}/apportionment/add_excluded_candidate",
+    request_body = ExcludedCandidate,
     responses(
-        (status = 200, description = "Added deceased candidate", body = ApportionmentState),
+        (status = 200, description = "Added excluded candidate", body = ApportionmentState),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 404, description = "Not found", body = ErrorResponse),
@@ -32,12 +34,12 @@ use crate::{
         ("election_id" = ElectionId, description = "Election database id"),
     ),
 )]
-pub async fn add_deceased_candidate(
+pub async fn add_excluded_candidate(
     user: User,
     State(pool): State<SqlitePool>,
     audit_service: AuditService,
     Path(election_id): Path<ElectionId>,
-    Json(candidate): Json<DeceasedCandidate>,
+    Json(candidate): Json<ExcludedCandidate>,
 ) -> Result<Json<ApportionmentState>, APIError> {
     let mut tx = pool.begin_immediate().await?;
 
@@ -45,10 +47,21 @@ pub async fn add_deceased_candidate(
     user.role().is_authorized(election.committee_category)?;
 
     let state = update_apportionment_state(&mut tx, &audit_service, election_id, |state| {
-        state.add_deceased_candidate(candidate)
+        state.add_excluded_candidate(candidate)
     })
     .await?;
 
+    audit_service
+        .log(
+            &mut tx,
+            &ExcludedCandidateAddedEvent(ExcludedCandidateAuditData {
+                election_id,
+                candidate,
+            }),
+            None,
+        )
+        .await?;
+
     tx.commit().await?;
     Ok(Json(state))
 }
@@ -60,9 +73,12 @@ mod tests {
     use super::*;
     use crate::{
         domain::{
-            apportionment_state::ApportionmentState, committee_session::CommitteeSessionId,
-            committee_session_status::CommitteeSessionStatus, role::Role,
+            apportionment_state::{ApportionmentState, ExclusionReason},
+            committee_session::CommitteeSessionId,
+            committee_session_status::CommitteeSessionStatus,
+            role::Role,
         },
+        infra::audit_log::{AuditEventLevel, AuditEventType, assert_last_event},
         repository::{apportionment_state_repo, committee_session_repo, user_repo::UserId},
     };
 
@@ -70,7 +86,7 @@ mod tests {
         path = "../../../../fixtures",
         scripts("election_8_csb_with_results")
     )))]
-    async fn test_add_deceased_candidate(pool: SqlitePool) {
+    async fn test_add_excluded_candidate(pool: SqlitePool) {
         let mut conn = pool.acquire().await.unwrap();
         let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
         let audit_service = AuditService::new(Some(user.clone()), None);
@@ -83,17 +99,17 @@ mod tests {
         apportionment_state_repo::upsert(
             &mut conn,
             id,
-            &ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: vec![],
+            &ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: vec![],
             },
         )
         .await
         .expect("should upsert initial state");
 
-        let candidate = DeceasedCandidate::from(4, 4);
-        let state = add_deceased_candidate(
+        let candidate = ExcludedCandidate::from(4, 4, ExclusionReason::Ineligible);
+        let state = add_excluded_candidate(
             user,
-            State(pool),
+            State(pool.clone()),
             audit_service,
             Path(ElectionId::from(8)),
             Json::from(candidate),
@@ -103,9 +119,22 @@ mod tests {
 
         assert_eq!(
             state.0,
-            ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: vec![candidate]
+            ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: vec![candidate]
             }
         );
+
+        assert_last_event(
+            &mut conn,
+            AuditEventType::ExcludedCandidateAdded,
+            AuditEventLevel::Success,
+            serde_json::json!({
+                "election_id": 8,
+                "pg_number": 4,
+                "candidate_number": 4,
+                "reason": "ineligible",
+            }),
+        )
+        .await;
     }
 }
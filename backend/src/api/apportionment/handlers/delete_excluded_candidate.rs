@@ -7,21 +7,23 @@ use sqlx::SqlitePool;
 use crate::{
     APIError, ErrorResponse, SqlitePoolExt,
     domain::{
-        apportionment_state::{ApportionmentState, DeceasedCandidate},
+        apportionment_state::{ApportionmentState, ExcludedCandidate},
         election::ElectionId,
     },
     infra::audit_log::AuditService,
     repository::{election_repo, user_repo::User},
-    service::update_apportionment_state,
+    service::{
+        ExcludedCandidateAuditData, ExcludedCandidateDeletedEvent, update_apportionment_state,
+    },
 };
 
-/// Delete deceased candidate
+/// Delete excluded candidate
 #[utoipa::path(
     post,
-    path = "/api/elections/{election_id}/apportionment/delete_deceased_candidate",
-    request_body = DeceasedCandidate,
+    path = "/api/elections/{election_id}/apportionment/delete_excluded_candidate",
+    request_body = ExcludedCandidate,
     responses(
-        (status = 200, description = "Deleted deceased candidate", body = ApportionmentState),
+        (status = 200, description = "Deleted excluded candidate", body = ApportionmentState),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 404, description = "Not found", body = ErrorResponse),
@@ -32,12 +34,12 @@ use crate::{
         ("election_id" = ElectionId, description = "Election database id"),
     ),
 )]
-pub async fn delete_deceased_candidate(
+pub async fn delete_excluded_candidate(
     user: User,
     State(pool): State<SqlitePool>,
     audit_service: AuditService,
     Path(election_id): Path<ElectionId>,
-    Json(candidate): Json<DeceasedCandidate>,
+    Json(candidate): Json<ExcludedCandidate>,
 ) -> Result<Json<ApportionmentState>, APIError> {
     let mut tx = pool.begin_immediate().await?;
 
@@ -45,10 +47,21 @@ pub async fn delete_deceased_candidate(
     user.role().is_authorized(election.committee_category)?;
 
     let state = update_apportionment_state(&mut tx, &audit_service, election_id, |state| {
-        state.delete_deceased_candidate(candidate)
+        state.delete_excluded_candidate(candidate)
     })
     .await?;
 
+    audit_service
+        .log(
+            &mut tx,
+            &ExcludedCandidateDeletedEvent(ExcludedCandidateAuditData {
+                election_id,
+                candidate,
+            }),
+            None,
+        )
+        .await?;
+
     tx.commit().await?;
     Ok(Json(state))
 }
@@ -63,6 +76,7 @@ mod tests {
             apportionment_state::ApportionmentState, committee_session::CommitteeSessionId,
             committee_session_status::CommitteeSessionStatus, role::Role,
         },
+        infra::audit_log::{AuditEventLevel, AuditEventType, assert_last_event},
         repository::{apportionment_state_repo, committee_session_repo, user_repo::UserId},
     };
 
@@ -70,7 +84,7 @@ mod tests {
         path = "../../../../fixtures",
         scripts("election_8_csb_with_results")
     )))]
-    async fn test_delete_deceased_candidate(pool: SqlitePool) {
+    async fn test_delete_excluded_candidate(pool: SqlitePool) {
         let mut conn = pool.acquire().await.unwrap();
         let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
         let audit_service = AuditService::new(Some(user.clone()), None);
@@ -80,20 +94,20 @@ mod tests {
             .await
             .expect("should change committee session status");
 
-        let candidate = DeceasedCandidate::from(4, 4);
+        let candidate = ExcludedCandidate::deceased(4, 4);
         apportionment_state_repo::upsert(
             &mut conn,
             id,
-            &ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: vec![candidate],
+            &ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: vec![candidate],
             },
         )
         .await
         .expect("should upsert initial state");
 
-        let state = delete_deceased_candidate(
+        let state = delete_excluded_candidate(
             user,
-            State(pool),
+            State(pool.clone()),
             audit_service,
             Path(ElectionId::from(8)),
             Json::from(candidate),
@@ -103,9 +117,22 @@ mod tests {
 
         assert_eq!(
             state.0,
-            ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: Vec::new()
+            ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: Vec::new()
             }
         );
+
+        assert_last_event(
+            &mut conn,
+            AuditEventType::ExcludedCandidateDeleted,
+            AuditEventLevel::Info,
+            serde_json::json!({
+                "election_id": 8,
+                "pg_number": 4,
+                "candidate_number": 4,
+                "reason": "deceased",
+            }),
+        )
+        .await;
     }
 }
@@ -0,0 +1,166 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use sqlx::SqlitePool;
+
+use crate::{
+    APIError, ErrorResponse,
+    domain::{apportionment_snapshot::ApportionmentSnapshotDiff, election::ElectionId},
+    repository::{election_repo, user_repo::User},
+    service,
+};
+
+/// Compare two apportionment snapshots of an election
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/apportionment/snapshots/{from_version}/diff/{to_version}",
+    responses(
+        (status = 200, description = "Differences between the snapshots", body = ApportionmentSnapshotDiff),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+        ("from_version" = u32, description = "Version of the snapshot to compare from"),
+        ("to_version" = u32, description = "Version of the snapshot to compare to"),
+    ),
+)]
+pub async fn diff_apportionment_snapshots(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path((election_id, from_version, to_version)): Path<(ElectionId, u32, u32)>,
+) -> Result<Json<ApportionmentSnapshotDiff>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let diff =
+        service::diff_apportionment_snapshots(&mut conn, election_id, from_version, to_version)
+            .await?;
+
+    Ok(Json(diff))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::StatusCode, response::IntoResponse};
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        api::apportionment::handlers::{
+            process_apportionment::{ProcessApportionmentResponse, process_apportionment},
+            register_vacancy::tests::finalise_apportionment,
+        },
+        domain::{
+            apportionment_snapshot::SnapshotCandidate,
+            apportionment_state::ExcludedCandidate,
+            election::{CandidateNumber, PGNumber},
+            role::Role,
+        },
+        infra::audit_log::{AuditEventLevel, AuditEventType, AuditService, list_all},
+        repository::user_repo::UserId,
+    };
+
+    async fn process(pool: &SqlitePool, user: &User) {
+        let audit_service = AuditService::new(Some(user.clone()), None);
+        let Json(response) = process_apportionment(
+            user.clone(),
+            State(pool.clone()),
+            audit_service,
+            Path(ElectionId::from(8)),
+        )
+        .await
+        .expect("should process apportionment");
+        assert!(matches!(
+            response,
+            ProcessApportionmentResponse::Finalised(_)
+        ));
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_diff_apportionment_snapshots(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+
+        finalise_apportionment(&mut conn, vec![]).await;
+        process(&pool, &user).await;
+        finalise_apportionment(&mut conn, vec![ExcludedCandidate::deceased(1, 1)]).await;
+        process(&pool, &user).await;
+
+        let Json(diff) =
+            diff_apportionment_snapshots(user.clone(), State(pool.clone()), Path((8.into(), 1, 2)))
+                .await
+                .expect("should diff snapshots");
+
+        assert_eq!(diff.from.version, 1);
+        assert_eq!(diff.to.version, 2);
+        assert!(diff.lists.is_empty());
+        assert_eq!(
+            diff.no_longer_elected_candidates,
+            vec![SnapshotCandidate {
+                pg_number: PGNumber::from(1),
+                candidate_number: CandidateNumber::from(1),
+            }]
+        );
+        assert_eq!(diff.elected_candidates.len(), 1);
+        assert_eq!(diff.elected_candidates[0].pg_number, PGNumber::from(1));
+        assert_eq!(
+            diff.excluded_candidates_added,
+            vec![ExcludedCandidate::deceased(1, 1)]
+        );
+        assert!(diff.excluded_candidates_removed.is_empty());
+
+        let Json(diff) = diff_apportionment_snapshots(user, State(pool), Path((8.into(), 2, 1)))
+            .await
+            .expect("should diff snapshots");
+        assert_eq!(
+            diff.excluded_candidates_removed,
+            vec![ExcludedCandidate::deceased(1, 1)]
+        );
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_snapshot_created_event(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+
+        finalise_apportionment(&mut conn, vec![]).await;
+        process(&pool, &user).await;
+
+        let events = list_all(&mut conn).await.unwrap();
+        let snapshot_event = events
+            .iter()
+            .find(|event| *event.event_name() == AuditEventType::ApportionmentSnapshotCreated)
+            .expect("should log snapshot created event");
+        assert_eq!(*snapshot_event.event_level(), AuditEventLevel::Success);
+        assert_eq!(
+            *snapshot_event.event(),
+            serde_json::json!({"election_id": 8, "snapshot_id": 1, "version": 1})
+        );
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_diff_unknown_snapshot(pool: SqlitePool) {
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+
+        let response = diff_apportionment_snapshots(user, State(pool), Path((8.into(), 1, 2)))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
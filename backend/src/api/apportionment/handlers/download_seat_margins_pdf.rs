@@ -0,0 +1,104 @@
+use axum::extract::{Path, State};
+use axum_extra::response::Attachment;
+use chrono::{Datelike, Local};
+use pdf_gen::generate_pdf;
+use sqlx::SqlitePool;
+
+use crate::{
+    APIError, ErrorResponse,
+    domain::{
+        election::ElectionId,
+        models::{SeatMarginsAppendixInput, ToPdfFileModel},
+        report::DEFAULT_DATE_TIME_FORMAT,
+    },
+    repository::{election_repo, user_repo::User},
+    service,
+};
+
+/// Download the seat margins of the completed apportionment as an appendix
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/apportionment/seat_margins/download_pdf",
+    responses(
+        (
+            status = 200,
+            description = "PDF",
+            content_type = "application/pdf",
+            headers(
+                ("Content-Disposition", description = "attachment; filename=\"filename.pdf\"")
+            )
+        ),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 412, description = "Committee session not completed", body = ErrorResponse),
+        (status = 422, description = "Apportionment not completed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+pub async fn download_seat_margins_pdf(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+) -> Result<Attachment<Vec<u8>>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let seat_margins = service::seat_margins(&mut conn, &election).await?;
+
+    let name = format!(
+        "Bijlage_zetelmarges_{}{}_{}.pdf",
+        election.category.to_eml_code(),
+        election.election_date.year(),
+        election.location
+    );
+
+    let input = SeatMarginsAppendixInput {
+        election: election.into(),
+        seat_margins,
+        creation_date_time: Local::now().format(DEFAULT_DATE_TIME_FORMAT).to_string(),
+    }
+    .to_pdf_file_model(name.clone());
+
+    let content = generate_pdf(input).await?;
+
+    Ok(Attachment::new(content.buffer)
+        .filename(&name)
+        .content_type("application/pdf"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::StatusCode, response::IntoResponse};
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        api::apportionment::handlers::register_vacancy::tests::finalise_apportionment,
+        domain::role::Role, repository::user_repo::UserId,
+    };
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_download_seat_margins_pdf(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+
+        let response = download_seat_margins_pdf(user, State(pool), Path(ElectionId::from(8)))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("Content-Type").unwrap(),
+            "application/pdf"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use axum::extract::{Path, State};
+use axum_extra::response::Attachment;
+use chrono::{Datelike, Local};
+use pdf_gen::generate_pdf;
+use sqlx::SqlitePool;
+
+use crate::{
+    APIError, ErrorResponse,
+    api::apportionment::ApportionmentApiError,
+    domain::{
+        election::{Candidate, CandidateNumber, ElectionId, PoliticalGroup},
+        models::{ToPdfFileModel, VacancyDecisionInput, VacancyDecisionSkippedCandidate},
+        report::DEFAULT_DATE_TIME_FORMAT,
+        vacancy::VacancyId,
+    },
+    repository::{election_repo, user_repo::User, vacancy_repo},
+    service,
+};
+
+fn find_candidate(
+    political_group: &PoliticalGroup,
+    candidate_number: CandidateNumber,
+) -> Result<Candidate, ApportionmentApiError> {
+    political_group
+        .candidates
+        .iter()
+        .find(|c| c.number == candidate_number)
+        .cloned()
+        .ok_or_else(|| {
+            ApportionmentApiError::InvalidState(format!(
+                "Candidate {candidate_number} not found on list {}",
+                political_group.number
+            ))
+        })
+}
+
+/// Download the decision document for a vacancy, stating the designated successor
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/apportionment/vacancies/{vacancy_id}/download_pdf",
+    responses(
+        (
+            status = 200,
+            description = "PDF",
+            content_type = "application/pdf",
+            headers(
+                ("Content-Disposition", description = "attachment; filename=\"filename.pdf\"")
+            )
+        ),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 412, description = "Committee session not completed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+        ("vacancy_id" = VacancyId, description = "Vacancy database id"),
+    ),
+)]
+pub async fn download_vacancy_decision_pdf(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path((election_id, vacancy_id)): Path<(ElectionId, VacancyId)>,
+) -> Result<Attachment<Vec<u8>>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let (committee_session_id, _) =
+        service::get_apportionment_state(&mut conn, election_id).await?;
+    let vacancy = vacancy_repo::get(&mut conn, committee_session_id, vacancy_id).await?;
+
+    let political_group = election
+        .political_groups
+        .iter()
+        .find(|pg| pg.number == vacancy.pg_number)
+        .ok_or_else(|| {
+            ApportionmentApiError::InvalidState(format!("List {} not found", vacancy.pg_number))
+        })?;
+
+    let name = format!(
+        "Besluit_vacature_{}{}_Lijst_{}_Kandidaat_{}.pdf",
+        election.category.to_eml_code(),
+        election.election_date.year(),
+        vacancy.pg_number,
+        vacancy.candidate_number
+    );
+
+    let input = VacancyDecisionInput {
+        list_number: political_group.number,
+        list_name: political_group.name.clone(),
+        reason: vacancy.reason,
+        vacated_candidate: find_candidate(political_group, vacancy.candidate_number)?,
+        successor: vacancy
+            .successor_candidate_number
+            .map(|number| find_candidate(political_group, number))
+            .transpose()?,
+        skipped_candidates: vacancy
+            .skipped_candidates
+            .iter()
+            .map(|skipped| {
+                Ok(VacancyDecisionSkippedCandidate {
+                    candidate: find_candidate(political_group, skipped.candidate_number)?,
+                    reason: skipped.reason,
+                })
+            })
+            .collect::<Result<_, ApportionmentApiError>>()?,
+        creation_date_time: vacancy
+            .created_at
+            .with_timezone(&Local)
+            .format(DEFAULT_DATE_TIME_FORMAT)
+            .to_string(),
+        language: election.model_language,
+        election: election.into(),
+    }
+    .to_pdf_file_model(name.clone());
+
+    let content = generate_pdf(input).await?;
+
+    Ok(Attachment::new(content.buffer)
+        .filename(&name)
+        .content_type("application/pdf"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::StatusCode, response::IntoResponse};
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        api::apportionment::handlers::register_vacancy::tests::{
+            finalise_apportionment, vacancy_request,
+        },
+        domain::{role::Role, vacancy::VacancyReason},
+        infra::audit_log::AuditService,
+        repository::user_repo::UserId,
+    };
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_download_vacancy_decision_pdf(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+        let election = election_repo::get(&mut conn, ElectionId::from(8))
+            .await
+            .unwrap();
+        let vacancy = service::register_vacancy(
+            &mut conn,
+            &AuditService::new(Some(user.clone()), None),
+            &election,
+            vacancy_request(1, 1, VacancyReason::Resigned, vec![]),
+        )
+        .await
+        .expect("should register vacancy");
+
+        let response = download_vacancy_decision_pdf(
+            user.clone(),
+            State(pool.clone()),
+            Path((election.id, vacancy.id)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("Content-Type").unwrap(),
+            "application/pdf"
+        );
+
+        let response = download_vacancy_decision_pdf(
+            user,
+            State(pool),
+            Path((election.id, VacancyId::from(404))),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
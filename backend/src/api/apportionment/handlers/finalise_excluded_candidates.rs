@@ -12,12 +12,12 @@ use crate::{
     service::next_apportionment_state,
 };
 
-/// Finalise deceased candidates
+/// Finalise excluded candidates
 #[utoipa::path(
     post,
-    path = "/api/elections/{election_id}/apportionment/finalise_deceased_candidates",
+    path = "/api/elections/{election_id}/apportionment/finalise_excluded_candidates",
     responses(
-        (status = 200, description = "Finalised deceased candidates", body = ApportionmentState),
+        (status = 200, description = "Finalised excluded candidates", body = ApportionmentState),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 404, description = "Not found", body = ErrorResponse),
@@ -28,7 +28,7 @@ use crate::{
         ("election_id" = ElectionId, description = "Election database id"),
     ),
 )]
-pub async fn finalise_deceased_candidates(
+pub async fn finalise_excluded_candidates(
     user: User,
     State(pool): State<SqlitePool>,
     audit_service: AuditService,
@@ -52,7 +52,7 @@ mod tests {
     use super::*;
     use crate::{
         domain::{
-            apportionment_state::{ApportionmentState, DeceasedCandidate},
+            apportionment_state::{ApportionmentState, ExcludedCandidate},
             committee_session::CommitteeSessionId,
             committee_session_status::CommitteeSessionStatus,
             role::Role,
@@ -64,7 +64,7 @@ mod tests {
         path = "../../../../fixtures",
         scripts("election_8_csb_with_results")
     )))]
-    async fn test_finalise_deceased_candidates(pool: SqlitePool) {
+    async fn test_finalise_excluded_candidates(pool: SqlitePool) {
         let mut conn = pool.acquire().await.unwrap();
         let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
         let audit_service = AuditService::new(Some(user.clone()), None);
@@ -74,18 +74,18 @@ mod tests {
             .await
             .expect("should change committee session status");
 
-        let candidate = DeceasedCandidate::from(4, 4);
+        let candidate = ExcludedCandidate::deceased(4, 4);
         apportionment_state_repo::upsert(
             &mut conn,
             id,
-            &ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: vec![candidate],
+            &ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: vec![candidate],
             },
         )
         .await
         .expect("should upsert initial state");
 
-        let state = finalise_deceased_candidates(
+        let state = finalise_excluded_candidates(
             user,
             State(pool),
             audit_service,
@@ -97,7 +97,7 @@ mod tests {
         assert_eq!(
             state.0,
             ApportionmentState::Finalised {
-                deceased_candidates: vec![candidate],
+                excluded_candidates: vec![candidate],
                 lists_drawn: vec![],
                 candidates_drawn: vec![],
             }
@@ -0,0 +1,122 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use sqlx::SqlitePool;
+
+use crate::{
+    APIError, ErrorResponse,
+    domain::{apportionment::SeatMargins, election::ElectionId},
+    repository::{election_repo, user_repo::User},
+    service,
+};
+
+/// Get the seat margins of the completed apportionment: how many votes lists needed to
+/// gain or lose a seat and how close candidates were to the preference threshold
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/apportionment/seat_margins",
+    responses(
+        (status = 200, description = "Seat margins", body = SeatMargins),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 412, description = "Committee session not completed", body = ErrorResponse),
+        (status = 422, description = "Apportionment not completed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+pub async fn get_seat_margins(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+) -> Result<Json<SeatMargins>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let seat_margins = service::seat_margins(&mut conn, &election).await?;
+
+    Ok(Json(seat_margins))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        api::apportionment::{
+            ApportionmentApiError, handlers::register_vacancy::tests::finalise_apportionment,
+        },
+        domain::{
+            apportionment::{ListSeatMargin, OtherListMargin},
+            election::{CandidateNumber, PGNumber},
+            role::Role,
+        },
+        error::assert_delegated,
+        repository::user_repo::UserId,
+    };
+
+    async fn call_get_seat_margins(pool: SqlitePool) -> Result<Json<SeatMargins>, APIError> {
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+        get_seat_margins(user, State(pool), Path(ElectionId::from(8))).await
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_get_seat_margins(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+
+        let Json(seat_margins) = call_get_seat_margins(pool)
+            .await
+            .expect("should get seat margins");
+
+        assert_eq!(seat_margins.list_seat_margins.len(), 5);
+        assert_eq!(
+            seat_margins.list_seat_margins[2],
+            ListSeatMargin {
+                list_number: PGNumber::from(3),
+                list_name: "Political Group C".to_string(),
+                total_seats: 1,
+                votes_to_gain_seat: Some(3),
+                other_list_votes_to_gain_seat: Some(OtherListMargin {
+                    list_number: PGNumber::from(4),
+                    votes: 3,
+                }),
+                votes_to_lose_seat: Some(104),
+            }
+        );
+        // all candidates of list 2 already have a seat
+        assert_eq!(seat_margins.list_seat_margins[1].votes_to_gain_seat, None);
+
+        let list_3 = &seat_margins.list_preference_margins[2];
+        assert_eq!(list_3.list_number, PGNumber::from(3));
+        let closest = list_3.candidates.last().unwrap();
+        assert_eq!(closest.candidate.number, CandidateNumber::from(4));
+        assert!(!closest.meets_preference_threshold);
+        assert!(
+            list_3.candidates[..list_3.candidates.len() - 1]
+                .iter()
+                .all(|c| c.meets_preference_threshold)
+        );
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_get_seat_margins_requires_completed_committee_session(pool: SqlitePool) {
+        let err = call_get_seat_margins(pool)
+            .await
+            .expect_err("should not get seat margins");
+        assert_delegated(err, &ApportionmentApiError::CommitteeSessionNotCompleted);
+    }
+}
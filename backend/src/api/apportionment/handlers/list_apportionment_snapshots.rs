@@ -0,0 +1,98 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use sqlx::SqlitePool;
+
+use crate::{
+    APIError, ErrorResponse,
+    domain::{apportionment_snapshot::ApportionmentSnapshotListResponse, election::ElectionId},
+    repository::{apportionment_snapshot_repo, election_repo, user_repo::User},
+};
+
+/// List the snapshots of the processed apportionments of an election
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/apportionment/snapshots",
+    responses(
+        (status = 200, description = "Apportionment snapshots", body = ApportionmentSnapshotListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+pub async fn list_apportionment_snapshots(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+) -> Result<Json<ApportionmentSnapshotListResponse>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let snapshots = apportionment_snapshot_repo::list(&mut conn, election_id).await?;
+
+    Ok(Json(ApportionmentSnapshotListResponse { snapshots }))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        api::apportionment::handlers::{
+            process_apportionment::{ProcessApportionmentResponse, process_apportionment},
+            register_vacancy::tests::finalise_apportionment,
+        },
+        domain::role::Role,
+        infra::audit_log::AuditService,
+        repository::user_repo::UserId,
+    };
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_list_apportionment_snapshots(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+        let election_id = ElectionId::from(8);
+
+        let Json(response) =
+            list_apportionment_snapshots(user.clone(), State(pool.clone()), Path(election_id))
+                .await
+                .expect("should list snapshots");
+        assert!(response.snapshots.is_empty());
+
+        // processing the same apportionment twice only creates one snapshot
+        for _ in 0..2 {
+            let audit_service = AuditService::new(Some(user.clone()), None);
+            let Json(response) = process_apportionment(
+                user.clone(),
+                State(pool.clone()),
+                audit_service,
+                Path(election_id),
+            )
+            .await
+            .expect("should process apportionment");
+            assert!(matches!(
+                response,
+                ProcessApportionmentResponse::Finalised(_)
+            ));
+        }
+
+        let Json(response) = list_apportionment_snapshots(user, State(pool), Path(election_id))
+            .await
+            .expect("should list snapshots");
+        assert_eq!(response.snapshots.len(), 1);
+        assert_eq!(response.snapshots[0].version, 1);
+        assert_eq!(response.snapshots[0].created_by, UserId::from(3));
+    }
+}
@@ -0,0 +1,100 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use sqlx::SqlitePool;
+
+use crate::{
+    APIError, ErrorResponse,
+    domain::{election::ElectionId, vacancy::VacancyListResponse},
+    repository::{election_repo, user_repo::User},
+    service,
+};
+
+/// List the vacancies of an election
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/apportionment/vacancies",
+    responses(
+        (status = 200, description = "Vacancies", body = VacancyListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 412, description = "Committee session not completed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+pub async fn list_vacancies(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+) -> Result<Json<VacancyListResponse>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let (_, vacancies) = service::list_vacancies(&mut conn, election_id).await?;
+
+    Ok(Json(VacancyListResponse { vacancies }))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        api::apportionment::handlers::register_vacancy::tests::{
+            finalise_apportionment, vacancy_request,
+        },
+        domain::{election::CandidateNumber, role::Role, vacancy::VacancyReason},
+        infra::audit_log::AuditService,
+        repository::user_repo::UserId,
+    };
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_list_vacancies(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+        let election = election_repo::get(&mut conn, ElectionId::from(8))
+            .await
+            .unwrap();
+
+        let Json(response) = list_vacancies(user.clone(), State(pool.clone()), Path(election.id))
+            .await
+            .expect("should list vacancies");
+        assert!(response.vacancies.is_empty());
+
+        let audit_service = AuditService::new(Some(user.clone()), None);
+        for candidate_number in [2, 1] {
+            service::register_vacancy(
+                &mut conn,
+                &audit_service,
+                &election,
+                vacancy_request(1, candidate_number, VacancyReason::Resigned, vec![]),
+            )
+            .await
+            .expect("should register vacancy");
+        }
+
+        let Json(response) = list_vacancies(user, State(pool), Path(election.id))
+            .await
+            .expect("should list vacancies");
+        assert_eq!(
+            response
+                .vacancies
+                .iter()
+                .map(|vacancy| vacancy.candidate_number)
+                .collect::<Vec<_>>(),
+            CandidateNumber::from_values([2, 1])
+        );
+    }
+}
@@ -1,10 +1,17 @@
 pub mod add_candidate_drawn;
-pub mod add_deceased_candidate;
+pub mod add_excluded_candidate;
 pub mod add_list_drawn;
-pub mod delete_deceased_candidate;
-pub mod finalise_deceased_candidates;
+pub mod delete_excluded_candidate;
+pub mod diff_apportionment_snapshots;
+pub mod download_seat_margins_pdf;
+pub mod download_vacancy_decision_pdf;
+pub mod finalise_excluded_candidates;
 pub mod get_apportionment_state;
+pub mod get_seat_margins;
+pub mod list_apportionment_snapshots;
+pub mod list_vacancies;
 pub mod process_apportionment;
-pub mod register_deceased_candidates;
+pub mod register_excluded_candidates;
+pub mod register_vacancy;
 pub mod reset_apportionment_state;
-pub mod skip_deceased_candidates;
+pub mod skip_excluded_candidates;
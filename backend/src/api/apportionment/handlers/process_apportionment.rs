@@ -7,7 +7,7 @@ use sqlx::SqlitePool;
 use utoipa::ToSchema;
 
 use crate::{
-    APIError, ErrorResponse,
+    APIError, ErrorResponse, SqlitePoolExt,
     api::{apportionment::structs::ElectionApportionmentResponse, election::ElectionAuditData},
     audit_log::AuditService,
     domain::{
@@ -62,14 +62,24 @@ pub async fn process_apportionment(
     Path(id): Path<ElectionId>,
 ) -> Result<Json<ProcessApportionmentResponse>, APIError> {
     use ProcessApportionmentResponse::*;
-    let mut conn = pool.acquire().await?;
+    let mut tx = pool.begin_immediate().await?;
 
-    let election = election_repo::get(&mut conn, id).await?;
+    let election = election_repo::get(&mut tx, id).await?;
     user.role().is_authorized(election.committee_category)?;
 
-    let apportionment_result = service::process_apportionment(&mut conn, &election).await?;
+    let apportionment_result = service::process_apportionment(&mut tx, &election).await?;
     let apportionment_output = match apportionment_result {
-        ApportionmentResult::Ok(apportionment_output) => Finalised(apportionment_output),
+        ApportionmentResult::Ok(apportionment_output) => {
+            service::create_apportionment_snapshot(
+                &mut tx,
+                &audit_service,
+                election.id,
+                user.id(),
+                &apportionment_output,
+            )
+            .await?;
+            Finalised(apportionment_output)
+        }
         ApportionmentResult::ListDrawingLotsRequired(_, election_totals, seat_assignment)
         | ApportionmentResult::CandidateDrawingLotsRequired(_, election_totals, seat_assignment) => {
             DrawingLotsRequired {
@@ -81,12 +91,14 @@ pub async fn process_apportionment(
 
     audit_service
         .log(
-            &mut conn,
+            &mut tx,
             &ApportionmentProcessed(Election::from(election).into()),
             None,
         )
         .await?;
 
+    tx.commit().await?;
+
     Ok(Json(apportionment_output))
 }
 
@@ -12,12 +12,12 @@ use crate::{
     service::update_apportionment_state,
 };
 
-/// Start registering deceased candidates
+/// Start registering excluded candidates
 #[utoipa::path(
     post,
-    path = "/api/elections/{election_id}/apportionment/register_deceased_candidates",
+    path = "/api/elections/{election_id}/apportionment/register_excluded_candidates",
     responses(
-        (status = 200, description = "Started registering deceased candidates", body = ApportionmentState),
+        (status = 200, description = "Started registering excluded candidates", body = ApportionmentState),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 404, description = "Not found", body = ErrorResponse),
@@ -28,7 +28,7 @@ use crate::{
         ("election_id" = ElectionId, description = "Election database id"),
     ),
 )]
-pub async fn register_deceased_candidates(
+pub async fn register_excluded_candidates(
     user: User,
     State(pool): State<SqlitePool>,
     audit_service: AuditService,
@@ -40,7 +40,7 @@ pub async fn register_deceased_candidates(
     user.role().is_authorized(election.committee_category)?;
 
     let state = update_apportionment_state(&mut tx, &audit_service, election_id, |state| {
-        state.register_deceased_candidates()
+        state.register_excluded_candidates()
     })
     .await?;
 
@@ -65,7 +65,7 @@ mod tests {
         path = "../../../../fixtures",
         scripts("election_8_csb_with_results")
     )))]
-    async fn test_register_deceased_candidates(pool: SqlitePool) {
+    async fn test_register_excluded_candidates(pool: SqlitePool) {
         let mut conn = pool.acquire().await.unwrap();
         let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
         let audit_service = AuditService::new(Some(user.clone()), None);
@@ -75,7 +75,7 @@ mod tests {
             .await
             .expect("should change committee session status");
 
-        let state = register_deceased_candidates(
+        let state = register_excluded_candidates(
             user,
             State(pool),
             audit_service,
@@ -86,8 +86,8 @@ mod tests {
 
         assert_eq!(
             state.0,
-            ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: Vec::new()
+            ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: Vec::new()
             }
         );
     }
@@ -0,0 +1,300 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use sqlx::SqlitePool;
+
+use crate::{
+    APIError, ErrorResponse, SqlitePoolExt,
+    domain::{
+        election::ElectionId,
+        vacancy::{Vacancy, VacancyRequest},
+    },
+    infra::audit_log::AuditService,
+    repository::{election_repo, user_repo::User},
+    service,
+};
+
+/// Register a vacancy for an elected candidate and designate the successor
+#[utoipa::path(
+    post,
+    path = "/api/elections/{election_id}/apportionment/vacancies",
+    request_body = VacancyRequest,
+    responses(
+        (status = 201, description = "Vacancy registered", body = Vacancy),
+        (status = 400, description = "Declined candidate is not on the list", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 412, description = "Committee session not completed", body = ErrorResponse),
+        (status = 422, description = "Apportionment not completed or candidate does not hold a seat", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+pub async fn register_vacancy(
+    user: User,
+    State(pool): State<SqlitePool>,
+    audit_service: AuditService,
+    Path(election_id): Path<ElectionId>,
+    Json(request): Json<VacancyRequest>,
+) -> Result<(StatusCode, Json<Vacancy>), APIError> {
+    let mut tx = pool.begin_immediate().await?;
+
+    let election = election_repo::get(&mut tx, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let vacancy = service::register_vacancy(&mut tx, &audit_service, &election, request).await?;
+
+    tx.commit().await?;
+    Ok((StatusCode::CREATED, Json(vacancy)))
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use sqlx::SqliteConnection;
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        api::apportionment::ApportionmentApiError,
+        domain::{
+            apportionment_state::{ApportionmentState, ExcludedCandidate},
+            committee_session::CommitteeSessionId,
+            committee_session_status::CommitteeSessionStatus,
+            election::{CandidateNumber, PGNumber},
+            role::Role,
+            vacancy::{SkippedCandidate, SuccessorSkipReason, VacancyReason},
+        },
+        error::{ErrorReference, assert_delegated},
+        infra::audit_log::list_event_names,
+        repository::{apportionment_state_repo, committee_session_repo, user_repo::UserId},
+    };
+
+    /// Complete the committee session of election 8 and finalise the apportionment
+    pub async fn finalise_apportionment(
+        conn: &mut SqliteConnection,
+        excluded_candidates: Vec<ExcludedCandidate>,
+    ) {
+        let id = CommitteeSessionId::from(801);
+        committee_session_repo::change_status(conn, id, CommitteeSessionStatus::Completed)
+            .await
+            .expect("should change committee session status");
+        apportionment_state_repo::upsert(
+            conn,
+            id,
+            &ApportionmentState::Finalised {
+                excluded_candidates,
+                lists_drawn: vec![],
+                candidates_drawn: vec![],
+            },
+        )
+        .await
+        .expect("should upsert finalised state");
+    }
+
+    pub fn vacancy_request(
+        pg_number: u32,
+        candidate_number: u32,
+        reason: VacancyReason,
+        declined_candidates: Vec<u32>,
+    ) -> VacancyRequest {
+        VacancyRequest {
+            pg_number: PGNumber::from(pg_number),
+            candidate_number: CandidateNumber::from(candidate_number),
+            reason,
+            declined_candidates: CandidateNumber::from_values(declined_candidates),
+        }
+    }
+
+    async fn call_register(
+        pool: &SqlitePool,
+        request: VacancyRequest,
+    ) -> Result<(StatusCode, Json<Vacancy>), APIError> {
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+        let audit_service = AuditService::new(Some(user.clone()), None);
+        register_vacancy(
+            user,
+            State(pool.clone()),
+            audit_service,
+            Path(ElectionId::from(8)),
+            Json(request),
+        )
+        .await
+    }
+
+    fn skipped(candidate_number: u32, reason: SuccessorSkipReason) -> SkippedCandidate {
+        SkippedCandidate {
+            candidate_number: CandidateNumber::from(candidate_number),
+            reason,
+        }
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_register_vacancy(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+
+        let (status, Json(vacancy)) = call_register(
+            &pool,
+            vacancy_request(1, 1, VacancyReason::Resigned, vec![]),
+        )
+        .await
+        .expect("should register vacancy");
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(vacancy.reason, VacancyReason::Resigned);
+        assert_eq!(
+            vacancy.successor_candidate_number,
+            Some(CandidateNumber::from(12))
+        );
+        // the ranking of list 1 was updated, candidate 30 was elected with preferential votes
+        assert_eq!(vacancy.skipped_candidates.len(), 11);
+        assert_eq!(
+            vacancy.skipped_candidates[0],
+            skipped(30, SuccessorSkipReason::SeatHolder)
+        );
+        assert!(
+            vacancy
+                .skipped_candidates
+                .iter()
+                .all(|c| c.reason == SuccessorSkipReason::SeatHolder)
+        );
+        assert_eq!(
+            list_event_names(&mut conn).await.unwrap().last().unwrap(),
+            "VacancyRegistered"
+        );
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_register_vacancy_skips_unavailable_candidates(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![ExcludedCandidate::deceased(1, 13)]).await;
+
+        let (_, Json(first)) = call_register(
+            &pool,
+            vacancy_request(1, 1, VacancyReason::Deceased, vec![12]),
+        )
+        .await
+        .expect("should register vacancy");
+        assert_eq!(
+            first.successor_candidate_number,
+            Some(CandidateNumber::from(14))
+        );
+        // excluded candidates are not part of the updated ranking
+        assert_eq!(
+            first.skipped_candidates.last(),
+            Some(&skipped(12, SuccessorSkipReason::Declined))
+        );
+
+        // the successor now holds a seat and can vacate it, candidate 12 is eligible again
+        let (_, Json(second)) = call_register(
+            &pool,
+            vacancy_request(1, 14, VacancyReason::Resigned, vec![]),
+        )
+        .await
+        .expect("should register vacancy");
+        assert_eq!(
+            second.successor_candidate_number,
+            Some(CandidateNumber::from(12))
+        );
+        assert!(
+            second
+                .skipped_candidates
+                .contains(&skipped(1, SuccessorSkipReason::Deceased))
+        );
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_register_vacancy_requires_seat_holder(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+
+        for request in [
+            vacancy_request(1, 12, VacancyReason::Resigned, vec![]),
+            vacancy_request(99, 1, VacancyReason::Resigned, vec![]),
+        ] {
+            let err = call_register(&pool, request)
+                .await
+                .expect_err("should not register vacancy");
+            assert_delegated(err, &ApportionmentApiError::CandidateNotSeatHolder);
+        }
+
+        let (status, _) = call_register(
+            &pool,
+            vacancy_request(1, 1, VacancyReason::Resigned, vec![]),
+        )
+        .await
+        .expect("should register vacancy");
+        assert_eq!(status, StatusCode::CREATED);
+        // the seat of the candidate is already vacant
+        let err = call_register(
+            &pool,
+            vacancy_request(1, 1, VacancyReason::Resigned, vec![]),
+        )
+        .await
+        .expect_err("should not register vacancy twice");
+        assert_delegated(err, &ApportionmentApiError::CandidateNotSeatHolder);
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_register_vacancy_unknown_declined_candidate(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        finalise_apportionment(&mut conn, vec![]).await;
+
+        let err = call_register(
+            &pool,
+            vacancy_request(1, 1, VacancyReason::Resigned, vec![12, 999]),
+        )
+        .await
+        .expect_err("should not register vacancy");
+        let APIError::BadRequest(_, reference) = err else {
+            panic!("expected a bad request, got {err:?}");
+        };
+        assert_eq!(reference, ErrorReference::InvalidData);
+        assert!(
+            !list_event_names(&mut conn)
+                .await
+                .unwrap()
+                .contains(&"VacancyRegistered".to_string())
+        );
+    }
+
+    #[test(sqlx::test(fixtures(
+        path = "../../../../fixtures",
+        scripts("election_8_csb_with_results")
+    )))]
+    async fn test_register_vacancy_requires_finalised_apportionment(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        committee_session_repo::change_status(
+            &mut conn,
+            CommitteeSessionId::from(801),
+            CommitteeSessionStatus::Completed,
+        )
+        .await
+        .unwrap();
+
+        let err = call_register(
+            &pool,
+            vacancy_request(1, 1, VacancyReason::Resigned, vec![]),
+        )
+        .await
+        .expect_err("should not register vacancy");
+        assert_delegated(err, &ApportionmentApiError::ApportionmentNotCompleted);
+    }
+}
@@ -85,8 +85,8 @@ mod tests {
         apportionment_state_repo::upsert(
             &mut conn,
             id,
-            &ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: Vec::new(),
+            &ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: Vec::new(),
             },
         )
         .await
@@ -12,12 +12,12 @@ use crate::{
     service::next_apportionment_state,
 };
 
-/// Skip registering deceased candidates
+/// Skip registering excluded candidates
 #[utoipa::path(
     post,
-    path = "/api/elections/{election_id}/apportionment/skip_deceased_candidates",
+    path = "/api/elections/{election_id}/apportionment/skip_excluded_candidates",
     responses(
-        (status = 200, description = "Skipped registering deceased candidates", body = ApportionmentState),
+        (status = 200, description = "Skipped registering excluded candidates", body = ApportionmentState),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 404, description = "Not found", body = ErrorResponse),
@@ -28,7 +28,7 @@ use crate::{
         ("election_id" = ElectionId, description = "Election database id"),
     ),
 )]
-pub async fn skip_deceased_candidates(
+pub async fn skip_excluded_candidates(
     user: User,
     State(pool): State<SqlitePool>,
     audit_service: AuditService,
@@ -62,7 +62,7 @@ mod tests {
         path = "../../../../fixtures",
         scripts("election_8_csb_with_results")
     )))]
-    async fn test_skip_deceased_candidates(pool: SqlitePool) {
+    async fn test_skip_excluded_candidates(pool: SqlitePool) {
         let mut conn = pool.acquire().await.unwrap();
         let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
         let audit_service = AuditService::new(Some(user.clone()), None);
@@ -73,14 +73,14 @@ mod tests {
             .expect("should change committee session status");
 
         let state =
-            skip_deceased_candidates(user, State(pool), audit_service, Path(ElectionId::from(8)))
+            skip_excluded_candidates(user, State(pool), audit_service, Path(ElectionId::from(8)))
                 .await
                 .expect("should call the handler successfully");
 
         assert_eq!(
             state.0,
             ApportionmentState::Finalised {
-                deceased_candidates: Vec::new(),
+                excluded_candidates: Vec::new(),
                 lists_drawn: vec![],
                 candidates_drawn: vec![],
             }
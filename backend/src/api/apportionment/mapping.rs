@@ -5,8 +5,9 @@ use icu_locale_core::locale;
 
 use crate::domain::{
     apportionment::{
-        CandidateNomination, ChosenCandidate, DisplayFraction, ListCandidateNomination,
-        ListSeatAssignment, PreferenceThreshold, SeatAssignment,
+        CandidateNomination, CandidatePreferenceMargin, ChosenCandidate, DisplayFraction,
+        ListCandidateNomination, ListPreferenceMargins, ListSeatAssignment, ListSeatMargin,
+        OtherListMargin, PreferenceThreshold, SeatAssignment, SeatMargins,
     },
     election::{Candidate, CandidateNumber, PGNumber, PoliticalGroup},
     results::political_group_candidate_votes::PoliticalGroupCandidateVotes,
@@ -115,6 +116,63 @@ pub fn map_candidate_nomination(
     }
 }
 
+pub fn map_seat_margins(
+    sm: &apportionment::SeatMarginDetails<PGNumber, CandidateNumber>,
+    preference_threshold: &apportionment::PreferenceThreshold,
+    political_groups: &[PoliticalGroup],
+) -> SeatMargins {
+    let political_groups: HashMap<PGNumber, &PoliticalGroup> =
+        political_groups.iter().map(|pg| (pg.number, pg)).collect();
+    let list_name = |list_number: PGNumber| political_groups[&list_number].name.clone();
+
+    SeatMargins {
+        preference_threshold: PreferenceThreshold {
+            percentage: preference_threshold.percentage,
+            number_of_votes: DisplayFraction::from(preference_threshold.number_of_votes),
+        },
+        list_seat_margins: sm
+            .list_seat_margins
+            .iter()
+            .map(|lsm| ListSeatMargin {
+                list_number: lsm.list_number,
+                list_name: list_name(lsm.list_number),
+                total_seats: lsm.total_seats,
+                votes_to_gain_seat: lsm.votes_to_gain_seat,
+                other_list_votes_to_gain_seat: lsm.other_list_votes_to_gain_seat.map(|other| {
+                    OtherListMargin {
+                        list_number: other.list_number,
+                        votes: other.votes,
+                    }
+                }),
+                votes_to_lose_seat: lsm.votes_to_lose_seat,
+            })
+            .collect(),
+        list_preference_margins: sm
+            .list_preference_margins
+            .iter()
+            .map(|lpm| ListPreferenceMargins {
+                list_number: lpm.list_number,
+                list_name: list_name(lpm.list_number),
+                candidates: lpm
+                    .candidates
+                    .iter()
+                    .map(|cpm| CandidatePreferenceMargin {
+                        candidate: political_groups[&lpm.list_number]
+                            .candidates
+                            .iter()
+                            .find(|c| c.number == cpm.candidate_number)
+                            .expect("candidate must exist in election data")
+                            .clone(),
+                        votes: cpm.votes,
+                        meets_preference_threshold: cpm.meets_preference_threshold,
+                        margin: cpm.margin,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::DisplayFraction;
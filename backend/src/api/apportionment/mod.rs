@@ -8,7 +8,7 @@ mod mapping;
 mod structs;
 
 pub use self::{
-    mapping::{map_candidate_nomination, map_seat_assignment},
+    mapping::{map_candidate_nomination, map_seat_assignment, map_seat_margins},
     structs::{ApportionmentInputData, ElectionApportionmentResponse},
 };
 use crate::{
@@ -22,6 +22,7 @@ use crate::{
 #[derive(Debug, Clone, PartialEq)]
 pub enum ApportionmentApiError {
     ApportionmentNotCompleted,
+    CandidateNotSeatHolder,
     CommitteeSessionNotCompleted,
     InvalidLotDrawing(String),
     InvalidState(String),
@@ -44,6 +45,14 @@ impl ApiErrorResponse for ApportionmentApiError {
                     false,
                 ),
             ),
+            ApportionmentApiError::CandidateNotSeatHolder => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse::new(
+                    "Candidate does not hold a seat",
+                    ErrorReference::ApportionmentCandidateNotSeatHolder,
+                    false,
+                ),
+            ),
             ApportionmentApiError::CommitteeSessionNotCompleted => (
                 StatusCode::PRECONDITION_FAILED,
                 ErrorResponse::new(
@@ -118,25 +127,48 @@ pub fn router() -> OpenApiRouter<AppState> {
 
     OpenApiRouter::default()
         .routes(routes!(add_candidate_drawn::add_candidate_drawn).authorize(ALLOWED_ROLES))
-        .routes(routes!(add_deceased_candidate::add_deceased_candidate).authorize(ALLOWED_ROLES))
+        .routes(routes!(add_excluded_candidate::add_excluded_candidate).authorize(ALLOWED_ROLES))
         .routes(routes!(add_list_drawn::add_list_drawn).authorize(ALLOWED_ROLES))
         .routes(
-            routes!(delete_deceased_candidate::delete_deceased_candidate).authorize(ALLOWED_ROLES),
+            routes!(delete_excluded_candidate::delete_excluded_candidate).authorize(ALLOWED_ROLES),
+        )
+        .routes(
+            routes!(diff_apportionment_snapshots::diff_apportionment_snapshots)
+                .authorize(ALLOWED_ROLES),
+        )
+        .routes(
+            routes!(download_seat_margins_pdf::download_seat_margins_pdf).authorize(ALLOWED_ROLES),
         )
         .routes(
-            routes!(finalise_deceased_candidates::finalise_deceased_candidates)
+            routes!(download_vacancy_decision_pdf::download_vacancy_decision_pdf)
+                .authorize(ALLOWED_ROLES),
+        )
+        .routes(
+            routes!(finalise_excluded_candidates::finalise_excluded_candidates)
                 .authorize(ALLOWED_ROLES),
         )
         .routes(routes!(get_apportionment_state::get_apportionment_state).authorize(ALLOWED_ROLES))
+        .routes(routes!(get_seat_margins::get_seat_margins).authorize(ALLOWED_ROLES))
+        .routes(
+            routes!(list_apportionment_snapshots::list_apportionment_snapshots)
+                .authorize(ALLOWED_ROLES),
+        )
+        .routes(
+            routes!(
+                list_vacancies::list_vacancies,
+                register_vacancy::register_vacancy
+            )
+            .authorize(ALLOWED_ROLES),
+        )
         .routes(routes!(process_apportionment::process_apportionment).authorize(ALLOWED_ROLES))
         .routes(
-            routes!(register_deceased_candidates::register_deceased_candidates)
+            routes!(register_excluded_candidates::register_excluded_candidates)
                 .authorize(ALLOWED_ROLES),
         )
         .routes(
             routes!(reset_apportionment_state::reset_apportionment_state).authorize(ALLOWED_ROLES),
         )
         .routes(
-            routes!(skip_deceased_candidates::skip_deceased_candidates).authorize(ALLOWED_ROLES),
+            routes!(skip_excluded_candidates::skip_excluded_candidates).authorize(ALLOWED_ROLES),
         )
 }
@@ -9,9 +9,9 @@ use crate::domain::{
         AbsoluteMajorityDrawingLots, ApportionmentWarning, CandidateDrawingLotsVariant,
         CandidateDrawn, CandidateNomination, HighestAverageResidualSeatDrawingLots,
         LargestRemainderResidualSeatDrawingLots, ListAverage, ListDrawingLotsVariant, ListDrawn,
-        ListRemainder, SeatAssignment,
+        ListRemainder, SeatAssignment, SeatChangeExplanation,
     },
-    apportionment_state::DeceasedCandidate,
+    apportionment_state::ExcludedCandidate,
     election::{CandidateNumber, PGNumber},
     results::political_group_candidate_votes::{CandidateVotes, PoliticalGroupCandidateVotes},
     tabulation::ElectionTotals,
@@ -21,7 +21,7 @@ use crate::domain::{
 pub struct ApportionmentInputData<'a> {
     pub number_of_seats: u32,
     pub list_votes: &'a [PoliticalGroupCandidateVotes],
-    pub deceased_candidates: HashMap<PGNumber, HashSet<CandidateNumber>>,
+    pub excluded_candidates: HashMap<PGNumber, HashSet<CandidateNumber>>,
     pub lists_drawn: &'a [ListDrawn],
     pub candidates_drawn: &'a [CandidateDrawn],
 }
@@ -30,23 +30,23 @@ impl<'a> ApportionmentInputData<'a> {
     pub fn new(
         number_of_seats: u32,
         list_votes: &'a [PoliticalGroupCandidateVotes],
-        deceased_candidates: &[DeceasedCandidate],
+        excluded_candidates: &[ExcludedCandidate],
         lists_drawn: &'a [ListDrawn],
         candidates_drawn: &'a [CandidateDrawn],
     ) -> Self {
         let mut grouped: HashMap<PGNumber, HashSet<CandidateNumber>> = HashMap::new();
 
-        for dc in deceased_candidates {
+        for ec in excluded_candidates {
             grouped
-                .entry(dc.pg_number)
+                .entry(ec.pg_number)
                 .or_default()
-                .insert(dc.candidate_number);
+                .insert(ec.candidate_number);
         }
 
         Self {
             number_of_seats,
             list_votes,
-            deceased_candidates: grouped,
+            excluded_candidates: grouped,
             lists_drawn,
             candidates_drawn,
         }
@@ -66,8 +66,8 @@ impl<'a> apportionment::ApportionmentInput for ApportionmentInputData<'a> {
         self.list_votes
     }
 
-    fn deceased_candidates(&self) -> &HashMap<PGNumber, HashSet<CandidateNumber>> {
-        &self.deceased_candidates
+    fn excluded_candidates(&self) -> &HashMap<PGNumber, HashSet<CandidateNumber>> {
+        &self.excluded_candidates
     }
 
     fn lists_drawn(&self) -> impl Iterator<Item = &ListDrawn> {
@@ -214,4 +214,8 @@ pub struct ElectionApportionmentResponse {
     pub candidate_nomination: CandidateNomination,
     pub election_totals: ElectionTotals,
     pub warnings: Vec<ApportionmentWarning>,
+    /// Explanation of every step of the seat assignment, absent if there were no steps
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[schema(required = false)]
+    pub explanations: Vec<SeatChangeExplanation>,
 }
@@ -9,7 +9,7 @@ use crate::{
     APIError, AppState, ErrorResponse,
     api::middleware::authentication::RouteAuthorization,
     domain::role::Role,
-    infra::audit_log::{AuditLogEvent, LogFilter},
+    infra::audit_log::{AuditLogEvent, AuditLogVerification, LogFilter},
 };
 
 pub fn router() -> OpenApiRouter<AppState> {
@@ -20,6 +20,7 @@ pub fn router() -> OpenApiRouter<AppState> {
     OpenApiRouter::default()
         .routes(routes!(audit_log_list).authorize(ALLOWED_ROLES))
         .routes(routes!(audit_log_list_users).authorize(ALLOWED_ROLES))
+        .routes(routes!(audit_log_verify).authorize(ALLOWED_ROLES))
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -138,6 +139,26 @@ async fn audit_log_list_users(
     Ok(Json(users))
 }
 
+/// Verify the hash chain of the audit log, to check that events were not changed or removed
+#[utoipa::path(
+    get,
+    path = "/api/log/verify",
+    responses(
+        (status = 200, description = "Audit log verification result", body = AuditLogVerification),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+async fn audit_log_verify(
+    State(pool): State<SqlitePool>,
+) -> Result<Json<AuditLogVerification>, APIError> {
+    let mut conn = pool.acquire().await?;
+    let verification = crate::audit_log::verify_chain(&mut conn).await?;
+
+    Ok(Json(verification))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -149,10 +170,12 @@ mod tests {
 
     use crate::{
         api::{
-            audit::{LogFilterQuery, audit_log_list, audit_log_list_users},
+            audit::{LogFilterQuery, audit_log_list, audit_log_list_users, audit_log_verify},
             authentication::{UserLoggedInAuditData, UserLoginFailedAuditData},
         },
-        infra::audit_log::{AuditLogListResponse, AuditLogUser, AuditService},
+        infra::audit_log::{
+            AuditLogListResponse, AuditLogUser, AuditLogVerification, AuditService,
+        },
         repository::user_repo::{self, User},
         test_support::TEST_IP_V4_ADDR,
     };
@@ -323,4 +346,17 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].username, "admin1");
     }
+
+    #[test(sqlx::test(fixtures("../../fixtures/users.sql")))]
+    async fn test_verify(pool: SqlitePool) {
+        create_log_entries(pool.clone()).await;
+
+        let response = audit_log_verify(State(pool)).await.into_response();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: AuditLogVerification = serde_json::from_slice(&body).unwrap();
+
+        assert!(result.valid);
+        assert_eq!(result.event_count, 4);
+        assert_eq!(result.first_invalid_event_id, None);
+    }
 }
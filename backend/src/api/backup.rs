@@ -1,6 +1,6 @@
 use axum::{Json, extract::State, http::StatusCode};
 use chrono::Local;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use utoipa::ToSchema;
 use utoipa_axum::{router::OpenApiRouter, routes};
@@ -11,7 +11,10 @@ use crate::{
     domain::role::Role,
     infra::{
         audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType, AuditService},
-        backup::{BackupConfig, BackupResult, create_local_backup},
+        backup::{
+            BackupConfig, BackupResult, RestoreResult, create_local_backup, list_local_backups,
+            restore_local_backup,
+        },
     },
 };
 
@@ -19,6 +22,44 @@ use crate::{
 pub struct BackupResponse {
     pub filename: String,
     pub created_at: chrono::DateTime<Local>,
+    /// Whether the backup is encrypted with the backup passphrase
+    pub encrypted: bool,
+}
+
+impl From<BackupResult> for BackupResponse {
+    fn from(
+        BackupResult {
+            filename,
+            created_at,
+            encrypted,
+        }: BackupResult,
+    ) -> Self {
+        Self {
+            filename,
+            created_at,
+            encrypted,
+        }
+    }
+}
+
+/// List of available backups, newest first
+#[derive(Serialize, ToSchema)]
+pub struct BackupListResponse {
+    pub backups: Vec<BackupResponse>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BackupRestoreRequest {
+    pub filename: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct BackupRestoreResponse {
+    /// The backup that was restored
+    pub filename: String,
+    /// Backup of the database as it was right before the restore
+    pub pre_restore_backup: BackupResponse,
 }
 
 #[derive(Serialize)]
@@ -31,10 +72,33 @@ impl AsAuditEvent for DatabaseBackupCreatedAuditData {
     const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Success;
 }
 
+#[derive(Serialize)]
+pub(crate) struct DatabaseBackupRestoredAuditData {
+    filename: String,
+    pre_restore_backup: String,
+}
+
+impl From<&RestoreResult> for DatabaseBackupRestoredAuditData {
+    fn from(result: &RestoreResult) -> Self {
+        Self {
+            filename: result.filename.clone(),
+            pre_restore_backup: result.pre_restore_backup.filename.clone(),
+        }
+    }
+}
+
+impl AsAuditEvent for DatabaseBackupRestoredAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::DatabaseBackupRestored;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Warning;
+}
+
 pub fn router() -> OpenApiRouter<AppState> {
     use Role::*;
     const ALLOWED_ROLES: &[Role] = &[Administrator, CoordinatorCSB, CoordinatorGSB];
-    OpenApiRouter::default().routes(routes!(create_backup).authorize(ALLOWED_ROLES))
+    const ADMIN: &[Role] = &[Administrator];
+    OpenApiRouter::default()
+        .routes(routes!(create_backup, list_backups).authorize(ALLOWED_ROLES))
+        .routes(routes!(restore_backup).authorize(ADMIN))
 }
 
 #[utoipa::path(
@@ -53,14 +117,7 @@ async fn create_backup(
     State(backup_config): State<BackupConfig>,
     audit_service: AuditService,
 ) -> Result<(StatusCode, Json<BackupResponse>), APIError> {
-    let BackupResult {
-        filename,
-        created_at,
-    } = create_local_backup(&pool, &backup_config).await?;
-    let response = BackupResponse {
-        filename,
-        created_at,
-    };
+    let response = BackupResponse::from(create_local_backup(&pool, &backup_config).await?);
 
     let mut conn = pool.acquire().await?;
     audit_service
@@ -75,3 +132,62 @@ async fn create_backup(
 
     Ok((StatusCode::CREATED, Json(response)))
 }
+
+#[utoipa::path(
+    get,
+    path = "/api/backup",
+    responses(
+        (status = 200, description = "List of available backups", body = BackupListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+async fn list_backups(
+    State(backup_config): State<BackupConfig>,
+) -> Result<Json<BackupListResponse>, APIError> {
+    let backups = list_local_backups(&backup_config)?
+        .into_iter()
+        .map(BackupResponse::from)
+        .collect();
+    Ok(Json(BackupListResponse { backups }))
+}
+
+/// Restore the database from a backup. A backup of the current database is
+/// created first. After restoring, all users need to log in again.
+#[utoipa::path(
+    post,
+    path = "/api/backup/restore",
+    request_body = BackupRestoreRequest,
+    responses(
+        (status = 200, description = "Backup restored successfully", body = BackupRestoreResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Backup not found", body = ErrorResponse),
+        (status = 409, description = "Backup does not match the database version, or cannot be decrypted", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+async fn restore_backup(
+    State(pool): State<SqlitePool>,
+    State(backup_config): State<BackupConfig>,
+    audit_service: AuditService,
+    Json(request): Json<BackupRestoreRequest>,
+) -> Result<Json<BackupRestoreResponse>, APIError> {
+    let result = restore_local_backup(&pool, &backup_config, &request.filename).await?;
+
+    // the restored database gets an audit event, so the restore can be traced back
+    let mut conn = pool.acquire().await?;
+    audit_service
+        .log(
+            &mut conn,
+            &DatabaseBackupRestoredAuditData::from(&result),
+            None,
+        )
+        .await?;
+
+    Ok(Json(BackupRestoreResponse {
+        filename: result.filename,
+        pre_restore_backup: result.pre_restore_backup.into(),
+    }))
+}
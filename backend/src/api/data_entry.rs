@@ -13,6 +13,8 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 
 use crate::{
     APIError, AppState, SqlitePoolExt,
+    api::data_entry_assignment::{check_different_users, get_typist},
+    api::election::check_hash,
     api::middleware::authentication::{RouteAuthorization, error::AuthenticationError},
     domain::{
         committee_session::{CommitteeSession, CommitteeSessionError},
@@ -20,7 +22,12 @@ use crate::{
         data_entry::{
             ClientState, DataEntryId, DataEntryRow, DataEntrySource, DataEntrySourceContext,
             DataEntryStatus, DataEntryStatusName, DataEntryStatusResponse,
-            DataEntryTransitionError, DataEntryUpdate, EntriesDifferent,
+            DataEntryTransitionError, DataEntryUpdate, EntriesArbitration, EntriesDifferent,
+            ImportEntryTarget,
+        },
+        data_entry_revision::{
+            DataEntryRevision, DataEntryRevisionAction, DataEntryRevisionDiff,
+            DataEntryRevisionListResponse,
         },
         election::ElectionId,
         entry_number::EntryNumber,
@@ -28,20 +35,28 @@ use crate::{
         polling_station::PollingStationId,
         results::{
             PollingStationResults, Results,
-            common_polling_station_results::CommonPollingStationResults,
+            common_polling_station_results::CommonPollingStationResults, gsb_results::GSBResults,
         },
         role::Role,
         validate::{DataError, ValidateRoot, ValidationResults},
     },
+    eml::{
+        EMLImportError,
+        count::{count_eml_authority_id, parse_count_eml_str},
+        hash::CHUNK_COUNT,
+    },
     error::{ErrorReference, ErrorResponse},
-    infra::audit_log::{AsAuditEvent, AuditEvent, AuditEventLevel, AuditEventType, AuditService},
+    infra::{
+        audit_log::{AsAuditEvent, AuditEvent, AuditEventLevel, AuditEventType, AuditService},
+        claim_expiry::ClaimTimeout,
+    },
     repository::{
-        committee_session_repo,
+        committee_session_repo, data_entry_assignment_repo,
         data_entry_repo::{self},
-        election_repo, investigation_repo, polling_station_repo,
+        data_entry_revision_repo, election_repo, investigation_repo, polling_station_repo,
         user_repo::{User, UserId},
     },
-    service::change_committee_session_status,
+    service::{change_committee_session_status, diff_data_entry_revisions, update_data_entry},
 };
 
 impl From<DataError> for APIError {
@@ -54,7 +69,8 @@ impl From<DataEntryTransitionError> for APIError {
     fn from(err: DataEntryTransitionError) -> Self {
         match err {
             DataEntryTransitionError::FirstEntryAlreadyClaimed
-            | DataEntryTransitionError::SecondEntryAlreadyClaimed => {
+            | DataEntryTransitionError::SecondEntryAlreadyClaimed
+            | DataEntryTransitionError::ThirdEntryAlreadyClaimed => {
                 APIError::Conflict(err.to_string(), ErrorReference::DataEntryAlreadyClaimed)
             }
             DataEntryTransitionError::FirstEntryAlreadyFinalised
@@ -76,6 +92,8 @@ pub struct DataEntryAuditData {
     pub first_entry_user_id: Option<UserId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub second_entry_user_id: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_entry_user_id: Option<UserId>,
 }
 
 impl From<DataEntryRow> for DataEntryAuditData {
@@ -88,6 +106,7 @@ impl From<DataEntryRow> for DataEntryAuditData {
             data_entry_progress: state.get_data_entry_progress().map(|p| format!("{p}%")),
             first_entry_user_id: state.get_first_entry_user_id(),
             second_entry_user_id: state.get_second_entry_user_id(),
+            third_entry_user_id: state.get_third_entry_user_id(),
         }
     }
 }
@@ -139,6 +158,13 @@ impl AsAuditEvent for DataEntryFinalisedAuditData {
     const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Success;
 }
 
+#[derive(Serialize)]
+struct DataEntryImportedAuditData(pub DataEntryAuditData);
+impl AsAuditEvent for DataEntryImportedAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::DataEntryImported;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Success;
+}
+
 /// Response structure for getting data entry results
 #[derive(Serialize, Deserialize, ToSchema, Debug)]
 #[serde(deny_unknown_fields)]
@@ -158,6 +184,10 @@ pub struct ClaimDataEntryResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub correction_warnings: Option<Vec<String>>,
+    /// Fields that differ between the first and second entry, which the third typist has to enter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub arbitration_fields: Option<Vec<String>>,
 }
 
 pub fn router() -> OpenApiRouter<AppState> {
@@ -173,10 +203,15 @@ pub fn router() -> OpenApiRouter<AppState> {
         .routes(routes!(data_entry_discard).authorize(TYPIST))
         .routes(routes!(data_entry_finalise).authorize(TYPIST))
         .routes(routes!(data_entry_reset).authorize(COORDINATOR))
+        .routes(routes!(data_entry_take_over).authorize(COORDINATOR))
         .routes(routes!(data_entry_get).authorize(COORDINATOR))
         .routes(routes!(data_entry_resolve_errors).authorize(COORDINATOR))
         .routes(routes!(data_entry_get_differences).authorize(COORDINATOR))
         .routes(routes!(data_entry_resolve_differences).authorize(COORDINATOR))
+        .routes(routes!(data_entry_import).authorize(&[CoordinatorCSB]))
+        .routes(routes!(data_entry_list_revisions).authorize(COORDINATOR))
+        .routes(routes!(data_entry_get_revision).authorize(COORDINATOR))
+        .routes(routes!(data_entry_diff_revisions).authorize(COORDINATOR))
         .routes(routes!(election_status).authorize(ALL_ROLES))
 }
 
@@ -225,6 +260,23 @@ async fn validate_and_get_data(
     Ok((context, data_entry_status))
 }
 
+/// An entry that is assigned to a typist can only be claimed, saved, discarded or finalised
+/// by that typist
+async fn check_assigned_user(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+    user_id: UserId,
+) -> Result<(), APIError> {
+    match data_entry_assignment_repo::get(conn, data_entry_id, entry_number).await? {
+        Some(assignment) if assignment.user_id != user_id => Err(APIError::Conflict(
+            "Data entry is assigned to another typist".to_string(),
+            ErrorReference::DataEntryAssignedToOtherUser,
+        )),
+        _ => Ok(()),
+    }
+}
+
 pub async fn delete_data_entry_for_polling_station(
     conn: &mut SqliteConnection,
     audit_service: &AuditService,
@@ -264,6 +316,7 @@ pub enum ResolveDifferencesAction {
     KeepSecondAndDiscardFirst,
     KeepSecondAndCorrectFirst,
     DiscardBoth,
+    Arbitrate,
 }
 
 impl ResolveDifferencesAction {
@@ -282,6 +335,7 @@ impl ResolveDifferencesAction {
                 AuditEventType::DataEntryKeptSecondReturnedFirst
             }
             ResolveDifferencesAction::DiscardBoth => AuditEventType::DataEntryDiscardedBoth,
+            ResolveDifferencesAction::Arbitrate => AuditEventType::DataEntrySentToArbitration,
         }
     }
 }
@@ -304,7 +358,7 @@ async fn get_previous_results(
                 Results::DSOFirstSession(r) => Some(r.as_common()),
                 Results::CSONextSession(r) => Some(r.as_common()),
                 Results::DSONextSession(r) => Some(r.as_common()),
-                Results::GSB(_) => None,
+                Results::GSB(_) | Results::Referendum(_) => None,
             },
             _ => None,
         },
@@ -314,6 +368,48 @@ async fn get_previous_results(
     Ok(previous_results)
 }
 
+/// Save the state of a claimed data entry, logging whether the entry was started or resumed
+async fn save_claimed_data_entry(
+    conn: &mut SqliteConnection,
+    audit_service: &AuditService,
+    data_entry_id: DataEntryId,
+    state: &DataEntryStatus,
+    new_state: &DataEntryStatus,
+    user_id: UserId,
+) -> Result<(), APIError> {
+    match state {
+        DataEntryStatus::Empty
+        | DataEntryStatus::FirstEntryFinalised(_)
+        | DataEntryStatus::EntriesArbitration(EntriesArbitration {
+            third_entry: None, ..
+        }) => {
+            // Save the new data entry state
+            let data_entry = update_data_entry(
+                conn,
+                data_entry_id,
+                new_state,
+                DataEntryRevisionAction::Claim,
+                user_id,
+            )
+            .await?;
+
+            audit_service
+                .log(conn, &DataEntryStartedAuditData(data_entry.into()), None)
+                .await?;
+        }
+        _ => {
+            // Resuming does not change the state, so no revision is recorded
+            let data_entry = data_entry_repo::update(conn, data_entry_id, new_state).await?;
+
+            audit_service
+                .log(conn, &DataEntryResumedAuditData(data_entry.into()), None)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Claim a data entry, returning any existing progress
 #[utoipa::path(
     post,
@@ -328,7 +424,7 @@ async fn get_previous_results(
     ),
     params(
         ("data_entry_id" = DataEntryId, description = "Data entry database id"),
-        ("entry_number" = u8, description = "Data entry number (first or second data entry)"),
+        ("entry_number" = u8, description = "Data entry number (first, second or third data entry)"),
     ),
 )]
 async fn data_entry_claim(
@@ -338,10 +434,25 @@ async fn data_entry_claim(
     audit_service: AuditService,
 ) -> Result<Json<ClaimDataEntryResponse>, APIError> {
     let mut tx = pool.begin_immediate().await?;
+    let response =
+        claim_data_entry(&mut tx, &audit_service, &user, data_entry_id, entry_number).await?;
+    tx.commit().await?;
 
-    let (context, state) = validate_and_get_data(&mut tx, data_entry_id, &user).await?;
+    Ok(Json(response))
+}
+
+/// Claim an entry of a data entry for the user, returning any existing progress
+pub async fn claim_data_entry(
+    conn: &mut SqliteConnection,
+    audit_service: &AuditService,
+    user: &User,
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+) -> Result<ClaimDataEntryResponse, APIError> {
+    let (context, state) = validate_and_get_data(conn, data_entry_id, user).await?;
+    check_assigned_user(conn, data_entry_id, entry_number, user.id()).await?;
 
-    let previous_results = get_previous_results(&mut tx, &context.source).await?;
+    let previous_results = get_previous_results(conn, &context.source).await?;
 
     let initial = Results::new(
         &context.election,
@@ -353,6 +464,7 @@ async fn data_entry_claim(
     let new_state = match entry_number {
         EntryNumber::FirstEntry => state.clone().claim_first_entry(user.id(), initial)?,
         EntryNumber::SecondEntry => state.clone().claim_second_entry(user.id(), initial)?,
+        EntryNumber::ThirdEntry => state.clone().claim_third_entry(user.id(), &initial)?,
     };
 
     // Validate the state
@@ -361,25 +473,15 @@ async fn data_entry_claim(
         .expect("data should be present because data entry is in progress");
     let validation_results = new_state.start_validate(&context.election)?;
 
-    // Save the new data entry state
-    let data_entry = data_entry_repo::update(&mut tx, data_entry_id, &new_state).await?;
-
-    match state {
-        DataEntryStatus::Empty | DataEntryStatus::FirstEntryFinalised(_) => {
-            audit_service
-                .log(&mut tx, &DataEntryStartedAuditData(data_entry.into()), None)
-                .await?;
-        }
-        _ => {
-            audit_service
-                .log(&mut tx, &DataEntryResumedAuditData(data_entry.into()), None)
-                .await?;
-        }
-    }
-
-    let client_state = new_state.get_client_state().map(|v| v.to_owned());
-
-    tx.commit().await?;
+    save_claimed_data_entry(
+        conn,
+        audit_service,
+        data_entry_id,
+        &state,
+        &new_state,
+        user.id(),
+    )
+    .await?;
 
     let correction_warnings = match new_state {
         DataEntryStatus::FirstEntryCorrection(_) | DataEntryStatus::SecondEntryCorrection(_) => {
@@ -388,16 +490,22 @@ async fn data_entry_claim(
         _ => None,
     };
 
-    Ok(Json(ClaimDataEntryResponse {
+    let arbitration_fields = match &new_state {
+        DataEntryStatus::EntriesArbitration(state) => Some(state.arbitration_fields.clone()),
+        _ => None,
+    };
+
+    Ok(ClaimDataEntryResponse {
         data: data.clone(),
-        client_state,
+        client_state: new_state.get_client_state().map(|v| v.to_owned()),
         validation_results,
         previous_results,
         source: context.source,
         status: new_state.status_name(),
         is_correction: new_state.is_correction(),
         correction_warnings,
-    }))
+        arbitration_fields,
+    })
 }
 
 /// Request structure for saving data entry
@@ -444,7 +552,7 @@ impl IntoResponse for SaveDataEntryResponse {
     ),
     params(
         ("data_entry_id" = DataEntryId, description = "Data entry database id"),
-        ("entry_number" = u8, description = "Data entry number (first or second data entry)"),
+        ("entry_number" = u8, description = "Data entry number (first, second or third data entry)"),
     ),
 )]
 async fn data_entry_save(
@@ -457,6 +565,7 @@ async fn data_entry_save(
     let mut tx = pool.begin_immediate().await?;
 
     let (context, state) = validate_and_get_data(&mut tx, data_entry_id, &user).await?;
+    check_assigned_user(&mut tx, data_entry_id, entry_number, user.id()).await?;
 
     let update = DataEntryUpdate {
         progress: data_entry_request.progress,
@@ -469,13 +578,21 @@ async fn data_entry_save(
     let new_state = match entry_number {
         EntryNumber::FirstEntry => state.update_first_entry(update)?,
         EntryNumber::SecondEntry => state.update_second_entry(update)?,
+        EntryNumber::ThirdEntry => state.update_third_entry(update)?,
     };
 
     // Validate the state
     let validation_results = new_state.start_validate(&context.election)?;
 
     // Save the new data entry state
-    let data_entry = data_entry_repo::update(&mut tx, data_entry_id, &new_state).await?;
+    let data_entry = update_data_entry(
+        &mut tx,
+        data_entry_id,
+        &new_state,
+        DataEntryRevisionAction::Save,
+        user.id(),
+    )
+    .await?;
 
     audit_service
         .log(&mut tx, &DataEntrySavedAuditData(data_entry.into()), None)
@@ -501,7 +618,7 @@ async fn data_entry_save(
     ),
     params(
         ("data_entry_id" = DataEntryId, description = "Data entry database id"),
-        ("entry_number" = u8, description = "Data entry number (first or second data entry)"),
+        ("entry_number" = u8, description = "Data entry number (first, second or third data entry)"),
     ),
 )]
 async fn data_entry_discard(
@@ -513,6 +630,7 @@ async fn data_entry_discard(
     let mut tx = pool.begin_immediate().await?;
 
     let (context, state) = validate_and_get_data(&mut tx, data_entry_id, &user).await?;
+    check_assigned_user(&mut tx, data_entry_id, entry_number, user.id()).await?;
 
     let user_id = user.id();
     let new_state = match entry_number {
@@ -522,9 +640,17 @@ async fn data_entry_discard(
         EntryNumber::SecondEntry => {
             state.discard_second_entry_in_progress(user_id, &context.election)?
         }
+        EntryNumber::ThirdEntry => state.discard_third_entry_in_progress(user_id)?,
     };
 
-    let entry = data_entry_repo::update(&mut tx, data_entry_id, &new_state).await?;
+    let entry = update_data_entry(
+        &mut tx,
+        data_entry_id,
+        &new_state,
+        DataEntryRevisionAction::Discard,
+        user_id,
+    )
+    .await?;
 
     audit_service
         .log(&mut tx, &DataEntryDiscardedAuditData(entry.into()), None)
@@ -550,7 +676,7 @@ async fn data_entry_discard(
     ),
     params(
         ("data_entry_id" = DataEntryId, description = "Data entry database id"),
-        ("entry_number" = u8, description = "Data entry number (first or second data entry)"),
+        ("entry_number" = u8, description = "Data entry number (first, second or third data entry)"),
     ),
 )]
 async fn data_entry_finalise(
@@ -562,18 +688,22 @@ async fn data_entry_finalise(
     let mut tx = pool.begin_immediate().await?;
 
     let (context, state) = validate_and_get_data(&mut tx, data_entry_id, &user).await?;
+    check_assigned_user(&mut tx, data_entry_id, entry_number, user.id()).await?;
 
     let user_id = user.id();
-    let data_entry = match entry_number {
-        EntryNumber::FirstEntry => {
-            let new_state = state.finalise_first_entry(&context.election, user_id)?;
-            data_entry_repo::update(&mut tx, data_entry_id, &new_state).await?
-        }
-        EntryNumber::SecondEntry => {
-            let new_state = state.finalise_second_entry(&context.election, user_id)?;
-            data_entry_repo::update(&mut tx, data_entry_id, &new_state).await?
-        }
+    let new_state = match entry_number {
+        EntryNumber::FirstEntry => state.finalise_first_entry(&context.election, user_id)?,
+        EntryNumber::SecondEntry => state.finalise_second_entry(&context.election, user_id)?,
+        EntryNumber::ThirdEntry => state.finalise_third_entry(&context.election, user_id)?,
     };
+    let data_entry = update_data_entry(
+        &mut tx,
+        data_entry_id,
+        &new_state,
+        DataEntryRevisionAction::Finalise,
+        user_id,
+    )
+    .await?;
 
     audit_service
         .log(
@@ -583,6 +713,31 @@ async fn data_entry_finalise(
         )
         .await?;
 
+    if entry_number == EntryNumber::ThirdEntry {
+        let (event_type, event_level) = match new_state {
+            DataEntryStatus::Definitive(_) => (
+                AuditEventType::DataEntryArbitrationSucceeded,
+                AuditEventLevel::Success,
+            ),
+            _ => (
+                AuditEventType::DataEntryArbitrationFailed,
+                AuditEventLevel::Warning,
+            ),
+        };
+        let data = serde_json::to_value(DataEntryAuditData::from(data_entry.clone()))?;
+        audit_service
+            .log(
+                &mut tx,
+                &AuditEvent {
+                    event_type,
+                    event_level,
+                    data,
+                },
+                None,
+            )
+            .await?;
+    }
+
     tx.commit().await?;
 
     Ok(Json(data_entry.into()))
@@ -645,7 +800,14 @@ async fn data_entry_reset(
             ErrorReference::DataEntryCannotBeReset,
         ))
     } else {
-        data_entry_repo::update(&mut tx, data_entry_id, &DataEntryStatus::Empty).await?;
+        update_data_entry(
+            &mut tx,
+            data_entry_id,
+            &DataEntryStatus::Empty,
+            DataEntryRevisionAction::Reset,
+            user.id(),
+        )
+        .await?;
 
         audit_service
             .log(&mut tx, &DataEntryResetAuditData(data_entry.into()), None)
@@ -667,6 +829,119 @@ async fn data_entry_reset(
     }
 }
 
+#[derive(Serialize)]
+struct DataEntryTakenOverAuditData {
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+    previous_user_id: Option<UserId>,
+    user_id: UserId,
+}
+impl AsAuditEvent for DataEntryTakenOverAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::DataEntryTakenOver;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Warning;
+}
+
+/// Request structure for handing over a data entry in progress to another typist
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, FromRequest)]
+#[from_request(via(axum::Json), rejection(APIError))]
+#[serde(deny_unknown_fields)]
+pub struct DataEntryTakeOverRequest {
+    /// Typist who continues the data entry
+    pub user_id: UserId,
+}
+
+/// Hand over a first, second or third entry in progress, or a correction, with an abandoned
+/// claim to another typist, who continues with the entered results. The assignment of the
+/// entry, if any, is moved along.
+#[utoipa::path(
+    post,
+    path = "/api/data_entries/{data_entry_id}/{entry_number}/take_over",
+    request_body = DataEntryTakeOverRequest,
+    responses(
+        (status = 200, description = "Data entry taken over successfully", body = DataEntryStatusResponse),
+        (status = 400, description = "Bad request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 409, description = "Request cannot be completed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("data_entry_id" = DataEntryId, description = "Data entry database id"),
+        ("entry_number" = u8, description = "Data entry number (first, second or third data entry)"),
+    ),
+)]
+async fn data_entry_take_over(
+    user: User,
+    State(pool): State<SqlitePool>,
+    State(claim_timeout): State<ClaimTimeout>,
+    Path((data_entry_id, entry_number)): Path<(DataEntryId, EntryNumber)>,
+    audit_service: AuditService,
+    request: DataEntryTakeOverRequest,
+) -> Result<Json<DataEntryStatusResponse>, APIError> {
+    let mut tx = pool.begin_immediate().await?;
+
+    let (context, state) = validate_and_get_data(&mut tx, data_entry_id, &user).await?;
+    let typist = get_typist(
+        &mut tx,
+        request.user_id,
+        context.election.committee_category,
+    )
+    .await?;
+
+    let data_entry = data_entry_repo::get(&mut tx, data_entry_id).await?;
+    if !data_entry.is_claim_abandoned(claim_timeout.0, Utc::now()) {
+        return Err(APIError::Conflict(
+            "Data entry claim is not abandoned".to_string(),
+            ErrorReference::DataEntryClaimNotAbandoned,
+        ));
+    }
+
+    let previous_user_id = state.get_entry_user_id(entry_number);
+    let new_state = state.take_over_entry_in_progress(entry_number, typist.id())?;
+    check_different_users(&mut tx, data_entry_id, entry_number, typist.id()).await?;
+
+    if data_entry_assignment_repo::get(&mut tx, data_entry_id, entry_number)
+        .await?
+        .is_some()
+    {
+        data_entry_assignment_repo::assign(
+            &mut tx,
+            data_entry_id,
+            entry_number,
+            typist.id(),
+            user.id(),
+        )
+        .await?;
+    }
+
+    update_data_entry(
+        &mut tx,
+        data_entry_id,
+        &new_state,
+        DataEntryRevisionAction::TakeOver,
+        user.id(),
+    )
+    .await?;
+
+    audit_service
+        .log(
+            &mut tx,
+            &DataEntryTakenOverAuditData {
+                data_entry_id,
+                entry_number,
+                previous_user_id,
+                user_id: typist.id(),
+            },
+            None,
+        )
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(Json(new_state.into()))
+}
+
 #[derive(Serialize, Deserialize, ToSchema, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct DataEntryGetResponse {
@@ -721,14 +996,14 @@ async fn data_entry_get(
             source: context.source,
         },
         FirstEntryHasErrors(first_entry_has_errors_state) => DataEntryGetResponse {
-            user_id: Some(first_entry_has_errors_state.first_entry_user_id),
+            user_id: first_entry_has_errors_state.first_entry_user_id,
             data: first_entry_has_errors_state.finalised_first_entry,
             status: state.status_name(),
             validation_results: state.start_validate(&context.election)?,
             source: context.source,
         },
         FirstEntryFinalised(first_entry_finalised_state) => DataEntryGetResponse {
-            user_id: Some(first_entry_finalised_state.first_entry_user_id),
+            user_id: first_entry_finalised_state.first_entry_user_id,
             data: first_entry_finalised_state.finalised_first_entry,
             status: state.status_name(),
             validation_results: state.start_validate(&context.election)?,
@@ -796,7 +1071,14 @@ async fn data_entry_resolve_errors(
         ResolveErrorsAction::ResumeFirstEntry => state.resume_first_entry_with_errors()?,
     };
 
-    let data_entry = data_entry_repo::update(&mut tx, data_entry_id, &new_state).await?;
+    let data_entry = update_data_entry(
+        &mut tx,
+        data_entry_id,
+        &new_state,
+        DataEntryRevisionAction::ResolveErrors,
+        user.id(),
+    )
+    .await?;
     let event_type = action.audit_event_type();
     let data = serde_json::to_value(DataEntryAuditData::from(data_entry.clone()))?;
     audit_service
@@ -819,7 +1101,14 @@ async fn data_entry_resolve_errors(
 #[derive(Serialize, Deserialize, ToSchema, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct DataEntryGetDifferencesResponse {
-    pub first_entry_user_id: UserId,
+    /// Not set when the first entry was imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who imported the first entry from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
     pub first_entry: Results,
     pub first_entry_has_errors: bool,
     pub second_entry_user_id: UserId,
@@ -856,6 +1145,7 @@ async fn data_entry_get_differences(
     match state {
         DataEntryStatus::EntriesDifferent(EntriesDifferent {
             first_entry_user_id,
+            imported_by_user_id,
             first_entry,
             second_entry_user_id,
             second_entry,
@@ -868,6 +1158,7 @@ async fn data_entry_get_differences(
 
             Ok(Json(DataEntryGetDifferencesResponse {
                 first_entry_user_id,
+                imported_by_user_id,
                 first_entry,
                 first_entry_has_errors,
                 second_entry_user_id,
@@ -926,9 +1217,17 @@ async fn data_entry_resolve_differences(
             state.correct_first_entry(&context.election)?
         }
         ResolveDifferencesAction::DiscardBoth => state.discard_entries()?,
+        ResolveDifferencesAction::Arbitrate => state.arbitrate_entries()?,
     };
 
-    let data_entry = data_entry_repo::update(&mut tx, data_entry_id, &new_state).await?;
+    let data_entry = update_data_entry(
+        &mut tx,
+        data_entry_id,
+        &new_state,
+        DataEntryRevisionAction::ResolveDifferences,
+        user.id(),
+    )
+    .await?;
     let event_type = action.audit_event();
     let data = serde_json::to_value(DataEntryAuditData::from(data_entry.clone()))?;
     audit_service
@@ -948,114 +1247,299 @@ async fn data_entry_resolve_differences(
     Ok(Json(new_state.into()))
 }
 
-/// Election data entry statuses response
-#[derive(Serialize, Deserialize, ToSchema, Debug)]
-#[serde(deny_unknown_fields)]
-pub struct ElectionStatusResponse {
-    pub statuses: Vec<ElectionStatusResponseEntry>,
-}
-
-/// Election data entry statuses response entry
-#[derive(Serialize, Deserialize, ToSchema, Debug)]
+/// Request structure for importing a data entry from an EML_NL 510b file of a GSB
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, FromRequest)]
+#[from_request(via(axum::Json), rejection(APIError))]
 #[serde(deny_unknown_fields)]
-pub struct ElectionStatusResponseEntry {
-    /// Data entry id
-    pub data_entry_id: DataEntryId,
-    /// Data entry source (polling station or sub committee)
-    pub source: DataEntrySource,
-    /// Data entry status
-    pub status: DataEntryStatusName,
-    /// First entry user id
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = u8)]
-    pub first_entry_user_id: Option<UserId>,
-    /// Second entry user id
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = u8)]
-    pub second_entry_user_id: Option<UserId>,
-    /// Current data entry progress as a percentage (0 to 100)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = u8)]
-    pub data_entry_progress: Option<u8>,
-    /// Time when the data entry was finalised
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = String)]
-    pub finished_at: Option<DateTime<Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(nullable = false)]
-    /// Whether the finalised first or second data entry has warnings
-    pub finalised_with_warnings: Option<bool>,
+pub struct DataEntryImportRequest {
+    /// Contents of the EML_NL 510b file
+    pub data: String,
+    /// Hash of the EML_NL 510b file, as printed on the official report of the GSB
+    #[schema(value_type = Vec<String>)]
+    pub hash: [String; CHUNK_COUNT],
+    /// Whether the imported results are the first entry or the definitive results
+    pub target: ImportEntryTarget,
 }
 
-/// Get election polling stations data entry statuses
+/// Import the results of a GSB from an EML_NL 510b file into the data entry of its sub-committee
 #[utoipa::path(
-    get,
-    path = "/api/elections/{election_id}/status",
+    post,
+    path = "/api/data_entries/{data_entry_id}/import",
+    request_body = DataEntryImportRequest,
     responses(
-        (status = 200, description = "Election", body = ElectionStatusResponse),
+        (status = 200, description = "Data entry imported successfully", body = DataEntryStatusResponse),
+        (status = 400, description = "Bad request", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 409, description = "Request cannot be completed", body = ErrorResponse),
+        (status = 422, description = "JSON error or invalid data (Unprocessable Content)", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse),
     ),
     params(
-        ("election_id" = ElectionId, description = "Election database id"),
+        ("data_entry_id" = DataEntryId, description = "Data entry database id"),
     ),
 )]
-async fn election_status(
+async fn data_entry_import(
     user: User,
     State(pool): State<SqlitePool>,
-    Path(election_id): Path<ElectionId>,
-) -> Result<Json<ElectionStatusResponse>, APIError> {
-    let mut conn = pool.acquire().await?;
+    Path(data_entry_id): Path<DataEntryId>,
+    audit_service: AuditService,
+    request: DataEntryImportRequest,
+) -> Result<Json<DataEntryStatusResponse>, APIError> {
+    let mut tx = pool.begin_immediate().await?;
 
-    let election = election_repo::get(&mut conn, election_id).await?;
-    user.role().is_authorized(election.committee_category)?;
+    let (context, state) = validate_and_get_data(&mut tx, data_entry_id, &user).await?;
 
-    let current_committee_session =
-        committee_session_repo::get_election_committee_session(&mut conn, election_id).await?;
+    if !matches!(context.source, DataEntrySource::SubCommittee(_)) {
+        return Err(APIError::Conflict(
+            "Only results of a GSB can be imported".to_string(),
+            ErrorReference::InvalidDataEntrySource,
+        ));
+    }
 
-    let statuses =
-        crate::service::election_statuses(&mut conn, &election.into(), &current_committee_session)
-            .await?;
+    let hash = check_hash(request.data.as_bytes(), Some(&request.hash))?;
+    let count = parse_count_eml_str(&request.data)?;
 
-    Ok(Json(ElectionStatusResponse { statuses }))
-}
+    // The managing authority of the GSB is the reporting unit of its sub-committee
+    if count_eml_authority_id(&count)?
+        != context
+            .source
+            .eml_reporting_unit_identifier_number(&context.election.authority_id)
+    {
+        return Err(EMLImportError::MismatchReportingUnit.into());
+    }
 
-#[cfg(test)]
-mod tests {
-    use std::assert_matches;
+    let results = Results::GSB(GSBResults::from_count_eml(&count, &context.election)?);
+    let new_state = state.import_entry(&context.election, user.id(), results, request.target)?;
+    let data_entry = update_data_entry(
+        &mut tx,
+        data_entry_id,
+        &new_state,
+        DataEntryRevisionAction::Import,
+        user.id(),
+    )
+    .await?;
 
-    use axum::http::StatusCode;
-    use http_body_util::BodyExt;
-    use sqlx::SqlitePool;
-    use test_log::test;
+    audit_service
+        .log(
+            &mut tx,
+            &DataEntryImportedAuditData(data_entry.clone().into()),
+            Some(format!("EML file hash: {}", hash.join(" "))),
+        )
+        .await?;
 
-    use super::*;
-    use crate::{
-        domain::{
-            committee_session::CommitteeSessionId,
-            committee_session_status::CommitteeSessionStatus,
-            results::tests::example_results,
-            role::Role,
-            validate::{ValidationResult, ValidationResultCode},
-        },
-        infra::audit_log::{self, AuditEventLevel},
-        repository::{
-            committee_session_repo::change_status,
-            polling_station_repo::insert_test_polling_station,
-        },
-        service::create_test_investigation,
-    };
+    tx.commit().await?;
 
-    fn example_data_entry() -> DataEntry {
-        DataEntry {
-            progress: 100,
-            data: example_results(),
-            client_state: ClientState(None),
-        }
-    }
+    Ok(Json(data_entry.into()))
+}
 
-    fn example_data_entry_with_warning() -> DataEntry {
+/// List the revisions of a data entry
+#[utoipa::path(
+    get,
+    path = "/api/data_entries/{data_entry_id}/revisions",
+    responses(
+        (status = 200, description = "Revisions of the data entry", body = DataEntryRevisionListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("data_entry_id" = DataEntryId, description = "Data entry database id"),
+    ),
+)]
+async fn data_entry_list_revisions(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(data_entry_id): Path<DataEntryId>,
+) -> Result<Json<DataEntryRevisionListResponse>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let context = data_entry_repo::resolve_source(&mut conn, data_entry_id).await?;
+    user.role()
+        .is_authorized(context.election.committee_category)?;
+
+    let revisions = data_entry_revision_repo::list(&mut conn, data_entry_id).await?;
+
+    Ok(Json(DataEntryRevisionListResponse { revisions }))
+}
+
+/// Get a revision of a data entry, including the state of the data entry
+#[utoipa::path(
+    get,
+    path = "/api/data_entries/{data_entry_id}/revisions/{revision}",
+    responses(
+        (status = 200, description = "Revision of the data entry", body = DataEntryRevision),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("data_entry_id" = DataEntryId, description = "Data entry database id"),
+        ("revision" = u32, description = "Revision number"),
+    ),
+)]
+async fn data_entry_get_revision(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path((data_entry_id, revision)): Path<(DataEntryId, u32)>,
+) -> Result<Json<DataEntryRevision>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let context = data_entry_repo::resolve_source(&mut conn, data_entry_id).await?;
+    user.role()
+        .is_authorized(context.election.committee_category)?;
+
+    let revision = data_entry_revision_repo::get(&mut conn, data_entry_id, revision).await?;
+
+    Ok(Json(revision))
+}
+
+/// Compare two revisions of a data entry
+#[utoipa::path(
+    get,
+    path = "/api/data_entries/{data_entry_id}/revisions/{from_revision}/diff/{to_revision}",
+    responses(
+        (status = 200, description = "Differences between the revisions", body = DataEntryRevisionDiff),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("data_entry_id" = DataEntryId, description = "Data entry database id"),
+        ("from_revision" = u32, description = "Revision number to compare from"),
+        ("to_revision" = u32, description = "Revision number to compare to"),
+    ),
+)]
+async fn data_entry_diff_revisions(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path((data_entry_id, from_revision, to_revision)): Path<(DataEntryId, u32, u32)>,
+) -> Result<Json<DataEntryRevisionDiff>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let context = data_entry_repo::resolve_source(&mut conn, data_entry_id).await?;
+    user.role()
+        .is_authorized(context.election.committee_category)?;
+
+    let diff =
+        diff_data_entry_revisions(&mut conn, data_entry_id, from_revision, to_revision).await?;
+
+    Ok(Json(diff))
+}
+
+/// Election data entry statuses response
+#[derive(Serialize, Deserialize, ToSchema, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ElectionStatusResponse {
+    pub statuses: Vec<ElectionStatusResponseEntry>,
+}
+
+/// Election data entry statuses response entry
+#[derive(Serialize, Deserialize, ToSchema, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ElectionStatusResponseEntry {
+    /// Data entry id
+    pub data_entry_id: DataEntryId,
+    /// Data entry source (polling station or sub committee)
+    pub source: DataEntrySource,
+    /// Data entry status
+    pub status: DataEntryStatusName,
+    /// First entry user id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = u8)]
+    pub first_entry_user_id: Option<UserId>,
+    /// Second entry user id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = u8)]
+    pub second_entry_user_id: Option<UserId>,
+    /// Current data entry progress as a percentage (0 to 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = u8)]
+    pub data_entry_progress: Option<u8>,
+    /// Time when the data entry was finalised
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub finished_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    /// Whether the finalised first or second data entry has warnings
+    pub finalised_with_warnings: Option<bool>,
+}
+
+/// Get election polling stations data entry statuses
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/status",
+    responses(
+        (status = 200, description = "Election", body = ElectionStatusResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+async fn election_status(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+) -> Result<Json<ElectionStatusResponse>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let current_committee_session =
+        committee_session_repo::get_election_committee_session(&mut conn, election_id).await?;
+
+    let statuses =
+        crate::service::election_statuses(&mut conn, &election.into(), &current_committee_session)
+            .await?;
+
+    Ok(Json(ElectionStatusResponse { statuses }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use axum::http::StatusCode;
+    use http_body_util::BodyExt;
+    use sqlx::SqlitePool;
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        domain::{
+            committee_session::CommitteeSessionId,
+            committee_session_status::CommitteeSessionStatus,
+            results::tests::example_results,
+            role::Role,
+            validate::{ValidationResult, ValidationResultCode},
+        },
+        eml::{EmlHash, count::test_helpers::gsb_count_eml},
+        infra::audit_log::{self, AuditEventLevel},
+        repository::{
+            committee_session_repo::change_status,
+            polling_station_repo::insert_test_polling_station,
+        },
+        service::create_test_investigation,
+    };
+
+    fn example_data_entry() -> DataEntry {
+        DataEntry {
+            progress: 100,
+            data: example_results(),
+            client_state: ClientState(None),
+        }
+    }
+
+    fn example_data_entry_with_warning() -> DataEntry {
         DataEntry {
             progress: 100,
             data: example_results().with_warning(),
@@ -1106,6 +1590,7 @@ mod tests {
         let user = match entry_number {
             EntryNumber::FirstEntry => User::test_user(Role::TypistGSB, UserId::from(1)),
             EntryNumber::SecondEntry => User::test_user(Role::TypistGSB, UserId::from(2)),
+            EntryNumber::ThirdEntry => User::test_user(Role::TypistGSB, UserId::from(3)),
         };
         data_entry_claim(
             user.clone(),
@@ -1137,6 +1622,7 @@ mod tests {
         let user = match entry_number {
             EntryNumber::FirstEntry => User::test_user(Role::TypistGSB, UserId::from(1)),
             EntryNumber::SecondEntry => User::test_user(Role::TypistGSB, UserId::from(2)),
+            EntryNumber::ThirdEntry => User::test_user(Role::TypistGSB, UserId::from(3)),
         };
         data_entry_save(
             user.clone(),
@@ -1157,6 +1643,7 @@ mod tests {
         let user = match entry_number {
             EntryNumber::FirstEntry => User::test_user(Role::TypistGSB, UserId::from(1)),
             EntryNumber::SecondEntry => User::test_user(Role::TypistGSB, UserId::from(2)),
+            EntryNumber::ThirdEntry => User::test_user(Role::TypistGSB, UserId::from(3)),
         };
         data_entry_discard(
             user.clone(),
@@ -1188,6 +1675,7 @@ mod tests {
         let user = match entry_number {
             EntryNumber::FirstEntry => User::test_user(Role::TypistGSB, UserId::from(1)),
             EntryNumber::SecondEntry => User::test_user(Role::TypistGSB, UserId::from(2)),
+            EntryNumber::ThirdEntry => User::test_user(Role::TypistGSB, UserId::from(3)),
         };
         data_entry_finalise(
             user.clone(),
@@ -1427,6 +1915,186 @@ mod tests {
         assert_eq!(status, DataEntryStatusName::FirstEntryInProgress);
     }
 
+    fn import_request(data: &str, target: ImportEntryTarget) -> DataEntryImportRequest {
+        DataEntryImportRequest {
+            data: data.to_string(),
+            hash: EmlHash::from(data.as_bytes()).chunks,
+            target,
+        }
+    }
+
+    /// Create a valid EML_NL 510b for the GSB of sub-committee 9101 in election 9
+    async fn gsb_510b(pool: SqlitePool, authority_id: &str) -> String {
+        let mut conn = pool.acquire().await.unwrap();
+        let election = election_repo::get(&mut conn, ElectionId::from(9))
+            .await
+            .unwrap();
+
+        let mut results = GSBResults::empty(&election);
+        results.number_of_voters = 100;
+        results.voters_counts.poll_card_count = 10;
+        results.voters_counts.total_admitted_voters_count = 10;
+        results.votes_counts.political_group_total_votes[0].total = 10;
+        results.votes_counts.total_votes_candidates_count = 10;
+        results.votes_counts.total_votes_cast_count = 10;
+        results.political_group_votes[0].total = 10;
+        results.political_group_votes[0].candidate_votes[0].votes = 10;
+
+        gsb_count_eml(&election, authority_id, &results)
+    }
+
+    async fn import(
+        pool: SqlitePool,
+        data_entry_id: DataEntryId,
+        request: DataEntryImportRequest,
+    ) -> Response {
+        let user = User::test_user(Role::CoordinatorCSB, UserId::from(3));
+        data_entry_import(
+            user.clone(),
+            State(pool),
+            Path(data_entry_id),
+            AuditService::new(Some(user), None),
+            request,
+        )
+        .await
+        .into_response()
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_9_csb"))))]
+    async fn test_import_data_entry_definitive(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(901);
+        let data = gsb_510b(pool.clone(), "9101").await;
+        let response = import(
+            pool.clone(),
+            data_entry_id,
+            import_request(&data, ImportEntryTarget::Definitive),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let DataEntryStatus::Definitive(state) =
+            get_data_entry_status(pool.clone(), data_entry_id).await
+        else {
+            panic!("data entry should be definitive");
+        };
+        assert_eq!(state.first_entry_user_id, None);
+        assert_eq!(state.imported_by_user_id, Some(UserId::from(3)));
+        let Results::GSB(results) = state.results else {
+            panic!("results should be GSB results");
+        };
+        assert_eq!(results.number_of_voters, 100);
+        assert_eq!(results.votes_counts.total_votes_cast_count, 10);
+        assert_eq!(
+            results.political_group_votes[0].candidate_votes[0].votes,
+            10
+        );
+
+        let mut conn = pool.acquire().await.unwrap();
+        let events = audit_log::list_all(&mut conn).await.unwrap();
+        let event = events.last().unwrap();
+        assert_eq!(*event.event_name(), AuditEventType::DataEntryImported);
+        assert_eq!(
+            event.message().unwrap(),
+            &format!(
+                "EML file hash: {}",
+                EmlHash::from(data.as_bytes()).chunks.join(" ")
+            )
+        );
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_9_csb"))))]
+    async fn test_import_data_entry_first_entry(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(901);
+        let data = gsb_510b(pool.clone(), "9101").await;
+        let response = import(
+            pool.clone(),
+            data_entry_id,
+            import_request(&data, ImportEntryTarget::FirstEntry),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // The coordinator is recorded as the importer, not as the first typist
+        let DataEntryStatus::FirstEntryFinalised(state) =
+            get_data_entry_status(pool.clone(), data_entry_id).await
+        else {
+            panic!("first entry should be finalised");
+        };
+        assert_eq!(state.first_entry_user_id, None);
+        assert_eq!(state.imported_by_user_id, Some(UserId::from(3)));
+
+        // Importing again is not possible
+        let response = import(
+            pool.clone(),
+            data_entry_id,
+            import_request(&data, ImportEntryTarget::FirstEntry),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_9_csb"))))]
+    async fn test_import_data_entry_invalid_hash(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(901);
+        let data = gsb_510b(pool.clone(), "9101").await;
+        let mut request = import_request(&data, ImportEntryTarget::Definitive);
+        request.hash[0] = "0000".to_string();
+
+        let response = import(pool.clone(), data_entry_id, request).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.reference, ErrorReference::InvalidHash);
+        assert_eq!(
+            get_data_entry_status(pool, data_entry_id).await,
+            DataEntryStatus::Empty
+        );
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_9_csb"))))]
+    async fn test_import_data_entry_other_gsb(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(901);
+        let data = gsb_510b(pool.clone(), "9102").await;
+
+        let response = import(
+            pool.clone(),
+            data_entry_id,
+            import_request(&data, ImportEntryTarget::Definitive),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.reference, ErrorReference::EmlImportError);
+        assert_eq!(
+            get_data_entry_status(pool, data_entry_id).await,
+            DataEntryStatus::Empty
+        );
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+    async fn test_import_data_entry_polling_station_is_rejected(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(201);
+        let user = User::test_user(Role::CoordinatorGSB, UserId::from(1));
+        let mut request = import_request("not an EML file", ImportEntryTarget::Definitive);
+        request.hash[0] = "0000".to_string();
+
+        // The source is checked before the file is looked at
+        let response = data_entry_import(
+            user.clone(),
+            State(pool.clone()),
+            Path(data_entry_id),
+            AuditService::new(Some(user), None),
+            request,
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.reference, ErrorReference::InvalidDataEntrySource);
+    }
+
     #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
     async fn test_claim_data_entry_committee_session_status_is_paused(pool: SqlitePool) {
         let polling_station_id = PollingStationId::from(211);
@@ -1752,6 +2420,52 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_data_entry_assigned_to_other_user(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(201);
+        let response = claim(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Assign the entry in progress to another typist
+        let mut conn = pool.acquire().await.unwrap();
+        data_entry_assignment_repo::assign(
+            &mut conn,
+            data_entry_id,
+            EntryNumber::FirstEntry,
+            UserId::from(5),
+            UserId::from(3),
+        )
+        .await
+        .unwrap();
+        drop(conn);
+
+        let responses = [
+            save(
+                pool.clone(),
+                example_data_entry(),
+                data_entry_id,
+                EntryNumber::FirstEntry,
+            )
+            .await,
+            finalise(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await,
+            discard(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await,
+        ];
+        for response in responses {
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let result: ErrorResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(
+                result.reference,
+                ErrorReference::DataEntryAssignedToOtherUser
+            );
+        }
+
+        assert_matches!(
+            get_data_entry_status(pool.clone(), data_entry_id).await,
+            DataEntryStatus::FirstEntryInProgress(_)
+        );
+    }
+
     #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
     async fn test_finalise_data_entry_committee_session_status_is_paused(pool: SqlitePool) {
         let request_body = example_data_entry();
@@ -2223,7 +2937,7 @@ mod tests {
         let DataEntryStatus::FirstEntryFinalised(state) = data_entry.state.0 else {
             panic!("Expected entry to be in FirstEntryFinalised state");
         };
-        assert_eq!(state.first_entry_user_id, UserId::from(2));
+        assert_eq!(state.first_entry_user_id, Some(UserId::from(2)));
         let Results::CSOFirstSession(first_entry) = state.finalised_first_entry else {
             panic!("Expected entry to be CSOFirstSession model");
         };
@@ -2263,7 +2977,7 @@ mod tests {
         let DataEntryStatus::FirstEntryFinalised(state) = data_entry.state.0 else {
             panic!("Expected entry to be in FirstEntryFinalised state");
         };
-        assert_eq!(state.first_entry_user_id, UserId::from(1));
+        assert_eq!(state.first_entry_user_id, Some(UserId::from(1)));
         let Results::CSOFirstSession(first_entry) = state.finalised_first_entry else {
             panic!("Expected entry to be CSOFirstSession model");
         };
@@ -2782,52 +3496,165 @@ mod tests {
             let response = resolve_differences(
                 pool.clone(),
                 data_entry_id,
-                ResolveDifferencesAction::KeepSecondAndCorrectFirst,
+                ResolveDifferencesAction::KeepSecondAndCorrectFirst,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let result: ErrorResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(result.reference, ErrorReference::InvalidStateTransition);
+
+            // The state must be unchanged
+            let mut conn = pool.acquire().await.unwrap();
+            let data_entry = get_data_entry_for_ps(&mut conn, polling_station_id).await;
+            assert!(matches!(
+                data_entry.state.0,
+                DataEntryStatus::EntriesDifferent(_)
+            ));
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+        async fn test_discard_both(pool: SqlitePool) {
+            let polling_station_id = PollingStationId::from(211);
+            let data_entry_id = DataEntryId::from(201);
+            finalise_different_entries(pool.clone()).await;
+
+            // Check that the data entry is created
+            let mut conn = pool.acquire().await.unwrap();
+            assert!(ps_has_data_entry(&mut conn, polling_station_id).await);
+
+            let response = resolve_differences(
+                pool.clone(),
+                data_entry_id,
+                ResolveDifferencesAction::DiscardBoth,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            // Check that the data entry is reset to Empty
+            let data_entry = get_data_entry_for_ps(&mut conn, polling_station_id).await;
+            assert_eq!(data_entry.state.0, DataEntryStatus::Empty);
+
+            audit_log::assert_last_event(
+                &mut conn,
+                AuditEventType::DataEntryDiscardedBoth,
+                AuditEventLevel::Info,
+                serde_json::to_value(DataEntryAuditData::from(data_entry)).unwrap(),
+            )
+            .await;
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+        async fn test_arbitrate(pool: SqlitePool) {
+            let polling_station_id = PollingStationId::from(211);
+            let data_entry_id = DataEntryId::from(201);
+            finalise_different_entries(pool.clone()).await;
+
+            let response = resolve_differences(
+                pool.clone(),
+                data_entry_id,
+                ResolveDifferencesAction::Arbitrate,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let mut conn = pool.acquire().await.unwrap();
+            let data_entry = get_data_entry_for_ps(&mut conn, polling_station_id).await;
+            audit_log::assert_last_event(
+                &mut conn,
+                AuditEventType::DataEntrySentToArbitration,
+                AuditEventLevel::Info,
+                serde_json::to_value(DataEntryAuditData::from(data_entry.clone())).unwrap(),
+            )
+            .await;
+            assert!(matches!(
+                data_entry.state.0,
+                DataEntryStatus::EntriesArbitration(_)
+            ));
+
+            // Claim the third entry, only the differing fields have to be entered
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::ThirdEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let result: ClaimDataEntryResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(
+                result.arbitration_fields,
+                Some(vec![
+                    "data.voters_counts.poll_card_count".to_string(),
+                    "data.voters_counts.proxy_certificate_count".to_string(),
+                ])
+            );
+            assert_eq!(result.data.voters_counts().poll_card_count, 0);
+            assert_eq!(result.data.votes_counts().unwrap().blank_votes_count, 2);
+
+            // The third entry agrees with the first entry
+            let response = save(
+                pool.clone(),
+                example_data_entry(),
+                data_entry_id,
+                EntryNumber::ThirdEntry,
             )
             .await;
-            assert_eq!(response.status(), StatusCode::CONFLICT);
-            let body = response.into_body().collect().await.unwrap().to_bytes();
-            let result: ErrorResponse = serde_json::from_slice(&body).unwrap();
-            assert_eq!(result.reference, ErrorReference::InvalidStateTransition);
+            assert_eq!(response.status(), StatusCode::OK);
+            let response = finalise(pool.clone(), data_entry_id, EntryNumber::ThirdEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
 
-            // The state must be unchanged
-            let mut conn = pool.acquire().await.unwrap();
             let data_entry = get_data_entry_for_ps(&mut conn, polling_station_id).await;
-            assert!(matches!(
-                data_entry.state.0,
-                DataEntryStatus::EntriesDifferent(_)
-            ));
+            audit_log::assert_last_event(
+                &mut conn,
+                AuditEventType::DataEntryArbitrationSucceeded,
+                AuditEventLevel::Success,
+                serde_json::to_value(DataEntryAuditData::from(data_entry.clone())).unwrap(),
+            )
+            .await;
+            let DataEntryStatus::Definitive(state) = data_entry.state.0 else {
+                panic!("Expected entry to be in Definitive state");
+            };
+            assert_eq!(state.results.voters_counts().poll_card_count, 99);
         }
 
         #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
-        async fn test_discard_both(pool: SqlitePool) {
+        async fn test_arbitrate_without_majority(pool: SqlitePool) {
             let polling_station_id = PollingStationId::from(211);
             let data_entry_id = DataEntryId::from(201);
             finalise_different_entries(pool.clone()).await;
-
-            // Check that the data entry is created
-            let mut conn = pool.acquire().await.unwrap();
-            assert!(ps_has_data_entry(&mut conn, polling_station_id).await);
-
             let response = resolve_differences(
                 pool.clone(),
                 data_entry_id,
-                ResolveDifferencesAction::DiscardBoth,
+                ResolveDifferencesAction::Arbitrate,
             )
             .await;
             assert_eq!(response.status(), StatusCode::OK);
 
-            // Check that the data entry is reset to Empty
-            let data_entry = get_data_entry_for_ps(&mut conn, polling_station_id).await;
-            assert_eq!(data_entry.state.0, DataEntryStatus::Empty);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::ThirdEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let mut third_entry = example_data_entry();
+            third_entry.data.voters_counts_mut().poll_card_count = 50;
+            third_entry.data.voters_counts_mut().proxy_certificate_count = 50;
+            let response = save(
+                pool.clone(),
+                third_entry,
+                data_entry_id,
+                EntryNumber::ThirdEntry,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let response = finalise(pool.clone(), data_entry_id, EntryNumber::ThirdEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
 
+            let mut conn = pool.acquire().await.unwrap();
+            let data_entry = get_data_entry_for_ps(&mut conn, polling_station_id).await;
             audit_log::assert_last_event(
                 &mut conn,
-                AuditEventType::DataEntryDiscardedBoth,
-                AuditEventLevel::Info,
-                serde_json::to_value(DataEntryAuditData::from(data_entry)).unwrap(),
+                AuditEventType::DataEntryArbitrationFailed,
+                AuditEventLevel::Warning,
+                serde_json::to_value(DataEntryAuditData::from(data_entry.clone())).unwrap(),
             )
             .await;
+            assert!(matches!(
+                data_entry.state.0,
+                DataEntryStatus::EntriesDifferent(_)
+            ));
         }
 
         #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
@@ -3318,6 +4145,193 @@ mod tests {
         }
     }
 
+    mod data_entry_revisions {
+        use test_log::test;
+
+        use super::*;
+        use crate::domain::data_entry_revision::DataEntryRevisionSummary;
+
+        fn coordinator() -> User {
+            User::test_user(Role::CoordinatorGSB, UserId::from(1))
+        }
+
+        async fn list_revisions(
+            pool: SqlitePool,
+            data_entry_id: DataEntryId,
+        ) -> Vec<DataEntryRevisionSummary> {
+            let response =
+                data_entry_list_revisions(coordinator(), State(pool), Path(data_entry_id))
+                    .await
+                    .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let result: DataEntryRevisionListResponse = serde_json::from_slice(&body).unwrap();
+            result.revisions
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+        async fn test_revisions_are_recorded(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(201);
+            finalise_different_entries(pool.clone()).await;
+            let response = resolve_differences(
+                pool.clone(),
+                data_entry_id,
+                ResolveDifferencesAction::KeepFirstAndDiscardSecond,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let revisions = list_revisions(pool, data_entry_id).await;
+            let actions: Vec<_> = revisions.iter().map(|r| r.action).collect();
+            let statuses: Vec<_> = revisions.iter().map(|r| r.status).collect();
+            let users: Vec<_> = revisions.iter().map(|r| r.created_by).collect();
+
+            assert_eq!(
+                revisions.iter().map(|r| r.revision).collect::<Vec<_>>(),
+                vec![1, 2, 3, 4, 5, 6, 7]
+            );
+            assert_eq!(
+                actions,
+                vec![
+                    DataEntryRevisionAction::Claim,
+                    DataEntryRevisionAction::Save,
+                    DataEntryRevisionAction::Finalise,
+                    DataEntryRevisionAction::Claim,
+                    DataEntryRevisionAction::Save,
+                    DataEntryRevisionAction::Finalise,
+                    DataEntryRevisionAction::ResolveDifferences,
+                ]
+            );
+            assert_eq!(
+                statuses,
+                vec![
+                    DataEntryStatusName::FirstEntryInProgress,
+                    DataEntryStatusName::FirstEntryInProgress,
+                    DataEntryStatusName::FirstEntryFinalised,
+                    DataEntryStatusName::SecondEntryInProgress,
+                    DataEntryStatusName::SecondEntryInProgress,
+                    DataEntryStatusName::EntriesDifferent,
+                    DataEntryStatusName::FirstEntryFinalised,
+                ]
+            );
+            assert_eq!(users, [1, 1, 1, 2, 2, 2, 1].map(UserId::from).to_vec());
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+        async fn test_resuming_claim_does_not_record_revision(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(201);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let revisions = list_revisions(pool, data_entry_id).await;
+            assert_eq!(revisions.len(), 1);
+            assert_eq!(revisions[0].action, DataEntryRevisionAction::Claim);
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+        async fn test_get_and_diff_revisions(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(201);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let response = save(
+                pool.clone(),
+                example_data_entry(),
+                data_entry_id,
+                EntryNumber::FirstEntry,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let mut changed_entry = example_data_entry();
+            changed_entry.data.voters_counts_mut().poll_card_count = 100;
+            changed_entry.client_state = ClientState(Some(serde_json::json!({"test": 1})));
+            let response = save(
+                pool.clone(),
+                changed_entry,
+                data_entry_id,
+                EntryNumber::FirstEntry,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let response = data_entry_get_revision(
+                coordinator(),
+                State(pool.clone()),
+                Path((data_entry_id, 2)),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let revision: DataEntryRevision = serde_json::from_slice(&body).unwrap();
+            assert_eq!(revision.summary.revision, 2);
+            assert_eq!(revision.summary.action, DataEntryRevisionAction::Save);
+            assert_eq!(revision.state.get_first_entry(), Some(&example_results()));
+
+            let response = data_entry_diff_revisions(
+                coordinator(),
+                State(pool.clone()),
+                Path((data_entry_id, 2, 3)),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let diff: DataEntryRevisionDiff = serde_json::from_slice(&body).unwrap();
+            assert_eq!(diff.from.revision, 2);
+            assert_eq!(diff.to.revision, 3);
+            assert_eq!(diff.first_entry, vec!["data.voters_counts.poll_card_count"]);
+            assert!(diff.second_entry.is_empty());
+            assert!(diff.definitive.is_empty());
+            assert!(diff.client_state_changed);
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+        async fn test_revisions_are_kept_when_data_entry_is_deleted(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(201);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let mut conn = pool.acquire().await.unwrap();
+            polling_station_repo::unlink_data_entry(&mut conn, PollingStationId::from(211))
+                .await
+                .unwrap();
+            data_entry_repo::delete(&mut conn, data_entry_id)
+                .await
+                .unwrap();
+
+            let revisions = data_entry_revision_repo::list(&mut conn, data_entry_id)
+                .await
+                .unwrap();
+            assert_eq!(revisions.len(), 1);
+            assert_eq!(revisions[0].action, DataEntryRevisionAction::Claim);
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2"))))]
+        async fn test_revision_not_found(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(201);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let response = data_entry_get_revision(
+                coordinator(),
+                State(pool.clone()),
+                Path((data_entry_id, 2)),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+            let response =
+                data_entry_diff_revisions(coordinator(), State(pool), Path((data_entry_id, 1, 2)))
+                    .await
+                    .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+    }
+
     mod authorization {
         use test_log::test;
 
@@ -3348,10 +4362,12 @@ mod tests {
                 ("discard",             data_entry_discard(typist_user.clone(), State(pool.clone()), Path((data_entry_id, entry_number)), typist_audit.clone()).await.into_response()),
                 ("finalise",            data_entry_finalise(typist_user.clone(), State(pool.clone()), Path((data_entry_id, entry_number)), typist_audit.clone()).await.into_response()),
                 ("reset",               data_entry_reset(coordinator_user.clone(), State(pool.clone()), Path(data_entry_id), coordinator_audit.clone()).await.into_response()),
+                ("take_over",           data_entry_take_over(coordinator_user.clone(), State(pool.clone()), State(ClaimTimeout::default()), Path((data_entry_id, entry_number)), coordinator_audit.clone(), DataEntryTakeOverRequest { user_id: UserId::from(5) }).await.into_response()),
                 ("get",                 data_entry_get(coordinator_user.clone(), State(pool.clone()), Path(data_entry_id)).await.into_response()),
                 ("resolve_errors",      data_entry_resolve_errors(coordinator_user.clone(), State(pool.clone()), Path(data_entry_id), coordinator_audit.clone(), ResolveErrorsAction::DiscardFirstEntry).await.into_response()),
                 ("get_differences",     data_entry_get_differences(coordinator_user.clone(), State(pool.clone()), Path(data_entry_id)).await.into_response()),
                 ("resolve_differences", data_entry_resolve_differences(coordinator_user.clone(), State(pool.clone()), Path(data_entry_id), coordinator_audit.clone(), ResolveDifferencesAction::DiscardBoth).await.into_response()),
+                ("import",              data_entry_import(coordinator_user.clone(), State(pool.clone()), Path(data_entry_id), coordinator_audit.clone(), import_request("", ImportEntryTarget::FirstEntry)).await.into_response()),
                 ("election_status",     election_status(coordinator_user.clone(), State(pool.clone()), Path(election_id)).await.into_response()),
             ];
             results
@@ -3369,4 +4385,243 @@ mod tests {
             assert_committee_category_authorization_ok(results);
         }
     }
+
+    mod data_entry_take_over {
+        use chrono::TimeDelta;
+        use test_log::test;
+
+        use super::*;
+
+        const DATA_ENTRY_ID: u32 = 201;
+        const TYPIST_ID: u32 = 5;
+
+        async fn take_over(pool: SqlitePool, entry_number: EntryNumber, user_id: u32) -> Response {
+            let user = User::test_user(Role::CoordinatorGSB, UserId::from(3));
+            data_entry_take_over(
+                user.clone(),
+                State(pool),
+                State(ClaimTimeout::default()),
+                Path((DataEntryId::from(DATA_ENTRY_ID), entry_number)),
+                AuditService::new(Some(user), None),
+                DataEntryTakeOverRequest {
+                    user_id: UserId::from(user_id),
+                },
+            )
+            .await
+            .into_response()
+        }
+
+        /// Claim and save the first entry, last saved the given number of minutes ago
+        async fn save_first_entry(pool: SqlitePool, minutes_ago: i64) {
+            let data_entry_id = DataEntryId::from(DATA_ENTRY_ID);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::FirstEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let mut request_body = example_data_entry();
+            request_body.progress = 60;
+            request_body.client_state = ClientState::new_from_str(Some("{\"page\":3}")).unwrap();
+            let response = save(
+                pool.clone(),
+                request_body,
+                data_entry_id,
+                EntryNumber::FirstEntry,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            set_last_saved(pool, minutes_ago).await;
+        }
+
+        /// Set the last save of the data entry to the given number of minutes ago
+        async fn set_last_saved(pool: SqlitePool, minutes_ago: i64) {
+            sqlx::query("UPDATE data_entries SET updated_at = ? WHERE id = ?")
+                .bind(Utc::now() - TimeDelta::minutes(minutes_ago))
+                .bind(DataEntryId::from(DATA_ENTRY_ID))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        async fn error_reference(response: Response) -> ErrorReference {
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            serde_json::from_slice::<ErrorResponse>(&body)
+                .unwrap()
+                .reference
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+        async fn test_take_over_abandoned_first_entry(pool: SqlitePool) {
+            save_first_entry(pool.clone(), 45).await;
+
+            let response = take_over(pool.clone(), EntryNumber::FirstEntry, TYPIST_ID).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let mut conn = pool.acquire().await.unwrap();
+            let data_entry = data_entry_repo::get(&mut conn, DataEntryId::from(DATA_ENTRY_ID))
+                .await
+                .unwrap();
+            let DataEntryStatus::FirstEntryInProgress(state) = data_entry.state.0 else {
+                panic!("Expected entry to be in FirstEntryInProgress state");
+            };
+            assert_eq!(state.first_entry_user_id, UserId::from(TYPIST_ID));
+            assert_eq!(state.progress, 60);
+            assert_eq!(state.first_entry, example_results());
+            assert_eq!(
+                state.client_state,
+                ClientState::new_from_str(Some("{\"page\":3}")).unwrap()
+            );
+
+            audit_log::assert_last_event(
+                &mut conn,
+                AuditEventType::DataEntryTakenOver,
+                AuditEventLevel::Warning,
+                serde_json::json!({
+                    "data_entry_id": DATA_ENTRY_ID,
+                    "entry_number": 1,
+                    "previous_user_id": 1,
+                    "user_id": TYPIST_ID,
+                }),
+            )
+            .await;
+
+            let revisions =
+                data_entry_revision_repo::list(&mut conn, DataEntryId::from(DATA_ENTRY_ID))
+                    .await
+                    .unwrap();
+            assert_eq!(
+                revisions.last().unwrap().action,
+                DataEntryRevisionAction::TakeOver
+            );
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+        async fn test_take_over_claim_not_abandoned(pool: SqlitePool) {
+            save_first_entry(pool.clone(), 5).await;
+
+            let response = take_over(pool.clone(), EntryNumber::FirstEntry, TYPIST_ID).await;
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+            assert_eq!(
+                error_reference(response).await,
+                ErrorReference::DataEntryClaimNotAbandoned
+            );
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+        async fn test_take_over_wrong_entry_number(pool: SqlitePool) {
+            save_first_entry(pool.clone(), 45).await;
+
+            let response = take_over(pool.clone(), EntryNumber::SecondEntry, TYPIST_ID).await;
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+            assert_eq!(
+                error_reference(response).await,
+                ErrorReference::InvalidStateTransition
+            );
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+        async fn test_take_over_not_a_typist(pool: SqlitePool) {
+            save_first_entry(pool.clone(), 45).await;
+
+            // user 4 is a coordinator
+            let response = take_over(pool.clone(), EntryNumber::FirstEntry, 4).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+        async fn test_take_over_moves_assignment(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(DATA_ENTRY_ID);
+            let mut conn = pool.acquire().await.unwrap();
+            // the claim helper claims the first entry as user 1
+            data_entry_assignment_repo::assign(
+                &mut conn,
+                data_entry_id,
+                EntryNumber::FirstEntry,
+                UserId::from(1),
+                UserId::from(3),
+            )
+            .await
+            .unwrap();
+            save_first_entry(pool.clone(), 45).await;
+
+            let response = take_over(pool.clone(), EntryNumber::FirstEntry, TYPIST_ID).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let assignment =
+                data_entry_assignment_repo::get(&mut conn, data_entry_id, EntryNumber::FirstEntry)
+                    .await
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(assignment.user_id, UserId::from(TYPIST_ID));
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+        async fn test_take_over_abandoned_correction(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(DATA_ENTRY_ID);
+            finalise_different_entries(pool.clone()).await;
+            let response = resolve_differences(
+                pool.clone(),
+                data_entry_id,
+                ResolveDifferencesAction::KeepSecondAndCorrectFirst,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+            set_last_saved(pool.clone(), 45).await;
+
+            let response = take_over(pool.clone(), EntryNumber::FirstEntry, TYPIST_ID).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let mut conn = pool.acquire().await.unwrap();
+            let data_entry = data_entry_repo::get(&mut conn, data_entry_id)
+                .await
+                .unwrap();
+            let DataEntryStatus::FirstEntryCorrection(state) = data_entry.state.0 else {
+                panic!("Expected entry to be in FirstEntryCorrection state");
+            };
+            assert_eq!(state.first_entry_user_id, UserId::from(TYPIST_ID));
+            assert_eq!(state.second_entry_user_id, UserId::from(2));
+        }
+
+        #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+        async fn test_take_over_abandoned_third_entry(pool: SqlitePool) {
+            let data_entry_id = DataEntryId::from(DATA_ENTRY_ID);
+            finalise_different_entries(pool.clone()).await;
+            let response = resolve_differences(
+                pool.clone(),
+                data_entry_id,
+                ResolveDifferencesAction::Arbitrate,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let response = claim(pool.clone(), data_entry_id, EntryNumber::ThirdEntry).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            set_last_saved(pool.clone(), 45).await;
+
+            let response = take_over(pool.clone(), EntryNumber::ThirdEntry, TYPIST_ID).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let mut conn = pool.acquire().await.unwrap();
+            let data_entry = data_entry_repo::get(&mut conn, data_entry_id)
+                .await
+                .unwrap();
+            assert_eq!(
+                data_entry
+                    .state
+                    .0
+                    .get_entry_user_id(EntryNumber::ThirdEntry),
+                Some(UserId::from(TYPIST_ID))
+            );
+
+            audit_log::assert_last_event(
+                &mut conn,
+                AuditEventType::DataEntryTakenOver,
+                AuditEventLevel::Warning,
+                serde_json::json!({
+                    "data_entry_id": DATA_ENTRY_ID,
+                    "entry_number": 3,
+                    "previous_user_id": 3,
+                    "user_id": TYPIST_ID,
+                }),
+            )
+            .await;
+        }
+    }
 }
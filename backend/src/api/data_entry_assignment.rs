@@ -0,0 +1,806 @@
+use axum::{
+    Json,
+    extract::{FromRequest, Path, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::{SqliteConnection, SqlitePool};
+use utoipa::ToSchema;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::{
+    APIError, AppState, SqlitePoolExt,
+    api::{
+        data_entry::{ClaimDataEntryResponse, ElectionStatusResponseEntry, claim_data_entry},
+        middleware::authentication::RouteAuthorization,
+    },
+    domain::{
+        data_entry::DataEntryId,
+        data_entry_assignment::{DataEntryAssignment, DataEntryAssignmentTarget},
+        election::{CommitteeCategory, ElectionId},
+        entry_number::EntryNumber,
+        role::Role,
+    },
+    error::{ErrorReference, ErrorResponse},
+    infra::audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType, AuditService},
+    repository::{
+        committee_session_repo, data_entry_assignment_repo, data_entry_repo, election_repo,
+        user_repo::{self, User, UserId},
+    },
+    service::election_statuses,
+};
+
+pub fn router() -> OpenApiRouter<AppState> {
+    use Role::*;
+
+    const TYPIST: &[Role] = &[TypistCSB, TypistGSB];
+    const COORDINATOR: &[Role] = &[CoordinatorCSB, CoordinatorGSB];
+    const COORDINATOR_AND_TYPIST: &[Role] = &[CoordinatorCSB, CoordinatorGSB, TypistCSB, TypistGSB];
+
+    OpenApiRouter::default()
+        .routes(routes!(data_entry_assignment_list).authorize(COORDINATOR_AND_TYPIST))
+        .routes(routes!(data_entry_assign).authorize(COORDINATOR))
+        .routes(routes!(data_entry_unassign).authorize(COORDINATOR))
+        .routes(routes!(data_entry_claim_next).authorize(TYPIST))
+}
+
+#[derive(Serialize)]
+struct DataEntriesAssignedAuditData {
+    user_id: UserId,
+    entry_number: EntryNumber,
+    data_entry_ids: Vec<DataEntryId>,
+}
+impl AsAuditEvent for DataEntriesAssignedAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::DataEntryAssigned;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Info;
+}
+
+#[derive(Serialize)]
+struct DataEntryUnassignedAuditData(DataEntryAssignment);
+impl AsAuditEvent for DataEntryUnassignedAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::DataEntryUnassigned;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Info;
+}
+
+/// Data entry assignments response
+#[derive(Serialize, Deserialize, ToSchema, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DataEntryAssignmentListResponse {
+    pub assignments: Vec<DataEntryAssignment>,
+}
+
+/// Request structure for assigning data entries to a typist
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, FromRequest)]
+#[from_request(via(axum::Json), rejection(APIError))]
+#[serde(deny_unknown_fields)]
+pub struct AssignDataEntriesRequest {
+    /// Typist to assign the data entries to
+    pub user_id: UserId,
+    /// Entry number (first, second or third data entry)
+    #[schema(value_type = u8)]
+    pub entry_number: EntryNumber,
+    pub target: DataEntryAssignmentTarget,
+}
+
+/// Response structure for claiming the next assigned data entry
+#[derive(Serialize, Deserialize, ToSchema, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ClaimNextDataEntryResponse {
+    pub data_entry_id: DataEntryId,
+    /// Entry number (first, second or third data entry)
+    #[schema(value_type = u8)]
+    pub entry_number: EntryNumber,
+    pub data_entry: ClaimDataEntryResponse,
+}
+
+/// All entries of a data entry need to be done by different typists, so a typist cannot be
+/// assigned to an entry if they did or are assigned to another entry of the same data entry
+pub async fn check_different_users(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+    user_id: UserId,
+) -> Result<(), APIError> {
+    let state = data_entry_repo::get_status(conn, data_entry_id).await?;
+    let assignments = data_entry_assignment_repo::list_for_data_entry(conn, data_entry_id).await?;
+
+    let same_user = [
+        EntryNumber::FirstEntry,
+        EntryNumber::SecondEntry,
+        EntryNumber::ThirdEntry,
+    ]
+    .into_iter()
+    .filter(|other| *other != entry_number)
+    .any(|other| {
+        state.get_entry_user_id(other) == Some(user_id)
+            || assignments
+                .iter()
+                .any(|a| a.entry_number == other && a.user_id == user_id)
+    });
+
+    if same_user {
+        return Err(APIError::Conflict(
+            "Entries of the same data entry must be assigned to different typists".to_string(),
+            ErrorReference::DataEntryAssignmentSameUser,
+        ));
+    }
+
+    Ok(())
+}
+
+/// An entry that another typist is working on cannot be assigned, because that typist would
+/// no longer be able to save or finalise it
+async fn check_not_in_progress(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+    user_id: UserId,
+) -> Result<(), APIError> {
+    let state = data_entry_repo::get_status(conn, data_entry_id).await?;
+    match state.get_entry_in_progress_user_id(entry_number) {
+        Some(in_progress_user_id) if in_progress_user_id != user_id => Err(APIError::Conflict(
+            "Data entry is in progress by another typist".to_string(),
+            ErrorReference::DataEntryAlreadyClaimed,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Get the typist to assign data entries to, who needs to be a typist of the committee
+pub async fn get_typist(
+    conn: &mut SqliteConnection,
+    user_id: UserId,
+    committee_category: CommitteeCategory,
+) -> Result<User, APIError> {
+    let typist = user_repo::get_by_id(conn, user_id)
+        .await?
+        .ok_or(APIError::NotFound(
+            "User not found".to_string(),
+            ErrorReference::UserNotFound,
+        ))?;
+
+    if !typist.role().is_typist() || typist.role().is_authorized(committee_category).is_err() {
+        return Err(APIError::BadRequest(
+            "Data entries can only be assigned to a typist of this election".to_string(),
+            ErrorReference::InvalidData,
+        ));
+    }
+
+    Ok(typist)
+}
+
+/// Find the data entries of the polling stations or sub-committees of the target.
+/// All numbers that are explicitly part of the target need to exist.
+fn resolve_target(
+    statuses: &[ElectionStatusResponseEntry],
+    target: &DataEntryAssignmentTarget,
+) -> Result<Vec<DataEntryId>, APIError> {
+    let missing_number = target.numbers().into_iter().find(|number| {
+        !statuses
+            .iter()
+            .any(|entry| entry.source.number() == *number)
+    });
+    if let Some(number) = missing_number {
+        return Err(APIError::NotFound(
+            format!("{number} not found"),
+            ErrorReference::EntryNotFound,
+        ));
+    }
+
+    let data_entry_ids: Vec<DataEntryId> = statuses
+        .iter()
+        .filter(|entry| target.includes(entry.source.number()))
+        .map(|entry| entry.data_entry_id)
+        .collect();
+    if data_entry_ids.is_empty() {
+        return Err(APIError::NotFound(
+            "No data entries found to assign".to_string(),
+            ErrorReference::EntryNotFound,
+        ));
+    }
+
+    Ok(data_entry_ids)
+}
+
+/// List the data entry assignments of the current committee session of an election.
+/// Typists only get the data entries that are assigned to them.
+#[utoipa::path(
+    get,
+    path = "/api/elections/{election_id}/data_entry_assignments",
+    responses(
+        (status = 200, description = "Data entry assignments", body = DataEntryAssignmentListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+async fn data_entry_assignment_list(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+) -> Result<Json<DataEntryAssignmentListResponse>, APIError> {
+    let mut conn = pool.acquire().await?;
+
+    let election = election_repo::get(&mut conn, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let committee_session =
+        committee_session_repo::get_election_committee_session(&mut conn, election_id).await?;
+    let mut assignments = data_entry_assignment_repo::list(&mut conn, committee_session.id).await?;
+
+    if user.role().is_typist() {
+        assignments.retain(|a| a.user_id == user.id());
+    }
+
+    Ok(Json(DataEntryAssignmentListResponse { assignments }))
+}
+
+/// Assign the first, second or third entry of the data entries of polling stations or
+/// sub-committees to a typist, replacing any existing assignment of these entries
+#[utoipa::path(
+    post,
+    path = "/api/elections/{election_id}/data_entry_assignments",
+    request_body = AssignDataEntriesRequest,
+    responses(
+        (status = 200, description = "Data entries assigned successfully", body = DataEntryAssignmentListResponse),
+        (status = 400, description = "Bad request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 409, description = "Request cannot be completed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+async fn data_entry_assign(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+    audit_service: AuditService,
+    request: AssignDataEntriesRequest,
+) -> Result<Json<DataEntryAssignmentListResponse>, APIError> {
+    let mut tx = pool.begin_immediate().await?;
+
+    let election = election_repo::get(&mut tx, election_id).await?;
+    let committee_category = election.committee_category;
+    user.role().is_authorized(committee_category)?;
+
+    let typist = get_typist(&mut tx, request.user_id, committee_category).await?;
+
+    let committee_session =
+        committee_session_repo::get_election_committee_session(&mut tx, election_id).await?;
+    let statuses = election_statuses(&mut tx, &election.into(), &committee_session).await?;
+    let data_entry_ids = resolve_target(&statuses, &request.target)?;
+
+    let mut assignments = Vec::with_capacity(data_entry_ids.len());
+    for data_entry_id in &data_entry_ids {
+        check_different_users(&mut tx, *data_entry_id, request.entry_number, typist.id()).await?;
+        check_not_in_progress(&mut tx, *data_entry_id, request.entry_number, typist.id()).await?;
+        assignments.push(
+            data_entry_assignment_repo::assign(
+                &mut tx,
+                *data_entry_id,
+                request.entry_number,
+                typist.id(),
+                user.id(),
+            )
+            .await?,
+        );
+    }
+
+    audit_service
+        .log(
+            &mut tx,
+            &DataEntriesAssignedAuditData {
+                user_id: typist.id(),
+                entry_number: request.entry_number,
+                data_entry_ids,
+            },
+            None,
+        )
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(Json(DataEntryAssignmentListResponse { assignments }))
+}
+
+/// Remove the assignment of an entry of a data entry, so any typist can claim it again
+#[utoipa::path(
+    delete,
+    path = "/api/data_entries/{data_entry_id}/{entry_number}/assignment",
+    responses(
+        (status = 204, description = "Data entry unassigned successfully"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("data_entry_id" = DataEntryId, description = "Data entry database id"),
+        ("entry_number" = u8, description = "Data entry number (first, second or third data entry)"),
+    ),
+)]
+async fn data_entry_unassign(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path((data_entry_id, entry_number)): Path<(DataEntryId, EntryNumber)>,
+    audit_service: AuditService,
+) -> Result<StatusCode, APIError> {
+    let mut tx = pool.begin_immediate().await?;
+
+    let context = data_entry_repo::resolve_source(&mut tx, data_entry_id).await?;
+    user.role()
+        .is_authorized(context.election.committee_category)?;
+
+    let Some(assignment) =
+        data_entry_assignment_repo::get(&mut tx, data_entry_id, entry_number).await?
+    else {
+        return Err(APIError::NotFound(
+            "Data entry is not assigned".to_string(),
+            ErrorReference::EntryNotFound,
+        ));
+    };
+
+    data_entry_assignment_repo::unassign(&mut tx, data_entry_id, entry_number).await?;
+
+    audit_service
+        .log(&mut tx, &DataEntryUnassignedAuditData(assignment), None)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Claim the next data entry that is assigned to the typist, returning any existing progress.
+/// Entries the typist is already working on come first, then the polling station or
+/// sub-committee with the lowest number.
+#[utoipa::path(
+    post,
+    path = "/api/elections/{election_id}/data_entries/claim_next",
+    responses(
+        (status = 200, description = "Data entry claimed successfully", body = ClaimNextDataEntryResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "No assigned data entry available", body = ErrorResponse),
+        (status = 409, description = "Request cannot be completed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+async fn data_entry_claim_next(
+    user: User,
+    State(pool): State<SqlitePool>,
+    Path(election_id): Path<ElectionId>,
+    audit_service: AuditService,
+) -> Result<Json<ClaimNextDataEntryResponse>, APIError> {
+    let mut tx = pool.begin_immediate().await?;
+
+    let election = election_repo::get(&mut tx, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    let committee_session =
+        committee_session_repo::get_election_committee_session(&mut tx, election_id).await?;
+    let statuses = election_statuses(&mut tx, &election.into(), &committee_session).await?;
+    let assignments = data_entry_assignment_repo::list(&mut tx, committee_session.id).await?;
+
+    let mut next = None;
+    for assignment in assignments.iter().filter(|a| a.user_id == user.id()) {
+        let Some(entry) = statuses
+            .iter()
+            .find(|entry| entry.data_entry_id == assignment.data_entry_id)
+        else {
+            continue;
+        };
+
+        let state = data_entry_repo::get_status(&mut tx, assignment.data_entry_id).await?;
+        if state.is_claimable_by(assignment.entry_number, user.id()) {
+            let not_started = state.get_entry_user_id(assignment.entry_number) != Some(user.id());
+            let key = (not_started, entry.source.number());
+            if next.as_ref().is_none_or(|(next_key, _)| key < *next_key) {
+                next = Some((key, assignment));
+            }
+        }
+    }
+
+    let Some((_, assignment)) = next else {
+        return Err(APIError::NotFound(
+            "No assigned data entry available".to_string(),
+            ErrorReference::EntryNotFound,
+        ));
+    };
+
+    let data_entry = claim_data_entry(
+        &mut tx,
+        &audit_service,
+        &user,
+        assignment.data_entry_id,
+        assignment.entry_number,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Json(ClaimNextDataEntryResponse {
+        data_entry_id: assignment.data_entry_id,
+        entry_number: assignment.entry_number,
+        data_entry,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::{IntoResponse, Response};
+    use http_body_util::BodyExt;
+    use test_log::test;
+
+    use super::*;
+    use crate::{domain::data_entry::DataEntryStatusName, infra::audit_log};
+
+    const ELECTION_ID: u32 = 2;
+    const COORDINATOR_ID: u32 = 3;
+    const TYPIST_1_ID: u32 = 5;
+    const TYPIST_2_ID: u32 = 6;
+
+    fn coordinator() -> User {
+        User::test_user(Role::CoordinatorGSB, UserId::from(COORDINATOR_ID))
+    }
+
+    fn typist(user_id: u32) -> User {
+        User::test_user(Role::TypistGSB, UserId::from(user_id))
+    }
+
+    async fn assign(
+        pool: SqlitePool,
+        user_id: u32,
+        entry_number: EntryNumber,
+        target: DataEntryAssignmentTarget,
+    ) -> Response {
+        data_entry_assign(
+            coordinator(),
+            State(pool),
+            Path(ElectionId::from(ELECTION_ID)),
+            AuditService::new(Some(coordinator()), None),
+            AssignDataEntriesRequest {
+                user_id: UserId::from(user_id),
+                entry_number,
+                target,
+            },
+        )
+        .await
+        .into_response()
+    }
+
+    async fn list(pool: SqlitePool, user: User) -> Vec<DataEntryAssignment> {
+        let response =
+            data_entry_assignment_list(user, State(pool), Path(ElectionId::from(ELECTION_ID)))
+                .await
+                .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: DataEntryAssignmentListResponse = serde_json::from_slice(&body).unwrap();
+        result.assignments
+    }
+
+    async fn claim_next(pool: SqlitePool, user_id: u32) -> Response {
+        let user = typist(user_id);
+        data_entry_claim_next(
+            user.clone(),
+            State(pool),
+            Path(ElectionId::from(ELECTION_ID)),
+            AuditService::new(Some(user), None),
+        )
+        .await
+        .into_response()
+    }
+
+    async fn claim(
+        pool: SqlitePool,
+        user_id: u32,
+        data_entry_id: DataEntryId,
+    ) -> Result<ClaimDataEntryResponse, APIError> {
+        let user = typist(user_id);
+        let mut conn = pool.acquire().await.unwrap();
+        claim_data_entry(
+            &mut conn,
+            &AuditService::new(Some(user.clone()), None),
+            &user,
+            data_entry_id,
+            EntryNumber::FirstEntry,
+        )
+        .await
+    }
+
+    async fn error_reference(response: Response) -> ErrorReference {
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        result.reference
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_assign_range(pool: SqlitePool) {
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            DataEntryAssignmentTarget::PollingStationRange { from: 30, to: 40 },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let assignments = list(pool.clone(), coordinator()).await;
+        assert_eq!(assignments.len(), 2);
+        assert!(
+            assignments
+                .iter()
+                .all(|a| a.user_id == UserId::from(TYPIST_1_ID)
+                    && a.assigned_by == UserId::from(COORDINATOR_ID)
+                    && a.entry_number == EntryNumber::FirstEntry)
+        );
+
+        let mut conn = pool.acquire().await.unwrap();
+        audit_log::assert_last_event(
+            &mut conn,
+            AuditEventType::DataEntryAssigned,
+            AuditEventLevel::Info,
+            serde_json::json!({
+                "user_id": TYPIST_1_ID,
+                "entry_number": 1,
+                "data_entry_ids": [201, 202],
+            }),
+        )
+        .await;
+
+        // Typists only see their own assignments
+        assert_eq!(list(pool.clone(), typist(TYPIST_1_ID)).await.len(), 2);
+        assert!(list(pool.clone(), typist(TYPIST_2_ID)).await.is_empty());
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_reassign(pool: SqlitePool) {
+        let target = DataEntryAssignmentTarget::PollingStations { numbers: vec![33] };
+        for user_id in [TYPIST_1_ID, TYPIST_2_ID] {
+            let response = assign(
+                pool.clone(),
+                user_id,
+                EntryNumber::FirstEntry,
+                target.clone(),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let assignments = list(pool.clone(), coordinator()).await;
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].data_entry_id, DataEntryId::from(201));
+        assert_eq!(assignments[0].user_id, UserId::from(TYPIST_2_ID));
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_assign_unknown_polling_station(pool: SqlitePool) {
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            DataEntryAssignmentTarget::PollingStations {
+                numbers: vec![33, 35],
+            },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // Sub-committees do not exist in a GSB election
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            DataEntryAssignmentTarget::SubCommittees { numbers: vec![1] },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        assert!(list(pool.clone(), coordinator()).await.is_empty());
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_assign_not_a_typist(pool: SqlitePool) {
+        // Coordinator and a typist of a CSB
+        for user_id in [COORDINATOR_ID, 9] {
+            let response = assign(
+                pool.clone(),
+                user_id,
+                EntryNumber::FirstEntry,
+                DataEntryAssignmentTarget::PollingStations { numbers: vec![33] },
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_assign_same_user_to_first_and_second_entry(pool: SqlitePool) {
+        let target = DataEntryAssignmentTarget::PollingStations { numbers: vec![33] };
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            target.clone(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::SecondEntry,
+            target.clone(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            error_reference(response).await,
+            ErrorReference::DataEntryAssignmentSameUser
+        );
+
+        let response = assign(pool.clone(), TYPIST_2_ID, EntryNumber::SecondEntry, target).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_assign_in_progress_by_other_user(pool: SqlitePool) {
+        claim(pool.clone(), TYPIST_1_ID, DataEntryId::from(201))
+            .await
+            .unwrap();
+
+        let target = DataEntryAssignmentTarget::PollingStations { numbers: vec![33] };
+        let response = assign(
+            pool.clone(),
+            TYPIST_2_ID,
+            EntryNumber::FirstEntry,
+            target.clone(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            error_reference(response).await,
+            ErrorReference::DataEntryAlreadyClaimed
+        );
+        assert!(list(pool.clone(), coordinator()).await.is_empty());
+
+        // The typist working on the entry can be assigned to it
+        let response = assign(pool.clone(), TYPIST_1_ID, EntryNumber::FirstEntry, target).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_claim_assigned_to_other_user(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(201);
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            DataEntryAssignmentTarget::PollingStations { numbers: vec![33] },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let Err(APIError::Conflict(_, reference)) =
+            claim(pool.clone(), TYPIST_2_ID, data_entry_id).await
+        else {
+            panic!("expected a conflict");
+        };
+        assert_eq!(reference, ErrorReference::DataEntryAssignedToOtherUser);
+
+        assert!(
+            claim(pool.clone(), TYPIST_1_ID, data_entry_id)
+                .await
+                .is_ok()
+        );
+
+        // Data entries that are not assigned can be claimed by anyone
+        assert!(
+            claim(pool.clone(), TYPIST_2_ID, DataEntryId::from(202))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_unassign(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(201);
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            DataEntryAssignmentTarget::PollingStations { numbers: vec![33] },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let unassign = || {
+            data_entry_unassign(
+                coordinator(),
+                State(pool.clone()),
+                Path((data_entry_id, EntryNumber::FirstEntry)),
+                AuditService::new(Some(coordinator()), None),
+            )
+        };
+        let response = unassign().await.into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(list(pool.clone(), coordinator()).await.is_empty());
+
+        let mut conn = pool.acquire().await.unwrap();
+        let events = audit_log::list_event_names(&mut conn).await.unwrap();
+        assert_eq!(events.last().unwrap(), "DataEntryUnassigned");
+
+        let response = unassign().await.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        assert!(
+            claim(pool.clone(), TYPIST_2_ID, data_entry_id)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_claim_next(pool: SqlitePool) {
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            DataEntryAssignmentTarget::PollingStations {
+                numbers: vec![34, 33],
+            },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // The first entry of polling station 34 is already started by the typist
+        claim(pool.clone(), TYPIST_1_ID, DataEntryId::from(202))
+            .await
+            .unwrap();
+
+        let response = claim_next(pool.clone(), TYPIST_1_ID).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: ClaimNextDataEntryResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.data_entry_id, DataEntryId::from(202));
+        assert_eq!(result.entry_number, EntryNumber::FirstEntry);
+
+        // Nothing is assigned to the other typist
+        let response = claim_next(pool.clone(), TYPIST_2_ID).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_claim_next_lowest_number(pool: SqlitePool) {
+        let response = assign(
+            pool.clone(),
+            TYPIST_1_ID,
+            EntryNumber::FirstEntry,
+            DataEntryAssignmentTarget::PollingStationRange { from: 33, to: 34 },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = claim_next(pool.clone(), TYPIST_1_ID).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let result: ClaimNextDataEntryResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.data_entry_id, DataEntryId::from(201));
+        assert_eq!(
+            result.data_entry.status,
+            DataEntryStatusName::FirstEntryInProgress
+        );
+    }
+}
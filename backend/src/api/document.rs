@@ -106,6 +106,7 @@ async fn election_download_n_10_2(
             );
 
             Ok(ModelN10_2Input {
+                language: election.model_language,
                 election: election.clone(),
                 polling_station: ps.clone(),
             }
@@ -195,6 +196,7 @@ async fn election_download_na_31_2_bijlage1(
 
             Ok(ModelNa31_2Bijlage1Input {
                 candidates_tables: CandidatesTables::new(&election)?,
+                language: election.model_language,
                 election: election.clone().into(),
                 polling_station: ps.clone(),
             }
@@ -255,6 +257,7 @@ async fn election_download_na_31_2_inlegvel(
     let name = "Model_Na_31_2_Inlegvel.pdf".to_string();
 
     let input = ModelNa31_2InlegvelInput {
+        language: election.model_language,
         election: election.into(),
     }
     .to_pdf_file_model(name.clone());
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use axum::{
     Json,
     extract::{Path, State},
@@ -26,18 +28,22 @@ use crate::{
         committee_session_status::CommitteeSessionStatus,
         election::{
             CommitteeCategory, CommitteeDistrict, Election, ElectionCategory, ElectionDomain,
-            ElectionId, ElectionNumberOfVotersChangeRequest, ElectionWithPoliticalGroups,
-            NewElection, RegionKey, VoteCountingMethod,
+            ElectionId, ElectionModelLanguageChangeRequest, ElectionNumberOfVotersChangeRequest,
+            ElectionWithPoliticalGroups, NewElection, RegionKey, VoteCountingMethod,
         },
         investigation::PollingStationInvestigation,
+        models::ModelLanguage,
         polling_station::{PollingStationRequest, PollingStationResponse, PollingStationsRequest},
         role::Role,
+        sub_committee::SubCommitteeNumber,
     },
     eml::{
-        EMLImportError, EmlHash, RedactedEmlHash, number_of_voters_from_polling_stations_eml,
-        parse_polling_stations_eml_str, polling_stations_eml_matches_election,
-        polling_stations_from_eml, polling_stations_from_eml_str,
+        EMLImportError, EmlHash, RedactedEmlHash, committees::ElectionTreeDetails,
+        number_of_voters_from_polling_stations_eml, parse_polling_stations_eml_str,
+        polling_stations_eml_matches_election, polling_stations_from_eml,
+        polling_stations_from_eml_str,
     },
+    error::ErrorReference,
     infra::audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType, AuditService},
     repository::{committee_session_repo, election_repo, user_repo::User},
     service::{create_sub_committee, list_polling_stations_for_session},
@@ -49,6 +55,7 @@ pub fn router() -> OpenApiRouter<AppState> {
     const ALL_ROLES: &[Role] = Role::VARIANTS;
     const ADMIN: &[Role] = &[Administrator];
     const ADMIN_GSB_COORDINATOR: &[Role] = &[Administrator, CoordinatorGSB];
+    const ADMIN_COORDINATOR: &[Role] = &[Administrator, CoordinatorGSB, CoordinatorCSB];
 
     OpenApiRouter::default()
         .routes(routes!(election_import_validate).authorize(ADMIN))
@@ -56,6 +63,7 @@ pub fn router() -> OpenApiRouter<AppState> {
         .routes(routes!(election_list).authorize(ALL_ROLES))
         .routes(routes!(election_details).authorize(ALL_ROLES))
         .routes(routes!(election_number_of_voters_change).authorize(ADMIN_GSB_COORDINATOR))
+        .routes(routes!(election_model_language_change).authorize(ADMIN_COORDINATOR))
 }
 
 /// Election list response
@@ -91,6 +99,7 @@ pub struct ElectionAuditData {
     pub election_location: String,
     pub election_authority_id: String,
     pub election_authority_name: String,
+    pub election_model_language: ModelLanguage,
     pub election_district: CommitteeDistrict,
     pub election_domain: Option<ElectionDomain>,
     pub election_category: String,
@@ -111,6 +120,7 @@ impl From<Election> for ElectionAuditData {
             election_location: value.location,
             election_authority_id: value.authority_id,
             election_authority_name: value.authority_name,
+            election_model_language: value.model_language,
             election_district: value.district,
             election_domain: value.domain,
             election_category: value.category.to_string(),
@@ -276,6 +286,55 @@ pub async fn election_number_of_voters_change(
     }
 }
 
+/// Change the language in which the models of an [Election] are produced.
+/// Frisian can only be chosen when the region of the committee allows it.
+#[utoipa::path(
+    put,
+    path = "/api/elections/{election_id}/model_language",
+    request_body = ElectionModelLanguageChangeRequest,
+    responses(
+        (status = 204, description = "Election model language changed successfully"),
+        (status = 400, description = "Bad request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Election not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("election_id" = ElectionId, description = "Election database id"),
+    ),
+)]
+pub async fn election_model_language_change(
+    user: User,
+    State(pool): State<SqlitePool>,
+    audit_service: AuditService,
+    Path(election_id): Path<ElectionId>,
+    Json(request): Json<ElectionModelLanguageChangeRequest>,
+) -> Result<StatusCode, APIError> {
+    let mut tx = pool.begin_immediate().await?;
+
+    let election = election_repo::get(&mut tx, election_id).await?;
+    user.role().is_authorized(election.committee_category)?;
+
+    if request.model_language == ModelLanguage::Frisian && !election.frisian_export_allowed {
+        return Err(APIError::BadRequest(
+            "The region of this election does not allow models in Frisian".to_string(),
+            ErrorReference::InvalidData,
+        ));
+    }
+
+    let election =
+        election_repo::change_model_language(&mut tx, election_id, request.model_language).await?;
+
+    audit_service
+        .log(&mut tx, &ElectionUpdatedAuditData(election.into()), None)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
 #[serde(tag = "committee_category")]
 pub enum ElectionCreationValidateRequest {
@@ -392,7 +451,7 @@ fn validate_gsb_election(
     }
 
     let mut hash = RedactedEmlHash::from(edu.election_data.as_bytes());
-    let mut election = parse_election_candidates_eml(
+    let (mut election, _) = parse_election_candidates_eml(
         &edu.election_data,
         Some((CommitteeCategory::GSB, None)),
         edu.candidate_data.as_deref(),
@@ -448,7 +507,7 @@ fn validate_csb_election(
     }
 
     let mut hash = RedactedEmlHash::from(edu.election_data.as_bytes());
-    let mut election = parse_election_candidates_eml(
+    let (mut election, _) = parse_election_candidates_eml(
         &edu.election_data,
         Some((CommitteeCategory::CSB, None)),
         edu.candidate_data.as_deref(),
@@ -552,7 +611,7 @@ async fn import_gsb_election(
     let election_data_hash = check_hash(edu.election_data.as_bytes(), Some(&edu.election_hash))?;
     let candidate_data_hash = check_hash(edu.candidate_data.as_bytes(), Some(&edu.candidate_hash))?;
 
-    let mut new_election = parse_election_candidates_eml(
+    let (mut new_election, election_tree) = parse_election_candidates_eml(
         &edu.election_data,
         Some((CommitteeCategory::GSB, None)),
         Some(&edu.candidate_data),
@@ -574,6 +633,7 @@ async fn import_gsb_election(
         &mut tx,
         audit_service,
         new_election,
+        &election_tree,
         election_data_hash,
         candidate_data_hash,
     )
@@ -608,15 +668,20 @@ async fn import_csb_election(
     let election_data_hash = check_hash(edu.election_data.as_bytes(), Some(&edu.election_hash))?;
     let candidate_data_hash = check_hash(edu.candidate_data.as_bytes(), Some(&edu.candidate_hash))?;
 
-    let mut new_election = parse_election_candidates_eml(
+    let (mut new_election, election_tree) = parse_election_candidates_eml(
         &edu.election_data,
         Some((CommitteeCategory::CSB, None)),
         Some(&edu.candidate_data),
     )?;
-    // PS/WS CSB support will be implemented later
+    // PS/WS CSB support will be implemented later, the TK/EP CSB is not run by a municipality
+    // and referendum results are only entered by the GSB
     if matches!(
         new_election.category,
-        ElectionCategory::Provincial | ElectionCategory::WaterAuthority
+        ElectionCategory::Provincial
+            | ElectionCategory::WaterAuthority
+            | ElectionCategory::NationalParliament
+            | ElectionCategory::EuropeanParliament
+            | ElectionCategory::Referendum
     ) {
         return Err(EMLImportError::CommitteeCategoryForElectionCategoryNotSupported.into());
     }
@@ -627,6 +692,7 @@ async fn import_csb_election(
         &mut tx,
         audit_service,
         new_election,
+        &election_tree,
         election_data_hash,
         candidate_data_hash,
     )
@@ -638,7 +704,7 @@ async fn import_csb_election(
 }
 
 /// Check if the user's entered hash matches the hash of given data
-fn check_hash(
+pub(crate) fn check_hash(
     data: &[u8],
     user_hash: Option<&[String; crate::eml::hash::CHUNK_COUNT]>,
 ) -> Result<[String; crate::eml::hash::CHUNK_COUNT], APIError> {
@@ -651,20 +717,21 @@ fn check_hash(
     Ok(computed)
 }
 
-/// Parse EML_NL 110 election definition and EML_NL 230 candidate list into a [`NewElection`]
+/// Parse EML_NL 110 election definition and EML_NL 230 candidate list into a [`NewElection`],
+/// together with the details of the election tree
 fn parse_election_candidates_eml(
     election_eml_data: &str,
     selected_committee: Option<(CommitteeCategory, Option<RegionKey>)>,
     candidate_eml_data: Option<&str>,
-) -> Result<NewElection, APIError> {
-    let (mut election, _election_tree) =
+) -> Result<(NewElection, ElectionTreeDetails), APIError> {
+    let (mut election, election_tree) =
         NewElection::from_eml_str(election_eml_data, selected_committee)?;
     // TODO: need to pick the right district first before adding candidates
     if let Some(candidate_eml_data) = candidate_eml_data {
         election.add_candidates_from_eml_str(candidate_eml_data)?;
     }
 
-    Ok(election)
+    Ok((election, election_tree))
 }
 
 /// Create an election with a committee session
@@ -672,6 +739,7 @@ async fn create_election_with_committee_session(
     tx: &mut SqliteConnection,
     audit_service: &AuditService,
     new_election: NewElection,
+    election_tree: &ElectionTreeDetails,
     election_data_hash: [String; 16],
     candidate_data_hash: [String; 16],
 ) -> Result<ElectionWithPoliticalGroups, APIError> {
@@ -693,7 +761,7 @@ async fn create_election_with_committee_session(
     )
     .await?;
 
-    create_sub_committees(tx, &election, committee_session.id).await?;
+    create_sub_committees(tx, &election, election_tree, committee_session.id).await?;
 
     // CSB elections have sub committees created at import time, so they can
     // go straight to InPreparation.
@@ -720,40 +788,95 @@ async fn create_election_with_committee_session(
 async fn create_sub_committees(
     tx: &mut SqliteConnection,
     election: &ElectionWithPoliticalGroups,
+    election_tree: &ElectionTreeDetails,
     committee_session_id: CommitteeSessionId,
 ) -> Result<(), APIError> {
     match (election.committee_category, election.category) {
-        (CommitteeCategory::CSB, ElectionCategory::Municipal) => {
-            // TODO: this is not right, we should use the election tree instead
-            let number = election
-                .domain
-                .as_ref()
-                .expect("Municipal elections should have an election domain")
-                .id
-                .as_ref()
-                .expect("Municipal elections should have an election domain id")
-                .parse()
-                .expect("domain_id should be numeric");
+        // The CSB of a public body has the public body as its single GSB, like a municipality
+        (
+            CommitteeCategory::CSB,
+            ElectionCategory::Municipal
+            | ElectionCategory::Island
+            | ElectionCategory::IslandElectoralCollege,
+        ) => {
             create_sub_committee(
                 tx,
                 committee_session_id,
-                number,
+                public_body_sub_committee_number(election)?,
                 &election.location,
                 CommitteeCategory::GSB,
             )
             .await?;
         }
+        // The CSB enters the results of the municipalities (kiescollege niet-ingezetenen) or
+        // polling stations (Eerste Kamer) in the election tree
         (
             CommitteeCategory::CSB,
-            ElectionCategory::Provincial | ElectionCategory::WaterAuthority,
+            ElectionCategory::NonResidentElectoralCollege | ElectionCategory::Senate,
         ) => {
-            todo!("Will be implemented in the future")
+            for (number, name) in gsb_sub_committees(election_tree)? {
+                create_sub_committee(
+                    tx,
+                    committee_session_id,
+                    number,
+                    &name,
+                    CommitteeCategory::GSB,
+                )
+                .await?;
+            }
+        }
+        (
+            CommitteeCategory::CSB,
+            ElectionCategory::Provincial
+            | ElectionCategory::WaterAuthority
+            | ElectionCategory::NationalParliament
+            | ElectionCategory::EuropeanParliament
+            | ElectionCategory::Referendum,
+        ) => {
+            return Err(EMLImportError::CommitteeCategoryForElectionCategoryNotSupported.into());
         }
         (CommitteeCategory::GSB, _) => {}
     }
     Ok(())
 }
 
+/// Number of the sub electoral committee of a municipality or public body, which is the
+/// numeric id of the election domain
+fn public_body_sub_committee_number(
+    election: &ElectionWithPoliticalGroups,
+) -> Result<SubCommitteeNumber, EMLImportError> {
+    election
+        .domain
+        .as_ref()
+        .and_then(|domain| domain.id.as_ref())
+        .ok_or(EMLImportError::MissingElectionDomain)?
+        .parse()
+        .map_err(|_| EMLImportError::InvalidElectionDomainId)
+}
+
+/// Number and name of the sub electoral committee for each GSB in the election tree
+fn gsb_sub_committees(
+    election_tree: &ElectionTreeDetails,
+) -> Result<Vec<(SubCommitteeNumber, String)>, EMLImportError> {
+    let mut numbers = HashSet::new();
+    election_tree
+        .get_committees(CommitteeCategory::GSB)
+        .iter()
+        .map(|committee| {
+            let number = committee
+                .responsible_region
+                .key
+                .number
+                .ok_or(EMLImportError::MissingRegionNumber)?;
+            // Polling station region numbers are only unique within a province
+            if !numbers.insert(number) {
+                return Err(EMLImportError::DuplicateRegionNumber);
+            }
+            Ok((number.into(), committee.managing_authority_name()))
+        })
+        .collect()
+}
+
 impl From<EMLImportError> for APIError {
     fn from(err: EMLImportError) -> Self {
         APIError::EmlImportError(err)
@@ -773,6 +896,10 @@ mod tests {
         extract::{Path, State},
         response::{IntoResponse, Response},
     };
+    use eml_nl::{
+        documents::election_definition::ElectionDefinition,
+        io::{EMLParsingMode, EMLRead as _},
+    };
     use test_log::test;
 
     use super::*;
@@ -780,6 +907,7 @@ mod tests {
         api::tests::{
             assert_committee_category_authorization_err, assert_committee_category_authorization_ok,
         },
+        domain::election::tests::election_fixture,
         repository::user_repo::UserId,
     };
 
@@ -795,6 +923,7 @@ mod tests {
         let results = vec![
             ("details", election_details(user.clone(), State(pool.clone()), Path(election_id)).await.into_response()),
             ("voters", election_number_of_voters_change(user.clone(), State(pool.clone()), audit.clone(), Path(election_id), Json(ElectionNumberOfVotersChangeRequest { number_of_voters: 1000 })).await.into_response()),
+            ("model_language", election_model_language_change(user.clone(), State(pool.clone()), audit.clone(), Path(election_id), Json(ElectionModelLanguageChangeRequest { model_language: ModelLanguage::Dutch })).await.into_response()),
         ];
         results
     }
@@ -810,4 +939,67 @@ mod tests {
         let results = call_handlers(pool, Role::CoordinatorGSB).await;
         assert_committee_category_authorization_ok(results);
     }
+
+    fn senate_election_tree(definition: &str) -> ElectionTreeDetails {
+        let definition = ElectionDefinition::parse_eml(definition, EMLParsingMode::Strict).unwrap();
+        ElectionTreeDetails::from_definition(&definition).unwrap()
+    }
+
+    #[test]
+    fn test_gsb_sub_committees() {
+        let election_tree = senate_election_tree(include_str!(
+            "../eml/tests/definitions/Verkiezingsdefinitie_EK2027.eml.xml"
+        ));
+        let mut numbers: Vec<SubCommitteeNumber> = gsb_sub_committees(&election_tree)
+            .unwrap()
+            .into_iter()
+            .map(|(number, _)| number)
+            .collect();
+        numbers.sort_unstable();
+        assert_eq!(numbers, vec![1, 2, 3, 13, 16]);
+    }
+
+    #[test]
+    fn test_public_body_sub_committee_number() {
+        let mut election = election_fixture(ElectionCategory::Island, CommitteeCategory::CSB, &[1]);
+        election.domain = Some(ElectionDomain {
+            id: Some("1901".to_string()),
+            name: "Bonaire".to_string(),
+        });
+        assert_eq!(public_body_sub_committee_number(&election).unwrap(), 1901);
+
+        election.domain = Some(ElectionDomain {
+            id: Some("BON".to_string()),
+            name: "Bonaire".to_string(),
+        });
+        assert!(matches!(
+            public_body_sub_committee_number(&election),
+            Err(EMLImportError::InvalidElectionDomainId)
+        ));
+
+        election.domain = Some(ElectionDomain {
+            id: None,
+            name: "Bonaire".to_string(),
+        });
+        assert!(matches!(
+            public_body_sub_committee_number(&election),
+            Err(EMLImportError::MissingElectionDomain)
+        ));
+    }
+
+    #[test]
+    fn test_gsb_sub_committees_duplicate_region_number() {
+        // Polling station 2 in another province with the same number as polling station 1
+        let definition =
+            include_str!("../eml/tests/definitions/Verkiezingsdefinitie_EK2027.eml.xml").replace(
+                r#"RegionNumber="2" RegionCategory="STEMBUREAU""#,
+                r#"RegionNumber="1" RegionCategory="STEMBUREAU""#,
+            );
+        let result = ElectionDefinition::parse_eml(&definition, EMLParsingMode::Strict)
+            .ok()
+            .map_err(EMLImportError::from)
+            .and_then(|definition| Ok(ElectionTreeDetails::from_definition(&definition)?))
+            .and_then(|election_tree| gsb_sub_committees(&election_tree));
+        assert!(result.is_err());
+    }
 }
@@ -730,6 +730,7 @@ async fn polling_station_investigation_download_corrigendum_pdf(
 
     let input = ModelNa14_2Bijlage1Input {
         votes_tables,
+        language: election.model_language,
         election: election.into(),
         polling_station,
         previous_results: previous_results.into(),
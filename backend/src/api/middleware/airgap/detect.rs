@@ -400,7 +400,13 @@ mod tests {
         let backup_config =
             crate::infra::backup::BackupConfig::new(backup_dir.path().to_path_buf());
         tokio::spawn(async move {
-            let app = router::create_router(pool, airgap_detection, backup_config).unwrap();
+            let app = router::create_router(
+                pool,
+                airgap_detection,
+                backup_config,
+                crate::infra::claim_expiry::ClaimTimeout::default(),
+            )
+            .unwrap();
 
             axum::serve(
                 listener,
@@ -55,7 +55,7 @@ mod tests {
         api::{authentication::*, middleware::airgap::AirgapDetection, user::*},
         domain::role::Role,
         error::ErrorReference,
-        infra::{audit_log::LogFilter, backup::BackupConfig},
+        infra::{audit_log::LogFilter, backup::BackupConfig, claim_expiry::ClaimTimeout},
         repository::{
             session_repo::{self, Session},
             user_repo::{self, User, UserId},
@@ -71,6 +71,7 @@ mod tests {
             pool: pool.clone(),
             airgap_detection: AirgapDetection::nop(),
             backup_config: BackupConfig::new(backup_dir.path().to_path_buf()),
+            claim_timeout: ClaimTimeout::default(),
         };
 
         Router::from(router())
@@ -4,6 +4,7 @@ pub mod authentication;
 pub mod backup;
 pub mod committee_session;
 pub mod data_entry;
+pub mod data_entry_assignment;
 pub mod document;
 pub mod election;
 pub mod investigation;
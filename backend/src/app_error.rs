@@ -1,3 +1,5 @@
+use crate::infra::{audit_log::AuditLogEventId, backup::BackupError};
+
 #[derive(Debug)]
 pub enum AppError {
     // wrapped errors
@@ -5,12 +7,15 @@ pub enum AppError {
     DatabaseMigration(sqlx::migrate::MigrateError),
     Json(serde_json::Error),
     Io(std::io::Error),
+    Backup(BackupError),
     Environment(tracing_subscriber::filter::FromEnvError),
     StdError(Box<dyn std::error::Error>),
     // server specific
     PortAlreadyInUse(u16),
     PermissionDeniedToBindPort(u16),
     Tls(String),
+    // audit log specific
+    AuditLogChainBroken(AuditLogEventId),
     // sqlite specific
     DatabaseBusy(String),
     DatabaseReadOnly(String),
@@ -25,6 +30,12 @@ impl From<std::io::Error> for AppError {
     }
 }
 
+impl From<BackupError> for AppError {
+    fn from(err: BackupError) -> Self {
+        AppError::Backup(err)
+    }
+}
+
 impl From<sqlx::Error> for AppError {
     fn from(err: sqlx::Error) -> Self {
         AppError::Database(err)
@@ -122,6 +133,7 @@ impl std::fmt::Display for AppError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             AppError::Io(e) => write!(f, "IO error: {}", e),
+            AppError::Backup(e) => write!(f, "Backup error: {}", e),
             AppError::Environment(e) => write!(f, "Environment error: {}", e),
             AppError::Database(e) => write!(f, "Database error: {}", e),
             AppError::Json(e) => write!(f, "Json error: {}", e),
@@ -155,6 +167,10 @@ impl std::fmt::Display for AppError {
             }
             AppError::StdError(e) => write!(f, "{}", e),
             AppError::Tls(e) => write!(f, "TLS error: {}", e),
+            AppError::AuditLogChainBroken(id) => write!(
+                f,
+                "The audit log hash chain is broken at event {id}, the audit log has been changed."
+            ),
         }
     }
 }
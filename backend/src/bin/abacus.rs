@@ -2,11 +2,20 @@ use std::{
     io::ErrorKind,
     net::{Ipv6Addr, SocketAddr},
     process,
+    time::Duration,
 };
 
-use abacus::{AppError, create_sqlite_pool, infra::backup::BackupConfig};
+use abacus::{
+    AppError, create_sqlite_pool,
+    infra::audit_log,
+    infra::backup::{
+        BackupConfig, BackupPassphrase, BackupRetention, BackupSchedule, list_local_backups,
+    },
+    infra::claim_expiry::{ClaimTimeout, DEFAULT_CLAIM_TIMEOUT_MINUTES},
+};
 use clap::Parser;
 use socket2::{Domain, Protocol, Socket, Type};
+use sqlx::SqlitePool;
 use tokio::net::TcpListener;
 use tracing::{error, level_filters::LevelFilter};
 use tracing_subscriber::EnvFilter;
@@ -89,6 +98,55 @@ struct Args {
     #[arg(long, default_value = "backups", env = "ABACUS_BACKUP_DIR")]
     backup_dir: std::path::PathBuf,
 
+    /// List the backups in the backup directory and exit without starting the server
+    #[arg(long)]
+    list_backups: bool,
+
+    /// Restore the database from the given backup in the backup directory and exit without
+    /// starting the server. A backup of the current database is created first.
+    #[arg(long, value_name = "FILENAME")]
+    restore_backup: Option<String>,
+
+    /// Encrypt new backups with a key derived from this passphrase, also needed to restore
+    /// encrypted backups. Prefer the environment variable, so the passphrase is not visible in
+    /// the process list.
+    #[arg(long, env = "ABACUS_BACKUP_PASSPHRASE", hide_env_values = true)]
+    backup_passphrase: Option<String>,
+
+    /// Verify the hash chain of the audit log and exit without starting the server
+    #[arg(long)]
+    verify_audit_log: bool,
+
+    /// Minutes between automatic backups while data entry is in progress, backups are also
+    /// created on every committee session status change. Set to 0 to disable automatic backups.
+    #[arg(
+        long,
+        default_value_t = 15,
+        value_name = "MINUTES",
+        env = "ABACUS_BACKUP_INTERVAL"
+    )]
+    backup_interval: u64,
+
+    /// Number of most recent backups to keep when removing old backups after an automatic backup
+    #[arg(long, default_value_t = 20, env = "ABACUS_BACKUP_KEEP_LAST")]
+    backup_keep_last: usize,
+
+    /// Number of most recent hours for which the newest backup of that hour is kept when
+    /// removing old backups after an automatic backup
+    #[arg(long, default_value_t = 48, env = "ABACUS_BACKUP_KEEP_HOURLY")]
+    backup_keep_hourly: usize,
+
+    /// Minutes without saving after which the claim of a typist on a data entry in progress is
+    /// abandoned, so a coordinator can hand over the data entry to another typist
+    #[arg(
+        long,
+        default_value_t = DEFAULT_CLAIM_TIMEOUT_MINUTES,
+        value_name = "MINUTES",
+        value_parser = clap::value_parser!(u32).range(1..),
+        env = "ABACUS_CLAIM_TIMEOUT"
+    )]
+    claim_timeout: u32,
+
     /// Location of the TLS directory (CA certificate and key), will be created if it doesn't exist
     #[cfg(feature = "tls")]
     #[arg(long, default_value = "tls", env = "ABACUS_TLS_DIR")]
@@ -154,38 +212,76 @@ fn init_tls(tls_dir: &std::path::Path) -> Result<(), AppError> {
     Ok(())
 }
 
-async fn run() -> Result<(), AppError> {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::builder()
-                .with_default_directive(LevelFilter::INFO.into())
-                .from_env()?,
-        )
-        .init();
-
-    let args = Args::parse();
-    if args.version {
-        println!(env!("ABACUS_GIT_VERSION"));
-        return Ok(());
+/// Create the backup configuration, with a backup schedule unless automatic backups are disabled
+/// and with encryption if a backup passphrase is given.
+fn create_backup_config(args: &Args) -> BackupConfig {
+    let mut backup_config = BackupConfig::new(args.backup_dir.clone());
+    if let Some(passphrase) = &args.backup_passphrase {
+        backup_config = backup_config.with_encryption(BackupPassphrase::from(passphrase.clone()));
+    }
+    if args.backup_interval == 0 {
+        return backup_config;
     }
+    backup_config.with_schedule(BackupSchedule {
+        interval: Duration::from_secs(args.backup_interval * 60),
+        retention: BackupRetention {
+            keep_last: args.backup_keep_last,
+            keep_hourly: args.backup_keep_hourly,
+        },
+    })
+}
 
-    // Create the CA and exit, without touching the database or starting the server.
-    #[cfg(feature = "tls")]
-    if args.init_tls {
-        return init_tls(&args.tls_dir);
+/// Create the inactivity timeout for data entry claims
+fn create_claim_timeout(args: &Args) -> ClaimTimeout {
+    ClaimTimeout(chrono::TimeDelta::minutes(i64::from(args.claim_timeout)))
+}
+
+/// Print the available backups, newest first. Used for the `--list-backups` CLI flag.
+fn print_backups(backup_config: &BackupConfig) -> Result<(), AppError> {
+    for backup in list_local_backups(backup_config)? {
+        println!("{}\t{}", backup.filename, backup.created_at.to_rfc3339());
     }
+    Ok(())
+}
 
-    let pool = create_sqlite_pool(
-        &args.database,
-        #[cfg(feature = "dev-database")]
-        args.reset_database,
-        #[cfg(feature = "dev-database")]
-        args.seed_data,
-    )
-    .await?;
+/// Verify the audit log hash chain and print the result. Used for the
+/// `--verify-audit-log` CLI flag.
+async fn verify_audit_log(pool: &SqlitePool) -> Result<(), AppError> {
+    let mut conn = pool.acquire().await?;
+    let verification = audit_log::verify_chain(&mut conn).await?;
+    println!("Events: {}", verification.event_count);
+    println!(
+        "Events logged before the hash chain was introduced: {}",
+        verification.unchained_event_count
+    );
+    println!("Chain head: {}", verification.chain_head);
+    match verification.first_invalid_event_id {
+        Some(id) => Err(AppError::AuditLogChainBroken(id)),
+        None => {
+            println!("The audit log hash chain is valid.");
+            Ok(())
+        }
+    }
+}
 
-    let backup_config = BackupConfig::new(args.backup_dir);
+/// Restore a backup and print which backups were involved. Used for the
+/// `--restore-backup` CLI flag.
+async fn restore_backup(
+    pool: &SqlitePool,
+    backup_config: &BackupConfig,
+    filename: &str,
+) -> Result<(), AppError> {
+    let result = abacus::restore_backup(pool, backup_config, filename).await?;
+    println!("Restored database backup: {}", result.filename);
+    println!(
+        "Previous database saved as: {}",
+        result.pre_restore_backup.filename
+    );
+    Ok(())
+}
 
+/// Start the server with the given database pool, over HTTPS when compiled with the `tls` feature.
+async fn serve(args: &Args, pool: SqlitePool, backup_config: BackupConfig) -> Result<(), AppError> {
     // Enable airgap detection if the feature is enabled or if the command line argument is set.
     #[cfg(feature = "airgap-detection")]
     let enable_airgap_detection = true;
@@ -193,6 +289,7 @@ async fn run() -> Result<(), AppError> {
     #[cfg(not(feature = "airgap-detection"))]
     let enable_airgap_detection = args.airgap_detection;
 
+    let claim_timeout = create_claim_timeout(args);
     let listener = bind_listener(args.port)?;
 
     // When compiled with the `tls` feature the server always serves HTTPS.
@@ -209,11 +306,73 @@ async fn run() -> Result<(), AppError> {
             listener,
             enable_airgap_detection,
             backup_config,
+            claim_timeout,
             tls_config,
             ca,
         )
         .await
     }
     #[cfg(not(feature = "tls"))]
-    abacus::start_server(pool, listener, enable_airgap_detection, backup_config).await
+    abacus::start_server(
+        pool,
+        listener,
+        enable_airgap_detection,
+        backup_config,
+        claim_timeout,
+    )
+    .await
+}
+
+async fn run() -> Result<(), AppError> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::builder()
+                .with_default_directive(LevelFilter::INFO.into())
+                .from_env()?,
+        )
+        .init();
+
+    let args = Args::parse();
+    if args.version {
+        println!(env!("ABACUS_GIT_VERSION"));
+        return Ok(());
+    }
+
+    // Create the CA and exit, without touching the database or starting the server.
+    #[cfg(feature = "tls")]
+    if args.init_tls {
+        return init_tls(&args.tls_dir);
+    }
+
+    let backup_config = create_backup_config(&args);
+
+    // List the backups and exit, without touching the database or starting the server.
+    if args.list_backups {
+        return print_backups(&backup_config);
+    }
+
+    let pool = create_sqlite_pool(
+        &args.database,
+        #[cfg(feature = "dev-database")]
+        args.reset_database,
+        #[cfg(feature = "dev-database")]
+        args.seed_data,
+    )
+    .await?;
+
+    // Restore the backup and exit, the server can be started again afterwards.
+    if let Some(filename) = args.restore_backup {
+        let result = restore_backup(&pool, &backup_config, &filename).await;
+        pool.close().await;
+        return result;
+    }
+
+    // Verify the audit log and exit, without starting the server.
+    if args.verify_audit_log {
+        let result = verify_audit_log(&pool).await;
+        pool.close().await;
+        return result;
+    }
+
+    serve(&args, pool, backup_config).await
 }
@@ -0,0 +1,1367 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Write as _},
+    fs,
+    io::{self, BufRead as _, Write as _},
+    path::{Path, PathBuf},
+    process,
+};
+
+use abacus::{
+    api::apportionment::{ApportionmentInputData, map_candidate_nomination, map_seat_assignment},
+    domain::{
+        apportionment::{
+            ApportionmentWarning, CandidateDrawingLotsVariant, CandidateDrawn, CandidateNomination,
+            DisplayFraction, ListDrawingLotsVariant, ListDrawn, SeatAssignment,
+            SeatChangeExplanation, SeatChangeReason,
+        },
+        apportionment_state::{ExcludedCandidate, ExclusionReason},
+        election::{
+            Candidate, CandidateNumber, PGNumber, PoliticalGroup, RegisteredPoliticalGroup,
+        },
+        models::{enriched_seat_assignment::EnrichedSeatAssignment, error::ModelsError},
+        results::{
+            political_group_candidate_votes::{CandidateVotes, PoliticalGroupCandidateVotes},
+            political_group_total_votes::EnrichedPoliticalGroupTotalVotes,
+        },
+    },
+    eml::{
+        EMLImportError,
+        count::{parse_count_eml_str, political_group_votes_from_count_eml},
+        result::{
+            ResultCandidate, ResultPoliticalGroup, elected_political_groups_from_result_eml,
+            number_of_seats_from_result_eml, parse_result_eml_str,
+        },
+    },
+};
+use apportionment::{ApportionmentError, ApportionmentOutput, record::ApportionmentRecord};
+use clap::{Parser, ValueEnum};
+use eml_nl::{
+    documents::{candidate_lists::CandidateLists, election_count::CountType},
+    io::{EMLParsingMode, EMLRead as _},
+};
+use serde::{Deserialize, Serialize};
+use tracing::level_filters::LevelFilter;
+
+/// Run the apportionment (seat assignment and candidate nomination) outside of an election in
+/// Abacus, for example to reproduce or simulate a seat distribution.
+///
+/// The input is either a JSON file or an EML_NL 510 count document (a file ending in `.xml`).
+/// When drawing lots is required, the outcomes given with `--draw-list` and
+/// `--draw-candidate` are used in order, or asked for when `--interactive` is set.
+///
+/// With `--verify`, an EML_NL 520 result document is checked against the apportionment
+/// calculated from the EML_NL 510d count document it is based on. The result only contains the
+/// names of the elected candidates, so `--candidates` is needed to compare them.
+#[derive(Parser, Debug)]
+struct Args {
+    /// JSON input file or EML_NL 510 count document
+    input: PathBuf,
+
+    /// Number of seats, overrides the number of seats in the JSON input
+    #[arg(short, long)]
+    seats: Option<u32>,
+
+    /// EML_NL 520 result document to take the number of seats from
+    #[arg(short, long)]
+    result: Option<PathBuf>,
+
+    /// EML_NL 520 result document to verify against the apportionment of the 510d input
+    #[arg(long, conflicts_with = "result", requires = "candidates")]
+    verify: Option<PathBuf>,
+
+    /// EML_NL 230 candidate lists document with the names of the candidates
+    #[arg(short, long)]
+    candidates: Option<PathBuf>,
+
+    /// Excluded candidate as `<list number>:<candidate number>[:<reason>]`, where the reason is
+    /// `deceased` (default), `declined` or `ineligible`, can be repeated
+    #[arg(long, value_parser = parse_excluded_candidate)]
+    exclude: Vec<ExcludedCandidate>,
+
+    /// List drawn when drawing lots between lists is required, can be repeated
+    #[arg(long)]
+    draw_list: Vec<u32>,
+
+    /// Candidate drawn when drawing lots between candidates is required, can be repeated
+    #[arg(long)]
+    draw_candidate: Vec<u32>,
+
+    /// Ask for the outcome when drawing lots is required and no outcome was given
+    #[arg(short, long)]
+    interactive: bool,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Log all calculations of the apportionment to stderr
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+    /// Versioned record of the apportionment crate, to archive or compare calculations
+    Record,
+}
+
+fn parse_excluded_candidate(value: &str) -> Result<ExcludedCandidate, String> {
+    let mut parts = value.split(':');
+    let (Some(pg_number), Some(candidate_number), reason, None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(format!(
+            "expected `<list number>:<candidate number>[:<reason>]`, got `{value}`"
+        ));
+    };
+    let reason = match reason.map(str::trim) {
+        None | Some("deceased") => ExclusionReason::Deceased,
+        Some("declined") => ExclusionReason::Declined,
+        Some("ineligible") => ExclusionReason::Ineligible,
+        Some(reason) => return Err(format!("invalid reason `{reason}`")),
+    };
+    let parse = |number: &str| {
+        number
+            .trim()
+            .parse::<u32>()
+            .map_err(|err| format!("invalid number `{number}`: {err}"))
+    };
+
+    Ok(ExcludedCandidate {
+        pg_number: PGNumber::from(parse(pg_number)?),
+        candidate_number: CandidateNumber::from(parse(candidate_number)?),
+        reason,
+    })
+}
+
+#[derive(Debug)]
+enum ApportionCliError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Eml(EMLImportError),
+    Apportionment(ApportionmentError),
+    Models(ModelsError),
+    MissingNumberOfSeats,
+    DrawingLotsRequired(String),
+}
+
+impl fmt::Display for ApportionCliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Could not read input: {err}"),
+            Self::Json(err) => write!(f, "Invalid JSON: {err}"),
+            Self::Eml(err) => write!(f, "Invalid EML_NL document: {err:?}"),
+            Self::Apportionment(err) => write!(f, "Apportionment failed: {err:?}"),
+            Self::Models(err) => write!(f, "Could not create the seat assignment: {err:?}"),
+            Self::MissingNumberOfSeats => write!(
+                f,
+                "Number of seats is missing, use `--seats`, `--result` or `--verify` or add `seats` to the JSON input"
+            ),
+            Self::DrawingLotsRequired(description) => write!(
+                f,
+                "{description}\nUse `--draw-list`, `--draw-candidate` or `--interactive` to provide the outcome"
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for ApportionCliError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ApportionCliError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<EMLImportError> for ApportionCliError {
+    fn from(err: EMLImportError) -> Self {
+        Self::Eml(err)
+    }
+}
+
+impl From<ModelsError> for ApportionCliError {
+    fn from(err: ModelsError) -> Self {
+        Self::Models(err)
+    }
+}
+
+impl From<ApportionmentError> for ApportionCliError {
+    fn from(err: ApportionmentError) -> Self {
+        Self::Apportionment(err)
+    }
+}
+
+/// Apportionment input as JSON, lists and candidates are identified by their number
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonInput {
+    #[serde(default)]
+    seats: Option<u32>,
+    lists: Vec<JsonInputList>,
+    #[serde(default)]
+    excluded_candidates: Vec<ExcludedCandidate>,
+    #[serde(default)]
+    lists_drawn: Vec<ListDrawn>,
+    #[serde(default)]
+    candidates_drawn: Vec<CandidateDrawn>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonInputList {
+    number: PGNumber,
+    #[serde(default)]
+    name: Option<String>,
+    candidate_votes: Vec<CandidateVotes>,
+}
+
+/// Everything needed to run the apportionment, the drawn lots are added while running it
+#[derive(Debug, Default)]
+struct Simulation {
+    seats: Option<u32>,
+    count_type: Option<CountType>,
+    political_groups: Vec<PoliticalGroup>,
+    list_votes: Vec<PoliticalGroupCandidateVotes>,
+    excluded_candidates: Vec<ExcludedCandidate>,
+    lists_drawn: Vec<ListDrawn>,
+    candidates_drawn: Vec<CandidateDrawn>,
+}
+
+impl Simulation {
+    fn add_list(&mut self, name: String, mut list_votes: PoliticalGroupCandidateVotes) {
+        list_votes.candidate_votes.sort_by_key(|cv| cv.number);
+        self.political_groups.push(PoliticalGroup {
+            number: list_votes.number,
+            name: name.clone(),
+            registered_name: name,
+            candidates: list_votes
+                .candidate_votes
+                .iter()
+                .map(|cv| candidate(cv.number))
+                .collect(),
+        });
+        self.list_votes.push(list_votes);
+    }
+
+    fn from_json(data: &str) -> Result<Self, ApportionCliError> {
+        let mut input: JsonInput = serde_json::from_str(data)?;
+        input.lists.sort_by_key(|list| list.number);
+
+        let mut simulation = Self {
+            seats: input.seats,
+            excluded_candidates: input.excluded_candidates,
+            lists_drawn: input.lists_drawn,
+            candidates_drawn: input.candidates_drawn,
+            ..Self::default()
+        };
+        for list in input.lists {
+            let name = list
+                .name
+                .unwrap_or_else(|| format!("Lijst {}", list.number));
+            let list_votes = PoliticalGroupCandidateVotes {
+                number: list.number,
+                total: list.candidate_votes.iter().map(|cv| cv.votes).sum(),
+                candidate_votes: list.candidate_votes,
+            };
+            simulation.add_list(name, list_votes);
+        }
+
+        Ok(simulation)
+    }
+
+    fn from_count_eml(data: &str) -> Result<Self, ApportionCliError> {
+        let count = parse_count_eml_str(data)?;
+
+        let mut simulation = Self {
+            count_type: Some(count.count_type),
+            ..Self::default()
+        };
+        for political_group in political_group_votes_from_count_eml(&count)? {
+            simulation.add_list(political_group.name, political_group.votes);
+        }
+
+        Ok(simulation)
+    }
+
+    /// Replace the candidates that are only known by their number with the candidates of the
+    /// candidate lists document, and use the registered names of the lists
+    fn add_candidate_lists_eml(&mut self, data: &str) -> Result<(), ApportionCliError> {
+        let candidate_lists = CandidateLists::parse_eml(data, EMLParsingMode::Strict)
+            .ok()
+            .map_err(EMLImportError::from)?;
+        let contest = candidate_lists
+            .candidate_list
+            .election
+            .contests
+            .first()
+            .ok_or(EMLImportError::CandidateListWithoutContest)?;
+
+        for registered in RegisteredPoliticalGroup::from_candidate_lists_contest(contest)? {
+            let Some(political_group) = self
+                .political_groups
+                .iter_mut()
+                .find(|pg| pg.number == registered.number)
+            else {
+                continue;
+            };
+            if !registered.registered_name.is_empty() {
+                political_group.name = registered.registered_name.clone();
+                political_group.registered_name = registered.registered_name;
+            }
+            political_group.candidates = registered.candidates;
+        }
+
+        Ok(())
+    }
+
+    fn political_group_total_votes(&self) -> Vec<EnrichedPoliticalGroupTotalVotes> {
+        self.list_votes
+            .iter()
+            .zip(&self.political_groups)
+            .map(
+                |(list_votes, political_group)| EnrichedPoliticalGroupTotalVotes {
+                    number: list_votes.number,
+                    name: political_group.name.clone(),
+                    total: list_votes.total,
+                },
+            )
+            .collect()
+    }
+}
+
+/// Candidates are only known by their number outside of an election
+fn candidate(number: CandidateNumber) -> Candidate {
+    Candidate {
+        number,
+        initials: String::new(),
+        first_name: None,
+        last_name_prefix: None,
+        last_name: format!("Kandidaat {number}"),
+        locality: String::new(),
+        country_code: None,
+        gender: None,
+    }
+}
+
+/// Outcomes of drawing lots, either given up front or asked for when needed
+struct LotDrawer {
+    lists: VecDeque<PGNumber>,
+    candidates: VecDeque<CandidateNumber>,
+    interactive: bool,
+}
+
+impl LotDrawer {
+    fn draw_list(
+        &mut self,
+        variant: &ListDrawingLotsVariant,
+    ) -> Result<PGNumber, ApportionCliError> {
+        if let Some(drawn) = self.lists.pop_front() {
+            return Ok(drawn);
+        }
+        let (description, options) = describe_list_drawing(variant);
+        if self.interactive {
+            return Ok(PGNumber::from(ask_drawn(&description, &options)?));
+        }
+        Err(ApportionCliError::DrawingLotsRequired(description))
+    }
+
+    fn draw_candidate(
+        &mut self,
+        variant: &CandidateDrawingLotsVariant,
+    ) -> Result<CandidateNumber, ApportionCliError> {
+        if let Some(drawn) = self.candidates.pop_front() {
+            return Ok(drawn);
+        }
+        let description = format!(
+            "Drawing lots required between candidates {} of list {} with {} votes, for {}",
+            join(&variant.options),
+            variant.list,
+            variant.number_of_votes,
+            seats(&variant.seat_numbers),
+        );
+        if self.interactive {
+            let options = variant
+                .options
+                .iter()
+                .map(CandidateNumber::as_internal_u32)
+                .collect::<Vec<_>>();
+            return Ok(CandidateNumber::from(ask_drawn(&description, &options)?));
+        }
+        Err(ApportionCliError::DrawingLotsRequired(description))
+    }
+}
+
+fn describe_list_drawing(variant: &ListDrawingLotsVariant) -> (String, Vec<u32>) {
+    let (description, options) = match variant {
+        ListDrawingLotsVariant::HighestAverageResidualSeat(lots) => (
+            format!(
+                "Drawing lots required between lists {} with the highest average of {}, for residual {}",
+                join(&lots.options),
+                fraction(&lots.max_average),
+                seats(&lots.residual_seat_numbers),
+            ),
+            &lots.options,
+        ),
+        ListDrawingLotsVariant::LargestRemainderResidualSeat(lots) => (
+            format!(
+                "Drawing lots required between lists {} with the largest remainder of {}, for residual {}",
+                join(&lots.options),
+                fraction(&lots.max_remainder),
+                seats(&lots.residual_seat_numbers),
+            ),
+            &lots.options,
+        ),
+        ListDrawingLotsVariant::AbsoluteMajorityHighestAverage(lots)
+        | ListDrawingLotsVariant::AbsoluteMajorityLargestRemainder(lots) => (
+            format!(
+                "Drawing lots required between lists {} for the residual seat that is reassigned to list {} because of its absolute majority",
+                join(&lots.options),
+                lots.assign_to,
+            ),
+            &lots.options,
+        ),
+    };
+    (
+        description,
+        options.iter().map(PGNumber::as_internal_u32).collect(),
+    )
+}
+
+/// Ask for the outcome of drawing lots on stdin until one of the options is given
+fn ask_drawn(description: &str, options: &[u32]) -> Result<u32, ApportionCliError> {
+    let mut stderr = io::stderr();
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        write!(stderr, "{description}\nDrawn: ")?;
+        stderr.flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            return Err(ApportionCliError::DrawingLotsRequired(
+                description.to_string(),
+            ));
+        };
+        match line.trim().parse::<u32>() {
+            Ok(drawn) if options.contains(&drawn) => return Ok(drawn),
+            _ => writeln!(stderr, "Choose one of {}", join(options))?,
+        }
+    }
+}
+
+/// Result of the apportionment, including the lots that were drawn to get to it
+#[derive(Debug, Serialize)]
+struct ApportionmentReport {
+    seat_assignment: SeatAssignment,
+    candidate_nomination: CandidateNomination,
+    lists_drawn: Vec<ListDrawn>,
+    candidates_drawn: Vec<CandidateDrawn>,
+    warnings: Vec<ApportionmentWarning>,
+    explanations: Vec<SeatChangeExplanation>,
+    #[serde(skip)]
+    record: ApportionmentRecord<PGNumber, CandidateNumber>,
+}
+
+enum LotDrawn {
+    List(ListDrawn),
+    Candidate(CandidateDrawn),
+}
+
+/// Run the apportionment, drawing lots and running it again until it is completed
+fn apportion(
+    simulation: &mut Simulation,
+    drawer: &mut LotDrawer,
+) -> Result<ApportionmentReport, ApportionCliError> {
+    let seats = simulation
+        .seats
+        .ok_or(ApportionCliError::MissingNumberOfSeats)?;
+
+    loop {
+        let input = ApportionmentInputData::new(
+            seats,
+            &simulation.list_votes,
+            &simulation.excluded_candidates,
+            &simulation.lists_drawn,
+            &simulation.candidates_drawn,
+        );
+        let lot_drawn = match apportionment::process(&input)? {
+            ApportionmentOutput::Completed(output) => {
+                return Ok(ApportionmentReport {
+                    seat_assignment: map_seat_assignment(&output.seat_assignment),
+                    candidate_nomination: map_candidate_nomination(
+                        &output.candidate_nomination,
+                        &simulation.political_groups,
+                    ),
+                    lists_drawn: simulation.lists_drawn.clone(),
+                    candidates_drawn: simulation.candidates_drawn.clone(),
+                    warnings: output
+                        .seat_assignment
+                        .warnings()
+                        .into_iter()
+                        .map(ApportionmentWarning::from)
+                        .collect(),
+                    explanations: output
+                        .seat_assignment
+                        .explanations()?
+                        .iter()
+                        .map(Into::into)
+                        .collect(),
+                    record: ApportionmentRecord::from(&output),
+                });
+            }
+            ApportionmentOutput::ListDrawingLotsRequired(variant, _) => {
+                let variant = ListDrawingLotsVariant::from(variant);
+                let drawn = drawer.draw_list(&variant)?;
+                LotDrawn::List(ListDrawn { variant, drawn })
+            }
+            ApportionmentOutput::CandidateDrawingLotsRequired(variant, _) => {
+                let variant = CandidateDrawingLotsVariant::from(variant);
+                let drawn = drawer.draw_candidate(&variant)?;
+                LotDrawn::Candidate(CandidateDrawn { variant, drawn })
+            }
+        };
+
+        match lot_drawn {
+            LotDrawn::List(list_drawn) => simulation.lists_drawn.push(list_drawn),
+            LotDrawn::Candidate(candidate_drawn) => {
+                simulation.candidates_drawn.push(candidate_drawn);
+            }
+        }
+    }
+}
+
+/// Difference between an EML_NL 520 result and the apportionment calculated from its totals
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "type")]
+enum Discrepancy {
+    NumberOfSeats {
+        calculated: u32,
+        result: u32,
+    },
+    ListSeats {
+        list_number: PGNumber,
+        calculated: u32,
+        result: u32,
+    },
+    /// Candidate is elected in the calculated apportionment but not in the result
+    CandidateNotInResult {
+        list_number: PGNumber,
+        name: String,
+    },
+    /// Candidate is elected in the result but not in the calculated apportionment
+    CandidateNotElected {
+        list_number: PGNumber,
+        name: String,
+    },
+    CandidatePosition {
+        list_number: PGNumber,
+        name: String,
+        calculated: u32,
+        result: u32,
+    },
+    PreferentialNomination {
+        list_number: PGNumber,
+        name: String,
+        calculated: bool,
+        result: bool,
+    },
+}
+
+impl fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elected = |preferential: &bool| {
+            if *preferential {
+                "with preference votes"
+            } else {
+                "without preference votes"
+            }
+        };
+        match self {
+            Self::NumberOfSeats { calculated, result } => write!(
+                f,
+                "Number of seats is {calculated}, but the result has {result} elected candidates"
+            ),
+            Self::ListSeats {
+                list_number,
+                calculated,
+                result,
+            } => write!(
+                f,
+                "List {list_number} gets {calculated} seats, but has {result} seats in the result"
+            ),
+            Self::CandidateNotInResult { list_number, name } => write!(
+                f,
+                "List {list_number}: {name} is elected, but not in the result"
+            ),
+            Self::CandidateNotElected { list_number, name } => write!(
+                f,
+                "List {list_number}: {name} is in the result, but not elected"
+            ),
+            Self::CandidatePosition {
+                list_number,
+                name,
+                calculated,
+                result,
+            } => write!(
+                f,
+                "List {list_number}: {name} is elected at position {calculated}, but at position {result} in the result"
+            ),
+            Self::PreferentialNomination {
+                list_number,
+                name,
+                calculated,
+                result,
+            } => write!(
+                f,
+                "List {list_number}: {name} is elected {}, but {} in the result",
+                elected(calculated),
+                elected(result)
+            ),
+        }
+    }
+}
+
+/// Result of verifying an EML_NL 520 result against the calculated apportionment
+#[derive(Debug, Serialize)]
+struct VerificationReport {
+    seat_assignment: EnrichedSeatAssignment,
+    lists_drawn: Vec<ListDrawn>,
+    candidates_drawn: Vec<CandidateDrawn>,
+    discrepancies: Vec<Discrepancy>,
+}
+
+/// Elected candidates of each list in the calculated apportionment, in the same form as in a
+/// result document
+fn calculated_elected_political_groups(
+    report: &ApportionmentReport,
+    political_groups: &[PoliticalGroup],
+) -> Vec<ResultPoliticalGroup> {
+    report
+        .candidate_nomination
+        .list_candidate_nomination
+        .iter()
+        .zip(political_groups)
+        .map(|(list, political_group)| {
+            let ranking = if list.updated_candidate_ranking.is_empty() {
+                &political_group.candidates
+            } else {
+                &list.updated_candidate_ranking
+            };
+            ResultPoliticalGroup {
+                number: list.list_number,
+                name: list.list_name.clone(),
+                elected_candidates: (1..)
+                    .zip(ranking.iter().take(list.list_seats as usize))
+                    .map(|(position, candidate)| {
+                        let preferential = list
+                            .preferential_candidate_nomination
+                            .iter()
+                            .any(|cv| cv.number == candidate.number);
+                        ResultCandidate::from_candidate(position, candidate, preferential)
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn compare_elected_candidates(
+    list_number: PGNumber,
+    calculated: &[ResultCandidate],
+    result: &[ResultCandidate],
+    discrepancies: &mut Vec<Discrepancy>,
+) {
+    for candidate in calculated {
+        let Some(in_result) = result.iter().find(|c| c.is_same_candidate(candidate)) else {
+            discrepancies.push(Discrepancy::CandidateNotInResult {
+                list_number,
+                name: candidate.name(),
+            });
+            continue;
+        };
+        if in_result.position != candidate.position {
+            discrepancies.push(Discrepancy::CandidatePosition {
+                list_number,
+                name: candidate.name(),
+                calculated: candidate.position,
+                result: in_result.position,
+            });
+        }
+        if in_result.preferential != candidate.preferential {
+            discrepancies.push(Discrepancy::PreferentialNomination {
+                list_number,
+                name: candidate.name(),
+                calculated: candidate.preferential,
+                result: in_result.preferential,
+            });
+        }
+    }
+    for candidate in result {
+        if !calculated.iter().any(|c| c.is_same_candidate(candidate)) {
+            discrepancies.push(Discrepancy::CandidateNotElected {
+                list_number,
+                name: candidate.name(),
+            });
+        }
+    }
+}
+
+fn number_of_elected(candidates: &[ResultCandidate]) -> u32 {
+    u32::try_from(candidates.len()).unwrap_or(u32::MAX)
+}
+
+/// Compare the seats and elected candidates per list, lists without seats are not in the result
+fn find_discrepancies(
+    number_of_seats: u32,
+    calculated: &[ResultPoliticalGroup],
+    result: &[ResultPoliticalGroup],
+) -> Vec<Discrepancy> {
+    let mut discrepancies = vec![];
+    let result_seats = result
+        .iter()
+        .map(|pg| number_of_elected(&pg.elected_candidates))
+        .sum();
+    if result_seats != number_of_seats {
+        discrepancies.push(Discrepancy::NumberOfSeats {
+            calculated: number_of_seats,
+            result: result_seats,
+        });
+    }
+
+    let mut list_numbers = calculated
+        .iter()
+        .chain(result)
+        .map(|pg| pg.number)
+        .collect::<Vec<_>>();
+    list_numbers.sort();
+    list_numbers.dedup();
+
+    for list_number in list_numbers {
+        let elected_candidates = |political_groups: &[ResultPoliticalGroup]| {
+            political_groups
+                .iter()
+                .find(|pg| pg.number == list_number)
+                .map(|pg| pg.elected_candidates.clone())
+                .unwrap_or_default()
+        };
+        let calculated = elected_candidates(calculated);
+        let result = elected_candidates(result);
+        if calculated.len() != result.len() {
+            discrepancies.push(Discrepancy::ListSeats {
+                list_number,
+                calculated: number_of_elected(&calculated),
+                result: number_of_elected(&result),
+            });
+        }
+        compare_elected_candidates(list_number, &calculated, &result, &mut discrepancies);
+    }
+    discrepancies
+}
+
+/// Calculate the apportionment of a 510d count and compare it with a 520 result
+fn verify(
+    simulation: &mut Simulation,
+    result: &[ResultPoliticalGroup],
+    drawer: &mut LotDrawer,
+) -> Result<VerificationReport, ApportionCliError> {
+    if simulation.count_type != Some(CountType::Central) {
+        return Err(EMLImportError::Needs510d.into());
+    }
+    let report = apportion(simulation, drawer)?;
+    let number_of_seats = report.seat_assignment.seats;
+
+    let calculated = calculated_elected_political_groups(&report, &simulation.political_groups);
+    Ok(VerificationReport {
+        seat_assignment: EnrichedSeatAssignment::from_political_group_total_votes(
+            number_of_seats,
+            &simulation.political_group_total_votes(),
+            &report.seat_assignment,
+        )?,
+        lists_drawn: report.lists_drawn,
+        candidates_drawn: report.candidates_drawn,
+        discrepancies: find_discrepancies(number_of_seats, &calculated, result),
+    })
+}
+
+fn join<T: fmt::Display>(values: &[T]) -> String {
+    if values.is_empty() {
+        return "-".to_string();
+    }
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn seats(seat_numbers: &[u32]) -> String {
+    if seat_numbers.len() == 1 {
+        format!("seat {}", seat_numbers[0])
+    } else {
+        format!("seats {}", join(seat_numbers))
+    }
+}
+
+fn fraction(fraction: &DisplayFraction) -> String {
+    if fraction.numerator == 0 {
+        fraction.integer.to_string()
+    } else {
+        format!(
+            "{} {}/{}",
+            fraction.integer, fraction.numerator, fraction.denominator
+        )
+    }
+}
+
+fn describe_seat_change(explanation: &SeatChangeExplanation) -> String {
+    let list = |number: Option<PGNumber>| number.map(|n| n.to_string()).unwrap_or_default();
+    let assigned = list(explanation.list_assigned_seat);
+    let retracted = list(explanation.list_retracted_seat);
+    let mut description = match &explanation.reason {
+        SeatChangeReason::HighestAverage { votes_per_seat } => format!(
+            "highest average, list {assigned} with {} votes per seat",
+            fraction(votes_per_seat)
+        ),
+        SeatChangeReason::UniqueHighestAverage { votes_per_seat } => format!(
+            "unique highest average, list {assigned} with {} votes per seat",
+            fraction(votes_per_seat)
+        ),
+        SeatChangeReason::LargestRemainder { remainder_votes } => format!(
+            "largest remainder, list {assigned} with a remainder of {} votes",
+            fraction(remainder_votes)
+        ),
+        SeatChangeReason::AbsoluteMajority {
+            votes_cast,
+            total_votes_cast,
+            list_seats,
+            seats,
+            ..
+        } => format!(
+            "absolute majority, seat of list {retracted} reassigned to list {assigned} \
+             with {votes_cast} of {total_votes_cast} votes and {list_seats} of {seats} seats"
+        ),
+        SeatChangeReason::ListExhaustion { full_seat } => format!(
+            "list exhaustion, {} seat of list {retracted} retracted",
+            if *full_seat { "full" } else { "residual" }
+        ),
+    };
+    if !explanation.tied_lists.is_empty() {
+        description.push_str(&format!(
+            ", tied with lists {}",
+            join(&explanation.tied_lists)
+        ));
+    }
+    if explanation.drawn_lots {
+        description.push_str(" (drawn by lot)");
+    }
+    let articles: Vec<&str> = explanation
+        .articles
+        .iter()
+        .map(|article| article.title())
+        .collect();
+    format!("{description} [{}]", articles.join(", "))
+}
+
+fn write_seat_assignment(
+    out: &mut String,
+    seat_assignment: &SeatAssignment,
+    explanations: &[SeatChangeExplanation],
+) -> fmt::Result {
+    writeln!(out, "Seat assignment")?;
+    writeln!(
+        out,
+        "  Seats: {}, quota: {}, full seats: {}, residual seats: {}",
+        seat_assignment.seats,
+        fraction(&seat_assignment.quota),
+        seat_assignment.full_seats,
+        seat_assignment.residual_seats
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "  {:>4} {:>10} {:>14} {:>6} {:>9} {:>6}",
+        "List", "Votes", "Remainder", "Full", "Residual", "Total"
+    )?;
+    for standing in &seat_assignment.standings {
+        writeln!(
+            out,
+            "  {:>4} {:>10} {:>14} {:>6} {:>9} {:>6}",
+            standing.list_number.to_string(),
+            standing.votes_cast,
+            fraction(&standing.remainder_votes),
+            standing.full_seats,
+            standing.residual_seats,
+            standing.total_seats
+        )?;
+    }
+
+    if !explanations.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "  Steps")?;
+    }
+    for explanation in explanations {
+        match explanation.residual_seat_number {
+            Some(number) => write!(out, "  - Residual seat {number}: ")?,
+            None => write!(out, "  - Change: ")?,
+        }
+        writeln!(out, "{}", describe_seat_change(explanation))?;
+    }
+    Ok(())
+}
+
+fn write_candidate_nomination(
+    out: &mut String,
+    candidate_nomination: &CandidateNomination,
+) -> fmt::Result {
+    let threshold = &candidate_nomination.preference_threshold;
+    writeln!(out, "Candidate nomination")?;
+    writeln!(
+        out,
+        "  Preference threshold: {}% of the quota, {} votes",
+        threshold.percentage,
+        fraction(&threshold.number_of_votes)
+    )?;
+    for list in &candidate_nomination.list_candidate_nomination {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "  List {} - {} ({} seats)",
+            list.list_number, list.list_name, list.list_seats
+        )?;
+        let preferential = list
+            .preferential_candidate_nomination
+            .iter()
+            .map(|cv| format!("{} ({} votes)", cv.number, cv.votes))
+            .collect::<Vec<_>>();
+        let other = list
+            .other_candidate_nomination
+            .iter()
+            .map(|cv| cv.number)
+            .collect::<Vec<_>>();
+        writeln!(out, "    Preferential: {}", join(&preferential))?;
+        writeln!(out, "    Other: {}", join(&other))?;
+        if !list.updated_candidate_ranking.is_empty() {
+            let ranking = list
+                .updated_candidate_ranking
+                .iter()
+                .map(|candidate| candidate.number)
+                .collect::<Vec<_>>();
+            writeln!(out, "    Updated ranking: {}", join(&ranking))?;
+        }
+    }
+    Ok(())
+}
+
+fn write_text(report: &ApportionmentReport) -> Result<String, fmt::Error> {
+    let mut out = String::new();
+    write_seat_assignment(&mut out, &report.seat_assignment, &report.explanations)?;
+    writeln!(out)?;
+    write_candidate_nomination(&mut out, &report.candidate_nomination)?;
+
+    if !report.lists_drawn.is_empty() || !report.candidates_drawn.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "Drawn by lot")?;
+    }
+    for list_drawn in &report.lists_drawn {
+        let (_, options) = describe_list_drawing(&list_drawn.variant);
+        writeln!(
+            out,
+            "  - Between lists {}: list {}",
+            join(&options),
+            list_drawn.drawn
+        )?;
+    }
+    for candidate_drawn in &report.candidates_drawn {
+        let variant = &candidate_drawn.variant;
+        writeln!(
+            out,
+            "  - Between candidates {} of list {}: candidate {}",
+            join(&variant.options),
+            variant.list,
+            candidate_drawn.drawn
+        )?;
+    }
+
+    for warning in &report.warnings {
+        writeln!(out)?;
+        writeln!(out, "Warning: {warning:?}")?;
+    }
+    Ok(out)
+}
+
+fn write_verification_text(report: &VerificationReport) -> Result<String, fmt::Error> {
+    let mut out = String::new();
+    if report.discrepancies.is_empty() {
+        writeln!(
+            out,
+            "The result matches the apportionment calculated from the count"
+        )?;
+    } else {
+        writeln!(
+            out,
+            "The result differs from the apportionment calculated from the count"
+        )?;
+        for discrepancy in &report.discrepancies {
+            writeln!(out, "  - {discrepancy}")?;
+        }
+    }
+
+    if !report.lists_drawn.is_empty() || !report.candidates_drawn.is_empty() {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Lots drawn: {} between lists, {} between candidates",
+            report.lists_drawn.len(),
+            report.candidates_drawn.len()
+        )?;
+    }
+    Ok(out)
+}
+
+fn read_simulation(args: &Args) -> Result<Simulation, ApportionCliError> {
+    let data = fs::read_to_string(&args.input)?;
+    let mut simulation = if is_xml(&args.input) {
+        Simulation::from_count_eml(&data)?
+    } else {
+        Simulation::from_json(&data)?
+    };
+
+    if let Some(result) = &args.result {
+        let result = parse_result_eml_str(&fs::read_to_string(result)?)?;
+        simulation.seats = Some(number_of_seats_from_result_eml(&result)?);
+    }
+    if let Some(result) = &args.verify {
+        let result = parse_result_eml_str(&fs::read_to_string(result)?)?;
+        simulation.seats = Some(number_of_seats_from_result_eml(&result)?);
+    }
+    if args.seats.is_some() {
+        simulation.seats = args.seats;
+    }
+    if let Some(candidates) = &args.candidates {
+        simulation.add_candidate_lists_eml(&fs::read_to_string(candidates)?)?;
+    }
+    simulation
+        .excluded_candidates
+        .extend(args.exclude.iter().copied());
+
+    Ok(simulation)
+}
+
+fn is_xml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"))
+}
+
+/// Output of a run, with whether a verified result matches the calculated apportionment
+struct Output {
+    text: String,
+    verified: bool,
+}
+
+fn run(args: &Args) -> Result<Output, ApportionCliError> {
+    let mut simulation = read_simulation(args)?;
+    let mut drawer = LotDrawer {
+        lists: args.draw_list.iter().copied().map(PGNumber::from).collect(),
+        candidates: args
+            .draw_candidate
+            .iter()
+            .copied()
+            .map(CandidateNumber::from)
+            .collect(),
+        interactive: args.interactive,
+    };
+
+    if let Some(result) = &args.verify {
+        let result = parse_result_eml_str(&fs::read_to_string(result)?)?;
+        let result = elected_political_groups_from_result_eml(&result)?;
+        let report = verify(&mut simulation, &result, &mut drawer)?;
+        return Ok(Output {
+            text: match args.format {
+                OutputFormat::Text => {
+                    write_verification_text(&report).expect("writing to a string should not fail")
+                }
+                OutputFormat::Json | OutputFormat::Record => {
+                    serde_json::to_string_pretty(&report)? + "\n"
+                }
+            },
+            verified: report.discrepancies.is_empty(),
+        });
+    }
+
+    let report = apportion(&mut simulation, &mut drawer)?;
+    Ok(Output {
+        text: match args.format {
+            OutputFormat::Text => write_text(&report).expect("writing to a string should not fail"),
+            OutputFormat::Json => serde_json::to_string_pretty(&report)? + "\n",
+            OutputFormat::Record => serde_json::to_string_pretty(&report.record)? + "\n",
+        },
+        verified: true,
+    })
+}
+
+fn main() {
+    let args = Args::parse();
+
+    if args.verbose {
+        tracing_subscriber::fmt()
+            .with_max_level(LevelFilter::INFO)
+            .with_writer(io::stderr)
+            .without_time()
+            .init();
+    }
+
+    match run(&args) {
+        Ok(output) => {
+            print!("{}", output.text);
+            if !output.verified {
+                process::exit(2);
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drawer(lists: &[u32], candidates: &[u32]) -> LotDrawer {
+        LotDrawer {
+            lists: lists.iter().copied().map(PGNumber::from).collect(),
+            candidates: candidates
+                .iter()
+                .copied()
+                .map(CandidateNumber::from)
+                .collect(),
+            interactive: false,
+        }
+    }
+
+    fn total_seats(report: &ApportionmentReport) -> Vec<u32> {
+        report
+            .seat_assignment
+            .standings
+            .iter()
+            .map(|standing| standing.total_seats)
+            .collect()
+    }
+
+    #[test]
+    fn test_apportion_json_input() {
+        let mut simulation = Simulation::from_json(
+            r#"{
+                "seats": 5,
+                "lists": [
+                    {"number": 2, "candidate_votes": [{"number": 1, "votes": 100}, {"number": 2, "votes": 0}]},
+                    {"number": 1, "name": "Partij A", "candidate_votes": [
+                        {"number": 2, "votes": 100}, {"number": 1, "votes": 200}, {"number": 3, "votes": 0}, {"number": 4, "votes": 0}
+                    ]},
+                    {"number": 3, "candidate_votes": [{"number": 1, "votes": 80}]}
+                ],
+                "excluded_candidates": [{"pg_number": 1, "candidate_number": 1}]
+            }"#,
+        )
+        .unwrap();
+
+        let report = apportion(&mut simulation, &mut drawer(&[], &[])).unwrap();
+        assert_eq!(total_seats(&report), vec![3, 1, 1]);
+
+        let list_1 = &report.candidate_nomination.list_candidate_nomination[0];
+        assert_eq!(list_1.list_name, "Partij A");
+        assert_eq!(
+            list_1.updated_candidate_ranking[0].number,
+            CandidateNumber::from(2)
+        );
+
+        let text = write_text(&report).unwrap();
+        assert!(text.contains("Seats: 5, quota: 96, full seats: 4, residual seats: 1"));
+        assert!(text.contains("Residual seat 1: largest remainder, list 3"));
+        assert!(text.contains("[Artikel P 8 Kieswet]"));
+        assert!(text.contains("List 1 - Partij A (3 seats)"));
+
+        let record = serde_json::to_value(&report.record).unwrap();
+        assert_eq!(record["schema_version"], 1);
+        assert_eq!(record["seat_assignment"]["seats"], 5);
+    }
+
+    #[test]
+    fn test_apportion_drawing_lots() {
+        let input = r#"{
+            "seats": 2,
+            "lists": [
+                {"number": 1, "candidate_votes": [{"number": 1, "votes": 50}]},
+                {"number": 2, "candidate_votes": [{"number": 1, "votes": 50}]},
+                {"number": 3, "candidate_votes": [{"number": 1, "votes": 50}]}
+            ]
+        }"#;
+
+        let mut simulation = Simulation::from_json(input).unwrap();
+        let Err(ApportionCliError::DrawingLotsRequired(description)) =
+            apportion(&mut simulation, &mut drawer(&[], &[]))
+        else {
+            panic!("should require drawing lots");
+        };
+        assert!(description.contains("between lists 1, 2, 3"));
+        assert!(description.contains("for residual seats 1, 2"));
+
+        let mut simulation = Simulation::from_json(input).unwrap();
+        let report = apportion(&mut simulation, &mut drawer(&[2, 3], &[])).unwrap();
+        assert_eq!(total_seats(&report), vec![0, 1, 1]);
+        assert_eq!(report.lists_drawn.len(), 2);
+        assert_eq!(report.lists_drawn[0].drawn, PGNumber::from(2));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["lists_drawn"][0]["drawn"], 2);
+    }
+
+    fn calculated_elected_fixture() -> Vec<ResultPoliticalGroup> {
+        let mut simulation = Simulation::from_json(
+            r#"{
+                "seats": 5,
+                "lists": [
+                    {"number": 1, "candidate_votes": [
+                        {"number": 1, "votes": 200}, {"number": 2, "votes": 100}, {"number": 3, "votes": 0}, {"number": 4, "votes": 0}
+                    ]},
+                    {"number": 2, "candidate_votes": [{"number": 1, "votes": 100}, {"number": 2, "votes": 0}]},
+                    {"number": 3, "candidate_votes": [{"number": 1, "votes": 80}]}
+                ],
+                "excluded_candidates": [{"pg_number": 1, "candidate_number": 1}]
+            }"#,
+        )
+        .unwrap();
+        let report = apportion(&mut simulation, &mut drawer(&[], &[])).unwrap();
+        calculated_elected_political_groups(&report, &simulation.political_groups)
+    }
+
+    #[test]
+    fn test_verify_matching_result() {
+        let calculated = calculated_elected_fixture();
+        assert!(find_discrepancies(5, &calculated, &calculated).is_empty());
+
+        let list_1 = &calculated[0].elected_candidates;
+        assert_eq!(list_1.len(), 3);
+        assert_eq!(list_1[0].name(), "Kandidaat 2");
+        assert!(list_1[0].preferential);
+        assert_eq!(list_1[1].name(), "Kandidaat 3");
+        assert!(!list_1[1].preferential);
+
+        assert_eq!(
+            Discrepancy::ListSeats {
+                list_number: PGNumber::from(1),
+                calculated: 3,
+                result: 2
+            }
+            .to_string(),
+            "List 1 gets 3 seats, but has 2 seats in the result"
+        );
+    }
+
+    #[test]
+    fn test_verify_discrepancies() {
+        let calculated = calculated_elected_fixture();
+        let mut result = calculated.clone();
+        result[0].elected_candidates.pop();
+        result[0].elected_candidates.swap(0, 1);
+        result[0].elected_candidates[0].position = 1;
+        result[0].elected_candidates[1].position = 2;
+        result[1].elected_candidates[0].preferential = false;
+        result[2].elected_candidates[0].last_name = "Kandidaat 9".to_string();
+
+        let list = PGNumber::from;
+        assert_eq!(
+            find_discrepancies(5, &calculated, &result),
+            vec![
+                Discrepancy::NumberOfSeats {
+                    calculated: 5,
+                    result: 4
+                },
+                Discrepancy::ListSeats {
+                    list_number: list(1),
+                    calculated: 3,
+                    result: 2
+                },
+                Discrepancy::CandidatePosition {
+                    list_number: list(1),
+                    name: "Kandidaat 2".to_string(),
+                    calculated: 1,
+                    result: 2
+                },
+                Discrepancy::CandidatePosition {
+                    list_number: list(1),
+                    name: "Kandidaat 3".to_string(),
+                    calculated: 2,
+                    result: 1
+                },
+                Discrepancy::CandidateNotInResult {
+                    list_number: list(1),
+                    name: "Kandidaat 4".to_string()
+                },
+                Discrepancy::PreferentialNomination {
+                    list_number: list(2),
+                    name: "Kandidaat 1".to_string(),
+                    calculated: true,
+                    result: false
+                },
+                Discrepancy::CandidateNotInResult {
+                    list_number: list(3),
+                    name: "Kandidaat 1".to_string()
+                },
+                Discrepancy::CandidateNotElected {
+                    list_number: list(3),
+                    name: "Kandidaat 9".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_needs_central_count() {
+        let mut simulation = Simulation::from_json(
+            r#"{"seats": 1, "lists": [{"number": 1, "candidate_votes": [{"number": 1, "votes": 10}]}]}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            verify(&mut simulation, &[], &mut drawer(&[], &[])),
+            Err(ApportionCliError::Eml(EMLImportError::Needs510d))
+        ));
+    }
+
+    #[test]
+    fn test_apportion_missing_number_of_seats() {
+        let mut simulation = Simulation::from_json(
+            r#"{"lists": [{"number": 1, "candidate_votes": [{"number": 1, "votes": 10}]}]}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            apportion(&mut simulation, &mut drawer(&[], &[])),
+            Err(ApportionCliError::MissingNumberOfSeats)
+        ));
+    }
+
+    #[test]
+    fn test_parse_excluded_candidate() {
+        assert_eq!(
+            parse_excluded_candidate("2:13").unwrap(),
+            ExcludedCandidate {
+                pg_number: PGNumber::from(2),
+                candidate_number: CandidateNumber::from(13),
+                reason: ExclusionReason::Deceased,
+            }
+        );
+        assert_eq!(
+            parse_excluded_candidate("2:13:ineligible").unwrap().reason,
+            ExclusionReason::Ineligible
+        );
+        assert!(parse_excluded_candidate("2").is_err());
+        assert!(parse_excluded_candidate("a:1").is_err());
+        assert!(parse_excluded_candidate("2:13:moved").is_err());
+        assert!(parse_excluded_candidate("2:13:declined:1").is_err());
+    }
+}
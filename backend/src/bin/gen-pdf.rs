@@ -237,6 +237,21 @@ static VARIANTS: &[ModelVariant] = &[
         model: "model-p-22-2-bijlage-1",
         input: "model-p-22-2-bijlage-1.json",
     },
+    ModelVariant {
+        name: "model-referendum",
+        model: "model-referendum",
+        input: "model-referendum.json",
+    },
+    ModelVariant {
+        name: "besluit-vacature",
+        model: "besluit-vacature",
+        input: "besluit-vacature.json",
+    },
+    ModelVariant {
+        name: "bijlage-zetelmarges",
+        model: "bijlage-zetelmarges",
+        input: "bijlage-zetelmarges.json",
+    },
 ];
 
 /// Temporary path to store generated PDFs
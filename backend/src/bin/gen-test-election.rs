@@ -11,7 +11,7 @@ use abacus::{
         election::{
             CommitteeCategory, ElectionCategory, ElectionWithPoliticalGroups, VoteCountingMethod,
         },
-        models::{ModelNa31_2Input, ToPdfFileModel, votes_table::VotesTables},
+        models::{ModelLanguage, ModelNa31_2Input, ToPdfFileModel, votes_table::VotesTables},
         polling_station::PollingStation,
         report::DEFAULT_DATE_TIME_FORMAT,
         results::Results,
@@ -312,9 +312,11 @@ async fn export_election(
             polling_stations: polling_stations.iter().map(Clone::clone).collect(),
             election: election.clone().into(),
             hash: "0000".to_string(),
+            audit_log_hash: "0000".to_string(),
             creation_date_time: chrono::Utc::now()
                 .format(DEFAULT_DATE_TIME_FORMAT)
                 .to_string(),
+            language: ModelLanguage::default(),
         }
         .to_pdf_file_model("file.pdf".to_string());
         let input_json = input.model.get_input();
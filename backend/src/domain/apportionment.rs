@@ -191,20 +191,20 @@ pub struct ListSeatAssignment {
     pub total_seats: u32,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct CandidateNomination {
     pub preference_threshold: PreferenceThreshold,
     pub chosen_candidates: Vec<ChosenCandidate>,
     pub list_candidate_nomination: Vec<ListCandidateNomination>,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct PreferenceThreshold {
     pub percentage: u64,
     pub number_of_votes: DisplayFraction,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct ListCandidateNomination {
     pub list_number: PGNumber,
     pub list_name: String,
@@ -214,6 +214,65 @@ pub struct ListCandidateNomination {
     pub updated_candidate_ranking: Vec<Candidate>,
 }
 
+/// Margins of a completed apportionment, how close the result was to a different outcome
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SeatMargins {
+    pub preference_threshold: PreferenceThreshold,
+    pub list_seat_margins: Vec<ListSeatMargin>,
+    pub list_preference_margins: Vec<ListPreferenceMargins>,
+}
+
+/// Smallest numbers of votes with which the number of seats of a list changes,
+/// absent if no number of votes changes it
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ListSeatMargin {
+    pub list_number: PGNumber,
+    pub list_name: String,
+    pub total_seats: u32,
+    /// Number of votes the list needs to gain to get an additional seat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub votes_to_gain_seat: Option<u32>,
+    /// Other list that needs to lose the fewest votes for this list to get an additional seat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub other_list_votes_to_gain_seat: Option<OtherListMargin>,
+    /// Number of votes the list needs to lose to lose one of its seats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub votes_to_lose_seat: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct OtherListMargin {
+    pub list_number: PGNumber,
+    pub votes: u32,
+}
+
+/// Preference threshold margins of the candidates of a list with seats: the candidates
+/// above the threshold and the candidate below the threshold with the most votes
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ListPreferenceMargins {
+    pub list_number: PGNumber,
+    pub list_name: String,
+    pub candidates: Vec<CandidatePreferenceMargin>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CandidatePreferenceMargin {
+    pub candidate: Candidate,
+    pub votes: u32,
+    pub meets_preference_threshold: bool,
+    /// Number of votes the candidate needs to gain to get above the preference threshold,
+    /// or to lose to no longer be above it
+    pub margin: u32,
+}
+
 impl From<&apportionment::SeatChange<PGNumber>> for SeatChange {
     fn from(change: &apportionment::SeatChange<PGNumber>) -> Self {
         use apportionment::SeatChange::*;
@@ -281,6 +340,161 @@ impl From<&apportionment::SeatChangeStep<PGNumber>> for SeatChangeStep {
     }
 }
 
+/// Article of the Kieswet that a seat change is based on
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+pub enum KieswetArticle {
+    P7,
+    P8,
+    P9,
+    P10,
+}
+
+impl KieswetArticle {
+    /// Returns the title of the article, e.g. "Artikel P 9 Kieswet"
+    pub fn title(self) -> &'static str {
+        apportionment::KieswetArticle::from(self).title()
+    }
+}
+
+impl From<apportionment::KieswetArticle> for KieswetArticle {
+    fn from(article: apportionment::KieswetArticle) -> Self {
+        match article {
+            apportionment::KieswetArticle::P7 => Self::P7,
+            apportionment::KieswetArticle::P8 => Self::P8,
+            apportionment::KieswetArticle::P9 => Self::P9,
+            apportionment::KieswetArticle::P10 => Self::P10,
+        }
+    }
+}
+
+impl From<KieswetArticle> for apportionment::KieswetArticle {
+    fn from(article: KieswetArticle) -> Self {
+        match article {
+            KieswetArticle::P7 => Self::P7,
+            KieswetArticle::P8 => Self::P8,
+            KieswetArticle::P9 => Self::P9,
+            KieswetArticle::P10 => Self::P10,
+        }
+    }
+}
+
+/// Method with which a residual seat was assigned
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+pub enum ResidualSeatMethod {
+    HighestAverage,
+    UniqueHighestAverage,
+    LargestRemainder,
+}
+
+impl From<apportionment::ResidualSeatMethod> for ResidualSeatMethod {
+    fn from(method: apportionment::ResidualSeatMethod) -> Self {
+        match method {
+            apportionment::ResidualSeatMethod::HighestAverage => Self::HighestAverage,
+            apportionment::ResidualSeatMethod::UniqueHighestAverage => Self::UniqueHighestAverage,
+            apportionment::ResidualSeatMethod::LargestRemainder => Self::LargestRemainder,
+        }
+    }
+}
+
+/// The reason for a seat change
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(tag = "reason")]
+pub enum SeatChangeReason {
+    /// The list had the highest average number of votes per seat
+    HighestAverage { votes_per_seat: DisplayFraction },
+    /// The list had the highest average number of votes per seat of the lists that did not
+    /// get a seat with this method yet
+    UniqueHighestAverage { votes_per_seat: DisplayFraction },
+    /// The list had the largest remainder of the lists that meet the threshold of 75% of the
+    /// quota and did not get a seat with this method yet
+    LargestRemainder { remainder_votes: DisplayFraction },
+    /// The list got more than half of the votes, but not more than half of the seats
+    AbsoluteMajority {
+        votes_cast: u64,
+        total_votes_cast: u64,
+        /// The number of seats of the list before the reassignment
+        list_seats: u32,
+        seats: u32,
+        /// The method with which the retracted residual seat was assigned
+        retracted_seat_method: ResidualSeatMethod,
+    },
+    /// The list got more seats than it has candidates
+    ListExhaustion { full_seat: bool },
+}
+
+impl From<apportionment::SeatChangeReason> for SeatChangeReason {
+    fn from(reason: apportionment::SeatChangeReason) -> Self {
+        use apportionment::SeatChangeReason::*;
+
+        match reason {
+            HighestAverage { votes_per_seat } => Self::HighestAverage {
+                votes_per_seat: votes_per_seat.into(),
+            },
+            UniqueHighestAverage { votes_per_seat } => Self::UniqueHighestAverage {
+                votes_per_seat: votes_per_seat.into(),
+            },
+            LargestRemainder { remainder_votes } => Self::LargestRemainder {
+                remainder_votes: remainder_votes.into(),
+            },
+            AbsoluteMajority {
+                votes_cast,
+                total_votes_cast,
+                list_seats,
+                seats,
+                retracted_seat_method,
+            } => Self::AbsoluteMajority {
+                votes_cast,
+                total_votes_cast,
+                list_seats,
+                seats,
+                retracted_seat_method: retracted_seat_method.into(),
+            },
+            ListExhaustion { full_seat } => Self::ListExhaustion { full_seat },
+        }
+    }
+}
+
+/// Explanation of a step of the seat assignment: which seat moved where, why,
+/// and on which articles of the Kieswet this is based
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct SeatChangeExplanation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub residual_seat_number: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub list_assigned_seat: Option<PGNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub list_retracted_seat: Option<PGNumber>,
+    /// The lists that had an equal claim in this step, including the selected list.
+    /// Empty if no other list had an equal claim.
+    pub tied_lists: Vec<PGNumber>,
+    /// Whether lots were drawn between the tied lists
+    pub drawn_lots: bool,
+    pub reason: SeatChangeReason,
+    pub articles: Vec<KieswetArticle>,
+}
+
+impl From<&apportionment::SeatChangeExplanation<PGNumber>> for SeatChangeExplanation {
+    fn from(explanation: &apportionment::SeatChangeExplanation<PGNumber>) -> Self {
+        SeatChangeExplanation {
+            residual_seat_number: explanation.residual_seat_number,
+            list_assigned_seat: explanation.list_assigned_seat,
+            list_retracted_seat: explanation.list_retracted_seat,
+            tied_lists: explanation.tied_lists.clone(),
+            drawn_lots: explanation.drawn_lots,
+            reason: explanation.reason.into(),
+            articles: explanation
+                .articles
+                .iter()
+                .copied()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
 /// Fraction with the integer part split out for display purposes
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 #[cfg_attr(test, derive(Default))]
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    domain::{
+        apportionment::{CandidateDrawn, CandidateNomination, ListDrawn, SeatAssignment},
+        apportionment_state::ExcludedCandidate,
+        committee_session::CommitteeSessionId,
+        election::{CandidateNumber, PGNumber},
+        identifier::id,
+        tabulation::ElectionTotals,
+    },
+    repository::user_repo::UserId,
+};
+
+id!(ApportionmentSnapshotId);
+
+/// Everything the apportionment of a snapshot was computed from
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApportionmentSnapshotInput {
+    pub election_totals: ElectionTotals,
+    pub excluded_candidates: Vec<ExcludedCandidate>,
+    pub lists_drawn: Vec<ListDrawn>,
+    pub candidates_drawn: Vec<CandidateDrawn>,
+}
+
+/// Result of the apportionment of a snapshot
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApportionmentSnapshotOutput {
+    pub seat_assignment: SeatAssignment,
+    pub candidate_nomination: CandidateNomination,
+}
+
+/// Details of a stored apportionment snapshot, without its input and output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApportionmentSnapshotSummary {
+    pub id: ApportionmentSnapshotId,
+    pub committee_session_id: CommitteeSessionId,
+    /// Version of the snapshot, counting from 1 for each election
+    pub version: u32,
+    pub created_by: UserId,
+    #[schema(value_type = String)]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Immutable record of a completed apportionment of a committee session
+#[derive(Debug, PartialEq)]
+pub struct ApportionmentSnapshot {
+    pub summary: ApportionmentSnapshotSummary,
+    pub input: ApportionmentSnapshotInput,
+    pub output: ApportionmentSnapshotOutput,
+}
+
+impl ApportionmentSnapshot {
+    /// Returns true if this snapshot was computed from the same input and has the same output
+    pub fn has_same_result(
+        &self,
+        input: &ApportionmentSnapshotInput,
+        output: &ApportionmentSnapshotOutput,
+    ) -> bool {
+        self.input == *input && self.output == *output
+    }
+
+    /// Determine which lists and candidates changed from this snapshot to the other snapshot
+    pub fn diff(&self, other: &ApportionmentSnapshot) -> ApportionmentSnapshotDiff {
+        let lists_before = self.list_totals();
+        let lists_after = other.list_totals();
+        let lists = lists_before
+            .keys()
+            .chain(lists_after.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|pg_number| {
+                let (votes_before, seats_before) =
+                    lists_before.get(pg_number).copied().unwrap_or_default();
+                let (votes_after, seats_after) =
+                    lists_after.get(pg_number).copied().unwrap_or_default();
+                (votes_before != votes_after || seats_before != seats_after).then_some(
+                    ListSnapshotDiff {
+                        pg_number: *pg_number,
+                        votes_before,
+                        votes_after,
+                        seats_before,
+                        seats_after,
+                    },
+                )
+            })
+            .collect();
+
+        let elected_before = self.elected_candidates();
+        let elected_after = other.elected_candidates();
+
+        ApportionmentSnapshotDiff {
+            from: self.summary.clone(),
+            to: other.summary.clone(),
+            lists,
+            elected_candidates: elected_after.difference(&elected_before).copied().collect(),
+            no_longer_elected_candidates: elected_before
+                .difference(&elected_after)
+                .copied()
+                .collect(),
+            excluded_candidates_added: excluded_difference(
+                &other.input.excluded_candidates,
+                &self.input.excluded_candidates,
+            ),
+            excluded_candidates_removed: excluded_difference(
+                &self.input.excluded_candidates,
+                &other.input.excluded_candidates,
+            ),
+        }
+    }
+
+    /// Votes and total seats per list
+    fn list_totals(&self) -> BTreeMap<PGNumber, (u64, u32)> {
+        self.output
+            .seat_assignment
+            .standings
+            .iter()
+            .map(|standing| {
+                (
+                    standing.list_number,
+                    (standing.votes_cast, standing.total_seats),
+                )
+            })
+            .collect()
+    }
+
+    fn elected_candidates(&self) -> BTreeSet<SnapshotCandidate> {
+        self.output
+            .candidate_nomination
+            .chosen_candidates
+            .iter()
+            .map(|candidate| SnapshotCandidate {
+                pg_number: candidate.list_number,
+                candidate_number: candidate.number,
+            })
+            .collect()
+    }
+}
+
+/// Excluded candidates in `candidates` that are not excluded for the same reason in `other`
+fn excluded_difference(
+    candidates: &[ExcludedCandidate],
+    other: &[ExcludedCandidate],
+) -> Vec<ExcludedCandidate> {
+    candidates
+        .iter()
+        .filter(|candidate| !other.contains(candidate))
+        .copied()
+        .collect()
+}
+
+/// Candidate of a list in an apportionment snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SnapshotCandidate {
+    pub pg_number: PGNumber,
+    pub candidate_number: CandidateNumber,
+}
+
+/// Change of the votes or seats of a list between two apportionment snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListSnapshotDiff {
+    pub pg_number: PGNumber,
+    pub votes_before: u64,
+    pub votes_after: u64,
+    pub seats_before: u32,
+    pub seats_after: u32,
+}
+
+/// Differences between two apportionment snapshots of an election
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApportionmentSnapshotDiff {
+    pub from: ApportionmentSnapshotSummary,
+    pub to: ApportionmentSnapshotSummary,
+    /// Lists of which the number of votes or seats changed
+    pub lists: Vec<ListSnapshotDiff>,
+    /// Candidates that are elected in `to`, but were not elected in `from`
+    pub elected_candidates: Vec<SnapshotCandidate>,
+    /// Candidates that were elected in `from`, but are not elected in `to`
+    pub no_longer_elected_candidates: Vec<SnapshotCandidate>,
+    /// Candidates that are excluded in `to`, but were not excluded (for the same reason) in `from`
+    pub excluded_candidates_added: Vec<ExcludedCandidate>,
+    /// Candidates that were excluded in `from`, but are not excluded (for the same reason) in `to`
+    pub excluded_candidates_removed: Vec<ExcludedCandidate>,
+}
+
+/// List of apportionment snapshots of an election
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApportionmentSnapshotListResponse {
+    pub snapshots: Vec<ApportionmentSnapshotSummary>,
+}
@@ -47,20 +47,50 @@ impl ApiErrorResponse for ApportionmentStateError {
     }
 }
 
+/// Reason why a candidate is excluded from the list
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExclusionReason {
+    /// The candidate is deceased
+    #[default]
+    Deceased,
+    /// The candidate declined, for example by validly withdrawing before the vote
+    Declined,
+    /// The candidate was struck from the list because they do not meet the requirements
+    Ineligible,
+}
+
+/// Candidate that is skipped in list exhaustion and candidate nomination
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, ToSchema)]
-pub struct DeceasedCandidate {
+pub struct ExcludedCandidate {
     pub pg_number: PGNumber,
     pub candidate_number: CandidateNumber,
+    // States stored before other reasons were supported only contain deceased candidates
+    #[serde(default)]
+    #[schema(required = true)]
+    pub reason: ExclusionReason,
+}
+
+impl ExcludedCandidate {
+    /// Whether both refer to the same candidate, regardless of the reason
+    pub fn is_same_candidate(&self, other: &Self) -> bool {
+        self.pg_number == other.pg_number && self.candidate_number == other.candidate_number
+    }
 }
 
 #[cfg(test)]
-impl DeceasedCandidate {
-    pub fn from(pg_number: u32, candidate_number: u32) -> Self {
+impl ExcludedCandidate {
+    pub fn from(pg_number: u32, candidate_number: u32, reason: ExclusionReason) -> Self {
         Self {
             pg_number: PGNumber::from(pg_number),
             candidate_number: CandidateNumber::from(candidate_number),
+            reason,
         }
     }
+
+    pub fn deceased(pg_number: u32, candidate_number: u32) -> Self {
+        Self::from(pg_number, candidate_number, ExclusionReason::Deceased)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, ToSchema)]
@@ -75,27 +105,32 @@ pub enum DrawingLotsRequired {
 pub enum ApportionmentState {
     #[default]
     Uninitialised,
-    RegisteringDeceasedCandidates {
-        deceased_candidates: Vec<DeceasedCandidate>,
+    // The aliases are needed to read states stored when only deceased candidates were registered
+    #[serde(alias = "RegisteringDeceasedCandidates")]
+    RegisteringExcludedCandidates {
+        #[serde(alias = "deceased_candidates")]
+        excluded_candidates: Vec<ExcludedCandidate>,
     },
     DrawingLots {
         drawing_lots_required: DrawingLotsRequired,
-        deceased_candidates: Vec<DeceasedCandidate>,
+        #[serde(alias = "deceased_candidates")]
+        excluded_candidates: Vec<ExcludedCandidate>,
         lists_drawn: Vec<ListDrawn>,
         candidates_drawn: Vec<CandidateDrawn>,
     },
     Finalised {
-        deceased_candidates: Vec<DeceasedCandidate>,
+        #[serde(alias = "deceased_candidates")]
+        excluded_candidates: Vec<ExcludedCandidate>,
         lists_drawn: Vec<ListDrawn>,
         candidates_drawn: Vec<CandidateDrawn>,
     },
 }
 
 impl ApportionmentState {
-    pub fn register_deceased_candidates(self) -> Result<Self, ApportionmentStateError> {
+    pub fn register_excluded_candidates(self) -> Result<Self, ApportionmentStateError> {
         match self {
-            Self::Uninitialised => Ok(Self::RegisteringDeceasedCandidates {
-                deceased_candidates: Vec::new(),
+            Self::Uninitialised => Ok(Self::RegisteringExcludedCandidates {
+                excluded_candidates: Vec::new(),
             }),
             _ => Err(ApportionmentStateError::InvalidState),
         }
@@ -108,26 +143,26 @@ impl ApportionmentState {
         match self {
             Self::Uninitialised => Ok(Self::DrawingLots {
                 drawing_lots_required,
-                deceased_candidates: Vec::new(),
+                excluded_candidates: Vec::new(),
                 lists_drawn: Vec::new(),
                 candidates_drawn: Vec::new(),
             }),
-            Self::RegisteringDeceasedCandidates {
-                deceased_candidates,
+            Self::RegisteringExcludedCandidates {
+                excluded_candidates,
             } => Ok(Self::DrawingLots {
                 drawing_lots_required,
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn: Vec::new(),
                 candidates_drawn: Vec::new(),
             }),
             Self::DrawingLots {
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn,
                 candidates_drawn,
                 ..
             } => Ok(Self::DrawingLots {
                 drawing_lots_required,
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn,
                 candidates_drawn,
             }),
@@ -139,12 +174,12 @@ impl ApportionmentState {
         match self {
             Self::DrawingLots {
                 drawing_lots_required,
-                deceased_candidates,
+                excluded_candidates,
                 mut lists_drawn,
                 candidates_drawn,
             } => Ok(Self::DrawingLots {
                 drawing_lots_required,
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn: {
                     lists_drawn.push(list_drawn);
                     lists_drawn
@@ -158,7 +193,7 @@ impl ApportionmentState {
     pub fn get_lists_drawn(&self) -> &[ListDrawn] {
         match self {
             ApportionmentState::Uninitialised
-            | ApportionmentState::RegisteringDeceasedCandidates { .. } => &[],
+            | ApportionmentState::RegisteringExcludedCandidates { .. } => &[],
             ApportionmentState::DrawingLots { lists_drawn, .. }
             | ApportionmentState::Finalised { lists_drawn, .. } => lists_drawn,
         }
@@ -171,12 +206,12 @@ impl ApportionmentState {
         match self {
             Self::DrawingLots {
                 drawing_lots_required,
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn,
                 mut candidates_drawn,
             } => Ok(Self::DrawingLots {
                 drawing_lots_required,
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn,
                 candidates_drawn: {
                     candidates_drawn.push(candidate_drawn);
@@ -190,7 +225,7 @@ impl ApportionmentState {
     pub fn get_candidates_drawn(&self) -> &[CandidateDrawn] {
         match self {
             ApportionmentState::Uninitialised
-            | ApportionmentState::RegisteringDeceasedCandidates { .. } => &[],
+            | ApportionmentState::RegisteringExcludedCandidates { .. } => &[],
             ApportionmentState::DrawingLots {
                 candidates_drawn, ..
             }
@@ -203,24 +238,24 @@ impl ApportionmentState {
     pub fn finalise(self) -> Result<Self, ApportionmentStateError> {
         match self {
             Self::Uninitialised => Ok(Self::Finalised {
-                deceased_candidates: Vec::new(),
+                excluded_candidates: Vec::new(),
                 lists_drawn: Vec::new(),
                 candidates_drawn: Vec::new(),
             }),
-            Self::RegisteringDeceasedCandidates {
-                deceased_candidates,
+            Self::RegisteringExcludedCandidates {
+                excluded_candidates,
             } => Ok(Self::Finalised {
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn: Vec::new(),
                 candidates_drawn: Vec::new(),
             }),
             Self::DrawingLots {
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn,
                 candidates_drawn,
                 ..
             } => Ok(Self::Finalised {
-                deceased_candidates,
+                excluded_candidates,
                 lists_drawn,
                 candidates_drawn,
             }),
@@ -229,61 +264,67 @@ impl ApportionmentState {
         }
     }
 
-    pub fn get_deceased_candidates(&self) -> &[DeceasedCandidate] {
+    pub fn get_excluded_candidates(&self) -> &[ExcludedCandidate] {
         match self {
             Self::Uninitialised => &[],
-            Self::RegisteringDeceasedCandidates {
-                deceased_candidates,
+            Self::RegisteringExcludedCandidates {
+                excluded_candidates,
             }
             | Self::Finalised {
-                deceased_candidates,
+                excluded_candidates,
                 ..
             }
             | Self::DrawingLots {
-                deceased_candidates,
+                excluded_candidates,
                 ..
-            } => deceased_candidates,
+            } => excluded_candidates,
         }
     }
 
-    pub fn add_deceased_candidate(
+    pub fn add_excluded_candidate(
         self,
-        candidate: DeceasedCandidate,
+        candidate: ExcludedCandidate,
     ) -> Result<Self, ApportionmentStateError> {
         match self {
-            Self::RegisteringDeceasedCandidates {
-                mut deceased_candidates,
+            Self::RegisteringExcludedCandidates {
+                mut excluded_candidates,
             } => {
-                if deceased_candidates.contains(&candidate) {
+                if excluded_candidates
+                    .iter()
+                    .any(|c| c.is_same_candidate(&candidate))
+                {
                     return Err(ApportionmentStateError::CandidateNotUnique);
                 }
 
-                deceased_candidates.push(candidate);
+                excluded_candidates.push(candidate);
 
-                Ok(Self::RegisteringDeceasedCandidates {
-                    deceased_candidates,
+                Ok(Self::RegisteringExcludedCandidates {
+                    excluded_candidates,
                 })
             }
             _ => Err(ApportionmentStateError::InvalidState),
         }
     }
 
-    pub fn delete_deceased_candidate(
+    pub fn delete_excluded_candidate(
         self,
-        candidate: DeceasedCandidate,
+        candidate: ExcludedCandidate,
     ) -> Result<Self, ApportionmentStateError> {
         match self {
-            Self::RegisteringDeceasedCandidates {
-                mut deceased_candidates,
+            Self::RegisteringExcludedCandidates {
+                mut excluded_candidates,
             } => {
-                if !deceased_candidates.contains(&candidate) {
+                if !excluded_candidates
+                    .iter()
+                    .any(|c| c.is_same_candidate(&candidate))
+                {
                     return Err(ApportionmentStateError::CandidateNotFound);
                 }
 
-                deceased_candidates.retain(|c| *c != candidate);
+                excluded_candidates.retain(|c| !c.is_same_candidate(&candidate));
 
-                Ok(Self::RegisteringDeceasedCandidates {
-                    deceased_candidates,
+                Ok(Self::RegisteringExcludedCandidates {
+                    excluded_candidates,
                 })
             }
             _ => Err(ApportionmentStateError::InvalidState),
@@ -358,10 +399,10 @@ mod tests {
         fn draw_lots() {
             #[rustfmt::skip]
             let scenarios = vec![
-                (Uninitialised, Ok(DrawingLots { drawing_lots_required: candidate_required(), deceased_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]})),
-                (RegisteringDeceasedCandidates { deceased_candidates: vec![] }, Ok(DrawingLots { drawing_lots_required: candidate_required(), deceased_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]})),
-                (DrawingLots { drawing_lots_required: list_required(), deceased_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]}, Ok(DrawingLots { drawing_lots_required: candidate_required(), deceased_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]})),
-                (Finalised { deceased_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]}, Err(InvalidState)),
+                (Uninitialised, Ok(DrawingLots { drawing_lots_required: candidate_required(), excluded_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]})),
+                (RegisteringExcludedCandidates { excluded_candidates: vec![] }, Ok(DrawingLots { drawing_lots_required: candidate_required(), excluded_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]})),
+                (DrawingLots { drawing_lots_required: list_required(), excluded_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]}, Ok(DrawingLots { drawing_lots_required: candidate_required(), excluded_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]})),
+                (Finalised { excluded_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]}, Err(InvalidState)),
             ];
 
             for (from, expected) in scenarios {
@@ -377,7 +418,7 @@ mod tests {
         fn add_list_drawn() {
             let state = DrawingLots {
                 drawing_lots_required: list_required(),
-                deceased_candidates: vec![],
+                excluded_candidates: vec![],
                 lists_drawn: vec![],
                 candidates_drawn: vec![],
             };
@@ -388,7 +429,7 @@ mod tests {
                     .expect("add_list_drawn should succeed"),
                 DrawingLots {
                     drawing_lots_required: list_required(),
-                    deceased_candidates: vec![],
+                    excluded_candidates: vec![],
                     lists_drawn: vec![list_drawn()],
                     candidates_drawn: vec![],
                 }
@@ -400,8 +441,8 @@ mod tests {
             #[rustfmt::skip]
             let invalid_states = vec![
                 Uninitialised,
-                RegisteringDeceasedCandidates {deceased_candidates: Vec::new() },
-                Finalised {deceased_candidates: vec![],lists_drawn: vec![],candidates_drawn: vec![] },
+                RegisteringExcludedCandidates {excluded_candidates: Vec::new() },
+                Finalised {excluded_candidates: vec![],lists_drawn: vec![],candidates_drawn: vec![] },
             ];
 
             for state in invalid_states {
@@ -417,7 +458,7 @@ mod tests {
         fn add_candidate_drawn() {
             let state = DrawingLots {
                 drawing_lots_required: candidate_required(),
-                deceased_candidates: vec![],
+                excluded_candidates: vec![],
                 lists_drawn: vec![],
                 candidates_drawn: vec![],
             };
@@ -428,7 +469,7 @@ mod tests {
                     .expect("add_candidate_drawn should succeed"),
                 DrawingLots {
                     drawing_lots_required: candidate_required(),
-                    deceased_candidates: vec![],
+                    excluded_candidates: vec![],
                     lists_drawn: vec![],
                     candidates_drawn: vec![candidate_drawn()],
                 }
@@ -440,8 +481,8 @@ mod tests {
             #[rustfmt::skip]
             let invalid_states = vec![
                 Uninitialised,
-                RegisteringDeceasedCandidates {deceased_candidates: Vec::new() },
-                Finalised {deceased_candidates: vec![],lists_drawn: vec![],candidates_drawn: vec![] },
+                RegisteringExcludedCandidates {excluded_candidates: Vec::new() },
+                Finalised {excluded_candidates: vec![],lists_drawn: vec![],candidates_drawn: vec![] },
             ];
 
             for state in invalid_states {
@@ -463,18 +504,18 @@ mod tests {
         }
 
         #[test]
-        fn register_deceased_candidates() {
+        fn register_excluded_candidates() {
             #[rustfmt::skip]
             let scenarios = vec![
-                (Uninitialised, Ok(RegisteringDeceasedCandidates { deceased_candidates: vec![] })),
-                (RegisteringDeceasedCandidates { deceased_candidates: vec![] }, Err(InvalidState)),
-                (DrawingLots { drawing_lots_required: list_required(), deceased_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]}, Err(InvalidState)),
-                (Finalised { deceased_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![]}, Err(InvalidState)),
+                (Uninitialised, Ok(RegisteringExcludedCandidates { excluded_candidates: vec![] })),
+                (RegisteringExcludedCandidates { excluded_candidates: vec![] }, Err(InvalidState)),
+                (DrawingLots { drawing_lots_required: list_required(), excluded_candidates: vec![], lists_drawn: vec![],candidates_drawn: vec![]}, Err(InvalidState)),
+                (Finalised { excluded_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![]}, Err(InvalidState)),
             ];
 
             for (from, expected) in scenarios {
                 assert_eq!(
-                    from.clone().register_deceased_candidates(),
+                    from.clone().register_excluded_candidates(),
                     expected,
                     "from {from:?}"
                 )
@@ -483,14 +524,14 @@ mod tests {
 
         #[test]
         fn finalise() {
-            let candidate = DeceasedCandidate::from(4, 4);
+            let candidate = ExcludedCandidate::deceased(4, 4);
 
             #[rustfmt::skip]
             let scenarios = vec![
-                (Uninitialised, Ok(Finalised { deceased_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![] })),
-                (RegisteringDeceasedCandidates { deceased_candidates: vec![candidate] }, Ok(Finalised { deceased_candidates: vec![candidate], lists_drawn: vec![], candidates_drawn: vec![] })),
-                (DrawingLots { drawing_lots_required: candidate_required(), deceased_candidates: vec![candidate], lists_drawn: vec![], candidates_drawn: vec![] }, Ok(Finalised { deceased_candidates: vec![candidate], lists_drawn: vec![], candidates_drawn: vec![] })),
-                (Finalised { deceased_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![] }, Err(InvalidState)),
+                (Uninitialised, Ok(Finalised { excluded_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![] })),
+                (RegisteringExcludedCandidates { excluded_candidates: vec![candidate] }, Ok(Finalised { excluded_candidates: vec![candidate], lists_drawn: vec![], candidates_drawn: vec![] })),
+                (DrawingLots { drawing_lots_required: candidate_required(), excluded_candidates: vec![candidate], lists_drawn: vec![], candidates_drawn: vec![] }, Ok(Finalised { excluded_candidates: vec![candidate], lists_drawn: vec![], candidates_drawn: vec![] })),
+                (Finalised { excluded_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![] }, Err(InvalidState)),
             ];
 
             for (from, expected) in scenarios {
@@ -503,9 +544,9 @@ mod tests {
             #[rustfmt::skip]
             let scenarios = vec![
                 Uninitialised,
-                RegisteringDeceasedCandidates { deceased_candidates: Vec::new() },
-                DrawingLots { drawing_lots_required: candidate_required(), deceased_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![]},
-                Finalised {deceased_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![]},
+                RegisteringExcludedCandidates { excluded_candidates: Vec::new() },
+                DrawingLots { drawing_lots_required: candidate_required(), excluded_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![]},
+                Finalised {excluded_candidates: vec![], lists_drawn: vec![], candidates_drawn: vec![]},
             ];
 
             for state in scenarios {
@@ -513,26 +554,26 @@ mod tests {
             }
         }
 
-        mod deceased_candidates {
+        mod excluded_candidates {
             use test_log::test;
 
             use super::*;
 
             #[test]
             fn invalid_state() {
-                let candidate_4 = DeceasedCandidate::from(4, 4);
-                let candidate_13 = DeceasedCandidate::from(4, 13);
+                let candidate_4 = ExcludedCandidate::deceased(4, 4);
+                let candidate_13 = ExcludedCandidate::deceased(4, 13);
 
                 let invalid_states = vec![
                     Uninitialised,
                     DrawingLots {
                         drawing_lots_required: candidate_required(),
-                        deceased_candidates: vec![],
+                        excluded_candidates: vec![],
                         lists_drawn: vec![],
                         candidates_drawn: vec![],
                     },
                     Finalised {
-                        deceased_candidates: vec![candidate_4],
+                        excluded_candidates: vec![candidate_4],
                         lists_drawn: vec![],
                         candidates_drawn: vec![],
                     },
@@ -540,13 +581,13 @@ mod tests {
 
                 for state in invalid_states {
                     assert_eq!(
-                        state.clone().add_deceased_candidate(candidate_13),
+                        state.clone().add_excluded_candidate(candidate_13),
                         Err(InvalidState),
                         "from {state:?}"
                     );
 
                     assert_eq!(
-                        state.clone().delete_deceased_candidate(candidate_4),
+                        state.clone().delete_excluded_candidate(candidate_4),
                         Err(InvalidState),
                         "from {state:?}"
                     );
@@ -554,63 +595,120 @@ mod tests {
             }
 
             #[test]
-            fn add_deceased_candidate() {
-                let candidate_4 = DeceasedCandidate::from(4, 4);
-                let candidate_13 = DeceasedCandidate::from(4, 13);
+            fn add_excluded_candidate() {
+                let candidate_4 = ExcludedCandidate::deceased(4, 4);
+                let candidate_13 = ExcludedCandidate::deceased(4, 13);
 
                 // Start with empty list
-                let status = RegisteringDeceasedCandidates {
-                    deceased_candidates: vec![],
+                let status = RegisteringExcludedCandidates {
+                    excluded_candidates: vec![],
                 };
 
                 // Add successfully
-                let status = status.add_deceased_candidate(candidate_4).unwrap();
+                let status = status.add_excluded_candidate(candidate_4).unwrap();
                 assert_eq!(
                     status,
-                    RegisteringDeceasedCandidates {
-                        deceased_candidates: vec![candidate_4]
+                    RegisteringExcludedCandidates {
+                        excluded_candidates: vec![candidate_4]
                     }
                 );
 
                 // Add another successfully
-                let status = status.add_deceased_candidate(candidate_13).unwrap();
+                let status = status.add_excluded_candidate(candidate_13).unwrap();
                 assert_eq!(
                     status,
-                    RegisteringDeceasedCandidates {
-                        deceased_candidates: vec![candidate_4, candidate_13]
+                    RegisteringExcludedCandidates {
+                        excluded_candidates: vec![candidate_4, candidate_13]
                     }
                 );
 
                 // Try to add again will return an error and do nothing
-                let result = status.add_deceased_candidate(candidate_13);
+                let result = status.clone().add_excluded_candidate(candidate_13);
                 assert_eq!(result, Err(CandidateNotUnique));
+
+                // Also when the reason is different
+                let result = status.add_excluded_candidate(ExcludedCandidate::from(
+                    4,
+                    13,
+                    ExclusionReason::Ineligible,
+                ));
+                assert_eq!(result, Err(CandidateNotUnique));
+            }
+
+            #[test]
+            fn delete_excluded_candidate_with_other_reason() {
+                let candidate = ExcludedCandidate::from(4, 4, ExclusionReason::Declined);
+                let status = RegisteringExcludedCandidates {
+                    excluded_candidates: vec![candidate],
+                };
+
+                let status = status
+                    .delete_excluded_candidate(ExcludedCandidate::deceased(4, 4))
+                    .unwrap();
+                assert_eq!(
+                    status,
+                    RegisteringExcludedCandidates {
+                        excluded_candidates: vec![]
+                    }
+                );
+            }
+
+            #[test]
+            fn deserialize_state_with_deceased_candidates() {
+                let state: ApportionmentState = serde_json::from_value(serde_json::json!({
+                    "type": "RegisteringDeceasedCandidates",
+                    "deceased_candidates": [{ "pg_number": 4, "candidate_number": 4 }],
+                }))
+                .unwrap();
+                assert_eq!(
+                    state,
+                    RegisteringExcludedCandidates {
+                        excluded_candidates: vec![ExcludedCandidate::deceased(4, 4)]
+                    }
+                );
+
+                let state: ApportionmentState = serde_json::from_value(serde_json::json!({
+                    "type": "Finalised",
+                    "deceased_candidates": [],
+                    "lists_drawn": [],
+                    "candidates_drawn": [],
+                }))
+                .unwrap();
+                assert_eq!(
+                    state,
+                    Finalised {
+                        excluded_candidates: vec![],
+                        lists_drawn: vec![],
+                        candidates_drawn: vec![],
+                    }
+                );
             }
 
             #[test]
-            fn delete_deceased_candidate() {
-                let candidate_4 = DeceasedCandidate::from(4, 4);
-                let candidate_13 = DeceasedCandidate::from(4, 13);
+            fn delete_excluded_candidate() {
+                let candidate_4 = ExcludedCandidate::deceased(4, 4);
+                let candidate_13 = ExcludedCandidate::deceased(4, 13);
 
                 // Start with one candidate
-                let status = RegisteringDeceasedCandidates {
-                    deceased_candidates: vec![candidate_4],
+                let status = RegisteringExcludedCandidates {
+                    excluded_candidates: vec![candidate_4],
                 };
 
                 // Try to delete a different candidate will return an error and do nothing
-                let result = status.clone().delete_deceased_candidate(candidate_13);
+                let result = status.clone().delete_excluded_candidate(candidate_13);
                 assert_eq!(result, Err(CandidateNotFound));
 
                 // Delete successfully
-                let status = status.delete_deceased_candidate(candidate_4).unwrap();
+                let status = status.delete_excluded_candidate(candidate_4).unwrap();
                 assert_eq!(
                     status,
-                    RegisteringDeceasedCandidates {
-                        deceased_candidates: vec![]
+                    RegisteringExcludedCandidates {
+                        excluded_candidates: vec![]
                     }
                 );
 
                 // Try to delete again will return an error
-                let result = status.delete_deceased_candidate(candidate_4);
+                let result = status.delete_excluded_candidate(candidate_4);
                 assert_eq!(result, Err(CandidateNotFound));
             }
         }
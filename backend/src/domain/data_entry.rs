@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Type, types::Json};
 use utoipa::ToSchema;
@@ -10,6 +10,7 @@ use crate::{
         committee_session::{CommitteeSession, CommitteeSessionId},
         compare::Compare,
         election::ElectionWithPoliticalGroups,
+        entry_number::EntryNumber,
         field_path::FieldPath,
         identifier::id,
         polling_station::{PollingStationForSession, PollingStationId, PollingStationNumber},
@@ -30,14 +31,19 @@ pub enum DataEntryTransitionError {
     Invalid,
     FirstEntryAlreadyClaimed,
     SecondEntryAlreadyClaimed,
+    ThirdEntryAlreadyClaimed,
     FirstEntryAlreadyFinalised,
     SecondEntryAlreadyFinalised,
     /// An existing first/second data entry needs to be saved, finalised, or discarded by the same user
     CannotTransitionUsingDifferentUser,
     /// The second data entry needs to be claimed by a user other than the one who claimed the first entry
     SecondEntryNeedsDifferentUser,
+    /// The third data entry needs to be claimed by a user other than the ones who did the first and second entry
+    ThirdEntryNeedsDifferentUser,
     /// Correction is not allowed because the entry to be kept has errors
     CorrectionNotAllowed,
+    /// An imported first entry cannot be returned to a typist, it needs to be discarded instead
+    ImportedEntryHasNoTypist,
     ValidatorError(DataError),
     ValidationError(ValidationResults),
 }
@@ -78,6 +84,14 @@ pub struct DataEntryRow {
     pub updated_at: DateTime<Utc>,
 }
 
+impl DataEntryRow {
+    /// Whether an entry is in progress and has not been saved for at least the given timeout,
+    /// so the claim of the typist is abandoned and a first or second entry can be taken over
+    pub fn is_claim_abandoned(&self, timeout: TimeDelta, now: DateTime<Utc>) -> bool {
+        self.state.0.get_claimed_entry().is_some() && now - self.updated_at >= timeout
+    }
+}
+
 impl From<DataEntryRow> for DataEntryStatusResponse {
     fn from(data_entry: DataEntryRow) -> Self {
         DataEntryStatusResponse {
@@ -153,7 +167,7 @@ impl DataEntrySource {
     }
 }
 
-#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(tag = "type", content = "number", deny_unknown_fields)]
 pub enum DataEntrySourceNumber {
     PollingStation(PollingStationNumber),
@@ -208,6 +222,7 @@ pub enum DataEntryStatus {
     FirstEntryFinalised(FirstEntryFinalised),
     SecondEntryInProgress(SecondEntryInProgress),
     EntriesDifferent(EntriesDifferent),
+    EntriesArbitration(EntriesArbitration),
     FirstEntryCorrection(FirstEntryCorrection),
     SecondEntryCorrection(SecondEntryCorrection),
     Definitive(Definitive), // First and second entry are finished
@@ -242,6 +257,10 @@ impl Validate for DataEntryStatus {
                 second_entry: entry,
                 ..
             })
+            | DataEntryStatus::EntriesArbitration(EntriesArbitration {
+                third_entry: Some(ThirdEntry { entry, .. }),
+                ..
+            })
             | DataEntryStatus::Definitive(Definitive { results: entry, .. }) => {
                 entry.validate(election, path)
             }
@@ -263,9 +282,12 @@ impl Validate for DataEntryStatus {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, strum::Display, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[derive(
+    Debug, Serialize, Deserialize, strum::Display, Clone, Copy, PartialEq, Eq, ToSchema, Type,
+)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
 pub enum DataEntryStatusName {
     Empty,
     FirstEntryInProgress,
@@ -273,6 +295,7 @@ pub enum DataEntryStatusName {
     FirstEntryFinalised,
     SecondEntryInProgress,
     EntriesDifferent,
+    EntriesArbitration,
     FirstEntryCorrection,
     SecondEntryCorrection,
     Definitive,
@@ -313,8 +336,14 @@ impl ClientState {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
 #[serde(deny_unknown_fields)]
 pub struct FirstEntryHasErrors {
-    /// User who did the first data entry
-    pub first_entry_user_id: UserId,
+    /// User who did the first data entry, not set when the first entry was imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who imported the first entry from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
     /// First data entry
     pub finalised_first_entry: Results,
     /// When the first data entry was finalised
@@ -325,8 +354,14 @@ pub struct FirstEntryHasErrors {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
 #[serde(deny_unknown_fields)]
 pub struct FirstEntryFinalised {
-    /// User who did the first data entry
-    pub first_entry_user_id: UserId,
+    /// User who did the first data entry, not set when the first entry was imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who imported the first entry from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
     /// First data entry
     pub finalised_first_entry: Results,
     /// When the first data entry was finalised
@@ -339,8 +374,14 @@ pub struct FirstEntryFinalised {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
 #[serde(deny_unknown_fields)]
 pub struct SecondEntryInProgress {
-    /// User who did the first data entry
-    pub first_entry_user_id: UserId,
+    /// User who did the first data entry, not set when the first entry was imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who imported the first entry from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
     /// First data entry
     pub finalised_first_entry: Results,
     /// When the first data entry was finalised
@@ -361,8 +402,39 @@ pub struct SecondEntryInProgress {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
 #[serde(deny_unknown_fields)]
 pub struct EntriesDifferent {
-    /// User who did the first data entry
-    pub first_entry_user_id: UserId,
+    /// User who did the first data entry, not set when the first entry was imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who imported the first entry from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
+    /// User who did the second data entry
+    pub second_entry_user_id: UserId,
+    /// First data entry
+    pub first_entry: Results,
+    /// Second data entry
+    pub second_entry: Results,
+    /// When the first data entry was finalised
+    #[schema(value_type = String)]
+    pub first_entry_finished_at: DateTime<Utc>,
+    /// When the second data entry was finalised
+    #[schema(value_type = String)]
+    pub second_entry_finished_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
+#[serde(deny_unknown_fields)]
+pub struct EntriesArbitration {
+    /// User who did the first data entry, not set when the first entry was imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who imported the first entry from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
     /// User who did the second data entry
     pub second_entry_user_id: UserId,
     /// First data entry
@@ -375,6 +447,36 @@ pub struct EntriesDifferent {
     /// When the second data entry was finalised
     #[schema(value_type = String)]
     pub second_entry_finished_at: DateTime<Utc>,
+    /// Fields that differ between the first and second entry, to be entered by a third typist
+    pub arbitration_fields: Vec<String>,
+    /// Third data entry, once it is claimed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub third_entry: Option<ThirdEntry>,
+}
+
+impl EntriesArbitration {
+    /// User who is doing the third data entry, once it is claimed
+    pub fn third_entry_user_id(&self) -> Option<UserId> {
+        self.third_entry
+            .as_ref()
+            .map(|third_entry| third_entry.user_id)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
+#[serde(deny_unknown_fields)]
+pub struct ThirdEntry {
+    /// User who is doing the third data entry
+    pub user_id: UserId,
+    /// Third data entry
+    pub entry: Results,
+    /// Data entry progress between 0 and 100
+    #[schema(maximum = 100)]
+    pub progress: u8,
+    #[schema(value_type = Object)]
+    /// Client state for the data entry (arbitrary JSON)
+    pub client_state: ClientState,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
@@ -396,7 +498,14 @@ pub struct FirstEntryCorrection {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
 #[serde(deny_unknown_fields)]
 pub struct SecondEntryCorrection {
-    pub first_entry_user_id: UserId,
+    /// User who did the first data entry, not set when the first entry was imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who imported the first entry from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
     pub second_entry_user_id: UserId,
     pub finalised_first_entry: Results,
     pub second_entry: Results,
@@ -413,10 +522,22 @@ pub struct SecondEntryCorrection {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, Type)]
 #[serde(deny_unknown_fields)]
 pub struct Definitive {
-    /// User who did the first data entry
-    pub first_entry_user_id: UserId,
-    /// User who did the second data entry
-    pub second_entry_user_id: UserId,
+    /// User who did the first data entry, not set when the results were imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_entry_user_id: Option<UserId>,
+    /// User who did the second data entry, not set when the results were imported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub second_entry_user_id: Option<UserId>,
+    /// User who did the third data entry, only set when the differences were arbitrated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub third_entry_user_id: Option<UserId>,
+    /// User who imported the definitive results, or the first entry, from a counting file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub imported_by_user_id: Option<UserId>,
     /// The definitive results data
     pub results: Results,
     /// When the result was finalised
@@ -426,6 +547,28 @@ pub struct Definitive {
     pub finalised_with_warnings: bool,
 }
 
+impl Definitive {
+    /// Definitive results of a first and second data entry that agree, where
+    /// the first entry may have been imported
+    fn from_entries(
+        first_entry_user_id: Option<UserId>,
+        imported_by_user_id: Option<UserId>,
+        second_entry_user_id: UserId,
+        results: Results,
+        finalised_with_warnings: bool,
+    ) -> Self {
+        Self {
+            first_entry_user_id,
+            second_entry_user_id: Some(second_entry_user_id),
+            third_entry_user_id: None,
+            imported_by_user_id,
+            results,
+            finished_at: Utc::now(),
+            finalised_with_warnings,
+        }
+    }
+}
+
 /// Data entry update, used for function parameters only
 #[derive(Debug, Clone)]
 pub struct DataEntryUpdate {
@@ -435,6 +578,16 @@ pub struct DataEntryUpdate {
     pub client_state: ClientState,
 }
 
+/// How imported results are recorded in the data entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum ImportEntryTarget {
+    /// Record the imported results as the finalised first entry, to be followed by a second entry
+    FirstEntry,
+    /// Record the imported results as the definitive results
+    Definitive,
+}
+
 impl DataEntryStatus {
     /// Claim of the first entry by a specific typist
     pub fn claim_first_entry(
@@ -475,13 +628,14 @@ impl DataEntryStatus {
     ) -> Result<Self, DataEntryTransitionError> {
         match self {
             DataEntryStatus::FirstEntryFinalised(state) => {
-                if user_id == state.first_entry_user_id {
+                if state.first_entry_user_id == Some(user_id) {
                     Err(DataEntryTransitionError::SecondEntryNeedsDifferentUser)
                 } else if !state.finalised_first_entry.is_same_model(&initial_results) {
                     Err(DataEntryTransitionError::Invalid)
                 } else {
                     Ok(Self::SecondEntryInProgress(SecondEntryInProgress {
                         first_entry_user_id: state.first_entry_user_id,
+                        imported_by_user_id: state.imported_by_user_id,
                         finalised_first_entry: state.finalised_first_entry,
                         first_entry_finished_at: state.first_entry_finished_at,
                         progress: 0,
@@ -615,13 +769,15 @@ impl DataEntryStatus {
 
                 if validation_results.has_errors() {
                     Ok(Self::FirstEntryHasErrors(FirstEntryHasErrors {
-                        first_entry_user_id: state.first_entry_user_id,
+                        first_entry_user_id: Some(state.first_entry_user_id),
+                        imported_by_user_id: None,
                         finalised_first_entry: state.first_entry.clone(),
                         first_entry_finished_at: Utc::now(),
                     }))
                 } else {
                     Ok(Self::FirstEntryFinalised(FirstEntryFinalised {
-                        first_entry_user_id: state.first_entry_user_id,
+                        first_entry_user_id: Some(state.first_entry_user_id),
+                        imported_by_user_id: None,
                         finalised_first_entry: state.first_entry.clone(),
                         first_entry_finished_at: Utc::now(),
                         finalised_with_warnings: validation_results.has_warnings(),
@@ -640,16 +796,17 @@ impl DataEntryStatus {
                         return Err(validation_results.into());
                     }
 
-                    Ok(Self::Definitive(Definitive {
-                        first_entry_user_id: state.first_entry_user_id,
-                        second_entry_user_id: state.second_entry_user_id,
-                        finished_at: Utc::now(),
-                        finalised_with_warnings: validation_results.has_warnings(),
-                        results: state.finalised_second_entry.clone(),
-                    }))
+                    Ok(Self::Definitive(Definitive::from_entries(
+                        Some(state.first_entry_user_id),
+                        None,
+                        state.second_entry_user_id,
+                        state.finalised_second_entry.clone(),
+                        validation_results.has_warnings(),
+                    )))
                 } else {
                     Ok(Self::EntriesDifferent(EntriesDifferent {
-                        first_entry_user_id: state.first_entry_user_id,
+                        first_entry_user_id: Some(state.first_entry_user_id),
+                        imported_by_user_id: None,
                         second_entry_user_id: state.second_entry_user_id,
                         first_entry: state.first_entry.clone(),
                         second_entry: state.finalised_second_entry.clone(),
@@ -675,71 +832,65 @@ impl DataEntryStatus {
         election: &ElectionWithPoliticalGroups,
         user_id: UserId,
     ) -> Result<Self, DataEntryTransitionError> {
-        match &self {
-            DataEntryStatus::SecondEntryInProgress(state) => {
-                if state.second_entry_user_id != user_id {
-                    return Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser);
-                }
-
-                if state.finalised_first_entry == state.second_entry {
-                    let validation_results = self.start_validate(election)?;
-
-                    if validation_results.has_errors() {
-                        return Err(validation_results.into());
-                    }
-
-                    Ok(Self::Definitive(Definitive {
-                        first_entry_user_id: state.first_entry_user_id,
-                        second_entry_user_id: state.second_entry_user_id,
-                        finished_at: Utc::now(),
-                        finalised_with_warnings: validation_results.has_warnings(),
-                        results: state.second_entry.clone(),
-                    }))
-                } else {
-                    Ok(Self::EntriesDifferent(EntriesDifferent {
-                        first_entry_user_id: state.first_entry_user_id,
-                        second_entry_user_id: state.second_entry_user_id,
-                        first_entry: state.finalised_first_entry.clone(),
-                        second_entry: state.second_entry.clone(),
-                        first_entry_finished_at: state.first_entry_finished_at,
-                        second_entry_finished_at: Utc::now(),
-                    }))
-                }
+        // A second entry and a correction of the second entry are finalised the same way
+        let (
+            first_entry_user_id,
+            imported_by_user_id,
+            second_entry_user_id,
+            first_entry,
+            second_entry,
+            first_entry_finished_at,
+        ) = match &self {
+            DataEntryStatus::SecondEntryInProgress(state) => (
+                state.first_entry_user_id,
+                state.imported_by_user_id,
+                state.second_entry_user_id,
+                &state.finalised_first_entry,
+                &state.second_entry,
+                state.first_entry_finished_at,
+            ),
+            DataEntryStatus::SecondEntryCorrection(state) => (
+                state.first_entry_user_id,
+                state.imported_by_user_id,
+                state.second_entry_user_id,
+                &state.finalised_first_entry,
+                &state.second_entry,
+                state.first_entry_finished_at,
+            ),
+            DataEntryStatus::Definitive(_) => {
+                return Err(DataEntryTransitionError::SecondEntryAlreadyFinalised);
             }
-            DataEntryStatus::SecondEntryCorrection(state) => {
-                if state.second_entry_user_id != user_id {
-                    return Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser);
-                }
+            _ => return Err(DataEntryTransitionError::Invalid),
+        };
 
-                if state.finalised_first_entry == state.second_entry {
-                    let validation_results = self.start_validate(election)?;
+        if second_entry_user_id != user_id {
+            return Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser);
+        }
 
-                    if validation_results.has_errors() {
-                        return Err(validation_results.into());
-                    }
+        if first_entry == second_entry {
+            let validation_results = self.start_validate(election)?;
 
-                    Ok(Self::Definitive(Definitive {
-                        first_entry_user_id: state.first_entry_user_id,
-                        second_entry_user_id: state.second_entry_user_id,
-                        finished_at: Utc::now(),
-                        finalised_with_warnings: validation_results.has_warnings(),
-                        results: state.second_entry.clone(),
-                    }))
-                } else {
-                    Ok(Self::EntriesDifferent(EntriesDifferent {
-                        first_entry_user_id: state.first_entry_user_id,
-                        second_entry_user_id: state.second_entry_user_id,
-                        first_entry: state.finalised_first_entry.clone(),
-                        second_entry: state.second_entry.clone(),
-                        first_entry_finished_at: state.first_entry_finished_at,
-                        second_entry_finished_at: Utc::now(),
-                    }))
-                }
+            if validation_results.has_errors() {
+                return Err(validation_results.into());
             }
-            DataEntryStatus::Definitive(_) => {
-                Err(DataEntryTransitionError::SecondEntryAlreadyFinalised)
-            }
-            _ => Err(DataEntryTransitionError::Invalid),
+
+            Ok(Self::Definitive(Definitive::from_entries(
+                first_entry_user_id,
+                imported_by_user_id,
+                second_entry_user_id,
+                second_entry.clone(),
+                validation_results.has_warnings(),
+            )))
+        } else {
+            Ok(Self::EntriesDifferent(EntriesDifferent {
+                first_entry_user_id,
+                imported_by_user_id,
+                second_entry_user_id,
+                first_entry: first_entry.clone(),
+                second_entry: second_entry.clone(),
+                first_entry_finished_at,
+                second_entry_finished_at: Utc::now(),
+            }))
         }
     }
 
@@ -773,7 +924,8 @@ impl DataEntryStatus {
                 }
 
                 Ok(Self::FirstEntryFinalised(FirstEntryFinalised {
-                    first_entry_user_id: state.second_entry_user_id,
+                    first_entry_user_id: Some(state.second_entry_user_id),
+                    imported_by_user_id: None,
                     finalised_first_entry: state.finalised_second_entry,
                     first_entry_finished_at: state.second_entry_finished_at,
                     finalised_with_warnings: validation_results.has_warnings(),
@@ -801,6 +953,7 @@ impl DataEntryStatus {
                 finalised_first_entry,
                 first_entry_finished_at,
                 first_entry_user_id,
+                imported_by_user_id,
                 second_entry_user_id,
                 ..
             }) => {
@@ -819,6 +972,7 @@ impl DataEntryStatus {
 
                 Ok(DataEntryStatus::FirstEntryFinalised(FirstEntryFinalised {
                     first_entry_user_id,
+                    imported_by_user_id,
                     finalised_first_entry,
                     first_entry_finished_at,
                     finalised_with_warnings: validation_results.has_warnings(),
@@ -840,6 +994,7 @@ impl DataEntryStatus {
 
                 Ok(Self::FirstEntryFinalised(FirstEntryFinalised {
                     first_entry_user_id: state.first_entry_user_id,
+                    imported_by_user_id: state.imported_by_user_id,
                     finalised_first_entry: state.finalised_first_entry,
                     first_entry_finished_at: state.first_entry_finished_at,
                     finalised_with_warnings: validation_results.has_warnings(),
@@ -852,13 +1007,102 @@ impl DataEntryStatus {
         }
     }
 
-    /// Resume first data entry while resolving accepted errors
+    /// Hand over an entry in progress, or a correction or third entry, to another
+    /// typist, keeping the progress, the entered results and the client state.
+    /// The first, second and third entry still need to be done by different typists.
+    pub fn take_over_entry_in_progress(
+        self,
+        entry_number: EntryNumber,
+        user_id: UserId,
+    ) -> Result<Self, DataEntryTransitionError> {
+        self.check_take_over(entry_number, user_id)?;
+
+        match self {
+            DataEntryStatus::FirstEntryInProgress(state) => {
+                Ok(Self::FirstEntryInProgress(FirstEntryInProgress {
+                    first_entry_user_id: user_id,
+                    ..state
+                }))
+            }
+            DataEntryStatus::FirstEntryCorrection(state) => {
+                Ok(Self::FirstEntryCorrection(FirstEntryCorrection {
+                    first_entry_user_id: user_id,
+                    ..state
+                }))
+            }
+            DataEntryStatus::SecondEntryInProgress(state) => {
+                Ok(Self::SecondEntryInProgress(SecondEntryInProgress {
+                    second_entry_user_id: user_id,
+                    ..state
+                }))
+            }
+            DataEntryStatus::SecondEntryCorrection(state) => {
+                Ok(Self::SecondEntryCorrection(SecondEntryCorrection {
+                    second_entry_user_id: user_id,
+                    ..state
+                }))
+            }
+            DataEntryStatus::EntriesArbitration(mut state) => {
+                if let Some(third_entry) = state.third_entry.as_mut() {
+                    third_entry.user_id = user_id;
+                }
+                Ok(Self::EntriesArbitration(state))
+            }
+            _ => Err(DataEntryTransitionError::Invalid),
+        }
+    }
+
+    /// Check that the given entry is claimed by another typist and that the user taking it
+    /// over has not done one of the other entries
+    fn check_take_over(
+        &self,
+        entry_number: EntryNumber,
+        user_id: UserId,
+    ) -> Result<(), DataEntryTransitionError> {
+        let Some((claimed_entry_number, claimed_by, _)) = self.get_claimed_entry() else {
+            return Err(DataEntryTransitionError::Invalid);
+        };
+        if claimed_entry_number != entry_number {
+            return Err(DataEntryTransitionError::Invalid);
+        }
+        if claimed_by == user_id {
+            return Err(match entry_number {
+                EntryNumber::FirstEntry => DataEntryTransitionError::FirstEntryAlreadyClaimed,
+                EntryNumber::SecondEntry => DataEntryTransitionError::SecondEntryAlreadyClaimed,
+                EntryNumber::ThirdEntry => DataEntryTransitionError::ThirdEntryAlreadyClaimed,
+            });
+        }
+
+        let done_other_entry = [
+            EntryNumber::FirstEntry,
+            EntryNumber::SecondEntry,
+            EntryNumber::ThirdEntry,
+        ]
+        .into_iter()
+        .filter(|other| *other != entry_number)
+        .any(|other| self.get_entry_user_id(other) == Some(user_id));
+        if done_other_entry {
+            return Err(match entry_number {
+                EntryNumber::ThirdEntry => DataEntryTransitionError::ThirdEntryNeedsDifferentUser,
+                _ => DataEntryTransitionError::SecondEntryNeedsDifferentUser,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Resume first data entry while resolving accepted errors.
+    /// An imported first entry has no typist to resume it, so it can only be discarded.
     pub fn resume_first_entry_with_errors(&self) -> Result<Self, DataEntryTransitionError> {
         match self {
             DataEntryStatus::FirstEntryHasErrors(state) => {
+                let Some(first_entry_user_id) = state.first_entry_user_id else {
+                    return Err(DataEntryTransitionError::ImportedEntryHasNoTypist);
+                };
+
                 Ok(Self::FirstEntryInProgress(FirstEntryInProgress {
                     progress: 0,
-                    first_entry_user_id: state.first_entry_user_id,
+                    first_entry_user_id,
                     first_entry: state.finalised_first_entry.clone(),
                     client_state: Default::default(),
                     is_correction: true,
@@ -896,12 +1140,14 @@ impl DataEntryStatus {
                 if validation_results.has_errors() {
                     Ok(Self::FirstEntryHasErrors(FirstEntryHasErrors {
                         first_entry_user_id: state.first_entry_user_id,
+                        imported_by_user_id: state.imported_by_user_id,
                         finalised_first_entry: state.first_entry.clone(),
                         first_entry_finished_at: state.first_entry_finished_at,
                     }))
                 } else {
                     Ok(Self::FirstEntryFinalised(FirstEntryFinalised {
                         first_entry_user_id: state.first_entry_user_id,
+                        imported_by_user_id: state.imported_by_user_id,
                         finalised_first_entry: state.first_entry.clone(),
                         first_entry_finished_at: state.first_entry_finished_at,
                         finalised_with_warnings: validation_results.has_warnings(),
@@ -925,13 +1171,15 @@ impl DataEntryStatus {
 
                 if validation_results.has_errors() {
                     Ok(Self::FirstEntryHasErrors(FirstEntryHasErrors {
-                        first_entry_user_id: state.second_entry_user_id,
+                        first_entry_user_id: Some(state.second_entry_user_id),
+                        imported_by_user_id: None,
                         finalised_first_entry: state.second_entry.clone(),
                         first_entry_finished_at: state.second_entry_finished_at,
                     }))
                 } else {
                     Ok(Self::FirstEntryFinalised(FirstEntryFinalised {
-                        first_entry_user_id: state.second_entry_user_id,
+                        first_entry_user_id: Some(state.second_entry_user_id),
+                        imported_by_user_id: None,
                         finalised_first_entry: state.second_entry.clone(),
                         first_entry_finished_at: state.second_entry_finished_at,
                         finalised_with_warnings: validation_results.has_warnings(),
@@ -943,19 +1191,23 @@ impl DataEntryStatus {
     }
 
     /// Correct first entry while resolving differences
-    /// This is only allowed when the second entry has no errors.
+    /// This is only allowed when the second entry has no errors, and when the
+    /// first entry was not imported.
     pub fn correct_first_entry(
         self,
         election: &ElectionWithPoliticalGroups,
     ) -> Result<Self, DataEntryTransitionError> {
         match &self {
             DataEntryStatus::EntriesDifferent(state) => {
+                let Some(first_entry_user_id) = state.first_entry_user_id else {
+                    return Err(DataEntryTransitionError::ImportedEntryHasNoTypist);
+                };
                 if state.second_entry.start_validate(election)?.has_errors() {
                     return Err(DataEntryTransitionError::CorrectionNotAllowed);
                 }
 
                 Ok(Self::FirstEntryCorrection(FirstEntryCorrection {
-                    first_entry_user_id: state.first_entry_user_id,
+                    first_entry_user_id,
                     second_entry_user_id: state.second_entry_user_id,
                     first_entry: state.first_entry.clone(),
                     finalised_second_entry: state.second_entry.clone(),
@@ -982,6 +1234,7 @@ impl DataEntryStatus {
 
                 Ok(Self::SecondEntryCorrection(SecondEntryCorrection {
                     first_entry_user_id: state.first_entry_user_id,
+                    imported_by_user_id: state.imported_by_user_id,
                     finalised_first_entry: state.first_entry.clone(),
                     second_entry_user_id: state.second_entry_user_id,
                     second_entry: state.second_entry.clone(),
@@ -994,73 +1247,468 @@ impl DataEntryStatus {
         }
     }
 
-    /// Get the progress of the current data entry (0..100) if data entry is in progress
-    pub fn get_data_entry_progress(&self) -> Option<u8> {
-        match self {
-            DataEntryStatus::Empty => None,
-            DataEntryStatus::FirstEntryInProgress(state) => Some(state.progress),
-            DataEntryStatus::FirstEntryHasErrors(_) => None,
-            DataEntryStatus::FirstEntryFinalised(_) => None,
-            DataEntryStatus::SecondEntryInProgress(state) => Some(state.progress),
-            DataEntryStatus::EntriesDifferent(_) => None,
-            DataEntryStatus::FirstEntryCorrection(state) => Some(state.progress),
-            DataEntryStatus::SecondEntryCorrection(state) => Some(state.progress),
-            DataEntryStatus::Definitive(_) => None,
-        }
-    }
+    /// Have a third typist enter the fields that differ while resolving differences
+    pub fn arbitrate_entries(self) -> Result<Self, DataEntryTransitionError> {
+        let arbitration_fields = match self.compare_entries() {
+            Some(fields) if !fields.is_empty() => fields,
+            _ => return Err(DataEntryTransitionError::Invalid),
+        };
 
-    /// Get the user ID of the first entry typist
-    pub fn get_first_entry_user_id(&self) -> Option<UserId> {
         match self {
-            DataEntryStatus::Empty => None,
-            DataEntryStatus::FirstEntryInProgress(state) => Some(state.first_entry_user_id),
-            DataEntryStatus::FirstEntryHasErrors(state) => Some(state.first_entry_user_id),
-            DataEntryStatus::FirstEntryFinalised(state) => Some(state.first_entry_user_id),
-            DataEntryStatus::SecondEntryInProgress(state) => Some(state.first_entry_user_id),
-            DataEntryStatus::EntriesDifferent(state) => Some(state.first_entry_user_id),
-            DataEntryStatus::FirstEntryCorrection(state) => Some(state.first_entry_user_id),
-            DataEntryStatus::SecondEntryCorrection(state) => Some(state.first_entry_user_id),
-            DataEntryStatus::Definitive(state) => Some(state.first_entry_user_id),
+            DataEntryStatus::EntriesDifferent(state) => {
+                Ok(Self::EntriesArbitration(EntriesArbitration {
+                    first_entry_user_id: state.first_entry_user_id,
+                    imported_by_user_id: state.imported_by_user_id,
+                    second_entry_user_id: state.second_entry_user_id,
+                    first_entry: state.first_entry,
+                    second_entry: state.second_entry,
+                    first_entry_finished_at: state.first_entry_finished_at,
+                    second_entry_finished_at: state.second_entry_finished_at,
+                    arbitration_fields,
+                    third_entry: None,
+                }))
+            }
+            _ => Err(DataEntryTransitionError::Invalid),
         }
     }
 
-    /// Get the user ID of the second entry typist
-    pub fn get_second_entry_user_id(&self) -> Option<UserId> {
+    /// Claim of the third entry by a typist who did not do the first or second entry.
+    /// The fields that differ are reset to their initial values, so the third typist
+    /// enters them independently of the first and second entry.
+    pub fn claim_third_entry(
+        self,
+        user_id: UserId,
+        initial_results: &Results,
+    ) -> Result<Self, DataEntryTransitionError> {
         match self {
-            DataEntryStatus::Empty
-            | DataEntryStatus::FirstEntryInProgress(_)
-            | DataEntryStatus::FirstEntryHasErrors(_)
-            | DataEntryStatus::FirstEntryFinalised(_) => None,
-            DataEntryStatus::SecondEntryInProgress(state) => Some(state.second_entry_user_id),
-            DataEntryStatus::EntriesDifferent(state) => Some(state.second_entry_user_id),
-            DataEntryStatus::FirstEntryCorrection(state) => Some(state.second_entry_user_id),
-            DataEntryStatus::SecondEntryCorrection(state) => Some(state.second_entry_user_id),
-            DataEntryStatus::Definitive(state) => Some(state.second_entry_user_id),
+            DataEntryStatus::EntriesArbitration(state) => match &state.third_entry {
+                None => {
+                    if state.first_entry_user_id == Some(user_id)
+                        || state.second_entry_user_id == user_id
+                    {
+                        return Err(DataEntryTransitionError::ThirdEntryNeedsDifferentUser);
+                    }
+
+                    if !state.first_entry.is_same_model(initial_results) {
+                        return Err(DataEntryTransitionError::Invalid);
+                    }
+
+                    let third_entry = copy_fields(
+                        &state.first_entry,
+                        initial_results,
+                        &state.arbitration_fields,
+                    )?;
+
+                    Ok(Self::EntriesArbitration(EntriesArbitration {
+                        third_entry: Some(ThirdEntry {
+                            user_id,
+                            entry: third_entry,
+                            progress: 0,
+                            client_state: ClientState::default(),
+                        }),
+                        ..state
+                    }))
+                }
+                Some(third_entry) if third_entry.user_id == user_id => {
+                    Ok(Self::EntriesArbitration(state))
+                }
+                Some(_) => Err(DataEntryTransitionError::ThirdEntryAlreadyClaimed),
+            },
+            DataEntryStatus::Definitive(_) => {
+                Err(DataEntryTransitionError::SecondEntryAlreadyFinalised)
+            }
+            _ => Err(DataEntryTransitionError::Invalid),
         }
     }
 
-    /// Get the data for the current entry if there is any
-    pub fn get_data(&self) -> Option<&Results> {
+    /// Update the data in the third entry while it is in progress
+    pub fn update_third_entry(
+        self,
+        update: DataEntryUpdate,
+    ) -> Result<Self, DataEntryTransitionError> {
         match self {
-            DataEntryStatus::FirstEntryInProgress(state) => Some(&state.first_entry),
-            DataEntryStatus::SecondEntryInProgress(state) => Some(&state.second_entry),
-            DataEntryStatus::FirstEntryCorrection(state) => Some(&state.first_entry),
-            DataEntryStatus::SecondEntryCorrection(state) => Some(&state.second_entry),
-            DataEntryStatus::Empty
-            | DataEntryStatus::FirstEntryHasErrors(_)
-            | DataEntryStatus::FirstEntryFinalised(_)
-            | DataEntryStatus::EntriesDifferent(_)
-            | DataEntryStatus::Definitive(_) => None,
+            DataEntryStatus::EntriesArbitration(state) => {
+                if state.third_entry_user_id() != Some(update.user_id) {
+                    return Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser);
+                }
+
+                if !state.first_entry.is_same_model(&update.entry) {
+                    return Err(DataEntryTransitionError::Invalid);
+                }
+
+                Ok(Self::EntriesArbitration(EntriesArbitration {
+                    third_entry: Some(ThirdEntry {
+                        user_id: update.user_id,
+                        entry: update.entry,
+                        progress: update.progress,
+                        client_state: update.client_state,
+                    }),
+                    ..state
+                }))
+            }
+            DataEntryStatus::Definitive(_) => {
+                Err(DataEntryTransitionError::SecondEntryAlreadyFinalised)
+            }
+            _ => Err(DataEntryTransitionError::Invalid),
         }
     }
 
-    /// Extract the client state if there is any
-    pub fn get_client_state(&self) -> Option<&serde_json::Value> {
+    /// Complete the third entry and accept, for each field that differed, the value that
+    /// two of the three entries agree on. If a field still has no majority, or the accepted
+    /// results have errors, the differences are returned to the coordinator.
+    pub fn finalise_third_entry(
+        self,
+        election: &ElectionWithPoliticalGroups,
+        user_id: UserId,
+    ) -> Result<Self, DataEntryTransitionError> {
+        match self {
+            DataEntryStatus::EntriesArbitration(state) => {
+                let Some(third_entry) = state
+                    .third_entry
+                    .as_ref()
+                    .filter(|third_entry| third_entry.user_id == user_id)
+                else {
+                    return Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser);
+                };
+
+                // Only the fields that differed are taken from the third entry,
+                // all other fields are the same in the first and second entry
+                let results = copy_fields(
+                    &state.first_entry,
+                    &third_entry.entry,
+                    &state.arbitration_fields,
+                )?;
+
+                if has_majority(&results, &state.first_entry, &state.second_entry) {
+                    let validation_results = results.start_validate(election)?;
+
+                    if !validation_results.has_errors() {
+                        return Ok(Self::Definitive(Definitive {
+                            third_entry_user_id: Some(third_entry.user_id),
+                            ..Definitive::from_entries(
+                                state.first_entry_user_id,
+                                state.imported_by_user_id,
+                                state.second_entry_user_id,
+                                results,
+                                validation_results.has_warnings(),
+                            )
+                        }));
+                    }
+                }
+
+                Ok(Self::EntriesDifferent(EntriesDifferent {
+                    first_entry_user_id: state.first_entry_user_id,
+                    imported_by_user_id: state.imported_by_user_id,
+                    second_entry_user_id: state.second_entry_user_id,
+                    first_entry: state.first_entry,
+                    second_entry: state.second_entry,
+                    first_entry_finished_at: state.first_entry_finished_at,
+                    second_entry_finished_at: state.second_entry_finished_at,
+                }))
+            }
+            DataEntryStatus::Definitive(_) => {
+                Err(DataEntryTransitionError::SecondEntryAlreadyFinalised)
+            }
+            _ => Err(DataEntryTransitionError::Invalid),
+        }
+    }
+
+    /// Discard the third entry while it is in progress, so another typist can claim it
+    pub fn discard_third_entry_in_progress(
+        self,
+        user_id: UserId,
+    ) -> Result<Self, DataEntryTransitionError> {
+        match self {
+            DataEntryStatus::EntriesArbitration(state) => {
+                if state.third_entry_user_id() != Some(user_id) {
+                    return Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser);
+                }
+
+                Ok(Self::EntriesArbitration(EntriesArbitration {
+                    third_entry: None,
+                    ..state
+                }))
+            }
+            DataEntryStatus::Definitive(_) => {
+                Err(DataEntryTransitionError::SecondEntryAlreadyFinalised)
+            }
+            _ => Err(DataEntryTransitionError::Invalid),
+        }
+    }
+
+    /// Import results from an external source (e.g. an EML file) into an empty data entry,
+    /// either as finalised first entry or as definitive results
+    pub fn import_entry(
+        self,
+        election: &ElectionWithPoliticalGroups,
+        user_id: UserId,
+        results: Results,
+        target: ImportEntryTarget,
+    ) -> Result<Self, DataEntryTransitionError> {
+        match self {
+            DataEntryStatus::Empty => {
+                let validation_results = results.start_validate(election)?;
+
+                match target {
+                    ImportEntryTarget::FirstEntry => {
+                        if validation_results.has_errors() {
+                            Ok(Self::FirstEntryHasErrors(FirstEntryHasErrors {
+                                first_entry_user_id: None,
+                                imported_by_user_id: Some(user_id),
+                                finalised_first_entry: results,
+                                first_entry_finished_at: Utc::now(),
+                            }))
+                        } else {
+                            Ok(Self::FirstEntryFinalised(FirstEntryFinalised {
+                                first_entry_user_id: None,
+                                imported_by_user_id: Some(user_id),
+                                finalised_first_entry: results,
+                                first_entry_finished_at: Utc::now(),
+                                finalised_with_warnings: validation_results.has_warnings(),
+                            }))
+                        }
+                    }
+                    ImportEntryTarget::Definitive => {
+                        if validation_results.has_errors() {
+                            return Err(validation_results.into());
+                        }
+
+                        Ok(Self::Definitive(Definitive {
+                            first_entry_user_id: None,
+                            second_entry_user_id: None,
+                            third_entry_user_id: None,
+                            imported_by_user_id: Some(user_id),
+                            finished_at: Utc::now(),
+                            finalised_with_warnings: validation_results.has_warnings(),
+                            results,
+                        }))
+                    }
+                }
+            }
+            DataEntryStatus::FirstEntryFinalised(_) | DataEntryStatus::SecondEntryInProgress(_) => {
+                Err(DataEntryTransitionError::FirstEntryAlreadyFinalised)
+            }
+            DataEntryStatus::Definitive(_) => {
+                Err(DataEntryTransitionError::SecondEntryAlreadyFinalised)
+            }
+            _ => Err(DataEntryTransitionError::Invalid),
+        }
+    }
+
+    /// Get the progress of the current data entry (0..100) if data entry is in progress
+    pub fn get_data_entry_progress(&self) -> Option<u8> {
+        match self {
+            DataEntryStatus::Empty => None,
+            DataEntryStatus::FirstEntryInProgress(state) => Some(state.progress),
+            DataEntryStatus::FirstEntryHasErrors(_) => None,
+            DataEntryStatus::FirstEntryFinalised(_) => None,
+            DataEntryStatus::SecondEntryInProgress(state) => Some(state.progress),
+            DataEntryStatus::EntriesDifferent(_) => None,
+            DataEntryStatus::EntriesArbitration(state) => state
+                .third_entry
+                .as_ref()
+                .map(|third_entry| third_entry.progress),
+            DataEntryStatus::FirstEntryCorrection(state) => Some(state.progress),
+            DataEntryStatus::SecondEntryCorrection(state) => Some(state.progress),
+            DataEntryStatus::Definitive(_) => None,
+        }
+    }
+
+    /// Get the user ID of the first entry typist
+    pub fn get_first_entry_user_id(&self) -> Option<UserId> {
+        match self {
+            DataEntryStatus::Empty => None,
+            DataEntryStatus::FirstEntryInProgress(state) => Some(state.first_entry_user_id),
+            DataEntryStatus::FirstEntryHasErrors(state) => state.first_entry_user_id,
+            DataEntryStatus::FirstEntryFinalised(state) => state.first_entry_user_id,
+            DataEntryStatus::SecondEntryInProgress(state) => state.first_entry_user_id,
+            DataEntryStatus::EntriesDifferent(state) => state.first_entry_user_id,
+            DataEntryStatus::EntriesArbitration(state) => state.first_entry_user_id,
+            DataEntryStatus::FirstEntryCorrection(state) => Some(state.first_entry_user_id),
+            DataEntryStatus::SecondEntryCorrection(state) => state.first_entry_user_id,
+            DataEntryStatus::Definitive(state) => state.first_entry_user_id,
+        }
+    }
+
+    /// Get the user ID of the second entry typist
+    pub fn get_second_entry_user_id(&self) -> Option<UserId> {
         match self {
-            DataEntryStatus::FirstEntryInProgress(state) => state.client_state.as_ref(),
-            DataEntryStatus::SecondEntryInProgress(state) => state.client_state.as_ref(),
-            DataEntryStatus::FirstEntryCorrection(state) => state.client_state.as_ref(),
-            DataEntryStatus::SecondEntryCorrection(state) => state.client_state.as_ref(),
+            DataEntryStatus::Empty
+            | DataEntryStatus::FirstEntryInProgress(_)
+            | DataEntryStatus::FirstEntryHasErrors(_)
+            | DataEntryStatus::FirstEntryFinalised(_) => None,
+            DataEntryStatus::SecondEntryInProgress(state) => Some(state.second_entry_user_id),
+            DataEntryStatus::EntriesDifferent(state) => Some(state.second_entry_user_id),
+            DataEntryStatus::EntriesArbitration(state) => Some(state.second_entry_user_id),
+            DataEntryStatus::FirstEntryCorrection(state) => Some(state.second_entry_user_id),
+            DataEntryStatus::SecondEntryCorrection(state) => Some(state.second_entry_user_id),
+            DataEntryStatus::Definitive(state) => state.second_entry_user_id,
+        }
+    }
+
+    /// Get the user ID of the third entry typist
+    pub fn get_third_entry_user_id(&self) -> Option<UserId> {
+        match self {
+            DataEntryStatus::EntriesArbitration(state) => state.third_entry_user_id(),
+            DataEntryStatus::Definitive(state) => state.third_entry_user_id,
+            _ => None,
+        }
+    }
+
+    /// Get the user ID of the typist who did the given entry, or is doing it
+    pub fn get_entry_user_id(&self, entry_number: EntryNumber) -> Option<UserId> {
+        match entry_number {
+            EntryNumber::FirstEntry => self.get_first_entry_user_id(),
+            EntryNumber::SecondEntry => self.get_second_entry_user_id(),
+            EntryNumber::ThirdEntry => self.get_third_entry_user_id(),
+        }
+    }
+
+    /// Whether the given entry can be claimed by the user, either to start it or to resume it
+    pub fn is_claimable_by(&self, entry_number: EntryNumber, user_id: UserId) -> bool {
+        match (entry_number, self) {
+            (EntryNumber::FirstEntry, DataEntryStatus::Empty) => true,
+            (
+                EntryNumber::FirstEntry,
+                DataEntryStatus::FirstEntryInProgress(_) | DataEntryStatus::FirstEntryCorrection(_),
+            ) => self.get_first_entry_user_id() == Some(user_id),
+            (EntryNumber::SecondEntry, DataEntryStatus::FirstEntryFinalised(state)) => {
+                state.first_entry_user_id != Some(user_id)
+            }
+            (
+                EntryNumber::SecondEntry,
+                DataEntryStatus::SecondEntryInProgress(_)
+                | DataEntryStatus::SecondEntryCorrection(_),
+            ) => self.get_second_entry_user_id() == Some(user_id),
+            (EntryNumber::ThirdEntry, DataEntryStatus::EntriesArbitration(state)) => {
+                match state.third_entry_user_id() {
+                    Some(third_entry_user_id) => third_entry_user_id == user_id,
+                    None => {
+                        state.first_entry_user_id != Some(user_id)
+                            && state.second_entry_user_id != user_id
+                    }
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the entry that a typist is currently working on, with the user ID of the typist and
+    /// the progress of the entry
+    pub fn get_claimed_entry(&self) -> Option<(EntryNumber, UserId, u8)> {
+        match self {
+            DataEntryStatus::FirstEntryInProgress(state) => Some((
+                EntryNumber::FirstEntry,
+                state.first_entry_user_id,
+                state.progress,
+            )),
+            DataEntryStatus::FirstEntryCorrection(state) => Some((
+                EntryNumber::FirstEntry,
+                state.first_entry_user_id,
+                state.progress,
+            )),
+            DataEntryStatus::SecondEntryInProgress(state) => Some((
+                EntryNumber::SecondEntry,
+                state.second_entry_user_id,
+                state.progress,
+            )),
+            DataEntryStatus::SecondEntryCorrection(state) => Some((
+                EntryNumber::SecondEntry,
+                state.second_entry_user_id,
+                state.progress,
+            )),
+            DataEntryStatus::EntriesArbitration(state) => {
+                state.third_entry.as_ref().map(|third_entry| {
+                    (
+                        EntryNumber::ThirdEntry,
+                        third_entry.user_id,
+                        third_entry.progress,
+                    )
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the user ID of the typist that is currently working on the given entry, if any
+    pub fn get_entry_in_progress_user_id(&self, entry_number: EntryNumber) -> Option<UserId> {
+        self.get_claimed_entry()
+            .filter(|(claimed_entry_number, ..)| *claimed_entry_number == entry_number)
+            .map(|(_, user_id, _)| user_id)
+    }
+
+    /// Get the data for the current entry if there is any
+    pub fn get_data(&self) -> Option<&Results> {
+        match self {
+            DataEntryStatus::FirstEntryInProgress(state) => Some(&state.first_entry),
+            DataEntryStatus::SecondEntryInProgress(state) => Some(&state.second_entry),
+            DataEntryStatus::FirstEntryCorrection(state) => Some(&state.first_entry),
+            DataEntryStatus::SecondEntryCorrection(state) => Some(&state.second_entry),
+            DataEntryStatus::EntriesArbitration(state) => state
+                .third_entry
+                .as_ref()
+                .map(|third_entry| &third_entry.entry),
+            DataEntryStatus::Empty
+            | DataEntryStatus::FirstEntryHasErrors(_)
+            | DataEntryStatus::FirstEntryFinalised(_)
+            | DataEntryStatus::EntriesDifferent(_)
+            | DataEntryStatus::Definitive(_) => None,
+        }
+    }
+
+    /// Get the first entry if there is any, whether it is in progress or finalised
+    pub fn get_first_entry(&self) -> Option<&Results> {
+        match self {
+            DataEntryStatus::FirstEntryInProgress(state) => Some(&state.first_entry),
+            DataEntryStatus::FirstEntryHasErrors(state) => Some(&state.finalised_first_entry),
+            DataEntryStatus::FirstEntryFinalised(state) => Some(&state.finalised_first_entry),
+            DataEntryStatus::SecondEntryInProgress(state) => Some(&state.finalised_first_entry),
+            DataEntryStatus::EntriesDifferent(state) => Some(&state.first_entry),
+            DataEntryStatus::EntriesArbitration(state) => Some(&state.first_entry),
+            DataEntryStatus::FirstEntryCorrection(state) => Some(&state.first_entry),
+            DataEntryStatus::SecondEntryCorrection(state) => Some(&state.finalised_first_entry),
+            DataEntryStatus::Empty | DataEntryStatus::Definitive(_) => None,
+        }
+    }
+
+    /// Get the second entry if there is any, whether it is in progress or finalised
+    pub fn get_second_entry(&self) -> Option<&Results> {
+        match self {
+            DataEntryStatus::SecondEntryInProgress(state) => Some(&state.second_entry),
+            DataEntryStatus::EntriesDifferent(state) => Some(&state.second_entry),
+            DataEntryStatus::EntriesArbitration(state) => Some(&state.second_entry),
+            DataEntryStatus::FirstEntryCorrection(state) => Some(&state.finalised_second_entry),
+            DataEntryStatus::SecondEntryCorrection(state) => Some(&state.second_entry),
+            DataEntryStatus::Empty
+            | DataEntryStatus::FirstEntryInProgress(_)
+            | DataEntryStatus::FirstEntryHasErrors(_)
+            | DataEntryStatus::FirstEntryFinalised(_)
+            | DataEntryStatus::Definitive(_) => None,
+        }
+    }
+
+    /// Get the third entry if there is any
+    pub fn get_third_entry(&self) -> Option<&Results> {
+        match self {
+            DataEntryStatus::EntriesArbitration(state) => state
+                .third_entry
+                .as_ref()
+                .map(|third_entry| &third_entry.entry),
+            _ => None,
+        }
+    }
+
+    /// Extract the client state if there is any
+    pub fn get_client_state(&self) -> Option<&serde_json::Value> {
+        match self {
+            DataEntryStatus::FirstEntryInProgress(state) => state.client_state.as_ref(),
+            DataEntryStatus::SecondEntryInProgress(state) => state.client_state.as_ref(),
+            DataEntryStatus::FirstEntryCorrection(state) => state.client_state.as_ref(),
+            DataEntryStatus::SecondEntryCorrection(state) => state.client_state.as_ref(),
+            DataEntryStatus::EntriesArbitration(state) => state
+                .third_entry
+                .as_ref()
+                .and_then(|third_entry| third_entry.client_state.as_ref()),
             DataEntryStatus::Empty
             | DataEntryStatus::FirstEntryHasErrors(_)
             | DataEntryStatus::FirstEntryFinalised(_)
@@ -1080,6 +1728,7 @@ impl DataEntryStatus {
             | DataEntryStatus::FirstEntryHasErrors(_)
             | DataEntryStatus::FirstEntryFinalised(_)
             | DataEntryStatus::EntriesDifferent(_)
+            | DataEntryStatus::EntriesArbitration(_)
             | DataEntryStatus::Definitive(_) => false,
         }
     }
@@ -1093,6 +1742,7 @@ impl DataEntryStatus {
             DataEntryStatus::FirstEntryFinalised(_) => DataEntryStatusName::FirstEntryFinalised,
             DataEntryStatus::SecondEntryInProgress(_) => DataEntryStatusName::SecondEntryInProgress,
             DataEntryStatus::EntriesDifferent(_) => DataEntryStatusName::EntriesDifferent,
+            DataEntryStatus::EntriesArbitration(_) => DataEntryStatusName::EntriesArbitration,
             DataEntryStatus::FirstEntryCorrection(_) => DataEntryStatusName::FirstEntryCorrection,
             DataEntryStatus::SecondEntryCorrection(_) => DataEntryStatusName::SecondEntryCorrection,
             DataEntryStatus::Definitive(_) => DataEntryStatusName::Definitive,
@@ -1130,6 +1780,11 @@ impl DataEntryStatus {
                 second_entry,
                 ..
             })
+            | DataEntryStatus::EntriesArbitration(EntriesArbitration {
+                first_entry,
+                second_entry,
+                ..
+            })
             | DataEntryStatus::FirstEntryCorrection(FirstEntryCorrection {
                 first_entry,
                 finalised_second_entry: second_entry,
@@ -1149,6 +1804,40 @@ impl DataEntryStatus {
     }
 }
 
+/// Copy the values of the given fields from one entry to another. The fields are
+/// paths as returned by `compare_entries`, e.g. `data.voters_counts.poll_card_count`.
+fn copy_fields(
+    to: &Results,
+    from: &Results,
+    fields: &[String],
+) -> Result<Results, DataEntryTransitionError> {
+    let mut to = serde_json::to_value(to).map_err(|_| DataEntryTransitionError::Invalid)?;
+    let from = serde_json::to_value(from).map_err(|_| DataEntryTransitionError::Invalid)?;
+
+    for field in fields {
+        let pointer = FieldPath::from(&field[..]).json_pointer();
+        let value = from
+            .pointer(&pointer)
+            .ok_or(DataEntryTransitionError::Invalid)?;
+        *to.pointer_mut(&pointer)
+            .ok_or(DataEntryTransitionError::Invalid)? = value.clone();
+    }
+
+    serde_json::from_value(to).map_err(|_| DataEntryTransitionError::Invalid)
+}
+
+/// Whether every field of the results matches the first or the second entry
+fn has_majority(results: &Results, first_entry: &Results, second_entry: &Results) -> bool {
+    let mut different_from_first = vec![];
+    results.compare(first_entry, &mut different_from_first, &"data".into());
+    let mut different_from_second = vec![];
+    results.compare(second_entry, &mut different_from_second, &"data".into());
+
+    !different_from_first
+        .iter()
+        .any(|field| different_from_second.contains(field))
+}
+
 impl Display for DataEntryTransitionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1158,6 +1847,9 @@ impl Display for DataEntryTransitionError {
             DataEntryTransitionError::SecondEntryAlreadyClaimed => {
                 write!(f, "Second entry already claimed")
             }
+            DataEntryTransitionError::ThirdEntryAlreadyClaimed => {
+                write!(f, "Third entry already claimed")
+            }
             DataEntryTransitionError::SecondEntryAlreadyFinalised => {
                 write!(f, "Second entry already finalised")
             }
@@ -1173,10 +1865,19 @@ impl Display for DataEntryTransitionError {
                     "Second entry needs a different user than the first entry"
                 )
             }
+            DataEntryTransitionError::ThirdEntryNeedsDifferentUser => {
+                write!(
+                    f,
+                    "Third entry needs a different user than the first and second entry"
+                )
+            }
             DataEntryTransitionError::Invalid => write!(f, "Invalid state transition"),
             DataEntryTransitionError::CorrectionNotAllowed => {
                 write!(f, "Correction not allowed: the entry to be kept has errors")
             }
+            DataEntryTransitionError::ImportedEntryHasNoTypist => {
+                write!(f, "An imported first entry cannot be returned to a typist")
+            }
             DataEntryTransitionError::ValidatorError(data_error) => {
                 write!(f, "Validator error: {data_error}")
             }
@@ -1194,6 +1895,7 @@ mod tests {
             ElectionDomain, ElectionId, ElectionSubCategory, PGNumber, PoliticalGroup,
             VoteCountingMethod,
         },
+        models::ModelLanguage,
         results::{
             cso_first_session_results::CSOFirstSessionResults,
             political_group_candidate_votes::{CandidateVotes, PoliticalGroupCandidateVotes},
@@ -1245,6 +1947,9 @@ mod tests {
             authority_id: "0000".to_string(),
             authority_name: "Test".to_string(),
             authority_region: "Test".to_string(),
+            frisian_export_allowed: false,
+            model_language: ModelLanguage::Dutch,
+            referendum: None,
             district: CommitteeDistrict::None,
             domain: Some(ElectionDomain {
                 id: Some("0000".to_string()),
@@ -1290,7 +1995,8 @@ mod tests {
 
     fn first_entry_has_errors() -> DataEntryStatus {
         DataEntryStatus::FirstEntryHasErrors(FirstEntryHasErrors {
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             finalised_first_entry: example_results(),
             first_entry_finished_at: Utc::now(),
         })
@@ -1298,7 +2004,8 @@ mod tests {
 
     fn first_entry_finalised() -> DataEntryStatus {
         DataEntryStatus::FirstEntryFinalised(FirstEntryFinalised {
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             finalised_first_entry: example_results(),
             first_entry_finished_at: Utc::now(),
             finalised_with_warnings: true,
@@ -1307,7 +2014,8 @@ mod tests {
 
     fn second_entry_in_progress() -> DataEntryStatus {
         DataEntryStatus::SecondEntryInProgress(SecondEntryInProgress {
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             finalised_first_entry: example_results(),
             first_entry_finished_at: Utc::now(),
             progress: 0,
@@ -1319,8 +2027,10 @@ mod tests {
 
     fn definitive() -> DataEntryStatus {
         DataEntryStatus::Definitive(Definitive {
-            first_entry_user_id: UserId::from(0),
-            second_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            second_entry_user_id: Some(UserId::from(0)),
+            third_entry_user_id: None,
+            imported_by_user_id: None,
             finished_at: Utc::now(),
             finalised_with_warnings: false,
             results: example_results(),
@@ -1330,7 +2040,8 @@ mod tests {
     fn entries_different() -> DataEntryStatus {
         DataEntryStatus::EntriesDifferent(EntriesDifferent {
             first_entry: example_results(),
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             second_entry: example_results().with_difference(),
             second_entry_user_id: UserId::from(0),
             first_entry_finished_at: Utc::now(),
@@ -1352,7 +2063,8 @@ mod tests {
 
     fn second_entry_correction() -> DataEntryStatus {
         DataEntryStatus::SecondEntryCorrection(SecondEntryCorrection {
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             finalised_first_entry: example_results(),
             second_entry_user_id: UserId::from(1),
             second_entry: example_results().with_difference(),
@@ -1672,7 +2384,8 @@ mod tests {
             second_entry_user_id: UserId::from(0),
             second_entry: different_second_entry,
             client_state: Default::default(),
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             finalised_first_entry: first_entry,
             first_entry_finished_at: Utc::now(),
         });
@@ -1706,7 +2419,8 @@ mod tests {
     #[test]
     fn second_entry_in_progress_finalise_not_equal() {
         let initial = DataEntryStatus::SecondEntryInProgress(SecondEntryInProgress {
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             finalised_first_entry: Results::CSOFirstSession(CSOFirstSessionResults {
                 voters_counts: VotersCounts {
                     poll_card_count: 1,
@@ -1911,7 +2625,8 @@ mod tests {
 
         let initial = DataEntryStatus::EntriesDifferent(EntriesDifferent {
             first_entry: first_entry.clone(),
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             second_entry,
             second_entry_user_id: UserId::from(0),
             first_entry_finished_at: Utc::now(),
@@ -1933,7 +2648,8 @@ mod tests {
 
         let initial = DataEntryStatus::EntriesDifferent(EntriesDifferent {
             first_entry,
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             second_entry,
             second_entry_user_id: UserId::from(0),
             first_entry_finished_at: Utc::now(),
@@ -1962,7 +2678,8 @@ mod tests {
 
         let initial = DataEntryStatus::EntriesDifferent(EntriesDifferent {
             first_entry: first_entry.clone(),
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             second_entry: second_entry.clone(),
             second_entry_user_id: UserId::from(0),
             first_entry_finished_at: Utc::now(),
@@ -1986,7 +2703,8 @@ mod tests {
 
         let initial = DataEntryStatus::EntriesDifferent(EntriesDifferent {
             first_entry: first_entry.clone(),
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             second_entry: second_entry.clone(),
             second_entry_user_id: UserId::from(0),
             first_entry_finished_at: Utc::now(),
@@ -2008,7 +2726,8 @@ mod tests {
 
         let initial = DataEntryStatus::EntriesDifferent(EntriesDifferent {
             first_entry,
-            first_entry_user_id: UserId::from(0),
+            first_entry_user_id: Some(UserId::from(0)),
+            imported_by_user_id: None,
             second_entry,
             second_entry_user_id: UserId::from(0),
             first_entry_finished_at: Utc::now(),
@@ -2036,6 +2755,141 @@ mod tests {
         assert!(matches!(next, DataEntryStatus::Empty));
     }
 
+    /// Empty --> FirstEntryFinalised: import as first entry
+    #[test]
+    fn empty_to_first_entry_finalised_import() {
+        let next = DataEntryStatus::Empty
+            .import_entry(
+                &election(),
+                UserId::from(1),
+                example_results(),
+                ImportEntryTarget::FirstEntry,
+            )
+            .unwrap();
+
+        let DataEntryStatus::FirstEntryFinalised(state) = next else {
+            panic!("{next:?}")
+        };
+        assert_eq!(state.first_entry_user_id, None);
+        assert_eq!(state.imported_by_user_id, Some(UserId::from(1)));
+        assert_eq!(state.finalised_first_entry, example_results());
+        assert!(!state.finalised_with_warnings);
+    }
+
+    /// Empty --> FirstEntryHasErrors: import as first entry with errors
+    #[test]
+    fn empty_to_first_entry_has_errors_import() {
+        let next = DataEntryStatus::Empty
+            .import_entry(
+                &election(),
+                UserId::from(1),
+                example_results().with_error(),
+                ImportEntryTarget::FirstEntry,
+            )
+            .unwrap();
+
+        let DataEntryStatus::FirstEntryHasErrors(state) = next else {
+            panic!("{next:?}")
+        };
+        assert_eq!(state.first_entry_user_id, None);
+        assert_eq!(state.imported_by_user_id, Some(UserId::from(1)));
+
+        // There is no typist to return the imported entry to
+        assert_eq!(
+            DataEntryStatus::FirstEntryHasErrors(state).resume_first_entry_with_errors(),
+            Err(DataEntryTransitionError::ImportedEntryHasNoTypist)
+        );
+    }
+
+    /// Imported first entry --> Definitive: the importer is kept next to the second typist
+    #[test]
+    fn imported_first_entry_to_definitive() {
+        let next = DataEntryStatus::Empty
+            .import_entry(
+                &election(),
+                UserId::from(1),
+                example_results(),
+                ImportEntryTarget::FirstEntry,
+            )
+            .unwrap()
+            .claim_second_entry(UserId::from(2), example_results())
+            .unwrap()
+            .finalise_second_entry(&election(), UserId::from(2))
+            .unwrap();
+
+        let DataEntryStatus::Definitive(state) = next else {
+            panic!("{next:?}")
+        };
+        assert_eq!(state.first_entry_user_id, None);
+        assert_eq!(state.imported_by_user_id, Some(UserId::from(1)));
+        assert_eq!(state.second_entry_user_id, Some(UserId::from(2)));
+    }
+
+    /// Empty --> Definitive: import as definitive results
+    #[test]
+    fn empty_to_definitive_import() {
+        let next = DataEntryStatus::Empty
+            .import_entry(
+                &election(),
+                UserId::from(1),
+                example_results().with_warning(),
+                ImportEntryTarget::Definitive,
+            )
+            .unwrap();
+
+        let DataEntryStatus::Definitive(state) = next else {
+            panic!("{next:?}")
+        };
+        assert_eq!(state.first_entry_user_id, None);
+        assert_eq!(state.second_entry_user_id, None);
+        assert_eq!(state.imported_by_user_id, Some(UserId::from(1)));
+        assert!(state.finalised_with_warnings);
+    }
+
+    #[test]
+    fn empty_to_definitive_import_with_errors_is_rejected() {
+        assert!(matches!(
+            DataEntryStatus::Empty.import_entry(
+                &election(),
+                UserId::from(1),
+                example_results().with_error(),
+                ImportEntryTarget::Definitive,
+            ),
+            Err(DataEntryTransitionError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn import_not_empty_error() {
+        assert_eq!(
+            first_entry_in_progress().import_entry(
+                &election(),
+                UserId::from(1),
+                example_results(),
+                ImportEntryTarget::FirstEntry,
+            ),
+            Err(DataEntryTransitionError::Invalid)
+        );
+        assert_eq!(
+            first_entry_finalised().import_entry(
+                &election(),
+                UserId::from(1),
+                example_results(),
+                ImportEntryTarget::FirstEntry,
+            ),
+            Err(DataEntryTransitionError::FirstEntryAlreadyFinalised)
+        );
+        assert_eq!(
+            definitive().import_entry(
+                &election(),
+                UserId::from(1),
+                example_results(),
+                ImportEntryTarget::Definitive,
+            ),
+            Err(DataEntryTransitionError::SecondEntryAlreadyFinalised)
+        );
+    }
+
     #[test]
     fn definitive_discard_entries_error() {
         assert_eq!(
@@ -2060,7 +2914,7 @@ mod tests {
         if let DataEntryStatus::FirstEntryFinalised(kept_entry) = next {
             assert_eq!(
                 kept_entry.first_entry_user_id,
-                correction.second_entry_user_id
+                Some(correction.second_entry_user_id)
             );
             assert_eq!(
                 kept_entry.finalised_first_entry,
@@ -2525,6 +3379,246 @@ mod tests {
         }
     }
 
+    mod arbitration {
+        use super::*;
+
+        const ARBITRATION_FIELDS: [&str; 2] = [
+            "data.voters_counts.poll_card_count",
+            "data.voters_counts.proxy_certificate_count",
+        ];
+
+        fn entries_arbitration() -> DataEntryStatus {
+            entries_different().arbitrate_entries().unwrap()
+        }
+
+        fn third_entry_in_progress() -> DataEntryStatus {
+            entries_arbitration()
+                .claim_third_entry(
+                    UserId::from(2),
+                    &Results::CSOFirstSession(cso_first_session_result()),
+                )
+                .unwrap()
+        }
+
+        /// Third entry with the given poll card and proxy certificate counts
+        fn third_entry_update(
+            poll_card_count: u32,
+            proxy_certificate_count: u32,
+        ) -> DataEntryUpdate {
+            let mut entry = example_results();
+            let voters_counts = entry.voters_counts_mut();
+            voters_counts.poll_card_count = poll_card_count;
+            voters_counts.proxy_certificate_count = proxy_certificate_count;
+
+            DataEntryUpdate {
+                progress: 100,
+                user_id: UserId::from(2),
+                entry,
+                client_state: ClientState::default(),
+            }
+        }
+
+        fn finalise_third_entry(update: DataEntryUpdate) -> DataEntryStatus {
+            third_entry_in_progress()
+                .update_third_entry(update)
+                .unwrap()
+                .finalise_third_entry(&election(), UserId::from(2))
+                .unwrap()
+        }
+
+        #[test]
+        fn claimed_third_entry() {
+            assert_eq!(entries_arbitration().get_claimed_entry(), None);
+            assert_eq!(
+                third_entry_in_progress().get_claimed_entry(),
+                Some((EntryNumber::ThirdEntry, UserId::from(2), 0))
+            );
+        }
+
+        /// EntriesDifferent --> EntriesArbitration: arbitrate
+        #[test]
+        fn entries_different_to_entries_arbitration() {
+            let DataEntryStatus::EntriesArbitration(state) = entries_arbitration() else {
+                panic!("expected EntriesArbitration");
+            };
+            assert_eq!(state.arbitration_fields, ARBITRATION_FIELDS);
+            assert_eq!(state.third_entry, None);
+        }
+
+        #[test]
+        fn arbitrate_entries_error() {
+            for status in [
+                first_entry_finalised(),
+                second_entry_in_progress(),
+                definitive(),
+            ] {
+                assert_eq!(
+                    status.arbitrate_entries(),
+                    Err(DataEntryTransitionError::Invalid)
+                );
+            }
+        }
+
+        /// The differing fields are reset to their initial values, all other fields are
+        /// the agreed values of the first and second entry
+        #[test]
+        fn claim_third_entry() {
+            let status = third_entry_in_progress();
+            assert_eq!(status.get_third_entry_user_id(), Some(UserId::from(2)));
+            assert_eq!(status.get_data_entry_progress(), Some(0));
+
+            let mut expected = example_results();
+            let voters_counts = expected.voters_counts_mut();
+            voters_counts.poll_card_count = 0;
+            voters_counts.proxy_certificate_count = 0;
+            assert_eq!(status.get_data(), Some(&expected));
+        }
+
+        #[test]
+        fn claim_third_entry_as_first_or_second_typist_error() {
+            let status = DataEntryStatus::EntriesArbitration(EntriesArbitration {
+                second_entry_user_id: UserId::from(1),
+                ..match entries_arbitration() {
+                    DataEntryStatus::EntriesArbitration(state) => state,
+                    _ => unreachable!(),
+                }
+            });
+
+            for user_id in [UserId::from(0), UserId::from(1)] {
+                assert_eq!(
+                    status
+                        .clone()
+                        .claim_third_entry(user_id, &example_results()),
+                    Err(DataEntryTransitionError::ThirdEntryNeedsDifferentUser)
+                );
+            }
+        }
+
+        #[test]
+        fn claim_third_entry_resume_and_other_user() {
+            let status = third_entry_in_progress();
+            assert_eq!(
+                status
+                    .clone()
+                    .claim_third_entry(UserId::from(2), &example_results()),
+                Ok(status.clone())
+            );
+            assert_eq!(
+                status.claim_third_entry(UserId::from(3), &example_results()),
+                Err(DataEntryTransitionError::ThirdEntryAlreadyClaimed)
+            );
+        }
+
+        #[test]
+        fn update_third_entry_as_other_user_error() {
+            let mut update = third_entry_update(99, 1);
+            update.user_id = UserId::from(3);
+            assert_eq!(
+                third_entry_in_progress().update_third_entry(update),
+                Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser)
+            );
+        }
+
+        /// EntriesArbitration --> Definitive: finalise, every field agrees with the first entry
+        #[test]
+        fn finalise_third_entry_agrees_with_first_entry() {
+            let DataEntryStatus::Definitive(state) =
+                finalise_third_entry(third_entry_update(99, 1))
+            else {
+                panic!("expected Definitive");
+            };
+            assert_eq!(state.results, example_results());
+            assert_eq!(state.first_entry_user_id, Some(UserId::from(0)));
+        }
+
+        /// The typist who did the third entry is recorded in the definitive results
+        #[test]
+        fn finalise_third_entry_records_third_entry_user() {
+            let status = finalise_third_entry(third_entry_update(89, 11));
+            let DataEntryStatus::Definitive(state) = &status else {
+                panic!("expected Definitive");
+            };
+            assert_eq!(state.third_entry_user_id, Some(UserId::from(2)));
+            assert_eq!(status.get_third_entry_user_id(), Some(UserId::from(2)));
+        }
+
+        /// EntriesArbitration --> Definitive: finalise, every field agrees with the second entry
+        #[test]
+        fn finalise_third_entry_agrees_with_second_entry() {
+            let DataEntryStatus::Definitive(state) =
+                finalise_third_entry(third_entry_update(89, 11))
+            else {
+                panic!("expected Definitive");
+            };
+            assert_eq!(state.results, example_results().with_difference());
+        }
+
+        /// Only the differing fields are taken from the third entry
+        #[test]
+        fn finalise_third_entry_ignores_other_fields() {
+            let mut update = third_entry_update(89, 11);
+            update.entry.votes_counts_mut().unwrap().blank_votes_count = 1000;
+
+            let DataEntryStatus::Definitive(state) = finalise_third_entry(update) else {
+                panic!("expected Definitive");
+            };
+            assert_eq!(state.results, example_results().with_difference());
+        }
+
+        /// EntriesArbitration --> EntriesDifferent: finalise, a field has no majority
+        #[test]
+        fn finalise_third_entry_without_majority() {
+            let DataEntryStatus::EntriesDifferent(state) =
+                finalise_third_entry(third_entry_update(50, 1))
+            else {
+                panic!("expected EntriesDifferent");
+            };
+            assert_eq!(state.first_entry, example_results());
+            assert_eq!(state.second_entry, example_results().with_difference());
+        }
+
+        /// EntriesArbitration --> EntriesDifferent: finalise, the accepted results have errors
+        #[test]
+        fn finalise_third_entry_accepted_results_have_errors() {
+            // Poll card count of the first entry and proxy certificate count of the second
+            // entry, which do not add up to the total admitted voters count
+            assert!(matches!(
+                finalise_third_entry(third_entry_update(99, 11)),
+                DataEntryStatus::EntriesDifferent(_)
+            ));
+        }
+
+        #[test]
+        fn finalise_third_entry_as_other_user_error() {
+            assert_eq!(
+                third_entry_in_progress().finalise_third_entry(&election(), UserId::from(3)),
+                Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser)
+            );
+            assert_eq!(
+                entries_arbitration().finalise_third_entry(&election(), UserId::from(2)),
+                Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser)
+            );
+        }
+
+        /// Discarding the third entry makes it available for another typist
+        #[test]
+        fn discard_third_entry() {
+            let status = entries_arbitration();
+            let in_progress = status
+                .clone()
+                .claim_third_entry(UserId::from(2), &example_results())
+                .unwrap();
+            assert_eq!(
+                in_progress.discard_third_entry_in_progress(UserId::from(2)),
+                Ok(status)
+            );
+            assert_eq!(
+                third_entry_in_progress().discard_third_entry_in_progress(UserId::from(3)),
+                Err(DataEntryTransitionError::CannotTransitionUsingDifferentUser)
+            );
+        }
+    }
+
     mod compare_entries {
         use super::*;
 
@@ -2583,4 +3677,242 @@ mod tests {
             );
         }
     }
+
+    mod take_over {
+        use super::*;
+
+        fn second_entry_in_progress_by(second_entry_user_id: UserId) -> DataEntryStatus {
+            let DataEntryStatus::SecondEntryInProgress(state) = second_entry_in_progress() else {
+                unreachable!()
+            };
+            DataEntryStatus::SecondEntryInProgress(SecondEntryInProgress {
+                second_entry_user_id,
+                ..state
+            })
+        }
+
+        fn data_entry_row(state: DataEntryStatus, updated_at: DateTime<Utc>) -> DataEntryRow {
+            DataEntryRow {
+                id: DataEntryId::from(1),
+                state: Json(state),
+                updated_at,
+            }
+        }
+
+        /// FirstEntryInProgress --> FirstEntryInProgress: take over by other user
+        #[test]
+        fn first_entry_in_progress_take_over() {
+            let DataEntryStatus::FirstEntryInProgress(state) = first_entry_in_progress()
+                .take_over_entry_in_progress(EntryNumber::FirstEntry, UserId::from(1))
+                .unwrap()
+            else {
+                panic!("expected first entry in progress");
+            };
+
+            assert_eq!(state.first_entry_user_id, UserId::from(1));
+            assert_eq!(state.first_entry, example_results());
+            assert_eq!(
+                state.client_state,
+                ClientState::new_from_str(Some("{}")).unwrap()
+            );
+        }
+
+        /// SecondEntryInProgress --> SecondEntryInProgress: take over by other user
+        #[test]
+        fn second_entry_in_progress_take_over() {
+            let DataEntryStatus::SecondEntryInProgress(state) =
+                second_entry_in_progress_by(UserId::from(1))
+                    .take_over_entry_in_progress(EntryNumber::SecondEntry, UserId::from(2))
+                    .unwrap()
+            else {
+                panic!("expected second entry in progress");
+            };
+
+            assert_eq!(state.first_entry_user_id, Some(UserId::from(0)));
+            assert_eq!(state.second_entry_user_id, UserId::from(2));
+            assert_eq!(state.second_entry, example_results());
+        }
+
+        #[test]
+        fn take_over_by_same_user_error() {
+            assert_eq!(
+                first_entry_in_progress()
+                    .take_over_entry_in_progress(EntryNumber::FirstEntry, UserId::from(0)),
+                Err(DataEntryTransitionError::FirstEntryAlreadyClaimed)
+            );
+            assert_eq!(
+                second_entry_in_progress_by(UserId::from(1))
+                    .take_over_entry_in_progress(EntryNumber::SecondEntry, UserId::from(1)),
+                Err(DataEntryTransitionError::SecondEntryAlreadyClaimed)
+            );
+        }
+
+        #[test]
+        fn second_entry_take_over_by_first_entry_user_error() {
+            assert_eq!(
+                second_entry_in_progress_by(UserId::from(1))
+                    .take_over_entry_in_progress(EntryNumber::SecondEntry, UserId::from(0)),
+                Err(DataEntryTransitionError::SecondEntryNeedsDifferentUser)
+            );
+        }
+
+        #[test]
+        fn take_over_not_in_progress_error() {
+            assert_eq!(
+                first_entry_in_progress()
+                    .take_over_entry_in_progress(EntryNumber::SecondEntry, UserId::from(1)),
+                Err(DataEntryTransitionError::Invalid)
+            );
+            assert_eq!(
+                first_entry_finalised()
+                    .take_over_entry_in_progress(EntryNumber::FirstEntry, UserId::from(1)),
+                Err(DataEntryTransitionError::Invalid)
+            );
+            assert_eq!(
+                entries_different()
+                    .arbitrate_entries()
+                    .unwrap()
+                    .take_over_entry_in_progress(EntryNumber::ThirdEntry, UserId::from(2)),
+                Err(DataEntryTransitionError::Invalid)
+            );
+        }
+
+        /// FirstEntryCorrection --> FirstEntryCorrection: take over by other user
+        #[test]
+        fn first_entry_correction_take_over() {
+            let DataEntryStatus::FirstEntryCorrection(state) = first_entry_correction()
+                .take_over_entry_in_progress(EntryNumber::FirstEntry, UserId::from(2))
+                .unwrap()
+            else {
+                panic!("expected first entry correction");
+            };
+
+            assert_eq!(state.first_entry_user_id, UserId::from(2));
+            assert_eq!(state.second_entry_user_id, UserId::from(1));
+            assert_eq!(state.first_entry, example_results());
+
+            assert_eq!(
+                first_entry_correction()
+                    .take_over_entry_in_progress(EntryNumber::FirstEntry, UserId::from(0)),
+                Err(DataEntryTransitionError::FirstEntryAlreadyClaimed)
+            );
+            assert_eq!(
+                first_entry_correction()
+                    .take_over_entry_in_progress(EntryNumber::FirstEntry, UserId::from(1)),
+                Err(DataEntryTransitionError::SecondEntryNeedsDifferentUser)
+            );
+        }
+
+        /// SecondEntryCorrection --> SecondEntryCorrection: take over by other user
+        #[test]
+        fn second_entry_correction_take_over() {
+            let DataEntryStatus::SecondEntryCorrection(state) = second_entry_correction()
+                .take_over_entry_in_progress(EntryNumber::SecondEntry, UserId::from(2))
+                .unwrap()
+            else {
+                panic!("expected second entry correction");
+            };
+
+            assert_eq!(state.first_entry_user_id, Some(UserId::from(0)));
+            assert_eq!(state.second_entry_user_id, UserId::from(2));
+            assert_eq!(state.second_entry, example_results().with_difference());
+
+            assert_eq!(
+                second_entry_correction()
+                    .take_over_entry_in_progress(EntryNumber::SecondEntry, UserId::from(1)),
+                Err(DataEntryTransitionError::SecondEntryAlreadyClaimed)
+            );
+            assert_eq!(
+                second_entry_correction()
+                    .take_over_entry_in_progress(EntryNumber::SecondEntry, UserId::from(0)),
+                Err(DataEntryTransitionError::SecondEntryNeedsDifferentUser)
+            );
+        }
+
+        fn third_entry_in_progress() -> DataEntryStatus {
+            let DataEntryStatus::EntriesDifferent(state) = entries_different() else {
+                unreachable!()
+            };
+            DataEntryStatus::EntriesDifferent(EntriesDifferent {
+                second_entry_user_id: UserId::from(1),
+                ..state
+            })
+            .arbitrate_entries()
+            .unwrap()
+            .claim_third_entry(
+                UserId::from(2),
+                &Results::CSOFirstSession(cso_first_session_result()),
+            )
+            .unwrap()
+        }
+
+        /// EntriesArbitration --> EntriesArbitration: take over third entry by other user
+        #[test]
+        fn third_entry_take_over() {
+            let DataEntryStatus::EntriesArbitration(state) = third_entry_in_progress()
+                .take_over_entry_in_progress(EntryNumber::ThirdEntry, UserId::from(3))
+                .unwrap()
+            else {
+                panic!("expected entries arbitration");
+            };
+
+            assert_eq!(
+                state.third_entry.map(|third_entry| third_entry.user_id),
+                Some(UserId::from(3))
+            );
+        }
+
+        #[test]
+        fn third_entry_take_over_error() {
+            assert_eq!(
+                third_entry_in_progress()
+                    .take_over_entry_in_progress(EntryNumber::ThirdEntry, UserId::from(2)),
+                Err(DataEntryTransitionError::ThirdEntryAlreadyClaimed)
+            );
+            for user_id in [UserId::from(0), UserId::from(1)] {
+                assert_eq!(
+                    third_entry_in_progress()
+                        .take_over_entry_in_progress(EntryNumber::ThirdEntry, user_id),
+                    Err(DataEntryTransitionError::ThirdEntryNeedsDifferentUser)
+                );
+            }
+        }
+
+        #[test]
+        fn claim_abandoned_after_timeout() {
+            let now = Utc::now();
+            let timeout = TimeDelta::minutes(30);
+
+            let row = data_entry_row(first_entry_in_progress(), now - TimeDelta::minutes(29));
+            assert!(!row.is_claim_abandoned(timeout, now));
+
+            let row = data_entry_row(first_entry_in_progress(), now - timeout);
+            assert!(row.is_claim_abandoned(timeout, now));
+
+            for state in [
+                second_entry_in_progress(),
+                first_entry_correction(),
+                second_entry_correction(),
+            ] {
+                let row = data_entry_row(state, now - TimeDelta::hours(2));
+                assert!(row.is_claim_abandoned(timeout, now));
+            }
+        }
+
+        #[test]
+        fn claim_not_abandoned_when_not_in_progress() {
+            let now = Utc::now();
+            let updated_at = now - TimeDelta::hours(2);
+            let timeout = TimeDelta::minutes(30);
+
+            for state in [
+                DataEntryStatus::Empty,
+                first_entry_finalised(),
+                entries_different(),
+                definitive(),
+            ] {
+                assert!(!data_entry_row(state, updated_at).is_claim_abandoned(timeout, now));
+            }
+        }
+    }
 }
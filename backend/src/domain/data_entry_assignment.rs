@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    domain::{
+        data_entry::{DataEntryId, DataEntrySourceNumber},
+        entry_number::EntryNumber,
+        polling_station::PollingStationNumber,
+        sub_committee::SubCommitteeNumber,
+    },
+    repository::user_repo::UserId,
+};
+
+/// Assignment of an entry of a data entry to a specific typist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataEntryAssignment {
+    pub data_entry_id: DataEntryId,
+    /// Entry number (first, second or third data entry)
+    #[schema(value_type = u8)]
+    pub entry_number: EntryNumber,
+    /// Typist who is assigned to the entry
+    pub user_id: UserId,
+    /// Coordinator who assigned the entry
+    pub assigned_by: UserId,
+    #[schema(value_type = String)]
+    pub assigned_at: DateTime<Utc>,
+}
+
+/// Polling stations or sub-committees of which the data entries are assigned
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DataEntryAssignmentTarget {
+    /// Polling stations with the given numbers
+    PollingStations { numbers: Vec<PollingStationNumber> },
+    /// Polling stations with a number from `from` up to and including `to`
+    PollingStationRange {
+        from: PollingStationNumber,
+        to: PollingStationNumber,
+    },
+    /// Sub-committees with the given numbers
+    SubCommittees { numbers: Vec<SubCommitteeNumber> },
+}
+
+impl DataEntryAssignmentTarget {
+    /// Whether the data entry of the polling station or sub-committee with the given number
+    /// is part of this target
+    pub fn includes(&self, number: DataEntrySourceNumber) -> bool {
+        match (self, number) {
+            (Self::PollingStations { numbers }, DataEntrySourceNumber::PollingStation(number)) => {
+                numbers.contains(&number)
+            }
+            (
+                Self::PollingStationRange { from, to },
+                DataEntrySourceNumber::PollingStation(number),
+            ) => (*from..=*to).contains(&number),
+            (Self::SubCommittees { numbers }, DataEntrySourceNumber::SubCommittee(number)) => {
+                numbers.contains(&number)
+            }
+            _ => false,
+        }
+    }
+
+    /// Numbers that were explicitly requested, which all need to exist
+    pub fn numbers(&self) -> Vec<DataEntrySourceNumber> {
+        match self {
+            Self::PollingStations { numbers } => numbers
+                .iter()
+                .map(|n| DataEntrySourceNumber::PollingStation(*n))
+                .collect(),
+            Self::PollingStationRange { .. } => vec![],
+            Self::SubCommittees { numbers } => numbers
+                .iter()
+                .map(|n| DataEntrySourceNumber::SubCommittee(*n))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_includes_polling_stations() {
+        let target = DataEntryAssignmentTarget::PollingStations {
+            numbers: vec![1, 3],
+        };
+
+        assert!(target.includes(DataEntrySourceNumber::PollingStation(1)));
+        assert!(!target.includes(DataEntrySourceNumber::PollingStation(2)));
+        assert!(target.includes(DataEntrySourceNumber::PollingStation(3)));
+        assert!(!target.includes(DataEntrySourceNumber::SubCommittee(1)));
+    }
+
+    #[test]
+    fn test_includes_polling_station_range() {
+        let target = DataEntryAssignmentTarget::PollingStationRange { from: 2, to: 4 };
+
+        assert!(!target.includes(DataEntrySourceNumber::PollingStation(1)));
+        assert!(target.includes(DataEntrySourceNumber::PollingStation(2)));
+        assert!(target.includes(DataEntrySourceNumber::PollingStation(4)));
+        assert!(!target.includes(DataEntrySourceNumber::PollingStation(5)));
+        assert!(!target.includes(DataEntrySourceNumber::SubCommittee(3)));
+        assert!(target.numbers().is_empty());
+    }
+
+    #[test]
+    fn test_includes_sub_committees() {
+        let target = DataEntryAssignmentTarget::SubCommittees { numbers: vec![2] };
+
+        assert!(target.includes(DataEntrySourceNumber::SubCommittee(2)));
+        assert!(!target.includes(DataEntrySourceNumber::SubCommittee(1)));
+        assert!(!target.includes(DataEntrySourceNumber::PollingStation(2)));
+        assert_eq!(
+            target.numbers(),
+            vec![DataEntrySourceNumber::SubCommittee(2)]
+        );
+    }
+}
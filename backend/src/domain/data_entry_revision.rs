@@ -0,0 +1,251 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+use crate::{
+    domain::{
+        compare::Compare,
+        data_entry::{DataEntryId, DataEntryStatus, DataEntryStatusName},
+        identifier::id,
+        results::Results,
+    },
+    repository::user_repo::UserId,
+};
+
+id!(DataEntryRevisionId);
+
+/// Action of a user that changed the state of a data entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum DataEntryRevisionAction {
+    /// A typist started the first or second entry
+    Claim,
+    /// A typist saved the progress of an entry
+    Save,
+    /// A typist discarded an entry in progress or a correction
+    Discard,
+    /// A typist finalised an entry
+    Finalise,
+    /// A coordinator resolved the errors of a finalised first entry
+    ResolveErrors,
+    /// A coordinator resolved the differences between the first and second entry
+    ResolveDifferences,
+    /// A coordinator imported the results from an EML_NL file
+    Import,
+    /// A coordinator reset the data entry to empty
+    Reset,
+    /// A coordinator handed over an abandoned entry in progress to another typist
+    TakeOver,
+}
+
+/// Details of a data entry revision, without its state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataEntryRevisionSummary {
+    pub id: DataEntryRevisionId,
+    pub data_entry_id: DataEntryId,
+    /// Number of the revision, counting from 1 for each data entry
+    pub revision: u32,
+    pub action: DataEntryRevisionAction,
+    /// Status of the data entry after the action
+    pub status: DataEntryStatusName,
+    pub created_by: UserId,
+    #[schema(value_type = String)]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Immutable record of the state of a data entry after an action of a user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataEntryRevision {
+    pub summary: DataEntryRevisionSummary,
+    /// State of the data entry after the action, including the entries and client state
+    pub state: DataEntryStatus,
+}
+
+impl DataEntryRevision {
+    /// Determine which fields of the entries changed from this revision to the other revision
+    pub fn diff(&self, other: &DataEntryRevision) -> DataEntryRevisionDiff {
+        DataEntryRevisionDiff {
+            from: self.summary.clone(),
+            to: other.summary.clone(),
+            first_entry: different_fields(
+                self.state.get_first_entry(),
+                other.state.get_first_entry(),
+            ),
+            second_entry: different_fields(
+                self.state.get_second_entry(),
+                other.state.get_second_entry(),
+            ),
+            third_entry: different_fields(
+                self.state.get_third_entry(),
+                other.state.get_third_entry(),
+            ),
+            definitive: different_fields(
+                definitive_results(&self.state),
+                definitive_results(&other.state),
+            ),
+            client_state_changed: self.state.get_client_state() != other.state.get_client_state(),
+        }
+    }
+}
+
+fn definitive_results(state: &DataEntryStatus) -> Option<&Results> {
+    match state {
+        DataEntryStatus::Definitive(definitive) => Some(&definitive.results),
+        _ => None,
+    }
+}
+
+/// Paths of the fields that differ between two versions of an entry. If the entry is only
+/// present in one of the revisions, the entry as a whole is different.
+fn different_fields(from: Option<&Results>, to: Option<&Results>) -> Vec<String> {
+    let mut different_fields = vec![];
+    match (from, to) {
+        (Some(from), Some(to)) => to.compare(from, &mut different_fields, &"data".into()),
+        (None, None) => {}
+        (Some(_), None) | (None, Some(_)) => different_fields.push("data".to_string()),
+    }
+    different_fields
+}
+
+/// Differences between two revisions of a data entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataEntryRevisionDiff {
+    pub from: DataEntryRevisionSummary,
+    pub to: DataEntryRevisionSummary,
+    /// Fields of the first entry that differ between the revisions
+    pub first_entry: Vec<String>,
+    /// Fields of the second entry that differ between the revisions
+    pub second_entry: Vec<String>,
+    /// Fields of the third entry that differ between the revisions
+    pub third_entry: Vec<String>,
+    /// Fields of the definitive results that differ between the revisions
+    pub definitive: Vec<String>,
+    /// Whether the client state differs between the revisions
+    pub client_state_changed: bool,
+}
+
+/// List of revisions of a data entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataEntryRevisionListResponse {
+    pub revisions: Vec<DataEntryRevisionSummary>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::domain::{
+        data_entry::{ClientState, Definitive, EntriesDifferent, FirstEntryInProgress},
+        results::tests::example_results,
+    };
+
+    fn revision(revision: u32, state: DataEntryStatus) -> DataEntryRevision {
+        DataEntryRevision {
+            summary: DataEntryRevisionSummary {
+                id: DataEntryRevisionId::from(revision),
+                data_entry_id: DataEntryId::from(1),
+                revision,
+                action: DataEntryRevisionAction::Save,
+                status: state.status_name(),
+                created_by: UserId::from(1),
+                created_at: Utc.with_ymd_and_hms(2026, 3, 18, 21, 0, 0).unwrap(),
+            },
+            state,
+        }
+    }
+
+    fn first_entry_in_progress(results: Results, client_state: ClientState) -> DataEntryStatus {
+        DataEntryStatus::FirstEntryInProgress(FirstEntryInProgress {
+            progress: 50,
+            first_entry_user_id: UserId::from(1),
+            first_entry: results,
+            client_state,
+            is_correction: false,
+        })
+    }
+
+    fn with_poll_card_count(count: u32) -> Results {
+        let mut results = example_results();
+        results.voters_counts_mut().poll_card_count = count;
+        results
+    }
+
+    #[test]
+    fn test_diff_same_revision() {
+        let revision = revision(
+            1,
+            first_entry_in_progress(example_results(), ClientState::default()),
+        );
+        let diff = revision.diff(&revision);
+
+        assert!(diff.first_entry.is_empty());
+        assert!(diff.second_entry.is_empty());
+        assert!(diff.third_entry.is_empty());
+        assert!(diff.definitive.is_empty());
+        assert!(!diff.client_state_changed);
+    }
+
+    #[test]
+    fn test_diff_changed_field_and_client_state() {
+        let from = revision(
+            1,
+            first_entry_in_progress(example_results(), ClientState::default()),
+        );
+        let to = revision(
+            2,
+            first_entry_in_progress(
+                with_poll_card_count(1234),
+                ClientState(Some(serde_json::json!({"section": "voters_votes_counts"}))),
+            ),
+        );
+        let diff = from.diff(&to);
+
+        assert_eq!(diff.from.revision, 1);
+        assert_eq!(diff.to.revision, 2);
+        assert_eq!(diff.first_entry, vec!["data.voters_counts.poll_card_count"]);
+        assert!(diff.second_entry.is_empty());
+        assert!(diff.client_state_changed);
+    }
+
+    #[test]
+    fn test_diff_entry_added_and_removed() {
+        let finished_at = Utc.with_ymd_and_hms(2026, 3, 18, 21, 0, 0).unwrap();
+        let from = revision(
+            1,
+            DataEntryStatus::EntriesDifferent(EntriesDifferent {
+                first_entry_user_id: Some(UserId::from(1)),
+                imported_by_user_id: None,
+                second_entry_user_id: UserId::from(2),
+                first_entry: example_results(),
+                second_entry: with_poll_card_count(1234),
+                first_entry_finished_at: finished_at,
+                second_entry_finished_at: finished_at,
+            }),
+        );
+        let to = revision(
+            2,
+            DataEntryStatus::Definitive(Definitive {
+                first_entry_user_id: Some(UserId::from(1)),
+                second_entry_user_id: Some(UserId::from(2)),
+                third_entry_user_id: None,
+                imported_by_user_id: None,
+                results: example_results(),
+                finished_at,
+                finalised_with_warnings: false,
+            }),
+        );
+        let diff = from.diff(&to);
+
+        assert_eq!(diff.first_entry, vec!["data"]);
+        assert_eq!(diff.second_entry, vec!["data"]);
+        assert_eq!(diff.definitive, vec!["data"]);
+        assert!(!diff.client_state_changed);
+    }
+}
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Type};
 use utoipa::ToSchema;
 
-use crate::domain::identifier::id;
+use crate::domain::{identifier::id, models::ModelLanguage};
 
 id!(ElectionId);
 
@@ -44,6 +44,11 @@ pub struct Election {
     pub authority_name: String,
     /// See [`ElectionWithPoliticalGroups::authority_region`]
     pub authority_region: String,
+    /// See [`ElectionWithPoliticalGroups::frisian_export_allowed`]
+    pub frisian_export_allowed: bool,
+    /// See [`ElectionWithPoliticalGroups::model_language`]
+    #[serde(default)]
+    pub model_language: ModelLanguage,
     /// See [`ElectionWithPoliticalGroups::district`]
     pub district: CommitteeDistrict,
     /// See [`ElectionWithPoliticalGroups::domain`]
@@ -105,6 +110,13 @@ pub struct ElectionWithPoliticalGroups {
     pub authority_name: String,
     /// The name of the region that the committee is responsible for.
     pub authority_region: String,
+    /// Whether the region that the committee is responsible for allows its
+    /// models to be produced in Frisian.
+    pub frisian_export_allowed: bool,
+    /// The language the models of this election are produced in. Defaults to
+    /// Dutch, Frisian can only be chosen when the region allows Frisian export.
+    #[serde(default)]
+    pub model_language: ModelLanguage,
     /// The district that this committee is responsible for. This will be None
     /// for committees within elections that do not have districts. When an
     /// election does have districts this will be All for committees that are
@@ -143,6 +155,12 @@ pub struct ElectionWithPoliticalGroups {
     /// election.
     #[sqlx(json)]
     pub political_groups: Vec<PoliticalGroup>,
+    /// For referendums this contains the question that is put to the voters
+    /// and its answer options. For other elections this field is None.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    #[sqlx(json(nullable))]
+    pub referendum: Option<ReferendumQuestion>,
 }
 
 impl From<ElectionWithPoliticalGroups> for Election {
@@ -157,6 +175,8 @@ impl From<ElectionWithPoliticalGroups> for Election {
             authority_id: value.authority_id,
             authority_name: value.authority_name,
             authority_region: value.authority_region,
+            frisian_export_allowed: value.frisian_export_allowed,
+            model_language: value.model_language,
             district: value.district,
             domain: value.domain,
             category: value.category,
@@ -263,6 +283,8 @@ pub struct NewElection {
     pub authority_name: String,
     /// See [`ElectionWithPoliticalGroups::authority_region`]
     pub authority_region: String,
+    /// See [`ElectionWithPoliticalGroups::frisian_export_allowed`]
+    pub frisian_export_allowed: bool,
     /// See [`ElectionWithPoliticalGroups::district`]
     pub district: CommitteeDistrict,
     /// See [`ElectionWithPoliticalGroups::domain`]
@@ -285,6 +307,10 @@ pub struct NewElection {
     pub nomination_date: NaiveDate,
     /// See [`ElectionWithPoliticalGroups::political_groups`]
     pub political_groups: Vec<RegisteredPoliticalGroup>,
+    /// See [`ElectionWithPoliticalGroups::referendum`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub referendum: Option<ReferendumQuestion>,
 }
 
 /// Election number of voters change request
@@ -294,6 +320,13 @@ pub struct ElectionNumberOfVotersChangeRequest {
     pub number_of_voters: u32,
 }
 
+/// Election model language change request
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ElectionModelLanguageChangeRequest {
+    pub model_language: ModelLanguage,
+}
+
 /// Election category (limited for now)
 #[derive(
     Serialize,
@@ -318,14 +351,55 @@ pub enum ElectionCategory {
     Provincial,
     /// Waterschapsverkiezing
     WaterAuthority,
+    /// Tweede Kamerverkiezing
+    NationalParliament,
+    /// Verkiezing van het Europees Parlement
+    EuropeanParliament,
+    /// Raadgevend referendum (volksraadpleging) with a yes/no question
+    Referendum,
+    /// Eilandsraadsverkiezing in one of the public bodies Bonaire, Sint Eustatius or Saba
+    Island,
+    /// Verkiezing van het kiescollege voor de Eerste Kamer in one of the public bodies
+    IslandElectoralCollege,
+    /// Verkiezing van het kiescollege niet-ingezetenen
+    NonResidentElectoralCollege,
+    /// Eerste Kamerverkiezing
+    Senate,
 }
 
 impl ElectionCategory {
+    /// Local elections have no voter cards and smaller polling station committees.
+    /// This is the only difference in the official GSB models (N 10-2, Na 14-2 and Na 31-2),
+    /// so e.g. Tweede Kamer elections use the same wording as provincial elections.
     pub fn is_local_election(&self) -> bool {
         match self {
             ElectionCategory::Municipal => true,
             ElectionCategory::Provincial => false,
             ElectionCategory::WaterAuthority => false,
+            ElectionCategory::NationalParliament => false,
+            ElectionCategory::EuropeanParliament => false,
+            ElectionCategory::Referendum => true,
+            ElectionCategory::Island => true,
+            ElectionCategory::IslandElectoralCollege => true,
+            ElectionCategory::NonResidentElectoralCollege => false,
+            ElectionCategory::Senate => false,
+        }
+    }
+
+    /// Whether this election is held in the whole country, in which case the
+    /// election definition does not contain an election domain.
+    pub fn is_national_election(&self) -> bool {
+        match self {
+            ElectionCategory::Municipal
+            | ElectionCategory::Provincial
+            | ElectionCategory::WaterAuthority
+            | ElectionCategory::Referendum
+            | ElectionCategory::Island
+            | ElectionCategory::IslandElectoralCollege => false,
+            ElectionCategory::NationalParliament
+            | ElectionCategory::EuropeanParliament
+            | ElectionCategory::NonResidentElectoralCollege
+            | ElectionCategory::Senate => true,
         }
     }
 
@@ -334,6 +408,13 @@ impl ElectionCategory {
             ElectionCategory::Municipal => "GR",
             ElectionCategory::Provincial => "PS",
             ElectionCategory::WaterAuthority => "AB",
+            ElectionCategory::NationalParliament => "TK",
+            ElectionCategory::EuropeanParliament => "EP",
+            ElectionCategory::Referendum => "LR",
+            ElectionCategory::Island => "ER",
+            ElectionCategory::IslandElectoralCollege => "IR",
+            ElectionCategory::NonResidentElectoralCollege => "NR",
+            ElectionCategory::Senate => "EK",
         }
     }
 
@@ -357,6 +438,14 @@ impl ElectionCategory {
                     ElectionSubCategory::AB2
                 }
             }
+            ElectionCategory::NationalParliament => ElectionSubCategory::TK,
+            ElectionCategory::EuropeanParliament => ElectionSubCategory::EP,
+            ElectionCategory::Referendum => ElectionSubCategory::LR,
+            // All island councils have less than 19 seats
+            ElectionCategory::Island => ElectionSubCategory::ER1,
+            ElectionCategory::IslandElectoralCollege => ElectionSubCategory::IR,
+            ElectionCategory::NonResidentElectoralCollege => ElectionSubCategory::NR,
+            ElectionCategory::Senate => ElectionSubCategory::EK,
         }
     }
 }
@@ -441,6 +530,30 @@ pub struct ElectionDomain {
     pub name: String,
 }
 
+/// The question of a referendum and its answer options
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(deny_unknown_fields)]
+pub struct ReferendumQuestion {
+    /// The question that is put to the voters
+    pub question: String,
+    /// The answer option whose votes are counted as yes votes
+    pub yes_option: String,
+    /// The answer option whose votes are counted as no votes
+    pub no_option: String,
+}
+
+impl ReferendumQuestion {
+    /// Create a referendum question with the default answer options
+    /// "Voor" and "Tegen".
+    pub fn new(question: impl Into<String>) -> Self {
+        Self {
+            question: question.into(),
+            yes_option: "Voor".to_string(),
+            no_option: "Tegen".to_string(),
+        }
+    }
+}
+
 /// Election sub category (limited for now)
 #[derive(
     Serialize, Deserialize, strum::Display, ToSchema, Clone, Copy, Debug, PartialEq, Eq, Hash, Type,
@@ -458,6 +571,20 @@ pub enum ElectionSubCategory {
     PS1,
     /// Provinciale Statenverkiezing multiple districts
     PS2,
+    /// Tweede Kamerverkiezing
+    TK,
+    /// Verkiezing van het Europees Parlement
+    EP,
+    /// Lokaal referendum
+    LR,
+    /// Eilandsraadsverkiezing (all island councils have less than 19 seats)
+    ER1,
+    /// Kiescollegeverkiezing in a public body
+    IR,
+    /// Kiescollegeverkiezing niet-ingezetenen
+    NR,
+    /// Eerste Kamerverkiezing
+    EK,
 }
 
 /// Committee category
@@ -671,8 +798,13 @@ pub(crate) mod tests {
             },
             authority_name: "Test".to_string(),
             authority_region: "Test".to_string(),
+            frisian_export_allowed: false,
+            model_language: ModelLanguage::Dutch,
+            referendum: (election_category == ElectionCategory::Referendum)
+                .then(|| ReferendumQuestion::new("Bent u voor of tegen het voorstel?")),
             district: CommitteeDistrict::None,
-            domain: Some(ElectionDomain {
+            // national elections do not have a domain
+            domain: (!election_category.is_national_election()).then(|| ElectionDomain {
                 id: if election_category != ElectionCategory::Provincial {
                     Some("0000".to_string()) // provincial elections do not have a domain id
                 } else {
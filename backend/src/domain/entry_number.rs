@@ -3,15 +3,29 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 use sqlx::Sqlite;
 
-#[derive(Debug, Copy, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum EntryNumber {
     FirstEntry,
     SecondEntry,
+    /// Third entry of the fields that differ between the first and second entry
+    ThirdEntry,
+}
+
+impl From<EntryNumber> for u8 {
+    fn from(entry_number: EntryNumber) -> Self {
+        match entry_number {
+            EntryNumber::FirstEntry => 1,
+            EntryNumber::SecondEntry => 2,
+            EntryNumber::ThirdEntry => 3,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct InvalidEntryNumberError(u8);
 
+impl std::error::Error for InvalidEntryNumberError {}
+
 impl Display for InvalidEntryNumberError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "invalid entry number `{}`", self.0)
@@ -24,11 +38,21 @@ impl TryFrom<u8> for EntryNumber {
         match n {
             1 => Ok(Self::FirstEntry),
             2 => Ok(Self::SecondEntry),
+            3 => Ok(Self::ThirdEntry),
             _ => Err(InvalidEntryNumberError(n)),
         }
     }
 }
 
+impl Serialize for EntryNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        u8::from(*self).serialize(serializer)
+    }
+}
+
 impl<'de> Deserialize<'de> for EntryNumber {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -44,11 +68,16 @@ impl<'q> sqlx::Encode<'q, Sqlite> for EntryNumber {
         &self,
         buf: &mut <Sqlite as sqlx::Database>::ArgumentBuffer,
     ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
-        let n: u8 = match self {
-            EntryNumber::FirstEntry => 1,
-            EntryNumber::SecondEntry => 2,
-        };
-        n.encode(buf)
+        u8::from(*self).encode(buf)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, Sqlite> for EntryNumber {
+    fn decode(
+        value: <Sqlite as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        let n = u8::decode(value)?;
+        Ok(Self::try_from(n)?)
     }
 }
 
@@ -56,4 +85,8 @@ impl sqlx::Type<Sqlite> for EntryNumber {
     fn type_info() -> <Sqlite as sqlx::Database>::TypeInfo {
         u8::type_info()
     }
+
+    fn compatible(ty: &<Sqlite as sqlx::Database>::TypeInfo) -> bool {
+        u8::compatible(ty)
+    }
 }
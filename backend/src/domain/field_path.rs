@@ -27,6 +27,15 @@ impl FieldPath {
         path
     }
 
+    /// JSON pointer to the field within the serialised data, leaving out the first component
+    pub fn json_pointer(&self) -> String {
+        self.to_string()
+            .split('.')
+            .skip(1)
+            .map(|component| format!("/{component}"))
+            .collect()
+    }
+
     pub fn last(&self) -> &str {
         self.components
             .last()
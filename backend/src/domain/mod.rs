@@ -1,9 +1,12 @@
 pub mod apportionment;
+pub mod apportionment_snapshot;
 pub mod apportionment_state;
 pub mod committee_session;
 pub mod committee_session_status;
 pub mod compare;
 pub mod data_entry;
+pub mod data_entry_assignment;
+pub mod data_entry_revision;
 pub mod election;
 pub mod entry_number;
 pub mod field_path;
@@ -17,6 +20,7 @@ pub mod results;
 pub mod role;
 pub mod sub_committee;
 pub mod tabulation;
+pub mod vacancy;
 #[cfg(test)]
 pub mod valid_default;
 pub mod validate;
@@ -6,6 +6,7 @@ use crate::{
         election::PoliticalGroup,
         results::{
             political_group_total_votes::EnrichedPoliticalGroupTotalVotes,
+            referendum_results::ReferendumVotesCounts,
             voters_counts::VotersCounts,
             votes_counts::{EnrichedVotesCounts, VotesCounts},
         },
@@ -89,6 +90,34 @@ impl ElectionTotalsCSB {
     }
 }
 
+/// A version of ElectionTotals for referendums, with the yes/no votes instead of the votes per list
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ElectionTotalsReferendum {
+    /// The total number of voters
+    pub voters_counts: VotersCounts,
+    /// The total number of votes
+    pub votes_counts: ReferendumVotesCounts,
+    /// The differences between voters and votes
+    pub differences_counts: DifferencesTotals,
+}
+
+impl ElectionTotalsReferendum {
+    pub fn new(totals: &ElectionTotals) -> Result<Self, APIError> {
+        let Some(referendum_votes_counts) = &totals.referendum_votes_counts else {
+            return Err(APIError::DataIntegrityError(
+                "Referendum election totals can only be created for referendum totals".to_string(),
+            ));
+        };
+
+        Ok(ElectionTotalsReferendum {
+            voters_counts: totals.voters_counts.clone(),
+            votes_counts: referendum_votes_counts.clone(),
+            differences_counts: totals.differences_counts.clone(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use test_log::test;
@@ -110,4 +139,15 @@ mod tests {
         let gsb_totals = ElectionTotals::tabulate(&gsb_election, &[]).unwrap();
         assert!(ElectionTotalsCSB::new(&gsb_totals, &gsb_election.political_groups).is_err());
     }
+
+    #[test]
+    fn test_election_totals_referendum_requires_referendum_totals() {
+        let referendum_election = election_fixture(ElectionCategory::Referendum, GSB, &[]);
+        let referendum_totals = ElectionTotals::tabulate(&referendum_election, &[]).unwrap();
+        assert!(ElectionTotalsReferendum::new(&referendum_totals).is_ok());
+
+        let gsb_election = election_fixture(ElectionCategory::Municipal, GSB, &[2, 3]);
+        let gsb_totals = ElectionTotals::tabulate(&gsb_election, &[]).unwrap();
+        assert!(ElectionTotalsReferendum::new(&gsb_totals).is_err());
+    }
 }
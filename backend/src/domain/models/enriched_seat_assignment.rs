@@ -4,6 +4,7 @@ use crate::domain::{
     apportionment::{DisplayFraction, ListSeatAssignment, SeatAssignment, SeatChangeStep},
     election::PGNumber,
     models::{election_totals::ElectionTotalsCSB, error::ModelsError},
+    results::political_group_total_votes::EnrichedPoliticalGroupTotalVotes,
 };
 
 struct InitialSteps<'a> {
@@ -142,7 +143,7 @@ impl EnrichedSeatAssignment {
     }
 
     fn get_list_seat_assignments(
-        totals: &ElectionTotalsCSB,
+        political_group_total_votes: &[EnrichedPoliticalGroupTotalVotes],
         seat_assignment: &SeatAssignment,
     ) -> Result<ListSeatAssignments, ModelsError> {
         let mut enriched_list_seat_assignments = Vec::new();
@@ -158,7 +159,7 @@ impl EnrichedSeatAssignment {
             )
         };
 
-        for pg_votes in &totals.votes_counts.political_group_total_votes {
+        for pg_votes in political_group_total_votes {
             let initial_full_seats = Self::get_initial_full_seats(seat_assignment, pg_votes.number);
             let largest_remainder = if initial_steps.initial_largest_remainder_steps.is_empty() {
                 None
@@ -197,7 +198,22 @@ impl EnrichedSeatAssignment {
         totals: &ElectionTotalsCSB,
         seat_assignment: &SeatAssignment,
     ) -> Result<Self, ModelsError> {
-        let list_seat_assignments = Self::get_list_seat_assignments(totals, seat_assignment)?;
+        Self::from_political_group_total_votes(
+            number_of_seats,
+            &totals.votes_counts.political_group_total_votes,
+            seat_assignment,
+        )
+    }
+
+    /// Create the enriched seat assignment from the total votes per political group only, for
+    /// seat assignments that were not calculated from the totals of an election in Abacus
+    pub fn from_political_group_total_votes(
+        number_of_seats: u32,
+        political_group_total_votes: &[EnrichedPoliticalGroupTotalVotes],
+        seat_assignment: &SeatAssignment,
+    ) -> Result<Self, ModelsError> {
+        let list_seat_assignments =
+            Self::get_list_seat_assignments(political_group_total_votes, seat_assignment)?;
         Ok(EnrichedSeatAssignment {
             quota: seat_assignment.quota,
             list_seat_assignment: list_seat_assignments.enriched_list_seat_assignments,
@@ -308,6 +324,7 @@ mod tests {
                 fewer_ballots_count: SumCount::zero(),
             },
             political_group_votes,
+            referendum_votes_counts: None,
             committee_specific: CommitteeSpecificTotals::CSB {
                 number_of_voters: election.number_of_voters,
             },
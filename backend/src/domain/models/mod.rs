@@ -12,10 +12,16 @@ mod model_na_31_1;
 mod model_na_31_2;
 mod model_p_22_2;
 mod model_p_2a;
+mod model_referendum;
+mod seat_margins_appendix;
+mod vacancy_decision;
 pub mod votes_table;
 
 use std::error::Error;
 
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
 use filter_input::replace_unsupported_glyphs;
 pub use model_n_10_1::*;
 pub use model_n_10_2::*;
@@ -25,11 +31,39 @@ pub use model_na_31_1::*;
 pub use model_na_31_2::*;
 pub use model_p_2a::*;
 pub use model_p_22_2::*;
+pub use model_referendum::*;
+pub use seat_margins_appendix::*;
+pub use vacancy_decision::*;
 
 pub trait ToPdfFileModel {
     fn to_pdf_file_model(self, file_name: String) -> PdfFileModel;
 }
 
+/// Language in which the models of an election are produced. Municipalities
+/// in Fryslân are allowed to produce their official models in Frisian.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, sqlx::Type,
+)]
+pub enum ModelLanguage {
+    #[default]
+    #[serde(rename = "nl")]
+    #[sqlx(rename = "nl")]
+    Dutch,
+    #[serde(rename = "fy")]
+    #[sqlx(rename = "fy")]
+    Frisian,
+}
+
+impl ModelLanguage {
+    /// Language code as used by the templates
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Dutch => "nl",
+            Self::Frisian => "fy",
+        }
+    }
+}
+
 pub struct PdfFileModel {
     pub file_name: String,
     pub model: PdfModel,
@@ -57,6 +91,9 @@ pub enum PdfModel {
     ModelP2a(Box<ModelP2aInput>),
     ModelP22_2(Box<ModelP22_2Input>),
     ModelP22_2Bijlage1(Box<ModelP22_2Bijlage1Input>),
+    ModelReferendum(Box<ModelReferendumInput>),
+    SeatMarginsAppendix(Box<SeatMarginsAppendixInput>),
+    VacancyDecision(Box<VacancyDecisionInput>),
     #[cfg(test)]
     TestTeletexCharset(),
     #[cfg(test)]
@@ -81,6 +118,9 @@ impl PdfModel {
             Self::ModelP2a(_) => "model-p-2a",
             Self::ModelP22_2(_) => "model-p-22-2",
             Self::ModelP22_2Bijlage1(_) => "model-p-22-2-bijlage-1",
+            Self::ModelReferendum(_) => "model-referendum",
+            Self::SeatMarginsAppendix(_) => "bijlage-zetelmarges",
+            Self::VacancyDecision(_) => "besluit-vacature",
             #[cfg(test)]
             Self::TestTeletexCharset() => "test-teletex-charset",
             #[cfg(test)]
@@ -105,6 +145,9 @@ impl PdfModel {
             Self::ModelP2a(_) => "model-p-2a.typ",
             Self::ModelP22_2(_) => "model-p-22-2.typ",
             Self::ModelP22_2Bijlage1(_) => "model-p-22-2-bijlage-1.typ",
+            Self::ModelReferendum(_) => "model-referendum.typ",
+            Self::SeatMarginsAppendix(_) => "bijlage-zetelmarges.typ",
+            Self::VacancyDecision(_) => "besluit-vacature.typ",
             #[cfg(test)]
             Self::TestTeletexCharset() => "test-teletex-charset.typ",
             #[cfg(test)]
@@ -137,6 +180,9 @@ impl PdfModel {
             Self::ModelP2a(_) => "inputs/model-p-2a-variations/model-p-2a-GR.json",
             Self::ModelP22_2(_) => "inputs/model-p-22-2-variations/lt-19-seats-and-p9-and-p10.json",
             Self::ModelP22_2Bijlage1(_) => "inputs/model-p-22-2-bijlage-1.json",
+            Self::ModelReferendum(_) => "inputs/model-referendum.json",
+            Self::SeatMarginsAppendix(_) => "inputs/bijlage-zetelmarges.json",
+            Self::VacancyDecision(_) => "inputs/besluit-vacature.json",
             #[cfg(test)]
             Self::TestTeletexCharset() => "inputs/test-teletex-charset.json",
             #[cfg(test)]
@@ -144,6 +190,32 @@ impl PdfModel {
         }
     }
 
+    /// Get the language of the model
+    pub fn language(&self) -> ModelLanguage {
+        match self {
+            Self::ModelN10_1(input) => input.language,
+            Self::ModelN10_1Inlegvel(input) => input.language,
+            Self::ModelN10_2(input) => input.language,
+            Self::ModelNa14_1Versie1(input) => input.language,
+            Self::ModelNa14_1Versie2(input) => input.language,
+            Self::ModelNa14_2(input) => input.language,
+            Self::ModelNa14_2Bijlage1(input) => input.language,
+            Self::ModelNa31_1Inlegvel(input) => input.language,
+            Self::ModelNa31_2(input) => input.language,
+            Self::ModelNa31_2Bijlage1(input) => input.language,
+            Self::ModelNa31_2Inlegvel(input) => input.language,
+            Self::ModelP2a(input) => input.language,
+            Self::ModelP22_2(input) => input.language,
+            Self::ModelP22_2Bijlage1(input) => input.language,
+            Self::ModelReferendum(input) => input.language,
+            Self::VacancyDecision(input) => input.language,
+            // this document is not an official model and is always in Dutch
+            Self::SeatMarginsAppendix(_) => ModelLanguage::Dutch,
+            #[cfg(test)]
+            Self::TestTeletexCharset() | Self::TestUnsupportedChars() => ModelLanguage::Dutch,
+        }
+    }
+
     /// Get the input, serialized as json
     pub fn get_input(&self) -> String {
         let data = match self {
@@ -161,6 +233,9 @@ impl PdfModel {
             Self::ModelP2a(input) => serde_json::to_string(input),
             Self::ModelP22_2(input) => serde_json::to_string(input),
             Self::ModelP22_2Bijlage1(input) => serde_json::to_string(input),
+            Self::ModelReferendum(input) => serde_json::to_string(input),
+            Self::SeatMarginsAppendix(input) => serde_json::to_string(input),
+            Self::VacancyDecision(input) => serde_json::to_string(input),
             #[cfg(test)]
             Self::TestTeletexCharset() => {
                 Ok(include_str!("../../../templates/inputs/test-teletex-charset.json").to_string())
@@ -198,6 +273,9 @@ impl PdfModel {
             "model-p-2a" => Ok(Self::ModelP2a(serde_json::from_str(input)?)),
             "model-p-22-2" => Ok(Self::ModelP22_2(serde_json::from_str(input)?)),
             "model-p-22-2-bijlage-1" => Ok(Self::ModelP22_2Bijlage1(serde_json::from_str(input)?)),
+            "model-referendum" => Ok(Self::ModelReferendum(serde_json::from_str(input)?)),
+            "besluit-vacature" => Ok(Self::VacancyDecision(serde_json::from_str(input)?)),
+            "bijlage-zetelmarges" => Ok(Self::SeatMarginsAppendix(serde_json::from_str(input)?)),
             _ => Err(Error::new(ErrorKind::InvalidInput, "Unknown model").into()),
         }
     }
@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::domain::{
     election::Election,
-    models::{PdfFileModel, PdfModel, ToPdfFileModel, votes_table::CandidatesTables},
+    models::{
+        ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel, votes_table::CandidatesTables,
+    },
     polling_station::PollingStation,
 };
 
@@ -12,6 +14,8 @@ pub struct ModelN10_1Input {
     pub election: Election,
     pub polling_station: PollingStation,
     pub candidates_tables: CandidatesTables,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelN10_1Input {
@@ -25,6 +29,8 @@ impl ToPdfFileModel for ModelN10_1Input {
 pub struct ModelN10_1InlegvelInput {
     pub election: Election,
     pub polling_station: PollingStation,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelN10_1InlegvelInput {
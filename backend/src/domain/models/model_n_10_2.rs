@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::domain::{
     election::ElectionWithPoliticalGroups,
-    models::{PdfFileModel, PdfModel, ToPdfFileModel},
+    models::{ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel},
     polling_station::PollingStation,
 };
 
@@ -10,6 +10,8 @@ use crate::domain::{
 pub struct ModelN10_2Input {
     pub election: ElectionWithPoliticalGroups,
     pub polling_station: PollingStation,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelN10_2Input {
@@ -5,7 +5,7 @@ use crate::domain::{
     election::Election,
     investigation::PollingStationInvestigation,
     models::{
-        PdfFileModel, PdfModel, ToPdfFileModel,
+        ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel,
         votes_table::{CandidatesTables, VotesTablesWithOnlyPreviousVotes},
     },
     polling_station::PollingStation,
@@ -19,6 +19,8 @@ pub struct ModelNa14_1Versie1Input {
     pub election: Election,
     pub polling_station: PollingStation,
     pub candidates_tables: CandidatesTables,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa14_1Versie1Input {
@@ -36,6 +38,8 @@ pub struct ModelNa14_1Versie2Input {
     pub previous_results: CommonPollingStationResultsWithoutVotes,
     pub investigation: PollingStationInvestigation,
     pub votes_tables: VotesTablesWithOnlyPreviousVotes,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa14_1Versie2Input {
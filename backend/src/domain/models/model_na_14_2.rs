@@ -5,7 +5,7 @@ use crate::domain::{
     election::Election,
     investigation::PollingStationInvestigation,
     models::{
-        PdfFileModel, PdfModel, ToPdfFileModel,
+        ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel,
         election_totals::ElectionTotalsWithoutVotes,
         votes_table::{VotesTablesWithOnlyPreviousVotes, VotesTablesWithPreviousVotes},
     },
@@ -22,8 +22,11 @@ pub struct ModelNa14_2Input {
     pub previous_summary: ElectionTotalsWithoutVotes,
     pub summary: ElectionTotalsWithoutVotes,
     pub hash: String,
+    pub audit_log_hash: String,
     pub creation_date_time: String,
     pub votes_tables: VotesTablesWithPreviousVotes,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa14_2Input {
@@ -40,6 +43,8 @@ pub struct ModelNa14_2Bijlage1Input {
     pub previous_results: CommonPollingStationResultsWithoutVotes,
     pub investigation: PollingStationInvestigation,
     pub votes_tables: VotesTablesWithOnlyPreviousVotes,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa14_2Bijlage1Input {
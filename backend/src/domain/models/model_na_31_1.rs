@@ -2,13 +2,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::domain::{
     election::Election,
-    models::{PdfFileModel, PdfModel, ToPdfFileModel},
+    models::{ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel},
 };
 
 #[derive(Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModelNa31_1InlegvelInput {
     pub election: Election,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa31_1InlegvelInput {
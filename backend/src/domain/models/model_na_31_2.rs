@@ -4,7 +4,7 @@ use crate::domain::{
     committee_session::CommitteeSession,
     election::Election,
     models::{
-        PdfFileModel, PdfModel, ToPdfFileModel,
+        ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel,
         election_totals::ElectionTotalsWithoutVotes,
         votes_table::{CandidatesTables, VotesTables},
     },
@@ -21,8 +21,11 @@ pub struct ModelNa31_2Input {
     pub polling_stations: Vec<PollingStation>,
     pub polling_station_investigations: CSOInvestigations,
     pub hash: String,
+    pub audit_log_hash: String,
     pub creation_date_time: String,
     pub votes_tables: VotesTables,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa31_2Input {
@@ -37,6 +40,8 @@ pub struct ModelNa31_2Bijlage1Input {
     pub election: Election,
     pub polling_station: PollingStation,
     pub candidates_tables: CandidatesTables,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa31_2Bijlage1Input {
@@ -49,6 +54,8 @@ impl ToPdfFileModel for ModelNa31_2Bijlage1Input {
 #[serde(deny_unknown_fields)]
 pub struct ModelNa31_2InlegvelInput {
     pub election: Election,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelNa31_2InlegvelInput {
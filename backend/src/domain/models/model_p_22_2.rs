@@ -1,11 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::domain::{
+    apportionment::SeatChangeExplanation,
     committee_session::CommitteeSession,
     election::Election,
     models::{
-        PdfFileModel, PdfModel, ToPdfFileModel, apportionment_footnotes::ApportionmentFootnotes,
-        election_totals::ElectionTotalsCSB,
+        ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel,
+        apportionment_footnotes::ApportionmentFootnotes, election_totals::ElectionTotalsCSB,
         enriched_candidate_nomination::EnrichedCandidateNomination,
         enriched_seat_assignment::EnrichedSeatAssignment, votes_table::VotesTables,
     },
@@ -21,8 +22,14 @@ pub struct ModelP22_2Input {
     pub footnotes: Option<ApportionmentFootnotes>,
     pub seat_assignment: EnrichedSeatAssignment,
     pub candidate_nomination: EnrichedCandidateNomination,
+    /// Explanation of every step of the residual seat assignment
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub explanations: Vec<SeatChangeExplanation>,
     pub hash: String,
+    pub audit_log_hash: String,
     pub creation_date_time: String,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelP22_2Input {
@@ -37,7 +44,10 @@ pub struct ModelP22_2Bijlage1Input {
     pub election: Election,
     pub votes_tables: VotesTables,
     pub hash: String,
+    pub audit_log_hash: String,
     pub creation_date_time: String,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelP22_2Bijlage1Input {
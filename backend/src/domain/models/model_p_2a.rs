@@ -4,7 +4,7 @@ use crate::domain::{
     committee_session::CommitteeSession,
     election::Election,
     investigation::PollingStationInvestigation,
-    models::{PdfFileModel, PdfModel, ToPdfFileModel},
+    models::{ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel},
     polling_station::PollingStation,
 };
 
@@ -14,6 +14,8 @@ pub struct ModelP2aInput {
     pub committee_session: CommitteeSession,
     pub election: Election,
     pub investigations: Vec<(PollingStation, PollingStationInvestigation)>,
+    #[serde(default)]
+    pub language: ModelLanguage,
 }
 
 impl ToPdfFileModel for ModelP2aInput {
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    committee_session::CommitteeSession,
+    election::Election,
+    models::{
+        ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel,
+        election_totals::ElectionTotalsReferendum,
+    },
+    polling_station::PollingStation,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelReferendumInput {
+    pub committee_session: CommitteeSession,
+    pub election: Election,
+    pub summary: ElectionTotalsReferendum,
+    pub polling_stations: Vec<PollingStation>,
+    pub hash: String,
+    pub audit_log_hash: String,
+    pub creation_date_time: String,
+    #[serde(default)]
+    pub language: ModelLanguage,
+}
+
+impl ToPdfFileModel for ModelReferendumInput {
+    fn to_pdf_file_model(self, file_name: String) -> PdfFileModel {
+        PdfFileModel::new(file_name, PdfModel::ModelReferendum(Box::new(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use super::*;
+
+    #[test]
+    fn test_json_matches_struct() {
+        let reader = BufReader::new(File::open("templates/inputs/model-referendum.json").unwrap());
+        serde_json::from_reader::<_, ModelReferendumInput>(reader)
+            .expect("model-referendum.json should deserialize to struct ModelReferendumInput");
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    apportionment::SeatMargins,
+    election::Election,
+    models::{PdfFileModel, PdfModel, ToPdfFileModel},
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeatMarginsAppendixInput {
+    pub election: Election,
+    pub seat_margins: SeatMargins,
+    pub creation_date_time: String,
+}
+
+impl ToPdfFileModel for SeatMarginsAppendixInput {
+    fn to_pdf_file_model(self, file_name: String) -> PdfFileModel {
+        PdfFileModel::new(file_name, PdfModel::SeatMarginsAppendix(Box::new(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_json_variations_match_struct() {
+        let reader =
+            BufReader::new(File::open("templates/inputs/bijlage-zetelmarges.json").unwrap());
+        serde_json::from_reader::<_, SeatMarginsAppendixInput>(reader).expect(
+            "bijlage-zetelmarges.json should deserialize to struct SeatMarginsAppendixInput",
+        );
+    }
+}
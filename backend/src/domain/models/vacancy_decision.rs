@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    election::{Candidate, Election, PGNumber},
+    models::{ModelLanguage, PdfFileModel, PdfModel, ToPdfFileModel},
+    vacancy::{SuccessorSkipReason, VacancyReason},
+};
+
+/// Candidate that was skipped while designating the successor
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VacancyDecisionSkippedCandidate {
+    pub candidate: Candidate,
+    pub reason: SuccessorSkipReason,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VacancyDecisionInput {
+    pub election: Election,
+    pub list_number: PGNumber,
+    pub list_name: String,
+    pub reason: VacancyReason,
+    pub vacated_candidate: Candidate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub successor: Option<Candidate>,
+    pub skipped_candidates: Vec<VacancyDecisionSkippedCandidate>,
+    pub creation_date_time: String,
+    #[serde(default)]
+    pub language: ModelLanguage,
+}
+
+impl ToPdfFileModel for VacancyDecisionInput {
+    fn to_pdf_file_model(self, file_name: String) -> PdfFileModel {
+        PdfFileModel::new(file_name, PdfModel::VacancyDecision(Box::new(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_json_variations_match_struct() {
+        let reader = BufReader::new(File::open("templates/inputs/besluit-vacature.json").unwrap());
+        serde_json::from_reader::<_, VacancyDecisionInput>(reader)
+            .expect("besluit-vacature.json should deserialize to struct VacancyDecisionInput");
+    }
+}
@@ -309,6 +309,7 @@ mod tests {
             CommitteeCategory, CommitteeDistrict, ElectionCategory, ElectionDomain, ElectionId,
             ElectionSubCategory, VoteCountingMethod,
         },
+        models::ModelLanguage,
         results::political_group_candidate_votes::CandidateVotes,
     };
 
@@ -348,6 +349,9 @@ mod tests {
             authority_id: "0000".to_string(),
             authority_name: "Test".to_string(),
             authority_region: "Test".to_string(),
+            frisian_export_allowed: false,
+            model_language: ModelLanguage::Dutch,
+            referendum: None,
             district: CommitteeDistrict::None,
             domain: Some(ElectionDomain {
                 id: Some("0000".to_string()),
@@ -21,7 +21,7 @@ use crate::{
     repository::{
         committee_session_repo::{self},
         data_entry_repo::are_results_complete_for_committee_session,
-        file_repo,
+        election_repo, file_repo,
     },
     service::{get_apportionment_state, list_polling_stations_for_session},
 };
@@ -80,7 +80,9 @@ async fn generate_and_save_files_gsb_election(
     // For next sessions without corrections, we don't store these
     if !committee_session.is_next_session() || corrections {
         files.results_eml = Some(saver.save(generated_files.results_eml).await?);
-        files.results_csv = Some(saver.save(generated_files.results_csv).await?);
+        if let Some(results_csv) = generated_files.results_csv {
+            files.results_csv = Some(saver.save(results_csv).await?);
+        }
 
         files.results_pdf = Some(saver.save(generated_files.results_pdf).await?);
     }
@@ -112,7 +114,7 @@ async fn generate_and_save_files_csb_election(
     let apportionment_input = ApportionmentInputData::new(
         input_data.election.number_of_seats,
         &input_data.totals.political_group_votes,
-        state.get_deceased_candidates(),
+        state.get_excluded_candidates(),
         state.get_lists_drawn(),
         state.get_candidates_drawn(),
     );
@@ -189,10 +191,11 @@ pub async fn get_files_gsb_election(
 
     // Check if files exist, if so, get files from database
     let mut files = get_existing_gsb_files(&mut conn, committee_session.id).await?;
+    let election = election_repo::get(&mut conn, committee_session.election_id).await?;
     drop(conn);
 
     // If one of the files doesn't exist, generate all and save them to the database
-    if files.needs_generation(&committee_session, corrections) {
+    if files.needs_generation(&committee_session, election.category, corrections) {
         let mut tx = pool.begin_immediate().await?;
         files = generate_and_save_files_gsb_election(
             &mut tx,
@@ -11,14 +11,16 @@ use crate::{
     domain::{
         committee_session::{CommitteeSession, CommitteeSessionId},
         data_entry::DataEntrySource,
-        election::{CommitteeCategory, ElectionWithPoliticalGroups, VoteCountingMethod},
+        election::{
+            CommitteeCategory, ElectionCategory, ElectionWithPoliticalGroups, VoteCountingMethod,
+        },
         file::{File, FileType},
         investigation::PollingStationInvestigation,
         models::{
             ModelNa14_2Input, ModelNa31_2Input, ModelP2aInput, ModelP22_2Bijlage1Input,
-            ModelP22_2Input, PdfFileModel, ToPdfFileModel,
+            ModelP22_2Input, ModelReferendumInput, PdfFileModel, ToPdfFileModel,
             apportionment_footnotes::ApportionmentFootnotes,
-            election_totals::ElectionTotalsCSB,
+            election_totals::{ElectionTotalsCSB, ElectionTotalsReferendum},
             enriched_candidate_nomination::EnrichedCandidateNomination,
             enriched_seat_assignment::EnrichedSeatAssignment,
             votes_table::{VotesTables, VotesTablesWithPreviousVotes},
@@ -29,7 +31,7 @@ use crate::{
         tabulation::ElectionTotals,
     },
     eml::EmlHash,
-    infra::audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType},
+    infra::audit_log::{self, AsAuditEvent, AuditEventLevel, AuditEventType},
     repository::{
         committee_session_repo::{self, get_previous_session},
         data_entry_repo::list_results_for_committee_session,
@@ -55,7 +57,7 @@ pub struct GsbGeneratedFiles {
     pub results_eml: GeneratedFile,
     pub results_pdf: GeneratedFile,
     pub overview_pdf: Option<GeneratedFile>,
-    pub results_csv: GeneratedFile,
+    pub results_csv: Option<GeneratedFile>,
 }
 
 pub struct GsbFiles {
@@ -76,22 +78,28 @@ impl GsbFiles {
             .expect("At least one file should be present")
     }
 
+    /// Whether any of the expected files is missing. The CSV is not expected
+    /// for referendums, because it only contains votes per list and candidate.
     pub fn needs_generation(
         &self,
         committee_session: &CommitteeSession,
+        election_category: ElectionCategory,
         corrections: bool,
     ) -> bool {
+        let csv_missing =
+            election_category != ElectionCategory::Referendum && self.results_csv.is_none();
+
         if committee_session.is_next_session() {
             if corrections {
                 self.results_eml.is_none()
                     || self.results_pdf.is_none()
                     || self.overview_pdf.is_none()
-                    || self.results_csv.is_none()
+                    || csv_missing
             } else {
                 self.overview_pdf.is_none()
             }
         } else {
-            self.results_eml.is_none() || self.results_pdf.is_none() || self.results_csv.is_none()
+            self.results_eml.is_none() || self.results_pdf.is_none() || csv_missing
         }
     }
 }
@@ -145,6 +153,18 @@ pub struct ResultsInputData {
     pub previous_totals: Option<ElectionTotals>,
     pub previous_committee_session: Option<CommitteeSession>,
     pub created_at: DateTime<Local>,
+    /// Head of the audit log hash chain, printed on the PDFs
+    pub audit_log_hash: String,
+}
+
+/// Format a hexadecimal hash in groups of four characters, like the EML hash
+fn format_hash(hash: &str) -> String {
+    hash.chars()
+        .collect::<Vec<_>>()
+        .chunks(4)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 impl ResultsInputData {
@@ -179,6 +199,7 @@ impl ResultsInputData {
         };
 
         let totals = ElectionTotals::tabulate(&election, &results)?;
+        let audit_log_hash = format_hash(&audit_log::chain_head(conn).await?);
 
         Ok(ResultsInputData {
             committee_session,
@@ -190,6 +211,7 @@ impl ResultsInputData {
             investigations,
             results,
             previous_committee_session,
+            audit_log_hash,
         })
     }
 
@@ -244,7 +266,9 @@ impl ResultsInputData {
         let filename = match file_type {
             GsbResultsEml => self.election_filename("Telling", "eml.xml"),
             GsbResultsPdf => {
-                if self.committee_session.is_next_session() {
+                if self.election.category == ElectionCategory::Referendum {
+                    "Proces-verbaal referendum.pdf".to_string()
+                } else if self.committee_session.is_next_session() {
                     "Model Na14-2.pdf".to_string()
                 } else {
                     "Model Na31-2.pdf".to_string()
@@ -366,6 +390,12 @@ impl ResultsInputCSB {
             EnrichedCandidateNomination::new(&data.election, &candidate_nomination)?;
         let footnotes =
             ApportionmentFootnotes::new(&data.election.political_groups, &seat_assignment.steps)?;
+        let explanations = apportionment_result
+            .seat_assignment
+            .explanations()?
+            .iter()
+            .map(Into::into)
+            .collect();
         let pdf_file: PdfFileModel = ModelP22_2Input {
             committee_session: data.committee_session.clone(),
             election: data.election.clone().into(),
@@ -373,8 +403,11 @@ impl ResultsInputCSB {
             footnotes,
             seat_assignment: enriched_seat_assignment,
             candidate_nomination: enriched_candidate_nomination,
+            explanations,
             hash,
+            audit_log_hash: data.audit_log_hash.clone(),
             creation_date_time,
+            language: data.election.model_language,
         }
         .to_pdf_file_model(filename);
         Ok(pdf_file)
@@ -393,7 +426,9 @@ impl ResultsInputCSB {
             election: data.election.clone().into(),
             votes_tables,
             hash,
+            audit_log_hash: data.audit_log_hash.clone(),
             creation_date_time,
+            language: data.election.model_language,
         }
         .to_pdf_file_model(filename);
         Ok(pdf_file)
@@ -429,10 +464,10 @@ impl ResultsInputGSB {
         let xml_string = xml.write_eml_root_str(true, true)?;
         let xml_bytes = xml_string.as_bytes();
         let xml_hash = EmlHash::from(xml_bytes).into();
-        let csv_string = xml.as_osv4_3_csv(&data.election, true, false)?;
+        let is_referendum = data.election.category == ElectionCategory::Referendum;
 
         let results_eml = data.generated_file(FileType::GsbResultsEml, xml_bytes.to_vec());
-        let results_csv = data.generated_file(FileType::GsbCsvCounts, csv_string.into_bytes());
+        let results_csv = self.get_results_csv_file(&xml)?;
 
         let overview_pdf = if data.committee_session.is_next_session() {
             let pdf_model = self.get_p2a_pdf_file(data.filename_for(FileType::GsbOverviewPdf));
@@ -443,7 +478,13 @@ impl ResultsInputGSB {
         };
 
         let results_pdf_file_type = FileType::GsbResultsPdf;
-        let results_pdf_model = if data.committee_session.is_next_session() {
+        let results_pdf_model = if is_referendum {
+            self.get_referendum_pdf_file(
+                xml_hash,
+                creation_date_time,
+                data.filename_for(results_pdf_file_type),
+            )?
+        } else if data.committee_session.is_next_session() {
             let Some(previous_totals) = &data.previous_totals else {
                 return Err(APIError::DataIntegrityError(
                 "Previous totals are required for generating results PDF for next committee sessions"
@@ -512,7 +553,9 @@ impl ResultsInputGSB {
             previous_summary: previous_totals.into(),
             previous_committee_session: previous_committee_session.clone(),
             hash,
+            audit_log_hash: data.audit_log_hash.clone(),
             creation_date_time,
+            language: data.election.model_language,
         }
         .to_pdf_file_model(results_pdf_filename);
         Ok(pdf_file)
@@ -534,7 +577,45 @@ impl ResultsInputGSB {
             polling_station_investigations: data.totals.cso_investigations()?.clone(),
             election: data.election.clone().into(),
             hash,
+            audit_log_hash: data.audit_log_hash.clone(),
+            creation_date_time,
+            language: data.election.model_language,
+        }
+        .to_pdf_file_model(results_pdf_filename);
+        Ok(pdf_file)
+    }
+
+    /// The CSV only supports votes per list and candidate, so it is not generated for referendums
+    fn get_results_csv_file(&self, xml: &ElectionCount) -> Result<Option<GeneratedFile>, APIError> {
+        let data = &self.data;
+        if data.election.category == ElectionCategory::Referendum {
+            return Ok(None);
+        }
+
+        let csv_string = xml.as_osv4_3_csv(&data.election, true, false)?;
+        Ok(Some(data.generated_file(
+            FileType::GsbCsvCounts,
+            csv_string.into_bytes(),
+        )))
+    }
+
+    fn get_referendum_pdf_file(
+        &self,
+        hash: String,
+        creation_date_time: String,
+        results_pdf_filename: String,
+    ) -> Result<PdfFileModel, APIError> {
+        let data = &self.data;
+
+        let pdf_file = ModelReferendumInput {
+            committee_session: data.committee_session.clone(),
+            election: data.election.clone().into(),
+            summary: ElectionTotalsReferendum::new(&data.totals)?,
+            polling_stations: data.polling_stations.clone(),
+            hash,
+            audit_log_hash: data.audit_log_hash.clone(),
             creation_date_time,
+            language: data.election.model_language,
         }
         .to_pdf_file_model(results_pdf_filename);
         Ok(pdf_file)
@@ -559,6 +640,7 @@ impl ResultsInputGSB {
                     (ps, inv.clone())
                 })
                 .collect(),
+            language: data.election.model_language,
         }
         .to_pdf_file_model(overview_filename)
     }
@@ -360,7 +360,7 @@ mod tests {
             voter_card_count: None,
             total_admitted_voters_count: 102,
         };
-        *second_entry.votes_counts_mut() = VotesCounts {
+        *second_entry.votes_counts_mut().unwrap() = VotesCounts {
             political_group_total_votes: vec![PoliticalGroupTotalVotes {
                 number: PGNumber::from(1),
                 total: 101,
@@ -441,7 +441,7 @@ mod tests {
             ],
         });
         let mut second_entry = first_entry.clone();
-        *second_entry.political_group_votes_mut() = vec![
+        *second_entry.political_group_votes_mut().unwrap() = vec![
             PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(1), &[50, 30]),
             PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(2), &[20]),
         ];
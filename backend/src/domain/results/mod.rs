@@ -12,11 +12,15 @@ use crate::domain::{
     committee_session::CommitteeSession,
     compare::Compare,
     election::{
-        CommitteeCategory, ElectionWithPoliticalGroups, PoliticalGroup, VoteCountingMethod,
+        CommitteeCategory, ElectionCategory, ElectionWithPoliticalGroups, PoliticalGroup,
+        VoteCountingMethod,
     },
     field_path::FieldPath,
     results::{
-        count::Count, dso_first_session_results::DSOFirstSessionResults, gsb_results::GSBResults,
+        count::Count,
+        dso_first_session_results::DSOFirstSessionResults,
+        gsb_results::GSBResults,
+        referendum_results::{ReferendumResults, ReferendumVotesCounts},
     },
     validate::{DataError, Validate, ValidateRoot, ValidationResults},
 };
@@ -36,6 +40,7 @@ pub mod gsb_results;
 pub mod next_session_results;
 pub mod political_group_candidate_votes;
 pub mod political_group_total_votes;
+pub mod referendum_results;
 pub mod voters_counts;
 pub mod votes_counts;
 pub mod yes_no;
@@ -64,6 +69,10 @@ pub enum Results {
     /// HSB/CSB enters GSB results
     /// This contains the data entry values from Model Na 31-2.
     GSB(GSBResults),
+
+    /// Results for referendums, any election committee session.
+    /// This contains the yes/no votes instead of the votes per list and candidate.
+    Referendum(ReferendumResults),
 }
 
 pub struct CommonDifferencesCounts<'a> {
@@ -88,6 +97,11 @@ impl Results {
             election.committee_category,
             committee_session.is_next_session(),
         ) {
+            // referendum results of a previous session can't be copied into the
+            // common polling station results, so every session starts empty
+            (CommitteeCategory::GSB, _) if election.category == ElectionCategory::Referendum => {
+                Results::Referendum(ReferendumResults::empty(election))
+            }
             (CommitteeCategory::GSB, false) => match election.counting_method {
                 Some(VoteCountingMethod::CSO) => {
                     Results::CSOFirstSession(CSOFirstSessionResults::empty(election))
@@ -140,6 +154,7 @@ impl Results {
             Results::DSONextSession(results) => &results.voters_counts,
             Results::CSONextSession(results) => &results.voters_counts,
             Results::GSB(results) => &results.voters_counts,
+            Results::Referendum(results) => &results.voters_counts,
         }
     }
 
@@ -152,29 +167,42 @@ impl Results {
             Results::DSONextSession(results) => &mut results.voters_counts,
             Results::CSONextSession(results) => &mut results.voters_counts,
             Results::GSB(results) => &mut results.voters_counts,
+            Results::Referendum(results) => &mut results.voters_counts,
         }
     }
 
     /// Common accessor for votes counts regardless of the underlying model.
-    pub fn votes_counts(&self) -> &VotesCounts {
+    /// Referendum results have no votes on lists and candidates, and return `None`.
+    pub fn votes_counts(&self) -> Option<&VotesCounts> {
         match self {
-            Results::DSOFirstSession(results) => &results.votes_counts,
-            Results::CSOFirstSession(results) => &results.votes_counts,
-            Results::DSONextSession(results) => &results.votes_counts,
-            Results::CSONextSession(results) => &results.votes_counts,
-            Results::GSB(results) => &results.votes_counts,
+            Results::DSOFirstSession(results) => Some(&results.votes_counts),
+            Results::CSOFirstSession(results) => Some(&results.votes_counts),
+            Results::DSONextSession(results) => Some(&results.votes_counts),
+            Results::CSONextSession(results) => Some(&results.votes_counts),
+            Results::GSB(results) => Some(&results.votes_counts),
+            Results::Referendum(_) => None,
+        }
+    }
+
+    /// Accessor for the yes/no votes counts, only available for referendum results.
+    pub fn referendum_votes_counts(&self) -> Option<&ReferendumVotesCounts> {
+        match self {
+            Results::Referendum(results) => Some(&results.votes_counts),
+            _ => None,
         }
     }
 
     /// Common mutable accessor for votes counts regardless of the underlying model.
+    /// Referendum results have no votes on lists and candidates, and return `None`.
     #[cfg(test)]
-    pub fn votes_counts_mut(&mut self) -> &mut VotesCounts {
+    pub fn votes_counts_mut(&mut self) -> Option<&mut VotesCounts> {
         match self {
-            Results::DSOFirstSession(results) => &mut results.votes_counts,
-            Results::CSOFirstSession(results) => &mut results.votes_counts,
-            Results::DSONextSession(results) => &mut results.votes_counts,
-            Results::CSONextSession(results) => &mut results.votes_counts,
-            Results::GSB(results) => &mut results.votes_counts,
+            Results::DSOFirstSession(results) => Some(&mut results.votes_counts),
+            Results::CSOFirstSession(results) => Some(&mut results.votes_counts),
+            Results::DSONextSession(results) => Some(&mut results.votes_counts),
+            Results::CSONextSession(results) => Some(&mut results.votes_counts),
+            Results::GSB(results) => Some(&mut results.votes_counts),
+            Results::Referendum(_) => None,
         }
     }
 
@@ -201,6 +229,10 @@ impl Results {
                 more_ballots_count: &results.differences_counts.more_ballots_count,
                 fewer_ballots_count: &results.differences_counts.fewer_ballots_count,
             },
+            Results::Referendum(results) => CommonDifferencesCounts {
+                more_ballots_count: &results.differences_counts.more_ballots_count,
+                fewer_ballots_count: &results.differences_counts.fewer_ballots_count,
+            },
         }
     }
 
@@ -228,6 +260,10 @@ impl Results {
                 more_ballots_count: &mut results.differences_counts.more_ballots_count,
                 fewer_ballots_count: &mut results.differences_counts.fewer_ballots_count,
             },
+            Results::Referendum(results) => CommonDifferenceCountsMut {
+                more_ballots_count: &mut results.differences_counts.more_ballots_count,
+                fewer_ballots_count: &mut results.differences_counts.fewer_ballots_count,
+            },
         }
     }
 
@@ -239,18 +275,21 @@ impl Results {
             Results::DSONextSession(results) => &results.political_group_votes,
             Results::CSONextSession(results) => &results.political_group_votes,
             Results::GSB(results) => &results.political_group_votes,
+            Results::Referendum(_) => &[],
         }
     }
 
     /// Common mutable accessor for political group votes regardless of the underlying model.
+    /// Referendum results have no votes on lists and candidates, and return `None`.
     #[cfg(test)]
-    pub fn political_group_votes_mut(&mut self) -> &mut Vec<PoliticalGroupCandidateVotes> {
+    pub fn political_group_votes_mut(&mut self) -> Option<&mut Vec<PoliticalGroupCandidateVotes>> {
         match self {
-            Results::DSOFirstSession(results) => &mut results.political_group_votes,
-            Results::CSOFirstSession(results) => &mut results.political_group_votes,
-            Results::DSONextSession(results) => &mut results.political_group_votes,
-            Results::CSONextSession(results) => &mut results.political_group_votes,
-            Results::GSB(results) => &mut results.political_group_votes,
+            Results::DSOFirstSession(results) => Some(&mut results.political_group_votes),
+            Results::CSOFirstSession(results) => Some(&mut results.political_group_votes),
+            Results::DSONextSession(results) => Some(&mut results.political_group_votes),
+            Results::CSONextSession(results) => Some(&mut results.political_group_votes),
+            Results::GSB(results) => Some(&mut results.political_group_votes),
+            Results::Referendum(_) => None,
         }
     }
 
@@ -263,6 +302,7 @@ impl Results {
                 | (Results::DSONextSession(_), Results::DSONextSession(_))
                 | (Results::CSONextSession(_), Results::CSONextSession(_))
                 | (Results::GSB(_), Results::GSB(_))
+                | (Results::Referendum(_), Results::Referendum(_))
         )
     }
 
@@ -439,6 +479,9 @@ impl Compare for Results {
                 s.compare(f, different_fields, path)
             }
             (Results::GSB(s), Results::GSB(f)) => s.compare(f, different_fields, path),
+            (Results::Referendum(s), Results::Referendum(f)) => {
+                s.compare(f, different_fields, path)
+            }
             _ => {
                 different_fields.push(path.to_string());
             }
@@ -472,6 +515,7 @@ impl Validate for Results {
             }
             Results::CSONextSession(results) => results.as_common().validate(election, path),
             Results::GSB(results) => results.validate(election, path),
+            Results::Referendum(results) => results.validate(election, path),
         }
     }
 }
@@ -544,7 +588,7 @@ pub mod tests {
             voters_counts.poll_card_count += extra_blank_votes;
             voters_counts.total_admitted_voters_count += extra_blank_votes;
 
-            let votes_counts = self.votes_counts_mut();
+            let votes_counts = self.votes_counts_mut().unwrap();
             votes_counts.blank_votes_count += extra_blank_votes;
             votes_counts.total_votes_cast_count += extra_blank_votes;
 
@@ -706,6 +750,21 @@ pub mod tests {
         Results::new(&election, &next_session, None);
     }
 
+    #[test]
+    fn test_results_new_referendum() {
+        let election = election_fixture(ElectionCategory::Referendum, CommitteeCategory::GSB, &[]);
+
+        for committee_session in [
+            CommitteeSession::first_session(),
+            CommitteeSession::next_session(),
+        ] {
+            let results = Results::new(&election, &committee_session, None);
+            assert_matches!(results, Results::Referendum(_));
+            assert_eq!(results.votes_counts(), None);
+            assert!(results.political_group_votes().is_empty());
+        }
+    }
+
     #[test]
     fn test_results_new_csb() {
         let first_session = CommitteeSession::first_session();
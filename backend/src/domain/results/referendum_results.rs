@@ -0,0 +1,433 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{
+    Results,
+    count::Count,
+    differences_counts::{DifferencesCounts, validate_differences_counts},
+    voters_counts::VotersCounts,
+    votes_counts::above_percentage_threshold,
+};
+use crate::domain::{
+    compare::Compare,
+    election::{CommitteeCategory, ElectionWithPoliticalGroups},
+    field_path::FieldPath,
+    results::common_validation::difference_admitted_voters_count_and_votes_cast_count_above_threshold,
+    validate::{DataError, Validate, ValidationResult, ValidationResultCode, ValidationResults},
+};
+
+/// ReferendumResults, the results of a polling station for a referendum.
+///
+/// A referendum has a single yes/no question instead of candidate lists, so
+/// the votes are counted per answer instead of per list and candidate. The
+/// voters counts and differences counts are the same as for other elections.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[serde(deny_unknown_fields)]
+pub struct ReferendumResults {
+    /// Voters counts ("Aantal toegelaten kiezers")
+    pub voters_counts: VotersCounts,
+    /// Votes counts ("Aantal getelde stembiljetten")
+    pub votes_counts: ReferendumVotesCounts,
+    /// Differences counts ("Verschil tussen het aantal toegelaten kiezers en het aantal getelde stembiljetten")
+    pub differences_counts: DifferencesCounts,
+}
+
+/// Votes counts for a referendum, part of the referendum results.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[serde(deny_unknown_fields)]
+pub struct ReferendumVotesCounts {
+    /// Number of votes in favour ("Stemmen voor")
+    #[schema(value_type = u32)]
+    pub yes_votes_count: Count,
+    /// Number of votes against ("Stemmen tegen")
+    #[schema(value_type = u32)]
+    pub no_votes_count: Count,
+    /// Number of blank votes ("Blanco stembiljetten")
+    #[schema(value_type = u32)]
+    pub blank_votes_count: Count,
+    /// Number of invalid votes ("Ongeldige stembiljetten")
+    #[schema(value_type = u32)]
+    pub invalid_votes_count: Count,
+    /// Total number of votes cast ("Totaal uitgebrachte stemmen")
+    #[schema(value_type = u32)]
+    pub total_votes_cast_count: Count,
+}
+
+impl ReferendumVotesCounts {
+    pub fn add(&mut self, other: &Self) {
+        self.yes_votes_count += other.yes_votes_count;
+        self.no_votes_count += other.no_votes_count;
+        self.blank_votes_count += other.blank_votes_count;
+        self.invalid_votes_count += other.invalid_votes_count;
+        self.total_votes_cast_count += other.total_votes_cast_count;
+    }
+
+    fn validate_votes_counts_errors(
+        &self,
+        validation_results: &mut ValidationResults,
+        path: &FieldPath,
+    ) {
+        // cast to u64 to avoid overflow
+        let sum = self.yes_votes_count as u64
+            + self.no_votes_count as u64
+            + self.blank_votes_count as u64
+            + self.invalid_votes_count as u64;
+
+        if sum != self.total_votes_cast_count as u64 {
+            validation_results.errors.push(ValidationResult {
+                fields: vec![
+                    path.field("yes_votes_count").to_string(),
+                    path.field("no_votes_count").to_string(),
+                    path.field("blank_votes_count").to_string(),
+                    path.field("invalid_votes_count").to_string(),
+                    path.field("total_votes_cast_count").to_string(),
+                ],
+                code: ValidationResultCode::F205,
+                context: None,
+            });
+        }
+    }
+
+    fn validate_votes_counts_warnings(
+        &self,
+        election: &ElectionWithPoliticalGroups,
+        validation_results: &mut ValidationResults,
+        path: &FieldPath,
+    ) {
+        if election.committee_category == CommitteeCategory::GSB {
+            if above_percentage_threshold(self.blank_votes_count, self.total_votes_cast_count, 3) {
+                validation_results.warnings.push(ValidationResult {
+                    fields: vec![path.field("blank_votes_count").to_string()],
+                    code: ValidationResultCode::W207,
+                    context: None,
+                });
+            }
+
+            if above_percentage_threshold(self.invalid_votes_count, self.total_votes_cast_count, 3)
+            {
+                validation_results.warnings.push(ValidationResult {
+                    fields: vec![path.field("invalid_votes_count").to_string()],
+                    code: ValidationResultCode::W208,
+                    context: None,
+                });
+            }
+        }
+
+        if self.total_votes_cast_count == 0 {
+            validation_results.warnings.push(ValidationResult {
+                fields: vec![path.field("total_votes_cast_count").to_string()],
+                code: ValidationResultCode::W209,
+                context: None,
+            });
+        }
+    }
+}
+
+impl Compare for ReferendumVotesCounts {
+    fn compare(&self, first_entry: &Self, different_fields: &mut Vec<String>, path: &FieldPath) {
+        self.yes_votes_count.compare(
+            &first_entry.yes_votes_count,
+            different_fields,
+            &path.field("yes_votes_count"),
+        );
+        self.no_votes_count.compare(
+            &first_entry.no_votes_count,
+            different_fields,
+            &path.field("no_votes_count"),
+        );
+        self.blank_votes_count.compare(
+            &first_entry.blank_votes_count,
+            different_fields,
+            &path.field("blank_votes_count"),
+        );
+        self.invalid_votes_count.compare(
+            &first_entry.invalid_votes_count,
+            different_fields,
+            &path.field("invalid_votes_count"),
+        );
+        self.total_votes_cast_count.compare(
+            &first_entry.total_votes_cast_count,
+            different_fields,
+            &path.field("total_votes_cast_count"),
+        );
+    }
+}
+
+impl Validate for ReferendumVotesCounts {
+    fn validate(
+        &self,
+        election: &ElectionWithPoliticalGroups,
+        path: &FieldPath,
+    ) -> Result<ValidationResults, DataError> {
+        let mut validation_results = ValidationResults::default();
+        // validate all counts
+        validation_results.join(
+            self.yes_votes_count
+                .validate(election, &path.field("yes_votes_count"))?,
+        );
+        validation_results.join(
+            self.no_votes_count
+                .validate(election, &path.field("no_votes_count"))?,
+        );
+        validation_results.join(
+            self.blank_votes_count
+                .validate(election, &path.field("blank_votes_count"))?,
+        );
+        validation_results.join(
+            self.invalid_votes_count
+                .validate(election, &path.field("invalid_votes_count"))?,
+        );
+        validation_results.join(
+            self.total_votes_cast_count
+                .validate(election, &path.field("total_votes_cast_count"))?,
+        );
+
+        self.validate_votes_counts_errors(&mut validation_results, path);
+
+        self.validate_votes_counts_warnings(election, &mut validation_results, path);
+
+        Ok(validation_results)
+    }
+}
+
+impl ReferendumResults {
+    pub fn empty(election: &ElectionWithPoliticalGroups) -> Self {
+        Self {
+            voters_counts: Results::default_voters_counts(election),
+            votes_counts: Default::default(),
+            differences_counts: Default::default(),
+        }
+    }
+}
+
+impl Compare for ReferendumResults {
+    fn compare(&self, first_entry: &Self, different_fields: &mut Vec<String>, path: &FieldPath) {
+        self.voters_counts.compare(
+            &first_entry.voters_counts,
+            different_fields,
+            &path.field("voters_counts"),
+        );
+
+        self.votes_counts.compare(
+            &first_entry.votes_counts,
+            different_fields,
+            &path.field("votes_counts"),
+        );
+
+        self.differences_counts.compare(
+            &first_entry.differences_counts,
+            different_fields,
+            &path.field("differences_counts"),
+        );
+    }
+}
+
+impl Validate for ReferendumResults {
+    fn validate(
+        &self,
+        election: &ElectionWithPoliticalGroups,
+        path: &FieldPath,
+    ) -> Result<ValidationResults, DataError> {
+        let votes_counts_path = path.field("votes_counts");
+        let voters_counts_path = path.field("voters_counts");
+        let differences_counts_path = path.field("differences_counts");
+
+        let total_voters_count = self.voters_counts.total_admitted_voters_count;
+        let total_votes_count = self.votes_counts.total_votes_cast_count;
+
+        let mut validation_results = self.votes_counts.validate(election, &votes_counts_path)?;
+        validation_results.join(self.voters_counts.validate(election, &voters_counts_path)?);
+
+        if difference_admitted_voters_count_and_votes_cast_count_above_threshold(
+            total_voters_count,
+            total_votes_count,
+        ) {
+            validation_results.warnings.push(ValidationResult {
+                fields: vec![
+                    voters_counts_path
+                        .field("total_admitted_voters_count")
+                        .to_string(),
+                    votes_counts_path
+                        .field("total_votes_cast_count")
+                        .to_string(),
+                ],
+                code: ValidationResultCode::W203,
+                context: None,
+            });
+        }
+
+        validation_results.join(
+            self.differences_counts
+                .validate(election, &differences_counts_path)?,
+        );
+
+        validate_differences_counts(
+            &self.differences_counts,
+            total_voters_count,
+            total_votes_count,
+            &mut validation_results,
+            &differences_counts_path,
+        )?;
+
+        Ok(validation_results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::domain::{
+        election::{ElectionCategory, tests::election_fixture},
+        valid_default::ValidDefault,
+    };
+
+    fn create_test_data() -> ReferendumResults {
+        ReferendumResults {
+            voters_counts: VotersCounts {
+                poll_card_count: 98,
+                proxy_certificate_count: 2,
+                voter_card_count: None,
+                total_admitted_voters_count: 100,
+            },
+            votes_counts: ReferendumVotesCounts {
+                yes_votes_count: 60,
+                no_votes_count: 38,
+                blank_votes_count: 1,
+                invalid_votes_count: 1,
+                total_votes_cast_count: 100,
+            },
+            differences_counts: DifferencesCounts::valid_default(),
+        }
+    }
+
+    fn validate(data: &ReferendumResults) -> Result<ValidationResults, DataError> {
+        data.validate(
+            &election_fixture(ElectionCategory::Referendum, CommitteeCategory::GSB, &[]),
+            &"data".into(),
+        )
+    }
+
+    #[test]
+    fn test_default() -> Result<(), DataError> {
+        let validation_results = validate(&create_test_data())?;
+        assert_eq!(validation_results.errors.len(), 0);
+        assert_eq!(validation_results.warnings.len(), 0);
+        Ok(())
+    }
+
+    /// GSB CSO, GSB DSO | F.205: 'Aantal kiezers en stemmen' (referendum): stemmen voor + stemmen tegen + blanco stemmen + ongeldige stemmen <> totaal aantal uitgebrachte stemmen
+    #[test]
+    fn test_f205() -> Result<(), DataError> {
+        let mut data = create_test_data();
+        data.votes_counts.yes_votes_count = 59;
+
+        let validation_results = validate(&data)?;
+        assert_eq!(
+            validation_results.errors,
+            [ValidationResult {
+                code: ValidationResultCode::F205,
+                fields: vec![
+                    "data.votes_counts.yes_votes_count".into(),
+                    "data.votes_counts.no_votes_count".into(),
+                    "data.votes_counts.blank_votes_count".into(),
+                    "data.votes_counts.invalid_votes_count".into(),
+                    "data.votes_counts.total_votes_cast_count".into(),
+                ],
+                context: None,
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_blank_and_invalid_warnings() -> Result<(), DataError> {
+        let mut data = create_test_data();
+        data.votes_counts.yes_votes_count = 51;
+        data.votes_counts.blank_votes_count = 6;
+        data.votes_counts.invalid_votes_count = 5;
+
+        let validation_results = validate(&data)?;
+        assert!(validation_results.errors.is_empty());
+        assert_eq!(
+            validation_results
+                .warnings
+                .iter()
+                .map(|w| w.code)
+                .collect::<Vec<_>>(),
+            [ValidationResultCode::W207, ValidationResultCode::W208]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_votes_cast_warning() -> Result<(), DataError> {
+        let mut data = create_test_data();
+        data.voters_counts.poll_card_count = 0;
+        data.voters_counts.proxy_certificate_count = 0;
+        data.voters_counts.total_admitted_voters_count = 0;
+        data.votes_counts = ReferendumVotesCounts::default();
+
+        let validation_results = validate(&data)?;
+        assert!(validation_results.errors.is_empty());
+        assert_eq!(
+            validation_results.warnings,
+            [ValidationResult {
+                code: ValidationResultCode::W209,
+                fields: vec!["data.votes_counts.total_votes_cast_count".into()],
+                context: None,
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_differences_counts_are_validated() -> Result<(), DataError> {
+        let mut data = create_test_data();
+        data.voters_counts.poll_card_count = 88;
+        data.voters_counts.total_admitted_voters_count = 90;
+
+        let validation_results = validate(&data)?;
+        assert!(
+            validation_results
+                .errors
+                .iter()
+                .any(|e| e.code == ValidationResultCode::F301)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() {
+        let first_entry = create_test_data();
+        let mut second_entry = first_entry.clone();
+        let mut different_fields = vec![];
+        second_entry.compare(&first_entry, &mut different_fields, &"data".into());
+        assert!(different_fields.is_empty());
+
+        second_entry.votes_counts.yes_votes_count = 61;
+        second_entry.votes_counts.no_votes_count = 37;
+        second_entry.compare(&first_entry, &mut different_fields, &"data".into());
+        assert_eq!(
+            different_fields,
+            [
+                "data.votes_counts.yes_votes_count",
+                "data.votes_counts.no_votes_count"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_votes_addition() {
+        let mut totals = ReferendumVotesCounts::default();
+        totals.add(&create_test_data().votes_counts);
+        totals.add(&create_test_data().votes_counts);
+
+        assert_eq!(totals.yes_votes_count, 120);
+        assert_eq!(totals.no_votes_count, 76);
+        assert_eq!(totals.blank_votes_count, 2);
+        assert_eq!(totals.invalid_votes_count, 2);
+        assert_eq!(totals.total_votes_cast_count, 200);
+    }
+}
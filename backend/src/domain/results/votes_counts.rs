@@ -247,7 +247,7 @@ impl VotesCounts {
 /// using only integers to avoid floating point arithmetic issues.
 /// The threshold is calculated as the percentage of the total, rounded up.
 /// For example, if the total is 101 and the percentage is 10, the threshold is 11.
-fn above_percentage_threshold(value: u32, total: u32, percentage: u8) -> bool {
+pub fn above_percentage_threshold(value: u32, total: u32, percentage: u8) -> bool {
     if value == 0 && total == 0 {
         false
     } else {
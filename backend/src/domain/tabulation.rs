@@ -5,7 +5,9 @@ use crate::{
     APIError,
     domain::{
         data_entry::{DataEntrySource, DataEntrySourceNumber},
-        election::{CommitteeCategory, ElectionWithPoliticalGroups, VoteCountingMethod},
+        election::{
+            CommitteeCategory, ElectionCategory, ElectionWithPoliticalGroups, VoteCountingMethod,
+        },
         polling_station::PollingStationForSession,
         results::{
             CommonDifferencesCounts, Results,
@@ -14,6 +16,7 @@ use crate::{
             dso_first_session_results::DSOFirstSessionResults,
             political_group_candidate_votes::{CandidateVotes, PoliticalGroupCandidateVotes},
             political_group_total_votes::PoliticalGroupTotalVotes,
+            referendum_results::ReferendumVotesCounts,
             voters_counts::VotersCounts,
             votes_counts::VotesCounts,
         },
@@ -34,6 +37,10 @@ pub struct ElectionTotals {
     pub differences_counts: DifferencesTotals,
     /// The total votes for each political group (and each candidate within)
     pub political_group_votes: Vec<PoliticalGroupCandidateVotes>,
+    /// The total yes/no votes, only for referendums
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub referendum_votes_counts: Option<ReferendumVotesCounts>,
     /// Totals specific to the committee (GSB or CSB) of the election
     pub committee_specific: CommitteeSpecificTotals,
 }
@@ -57,6 +64,8 @@ impl ElectionTotals {
             },
             differences_counts: DifferencesTotals::zero(),
             political_group_votes: vec![],
+            referendum_votes_counts: (election.category == ElectionCategory::Referendum)
+                .then(ReferendumVotesCounts::default),
             committee_specific: CommitteeSpecificTotals::zero(election),
         }
     }
@@ -94,7 +103,26 @@ impl ElectionTotals {
 
             // add voters and votes to the total
             totals.voters_counts += result.voters_counts();
-            totals.votes_counts.add(result.votes_counts())?;
+            if let Some(votes_counts) = result.votes_counts() {
+                totals.votes_counts.add(votes_counts)?;
+            }
+
+            // add yes/no votes of referendums to the total
+            match (result, totals.referendum_votes_counts.as_mut()) {
+                (Results::Referendum(referendum_result), Some(referendum_totals)) => {
+                    referendum_totals.add(&referendum_result.votes_counts)
+                }
+                (Results::Referendum(_), None) | (_, Some(_)) => {
+                    return Err(APIError::AddError(
+                        format!(
+                            "Result model of data entry source {} does not match the election",
+                            data_source.number()
+                        ),
+                        ErrorReference::InvalidData,
+                    ));
+                }
+                (_, None) => {}
+            }
 
             // add any differences noted to the total
             totals
@@ -253,6 +281,8 @@ impl CommitteeSpecificTotals {
             // next session results contain no investigation status information
             (CommitteeSpecificTotals::GSB(GSBTotals::CSO(_)), Results::CSONextSession(_))
             | (CommitteeSpecificTotals::GSB(GSBTotals::DSO(_)), Results::DSONextSession(_)) => {}
+            // referendum results contain no investigation status information
+            (CommitteeSpecificTotals::GSB(_), Results::Referendum(_)) => {}
             // GSB results contain number of voters which need to be added
             (CommitteeSpecificTotals::CSB { number_of_voters }, Results::GSB(gsb_result)) => {
                 *number_of_voters += gsb_result.number_of_voters;
@@ -456,6 +486,8 @@ mod tests {
             gsb_differences_counts::GSBDifferencesCounts,
             gsb_results::GSBResults,
             next_session_results::NextSessionResults,
+            referendum_results::{ReferendumResults, ReferendumVotesCounts},
+            voters_counts::VotersCounts,
             yes_no::YesNo,
         },
         sub_committee::{SubCommittee, SubCommitteeFirstSession, SubCommitteeId},
@@ -781,19 +813,33 @@ mod tests {
         let election = election_fixture(ElectionCategory::Municipal, GSB, &[2, 3]);
         let ps = polling_stations_fixture(&[20; 5]);
         let mut ps_results = results_fixture_a();
-        ps_results.political_group_votes_mut()[0].total = 999_999_998;
-        ps_results.political_group_votes_mut()[0].candidate_votes[0].votes = 999_999_998;
-        ps_results.political_group_votes_mut()[0].candidate_votes[1].votes = 0;
-        ps_results.political_group_votes_mut()[1].total = 0;
-        ps_results.political_group_votes_mut()[1].candidate_votes[0].votes = 0;
-        ps_results.political_group_votes_mut()[1].candidate_votes[1].votes = 0;
-        ps_results.political_group_votes_mut()[1].candidate_votes[2].votes = 0;
-        ps_results.votes_counts_mut().political_group_total_votes[0].total = 999_999_998;
-        ps_results.votes_counts_mut().political_group_total_votes[1].total = 0;
-        ps_results.votes_counts_mut().total_votes_cast_count = 999_999_998;
-        ps_results.votes_counts_mut().total_votes_candidates_count = 999_999_998;
-        ps_results.votes_counts_mut().blank_votes_count = 0;
-        ps_results.votes_counts_mut().invalid_votes_count = 0;
+        ps_results.political_group_votes_mut().unwrap()[0].total = 999_999_998;
+        ps_results.political_group_votes_mut().unwrap()[0].candidate_votes[0].votes = 999_999_998;
+        ps_results.political_group_votes_mut().unwrap()[0].candidate_votes[1].votes = 0;
+        ps_results.political_group_votes_mut().unwrap()[1].total = 0;
+        ps_results.political_group_votes_mut().unwrap()[1].candidate_votes[0].votes = 0;
+        ps_results.political_group_votes_mut().unwrap()[1].candidate_votes[1].votes = 0;
+        ps_results.political_group_votes_mut().unwrap()[1].candidate_votes[2].votes = 0;
+        ps_results
+            .votes_counts_mut()
+            .unwrap()
+            .political_group_total_votes[0]
+            .total = 999_999_998;
+        ps_results
+            .votes_counts_mut()
+            .unwrap()
+            .political_group_total_votes[1]
+            .total = 0;
+        ps_results
+            .votes_counts_mut()
+            .unwrap()
+            .total_votes_cast_count = 999_999_998;
+        ps_results
+            .votes_counts_mut()
+            .unwrap()
+            .total_votes_candidates_count = 999_999_998;
+        ps_results.votes_counts_mut().unwrap().blank_votes_count = 0;
+        ps_results.votes_counts_mut().unwrap().invalid_votes_count = 0;
         ps_results.voters_counts_mut().poll_card_count = 999_999_998;
         ps_results.voters_counts_mut().proxy_certificate_count = 0;
         ps_results.voters_counts_mut().total_admitted_voters_count = 999_999_998;
@@ -822,7 +868,10 @@ mod tests {
         let ps = polling_stations_fixture(&[20, 20]);
         let ps_results = results_fixture_a();
         let mut ps_results2 = ps_results.clone();
-        ps_results2.votes_counts_mut().total_votes_cast_count = 0;
+        ps_results2
+            .votes_counts_mut()
+            .unwrap()
+            .total_votes_cast_count = 0;
 
         let totals = ElectionTotals::tabulate(
             &election,
@@ -859,6 +908,7 @@ mod tests {
         let mut ps2_result = results_fixture_b();
         ps2_result
             .votes_counts_mut()
+            .unwrap()
             .political_group_total_votes
             .pop();
         let totals = ElectionTotals::tabulate(
@@ -880,6 +930,7 @@ mod tests {
         let mut ps2_result = results_fixture_b();
         ps2_result
             .votes_counts_mut()
+            .unwrap()
             .political_group_total_votes
             .push(PoliticalGroupTotalVotes {
                 number: PGNumber::from(3),
@@ -902,9 +953,14 @@ mod tests {
         let ps = polling_stations_fixture(&[20, 20]);
         let ps1_result = results_fixture_a();
         let mut ps2_result = results_fixture_b();
-        let pgvote_copy = ps2_result.votes_counts().political_group_total_votes[1].clone();
+        let pgvote_copy = ps2_result
+            .votes_counts()
+            .unwrap()
+            .political_group_total_votes[1]
+            .clone();
         ps2_result
             .votes_counts_mut()
+            .unwrap()
             .political_group_total_votes
             .push(pgvote_copy);
         let totals = ElectionTotals::tabulate(
@@ -924,7 +980,10 @@ mod tests {
         let ps = polling_stations_fixture(&[20, 20]);
         let ps1_result = results_fixture_a();
         let mut ps2_result = results_fixture_b();
-        ps2_result.votes_counts_mut().political_group_total_votes[1] = PoliticalGroupTotalVotes {
+        ps2_result
+            .votes_counts_mut()
+            .unwrap()
+            .political_group_total_votes[1] = PoliticalGroupTotalVotes {
             number: PGNumber::from(3),
             total: 0,
         };
@@ -945,7 +1004,7 @@ mod tests {
         let ps = polling_stations_fixture(&[20, 20]);
         let ps1_result = results_fixture_a();
         let mut ps2_result = results_fixture_b();
-        ps2_result.political_group_votes_mut().pop();
+        ps2_result.political_group_votes_mut().unwrap().pop();
         let totals = ElectionTotals::tabulate(
             &election,
             &[
@@ -963,7 +1022,7 @@ mod tests {
         let ps = polling_stations_fixture(&[20, 20]);
         let ps1_result = results_fixture_a();
         let mut ps2_result = results_fixture_b();
-        ps2_result.political_group_votes_mut().push(
+        ps2_result.political_group_votes_mut().unwrap().push(
             PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(3), &[0]),
         );
         let totals = ElectionTotals::tabulate(
@@ -985,8 +1044,14 @@ mod tests {
         let ps1_pgvote_copy = ps1_result.political_group_votes()[1].clone();
         let mut ps2_result = results_fixture_b();
         let ps2_pgvote_copy = ps2_result.political_group_votes()[1].clone();
-        ps1_result.political_group_votes_mut().push(ps1_pgvote_copy);
-        ps2_result.political_group_votes_mut().push(ps2_pgvote_copy);
+        ps1_result
+            .political_group_votes_mut()
+            .unwrap()
+            .push(ps1_pgvote_copy);
+        ps2_result
+            .political_group_votes_mut()
+            .unwrap()
+            .push(ps2_pgvote_copy);
         let totals = ElectionTotals::tabulate(
             &election,
             &[
@@ -1004,7 +1069,7 @@ mod tests {
         let ps = polling_stations_fixture(&[20, 20]);
         let ps1_result = results_fixture_a();
         let mut ps2_result = results_fixture_b();
-        ps2_result.political_group_votes_mut()[1] =
+        ps2_result.political_group_votes_mut().unwrap()[1] =
             PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(3), &[0]);
         let totals = ElectionTotals::tabulate(
             &election,
@@ -1023,7 +1088,7 @@ mod tests {
         let ps = polling_stations_fixture(&[20, 20]);
         let ps1_result = results_fixture_a();
         let mut ps2_result = results_fixture_b();
-        ps2_result.political_group_votes_mut()[1]
+        ps2_result.political_group_votes_mut().unwrap()[1]
             .candidate_votes
             .pop();
         let totals = ElectionTotals::tabulate(
@@ -1259,6 +1324,76 @@ mod tests {
         assert!(totals.is_err());
     }
 
+    fn referendum_results_fixture(yes: u32, no: u32) -> Results {
+        Results::Referendum(ReferendumResults {
+            voters_counts: VotersCounts {
+                poll_card_count: yes + no + 2,
+                proxy_certificate_count: 0,
+                voter_card_count: None,
+                total_admitted_voters_count: yes + no + 2,
+            },
+            votes_counts: ReferendumVotesCounts {
+                yes_votes_count: yes,
+                no_votes_count: no,
+                blank_votes_count: 1,
+                invalid_votes_count: 1,
+                total_votes_cast_count: yes + no + 2,
+            },
+            differences_counts: DifferencesCounts::valid_default(),
+        })
+    }
+
+    #[test]
+    fn test_referendum_totals() {
+        let election = election_fixture(ElectionCategory::Referendum, GSB, &[]);
+        let ps = polling_stations_fixture(&[20, 30]);
+        let totals = ElectionTotals::tabulate(
+            &election,
+            &[
+                (
+                    test_ps_to_source(ps[0].clone()),
+                    referendum_results_fixture(10, 5),
+                ),
+                (
+                    test_ps_to_source(ps[1].clone()),
+                    referendum_results_fixture(7, 12),
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(totals.voters_counts.total_admitted_voters_count, 38);
+        assert_eq!(
+            totals.referendum_votes_counts,
+            Some(ReferendumVotesCounts {
+                yes_votes_count: 17,
+                no_votes_count: 17,
+                blank_votes_count: 2,
+                invalid_votes_count: 2,
+                total_votes_cast_count: 38,
+            })
+        );
+        assert_eq!(totals.votes_counts, VotesCounts::default());
+        assert!(totals.political_group_votes.is_empty());
+    }
+
+    #[test]
+    fn test_referendum_result_must_match_election() {
+        let ps = polling_stations_fixture(&[20]);
+        let source = || test_ps_to_source(ps[0].clone());
+
+        // a referendum result in a municipal election
+        let election = election_fixture(ElectionCategory::Municipal, GSB, &[2, 3]);
+        let totals =
+            ElectionTotals::tabulate(&election, &[(source(), referendum_results_fixture(1, 2))]);
+        assert!(totals.is_err());
+
+        // a CSO result in a referendum
+        let election = election_fixture(ElectionCategory::Referendum, GSB, &[]);
+        let totals = ElectionTotals::tabulate(&election, &[(source(), results_fixture_a())]);
+        assert!(totals.is_err());
+    }
+
     #[test]
     fn test_cso_investigations_requires_cso_totals() {
         let cso_election = election_fixture(ElectionCategory::Municipal, GSB, &[2, 3]);
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    committee_session::CommitteeSessionId,
+    election::{CandidateNumber, PGNumber},
+    identifier::id,
+};
+
+id!(VacancyId);
+
+/// Reason why the seat of an elected candidate became vacant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum VacancyReason {
+    /// The seat holder resigned
+    Resigned,
+    /// The seat holder is deceased
+    Deceased,
+}
+
+/// Reason why a candidate could not be designated as successor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SuccessorSkipReason {
+    /// The candidate currently holds a seat
+    SeatHolder,
+    /// The candidate held a seat that was vacated before
+    Vacated,
+    /// The candidate declined the appointment
+    Declined,
+    /// The candidate is deceased
+    Deceased,
+    /// The candidate was struck from the list because they are ineligible
+    Ineligible,
+}
+
+impl From<apportionment::SuccessorSkipReason> for SuccessorSkipReason {
+    fn from(reason: apportionment::SuccessorSkipReason) -> Self {
+        match reason {
+            apportionment::SuccessorSkipReason::SeatHolder => Self::SeatHolder,
+            apportionment::SuccessorSkipReason::Vacated => Self::Vacated,
+            apportionment::SuccessorSkipReason::Declined => Self::Declined,
+            apportionment::SuccessorSkipReason::Deceased => Self::Deceased,
+            apportionment::SuccessorSkipReason::Ineligible => Self::Ineligible,
+        }
+    }
+}
+
+/// Candidate that ranks before the successor, but could not be designated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SkippedCandidate {
+    pub candidate_number: CandidateNumber,
+    pub reason: SuccessorSkipReason,
+}
+
+impl From<apportionment::SkippedCandidate<CandidateNumber>> for SkippedCandidate {
+    fn from(skipped: apportionment::SkippedCandidate<CandidateNumber>) -> Self {
+        Self {
+            candidate_number: skipped.candidate_number,
+            reason: skipped.reason.into(),
+        }
+    }
+}
+
+/// Vacancy of a seat after the result of the election was determined,
+/// with the successor that was designated for it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Vacancy {
+    pub id: VacancyId,
+    pub committee_session_id: CommitteeSessionId,
+    pub pg_number: PGNumber,
+    pub candidate_number: CandidateNumber,
+    pub reason: VacancyReason,
+    /// Candidate designated as successor, absent if no candidate on the list is eligible
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub successor_candidate_number: Option<CandidateNumber>,
+    pub skipped_candidates: Vec<SkippedCandidate>,
+    #[schema(value_type = String)]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request to register a vacancy for an elected candidate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VacancyRequest {
+    pub pg_number: PGNumber,
+    pub candidate_number: CandidateNumber,
+    pub reason: VacancyReason,
+    /// Candidates that declined the appointment for this vacancy
+    #[serde(default)]
+    pub declined_candidates: Vec<CandidateNumber>,
+}
+
+/// List of vacancies of an election
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VacancyListResponse {
+    pub vacancies: Vec<Vacancy>,
+}
@@ -81,6 +81,8 @@ pub enum ValidationResultCode {
     F203,
     /// GSB CSO, GSB DSO, CSB: 'Aantal kiezers en stemmen': De som van lijsttotalen (E.1 t/m E.n) is groter dan 0 en E = leeg of 0
     F204,
+    /// GSB CSO, GSB DSO: 'Aantal kiezers en stemmen' (referendum): stemmen voor + stemmen tegen + blanco stemmen + ongeldige stemmen <> totaal aantal uitgebrachte stemmen
+    F205,
 
     /// GSB CSO, GSB DSO: "Vergelijk D&H": (Als checkbox D=H is aangevinkt) D<>H
     F301,
@@ -129,6 +131,12 @@ pub enum ValidationResultCode {
     W205,
     /// CSB: 'Aantal kiezers en stemmen': (Als W.205 niet getoond wordt) Aantal kiesgerechtigden is kleiner dan aantal stempassen
     W206,
+    /// GSB CSO, GSB DSO: 'Aantal kiezers en stemmen' (referendum): Aantal blanco stemmen is groter dan of gelijk aan 3% van het totaal aantal uitgebrachte stemmen
+    W207,
+    /// GSB CSO, GSB DSO: 'Aantal kiezers en stemmen' (referendum): Aantal ongeldige stemmen is groter dan of gelijk aan 3% van het totaal aantal uitgebrachte stemmen
+    W208,
+    /// GSB CSO, GSB DSO: 'Aantal kiezers en stemmen' (referendum): Totaal aantal uitgebrachte stemmen (voor, tegen, blanco en ongeldig) leeg of 0
+    W209,
 }
 
 #[derive(Debug, Eq, PartialEq)]
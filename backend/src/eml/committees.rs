@@ -20,8 +20,17 @@ mod expected {
     /// for each different category of election. This maps those two together.
     pub const CSB_REGION_CATEGORY: &[(ElectionCategory, RegionCategory)] = &[
         (ElectionCategory::GR, RegionCategory::Municipality),
+        // local referendums are held within a single municipality
+        (ElectionCategory::LR, RegionCategory::Municipality),
+        // public bodies are registered as municipalities in the election tree
+        (ElectionCategory::ER, RegionCategory::Municipality),
+        (ElectionCategory::IR, RegionCategory::Municipality),
         (ElectionCategory::PS, RegionCategory::Province),
         (ElectionCategory::AB, RegionCategory::WaterAuthority),
+        (ElectionCategory::TK, RegionCategory::State),
+        (ElectionCategory::EP, RegionCategory::State),
+        (ElectionCategory::NR, RegionCategory::State),
+        (ElectionCategory::EK, RegionCategory::State),
     ];
 }
 
@@ -326,8 +335,14 @@ impl TryFrom<&ElectionDefinition> for ElectionTreeDetails {
         }
 
         let (committees, has_districts) = match (election_category, election_sub_category) {
-            // election within a municipality
-            (ElectionCategory::GR, _) => (
+            // election or referendum within a municipality or public body
+            (
+                ElectionCategory::GR
+                | ElectionCategory::LR
+                | ElectionCategory::ER
+                | ElectionCategory::IR,
+                _,
+            ) => (
                 vec![CommitteeDetails::new(
                     root_region.region(),
                     root_region.region(),
@@ -342,8 +357,19 @@ impl TryFrom<&ElectionDefinition> for ElectionTreeDetails {
                 (committees_single_electoral_district(root_region)?, false)
             }
 
+            // country-wide elections with HSBs, but with a single national district
+            (ElectionCategory::EP, _) => (committees_national_single_district(root_region)?, false),
+
+            // country-wide election without districts, the votes are counted
+            // by the municipalities directly below the state
+            (ElectionCategory::NR, _) => (committees_national_municipalities(root_region)?, false),
+
+            // the members of the provincial councils and electoral colleges
+            // vote in their own polling station, which are the lowest regions
+            (ElectionCategory::EK, _) => (committees_polling_stations(root_region)?, false),
+
             // Elections with HSBs
-            (ElectionCategory::PS, ElectionSubcategory::PS2) => {
+            (ElectionCategory::PS, ElectionSubcategory::PS2) | (ElectionCategory::TK, _) => {
                 (committees_multiple_electoral_districts(root_region)?, true)
             }
 
@@ -388,6 +414,83 @@ fn committees_single_electoral_district(
     Ok(committees)
 }
 
+fn committees_national_single_district(
+    root_region: &RegionNode,
+) -> Result<Vec<CommitteeDetails>, EMLError> {
+    let mut committees = vec![];
+
+    // the electoral districts only determine the HSBs, all GSBs share the
+    // national candidate lists
+    for hsb_region in root_region.children() {
+        if hsb_region.key.category != eml_nl::utils::RegionCategory::ElectoralDistrict {
+            return Err(EMLError::custom("Expected to find an electoral district"));
+        }
+
+        for gsb_region in hsb_region.children() {
+            if gsb_region.key.category != eml_nl::utils::RegionCategory::Municipality {
+                return Err(EMLError::custom("GSB on a non-municipal region"));
+            }
+            committees.push(CommitteeDetails::new(
+                gsb_region.region(),
+                gsb_region.region(),
+                CommitteeDistrict::None,
+                CommitteeCategory::GSB,
+                None,
+            )?);
+        }
+    }
+
+    Ok(committees)
+}
+
+fn committees_national_municipalities(
+    root_region: &RegionNode,
+) -> Result<Vec<CommitteeDetails>, EMLError> {
+    let mut committees = vec![];
+    for gsb_region in root_region.children() {
+        if gsb_region.key.category != eml_nl::utils::RegionCategory::Municipality {
+            return Err(EMLError::custom("GSB on a non-municipal region"));
+        }
+        committees.push(CommitteeDetails::new(
+            gsb_region.region(),
+            gsb_region.region(),
+            CommitteeDistrict::None,
+            CommitteeCategory::GSB,
+            None,
+        )?);
+    }
+
+    Ok(committees)
+}
+
+fn committees_polling_stations(
+    root_region: &RegionNode,
+) -> Result<Vec<CommitteeDetails>, EMLError> {
+    // polling stations may be placed directly below the state or below the
+    // province they belong to, so we search the whole tree for them
+    let committees = root_region
+        .iter()
+        .filter(|node| node.key.category == eml_nl::utils::RegionCategory::PollingStation)
+        .map(|node| {
+            CommitteeDetails::new(
+                node.region(),
+                node.region(),
+                CommitteeDistrict::None,
+                CommitteeCategory::GSB,
+                None,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if committees.is_empty() {
+        return Err(EMLError::custom(
+            "Expected to find at least one polling station",
+        ));
+    }
+
+    Ok(committees)
+}
+
 fn committees_multiple_electoral_districts(
     root_region: &RegionNode,
 ) -> Result<Vec<CommitteeDetails>, EMLError> {
@@ -583,6 +686,82 @@ mod tests {
         assert_eq!(gsb.district, CommitteeDistrict::None);
     }
 
+    #[test]
+    fn test_island_election_tree() {
+        for definition_str in [
+            include_str!("tests/definitions/Verkiezingsdefinitie_ER2027_Bonaire.eml.xml"),
+            include_str!("tests/definitions/Verkiezingsdefinitie_IR2027_Bonaire.eml.xml"),
+        ] {
+            let definition =
+                ElectionDefinition::parse_eml(definition_str, EMLParsingMode::Strict).unwrap();
+            let details = ElectionTreeDetails::from_definition(&definition).unwrap();
+
+            let csb = details
+                .get_committee(CommitteeCategory::CSB, None)
+                .expect("Missing CSB");
+            assert_eq!(csb.responsible_region.name, "Bonaire");
+            assert_eq!(csb.managing_authority_id, "CSB");
+            assert_eq!(csb.district, CommitteeDistrict::None);
+
+            assert_eq!(details.get_committees(CommitteeCategory::GSB).len(), 1);
+            let gsb = details
+                .get_committee(CommitteeCategory::GSB, None)
+                .expect("Missing GSB");
+            assert_eq!(gsb.responsible_region.name, "Bonaire");
+            assert_eq!(gsb.managing_authority_id, "9001");
+        }
+    }
+
+    #[test]
+    fn test_non_resident_electoral_college_election_tree() {
+        let definition_str = include_str!("tests/definitions/Verkiezingsdefinitie_NR2027.eml.xml");
+        let definition =
+            ElectionDefinition::parse_eml(definition_str, EMLParsingMode::Strict).unwrap();
+        let details = ElectionTreeDetails::from_definition(&definition).unwrap();
+
+        assert_eq!(details.csb.responsible_region.name, "Nederland");
+        assert_eq!(details.csb.managing_authority_name(), "Kiesraad");
+        assert_eq!(details.csb.district, CommitteeDistrict::None);
+
+        assert_eq!(details.get_committees(CommitteeCategory::GSB).len(), 1);
+        let gsb = details
+            .get_committee(CommitteeCategory::GSB, None)
+            .expect("Missing GSB");
+        assert_eq!(gsb.responsible_region.name, "'s-Gravenhage");
+        assert_eq!(gsb.managing_authority_id, "0518");
+        assert_eq!(gsb.district, CommitteeDistrict::None);
+    }
+
+    #[test]
+    fn test_senate_election_tree() {
+        let definition_str = include_str!("tests/definitions/Verkiezingsdefinitie_EK2027.eml.xml");
+        let definition =
+            ElectionDefinition::parse_eml(definition_str, EMLParsingMode::Strict).unwrap();
+        let details = ElectionTreeDetails::from_definition(&definition).unwrap();
+
+        assert_eq!(details.csb.responsible_region.name, "Nederland");
+        assert_eq!(details.csb.managing_authority_name(), "Kiesraad");
+        assert_eq!(details.csb.district, CommitteeDistrict::None);
+
+        // polling stations both below a province and directly below the state
+        let gsbs = details.get_committees(CommitteeCategory::GSB);
+        assert_eq!(gsbs.len(), 5);
+        assert!(gsbs.iter().all(|c| c.district == CommitteeDistrict::None
+            && c.responsible_region.key.category == RegionCategory::PollingStation));
+
+        let gsb = details
+            .get_committee(
+                CommitteeCategory::GSB,
+                Some(RegionKey {
+                    category: RegionCategory::PollingStation,
+                    number: Some(16),
+                }),
+            )
+            .expect("Missing polling station");
+        assert_eq!(gsb.responsible_region.name, "Kiescollege niet-ingezetenen");
+        assert_eq!(gsb.managing_authority_id, "0016");
+    }
+
     #[test]
     fn test_provincial_council_election_tree_single_district() {
         let definition_str =
@@ -701,4 +880,92 @@ mod tests {
         // );
         assert_eq!(details.get_committees(CommitteeCategory::GSB).len(), 31);
     }
+
+    #[test]
+    fn test_national_parliament_election_tree() {
+        let definition_str = include_str!("tests/definitions/Verkiezingsdefinitie_TK2025.eml.xml");
+        let definition =
+            ElectionDefinition::parse_eml(definition_str, EMLParsingMode::Strict).unwrap();
+        let details = ElectionTreeDetails::from_definition(&definition).unwrap();
+
+        assert_eq!(details.csb.responsible_region.name, "Nederland");
+        assert_eq!(details.csb.managing_authority_id, "CSB");
+        assert_eq!(details.csb.managing_authority_name(), "Kiesraad");
+        assert_eq!(details.csb.location(), "Nederland");
+        assert_eq!(details.csb.district, CommitteeDistrict::All);
+
+        assert_eq!(details.get_committees(CommitteeCategory::GSB).len(), 23);
+
+        let gsb_stadskanaal = details
+            .get_committee(
+                CommitteeCategory::GSB,
+                Some(RegionKey {
+                    category: RegionCategory::Municipality,
+                    number: Some(37),
+                }),
+            )
+            .expect("Missing GSB");
+        assert_eq!(gsb_stadskanaal.managing_authority_id, "0037");
+        assert_eq!(gsb_stadskanaal.managing_authority_name(), "Stadskanaal");
+        assert_eq!(
+            gsb_stadskanaal.district.region_details().unwrap().name,
+            "Groningen"
+        );
+        assert_eq!(
+            gsb_stadskanaal
+                .district
+                .as_contest_identifier()
+                .unwrap()
+                .id
+                .raw()
+                .to_string(),
+            "1"
+        );
+
+        let gsb_saba = details
+            .get_committee(
+                CommitteeCategory::GSB,
+                Some(RegionKey {
+                    category: RegionCategory::Municipality,
+                    number: Some(9003),
+                }),
+            )
+            .expect("Missing GSB");
+        assert_eq!(gsb_saba.managing_authority_id, "9003");
+        assert_eq!(gsb_saba.district.region_details().unwrap().name, "Bonaire");
+    }
+
+    #[test]
+    fn test_european_parliament_election_tree() {
+        let definition_str = include_str!("tests/definitions/Verkiezingsdefinitie_EP2024.eml.xml");
+        let definition =
+            ElectionDefinition::parse_eml(definition_str, EMLParsingMode::Strict).unwrap();
+        let details = ElectionTreeDetails::from_definition(&definition).unwrap();
+
+        assert_eq!(details.csb.responsible_region.name, "Nederland");
+        assert_eq!(details.csb.managing_authority_id, "CSB");
+        assert_eq!(details.csb.managing_authority_name(), "Kiesraad");
+        assert_eq!(details.csb.district, CommitteeDistrict::None);
+
+        // all GSBs in all electoral districts share the single national district
+        assert_eq!(details.get_committees(CommitteeCategory::GSB).len(), 23);
+        assert!(
+            details
+                .get_committees(CommitteeCategory::GSB)
+                .iter()
+                .all(|gsb| gsb.district == CommitteeDistrict::None)
+        );
+
+        let gsb_maastricht = details
+            .get_committee(
+                CommitteeCategory::GSB,
+                Some(RegionKey {
+                    category: RegionCategory::Municipality,
+                    number: Some(935),
+                }),
+            )
+            .expect("Missing GSB");
+        assert_eq!(gsb_maastricht.managing_authority_id, "0935");
+        assert_eq!(gsb_maastricht.managing_authority_name(), "Maastricht");
+    }
 }
@@ -0,0 +1,399 @@
+use eml_nl::{
+    documents::election_count::{CountType, ElectionCount, TotalVotes, UncountedVotesReason},
+    io::{EMLParsingMode, EMLRead as _},
+    utils::StringValue,
+};
+
+use super::EMLImportError;
+use crate::domain::{
+    election::{CandidateNumber, ElectionWithPoliticalGroups, PGNumber},
+    results::{
+        count::Count,
+        gsb_results::GSBResults,
+        political_group_candidate_votes::{CandidateVotes, PoliticalGroupCandidateVotes},
+    },
+};
+
+/// Parse an EML_NL 510 count document
+pub fn parse_count_eml_str(count_data: &str) -> Result<ElectionCount, EMLImportError> {
+    Ok(ElectionCount::parse_eml(count_data, EMLParsingMode::Strict).ok()?)
+}
+
+/// Get the managing authority identifier of an EML_NL 510 count document
+pub fn count_eml_authority_id(count: &ElectionCount) -> Result<String, EMLImportError> {
+    Ok(count
+        .managing_authority
+        .authority_identifier
+        .id
+        .cloned_value()?
+        .value()
+        .to_string())
+}
+
+/// Votes of a political group in the totals of an EML_NL 510 count document
+#[derive(Debug, Clone, PartialEq)]
+pub struct CountedPoliticalGroup {
+    /// Name of the political group as included in the count document
+    pub name: String,
+    pub votes: PoliticalGroupCandidateVotes,
+}
+
+/// Get the votes per political group and candidate from the totals of an EML_NL 510 count
+/// document, sorted by political group number and candidate number.
+pub fn political_group_votes_from_count_eml(
+    count: &ElectionCount,
+) -> Result<Vec<CountedPoliticalGroup>, EMLImportError> {
+    let total_votes = count
+        .count
+        .election
+        .contests
+        .first()
+        .ok_or(EMLImportError::CountWithoutContest)?
+        .total_votes
+        .as_ref()
+        .ok_or(EMLImportError::CountWithoutTotalVotes)?;
+
+    let mut political_groups = total_votes
+        .selections_per_affiliation()?
+        .into_iter()
+        .map(|affiliation| {
+            let mut candidate_votes = affiliation
+                .candidates
+                .iter()
+                .map(|candidate| {
+                    Ok(CandidateVotes {
+                        number: CandidateNumber::from(to_count(
+                            candidate
+                                .candidate
+                                .identifier
+                                .id
+                                .copied_value()?
+                                .value()
+                                .get(),
+                        )?),
+                        votes: to_count(candidate.valid_votes)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, EMLImportError>>()?;
+            candidate_votes.sort_by_key(|cv| cv.number);
+
+            Ok(CountedPoliticalGroup {
+                name: affiliation.affiliation.name.to_string(),
+                votes: PoliticalGroupCandidateVotes {
+                    number: PGNumber::from(to_count(
+                        affiliation.affiliation.id.copied_value()?.value().get(),
+                    )?),
+                    total: to_count(affiliation.valid_votes)?,
+                    candidate_votes,
+                },
+            })
+        })
+        .collect::<Result<Vec<_>, EMLImportError>>()?;
+    political_groups.sort_by_key(|pg| pg.votes.number);
+
+    Ok(political_groups)
+}
+
+fn to_count(value: u64) -> Result<Count, EMLImportError> {
+    value
+        .try_into()
+        .map_err(|_| EMLImportError::CountNotInRange)
+}
+
+fn string_value_to_count(value: &StringValue<u64>) -> Result<Count, EMLImportError> {
+    to_count(value.copied_value()?)
+}
+
+fn uncounted_votes(
+    total_votes: &TotalVotes,
+    reason: UncountedVotesReason,
+) -> Result<Option<Count>, EMLImportError> {
+    total_votes
+        .uncounted_votes
+        .get(&reason)
+        .map(string_value_to_count)
+        .transpose()
+}
+
+fn required_uncounted_votes(
+    total_votes: &TotalVotes,
+    reason: UncountedVotesReason,
+) -> Result<Count, EMLImportError> {
+    uncounted_votes(total_votes, reason)?.ok_or(EMLImportError::MissingUncountedVotes)
+}
+
+impl GSBResults {
+    /// Convert the totals of an EML_NL 510b count document of a GSB into [`GSBResults`]
+    /// that can be entered at the CSB.
+    pub fn from_count_eml(
+        count: &ElectionCount,
+        election: &ElectionWithPoliticalGroups,
+    ) -> Result<Self, EMLImportError> {
+        if count.count_type != CountType::Municipal {
+            return Err(EMLImportError::Needs510b);
+        }
+
+        let election_id = count.count.election.identifier.id.cloned_value()?;
+        if election_id.value() != election.election_id {
+            return Err(EMLImportError::MismatchElection);
+        }
+
+        let total_votes = count
+            .count
+            .election
+            .contests
+            .first()
+            .ok_or(EMLImportError::CountWithoutContest)?
+            .total_votes
+            .as_ref()
+            .ok_or(EMLImportError::CountWithoutTotalVotes)?;
+
+        // Start from empty results, so every political group and candidate is present
+        let mut results = GSBResults::empty(election);
+
+        results.number_of_voters = string_value_to_count(&total_votes.eligible_voter_count)?;
+
+        let voters_counts = &mut results.voters_counts;
+        voters_counts.poll_card_count =
+            required_uncounted_votes(total_votes, UncountedVotesReason::ValidPollCards)?;
+        voters_counts.proxy_certificate_count =
+            required_uncounted_votes(total_votes, UncountedVotesReason::ValidProxyCertificates)?;
+        voters_counts.voter_card_count =
+            uncounted_votes(total_votes, UncountedVotesReason::ValidVoterCards)?;
+        voters_counts.total_admitted_voters_count =
+            required_uncounted_votes(total_votes, UncountedVotesReason::AdmittedVoters)?;
+
+        let votes_counts = &mut results.votes_counts;
+        votes_counts.total_votes_candidates_count =
+            string_value_to_count(&total_votes.candidate_votes_count)?;
+        votes_counts.blank_votes_count = string_value_to_count(total_votes.blank_votes()?)?;
+        votes_counts.invalid_votes_count = string_value_to_count(total_votes.invalid_votes()?)?;
+        votes_counts.total_votes_cast_count = votes_counts
+            .total_votes_candidates_count
+            .checked_add(votes_counts.blank_votes_count)
+            .and_then(|total| total.checked_add(votes_counts.invalid_votes_count))
+            .ok_or(EMLImportError::CountNotInRange)?;
+
+        results.differences_counts.more_ballots_count =
+            required_uncounted_votes(total_votes, UncountedVotesReason::MoreBallotsCounted)?;
+        results.differences_counts.fewer_ballots_count =
+            required_uncounted_votes(total_votes, UncountedVotesReason::FewerBallotsCounted)?;
+
+        results.set_political_group_votes_from_eml(total_votes)?;
+
+        Ok(results)
+    }
+
+    fn set_political_group_votes_from_eml(
+        &mut self,
+        total_votes: &TotalVotes,
+    ) -> Result<(), EMLImportError> {
+        for affiliation in total_votes.selections_per_affiliation()? {
+            let pg_number = PGNumber::from(to_count(
+                affiliation.affiliation.id.copied_value()?.value().get(),
+            )?);
+            let total = to_count(affiliation.valid_votes)?;
+
+            let pg_total = self
+                .votes_counts
+                .political_group_total_votes
+                .iter_mut()
+                .find(|pg| pg.number == pg_number)
+                .ok_or(EMLImportError::InvalidPoliticalGroup)?;
+            pg_total.total = total;
+
+            let pg_votes = self
+                .political_group_votes
+                .iter_mut()
+                .find(|pg| pg.number == pg_number)
+                .ok_or(EMLImportError::InvalidPoliticalGroup)?;
+            pg_votes.total = total;
+
+            for candidate in affiliation.candidates {
+                let candidate_number = CandidateNumber::from(to_count(
+                    candidate
+                        .candidate
+                        .identifier
+                        .id
+                        .copied_value()?
+                        .value()
+                        .get(),
+                )?);
+                pg_votes
+                    .candidate_votes
+                    .iter_mut()
+                    .find(|cv| cv.number == candidate_number)
+                    .ok_or(EMLImportError::InvalidCandidate)?
+                    .votes = to_count(candidate.valid_votes)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use chrono::Local;
+    use eml_nl::io::EMLWrite as _;
+
+    use crate::domain::{
+        committee_session::committee_session_fixture,
+        election::{CommitteeCategory, ElectionWithPoliticalGroups, VoteCountingMethod},
+        results::gsb_results::GSBResults,
+        tabulation::ElectionTotals,
+    };
+
+    /// Create the EML_NL 510b of a GSB with the given authority id and results
+    pub fn gsb_count_eml(
+        election: &ElectionWithPoliticalGroups,
+        authority_id: &str,
+        results: &GSBResults,
+    ) -> String {
+        let mut gsb_election = election.clone();
+        gsb_election.committee_category = CommitteeCategory::GSB;
+        gsb_election.counting_method = Some(VoteCountingMethod::CSO);
+        gsb_election.authority_id = authority_id.to_string();
+        gsb_election.number_of_voters = results.number_of_voters;
+
+        let mut totals = ElectionTotals::tabulate(&gsb_election, &[]).unwrap();
+        totals.voters_counts = results.voters_counts.clone();
+        totals.votes_counts = results.votes_counts.clone();
+        totals.differences_counts.more_ballots_count.count =
+            results.differences_counts.more_ballots_count;
+        totals.differences_counts.fewer_ballots_count.count =
+            results.differences_counts.fewer_ballots_count;
+        totals.political_group_votes = results.political_group_votes.clone();
+
+        gsb_election
+            .as_count_eml(
+                None,
+                &committee_session_fixture(election.id),
+                &[],
+                &totals,
+                Local::now(),
+            )
+            .unwrap()
+            .write_eml_root_str(true, true)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+    use eml_nl::io::EMLWrite as _;
+
+    use super::{test_helpers::gsb_count_eml, *};
+    use crate::domain::{
+        committee_session::committee_session_fixture,
+        election::{CommitteeCategory, ElectionCategory, tests::election_fixture},
+        results::political_group_candidate_votes::PoliticalGroupCandidateVotes,
+        tabulation::ElectionTotals,
+    };
+
+    fn example_gsb_results(election: &ElectionWithPoliticalGroups) -> GSBResults {
+        let mut results = GSBResults::empty(election);
+        results.number_of_voters = 120;
+        results.voters_counts.poll_card_count = 100;
+        results.voters_counts.proxy_certificate_count = 4;
+        results.voters_counts.total_admitted_voters_count = 104;
+        results.votes_counts.political_group_total_votes[0].total = 60;
+        results.votes_counts.political_group_total_votes[1].total = 40;
+        results.votes_counts.total_votes_candidates_count = 100;
+        results.votes_counts.blank_votes_count = 1;
+        results.votes_counts.invalid_votes_count = 2;
+        results.votes_counts.total_votes_cast_count = 103;
+        results.differences_counts.fewer_ballots_count = 1;
+        results.political_group_votes = vec![
+            PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(1), &[50, 10]),
+            PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(2), &[40, 0]),
+        ];
+        results
+    }
+
+    #[test]
+    fn test_gsb_results_from_count_eml() {
+        let election =
+            election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[2, 2]);
+        let expected = example_gsb_results(&election);
+        let data = gsb_count_eml(&election, "0001", &expected);
+
+        let count = parse_count_eml_str(&data).unwrap();
+        assert_eq!(count_eml_authority_id(&count).unwrap(), "0001");
+
+        let results = GSBResults::from_count_eml(&count, &election).unwrap();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_gsb_results_from_count_eml_mismatch_election() {
+        let election =
+            election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[2, 2]);
+        let data = gsb_count_eml(&election, "0001", &example_gsb_results(&election));
+        let count = parse_count_eml_str(&data).unwrap();
+
+        let mut other_election = election.clone();
+        other_election.election_id = "GR2030_Other".to_string();
+        assert!(matches!(
+            GSBResults::from_count_eml(&count, &other_election),
+            Err(EMLImportError::MismatchElection)
+        ));
+    }
+
+    #[test]
+    fn test_gsb_results_from_count_eml_unknown_candidate() {
+        let election =
+            election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[2, 2]);
+        let data = gsb_count_eml(&election, "0001", &example_gsb_results(&election));
+        let count = parse_count_eml_str(&data).unwrap();
+
+        let smaller_election =
+            election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[2, 1]);
+        assert!(matches!(
+            GSBResults::from_count_eml(&count, &smaller_election),
+            Err(EMLImportError::InvalidCandidate)
+        ));
+    }
+
+    #[test]
+    fn test_political_group_votes_from_count_eml() {
+        let election =
+            election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[2, 2]);
+        let data = gsb_count_eml(&election, "0001", &example_gsb_results(&election));
+        let count = parse_count_eml_str(&data).unwrap();
+
+        let political_groups = political_group_votes_from_count_eml(&count).unwrap();
+        assert_eq!(political_groups.len(), 2);
+        assert_eq!(
+            political_groups[0].name,
+            election.political_groups[0].registered_name
+        );
+        assert_eq!(
+            political_groups[0].votes,
+            PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(1), &[50, 10])
+        );
+        assert_eq!(
+            political_groups[1].votes,
+            PoliticalGroupCandidateVotes::from_test_data_auto(PGNumber::from(2), &[40, 0])
+        );
+    }
+
+    #[test]
+    fn test_gsb_results_from_count_eml_needs_510b() {
+        let election = election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[2]);
+        let committee_session = committee_session_fixture(election.id);
+        let totals = ElectionTotals::tabulate(&election, &[]).unwrap();
+        let data = election
+            .as_count_eml(None, &committee_session, &[], &totals, Local::now())
+            .unwrap()
+            .write_eml_root_str(true, true)
+            .unwrap();
+        let count = parse_count_eml_str(&data).unwrap();
+
+        assert!(matches!(
+            GSBResults::from_count_eml(&count, &election),
+            Err(EMLImportError::Needs510b)
+        ));
+    }
+}
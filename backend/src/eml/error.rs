@@ -9,18 +9,26 @@ pub enum EMLImportError {
         found: CandidateNumber,
     },
     CommitteeCategoryForElectionCategoryNotSupported,
+    CountNotInRange,
+    CountWithoutContest,
+    CountWithoutTotalVotes,
+    DuplicateRegionNumber,
     EMLError(eml_nl::EMLError),
     InvalidCandidate,
     InvalidDateFormat,
+    InvalidElectionDomainId,
+    InvalidPoliticalGroup,
     InvalidPollingStation,
     InvalidVotingMethod,
     LimitedElectionsSupported,
     MismatchElection,
     MismatchElectionCategoryAndSubCategory,
     MismatchElectionDate,
+    MismatchElectionDistrict,
     MismatchElectionDomain,
     MismatchNumberOfSeats,
     MismatchPreferenceThreshold,
+    MismatchReportingUnit,
     MissingElectionDomain,
     MissingFileName,
     MissingManagingAuthority,
@@ -28,10 +36,15 @@ pub enum EMLImportError {
     MissingNumberOfSeats,
     MissingPollingStations,
     MissingPreferenceThreshold,
+    MissingReferendumQuestion,
+    MissingRegionNumber,
     MissingSubcategory,
+    MissingUncountedVotes,
     Needs110a,
     Needs110b,
     Needs230b,
+    Needs510b,
+    Needs510d,
     NumberOfPollingStationsNotInRange,
     NumberOfSeatsNotInRange,
     PoliticalGroupNumbersNotIncreasing {
@@ -39,6 +52,7 @@ pub enum EMLImportError {
         found: PGNumber,
     },
     PollingStationsWithoutContest,
+    ResultWithoutContest,
     TooManyPoliticalGroups,
     UnknownCommittee,
 }
@@ -1,8 +1,10 @@
 use std::{num::NonZeroU64, str::FromStr as _};
 
 pub mod committees;
+pub mod count;
 mod error;
 pub mod hash;
+pub mod result;
 
 use apportionment::CandidateNominationDetails;
 use chrono::{DateTime, Local};
@@ -19,8 +21,9 @@ use eml_nl::{
         },
         election_count::{
             AffiliationSelection, CountType, ElectionCount, ElectionCountContest,
-            ElectionCountSelection, InvestigationReason, RejectedVotesReason, ReportingUnitVotes,
-            ReportingUnitVotesBuilder, UncountedVotesReason,
+            ElectionCountSelection, InvestigationReason, ReferendumOptionSelection,
+            RejectedVotesReason, ReportingUnitVotes, ReportingUnitVotesBuilder,
+            UncountedVotesReason,
         },
         election_definition::{ElectionDefinition, ElectionDefinitionRegisteredParty},
         election_result::{
@@ -42,11 +45,14 @@ use crate::{
     domain::{
         committee_session::CommitteeSession,
         election::{
-            Candidate, CandidateGender, CandidateNumber, CommitteeCategory, ElectionDomain,
-            ElectionWithPoliticalGroups, NewElection, PGNumber, RegionKey,
-            RegisteredPoliticalGroup,
+            Candidate, CandidateGender, CandidateNumber, CommitteeCategory, CommitteeDistrict,
+            ElectionDomain, ElectionWithPoliticalGroups, NewElection, PGNumber, ReferendumQuestion,
+            RegionKey, RegisteredPoliticalGroup,
+        },
+        results::{
+            political_group_candidate_votes::PoliticalGroupCandidateVotes,
+            referendum_results::ReferendumVotesCounts, votes_counts::VotesCounts,
         },
-        results::political_group_candidate_votes::PoliticalGroupCandidateVotes,
         tabulation::{CommitteeSpecificTotals, ElectionTotals},
     },
     eml::committees::ElectionTreeDetails,
@@ -71,6 +77,13 @@ impl NewElection {
             Eml::AB => Domain::WaterAuthority,
             Eml::GR => Domain::Municipal,
             Eml::PS => Domain::Provincial,
+            Eml::TK => Domain::NationalParliament,
+            Eml::EP => Domain::EuropeanParliament,
+            Eml::ER => Domain::Island,
+            Eml::IR => Domain::IslandElectoralCollege,
+            Eml::NR => Domain::NonResidentElectoralCollege,
+            Eml::EK => Domain::Senate,
+            Eml::LR => Domain::Referendum,
             _ => return Err(EMLImportError::LimitedElectionsSupported),
         };
         Ok(category)
@@ -88,6 +101,13 @@ impl NewElection {
             Eml::GR2 => Domain::GR2,
             Eml::PS1 => Domain::PS1,
             Eml::PS2 => Domain::PS2,
+            Eml::TK => Domain::TK,
+            Eml::EP => Domain::EP,
+            Eml::ER1 => Domain::ER1,
+            Eml::IR => Domain::IR,
+            Eml::NR => Domain::NR,
+            Eml::EK => Domain::EK,
+            Eml::LR => Domain::LR,
             _ => return Err(EMLImportError::LimitedElectionsSupported),
         };
         Ok(sub_category)
@@ -109,6 +129,15 @@ impl NewElection {
             ElectionCategory::Provincial => {
                 sub_category == ElectionSubCategory::PS1 || sub_category == ElectionSubCategory::PS2
             }
+            ElectionCategory::NationalParliament => sub_category == ElectionSubCategory::TK,
+            ElectionCategory::EuropeanParliament => sub_category == ElectionSubCategory::EP,
+            ElectionCategory::Referendum => sub_category == ElectionSubCategory::LR,
+            ElectionCategory::Island => sub_category == ElectionSubCategory::ER1,
+            ElectionCategory::IslandElectoralCollege => sub_category == ElectionSubCategory::IR,
+            ElectionCategory::NonResidentElectoralCollege => {
+                sub_category == ElectionSubCategory::NR
+            }
+            ElectionCategory::Senate => sub_category == ElectionSubCategory::EK,
         }
     }
 
@@ -151,8 +180,8 @@ impl NewElection {
         // be so we try and extract it anyway
         let number_of_voters = max_votes(&election.contest.max_votes);
 
-        // TODO: once we support country-wide elections this is no longer true and the domain can be absent
-        if identifier.domain.is_none() {
+        // country-wide elections do not have a domain, all other elections do
+        if identifier.domain.is_none() && !category.is_national_election() {
             return Err(EMLImportError::MissingElectionDomain);
         }
 
@@ -173,6 +202,19 @@ impl NewElection {
             })
             .collect::<Result<Vec<RegisteredPoliticalGroup>, EMLImportError>>()?;
 
+        // a referendum asks its question in the name of the contest
+        let referendum = if category == crate::domain::election::ElectionCategory::Referendum {
+            let question = election
+                .contest
+                .identifier
+                .name
+                .as_deref()
+                .ok_or(EMLImportError::MissingReferendumQuestion)?;
+            Some(ReferendumQuestion::new(question))
+        } else {
+            None
+        };
+
         // find the selected committee, falling back to the CSB if none is selected
         let selected_committee = if let Some(selected) = selected_committee {
             election_tree_details
@@ -192,6 +234,9 @@ impl NewElection {
                 authority_id: selected_committee.managing_authority_id.clone(),
                 authority_name: selected_committee.managing_authority_name(),
                 authority_region: selected_committee.responsible_region.name.clone(),
+                frisian_export_allowed: selected_committee
+                    .responsible_region
+                    .frisian_export_allowed,
                 district: selected_committee.district.clone(),
                 domain: identifier
                     .domain
@@ -205,6 +250,7 @@ impl NewElection {
                 election_date,
                 nomination_date,
                 political_groups,
+                referendum,
             },
             election_tree_details,
         ))
@@ -246,15 +292,32 @@ impl NewElection {
             return Err(EMLImportError::MismatchElectionDate);
         }
 
-        // TODO: ensure that contest is for same district as chosen district
         let contest = election
             .contests
             .first()
             .ok_or(EMLImportError::CandidateListWithoutContest)?;
 
+        // candidate lists are per district, so they should match the chosen district
+        if let CommitteeDistrict::Specific(_) = self.district
+            && contest.identifier.id.raw() != self.district.as_contest_identifier()?.id.raw()
+        {
+            return Err(EMLImportError::MismatchElectionDistrict);
+        }
+
         // extract initial listing of political groups with candidates
+        self.political_groups = RegisteredPoliticalGroup::from_candidate_lists_contest(contest)?;
+        Ok(())
+    }
+}
+
+impl RegisteredPoliticalGroup {
+    /// Extract the political groups with their candidates from a contest of a candidate lists
+    /// document, the political group numbers should be increasing
+    pub fn from_candidate_lists_contest(
+        contest: &CandidateListsContest,
+    ) -> Result<Vec<Self>, EMLImportError> {
         let mut previous_pg_number = PGNumber::from(0);
-        self.political_groups = contest
+        contest
             .affiliations
             .iter()
             .map(|aff| {
@@ -269,12 +332,9 @@ impl NewElection {
                 previous_pg_number = pg.number;
                 Ok(pg)
             })
-            .collect::<Result<Vec<RegisteredPoliticalGroup>, EMLImportError>>()?;
-        Ok(())
+            .collect()
     }
-}
 
-impl RegisteredPoliticalGroup {
     pub fn from_candidates_list_affiliation(
         aff: &CandidateListsAffiliation,
     ) -> Result<Self, EMLImportError> {
@@ -426,6 +486,13 @@ impl ElectionWithPoliticalGroups {
             Domain::Municipal => Eml::GR,
             Domain::Provincial => Eml::PS,
             Domain::WaterAuthority => Eml::AB,
+            Domain::NationalParliament => Eml::TK,
+            Domain::EuropeanParliament => Eml::EP,
+            Domain::Referendum => Eml::LR,
+            Domain::Island => Eml::ER,
+            Domain::IslandElectoralCollege => Eml::IR,
+            Domain::NonResidentElectoralCollege => Eml::NR,
+            Domain::Senate => Eml::EK,
         }
     }
 
@@ -440,6 +507,13 @@ impl ElectionWithPoliticalGroups {
             Domain::GR2 => Eml::GR2,
             Domain::PS1 => Eml::PS1,
             Domain::PS2 => Eml::PS2,
+            Domain::TK => Eml::TK,
+            Domain::EP => Eml::EP,
+            Domain::LR => Eml::LR,
+            Domain::ER1 => Eml::ER1,
+            Domain::IR => Eml::IR,
+            Domain::NR => Eml::NR,
+            Domain::EK => Eml::EK,
         }
     }
 
@@ -451,6 +525,16 @@ impl ElectionWithPoliticalGroups {
         }
     }
 
+    /// Get the contest identifier for documents of this committee. Committees
+    /// within a specific district use the identifier of that district, all
+    /// other committees use `geen`.
+    pub fn get_eml_contest_identifier(&self) -> Result<ContestIdentifier, EMLError> {
+        match self.district {
+            CommitteeDistrict::Specific(_) => self.district.as_contest_identifier(),
+            CommitteeDistrict::None | CommitteeDistrict::All => Ok(ContestIdentifier::geen()),
+        }
+    }
+
     /// Create a prefilled builder that allows construction of election
     /// identifiers for different EML documents.
     pub fn get_eml_election_identifier_builder(
@@ -491,7 +575,7 @@ impl ElectionWithPoliticalGroups {
                     .build_for_candidate_lists()?,
             )
             .contests([CandidateListsContest::builder()
-                .identifier(ContestIdentifier::geen())
+                .identifier(self.get_eml_contest_identifier()?)
                 .affiliations(
                     self.political_groups
                         .iter()
@@ -647,17 +731,19 @@ impl ElectionWithPoliticalGroups {
         )],
         totals: &ElectionTotals,
     ) -> Result<ElectionCountContest, EMLError> {
+        let count_votes = self.as_eml_count_votes(
+            Some(&totals.votes_counts),
+            &totals.political_group_votes,
+            totals.referendum_votes_counts.as_ref(),
+        )?;
         let mut builder = ElectionCountContest::builder()
-            .identifier(ContestIdentifier::geen())
+            .identifier(self.get_eml_contest_identifier()?)
             .total_eligible_voter_count(self.get_eligible_voter_count(totals))
-            .total_candidate_votes_count(totals.votes_counts.total_votes_candidates_count)
-            .total_rejected_votes(
-                RejectedVotesReason::Blank,
-                totals.votes_counts.blank_votes_count,
-            )
+            .total_candidate_votes_count(count_votes.valid_votes_count)
+            .total_rejected_votes(RejectedVotesReason::Blank, count_votes.blank_votes_count)
             .total_rejected_votes(
                 RejectedVotesReason::Invalid,
-                totals.votes_counts.invalid_votes_count,
+                count_votes.invalid_votes_count,
             )
             .total_uncounted_votes(
                 UncountedVotesReason::ValidPollCards,
@@ -686,7 +772,7 @@ impl ElectionWithPoliticalGroups {
                 UncountedVotesReason::FewerBallotsCounted,
                 totals.differences_counts.fewer_ballots_count.count,
             )
-            .total_votes_selections(self.as_eml_count_selections(&totals.political_group_votes)?);
+            .total_votes_selections(count_votes.selections);
 
         // GSB elections include reporting unit votes in the count
         let builder = if self.committee_category == CommitteeCategory::GSB {
@@ -713,6 +799,37 @@ impl ElectionWithPoliticalGroups {
         }
     }
 
+    /// Get the votes counts and selections for a count, which are either the
+    /// votes per list and candidate, or the votes per referendum option.
+    fn as_eml_count_votes(
+        &self,
+        votes_counts: Option<&VotesCounts>,
+        political_group_votes: &[PoliticalGroupCandidateVotes],
+        referendum_votes_counts: Option<&ReferendumVotesCounts>,
+    ) -> Result<CountVotes, EMLError> {
+        match (referendum_votes_counts, votes_counts) {
+            (Some(referendum_votes_counts), _) => Ok(CountVotes {
+                valid_votes_count: referendum_votes_counts.yes_votes_count
+                    + referendum_votes_counts.no_votes_count,
+                blank_votes_count: referendum_votes_counts.blank_votes_count,
+                invalid_votes_count: referendum_votes_counts.invalid_votes_count,
+                selections: as_eml_referendum_selections(
+                    self.referendum
+                        .as_ref()
+                        .ok_or_else(|| EMLError::custom("Referendum should have a question"))?,
+                    referendum_votes_counts,
+                )?,
+            }),
+            (None, Some(votes_counts)) => Ok(CountVotes {
+                valid_votes_count: votes_counts.total_votes_candidates_count,
+                blank_votes_count: votes_counts.blank_votes_count,
+                invalid_votes_count: votes_counts.invalid_votes_count,
+                selections: self.as_eml_count_selections(political_group_votes)?,
+            }),
+            (None, None) => Err(EMLError::custom("Results should contain votes counts")),
+        }
+    }
+
     fn as_eml_count_selections(
         &self,
         votes: &[PoliticalGroupCandidateVotes],
@@ -752,6 +869,11 @@ impl ElectionWithPoliticalGroups {
         data_source: &crate::domain::data_entry::DataEntrySource,
         results: &crate::domain::results::Results,
     ) -> Result<ReportingUnitVotes, EMLError> {
+        let count_votes = self.as_eml_count_votes(
+            results.votes_counts(),
+            results.political_group_votes(),
+            results.referendum_votes_counts(),
+        )?;
         let mut builder = ReportingUnitVotes::builder()
             .identifier(ReportingUnitIdentifier::new(
                 ReportingUnitIdentifierId::new(
@@ -759,24 +881,22 @@ impl ElectionWithPoliticalGroups {
                 )?,
                 data_source.eml_reporting_unit_identifier_name(),
             ))
-            .selections(self.as_eml_count_selections(results.political_group_votes())?)
+            .selections(count_votes.selections)
             .eligible_voter_count(match results {
                 crate::domain::results::Results::DSOFirstSession(_)
                 | crate::domain::results::Results::DSONextSession(_)
                 | crate::domain::results::Results::CSOFirstSession(_)
-                | crate::domain::results::Results::CSONextSession(_) => {
+                | crate::domain::results::Results::CSONextSession(_)
+                | crate::domain::results::Results::Referendum(_) => {
                     data_source.eml_eligible_voter_count().unwrap_or(0)
                 }
                 crate::domain::results::Results::GSB(gsb_results) => gsb_results.number_of_voters,
             })
-            .candidate_votes_count(results.votes_counts().total_votes_candidates_count)
-            .rejected_votes(
-                RejectedVotesReason::Blank,
-                results.votes_counts().blank_votes_count,
-            )
+            .candidate_votes_count(count_votes.valid_votes_count)
+            .rejected_votes(RejectedVotesReason::Blank, count_votes.blank_votes_count)
             .rejected_votes(
                 RejectedVotesReason::Invalid,
-                results.votes_counts().invalid_votes_count,
+                count_votes.invalid_votes_count,
             )
             .uncounted_votes(
                 UncountedVotesReason::ValidPollCards,
@@ -895,6 +1015,35 @@ impl ElectionWithPoliticalGroups {
     }
 }
 
+/// Votes counts and selections of a count or of a single reporting unit.
+struct CountVotes {
+    valid_votes_count: u32,
+    blank_votes_count: u32,
+    invalid_votes_count: u32,
+    selections: Vec<ElectionCountSelection>,
+}
+
+/// Create the selections for the yes and no options of a referendum.
+fn as_eml_referendum_selections(
+    referendum: &ReferendumQuestion,
+    votes_counts: &ReferendumVotesCounts,
+) -> Result<Vec<ElectionCountSelection>, EMLError> {
+    Ok(vec![
+        ElectionCountSelection::builder()
+            .referendum_option(
+                ReferendumOptionSelection::new(referendum.yes_option.as_str()).with_id("voor"),
+            )
+            .valid_votes(votes_counts.yes_votes_count)
+            .build()?,
+        ElectionCountSelection::builder()
+            .referendum_option(
+                ReferendumOptionSelection::new(referendum.no_option.as_str()).with_id("tegen"),
+            )
+            .valid_votes(votes_counts.no_votes_count)
+            .build()?,
+    ])
+}
+
 fn build_candidate_result(
     can: &Candidate,
     position: usize,
@@ -1063,7 +1212,10 @@ mod tests {
     use crate::domain::{
         committee_session::{CommitteeSessionId, committee_session_fixture},
         data_entry::{DataEntryId, DataEntrySource},
-        election::{ElectionCategory, tests::election_fixture},
+        election::{
+            ElectionCategory, ElectionSubCategory, RegionCategory, RegionDetails,
+            tests::election_fixture,
+        },
         polling_station::{
             PollingStationFirstSession, PollingStationForSession,
             test_helpers::polling_stations_fixture,
@@ -1121,6 +1273,266 @@ mod tests {
         ));
     }
 
+    fn national_parliament_gsb_stadskanaal() -> NewElection {
+        let data = include_str!("tests/definitions/Verkiezingsdefinitie_TK2025.eml.xml");
+        let (election, _) = NewElection::from_eml_str(
+            data,
+            Some((
+                CommitteeCategory::GSB,
+                Some(RegionKey {
+                    category: RegionCategory::Municipality,
+                    number: Some(37),
+                }),
+            )),
+        )
+        .unwrap();
+        election
+    }
+
+    #[test]
+    fn test_national_parliament_election_from_eml() {
+        let election = national_parliament_gsb_stadskanaal();
+        assert_eq!(election.category, ElectionCategory::NationalParliament);
+        assert_eq!(election.sub_category, ElectionSubCategory::TK);
+        assert_eq!(election.election_id, "TK2025");
+        assert_eq!(election.domain, None);
+        assert_eq!(election.number_of_seats, 150);
+        assert_eq!(election.authority_id, "0037");
+        assert_eq!(election.authority_name, "Stadskanaal");
+        assert_eq!(
+            election.district.region_details().unwrap().key,
+            RegionKey {
+                category: RegionCategory::ElectoralDistrict,
+                number: Some(1),
+            }
+        );
+        assert_eq!(election.political_groups.len(), 10);
+    }
+
+    #[test]
+    fn test_national_parliament_add_candidates_from_eml() {
+        let mut election = national_parliament_gsb_stadskanaal();
+        election
+            .add_candidates_from_eml_str(include_str!("tests/eml230b_test_TK.eml.xml"))
+            .unwrap();
+
+        // list numbers are taken from the candidate list of the district
+        assert_eq!(election.political_groups.len(), 2);
+        assert_eq!(election.political_groups[1].number, PGNumber::from(3));
+        assert_eq!(election.political_groups[1].candidates.len(), 3);
+    }
+
+    #[test]
+    fn test_national_parliament_add_candidates_from_eml_other_district() {
+        let data = include_str!("tests/definitions/Verkiezingsdefinitie_TK2025.eml.xml");
+        let (mut election, _) = NewElection::from_eml_str(
+            data,
+            Some((
+                CommitteeCategory::GSB,
+                Some(RegionKey {
+                    category: RegionCategory::Municipality,
+                    number: Some(363),
+                }),
+            )),
+        )
+        .unwrap();
+
+        let res = election
+            .add_candidates_from_eml_str(include_str!("tests/eml230b_test_TK.eml.xml"))
+            .unwrap_err();
+        assert!(matches!(res, EMLImportError::MismatchElectionDistrict));
+    }
+
+    #[test]
+    fn test_as_count_eml_gsb_national_parliament() {
+        let mut election = election_fixture(
+            ElectionCategory::NationalParliament,
+            CommitteeCategory::GSB,
+            &[2],
+        );
+        election.district = CommitteeDistrict::Specific(RegionDetails {
+            name: "Groningen".to_string(),
+            key: RegionKey {
+                category: RegionCategory::ElectoralDistrict,
+                number: Some(1),
+            },
+            roman_numerals: false,
+            frisian_export_allowed: false,
+        });
+        let committee_session = committee_session_fixture(election.id);
+        let totals = ElectionTotals::tabulate(&election, &[]).unwrap();
+
+        let eml_count = election
+            .as_count_eml(None, &committee_session, &[], &totals, Local::now())
+            .unwrap();
+        assert_eq!(eml_count.count_type, CountType::Municipal);
+        assert_eq!(eml_count.count.election.identifier.category.raw(), "TK");
+        assert!(eml_count.count.election.identifier.domain.is_none());
+        assert_eq!(
+            eml_count.count.election.contests[0].identifier.id.raw(),
+            "1"
+        );
+    }
+
+    #[test]
+    fn test_european_parliament_election_from_eml() {
+        let data = include_str!("tests/definitions/Verkiezingsdefinitie_EP2024.eml.xml");
+        let (election, _) = NewElection::from_eml_str(
+            data,
+            Some((
+                CommitteeCategory::GSB,
+                Some(RegionKey {
+                    category: RegionCategory::Municipality,
+                    number: Some(37),
+                }),
+            )),
+        )
+        .unwrap();
+        assert_eq!(election.category, ElectionCategory::EuropeanParliament);
+        assert_eq!(election.sub_category, ElectionSubCategory::EP);
+        assert_eq!(election.election_id, "EP2024");
+        assert_eq!(election.domain, None);
+        assert_eq!(election.number_of_seats, 31);
+        assert_eq!(election.authority_id, "0037");
+        assert_eq!(election.district, CommitteeDistrict::None);
+    }
+
+    #[test]
+    fn test_as_count_eml_gsb_european_parliament() {
+        let election = election_fixture(
+            ElectionCategory::EuropeanParliament,
+            CommitteeCategory::GSB,
+            &[2],
+        );
+        let committee_session = committee_session_fixture(election.id);
+        let totals = ElectionTotals::tabulate(&election, &[]).unwrap();
+        let eml_count = election
+            .as_count_eml(None, &committee_session, &[], &totals, Local::now())
+            .unwrap();
+        assert_eq!(eml_count.count_type, CountType::Municipal);
+        assert_eq!(eml_count.count.election.identifier.category.raw(), "EP");
+        assert_eq!(
+            eml_count.count.election.contests[0].identifier.id.raw(),
+            "geen"
+        );
+    }
+
+    #[test]
+    fn test_island_election_from_eml() {
+        let data = include_str!("tests/definitions/Verkiezingsdefinitie_ER2027_Bonaire.eml.xml");
+        let (election, _) =
+            NewElection::from_eml_str(data, Some((CommitteeCategory::GSB, None))).unwrap();
+        assert_eq!(election.category, ElectionCategory::Island);
+        assert_eq!(election.sub_category, ElectionSubCategory::ER1);
+        assert_eq!(election.election_id, "ER2027_Bonaire");
+        assert_eq!(election.domain.unwrap().id.as_deref(), Some("9001"));
+        assert_eq!(election.number_of_seats, 9);
+        assert_eq!(election.authority_id, "9001");
+        assert_eq!(election.authority_name, "Bonaire");
+        assert_eq!(election.district, CommitteeDistrict::None);
+    }
+
+    #[test]
+    fn test_island_electoral_college_election_from_eml() {
+        let data = include_str!("tests/definitions/Verkiezingsdefinitie_IR2027_Bonaire.eml.xml");
+        let (election, _) = NewElection::from_eml_str(data, None).unwrap();
+        assert_eq!(election.category, ElectionCategory::IslandElectoralCollege);
+        assert_eq!(election.sub_category, ElectionSubCategory::IR);
+        assert_eq!(election.election_id, "IR2027_Bonaire");
+        assert_eq!(election.authority_id, "CSB");
+        assert_eq!(election.authority_name, "Bonaire");
+    }
+
+    #[test]
+    fn test_referendum_from_eml() {
+        let data =
+            include_str!("tests/definitions/Verkiezingsdefinitie_LR2026_Stadskanaal.eml.xml");
+        let (election, _) = NewElection::from_eml_str(data, None).unwrap();
+        assert_eq!(election.category, ElectionCategory::Referendum);
+        assert_eq!(election.sub_category, ElectionSubCategory::LR);
+        assert_eq!(election.election_id, "LR2026_Stadskanaal");
+        assert_eq!(election.domain.unwrap().id.as_deref(), Some("0037"));
+        assert!(election.political_groups.is_empty());
+        assert_eq!(
+            election.referendum,
+            Some(ReferendumQuestion::new(
+                "Bent u voor of tegen het voorstel van de gemeenteraad om het gemeentehuis te verbouwen?"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_referendum_missing_question() {
+        let data = include_str!("tests/eml110a_invalid_referendum_missing_question.eml.xml");
+        let res = NewElection::from_eml_str(data, None).unwrap_err();
+        assert!(matches!(res, EMLImportError::MissingReferendumQuestion));
+    }
+
+    #[test]
+    fn test_as_count_eml_gsb_island() {
+        let election = election_fixture(ElectionCategory::Island, CommitteeCategory::GSB, &[2]);
+        let committee_session = committee_session_fixture(election.id);
+        let totals = ElectionTotals::tabulate(&election, &[]).unwrap();
+        let eml_count = election
+            .as_count_eml(None, &committee_session, &[], &totals, Local::now())
+            .unwrap();
+        assert_eq!(eml_count.count.election.identifier.category.raw(), "ER");
+    }
+
+    #[test]
+    fn test_non_resident_electoral_college_election_from_eml() {
+        let data = include_str!("tests/definitions/Verkiezingsdefinitie_NR2027.eml.xml");
+        let (election, election_tree) = NewElection::from_eml_str(data, None).unwrap();
+        assert_eq!(
+            election.category,
+            ElectionCategory::NonResidentElectoralCollege
+        );
+        assert_eq!(election.sub_category, ElectionSubCategory::NR);
+        assert_eq!(election.election_id, "NR2027");
+        assert_eq!(election.domain, None);
+        assert_eq!(election.number_of_seats, 25);
+        assert_eq!(election.authority_id, "CSB");
+        assert_eq!(election.authority_name, "Kiesraad");
+        assert_eq!(
+            election_tree.get_committees(CommitteeCategory::GSB).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_senate_election_from_eml() {
+        let data = include_str!("tests/definitions/Verkiezingsdefinitie_EK2027.eml.xml");
+        let (election, election_tree) = NewElection::from_eml_str(data, None).unwrap();
+        assert_eq!(election.category, ElectionCategory::Senate);
+        assert_eq!(election.sub_category, ElectionSubCategory::EK);
+        assert_eq!(election.election_id, "EK2027");
+        assert_eq!(election.domain, None);
+        assert_eq!(election.number_of_seats, 75);
+        assert_eq!(election.authority_id, "CSB");
+        assert_eq!(election.district, CommitteeDistrict::None);
+        assert_eq!(
+            election_tree.get_committees(CommitteeCategory::GSB).len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_as_count_eml_csb_senate() {
+        let election = election_fixture(ElectionCategory::Senate, CommitteeCategory::CSB, &[2]);
+        let committee_session = committee_session_fixture(election.id);
+        let totals = ElectionTotals::tabulate(&election, &[]).unwrap();
+        let eml_count = election
+            .as_count_eml(None, &committee_session, &[], &totals, Local::now())
+            .unwrap();
+        assert_eq!(eml_count.count_type, CountType::Central);
+        assert_eq!(eml_count.count.election.identifier.category.raw(), "EK");
+        assert!(eml_count.count.election.identifier.domain.is_none());
+        assert_eq!(
+            eml_count.count.election.contests[0].identifier.id.raw(),
+            "geen"
+        );
+    }
+
     #[test]
     fn test_as_count_eml_csb() {
         let election = election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[0]);
@@ -1319,6 +1731,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_as_eml_count_contest_referendum() {
+        let mut election =
+            election_fixture(ElectionCategory::Referendum, CommitteeCategory::GSB, &[]);
+        election.referendum = Some(ReferendumQuestion {
+            question: "Bent u voor of tegen het voorstel?".to_string(),
+            yes_option: "Ja".to_string(),
+            no_option: "Nee".to_string(),
+        });
+        let committee_session = committee_session_fixture(election.id);
+        let mut totals = ElectionTotals::tabulate(&election, &[]).unwrap();
+        totals.referendum_votes_counts = Some(ReferendumVotesCounts {
+            yes_votes_count: 60,
+            no_votes_count: 35,
+            blank_votes_count: 3,
+            invalid_votes_count: 2,
+            total_votes_cast_count: 100,
+        });
+
+        let result = election
+            .as_eml_count_contest(&committee_session, &[], &totals)
+            .unwrap();
+        let total_votes = result.total_votes.unwrap();
+
+        assert_eq!(
+            total_votes.candidate_votes_count,
+            StringValue::from_value(95u64)
+        );
+        assert_eq!(total_votes.selections.len(), 2);
+        assert!(total_votes.selections.iter().all(|selection| matches!(
+            selection.selection_type,
+            eml_nl::documents::election_count::ElectionCountSelectionType::ReferendumOption(_)
+        )));
+        let options = total_votes
+            .selections
+            .iter()
+            .filter_map(|selection| selection.selection_type.as_referendum_option())
+            .map(|option| option.value.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(options, ["Ja", "Nee"]);
+        assert_eq!(
+            total_votes.selections[0].valid_votes,
+            StringValue::from_value(60u64)
+        );
+        assert_eq!(
+            total_votes.selections[1].valid_votes,
+            StringValue::from_value(35u64)
+        );
+    }
+
     #[test]
     fn test_max_votes_extraction() {
         assert_eq!(max_votes(&StringValue::Raw("0".into())), 0);
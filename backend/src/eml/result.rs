@@ -0,0 +1,262 @@
+use eml_nl::{
+    common::PersonName,
+    documents::election_result::{ElectionResult, ElectionResultSelectionType, RankingType},
+    io::{EMLParsingMode, EMLRead as _},
+};
+
+use super::EMLImportError;
+use crate::domain::election::{Candidate, PGNumber};
+
+/// A political group with seats in an EML_NL 520 result document, with its elected candidates
+/// in the order of the result
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultPoliticalGroup {
+    pub number: PGNumber,
+    pub name: String,
+    pub elected_candidates: Vec<ResultCandidate>,
+}
+
+/// An elected candidate in an EML_NL 520 result document. Candidates in the result are
+/// identified by their position in the ranking of the political group, not by their candidate
+/// number, so they can only be matched to a [`Candidate`] by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultCandidate {
+    pub position: u32,
+    pub initials: String,
+    pub last_name_prefix: Option<String>,
+    pub last_name: String,
+    /// Whether the candidate was elected with preference votes
+    pub preferential: bool,
+}
+
+impl ResultCandidate {
+    fn from_person_name(position: u32, name: &PersonName, preferential: bool) -> Self {
+        Self {
+            position,
+            initials: name.get_initials().unwrap_or_default().to_string(),
+            last_name_prefix: name.get_name_prefix().map(str::to_string),
+            last_name: name.get_last_name().to_string(),
+            preferential,
+        }
+    }
+
+    /// A candidate of a political group at the given position in its ranking
+    pub fn from_candidate(position: u32, candidate: &Candidate, preferential: bool) -> Self {
+        Self {
+            position,
+            initials: candidate.initials.clone(),
+            last_name_prefix: candidate.last_name_prefix.clone(),
+            last_name: candidate.last_name.clone(),
+            preferential,
+        }
+    }
+
+    /// Whether both are the same candidate, comparing initials and last name
+    pub fn is_same_candidate(&self, other: &Self) -> bool {
+        self.initials == other.initials
+            && self.last_name_prefix == other.last_name_prefix
+            && self.last_name == other.last_name
+    }
+
+    /// Name of the candidate as `<prefix> <last name>, <initials>`
+    pub fn name(&self) -> String {
+        let last_name = match &self.last_name_prefix {
+            Some(prefix) => format!("{prefix} {}", self.last_name),
+            None => self.last_name.clone(),
+        };
+        if self.initials.is_empty() {
+            last_name
+        } else {
+            format!("{last_name}, {}", self.initials)
+        }
+    }
+}
+
+/// Parse an EML_NL 520 result document
+pub fn parse_result_eml_str(result_data: &str) -> Result<ElectionResult, EMLImportError> {
+    Ok(ElectionResult::parse_eml(result_data, EMLParsingMode::Strict).ok()?)
+}
+
+/// Get the number of seats of an EML_NL 520 result document, which is the number of
+/// elected candidates
+pub fn number_of_seats_from_result_eml(result: &ElectionResult) -> Result<u32, EMLImportError> {
+    let contest = result
+        .result
+        .election
+        .contests
+        .first()
+        .ok_or(EMLImportError::ResultWithoutContest)?;
+
+    let mut number_of_seats: u32 = 0;
+    for selection in &contest.selections {
+        if matches!(
+            selection.selection_type,
+            ElectionResultSelectionType::Candidate(_)
+        ) && selection.elected.copied_value()?.is_yes()
+        {
+            number_of_seats = number_of_seats
+                .checked_add(1)
+                .ok_or(EMLImportError::NumberOfSeatsNotInRange)?;
+        }
+    }
+
+    Ok(number_of_seats)
+}
+
+/// Get the political groups with seats and their elected candidates from an EML_NL 520 result
+/// document. The candidates of a political group follow the selection of the political group.
+pub fn elected_political_groups_from_result_eml(
+    result: &ElectionResult,
+) -> Result<Vec<ResultPoliticalGroup>, EMLImportError> {
+    let contest = result
+        .result
+        .election
+        .contests
+        .first()
+        .ok_or(EMLImportError::ResultWithoutContest)?;
+
+    let mut political_groups: Vec<ResultPoliticalGroup> = vec![];
+    for selection in &contest.selections {
+        if selection.elected.copied_value()?.is_no() {
+            continue;
+        }
+        match &selection.selection_type {
+            ElectionResultSelectionType::Affiliation(affiliation) => {
+                political_groups.push(ResultPoliticalGroup {
+                    number: affiliation
+                        .id
+                        .copied_value()?
+                        .value()
+                        .get()
+                        .try_into()
+                        .map(|v: u32| PGNumber::from(v))
+                        .or(Err(EMLImportError::InvalidPoliticalGroup))?,
+                    name: affiliation.name.to_string(),
+                    elected_candidates: vec![],
+                });
+            }
+            ElectionResultSelectionType::Candidate(candidate) => {
+                let political_group = political_groups
+                    .last_mut()
+                    .ok_or(EMLImportError::InvalidCandidate)?;
+                let position = candidate
+                    .identifier
+                    .id
+                    .copied_value()?
+                    .value()
+                    .get()
+                    .try_into()
+                    .or(Err(EMLImportError::InvalidCandidate))?;
+                let preferential = selection
+                    .ranking
+                    .as_ref()
+                    .map(|ranking| ranking.cloned_value())
+                    .transpose()?
+                    == Some(RankingType::First);
+                political_group
+                    .elected_candidates
+                    .push(ResultCandidate::from_person_name(
+                        position,
+                        &candidate.name.person_name,
+                        preferential,
+                    ));
+            }
+        }
+    }
+
+    Ok(political_groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use apportionment::{CandidateNominationDetails, CandidateRanking, ListCandidateNomination};
+    use chrono::Local;
+    use eml_nl::io::EMLWrite as _;
+
+    use super::*;
+    use crate::domain::{
+        election::{
+            CandidateNumber, CommitteeCategory, ElectionCategory, ElectionWithPoliticalGroups,
+            tests::election_fixture,
+        },
+        results::political_group_candidate_votes::CandidateVotes,
+    };
+
+    fn result_eml_fixture() -> (ElectionWithPoliticalGroups, ElectionResult) {
+        let election =
+            election_fixture(ElectionCategory::Municipal, CommitteeCategory::CSB, &[3, 2]);
+        let cv1 = CandidateVotes {
+            number: CandidateNumber::from(1),
+            votes: 20,
+        };
+        let details = CandidateNominationDetails {
+            preference_threshold: apportionment::PreferenceThreshold {
+                percentage: 50,
+                number_of_votes: apportionment::Fraction::new(10, 1),
+            },
+            chosen_candidates: vec![],
+            list_candidate_nomination: vec![
+                ListCandidateNomination {
+                    list_number: PGNumber::from(1),
+                    list_seats: 2,
+                    preferential_candidate_nomination: vec![&cv1],
+                    other_candidate_nomination: vec![],
+                    candidate_ranking: CandidateRanking::Original(CandidateNumber::from_values([
+                        1, 2, 3,
+                    ])),
+                },
+                ListCandidateNomination {
+                    list_number: PGNumber::from(2),
+                    list_seats: 0,
+                    preferential_candidate_nomination: vec![],
+                    other_candidate_nomination: vec![],
+                    candidate_ranking: CandidateRanking::Original(CandidateNumber::from_values([
+                        1, 2,
+                    ])),
+                },
+            ],
+        };
+        let data = election
+            .as_result_eml(None, Local::now(), &details)
+            .unwrap()
+            .write_eml_root_str(true, true)
+            .unwrap();
+
+        (election, parse_result_eml_str(&data).unwrap())
+    }
+
+    #[test]
+    fn test_number_of_seats_from_result_eml() {
+        let (_, result) = result_eml_fixture();
+        assert_eq!(number_of_seats_from_result_eml(&result).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_elected_political_groups_from_result_eml() {
+        let (election, result) = result_eml_fixture();
+        let political_groups = elected_political_groups_from_result_eml(&result).unwrap();
+
+        assert_eq!(political_groups.len(), 1);
+        let political_group = &political_groups[0];
+        assert_eq!(political_group.number, PGNumber::from(1));
+        assert_eq!(political_group.name, election.political_groups[0].name);
+
+        let candidates = &political_group.elected_candidates;
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].position, 1);
+        assert!(candidates[0].preferential);
+        assert_eq!(
+            candidates[0],
+            ResultCandidate::from_candidate(1, &election.political_groups[0].candidates[0], true)
+        );
+        assert_eq!(candidates[1].position, 2);
+        assert!(!candidates[1].preferential);
+        assert!(
+            candidates[1].is_same_candidate(&ResultCandidate::from_candidate(
+                1,
+                &election.political_groups[0].candidates[1],
+                true
+            ))
+        );
+    }
+}
@@ -42,14 +42,21 @@ pub enum ErrorReference {
     AirgapViolation,
     AlreadyInitialised,
     BackupAlreadyExists,
+    BackupDecryptionFailed,
+    BackupMigrationVersionMismatch,
+    BackupPassphraseRequired,
     ApportionmentNotCompleted,
     ApportionmentCommitteeSessionNotCompleted,
     ApportionmentInvalidLotDrawing,
+    ApportionmentCandidateNotSeatHolder,
     CommitteeSessionPaused,
     DatabaseError,
     DataEntryAlreadyClaimed,
     DataEntryAlreadyFinalised,
+    DataEntryAssignedToOtherUser,
+    DataEntryAssignmentSameUser,
     DataEntryCannotBeReset,
+    DataEntryClaimNotAbandoned,
     DataEntryGetNotAllowed,
     DataEntryNotAllowed,
     EmlImportError,
@@ -347,6 +354,23 @@ impl From<BackupError> for APIError {
                 "A backup with this filename already exists".to_string(),
                 ErrorReference::BackupAlreadyExists,
             ),
+            BackupError::NotFound => APIError::NotFound(
+                "Backup not found".to_string(),
+                ErrorReference::EntryNotFound,
+            ),
+            BackupError::MigrationVersionMismatch { .. } => APIError::Conflict(
+                err.to_string(),
+                ErrorReference::BackupMigrationVersionMismatch,
+            ),
+            BackupError::PassphraseRequired => {
+                APIError::Conflict(err.to_string(), ErrorReference::BackupPassphraseRequired)
+            }
+            BackupError::DecryptionFailed => {
+                APIError::Conflict(err.to_string(), ErrorReference::BackupDecryptionFailed)
+            }
+            BackupError::EncryptionFailed => {
+                APIError::StdError(Box::new(std::io::Error::other("backup encryption failed")))
+            }
             BackupError::InvalidPath => {
                 APIError::StdError(Box::new(std::io::Error::other("invalid backup path")))
             }
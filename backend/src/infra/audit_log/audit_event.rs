@@ -120,6 +120,10 @@ pub enum AuditEventType {
     // apportionment
     ApportionmentProcessed,
     ApportionmentStateUpdated,
+    ApportionmentSnapshotCreated,
+    ExcludedCandidateAdded,
+    ExcludedCandidateDeleted,
+    VacancyRegistered,
     // investigation events
     InvestigationCreated,
     InvestigationConcluded,
@@ -138,6 +142,7 @@ pub enum AuditEventType {
     DataEntryDiscarded,
     DataEntryReset,
     DataEntryFinalised,
+    DataEntryImported,
     // data entry resolving events
     DataEntryDiscardedFirst,
     DataEntryReturnedFirst,
@@ -146,12 +151,21 @@ pub enum AuditEventType {
     DataEntryKeptFirstReturnedSecond,
     DataEntryKeptSecondReturnedFirst,
     DataEntryDiscardedBoth,
+    DataEntrySentToArbitration,
+    DataEntryArbitrationSucceeded,
+    DataEntryArbitrationFailed,
+    DataEntryAssigned,
+    DataEntryUnassigned,
+    DataEntryClaimExpired,
+    DataEntryTakenOver,
     // airgap detection events
     AirGapViolationDetected,
     AirGapViolationResolved,
     // system events
     ApplicationStarted,
     DatabaseBackupCreated,
+    DatabaseBackupCreatedAutomatically,
+    DatabaseBackupRestored,
     // API events (one for each severity level)
     ApiError,
     ApiWarning,
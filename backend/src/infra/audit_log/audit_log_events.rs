@@ -2,11 +2,11 @@ use std::net::IpAddr;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::{SqliteConnection, Type, prelude::FromRow, types::Json};
+use sqlx::{Connection, SqliteConnection, Type, prelude::FromRow, types::Json};
 use strum::VariantNames;
 use utoipa::ToSchema;
 
-use super::{AuditEventType, AuditLogUser, LogFilterQuery};
+use super::{AuditEventType, AuditLogUser, LogFilterQuery, hash_chain};
 use crate::{
     APIError,
     domain::{identifier::id, role::Role},
@@ -183,9 +183,12 @@ pub async fn create(
     let role = user.map(|u| u.role());
     let ip = ip.map(|ip| ip.to_string());
 
-    sqlx::query!(
-        "INSERT INTO audit_log (event, event_name, event_level, message, user_id, username, user_fullname, user_role, ip)
-        VALUES (jsonb(?), ?, ?, ?, ?, ?, ?, ?, ?)",
+    // the insert acquires the write lock, so the hash is chained to the last event
+    let mut tx = conn.begin().await?;
+    let id = sqlx::query_scalar!(
+        r#"INSERT INTO audit_log (event, event_name, event_level, message, user_id, username, user_fullname, user_role, ip)
+        VALUES (jsonb(?), ?, ?, ?, ?, ?, ?, ?, ?)
+        RETURNING id as "id: AuditLogEventId""#,
         event,
         event_name,
         event_level,
@@ -196,8 +199,10 @@ pub async fn create(
         role,
         ip
     )
-    .execute(conn)
+    .fetch_one(&mut *tx)
     .await?;
+    hash_chain::chain_event(&mut tx, id).await?;
+    tx.commit().await?;
 
     Ok(())
 }
@@ -0,0 +1,396 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::SqliteConnection;
+use utoipa::ToSchema;
+
+use super::AuditLogEventId;
+
+/// Previous hash of the first event in the chain
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Number of events that are verified per query
+const VERIFY_BATCH_SIZE: i64 = 1000;
+
+/// An audit log row as it is stored in the database, used to calculate the hash
+struct HashedEvent {
+    id: AuditLogEventId,
+    time: String,
+    event: String,
+    event_name: String,
+    event_level: String,
+    message: Option<String>,
+    ip: Option<String>,
+    user_id: Option<i64>,
+    username: Option<String>,
+    user_fullname: Option<String>,
+    user_role: Option<String>,
+    hash: Option<String>,
+}
+
+/// The content that is hashed, the JSON serialization makes the input unambiguous
+#[derive(Serialize)]
+struct HashContent<'a> {
+    previous_hash: &'a str,
+    id: AuditLogEventId,
+    time: &'a str,
+    event: &'a str,
+    event_name: &'a str,
+    event_level: &'a str,
+    message: Option<&'a str>,
+    ip: Option<&'a str>,
+    user_id: Option<i64>,
+    username: Option<&'a str>,
+    user_fullname: Option<&'a str>,
+    user_role: Option<&'a str>,
+}
+
+impl HashedEvent {
+    /// Calculate the SHA-256 hash of this event, chained to the hash of the previous event
+    fn calculate_hash(&self, previous_hash: &str) -> String {
+        let content = HashContent {
+            previous_hash,
+            id: self.id,
+            time: &self.time,
+            event: &self.event,
+            event_name: &self.event_name,
+            event_level: &self.event_level,
+            message: self.message.as_deref(),
+            ip: self.ip.as_deref(),
+            user_id: self.user_id,
+            username: self.username.as_deref(),
+            user_fullname: self.user_fullname.as_deref(),
+            user_role: self.user_role.as_deref(),
+        };
+        let json = serde_json::to_vec(&content).expect("Serializing strings cannot fail");
+        Sha256::digest(json)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+/// Result of verifying the audit log hash chain
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditLogVerification {
+    /// Whether the hash of every chained event matches its content and the previous event
+    pub valid: bool,
+    /// Number of events in the audit log
+    pub event_count: u64,
+    /// Number of events logged before the hash chain was introduced, these cannot be verified
+    pub unchained_event_count: u64,
+    /// Hash of the last event in the audit log
+    pub chain_head: String,
+    /// Id of the first event that does not match the hash chain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub first_invalid_event_id: Option<AuditLogEventId>,
+}
+
+async fn get_event(
+    conn: &mut SqliteConnection,
+    id: AuditLogEventId,
+) -> Result<HashedEvent, sqlx::Error> {
+    sqlx::query_as!(
+        HashedEvent,
+        r#"SELECT
+            id as "id: AuditLogEventId",
+            time as "time: String",
+            json(event) as "event!: String",
+            event_name as "event_name: String",
+            event_level as "event_level: String",
+            message,
+            ip,
+            user_id as "user_id: i64",
+            username,
+            user_fullname,
+            user_role as "user_role: String",
+            hash
+        FROM audit_log
+        WHERE id = ?"#,
+        id
+    )
+    .fetch_one(conn)
+    .await
+}
+
+/// Get the next batch of events after the given event, ordered by id
+async fn get_events_after(
+    conn: &mut SqliteConnection,
+    last_id: AuditLogEventId,
+) -> Result<Vec<HashedEvent>, sqlx::Error> {
+    sqlx::query_as!(
+        HashedEvent,
+        r#"SELECT
+            id as "id: AuditLogEventId",
+            time as "time: String",
+            json(event) as "event!: String",
+            event_name as "event_name: String",
+            event_level as "event_level: String",
+            message,
+            ip,
+            user_id as "user_id: i64",
+            username,
+            user_fullname,
+            user_role as "user_role: String",
+            hash
+        FROM audit_log
+        WHERE id > ?
+        ORDER BY id ASC
+        LIMIT ?"#,
+        last_id,
+        VERIFY_BATCH_SIZE
+    )
+    .fetch_all(conn)
+    .await
+}
+
+/// Get the hash of the event before the given event, or the genesis hash if there is none
+async fn previous_hash(
+    conn: &mut SqliteConnection,
+    id: AuditLogEventId,
+) -> Result<String, sqlx::Error> {
+    let hash = sqlx::query_scalar!(
+        "SELECT hash FROM audit_log WHERE id < ? ORDER BY id DESC LIMIT 1",
+        id
+    )
+    .fetch_optional(conn)
+    .await?;
+    Ok(hash.flatten().unwrap_or_else(|| GENESIS_HASH.to_string()))
+}
+
+/// Calculate and store the hash of a newly created event. The caller should hold
+/// the write lock, so no other event can be created in the meantime.
+pub(super) async fn chain_event(
+    conn: &mut SqliteConnection,
+    id: AuditLogEventId,
+) -> Result<(), sqlx::Error> {
+    let previous_hash = previous_hash(conn, id).await?;
+    let hash = get_event(conn, id).await?.calculate_hash(&previous_hash);
+    sqlx::query!("UPDATE audit_log SET hash = ? WHERE id = ?", hash, id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Get the hash of the last event in the audit log, or the genesis hash if there is none
+pub async fn chain_head(conn: &mut SqliteConnection) -> Result<String, sqlx::Error> {
+    let hash = sqlx::query_scalar!("SELECT hash FROM audit_log ORDER BY id DESC LIMIT 1")
+        .fetch_optional(conn)
+        .await?;
+    Ok(hash.flatten().unwrap_or_else(|| GENESIS_HASH.to_string()))
+}
+
+/// Get the id of the first event in the hash chain, as recorded when the chain was introduced
+async fn first_chained_event_id(
+    conn: &mut SqliteConnection,
+) -> Result<AuditLogEventId, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT first_event_id as "first_event_id: AuditLogEventId" FROM audit_log_chain"#
+    )
+    .fetch_one(conn)
+    .await
+}
+
+/// Verify the hash chain of the audit log, from the first event to the last.
+/// Events without a hash are only allowed before the first chained event.
+pub async fn verify_chain(
+    conn: &mut SqliteConnection,
+) -> Result<AuditLogVerification, sqlx::Error> {
+    let first_chained_event_id = first_chained_event_id(&mut *conn).await?;
+    let mut previous_hash = GENESIS_HASH.to_string();
+    let mut unchained_event_count = 0;
+    let mut first_invalid_event_id = None;
+    let mut last_id = AuditLogEventId::from(0);
+
+    'batches: loop {
+        let events = get_events_after(&mut *conn, last_id).await?;
+
+        let Some(last_event) = events.last() else {
+            break;
+        };
+        last_id = last_event.id;
+
+        for event in &events {
+            if event.id < first_chained_event_id {
+                unchained_event_count += 1;
+                continue;
+            }
+            match &event.hash {
+                Some(hash) if *hash == event.calculate_hash(&previous_hash) => {
+                    previous_hash.clone_from(hash);
+                }
+                _ => {
+                    first_invalid_event_id = Some(event.id);
+                    break 'batches;
+                }
+            }
+        }
+    }
+
+    let event_count = sqlx::query_scalar!(r#"SELECT COUNT(*) as "count: u64" FROM audit_log"#)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    Ok(AuditLogVerification {
+        valid: first_invalid_event_id.is_none(),
+        event_count,
+        unchained_event_count,
+        chain_head: chain_head(conn).await?,
+        first_invalid_event_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use test_log::test;
+
+    use super::*;
+    use crate::infra::audit_log::{AuditEvent, AuditEventLevel, AuditEventType, create};
+
+    async fn create_events(conn: &mut SqliteConnection, count: usize) {
+        for index in 0..count {
+            let event = AuditEvent {
+                event_type: AuditEventType::ApplicationStarted,
+                event_level: AuditEventLevel::Info,
+                data: serde_json::json!({ "index": index }),
+            };
+            create(conn, event, None, None, None).await.unwrap();
+        }
+    }
+
+    #[test(sqlx::test)]
+    async fn test_empty_chain_is_valid(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let verification = verify_chain(&mut conn).await.unwrap();
+        assert!(verification.valid);
+        assert_eq!(verification.event_count, 0);
+        assert_eq!(verification.chain_head, GENESIS_HASH);
+    }
+
+    #[test(sqlx::test)]
+    async fn test_chain_is_valid(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        create_events(&mut conn, 3).await;
+
+        let verification = verify_chain(&mut conn).await.unwrap();
+        assert!(verification.valid);
+        assert_eq!(verification.event_count, 3);
+        assert_eq!(verification.unchained_event_count, 0);
+        assert_eq!(verification.first_invalid_event_id, None);
+        assert_eq!(
+            verification.chain_head,
+            chain_head(&mut conn).await.unwrap()
+        );
+        assert_ne!(verification.chain_head, GENESIS_HASH);
+    }
+
+    #[test(sqlx::test)]
+    async fn test_events_before_chain_are_unchained(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        create_events(&mut conn, 4).await;
+        sqlx::query("UPDATE audit_log SET hash = NULL WHERE id <= 2")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE audit_log_chain SET first_event_id = 3")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        // the first chained event after the unchained events starts from the genesis hash
+        let event = get_event(&mut conn, AuditLogEventId::from(3))
+            .await
+            .unwrap();
+        let hash = event.calculate_hash(GENESIS_HASH);
+        sqlx::query("UPDATE audit_log SET hash = ? WHERE id = 3")
+            .bind(&hash)
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE audit_log SET hash = ? WHERE id = 4")
+            .bind(
+                get_event(&mut conn, AuditLogEventId::from(4))
+                    .await
+                    .unwrap()
+                    .calculate_hash(&hash),
+            )
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let verification = verify_chain(&mut conn).await.unwrap();
+        assert!(verification.valid);
+        assert_eq!(verification.unchained_event_count, 2);
+    }
+
+    #[test(sqlx::test)]
+    async fn test_modified_event_is_detected(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        create_events(&mut conn, 3).await;
+        sqlx::query("UPDATE audit_log SET event_level = 'error' WHERE id = 2")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let verification = verify_chain(&mut conn).await.unwrap();
+        assert!(!verification.valid);
+        assert_eq!(
+            verification.first_invalid_event_id,
+            Some(AuditLogEventId::from(2))
+        );
+    }
+
+    #[test(sqlx::test)]
+    async fn test_deleted_event_is_detected(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        create_events(&mut conn, 3).await;
+        sqlx::query("DELETE FROM audit_log WHERE id = 2")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let verification = verify_chain(&mut conn).await.unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.event_count, 2);
+        assert_eq!(
+            verification.first_invalid_event_id,
+            Some(AuditLogEventId::from(3))
+        );
+    }
+
+    #[test(sqlx::test)]
+    async fn test_removed_hash_is_detected(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        create_events(&mut conn, 2).await;
+        sqlx::query("UPDATE audit_log SET hash = NULL WHERE id = 2")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let verification = verify_chain(&mut conn).await.unwrap();
+        assert!(!verification.valid);
+        assert_eq!(
+            verification.first_invalid_event_id,
+            Some(AuditLogEventId::from(2))
+        );
+    }
+
+    #[test(sqlx::test)]
+    async fn test_removed_hashes_are_detected(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        create_events(&mut conn, 3).await;
+        sqlx::query("UPDATE audit_log SET hash = NULL")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let verification = verify_chain(&mut conn).await.unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.unchained_event_count, 0);
+        assert_eq!(
+            verification.first_invalid_event_id,
+            Some(AuditLogEventId::from(1))
+        );
+    }
+}
@@ -1,11 +1,13 @@
 mod audit_event;
 mod audit_log_events;
 mod error_logging;
+mod hash_chain;
 mod service;
 
 pub use audit_event::*;
 pub use audit_log_events::*;
 pub use error_logging::log_error;
+pub use hash_chain::{AuditLogVerification, GENESIS_HASH, chain_head, verify_chain};
 pub use service::AuditService;
 
 pub use crate::api::audit::*;
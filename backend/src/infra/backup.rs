@@ -1,18 +1,34 @@
-use chrono::Local;
+use chrono::{Local, NaiveDateTime};
 use sqlx::{
-    Connection, SqlitePool,
+    AssertSqlSafe, Connection, SqlitePool,
     sqlite::{SqliteConnectOptions, SqliteConnection},
 };
 use std::{
+    collections::HashSet,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 use tokio::sync::Mutex;
-use tracing::info;
+use tracing::{info, warn};
+
+mod encryption;
+
+pub use encryption::BackupPassphrase;
+
+const BACKUP_FILENAME_PREFIX: &str = "db_backup_";
+const BACKUP_FILENAME_EXTENSION: &str = ".sqlite";
+const ENCRYPTED_BACKUP_FILENAME_EXTENSION: &str = ".sqlite.enc";
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+const TEMPORARY_BACKUP_FILENAME: &str = "db_backup.sqlite";
 
 #[derive(Clone)]
 pub struct BackupConfig {
     pub directory: PathBuf,
+    /// Schedule for automatic backups, `None` if automatic backups are disabled
+    pub schedule: Option<BackupSchedule>,
+    /// Passphrase to encrypt new backups with, `None` if backups are not encrypted
+    encryption: Option<BackupPassphrase>,
     /// Mutex to prevent concurrent backup creation
     lock: Arc<Mutex<()>>,
 }
@@ -21,25 +37,106 @@ impl BackupConfig {
     pub fn new(directory: PathBuf) -> Self {
         Self {
             directory,
+            schedule: None,
+            encryption: None,
             lock: Arc::new(Mutex::new(())),
         }
     }
+
+    /// Enable automatic backups with the given schedule
+    pub fn with_schedule(mut self, schedule: BackupSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// Encrypt new backups with a key derived from the given passphrase,
+    /// the passphrase is also used to restore encrypted backups
+    pub fn with_encryption(mut self, passphrase: BackupPassphrase) -> Self {
+        self.encryption = Some(passphrase);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BackupSchedule {
+    /// Time between automatic backups while data entry is in progress
+    pub interval: Duration,
+    pub retention: BackupRetention,
+}
+
+/// Which backups to keep when old backups are pruned after an automatic backup.
+/// A backup is kept if it matches at least one of the rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BackupRetention {
+    /// Number of most recent backups to keep, the newest backup is always kept
+    pub keep_last: usize,
+    /// Number of most recent hours for which the newest backup of that hour is kept
+    pub keep_hourly: usize,
 }
 
 pub struct BackupResult {
     pub filename: String,
     pub created_at: chrono::DateTime<Local>,
+    pub encrypted: bool,
+}
+
+pub struct AutomaticBackupResult {
+    pub backup: BackupResult,
+    /// Filenames of the old backups that were removed by the retention policy
+    pub pruned_backups: Vec<String>,
+}
+
+pub struct RestoreResult {
+    /// The backup that was restored
+    pub filename: String,
+    /// Backup of the database as it was right before the restore
+    pub pre_restore_backup: BackupResult,
 }
 
 #[derive(Debug)]
 pub enum BackupError {
     AlreadyExists,
+    NotFound,
     InvalidPath,
+    PassphraseRequired,
+    EncryptionFailed,
+    DecryptionFailed,
     IntegrityCheckFailed(String),
+    MigrationVersionMismatch {
+        backup: Option<i64>,
+        database: Option<i64>,
+    },
     Io(std::io::Error),
     Database(sqlx::Error),
 }
 
+impl std::fmt::Display for BackupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackupError::AlreadyExists => write!(f, "a backup with this filename already exists"),
+            BackupError::NotFound => write!(f, "backup not found"),
+            BackupError::InvalidPath => write!(f, "invalid backup path"),
+            BackupError::PassphraseRequired => {
+                write!(f, "a passphrase is required to restore an encrypted backup")
+            }
+            BackupError::EncryptionFailed => write!(f, "backup encryption failed"),
+            BackupError::DecryptionFailed => write!(
+                f,
+                "backup decryption failed, the passphrase is wrong or the backup is damaged"
+            ),
+            BackupError::IntegrityCheckFailed(output) => {
+                write!(f, "backup integrity check failed: {output}")
+            }
+            BackupError::MigrationVersionMismatch { backup, database } => write!(
+                f,
+                "backup has migration version {backup:?}, but the database has migration version {database:?}"
+            ),
+            BackupError::Io(err) => write!(f, "IO error: {err}"),
+            BackupError::Database(err) => write!(f, "database error: {err}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for BackupError {
     fn from(error: std::io::Error) -> Self {
         BackupError::Io(error)
@@ -57,26 +154,249 @@ pub async fn create_local_backup(
     backup_config: &BackupConfig,
 ) -> Result<BackupResult, BackupError> {
     let guard = backup_config.lock.lock().await;
+    let result = create_backup(pool, backup_config).await?;
+    drop(guard);
+    Ok(result)
+}
+
+/// Create a backup and remove old backups according to the retention policy.
+/// Used for backups that are not requested by a user.
+pub async fn create_automatic_backup(
+    pool: &SqlitePool,
+    backup_config: &BackupConfig,
+    retention: BackupRetention,
+) -> Result<AutomaticBackupResult, BackupError> {
+    let guard = backup_config.lock.lock().await;
+    let backup = create_backup(pool, backup_config).await?;
+    let pruned_backups = prune_backups(backup_config, retention, &backup.filename)?;
+    drop(guard);
+    Ok(AutomaticBackupResult {
+        backup,
+        pruned_backups,
+    })
+}
+
+/// Remove the backups that are not kept by the retention policy, the caller
+/// should hold the backup lock. The backup with filename `keep` is never removed.
+fn prune_backups(
+    backup_config: &BackupConfig,
+    retention: BackupRetention,
+    keep: &str,
+) -> Result<Vec<String>, BackupError> {
+    let backups = list_local_backups(backup_config)?;
+    let mut pruned = vec![];
+    for backup in backups_to_prune(&backups, retention) {
+        if backup.filename == keep {
+            continue;
+        }
+        std::fs::remove_file(backup_config.directory.join(&backup.filename))?;
+        info!("Removed old database backup: {}", backup.filename);
+        pruned.push(backup.filename.clone());
+    }
+    Ok(pruned)
+}
+
+/// Select the backups that are not kept by the retention policy,
+/// `backups` should be sorted newest first.
+fn backups_to_prune(backups: &[BackupResult], retention: BackupRetention) -> Vec<&BackupResult> {
+    let mut hours = HashSet::new();
+    backups
+        .iter()
+        .enumerate()
+        .filter(|(index, backup)| {
+            let keep_last = *index < retention.keep_last.max(1);
+            // the first backup of an hour is the newest one, because of the sort order
+            let hour = backup.created_at.timestamp().div_euclid(3600);
+            let keep_hourly = hours.len() < retention.keep_hourly && hours.insert(hour);
+            !keep_last && !keep_hourly
+        })
+        .map(|(_, backup)| backup)
+        .collect()
+}
+
+/// Create a backup, the caller should hold the backup lock.
+async fn create_backup(
+    pool: &SqlitePool,
+    backup_config: &BackupConfig,
+) -> Result<BackupResult, BackupError> {
     create_backup_directory(backup_config)?;
     let now = Local::now();
-    let filename = format!("db_backup_{}.sqlite", now.format("%Y-%m-%d_%H-%M-%S"));
+    let extension = if backup_config.encryption.is_some() {
+        ENCRYPTED_BACKUP_FILENAME_EXTENSION
+    } else {
+        BACKUP_FILENAME_EXTENSION
+    };
+    let filename = format!(
+        "{BACKUP_FILENAME_PREFIX}{}{extension}",
+        now.format(BACKUP_TIMESTAMP_FORMAT)
+    );
     let backup_path = backup_config.directory.join(&filename);
     if backup_path.exists() {
         return Err(BackupError::AlreadyExists);
     }
-    if let Err(err) = write_and_verify_backup(pool, &backup_path).await {
+    let result = match &backup_config.encryption {
+        Some(passphrase) => write_encrypted_backup(pool, &backup_path, passphrase).await,
+        None => write_and_verify_backup(pool, &backup_path).await,
+    };
+    if let Err(err) = result {
         // try to delete the backup, ignore error if deleting fails
         let _ = std::fs::remove_file(&backup_path);
         return Err(err);
     }
-    drop(guard);
     info!("Created database backup: {filename}");
     Ok(BackupResult {
         filename,
         created_at: now,
+        encrypted: backup_config.encryption.is_some(),
     })
 }
 
+/// List the backups in the backup directory, newest first.
+/// Files in the directory that were not created by Abacus are ignored.
+pub fn list_local_backups(backup_config: &BackupConfig) -> Result<Vec<BackupResult>, BackupError> {
+    if !backup_config.directory.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut backups = vec![];
+    for entry in std::fs::read_dir(&backup_config.directory)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let Some(filename) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        if let Some((created_at, encrypted)) = parse_backup_filename(&filename) {
+            backups.push(BackupResult {
+                filename,
+                created_at,
+                encrypted,
+            });
+        }
+    }
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.created_at));
+    Ok(backups)
+}
+
+/// Get the creation time and whether the backup is encrypted from a backup filename,
+/// or `None` if this is not a backup filename
+fn parse_backup_filename(filename: &str) -> Option<(chrono::DateTime<Local>, bool)> {
+    let filename = filename.strip_prefix(BACKUP_FILENAME_PREFIX)?;
+    let (timestamp, encrypted) = match filename.strip_suffix(ENCRYPTED_BACKUP_FILENAME_EXTENSION) {
+        Some(timestamp) => (timestamp, true),
+        None => (filename.strip_suffix(BACKUP_FILENAME_EXTENSION)?, false),
+    };
+    let created_at = NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT)
+        .ok()?
+        .and_local_timezone(Local)
+        .earliest()?;
+    Some((created_at, encrypted))
+}
+
+/// Replace the contents of the live database with those of a backup.
+///
+/// The backup must pass the integrity check and must have the same migration
+/// version as the live database. Before restoring, a backup of the current
+/// database is created, so the restore itself can be undone. The restore runs
+/// in a single transaction, so other connections either see the old or the
+/// restored database. All sessions are removed, so every user has to log in
+/// again after a restore. Encrypted backups are first decrypted to a private temporary
+/// directory, using the passphrase from the backup configuration.
+pub async fn restore_local_backup(
+    pool: &SqlitePool,
+    backup_config: &BackupConfig,
+    filename: &str,
+) -> Result<RestoreResult, BackupError> {
+    let guard = backup_config.lock.lock().await;
+
+    // only accept backups from the listing, this also prevents path traversal
+    let backup = list_local_backups(backup_config)?
+        .into_iter()
+        .find(|backup| backup.filename == filename)
+        .ok_or(BackupError::NotFound)?;
+    let backup_path = backup_config.directory.join(&backup.filename);
+
+    let pre_restore_backup = if backup.encrypted {
+        let passphrase = backup_config
+            .encryption
+            .as_ref()
+            .ok_or(BackupError::PassphraseRequired)?;
+        // the decrypted backup is removed together with the temporary directory
+        let temporary_dir = temporary_backup_dir()?;
+        let decrypted_path = temporary_dir.path().join(TEMPORARY_BACKUP_FILENAME);
+        decrypt_backup(&backup_path, &decrypted_path, passphrase)?;
+        restore_verified_backup(pool, backup_config, &decrypted_path).await?
+    } else {
+        restore_verified_backup(pool, backup_config, &backup_path).await?
+    };
+    drop(guard);
+
+    warn!(
+        "Restored database backup: {} (previous database saved as {})",
+        backup.filename, pre_restore_backup.filename
+    );
+    Ok(RestoreResult {
+        filename: backup.filename,
+        pre_restore_backup,
+    })
+}
+
+/// Verify an unencrypted backup, create a backup of the current database and then restore
+/// the backup. The caller should hold the backup lock.
+async fn restore_verified_backup(
+    pool: &SqlitePool,
+    backup_config: &BackupConfig,
+    backup_path: &Path,
+) -> Result<BackupResult, BackupError> {
+    verify_backup(backup_path).await?;
+    verify_migration_version(pool, backup_path).await?;
+
+    let pre_restore_backup = create_backup(pool, backup_config).await?;
+    restore_database(pool, backup_path).await?;
+    Ok(pre_restore_backup)
+}
+
+/// Private temporary directory for an unencrypted copy of a backup, outside of the backup
+/// directory, so unencrypted data is never written to the backup media. The directory is
+/// only accessible by the current user and is removed with its contents when dropped.
+fn temporary_backup_dir() -> Result<tempfile::TempDir, BackupError> {
+    let mut builder = tempfile::Builder::new();
+    builder.prefix("abacus_backup_");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(std::fs::Permissions::from_mode(0o700));
+    }
+    Ok(builder.tempdir()?)
+}
+
+/// Write a verified backup to a private temporary directory, then encrypt it to the backup path
+async fn write_encrypted_backup(
+    pool: &SqlitePool,
+    backup_path: &Path,
+    passphrase: &BackupPassphrase,
+) -> Result<(), BackupError> {
+    let temporary_dir = temporary_backup_dir()?;
+    let plain_path = temporary_dir.path().join(TEMPORARY_BACKUP_FILENAME);
+    write_and_verify_backup(pool, &plain_path).await?;
+    let data = std::fs::read(&plain_path)?;
+    // remove the unencrypted backup before writing the encrypted one
+    temporary_dir.close()?;
+    std::fs::write(backup_path, encryption::encrypt(&data, passphrase)?)?;
+    Ok(())
+}
+
+fn decrypt_backup(
+    backup_path: &Path,
+    decrypted_path: &Path,
+    passphrase: &BackupPassphrase,
+) -> Result<(), BackupError> {
+    let data = std::fs::read(backup_path)?;
+    std::fs::write(decrypted_path, encryption::decrypt(&data, passphrase)?)?;
+    Ok(())
+}
+
 fn create_backup_directory(backup_config: &BackupConfig) -> Result<(), BackupError> {
     std::fs::create_dir_all(&backup_config.directory)?;
     Ok(())
@@ -100,6 +420,103 @@ async fn backup_database(pool: &SqlitePool, destination: &Path) -> Result<(), Ba
     Ok(())
 }
 
+/// Get the version of the latest successful migration of a database.
+async fn migration_version(conn: &mut SqliteConnection) -> Result<Option<i64>, BackupError> {
+    Ok(sqlx::query_scalar::<_, Option<i64>>(
+        "SELECT MAX(version) FROM _sqlx_migrations WHERE success = 1",
+    )
+    .fetch_one(conn)
+    .await?)
+}
+
+/// Check that the backup was made with the same database schema as the live
+/// database, using a separate read-only connection for the backup.
+async fn verify_migration_version(
+    pool: &SqlitePool,
+    backup_path: &Path,
+) -> Result<(), BackupError> {
+    let options = SqliteConnectOptions::new()
+        .filename(backup_path)
+        .read_only(true);
+    let mut connection = SqliteConnection::connect_with(&options).await?;
+    let backup = migration_version(&mut connection).await;
+    connection.close().await?;
+    let backup = backup?;
+
+    let database = migration_version(&mut *pool.acquire().await?).await?;
+    if backup.is_none() || backup != database {
+        return Err(BackupError::MigrationVersionMismatch { backup, database });
+    }
+    Ok(())
+}
+
+async fn restore_database(pool: &SqlitePool, backup_path: &Path) -> Result<(), BackupError> {
+    let source = backup_path
+        .to_str()
+        .ok_or(BackupError::InvalidPath)?
+        .to_string();
+    let mut connection = pool.acquire().await?;
+    sqlx::query("ATTACH DATABASE ? AS backup")
+        .bind(source)
+        .execute(&mut *connection)
+        .await?;
+    let result = copy_attached_backup(&mut connection).await;
+
+    // always detach the backup, so the connection can be reused by the pool
+    if let Err(err) = sqlx::query("DETACH DATABASE backup")
+        .execute(&mut *connection)
+        .await
+    {
+        connection.close_on_drop();
+        result?;
+        return Err(err.into());
+    }
+    result
+}
+
+/// Replace the contents of all tables with the contents of the attached
+/// backup, in a single transaction.
+async fn copy_attached_backup(conn: &mut SqliteConnection) -> Result<(), BackupError> {
+    let mut tx = conn.begin_with("BEGIN IMMEDIATE").await?;
+    // rows are copied table by table, so foreign keys can only be checked at the end
+    sqlx::query("PRAGMA defer_foreign_keys = ON")
+        .execute(&mut *tx)
+        .await?;
+
+    // the migrations are known to be the same, so they are left untouched
+    let tables = sqlx::query_scalar::<_, String>(
+        "SELECT name FROM main.sqlite_schema
+        WHERE type = 'table' AND name NOT LIKE 'sqlite_%' AND name != '_sqlx_migrations'",
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    // table names come from the schema of the database itself, not from user input
+    for table in &tables {
+        sqlx::query(AssertSqlSafe(format!("DELETE FROM main.\"{table}\"")))
+            .execute(&mut *tx)
+            .await?;
+    }
+    for table in tables.iter().filter(|table| *table != "sessions") {
+        sqlx::query(AssertSqlSafe(format!(
+            "INSERT INTO main.\"{table}\" SELECT * FROM backup.\"{table}\""
+        )))
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    // inserting rows updates the AUTOINCREMENT counters, so these are replaced last
+    sqlx::query("DELETE FROM main.sqlite_sequence")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("INSERT INTO main.sqlite_sequence SELECT * FROM backup.sqlite_sequence")
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
 /// Run SQLite integrity check on a separate read-only connection.
 async fn verify_backup(backup_path: &Path) -> Result<(), BackupError> {
     let options = SqliteConnectOptions::new()
@@ -129,6 +546,20 @@ mod tests {
         (temp_dir, backup_config)
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn temporary_backup_dir_is_private_and_removed_on_drop() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temporary_dir = temporary_backup_dir().unwrap();
+        let path = temporary_dir.path().to_path_buf();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+
+        drop(temporary_dir);
+        assert!(!path.exists());
+    }
+
     #[tokio::test]
     async fn backup_directory_is_created_succesfully() {
         let (_temp_dir, backup_config) = setup_backup_config();
@@ -151,6 +582,310 @@ mod tests {
         assert!(backup_config.directory.join(&result.filename).exists());
     }
 
+    /// Create a backup with a fixed timestamp, so it does not collide with the
+    /// backup that is created right before restoring
+    async fn create_old_backup(pool: &SqlitePool, backup_config: &BackupConfig) -> String {
+        let filename = "db_backup_2020-01-01_12-00-00.sqlite".to_string();
+        create_backup_directory(backup_config).unwrap();
+        write_and_verify_backup(pool, &backup_config.directory.join(&filename))
+            .await
+            .unwrap();
+        filename
+    }
+
+    async fn count_users(pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn list_backups_without_directory_is_empty() {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        assert!(list_local_backups(&backup_config).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_backups_is_sorted_and_ignores_other_files() {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        create_backup_directory(&backup_config).unwrap();
+        for filename in [
+            "db_backup_2024-03-01_10-00-00.sqlite",
+            "db_backup_2025-03-01_10-00-00.sqlite",
+            "db_backup_invalid.sqlite",
+            "other.sqlite",
+        ] {
+            std::fs::write(backup_config.directory.join(filename), b"").unwrap();
+        }
+
+        let filenames = list_local_backups(&backup_config)
+            .unwrap()
+            .into_iter()
+            .map(|backup| backup.filename)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            filenames,
+            [
+                "db_backup_2025-03-01_10-00-00.sqlite",
+                "db_backup_2024-03-01_10-00-00.sqlite"
+            ]
+        );
+    }
+
+    #[sqlx::test(fixtures("../../fixtures/users.sql"))]
+    async fn restore_replaces_database_contents(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        let users = count_users(&pool).await;
+        let filename = create_old_backup(&pool, &backup_config).await;
+
+        sqlx::query("DELETE FROM users")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count_users(&pool).await, 0);
+
+        let result = restore_local_backup(&pool, &backup_config, &filename)
+            .await
+            .unwrap();
+        assert_eq!(result.filename, filename);
+        assert_eq!(count_users(&pool).await, users);
+
+        // the database before restoring is kept as a backup
+        let pre_restore_path = backup_config
+            .directory
+            .join(&result.pre_restore_backup.filename);
+        let options = SqliteConnectOptions::new()
+            .filename(pre_restore_path)
+            .read_only(true);
+        let mut connection = SqliteConnection::connect_with(&options).await.unwrap();
+        let pre_restore_users: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(pre_restore_users, 0);
+    }
+
+    #[sqlx::test(fixtures("../../fixtures/users.sql"))]
+    async fn restore_removes_sessions(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        sqlx::query(
+            "INSERT INTO sessions (session_key, user_id, user_agent, ip_address, expires_at)
+            VALUES ('key', 1, 'agent', '127.0.0.1', '2100-01-01 00:00:00')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let filename = create_old_backup(&pool, &backup_config).await;
+
+        restore_local_backup(&pool, &backup_config, &filename)
+            .await
+            .unwrap();
+        let sessions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sessions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sessions, 0);
+    }
+
+    #[sqlx::test]
+    async fn restore_unknown_backup_fails(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        create_old_backup(&pool, &backup_config).await;
+        for filename in ["db_backup_2021-01-01_12-00-00.sqlite", "../db.sqlite"] {
+            assert!(matches!(
+                restore_local_backup(&pool, &backup_config, filename).await,
+                Err(BackupError::NotFound)
+            ));
+        }
+    }
+
+    #[sqlx::test]
+    async fn restore_fails_on_migration_version_mismatch(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        let filename = create_old_backup(&pool, &backup_config).await;
+
+        let options = SqliteConnectOptions::new().filename(backup_config.directory.join(&filename));
+        let mut connection = SqliteConnection::connect_with(&options).await.unwrap();
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = (SELECT MAX(version) FROM _sqlx_migrations)")
+            .execute(&mut connection)
+            .await
+            .unwrap();
+        connection.close().await.unwrap();
+
+        assert!(matches!(
+            restore_local_backup(&pool, &backup_config, &filename).await,
+            Err(BackupError::MigrationVersionMismatch { .. })
+        ));
+    }
+
+    fn backup_at(timestamp: &str) -> BackupResult {
+        let filename = format!("db_backup_{timestamp}.sqlite");
+        BackupResult {
+            created_at: parse_backup_filename(&filename).unwrap().0,
+            filename,
+            encrypted: false,
+        }
+    }
+
+    fn pruned_filenames(backups: &[BackupResult], retention: BackupRetention) -> Vec<&str> {
+        backups_to_prune(backups, retention)
+            .into_iter()
+            .map(|backup| backup.filename.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn prune_keeps_last_backups() {
+        let backups = [
+            backup_at("2025-03-01_10-30-00"),
+            backup_at("2025-03-01_10-15-00"),
+            backup_at("2025-03-01_10-00-00"),
+        ];
+        let retention = BackupRetention {
+            keep_last: 2,
+            keep_hourly: 0,
+        };
+        assert_eq!(
+            pruned_filenames(&backups, retention),
+            ["db_backup_2025-03-01_10-00-00.sqlite"]
+        );
+    }
+
+    #[test]
+    fn prune_keeps_newest_backup_per_hour() {
+        let backups = [
+            backup_at("2025-03-01_12-10-00"),
+            backup_at("2025-03-01_11-50-00"),
+            backup_at("2025-03-01_11-20-00"),
+            backup_at("2025-03-01_09-40-00"),
+            backup_at("2025-03-01_08-40-00"),
+        ];
+        let retention = BackupRetention {
+            keep_last: 1,
+            keep_hourly: 3,
+        };
+        assert_eq!(
+            pruned_filenames(&backups, retention),
+            [
+                "db_backup_2025-03-01_11-20-00.sqlite",
+                "db_backup_2025-03-01_08-40-00.sqlite"
+            ]
+        );
+    }
+
+    #[test]
+    fn prune_always_keeps_newest_backup() {
+        let backups = [
+            backup_at("2025-03-01_10-30-00"),
+            backup_at("2025-03-01_10-15-00"),
+        ];
+        let retention = BackupRetention {
+            keep_last: 0,
+            keep_hourly: 0,
+        };
+        assert_eq!(
+            pruned_filenames(&backups, retention),
+            ["db_backup_2025-03-01_10-15-00.sqlite"]
+        );
+    }
+
+    #[sqlx::test]
+    async fn automatic_backup_prunes_old_backups(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        let old_backup = create_old_backup(&pool, &backup_config).await;
+        std::fs::write(backup_config.directory.join("other.sqlite"), b"").unwrap();
+
+        let retention = BackupRetention {
+            keep_last: 1,
+            keep_hourly: 0,
+        };
+        let result = create_automatic_backup(&pool, &backup_config, retention)
+            .await
+            .unwrap();
+        assert_eq!(result.pruned_backups, std::slice::from_ref(&old_backup));
+        assert!(!backup_config.directory.join(&old_backup).exists());
+        assert!(backup_config.directory.join("other.sqlite").exists());
+
+        let filenames = list_local_backups(&backup_config)
+            .unwrap()
+            .into_iter()
+            .map(|backup| backup.filename)
+            .collect::<Vec<_>>();
+        assert_eq!(filenames, [result.backup.filename]);
+    }
+
+    fn with_passphrase(backup_config: BackupConfig, passphrase: &str) -> BackupConfig {
+        backup_config.with_encryption(BackupPassphrase::from(passphrase.to_string()))
+    }
+
+    /// Create an encrypted backup with a fixed timestamp, see [`create_old_backup`]
+    async fn create_old_encrypted_backup(
+        pool: &SqlitePool,
+        backup_config: &BackupConfig,
+    ) -> String {
+        let filename = "db_backup_2020-01-01_12-00-00.sqlite.enc".to_string();
+        let backup = create_local_backup(pool, backup_config).await.unwrap();
+        std::fs::rename(
+            backup_config.directory.join(backup.filename),
+            backup_config.directory.join(&filename),
+        )
+        .unwrap();
+        filename
+    }
+
+    #[sqlx::test]
+    async fn encrypted_backup_is_not_readable(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        let backup_config = with_passphrase(backup_config, "secret");
+        let result = create_local_backup(&pool, &backup_config).await.unwrap();
+        assert!(result.encrypted);
+        assert!(result.filename.ends_with(".sqlite.enc"));
+
+        let data = std::fs::read(backup_config.directory.join(&result.filename)).unwrap();
+        assert!(!data.starts_with(b"SQLite format 3"));
+
+        let backups = list_local_backups(&backup_config).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert!(backups[0].encrypted);
+    }
+
+    #[sqlx::test]
+    async fn restore_encrypted_backup(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        let backup_config = with_passphrase(backup_config, "secret");
+        let users = count_users(&pool).await;
+        let filename = create_old_encrypted_backup(&pool, &backup_config).await;
+
+        sqlx::query("DELETE FROM users")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let result = restore_local_backup(&pool, &backup_config, &filename)
+            .await
+            .unwrap();
+        assert_eq!(count_users(&pool).await, users);
+        assert!(result.pre_restore_backup.encrypted);
+    }
+
+    #[sqlx::test]
+    async fn restore_encrypted_backup_requires_passphrase(pool: SqlitePool) {
+        let (_temp_dir, backup_config) = setup_backup_config();
+        let filename =
+            create_old_encrypted_backup(&pool, &with_passphrase(backup_config.clone(), "secret"))
+                .await;
+
+        assert!(matches!(
+            restore_local_backup(&pool, &backup_config, &filename).await,
+            Err(BackupError::PassphraseRequired)
+        ));
+        assert!(matches!(
+            restore_local_backup(&pool, &with_passphrase(backup_config, "wrong"), &filename).await,
+            Err(BackupError::DecryptionFailed)
+        ));
+    }
+
     #[tokio::test]
     async fn verify_backup_fails_on_corrupt_file() {
         let (_temp_dir, backup_config) = setup_backup_config();
@@ -0,0 +1,147 @@
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, KeyInit, Payload},
+};
+use argon2::{Algorithm, Argon2, Params, Version};
+
+use super::BackupError;
+
+/// Marks the start of an encrypted backup file
+const MAGIC: &[u8; 8] = b"ABACUSBK";
+/// Version of the encrypted file format, increase when changing the cipher or key derivation
+const FORMAT_VERSION: u8 = 1;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const HEADER_LENGTH: usize = MAGIC.len() + 1 + SALT_LENGTH + NONCE_LENGTH;
+
+/// Passphrase that is used to derive the backup encryption key.
+#[derive(Clone)]
+pub struct BackupPassphrase(String);
+
+impl From<String> for BackupPassphrase {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl std::fmt::Debug for BackupPassphrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("BackupPassphrase(..)")
+    }
+}
+
+/// Derive a 256-bit key from the passphrase with Argon2id v19
+fn derive_key(passphrase: &BackupPassphrase, salt: &[u8]) -> Result<Key<Aes256Gcm>, BackupError> {
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, Params::default());
+    let mut key = Key::<Aes256Gcm>::default();
+    argon2
+        .hash_password_into(passphrase.0.as_bytes(), salt, &mut key)
+        .map_err(|_| BackupError::EncryptionFailed)?;
+    Ok(key)
+}
+
+/// Encrypt a backup with AES-256-GCM. The result starts with a header containing the
+/// format version, the salt for the key derivation and the nonce, followed by the ciphertext.
+/// The header is authenticated as associated data.
+pub(super) fn encrypt(
+    plaintext: &[u8],
+    passphrase: &BackupPassphrase,
+) -> Result<Vec<u8>, BackupError> {
+    let mut salt = [0u8; SALT_LENGTH];
+    let mut nonce = [0u8; NONCE_LENGTH];
+    rand::fill(&mut salt);
+    rand::fill(&mut nonce);
+
+    let mut data = Vec::with_capacity(HEADER_LENGTH + plaintext.len() + 16);
+    data.extend_from_slice(MAGIC);
+    data.push(FORMAT_VERSION);
+    data.extend_from_slice(&salt);
+    data.extend_from_slice(&nonce);
+
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, &salt)?);
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: plaintext,
+                aad: &data,
+            },
+        )
+        .map_err(|_| BackupError::EncryptionFailed)?;
+    data.extend_from_slice(&ciphertext);
+    Ok(data)
+}
+
+/// Decrypt a backup created by [`encrypt`]. Fails if the passphrase is wrong
+/// or if the file was modified.
+pub(super) fn decrypt(data: &[u8], passphrase: &BackupPassphrase) -> Result<Vec<u8>, BackupError> {
+    if data.len() < HEADER_LENGTH || !data.starts_with(MAGIC) || data[MAGIC.len()] != FORMAT_VERSION
+    {
+        return Err(BackupError::DecryptionFailed);
+    }
+    let (header, ciphertext) = data.split_at(HEADER_LENGTH);
+    let salt = &header[MAGIC.len() + 1..MAGIC.len() + 1 + SALT_LENGTH];
+    let nonce = &header[MAGIC.len() + 1 + SALT_LENGTH..];
+
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, salt)?);
+    cipher
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| BackupError::DecryptionFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passphrase(value: &str) -> BackupPassphrase {
+        BackupPassphrase::from(value.to_string())
+    }
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let encrypted = encrypt(b"database contents", &passphrase("secret")).unwrap();
+        assert!(encrypted.starts_with(MAGIC));
+        assert!(
+            !encrypted
+                .windows(b"database contents".len())
+                .any(|window| window == b"database contents")
+        );
+        assert_eq!(
+            decrypt(&encrypted, &passphrase("secret")).unwrap(),
+            b"database contents"
+        );
+    }
+
+    #[test]
+    fn decrypt_fails_with_wrong_passphrase() {
+        let encrypted = encrypt(b"database contents", &passphrase("secret")).unwrap();
+        assert!(matches!(
+            decrypt(&encrypted, &passphrase("wrong")),
+            Err(BackupError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn decrypt_fails_on_modified_data() {
+        let mut encrypted = encrypt(b"database contents", &passphrase("secret")).unwrap();
+        // modify the header and the ciphertext, both are authenticated
+        for index in [MAGIC.len() + 1, encrypted.len() - 1] {
+            encrypted[index] ^= 1;
+            assert!(matches!(
+                decrypt(&encrypted, &passphrase("secret")),
+                Err(BackupError::DecryptionFailed)
+            ));
+            encrypted[index] ^= 1;
+        }
+        assert!(matches!(
+            decrypt(b"plain sqlite file", &passphrase("secret")),
+            Err(BackupError::DecryptionFailed)
+        ));
+    }
+}
@@ -0,0 +1,318 @@
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use sqlx::{Connection, SqliteConnection, SqlitePool};
+use tracing::{error, info};
+
+use crate::{
+    domain::{
+        data_entry::{DataEntryId, DataEntryRow},
+        entry_number::EntryNumber,
+    },
+    infra::audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType},
+    repository::{data_entry_repo, user_repo::UserId},
+};
+
+pub const CLAIM_EXPIRY_CHECK_INTERVAL: u64 = 60; // interval in seconds
+pub const DEFAULT_CLAIM_TIMEOUT_MINUTES: u32 = 30;
+
+/// Inactivity timeout after which the claim of a typist on an entry in progress is abandoned,
+/// so a coordinator can hand over a first or second entry to another typist
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimTimeout(pub TimeDelta);
+
+impl Default for ClaimTimeout {
+    fn default() -> Self {
+        Self(TimeDelta::minutes(i64::from(DEFAULT_CLAIM_TIMEOUT_MINUTES)))
+    }
+}
+
+#[derive(Serialize)]
+struct DataEntryClaimExpiredAuditData {
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+    user_id: UserId,
+    data_entry_progress: String,
+    last_saved_at: DateTime<Utc>,
+}
+
+impl DataEntryClaimExpiredAuditData {
+    fn new(data_entry: &DataEntryRow) -> Option<Self> {
+        let (entry_number, user_id, progress) = data_entry.state.0.get_claimed_entry()?;
+
+        Some(Self {
+            data_entry_id: data_entry.id,
+            entry_number,
+            user_id,
+            data_entry_progress: format!("{progress}%"),
+            last_saved_at: data_entry.updated_at,
+        })
+    }
+}
+
+impl AsAuditEvent for DataEntryClaimExpiredAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::DataEntryClaimExpired;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Warning;
+}
+
+/// Logs abandoned data entry claims in a background task, see [`ClaimExpiry::start`].
+pub struct ClaimExpiry {
+    pool: SqlitePool,
+    timeout: ClaimTimeout,
+}
+
+impl ClaimExpiry {
+    /// Starts checking for abandoned data entry claims in a background task.
+    /// It will periodically check all entries in progress and log an audit event once for every
+    /// entry that was not saved within the claim timeout. The logged entries are marked in the
+    /// database until they are saved again, so they are not logged again after a restart.
+    pub fn start(pool: SqlitePool, timeout: ClaimTimeout) {
+        let claim_expiry = ClaimExpiry { pool, timeout };
+
+        tokio::task::spawn(async move {
+            loop {
+                claim_expiry.perform_check().await;
+                tokio::time::sleep(Duration::from_secs(CLAIM_EXPIRY_CHECK_INTERVAL)).await;
+            }
+        });
+    }
+
+    async fn perform_check(&self) {
+        if let Err(e) = self.log_expired_claims(Utc::now()).await {
+            error!("Failed to check for abandoned data entry claims: {e:#?}");
+        }
+    }
+
+    async fn log_expired_claims(&self, now: DateTime<Utc>) -> Result<(), sqlx::Error> {
+        let mut conn = self.pool.acquire().await?;
+        let abandoned: Vec<DataEntryRow> = data_entry_repo::list_unexpired_in_progress(&mut conn)
+            .await?
+            .into_iter()
+            .filter(|data_entry| data_entry.is_claim_abandoned(self.timeout.0, now))
+            .collect();
+
+        for data_entry in abandoned {
+            log_expired_claim(&mut conn, &data_entry).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Log the expired claim on the entry in progress and mark it as logged
+async fn log_expired_claim(
+    conn: &mut SqliteConnection,
+    data_entry: &DataEntryRow,
+) -> Result<(), sqlx::Error> {
+    let Some(audit_data) = DataEntryClaimExpiredAuditData::new(data_entry) else {
+        return Ok(());
+    };
+    let Ok(event) = audit_data.as_audit_event() else {
+        error!("Failed to serialize an abandoned data entry claim to JSON");
+        return Ok(());
+    };
+
+    let mut tx = conn.begin().await?;
+    crate::audit_log::create(&mut tx, event, None, None, None).await?;
+    data_entry_repo::mark_claim_expired(&mut tx, data_entry.id).await?;
+    tx.commit().await?;
+    info!(
+        "Claim on data entry {} of user {} expired",
+        data_entry.id, audit_data.user_id
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::{
+        domain::{
+            data_entry::{
+                ClientState, DataEntryStatus, EntriesArbitration, FirstEntryInProgress,
+                SecondEntryCorrection, ThirdEntry,
+            },
+            results::tests::example_results,
+        },
+        infra::audit_log::{list_all, list_event_names},
+    };
+
+    fn first_entry_in_progress() -> DataEntryStatus {
+        DataEntryStatus::FirstEntryInProgress(FirstEntryInProgress {
+            progress: 40,
+            first_entry_user_id: UserId::from(5),
+            first_entry: example_results(),
+            client_state: ClientState::default(),
+            is_correction: false,
+        })
+    }
+
+    async fn save_entry(
+        pool: &SqlitePool,
+        data_entry_id: DataEntryId,
+        state: &DataEntryStatus,
+        minutes_ago: i64,
+    ) {
+        let mut conn = pool.acquire().await.unwrap();
+        data_entry_repo::update(&mut conn, data_entry_id, state)
+            .await
+            .unwrap();
+
+        let updated_at = Utc::now() - TimeDelta::minutes(minutes_ago);
+        sqlx::query("UPDATE data_entries SET updated_at = ? WHERE id = ?")
+            .bind(updated_at)
+            .bind(data_entry_id)
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_log_expired_claims(pool: SqlitePool) {
+        save_entry(
+            &pool,
+            DataEntryId::from(201),
+            &first_entry_in_progress(),
+            45,
+        )
+        .await;
+        save_entry(&pool, DataEntryId::from(202), &first_entry_in_progress(), 5).await;
+
+        let claim_expiry = ClaimExpiry {
+            pool: pool.clone(),
+            timeout: ClaimTimeout::default(),
+        };
+        claim_expiry.log_expired_claims(Utc::now()).await.unwrap();
+
+        // an abandoned claim is only logged once
+        claim_expiry.log_expired_claims(Utc::now()).await.unwrap();
+
+        let mut conn = pool.acquire().await.unwrap();
+        assert_eq!(
+            list_event_names(&mut conn).await.unwrap(),
+            ["DataEntryClaimExpired"]
+        );
+
+        // the other claim expires later
+        claim_expiry
+            .log_expired_claims(Utc::now() + TimeDelta::minutes(30))
+            .await
+            .unwrap();
+        assert_eq!(
+            list_event_names(&mut conn).await.unwrap(),
+            ["DataEntryClaimExpired", "DataEntryClaimExpired"]
+        );
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_log_expired_claims_after_restart(pool: SqlitePool) {
+        let data_entry_id = DataEntryId::from(201);
+        save_entry(&pool, data_entry_id, &first_entry_in_progress(), 45).await;
+
+        for _ in 0..2 {
+            let claim_expiry = ClaimExpiry {
+                pool: pool.clone(),
+                timeout: ClaimTimeout::default(),
+            };
+            claim_expiry.log_expired_claims(Utc::now()).await.unwrap();
+        }
+
+        let mut conn = pool.acquire().await.unwrap();
+        assert_eq!(
+            list_event_names(&mut conn).await.unwrap(),
+            ["DataEntryClaimExpired"]
+        );
+
+        // saving the entry again resets the expiry
+        save_entry(&pool, data_entry_id, &first_entry_in_progress(), 45).await;
+        let claim_expiry = ClaimExpiry {
+            pool: pool.clone(),
+            timeout: ClaimTimeout::default(),
+        };
+        claim_expiry.log_expired_claims(Utc::now()).await.unwrap();
+        assert_eq!(
+            list_event_names(&mut conn).await.unwrap(),
+            ["DataEntryClaimExpired", "DataEntryClaimExpired"]
+        );
+    }
+
+    #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+    async fn test_log_expired_claims_correction_and_arbitration(pool: SqlitePool) {
+        let second_entry_correction =
+            DataEntryStatus::SecondEntryCorrection(SecondEntryCorrection {
+                first_entry_user_id: Some(UserId::from(5)),
+                imported_by_user_id: None,
+                finalised_first_entry: example_results(),
+                first_entry_finished_at: Utc::now(),
+                second_entry_user_id: UserId::from(6),
+                second_entry: example_results(),
+                progress: 60,
+                client_state: ClientState::default(),
+            });
+        let arbitration = |third_entry: Option<ThirdEntry>| {
+            DataEntryStatus::EntriesArbitration(EntriesArbitration {
+                first_entry_user_id: Some(UserId::from(5)),
+                imported_by_user_id: None,
+                first_entry: example_results(),
+                first_entry_finished_at: Utc::now(),
+                second_entry_user_id: UserId::from(6),
+                second_entry: example_results(),
+                second_entry_finished_at: Utc::now(),
+                arbitration_fields: vec![],
+                third_entry,
+            })
+        };
+        let third_entry = ThirdEntry {
+            user_id: UserId::from(7),
+            entry: example_results(),
+            progress: 20,
+            client_state: ClientState::default(),
+        };
+
+        save_entry(&pool, DataEntryId::from(201), &second_entry_correction, 45).await;
+        save_entry(
+            &pool,
+            DataEntryId::from(202),
+            &arbitration(Some(third_entry)),
+            45,
+        )
+        .await;
+
+        let claim_expiry = ClaimExpiry {
+            pool: pool.clone(),
+            timeout: ClaimTimeout::default(),
+        };
+        claim_expiry.log_expired_claims(Utc::now()).await.unwrap();
+
+        // arbitration without a claimed third entry has no claim to expire
+        save_entry(&pool, DataEntryId::from(201), &arbitration(None), 45).await;
+        claim_expiry.log_expired_claims(Utc::now()).await.unwrap();
+
+        let mut conn = pool.acquire().await.unwrap();
+        let claims: Vec<_> = list_all(&mut conn)
+            .await
+            .unwrap()
+            .iter()
+            .map(|event| {
+                let event = event.event();
+                (
+                    event["data_entry_id"].as_u64().unwrap(),
+                    event["entry_number"].as_u64().unwrap(),
+                    event["user_id"].as_u64().unwrap(),
+                    event["data_entry_progress"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            claims,
+            [
+                (201, 2, 6, "60%".to_string()),
+                (202, 3, 7, "20%".to_string())
+            ]
+        );
+    }
+}
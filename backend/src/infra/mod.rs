@@ -1,9 +1,11 @@
 pub mod audit_log;
 pub mod backup;
+pub mod claim_expiry;
 pub mod pdf_gen;
 #[cfg(feature = "tls")]
 pub mod plain_http;
 pub mod router;
+pub mod scheduled_backup;
 #[cfg(feature = "dev-database")]
 pub mod seed_data;
 #[cfg(feature = "tls")]
@@ -31,4 +31,8 @@ impl PdfGenInput for PdfFileModel {
     fn output_file_name(&self) -> &str {
         &self.file_name
     }
+
+    fn language(&self) -> &str {
+        self.model.language().as_str()
+    }
 }
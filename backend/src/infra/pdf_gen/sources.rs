@@ -33,6 +33,8 @@ pub(super) fn load_sources() -> &'static [SourceFile] {
     &[
         include_source!("common/scripts.typ"),
         include_source!("common/style.typ"),
+        include_source!("besluit-vacature.typ"),
+        include_source!("bijlage-zetelmarges.typ"),
         include_source!("model-n-10-1.typ"),
         include_source!("model-n-10-1-inlegvel.typ"),
         include_source!("model-n-10-2.typ"),
@@ -47,6 +49,7 @@ pub(super) fn load_sources() -> &'static [SourceFile] {
         include_source!("model-p-2a.typ"),
         include_source!("model-p-22-2.typ"),
         include_source!("model-p-22-2-bijlage-1.typ"),
+        include_source!("model-referendum.typ"),
         #[cfg(test)]
         include_source!("test-teletex-charset.typ"),
         #[cfg(test)]
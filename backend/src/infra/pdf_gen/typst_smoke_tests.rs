@@ -23,13 +23,13 @@ use crate::{
         },
         investigation::PollingStationInvestigation,
         models::{
-            ModelN10_1InlegvelInput, ModelN10_1Input, ModelN10_2Input, ModelNa14_1Versie1Input,
-            ModelNa14_1Versie2Input, ModelNa14_2Bijlage1Input, ModelNa14_2Input,
-            ModelNa31_1InlegvelInput, ModelNa31_2Bijlage1Input, ModelNa31_2InlegvelInput,
-            ModelNa31_2Input, ModelP2aInput, ModelP22_2Bijlage1Input, ModelP22_2Input,
-            PdfFileModel, PdfModel,
+            ModelLanguage, ModelN10_1InlegvelInput, ModelN10_1Input, ModelN10_2Input,
+            ModelNa14_1Versie1Input, ModelNa14_1Versie2Input, ModelNa14_2Bijlage1Input,
+            ModelNa14_2Input, ModelNa31_1InlegvelInput, ModelNa31_2Bijlage1Input,
+            ModelNa31_2InlegvelInput, ModelNa31_2Input, ModelP2aInput, ModelP22_2Bijlage1Input,
+            ModelP22_2Input, ModelReferendumInput, PdfFileModel, PdfModel, VacancyDecisionInput,
             apportionment_footnotes::ApportionmentFootnotes,
-            election_totals::ElectionTotalsCSB,
+            election_totals::{ElectionTotalsCSB, ElectionTotalsReferendum},
             enriched_candidate_nomination::EnrichedCandidateNomination,
             enriched_seat_assignment::EnrichedSeatAssignment,
             votes_table::{
@@ -51,6 +51,7 @@ use crate::{
             political_group_total_votes::{
                 EnrichedPoliticalGroupTotalVotes, PoliticalGroupTotalVotes,
             },
+            referendum_results::ReferendumVotesCounts,
             voters_counts::VotersCounts,
             votes_counts::{EnrichedVotesCounts, VotesCounts},
             yes_no::YesNo,
@@ -148,6 +149,9 @@ fn random_election(
         authority_id: random_string(rng, string_length),
         authority_name: random_string(rng, string_length),
         authority_region: random_string(rng, string_length),
+        frisian_export_allowed: false,
+        model_language: ModelLanguage::Dutch,
+        referendum: None,
         district: CommitteeDistrict::None,
         domain: Some(ElectionDomain {
             id: Some(random_string(rng, string_length)),
@@ -438,6 +442,7 @@ fn random_election_totals(
             fewer_ballots_count: random_sum_count(rng, data_sources),
         },
         political_group_votes: result.political_group_votes,
+        referendum_votes_counts: None,
         committee_specific: CommitteeSpecificTotals::GSB(GSBTotals::CSO(CSOInvestigations {
             admitted_voters_recounted: random_station_subset(rng, data_sources)
                 .into_iter()
@@ -455,6 +460,24 @@ fn random_election_totals(
     }
 }
 
+fn random_referendum_votes_counts(rng: &mut impl RngExt) -> ReferendumVotesCounts {
+    let yes_votes_count = rng.random_range(0..1_000);
+    let no_votes_count = rng.random_range(0..1_000);
+    let blank_votes_count = rng.random_range(0..10);
+    let invalid_votes_count = rng.random_range(0..10);
+
+    ReferendumVotesCounts {
+        yes_votes_count,
+        no_votes_count,
+        blank_votes_count,
+        invalid_votes_count,
+        total_votes_cast_count: yes_votes_count
+            + no_votes_count
+            + blank_votes_count
+            + invalid_votes_count,
+    }
+}
+
 fn random_election_totals_csb(
     rng: &mut impl RngExt,
     election: &ElectionWithPoliticalGroups,
@@ -550,6 +573,7 @@ async fn test_n_10_1() {
             candidates_tables: CandidatesTables::new(&election).unwrap(),
             election: election.into(),
             polling_station,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -573,6 +597,7 @@ async fn test_n_10_1_inlegvel() {
         let model = PdfModel::ModelN10_1Inlegvel(Box::new(ModelN10_1InlegvelInput {
             election: election.into(),
             polling_station,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -596,6 +621,7 @@ async fn test_n_10_2() {
         let model = PdfModel::ModelN10_2(Box::new(ModelN10_2Input {
             election,
             polling_station,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -622,6 +648,7 @@ async fn test_na_14_1_versie_1() {
             committee_session,
             election: election.into(),
             polling_station,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -658,6 +685,7 @@ async fn test_na_14_1_versie_2() {
             election: election.into(),
             polling_station,
             investigation,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -686,6 +714,8 @@ async fn test_na_14_2() {
         let totals = random_election_totals(&mut rng, &election, &data_sources);
 
         let hash = random_string(&mut rng, 64);
+
+        let audit_log_hash = random_string(&mut rng, 64);
         let creation_date_time = random_date_time(&mut rng)
             .format(DEFAULT_DATE_TIME_FORMAT)
             .to_string();
@@ -699,7 +729,9 @@ async fn test_na_14_2() {
             committee_session,
             previous_committee_session,
             hash,
+            audit_log_hash,
             creation_date_time,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -734,6 +766,7 @@ async fn test_na_14_2_bijlage_1() {
             election: election.into(),
             polling_station,
             investigation,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -755,6 +788,7 @@ async fn test_na_31_1_inlegvel() {
 
         let model = PdfModel::ModelNa31_1Inlegvel(Box::new(ModelNa31_1InlegvelInput {
             election: election.into(),
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -779,6 +813,7 @@ async fn test_na_31_2() {
         let data_sources = ps_as_first_data_entry_sources(&polling_stations);
         let totals = random_election_totals(&mut rng, &election, &data_sources);
         let hash = random_string(&mut rng, 64);
+        let audit_log_hash = random_string(&mut rng, 64);
         let creation_date_time = random_date_time(&mut rng)
             .format(DEFAULT_DATE_TIME_FORMAT)
             .to_string();
@@ -791,7 +826,47 @@ async fn test_na_31_2() {
             polling_station_investigations: totals.cso_investigations().unwrap().clone(),
             polling_stations,
             hash,
+            audit_log_hash,
+            creation_date_time,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
+        }));
+
+        test_pdf(model).await;
+    }
+}
+
+#[test(tokio::test)]
+async fn test_referendum() {
+    let mut rng = rand::rng();
+
+    for (_, _, string_length, none_where_possible) in EDGE_VALUES {
+        let mut election = random_election(&mut rng, 0, 0, string_length, none_where_possible);
+        election.category = ElectionCategory::Referendum;
+        election.sub_category = ElectionCategory::Referendum.sub_category(0);
+        election.committee_category = CommitteeCategory::GSB;
+        election.number_of_seats = 0;
+
+        let committee_session = random_committee_session(&mut rng, election.id, string_length);
+        let polling_stations =
+            random_polling_stations(&mut rng, string_length, none_where_possible);
+        let data_sources = ps_as_first_data_entry_sources(&polling_stations);
+        let mut totals = random_election_totals(&mut rng, &election, &data_sources);
+        totals.referendum_votes_counts = Some(random_referendum_votes_counts(&mut rng));
+        let hash = random_string(&mut rng, 64);
+        let audit_log_hash = random_string(&mut rng, 64);
+        let creation_date_time = random_date_time(&mut rng)
+            .format(DEFAULT_DATE_TIME_FORMAT)
+            .to_string();
+
+        let model = PdfModel::ModelReferendum(Box::new(ModelReferendumInput {
+            committee_session,
+            election: election.into(),
+            summary: ElectionTotalsReferendum::new(&totals).unwrap(),
+            polling_stations,
+            hash,
+            audit_log_hash,
             creation_date_time,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -816,6 +891,7 @@ async fn test_na_31_2_bijlage_1() {
             candidates_tables: CandidatesTables::new(&election).unwrap(),
             election: election.into(),
             polling_station,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -837,6 +913,7 @@ async fn test_na_31_2_inlegvel() {
 
         let model = PdfModel::ModelNa31_2Inlegvel(Box::new(ModelNa31_2InlegvelInput {
             election: election.into(),
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -874,6 +951,7 @@ async fn test_p_2a() {
             committee_session,
             election: election.into(),
             investigations,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
@@ -926,8 +1004,17 @@ async fn test_p_22_2() {
         EnrichedCandidateNomination::new(&election, &candidate_nomination).unwrap();
     let footnotes =
         ApportionmentFootnotes::new(&election.political_groups, &seat_assignment.steps).unwrap();
+    let explanations = apportionment
+        .seat_assignment
+        .explanations()
+        .unwrap()
+        .iter()
+        .map(Into::into)
+        .collect();
 
     let hash = random_string(&mut rng, 64);
+
+    let audit_log_hash = random_string(&mut rng, 64);
     let creation_date_time = random_date_time(&mut rng)
         .format(DEFAULT_DATE_TIME_FORMAT)
         .to_string();
@@ -939,8 +1026,11 @@ async fn test_p_22_2() {
         footnotes,
         seat_assignment: enriched_seat_assignment,
         candidate_nomination: enriched_candidate_nomination,
+        explanations,
         hash,
+        audit_log_hash,
         creation_date_time,
+        language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
     }));
 
     test_pdf(model).await;
@@ -1018,8 +1108,17 @@ async fn test_p_22_2_no_votes_cast_regression_3669() {
         EnrichedCandidateNomination::new(&election, &candidate_nomination).unwrap();
     let footnotes =
         ApportionmentFootnotes::new(&election.political_groups, &seat_assignment.steps).unwrap();
+    let explanations = apportionment
+        .seat_assignment
+        .explanations()
+        .unwrap()
+        .iter()
+        .map(Into::into)
+        .collect();
 
     let hash = random_string(&mut rng, 64);
+
+    let audit_log_hash = random_string(&mut rng, 64);
     let creation_date_time = random_date_time(&mut rng)
         .format(DEFAULT_DATE_TIME_FORMAT)
         .to_string();
@@ -1031,13 +1130,32 @@ async fn test_p_22_2_no_votes_cast_regression_3669() {
         footnotes,
         seat_assignment: enriched_seat_assignment,
         candidate_nomination: enriched_candidate_nomination,
+        explanations,
         hash,
+        audit_log_hash,
         creation_date_time,
+        language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
     }));
 
     test_pdf(model).await;
 }
 
+/// Renders the explanations of the absolute majority reassignment and list exhaustion
+/// in both languages
+#[test(tokio::test)]
+async fn test_p_22_2_explanations() {
+    let input = std::fs::read_to_string(
+        "templates/inputs/model-p-22-2-variations/lt-19-seats-and-p9-and-p10.json",
+    )
+    .unwrap();
+    for language in [ModelLanguage::Dutch, ModelLanguage::Frisian] {
+        let mut model: ModelP22_2Input = serde_json::from_str(&input).unwrap();
+        assert_eq!(model.explanations.len(), 6);
+        model.language = language;
+        test_pdf(PdfModel::ModelP22_2(Box::new(model))).await;
+    }
+}
+
 #[test(tokio::test)]
 async fn test_p_22_2_bijlage_1() {
     let mut rng = rand::rng();
@@ -1055,6 +1173,7 @@ async fn test_p_22_2_bijlage_1() {
         let totals = random_election_totals(&mut rng, &election, &data_sources);
         let votes_tables = VotesTables::new(&election, &totals).unwrap();
         let hash = random_string(&mut rng, 64);
+        let audit_log_hash = random_string(&mut rng, 64);
         let creation_date_time = random_date_time(&mut rng)
             .format(DEFAULT_DATE_TIME_FORMAT)
             .to_string();
@@ -1063,9 +1182,21 @@ async fn test_p_22_2_bijlage_1() {
             election: election.into(),
             votes_tables,
             hash,
+            audit_log_hash,
             creation_date_time,
+            language: random_value(&mut rng, &[ModelLanguage::Dutch, ModelLanguage::Frisian]),
         }));
 
         test_pdf(model).await;
     }
 }
+
+#[test(tokio::test)]
+async fn test_vacancy_decision() {
+    let input = std::fs::read_to_string("templates/inputs/besluit-vacature.json").unwrap();
+    for language in [ModelLanguage::Dutch, ModelLanguage::Frisian] {
+        let mut model: VacancyDecisionInput = serde_json::from_str(&input).unwrap();
+        model.language = language;
+        test_pdf(PdfModel::VacancyDecision(Box::new(model))).await;
+    }
+}
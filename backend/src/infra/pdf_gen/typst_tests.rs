@@ -10,7 +10,7 @@ use crate::domain::{
         tests::election_fixture,
     },
     models::{
-        ModelNa31_2Input, PdfFileModel, PdfModel, ToPdfFileModel, filter_input,
+        ModelLanguage, ModelNa31_2Input, PdfFileModel, PdfModel, ToPdfFileModel, filter_input,
         votes_table::VotesTables,
     },
     polling_station::test_helpers::polling_stations_fixture,
@@ -29,6 +29,9 @@ async fn it_generates_a_pdf() {
         authority_id: "0000".to_string(),
         authority_name: "Heemdamseburg".to_string(),
         authority_region: "Heemdamseburg".to_string(),
+        frisian_export_allowed: false,
+        model_language: ModelLanguage::Dutch,
+        referendum: None,
         district: CommitteeDistrict::None,
         domain: Some(ElectionDomain {
             id: Some("0000".to_string()),
@@ -54,7 +57,11 @@ async fn it_generates_a_pdf() {
             polling_stations: vec![],
             hash: "ed36 60eb 017a 0d3a d3ef 72b1 6865 f991 a36a 9f92 72d9 1516 39cd 422b 4756 d161"
                 .to_string(),
+            audit_log_hash:
+                "9b4c 1e0f 7a62 d3c8 5e91 04bb 2f6a 8c1d 73e5 a940 6d2b f8c7 1a05 e3d9 4b86 c072"
+                    .to_string(),
             creation_date_time: "04-12-2024 12:08".to_string(),
+            language: ModelLanguage::Dutch,
         }
         .to_pdf_file_model("file.pdf".into()),
     )
@@ -122,7 +129,44 @@ async fn it_generates_a_pdf_with_polling_stations() {
             election: election.into(),
             hash: "ed36 60eb 017a 0d3a d3ef 72b1 6865 f991 a36a 9f92 72d9 1516 39cd 422b 4756 d161"
                 .to_string(),
+            audit_log_hash:
+                "9b4c 1e0f 7a62 d3c8 5e91 04bb 2f6a 8c1d 73e5 a940 6d2b f8c7 1a05 e3d9 4b86 c072"
+                    .to_string(),
             creation_date_time: "04-12-2024 12:08".to_string(),
+            language: ModelLanguage::Dutch,
+        }
+        .to_pdf_file_model("file.pdf".into()),
+    )
+    .await
+    .unwrap();
+
+    assert!(!content.buffer.is_empty());
+}
+
+#[test(tokio::test)]
+async fn it_generates_a_frisian_pdf() {
+    let mut election =
+        election_fixture(ElectionCategory::Municipal, CommitteeCategory::GSB, &[2, 3]);
+    election.frisian_export_allowed = true;
+    election.model_language = ModelLanguage::Frisian;
+    let committee_session = committee_session_fixture(election.id);
+    let totals = ElectionTotals::tabulate(&election, &[]).unwrap();
+
+    let content = generate_pdf(
+        ModelNa31_2Input {
+            votes_tables: VotesTables::new(&election, &totals).unwrap(),
+            summary: (&totals).into(),
+            polling_station_investigations: totals.cso_investigations().unwrap().clone(),
+            polling_stations: polling_stations_fixture(&[100, 200, 300]),
+            committee_session,
+            election: election.into(),
+            hash: "ed36 60eb 017a 0d3a d3ef 72b1 6865 f991 a36a 9f92 72d9 1516 39cd 422b 4756 d161"
+                .to_string(),
+            audit_log_hash:
+                "9b4c 1e0f 7a62 d3c8 5e91 04bb 2f6a 8c1d 73e5 a940 6d2b f8c7 1a05 e3d9 4b86 c072"
+                    .to_string(),
+            creation_date_time: "04-12-2024 12:08".to_string(),
+            language: ModelLanguage::Frisian,
         }
         .to_pdf_file_model("file.pdf".into()),
     )
@@ -25,7 +25,7 @@ use crate::{
     AppError, AppState, MAX_BODY_SIZE_MB, api,
     api::middleware::{airgap, airgap::AirgapDetection, authentication},
     error,
-    infra::{audit_log, backup::BackupConfig},
+    infra::{audit_log, backup::BackupConfig, claim_expiry::ClaimTimeout},
 };
 #[cfg(feature = "tls")]
 use axum::{body::Bytes, routing::get};
@@ -65,6 +65,7 @@ fn build_routes(doc: utoipa::openapi::OpenApi) -> OpenApiRouter<AppState> {
         .merge(api::user::user_router())
         .merge(api::committee_session::router())
         .merge(api::data_entry::router())
+        .merge(api::data_entry_assignment::router())
         .merge(api::election::router())
         .merge(api::polling_station::router())
         .merge(api::report::router())
@@ -273,12 +274,14 @@ pub fn create_router(
     pool: SqlitePool,
     airgap_detection: AirgapDetection,
     backup_config: BackupConfig,
+    claim_timeout: ClaimTimeout,
 ) -> Result<Router, AppError> {
     let router = axum_router_from_openapi(openapi_router());
     let state = AppState {
         pool,
         airgap_detection,
         backup_config,
+        claim_timeout,
     };
     let router = add_middleware(router, &state);
     #[cfg(feature = "memory-serve")]
@@ -0,0 +1,278 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+use tracing::{debug, error, info};
+
+use crate::{
+    domain::{
+        committee_session::CommitteeSessionId, committee_session_status::CommitteeSessionStatus,
+    },
+    infra::{
+        audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType},
+        backup::{AutomaticBackupResult, BackupConfig, BackupSchedule, create_automatic_backup},
+    },
+};
+
+pub const SCHEDULED_BACKUP_CHECK_INTERVAL: u64 = 30; // interval in seconds
+
+/// Why an automatic backup was created
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomaticBackupReason {
+    /// The backup interval passed while data entry was in progress
+    Interval,
+    /// The status of a committee session changed
+    StatusChange,
+}
+
+#[derive(Serialize)]
+struct DatabaseBackupCreatedAutomaticallyAuditData {
+    filename: String,
+    reason: AutomaticBackupReason,
+    pruned_backups: Vec<String>,
+}
+
+impl AsAuditEvent for DatabaseBackupCreatedAutomaticallyAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::DatabaseBackupCreatedAutomatically;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Info;
+}
+
+type SessionStatuses = Vec<(CommitteeSessionId, CommitteeSessionStatus)>;
+
+/// Creates automatic backups in a background task, see [`ScheduledBackups::start`].
+pub struct ScheduledBackups {
+    pool: SqlitePool,
+    backup_config: BackupConfig,
+    schedule: BackupSchedule,
+    /// Statuses of the committee sessions at the last check
+    statuses: Option<SessionStatuses>,
+    last_backup: Option<Instant>,
+}
+
+impl ScheduledBackups {
+    /// Starts creating automatic backups in a background task.
+    /// It will periodically check the status of all committee sessions and create a backup
+    /// when the status of a committee session changed, or when the backup interval passed
+    /// while a committee session is in the data entry status.
+    pub fn start(pool: SqlitePool, backup_config: BackupConfig, schedule: BackupSchedule) {
+        let mut scheduled_backups = ScheduledBackups {
+            pool,
+            backup_config,
+            schedule,
+            statuses: None,
+            last_backup: None,
+        };
+
+        tokio::task::spawn(async move {
+            loop {
+                scheduled_backups.perform_check().await;
+                tokio::time::sleep(Duration::from_secs(SCHEDULED_BACKUP_CHECK_INTERVAL)).await;
+            }
+        });
+    }
+
+    async fn perform_check(&mut self) {
+        let statuses = match self.get_statuses().await {
+            Ok(statuses) => statuses,
+            Err(err) => {
+                error!("Failed to get committee session statuses for automatic backup: {err}");
+                return;
+            }
+        };
+
+        let Some(reason) = backup_reason(
+            self.statuses.as_ref(),
+            &statuses,
+            self.last_backup.map(|last_backup| last_backup.elapsed()),
+            self.schedule.interval,
+        ) else {
+            debug!("No automatic backup needed.");
+            self.statuses = Some(statuses);
+            return;
+        };
+
+        // statuses are only updated after a successful backup, so a failed backup is retried
+        if self.create_backup(reason).await {
+            self.statuses = Some(statuses);
+            self.last_backup = Some(Instant::now());
+        }
+    }
+
+    async fn get_statuses(&self) -> Result<SessionStatuses, sqlx::Error> {
+        sqlx::query_as("SELECT id, status FROM committee_sessions ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Create a backup and log it in the audit log, returns whether the backup was created
+    async fn create_backup(&self, reason: AutomaticBackupReason) -> bool {
+        let result =
+            create_automatic_backup(&self.pool, &self.backup_config, self.schedule.retention).await;
+        let AutomaticBackupResult {
+            backup,
+            pruned_backups,
+        } = match result {
+            Ok(result) => result,
+            Err(err) => {
+                error!("Failed to create automatic database backup: {err}");
+                return false;
+            }
+        };
+        info!(
+            "Created automatic database backup ({reason:?}): {}",
+            backup.filename
+        );
+
+        self.log_backup(DatabaseBackupCreatedAutomaticallyAuditData {
+            filename: backup.filename,
+            reason,
+            pruned_backups,
+        })
+        .await;
+        true
+    }
+
+    #[expect(clippy::cognitive_complexity)]
+    async fn log_backup(&self, audit_data: DatabaseBackupCreatedAutomaticallyAuditData) {
+        let Ok(event) = audit_data.as_audit_event() else {
+            error!("Failed to serialize an automatic backup to JSON");
+            return;
+        };
+
+        let Ok(mut conn) = self.pool.acquire().await else {
+            error!("Failed to acquire database connection for automatic backup logging");
+            return;
+        };
+
+        if let Err(e) = crate::audit_log::create(&mut conn, event, None, None, None).await {
+            error!("Failed to log automatic backup: {e:#?}");
+        };
+    }
+}
+
+/// Determine whether an automatic backup is needed, given the statuses at the
+/// previous check, the current statuses and the time since the last backup
+fn backup_reason(
+    previous: Option<&SessionStatuses>,
+    current: &SessionStatuses,
+    since_last_backup: Option<Duration>,
+    interval: Duration,
+) -> Option<AutomaticBackupReason> {
+    if previous.is_some_and(|previous| previous != current) {
+        return Some(AutomaticBackupReason::StatusChange);
+    }
+
+    let data_entry_in_progress = current
+        .iter()
+        .any(|(_, status)| *status == CommitteeSessionStatus::DataEntry);
+    let interval_passed = since_last_backup.is_none_or(|elapsed| elapsed >= interval);
+    (data_entry_in_progress && interval_passed).then_some(AutomaticBackupReason::Interval)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::infra::{
+        audit_log::list_event_names,
+        backup::{BackupRetention, list_local_backups},
+    };
+
+    const INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+    fn statuses(status: CommitteeSessionStatus) -> SessionStatuses {
+        vec![
+            (
+                CommitteeSessionId::from(1),
+                CommitteeSessionStatus::Completed,
+            ),
+            (CommitteeSessionId::from(2), status),
+        ]
+    }
+
+    #[test]
+    fn backup_on_status_change() {
+        let previous = statuses(CommitteeSessionStatus::DataEntry);
+        let current = statuses(CommitteeSessionStatus::Paused);
+        assert_eq!(
+            backup_reason(
+                Some(&previous),
+                &current,
+                Some(Duration::from_secs(1)),
+                INTERVAL
+            ),
+            Some(AutomaticBackupReason::StatusChange)
+        );
+    }
+
+    #[test]
+    fn backup_on_interval_during_data_entry() {
+        let current = statuses(CommitteeSessionStatus::DataEntry);
+        assert_eq!(
+            backup_reason(Some(&current), &current, Some(INTERVAL), INTERVAL),
+            Some(AutomaticBackupReason::Interval)
+        );
+        assert_eq!(
+            backup_reason(None, &current, None, INTERVAL),
+            Some(AutomaticBackupReason::Interval)
+        );
+        assert_eq!(
+            backup_reason(
+                Some(&current),
+                &current,
+                Some(Duration::from_secs(60)),
+                INTERVAL
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn no_backup_without_data_entry() {
+        let current = statuses(CommitteeSessionStatus::Paused);
+        assert_eq!(backup_reason(None, &current, None, INTERVAL), None);
+        assert_eq!(
+            backup_reason(Some(&current), &current, Some(INTERVAL), INTERVAL),
+            None
+        );
+    }
+
+    fn scheduled_backups(pool: SqlitePool, backup_config: BackupConfig) -> ScheduledBackups {
+        ScheduledBackups {
+            pool,
+            backup_config,
+            schedule: BackupSchedule {
+                interval: INTERVAL,
+                retention: BackupRetention {
+                    keep_last: 10,
+                    keep_hourly: 0,
+                },
+            },
+            statuses: None,
+            last_backup: None,
+        }
+    }
+
+    #[test(sqlx::test(fixtures("../../fixtures/election_2.sql")))]
+    async fn test_backup_during_data_entry(pool: SqlitePool) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let backup_config = BackupConfig::new(temp_dir.path().join("backups"));
+        let mut scheduled_backups = scheduled_backups(pool.clone(), backup_config.clone());
+
+        scheduled_backups.perform_check().await;
+        assert_eq!(list_local_backups(&backup_config).unwrap().len(), 1);
+        assert!(scheduled_backups.last_backup.is_some());
+
+        // the interval has not passed yet, so no new backup is created
+        scheduled_backups.perform_check().await;
+        assert_eq!(list_local_backups(&backup_config).unwrap().len(), 1);
+
+        let mut conn = pool.acquire().await.unwrap();
+        assert_eq!(
+            list_event_names(&mut conn).await.unwrap(),
+            ["DatabaseBackupCreatedAutomatically"]
+        );
+    }
+}
@@ -29,9 +29,16 @@ pub use app_error::AppError;
 pub use error::{APIError, ErrorResponse};
 #[cfg(feature = "dev-database")]
 use infra::seed_data;
-use infra::{audit_log, backup::BackupConfig, router};
+use infra::{
+    audit_log,
+    backup::{BackupConfig, RestoreResult, restore_local_backup},
+    claim_expiry::{ClaimExpiry, ClaimTimeout},
+    router,
+    scheduled_backup::ScheduledBackups,
+};
 
 use crate::{
+    api::backup::DatabaseBackupRestoredAuditData,
     app_error::{DatabaseErrorWithPath, DatabaseMigrationErrorWithPath},
     infra::audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType},
 };
@@ -60,6 +67,7 @@ pub struct AppState {
     pool: SqlitePool,
     airgap_detection: AirgapDetection,
     backup_config: BackupConfig,
+    claim_timeout: ClaimTimeout,
 }
 
 /// Start airgap detection if enabled, logging which path was taken.
@@ -73,16 +81,41 @@ fn setup_airgap_detection(pool: &SqlitePool, enable: bool) -> AirgapDetection {
     }
 }
 
-/// Log startup, start airgap detection, and build the router shared by the
-/// HTTP and HTTPS servers.
+/// Start automatic backups if a backup schedule is configured.
+fn setup_scheduled_backups(pool: &SqlitePool, backup_config: &BackupConfig) {
+    if let Some(schedule) = backup_config.schedule {
+        info!(
+            "Automatic backups are enabled, creating a backup every {} minutes during data entry...",
+            schedule.interval.as_secs() / 60
+        );
+        ScheduledBackups::start(pool.clone(), backup_config.clone(), schedule);
+    } else {
+        warn!("Automatic backups are disabled.");
+    }
+}
+
+/// Start logging abandoned data entry claims.
+fn setup_claim_expiry(pool: &SqlitePool, claim_timeout: ClaimTimeout) {
+    info!(
+        "Data entry claims expire after {} minutes of inactivity.",
+        claim_timeout.0.num_minutes()
+    );
+    ClaimExpiry::start(pool.clone(), claim_timeout);
+}
+
+/// Log startup, start airgap detection, automatic backups and claim expiry, and build the router
+/// shared by the HTTP and HTTPS servers.
 fn build_app(
     pool: &SqlitePool,
     enable_airgap_detection: bool,
     backup_config: BackupConfig,
+    claim_timeout: ClaimTimeout,
 ) -> Result<axum::Router, AppError> {
     info!("Starting Abacus (version {})", env!("ABACUS_GIT_VERSION"));
     let airgap_detection = setup_airgap_detection(pool, enable_airgap_detection);
-    router::create_router(pool.clone(), airgap_detection, backup_config)
+    setup_scheduled_backups(pool, &backup_config);
+    setup_claim_expiry(pool, claim_timeout);
+    router::create_router(pool.clone(), airgap_detection, backup_config, claim_timeout)
 }
 
 /// Close the database pool (flushing SQLite WAL/shm) and log a clean shutdown.
@@ -97,8 +130,9 @@ pub async fn start_server(
     listener: TcpListener,
     enable_airgap_detection: bool,
     backup_config: BackupConfig,
+    claim_timeout: ClaimTimeout,
 ) -> Result<(), AppError> {
-    let app = build_app(&pool, enable_airgap_detection, backup_config)?;
+    let app = build_app(&pool, enable_airgap_detection, backup_config, claim_timeout)?;
 
     warn!("TLS is disabled, serving Abacus over plain HTTP. This is not allowed in production.");
     info!("Starting Abacus on http://{}", listener.local_addr()?);
@@ -127,6 +161,7 @@ pub async fn start_server_tls(
     listener: TcpListener,
     enable_airgap_detection: bool,
     backup_config: BackupConfig,
+    claim_timeout: ClaimTimeout,
     tls_config: std::sync::Arc<rustls::ServerConfig>,
     ca: std::sync::Arc<infra::tls::CaCertificate>,
 ) -> Result<(), AppError> {
@@ -139,7 +174,7 @@ pub async fn start_server_tls(
     use hyper::http::{HeaderValue, header};
     use tower_http::set_header::SetResponseHeaderLayer;
 
-    let app = build_app(&pool, enable_airgap_detection, backup_config)?
+    let app = build_app(&pool, enable_airgap_detection, backup_config, claim_timeout)?
         .merge(infra::router::ca_router(&ca))
         .layer(SetResponseHeaderLayer::overriding(
             header::STRICT_TRANSPORT_SECURITY,
@@ -257,6 +292,26 @@ async fn log_app_started(conn: &mut SqliteConnection, db_path: &str) -> Result<(
     .map_err(DatabaseErrorWithPath::with_path(db_path))?)
 }
 
+/// Restore a database backup from the command line, logging the restore in
+/// the audit log of the restored database.
+pub async fn restore_backup(
+    pool: &SqlitePool,
+    backup_config: &BackupConfig,
+    filename: &str,
+) -> Result<RestoreResult, AppError> {
+    let result = restore_local_backup(pool, backup_config, filename).await?;
+    let mut connection = pool.acquire().await?;
+    audit_log::create(
+        &mut connection,
+        DatabaseBackupRestoredAuditData::from(&result).as_audit_event()?,
+        None,
+        None,
+        None,
+    )
+    .await?;
+    Ok(result)
+}
+
 /// Create a SQLite database if needed, then connect to it and run migrations.
 /// Return a connection pool.
 pub async fn create_sqlite_pool(
@@ -304,7 +359,10 @@ mod test {
     use tokio::net::TcpListener;
 
     use super::start_server;
-    use crate::{AppError, create_sqlite_pool, infra::backup::BackupConfig};
+    use crate::{
+        AppError, create_sqlite_pool,
+        infra::{backup::BackupConfig, claim_expiry::ClaimTimeout},
+    };
 
     pub(crate) async fn run_server_test<F, Fut>(pool: SqlitePool, test_fn: F)
     where
@@ -320,9 +378,15 @@ mod test {
         let backup_dir = tempfile::tempdir().unwrap();
         let backup_config = BackupConfig::new(backup_dir.path().to_path_buf());
         let server_task = tokio::spawn(async move {
-            start_server(pool, listener, false, backup_config)
-                .await
-                .unwrap();
+            start_server(
+                pool,
+                listener,
+                false,
+                backup_config,
+                ClaimTimeout::default(),
+            )
+            .await
+            .unwrap();
         });
 
         // Run the test
@@ -486,8 +550,8 @@ mod test {
         use tokio::{net::TcpListener, task::JoinHandle};
 
         use crate::{
-            infra::audit_log, infra::backup::BackupConfig, infra::tls::load_or_generate,
-            start_server_tls,
+            infra::audit_log, infra::backup::BackupConfig, infra::claim_expiry::ClaimTimeout,
+            infra::tls::load_or_generate, start_server_tls,
         };
 
         /// Helper to start an HTTPS server on `bind_addr` with a new CA+leaf certificate
@@ -505,9 +569,17 @@ mod test {
             let listener = TcpListener::bind(bind_addr).await.unwrap();
             let addr = listener.local_addr().unwrap();
             let task = tokio::spawn(async move {
-                start_server_tls(pool, listener, false, backup_config, server_config, ca)
-                    .await
-                    .unwrap();
+                start_server_tls(
+                    pool,
+                    listener,
+                    false,
+                    backup_config,
+                    ClaimTimeout::default(),
+                    server_config,
+                    ca,
+                )
+                .await
+                .unwrap();
             });
             (addr, ca_pem, task)
         }
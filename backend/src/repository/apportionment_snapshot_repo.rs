@@ -0,0 +1,180 @@
+use chrono::{DateTime, Utc};
+use sqlx::{SqliteConnection, query_as, types::Json};
+
+use crate::{
+    domain::{
+        apportionment_snapshot::{
+            ApportionmentSnapshot, ApportionmentSnapshotId, ApportionmentSnapshotInput,
+            ApportionmentSnapshotOutput, ApportionmentSnapshotSummary,
+        },
+        committee_session::CommitteeSessionId,
+        election::ElectionId,
+    },
+    repository::user_repo::UserId,
+};
+
+struct ApportionmentSnapshotRow {
+    id: ApportionmentSnapshotId,
+    committee_session_id: CommitteeSessionId,
+    version: u32,
+    input: Json<ApportionmentSnapshotInput>,
+    output: Json<ApportionmentSnapshotOutput>,
+    created_by: UserId,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ApportionmentSnapshotRow> for ApportionmentSnapshot {
+    fn from(row: ApportionmentSnapshotRow) -> Self {
+        Self {
+            summary: ApportionmentSnapshotSummary {
+                id: row.id,
+                committee_session_id: row.committee_session_id,
+                version: row.version,
+                created_by: row.created_by,
+                created_at: row.created_at,
+            },
+            input: row.input.0,
+            output: row.output.0,
+        }
+    }
+}
+
+/// List the snapshots of all committee sessions of an election, in the order they were created
+pub async fn list(
+    conn: &mut SqliteConnection,
+    election_id: ElectionId,
+) -> Result<Vec<ApportionmentSnapshotSummary>, sqlx::Error> {
+    query_as!(
+        ApportionmentSnapshotSummary,
+        r#"
+        SELECT
+            s.id AS "id: _",
+            s.committee_session_id AS "committee_session_id: _",
+            s.version AS "version: _",
+            s.created_by AS "created_by: _",
+            s.created_at AS "created_at: _"
+        FROM apportionment_snapshots AS s
+        JOIN committee_sessions AS c ON c.id = s.committee_session_id
+        WHERE c.election_id = $1
+        ORDER BY s.version
+        "#,
+        election_id
+    )
+    .fetch_all(conn)
+    .await
+}
+
+/// Get a snapshot of an election by its version
+pub async fn get_by_version(
+    conn: &mut SqliteConnection,
+    election_id: ElectionId,
+    version: u32,
+) -> Result<ApportionmentSnapshot, sqlx::Error> {
+    let row = query_as!(
+        ApportionmentSnapshotRow,
+        r#"
+        SELECT
+            s.id AS "id: _",
+            s.committee_session_id AS "committee_session_id: _",
+            s.version AS "version: _",
+            s.input AS "input: _",
+            s.output AS "output: _",
+            s.created_by AS "created_by: _",
+            s.created_at AS "created_at: _"
+        FROM apportionment_snapshots AS s
+        JOIN committee_sessions AS c ON c.id = s.committee_session_id
+        WHERE c.election_id = $1 AND s.version = $2
+        "#,
+        election_id,
+        version
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(row.into())
+}
+
+/// Get the most recent snapshot of an election, if there is any
+pub async fn get_latest(
+    conn: &mut SqliteConnection,
+    election_id: ElectionId,
+) -> Result<Option<ApportionmentSnapshot>, sqlx::Error> {
+    let row = query_as!(
+        ApportionmentSnapshotRow,
+        r#"
+        SELECT
+            s.id AS "id: _",
+            s.committee_session_id AS "committee_session_id: _",
+            s.version AS "version: _",
+            s.input AS "input: _",
+            s.output AS "output: _",
+            s.created_by AS "created_by: _",
+            s.created_at AS "created_at: _"
+        FROM apportionment_snapshots AS s
+        JOIN committee_sessions AS c ON c.id = s.committee_session_id
+        WHERE c.election_id = $1
+        ORDER BY s.version DESC
+        LIMIT 1
+        "#,
+        election_id
+    )
+    .fetch_optional(conn)
+    .await?;
+
+    Ok(row.map(ApportionmentSnapshot::from))
+}
+
+/// Create a snapshot for a committee session, with the next version number of its election.
+/// Snapshots are never updated or deleted.
+pub async fn create(
+    conn: &mut SqliteConnection,
+    committee_session_id: CommitteeSessionId,
+    created_by: UserId,
+    input: &ApportionmentSnapshotInput,
+    output: &ApportionmentSnapshotOutput,
+) -> Result<ApportionmentSnapshot, sqlx::Error> {
+    let input = Json(input);
+    let output = Json(output);
+    let created_at = Utc::now();
+    let row = query_as!(
+        ApportionmentSnapshotRow,
+        r#"
+        INSERT INTO apportionment_snapshots (
+            committee_session_id,
+            version,
+            input,
+            output,
+            created_by,
+            created_at
+        )
+        SELECT
+            $1,
+            COALESCE(MAX(s.version), 0) + 1,
+            $2,
+            $3,
+            $4,
+            $5
+        FROM committee_sessions AS c
+        LEFT JOIN committee_sessions AS other ON other.election_id = c.election_id
+        LEFT JOIN apportionment_snapshots AS s ON s.committee_session_id = other.id
+        WHERE c.id = $1
+        RETURNING
+            id AS "id!: _",
+            committee_session_id AS "committee_session_id: _",
+            version AS "version: _",
+            input AS "input: _",
+            output AS "output: _",
+            created_by AS "created_by: _",
+            created_at AS "created_at: _"
+        "#,
+        committee_session_id,
+        input,
+        output,
+        created_by,
+        created_at,
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(row.into())
+}
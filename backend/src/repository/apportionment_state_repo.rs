@@ -59,7 +59,7 @@ mod tests {
             DisplayFraction, HighestAverageResidualSeatDrawingLots, ListDrawingLotsVariant,
             ListDrawn,
         },
-        apportionment_state::{DeceasedCandidate, DrawingLotsRequired},
+        apportionment_state::{DrawingLotsRequired, ExcludedCandidate},
         election::{CandidateNumber, PGNumber},
     };
 
@@ -76,8 +76,8 @@ mod tests {
 
         let scenarios = [
             ApportionmentState::Uninitialised,
-            ApportionmentState::RegisteringDeceasedCandidates {
-                deceased_candidates: vec![DeceasedCandidate::from(4, 4)],
+            ApportionmentState::RegisteringExcludedCandidates {
+                excluded_candidates: vec![ExcludedCandidate::deceased(4, 4)],
             },
             ApportionmentState::DrawingLots {
                 drawing_lots_required: DrawingLotsRequired::ListDrawingLotsRequired(
@@ -94,7 +94,7 @@ mod tests {
                         },
                     ),
                 ),
-                deceased_candidates: vec![DeceasedCandidate::from(4, 4)],
+                excluded_candidates: vec![ExcludedCandidate::deceased(4, 4)],
                 lists_drawn: vec![ListDrawn {
                     variant: ListDrawingLotsVariant::AbsoluteMajorityHighestAverage(
                         AbsoluteMajorityDrawingLots {
@@ -107,7 +107,7 @@ mod tests {
                 candidates_drawn: vec![],
             },
             ApportionmentState::Finalised {
-                deceased_candidates: vec![DeceasedCandidate::from(4, 4)],
+                excluded_candidates: vec![ExcludedCandidate::deceased(4, 4)],
                 lists_drawn: vec![],
                 candidates_drawn: vec![CandidateDrawn {
                     variant: CandidateDrawingLotsVariant {
@@ -0,0 +1,149 @@
+use chrono::Utc;
+use sqlx::{SqliteConnection, query, query_as};
+
+use crate::{
+    domain::{
+        committee_session::CommitteeSessionId, data_entry::DataEntryId,
+        data_entry_assignment::DataEntryAssignment, entry_number::EntryNumber,
+    },
+    repository::user_repo::UserId,
+};
+
+/// List the assignments of the data entries of the polling stations and sub-committees
+/// of a committee session
+pub async fn list(
+    conn: &mut SqliteConnection,
+    committee_session_id: CommitteeSessionId,
+) -> Result<Vec<DataEntryAssignment>, sqlx::Error> {
+    query_as!(
+        DataEntryAssignment,
+        r#"
+        SELECT
+            a.data_entry_id AS "data_entry_id: _",
+            a.entry_number AS "entry_number: _",
+            a.user_id AS "user_id: _",
+            a.assigned_by AS "assigned_by: _",
+            a.assigned_at AS "assigned_at: _"
+        FROM data_entry_assignments AS a
+        WHERE a.data_entry_id IN (
+            SELECT data_entry_id FROM polling_stations WHERE committee_session_id = $1
+            UNION
+            SELECT data_entry_id FROM sub_committees WHERE committee_session_id = $1
+        )
+        ORDER BY a.data_entry_id, a.entry_number
+        "#,
+        committee_session_id
+    )
+    .fetch_all(conn)
+    .await
+}
+
+/// List all assignments of the entries of a data entry
+pub async fn list_for_data_entry(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+) -> Result<Vec<DataEntryAssignment>, sqlx::Error> {
+    query_as!(
+        DataEntryAssignment,
+        r#"
+        SELECT
+            data_entry_id AS "data_entry_id: _",
+            entry_number AS "entry_number: _",
+            user_id AS "user_id: _",
+            assigned_by AS "assigned_by: _",
+            assigned_at AS "assigned_at: _"
+        FROM data_entry_assignments
+        WHERE data_entry_id = $1
+        ORDER BY entry_number
+        "#,
+        data_entry_id
+    )
+    .fetch_all(conn)
+    .await
+}
+
+/// Get the assignment of an entry of a data entry, if it is assigned
+pub async fn get(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+) -> Result<Option<DataEntryAssignment>, sqlx::Error> {
+    query_as!(
+        DataEntryAssignment,
+        r#"
+        SELECT
+            data_entry_id AS "data_entry_id: _",
+            entry_number AS "entry_number: _",
+            user_id AS "user_id: _",
+            assigned_by AS "assigned_by: _",
+            assigned_at AS "assigned_at: _"
+        FROM data_entry_assignments
+        WHERE data_entry_id = $1 AND entry_number = $2
+        "#,
+        data_entry_id,
+        entry_number
+    )
+    .fetch_optional(conn)
+    .await
+}
+
+/// Assign an entry of a data entry to a typist, replacing any existing assignment of that entry
+pub async fn assign(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+    user_id: UserId,
+    assigned_by: UserId,
+) -> Result<DataEntryAssignment, sqlx::Error> {
+    let assigned_at = Utc::now();
+    query_as!(
+        DataEntryAssignment,
+        r#"
+        INSERT INTO data_entry_assignments (
+            data_entry_id,
+            entry_number,
+            user_id,
+            assigned_by,
+            assigned_at
+        )
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (data_entry_id, entry_number) DO UPDATE SET
+            user_id = excluded.user_id,
+            assigned_by = excluded.assigned_by,
+            assigned_at = excluded.assigned_at
+        RETURNING
+            data_entry_id AS "data_entry_id!: _",
+            entry_number AS "entry_number!: _",
+            user_id AS "user_id: _",
+            assigned_by AS "assigned_by: _",
+            assigned_at AS "assigned_at: _"
+        "#,
+        data_entry_id,
+        entry_number,
+        user_id,
+        assigned_by,
+        assigned_at,
+    )
+    .fetch_one(conn)
+    .await
+}
+
+/// Remove the assignment of an entry of a data entry, returning whether it was assigned
+pub async fn unassign(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    entry_number: EntryNumber,
+) -> Result<bool, sqlx::Error> {
+    let result = query!(
+        r#"
+        DELETE FROM data_entry_assignments
+        WHERE data_entry_id = $1 AND entry_number = $2
+        "#,
+        data_entry_id,
+        entry_number
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -83,7 +83,7 @@ pub async fn update(
         DataEntryRow,
         r#"
             UPDATE data_entries
-            SET state = ?, updated_at = CURRENT_TIMESTAMP
+            SET state = ?, updated_at = CURRENT_TIMESTAMP, claim_expired_at = NULL
             WHERE id = ?
             RETURNING
                 id,
@@ -97,6 +97,47 @@ pub async fn update(
     .await
 }
 
+/// List the data entries with an entry in progress of which the expired claim was not logged
+pub async fn list_unexpired_in_progress(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<DataEntryRow>, sqlx::Error> {
+    query_as!(
+        DataEntryRow,
+        r#"
+            SELECT
+                id AS "id!: _",
+                state,
+                updated_at
+            FROM data_entries
+            WHERE json_extract(state, '$.status') IN (
+                'FirstEntryInProgress',
+                'SecondEntryInProgress',
+                'FirstEntryCorrection',
+                'SecondEntryCorrection',
+                'EntriesArbitration'
+            )
+            AND claim_expired_at IS NULL
+            ORDER BY id
+        "#,
+    )
+    .fetch_all(conn)
+    .await
+}
+
+/// Record that the expired claim on the entry in progress was logged
+pub async fn mark_claim_expired(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+) -> Result<(), sqlx::Error> {
+    query!(
+        "UPDATE data_entries SET claim_expired_at = CURRENT_TIMESTAMP WHERE id = ?",
+        data_entry_id
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
 /// Find which entity (polling station or sub committee) is the source of a data entry.
 /// We expect to only find one match: either a polling station or a sub committee, not both.
 pub async fn resolve_source(
@@ -795,8 +836,10 @@ mod tests {
             .unwrap();
 
             let state = DataEntryStatus::Definitive(data_entry::Definitive {
-                first_entry_user_id: UserId::from(5),
-                second_entry_user_id: UserId::from(6),
+                first_entry_user_id: Some(UserId::from(5)),
+                second_entry_user_id: Some(UserId::from(6)),
+                third_entry_user_id: None,
+                imported_by_user_id: None,
                 finished_at: chrono::Utc::now(),
                 finalised_with_warnings: false,
                 results: create_test_results(10),
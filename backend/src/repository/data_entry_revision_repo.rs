@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use sqlx::{SqliteConnection, query_as, types::Json};
+
+use crate::{
+    domain::{
+        data_entry::{DataEntryId, DataEntryStatus, DataEntryStatusName},
+        data_entry_revision::{
+            DataEntryRevision, DataEntryRevisionAction, DataEntryRevisionId,
+            DataEntryRevisionSummary,
+        },
+    },
+    repository::user_repo::UserId,
+};
+
+struct DataEntryRevisionRow {
+    id: DataEntryRevisionId,
+    data_entry_id: DataEntryId,
+    revision: u32,
+    action: DataEntryRevisionAction,
+    status: DataEntryStatusName,
+    state: Json<DataEntryStatus>,
+    created_by: UserId,
+    created_at: DateTime<Utc>,
+}
+
+impl From<DataEntryRevisionRow> for DataEntryRevision {
+    fn from(row: DataEntryRevisionRow) -> Self {
+        Self {
+            summary: DataEntryRevisionSummary {
+                id: row.id,
+                data_entry_id: row.data_entry_id,
+                revision: row.revision,
+                action: row.action,
+                status: row.status,
+                created_by: row.created_by,
+                created_at: row.created_at,
+            },
+            state: row.state.0,
+        }
+    }
+}
+
+/// List the revisions of a data entry, in the order they were created
+pub async fn list(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+) -> Result<Vec<DataEntryRevisionSummary>, sqlx::Error> {
+    query_as!(
+        DataEntryRevisionSummary,
+        r#"
+        SELECT
+            id AS "id: _",
+            data_entry_id AS "data_entry_id: _",
+            revision AS "revision: _",
+            action AS "action: _",
+            status AS "status: _",
+            created_by AS "created_by: _",
+            created_at AS "created_at: _"
+        FROM data_entry_revisions
+        WHERE data_entry_id = $1
+        ORDER BY revision
+        "#,
+        data_entry_id
+    )
+    .fetch_all(conn)
+    .await
+}
+
+/// Get a revision of a data entry by its revision number
+pub async fn get(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    revision: u32,
+) -> Result<DataEntryRevision, sqlx::Error> {
+    let row = query_as!(
+        DataEntryRevisionRow,
+        r#"
+        SELECT
+            id AS "id: _",
+            data_entry_id AS "data_entry_id: _",
+            revision AS "revision: _",
+            action AS "action: _",
+            status AS "status: _",
+            state AS "state: _",
+            created_by AS "created_by: _",
+            created_at AS "created_at: _"
+        FROM data_entry_revisions
+        WHERE data_entry_id = $1 AND revision = $2
+        "#,
+        data_entry_id,
+        revision
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(row.into())
+}
+
+/// Create a revision of a data entry with the next revision number.
+/// Revisions are never updated, and only deleted together with their data entry.
+pub async fn create(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    action: DataEntryRevisionAction,
+    state: &DataEntryStatus,
+    created_by: UserId,
+) -> Result<DataEntryRevision, sqlx::Error> {
+    let status = state.status_name();
+    let state = Json(state);
+    let created_at = Utc::now();
+    let row = query_as!(
+        DataEntryRevisionRow,
+        r#"
+        INSERT INTO data_entry_revisions (
+            data_entry_id,
+            revision,
+            action,
+            status,
+            state,
+            created_by,
+            created_at
+        )
+        SELECT
+            $1,
+            COALESCE(MAX(revision), 0) + 1,
+            $2,
+            $3,
+            $4,
+            $5,
+            $6
+        FROM data_entry_revisions
+        WHERE data_entry_id = $1
+        RETURNING
+            id AS "id!: _",
+            data_entry_id AS "data_entry_id: _",
+            revision AS "revision: _",
+            action AS "action: _",
+            status AS "status: _",
+            state AS "state: _",
+            created_by AS "created_by: _",
+            created_at AS "created_at: _"
+        "#,
+        data_entry_id,
+        action,
+        status,
+        state,
+        created_by,
+        created_at,
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(row.into())
+}
@@ -4,10 +4,13 @@ use sqlx::{
     sqlite::SqliteValueRef, types::Json,
 };
 
-use crate::domain::election::{
-    CommitteeCategory, CommitteeDistrict, Election, ElectionCategory, ElectionDomain, ElectionId,
-    ElectionSubCategory, ElectionWithPoliticalGroups, NewElection, RegisteredPoliticalGroup,
-    VoteCountingMethod,
+use crate::domain::{
+    election::{
+        CommitteeCategory, CommitteeDistrict, Election, ElectionCategory, ElectionDomain,
+        ElectionId, ElectionSubCategory, ElectionWithPoliticalGroups, NewElection,
+        ReferendumQuestion, RegisteredPoliticalGroup, VoteCountingMethod,
+    },
+    models::ModelLanguage,
 };
 
 pub async fn list(
@@ -26,6 +29,8 @@ pub async fn list(
             authority_id,
             authority_name,
             authority_region,
+            frisian_export_allowed,
+            model_language,
             district,
             domain,
             category,
@@ -55,6 +60,8 @@ pub struct ElectionRow {
     pub authority_id: String,
     pub authority_name: String,
     pub authority_region: String,
+    pub frisian_export_allowed: bool,
+    pub model_language: ModelLanguage,
     pub district: CommitteeDistrict,
     pub domain: Option<ElectionDomain>,
     pub category: ElectionCategory,
@@ -64,6 +71,7 @@ pub struct ElectionRow {
     pub election_date: NaiveDate,
     pub nomination_date: NaiveDate,
     pub political_groups: Json<Vec<RegisteredPoliticalGroup>>,
+    pub referendum: Option<Json<ReferendumQuestion>>,
 }
 
 impl From<ElectionRow> for ElectionWithPoliticalGroups {
@@ -78,6 +86,8 @@ impl From<ElectionRow> for ElectionWithPoliticalGroups {
             authority_id: row.authority_id,
             authority_name: row.authority_name,
             authority_region: row.authority_region,
+            frisian_export_allowed: row.frisian_export_allowed,
+            model_language: row.model_language,
             district: row.district,
             domain: row.domain,
             category: row.category,
@@ -92,6 +102,7 @@ impl From<ElectionRow> for ElectionWithPoliticalGroups {
                 .into_iter()
                 .map(|pg| pg.into())
                 .collect(),
+            referendum: row.referendum.map(|referendum| referendum.0),
         }
     }
 }
@@ -157,6 +168,8 @@ pub async fn get(
             authority_id,
             authority_name,
             authority_region,
+            frisian_export_allowed,
+            model_language,
             district,
             domain,
             category,
@@ -165,7 +178,8 @@ pub async fn get(
             number_of_voters,
             election_date,
             nomination_date,
-            political_groups
+            political_groups,
+            referendum
         FROM elections
         WHERE id = ?
         "#,
@@ -184,6 +198,7 @@ pub async fn create(
     let political_groups = Json(election.political_groups);
     let district = Json(election.district);
     let domain = Json(election.domain);
+    let referendum = election.referendum.map(Json);
     let row = query_as!(
         ElectionRow,
         r#"
@@ -196,6 +211,7 @@ pub async fn create(
             authority_id,
             authority_name,
             authority_region,
+            frisian_export_allowed,
             district,
             domain,
             category,
@@ -204,8 +220,9 @@ pub async fn create(
             number_of_voters,
             election_date,
             nomination_date,
-            political_groups
-        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            political_groups,
+            referendum
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         RETURNING
             id,
             name,
@@ -216,6 +233,8 @@ pub async fn create(
             authority_id,
             authority_name,
             authority_region,
+            frisian_export_allowed,
+            model_language,
             district,
             domain,
             category,
@@ -224,7 +243,8 @@ pub async fn create(
             number_of_voters,
             election_date,
             nomination_date,
-            political_groups
+            political_groups,
+            referendum
         "#,
         election.name,
         election.committee_category,
@@ -234,6 +254,7 @@ pub async fn create(
         election.authority_id,
         election.authority_name,
         election.authority_region,
+        election.frisian_export_allowed,
         district,
         domain,
         election.category,
@@ -243,6 +264,7 @@ pub async fn create(
         election.election_date,
         election.nomination_date,
         political_groups,
+        referendum,
     )
     .fetch_one(conn)
     .await?;
@@ -270,6 +292,8 @@ pub async fn change_number_of_voters(
             authority_id,
             authority_name,
             authority_region,
+            frisian_export_allowed,
+            model_language,
             district,
             domain,
             category,
@@ -285,3 +309,42 @@ pub async fn change_number_of_voters(
     .fetch_one(conn)
     .await
 }
+
+pub async fn change_model_language(
+    conn: &mut SqliteConnection,
+    election_id: ElectionId,
+    model_language: ModelLanguage,
+) -> Result<Election, sqlx::Error> {
+    query_as!(
+        Election,
+        r#"
+        UPDATE elections
+        SET model_language = ?
+        WHERE id = ?
+        RETURNING
+            id,
+            name,
+            committee_category,
+            counting_method,
+            election_id,
+            location,
+            authority_id,
+            authority_name,
+            authority_region,
+            frisian_export_allowed,
+            model_language,
+            district,
+            domain,
+            category,
+            sub_category,
+            number_of_seats,
+            number_of_voters,
+            election_date,
+            nomination_date
+        "#,
+        model_language,
+        election_id,
+    )
+    .fetch_one(conn)
+    .await
+}
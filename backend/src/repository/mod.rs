@@ -1,7 +1,10 @@
+pub mod apportionment_snapshot_repo;
 pub mod apportionment_state_repo;
 pub mod committee_session_repo;
 mod common;
+pub mod data_entry_assignment_repo;
 pub mod data_entry_repo;
+pub mod data_entry_revision_repo;
 pub mod election_repo;
 pub mod file_repo;
 pub mod investigation_repo;
@@ -9,3 +12,4 @@ pub mod polling_station_repo;
 pub mod session_repo;
 pub mod sub_committee_repo;
 pub mod user_repo;
+pub mod vacancy_repo;
@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use sqlx::{SqliteConnection, query_as, types::Json};
+
+use crate::domain::{
+    committee_session::CommitteeSessionId,
+    election::{CandidateNumber, PGNumber},
+    vacancy::{SkippedCandidate, Vacancy, VacancyId, VacancyReason},
+};
+
+struct VacancyRow {
+    id: VacancyId,
+    committee_session_id: CommitteeSessionId,
+    pg_number: PGNumber,
+    candidate_number: CandidateNumber,
+    reason: VacancyReason,
+    successor_candidate_number: Option<CandidateNumber>,
+    skipped_candidates: Json<Vec<SkippedCandidate>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<VacancyRow> for Vacancy {
+    fn from(row: VacancyRow) -> Self {
+        Self {
+            id: row.id,
+            committee_session_id: row.committee_session_id,
+            pg_number: row.pg_number,
+            candidate_number: row.candidate_number,
+            reason: row.reason,
+            successor_candidate_number: row.successor_candidate_number,
+            skipped_candidates: row.skipped_candidates.0,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// List all vacancies for a committee session, in the order they were registered
+pub async fn list(
+    conn: &mut SqliteConnection,
+    committee_session_id: CommitteeSessionId,
+) -> Result<Vec<Vacancy>, sqlx::Error> {
+    let rows = query_as!(
+        VacancyRow,
+        r#"
+        SELECT
+            id AS "id: _",
+            committee_session_id AS "committee_session_id: _",
+            pg_number AS "pg_number: _",
+            candidate_number AS "candidate_number: _",
+            reason AS "reason: _",
+            successor_candidate_number AS "successor_candidate_number: _",
+            skipped_candidates AS "skipped_candidates: _",
+            created_at AS "created_at: _"
+        FROM vacancies
+        WHERE committee_session_id = $1
+        ORDER BY id
+        "#,
+        committee_session_id
+    )
+    .fetch_all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(Vacancy::from).collect())
+}
+
+/// Get a single vacancy of a committee session
+pub async fn get(
+    conn: &mut SqliteConnection,
+    committee_session_id: CommitteeSessionId,
+    id: VacancyId,
+) -> Result<Vacancy, sqlx::Error> {
+    let row = query_as!(
+        VacancyRow,
+        r#"
+        SELECT
+            id AS "id: _",
+            committee_session_id AS "committee_session_id: _",
+            pg_number AS "pg_number: _",
+            candidate_number AS "candidate_number: _",
+            reason AS "reason: _",
+            successor_candidate_number AS "successor_candidate_number: _",
+            skipped_candidates AS "skipped_candidates: _",
+            created_at AS "created_at: _"
+        FROM vacancies
+        WHERE committee_session_id = $1 AND id = $2
+        "#,
+        committee_session_id,
+        id
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(row.into())
+}
+
+/// Create a vacancy with its designated successor
+pub async fn create(
+    conn: &mut SqliteConnection,
+    committee_session_id: CommitteeSessionId,
+    pg_number: PGNumber,
+    candidate_number: CandidateNumber,
+    reason: VacancyReason,
+    successor_candidate_number: Option<CandidateNumber>,
+    skipped_candidates: &[SkippedCandidate],
+) -> Result<Vacancy, sqlx::Error> {
+    let skipped_candidates = Json(skipped_candidates);
+    let created_at = Utc::now();
+    let row = query_as!(
+        VacancyRow,
+        r#"
+        INSERT INTO vacancies (
+            committee_session_id,
+            pg_number,
+            candidate_number,
+            reason,
+            successor_candidate_number,
+            skipped_candidates,
+            created_at
+        ) VALUES (?, ?, ?, ?, ?, ?, ?)
+        RETURNING
+            id AS "id!: _",
+            committee_session_id AS "committee_session_id: _",
+            pg_number AS "pg_number: _",
+            candidate_number AS "candidate_number: _",
+            reason AS "reason: _",
+            successor_candidate_number AS "successor_candidate_number: _",
+            skipped_candidates AS "skipped_candidates: _",
+            created_at AS "created_at: _"
+        "#,
+        committee_session_id,
+        pg_number,
+        candidate_number,
+        reason,
+        successor_candidate_number,
+        skipped_candidates,
+        created_at,
+    )
+    .fetch_one(conn)
+    .await?;
+
+    Ok(row.into())
+}
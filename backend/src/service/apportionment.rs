@@ -6,15 +6,21 @@ use crate::{
     APIError,
     api::apportionment::{
         ApportionmentApiError, ApportionmentInputData, ElectionApportionmentResponse,
-        map_candidate_nomination, map_seat_assignment,
+        map_candidate_nomination, map_seat_assignment, map_seat_margins,
     },
     domain::{
         apportionment::{
             AbsoluteMajorityDrawingLots, ApportionmentWarning, CandidateDrawingLotsVariant,
             HighestAverageResidualSeatDrawingLots, LargestRemainderResidualSeatDrawingLots,
-            ListAverage, ListDrawingLotsVariant, ListRemainder, SeatAssignment,
+            ListAverage, ListDrawingLotsVariant, ListRemainder, SeatAssignment, SeatMargins,
+        },
+        apportionment_snapshot::{
+            ApportionmentSnapshot, ApportionmentSnapshotDiff, ApportionmentSnapshotId,
+            ApportionmentSnapshotInput, ApportionmentSnapshotOutput,
+        },
+        apportionment_state::{
+            ApportionmentState, ApportionmentStateError, DrawingLotsRequired, ExcludedCandidate,
         },
-        apportionment_state::{ApportionmentState, ApportionmentStateError, DrawingLotsRequired},
         committee_session::CommitteeSessionId,
         committee_session_status::CommitteeSessionStatus,
         election::{
@@ -24,7 +30,8 @@ use crate::{
     },
     infra::audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType, AuditService},
     repository::{
-        apportionment_state_repo, committee_session_repo, data_entry_repo, election_repo,
+        apportionment_snapshot_repo, apportionment_state_repo, committee_session_repo,
+        data_entry_repo, election_repo, user_repo::UserId,
     },
     service,
 };
@@ -42,6 +49,41 @@ struct ApportionmentStateChange {
     state: String,
 }
 
+#[derive(Serialize)]
+pub struct ExcludedCandidateAuditData {
+    pub election_id: ElectionId,
+    #[serde(flatten)]
+    pub candidate: ExcludedCandidate,
+}
+
+#[derive(Serialize)]
+pub struct ExcludedCandidateAddedEvent(pub ExcludedCandidateAuditData);
+impl AsAuditEvent for ExcludedCandidateAddedEvent {
+    const EVENT_TYPE: AuditEventType = AuditEventType::ExcludedCandidateAdded;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Success;
+}
+
+#[derive(Serialize)]
+pub struct ExcludedCandidateDeletedEvent(pub ExcludedCandidateAuditData);
+impl AsAuditEvent for ExcludedCandidateDeletedEvent {
+    const EVENT_TYPE: AuditEventType = AuditEventType::ExcludedCandidateDeleted;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Info;
+}
+
+#[derive(Serialize)]
+pub struct ApportionmentSnapshotCreatedEvent(ApportionmentSnapshotAuditData);
+impl AsAuditEvent for ApportionmentSnapshotCreatedEvent {
+    const EVENT_TYPE: AuditEventType = AuditEventType::ApportionmentSnapshotCreated;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Success;
+}
+
+#[derive(Serialize)]
+struct ApportionmentSnapshotAuditData {
+    election_id: ElectionId,
+    snapshot_id: ApportionmentSnapshotId,
+    version: u32,
+}
+
 /// Checks preconditions for getting the apportionment state and returns stored state,
 /// or `ApportionmentState::Uninitialised` if there is no stored state yet,
 /// and the committee session id to which this state belongs.
@@ -222,7 +264,7 @@ pub async fn process(
     let input = ApportionmentInputData::new(
         election.number_of_seats,
         &election_totals.political_group_votes,
-        state.get_deceased_candidates(),
+        state.get_excluded_candidates(),
         state.get_lists_drawn(),
         state.get_candidates_drawn(),
     );
@@ -242,6 +284,12 @@ pub async fn process(
                     .into_iter()
                     .map(ApportionmentWarning::from)
                     .collect(),
+                explanations: output
+                    .seat_assignment
+                    .explanations()?
+                    .iter()
+                    .map(Into::into)
+                    .collect(),
             })
         }
         ApportionmentOutput::ListDrawingLotsRequired(variant, preliminary_seat_assignment) => {
@@ -263,6 +311,117 @@ pub async fn process(
     Ok(apportionment_result)
 }
 
+/// Store a completed apportionment as a new snapshot of the election and log it to the audit log.
+/// No snapshot is created if the latest snapshot was computed from the same input and has the
+/// same output, so processing the same apportionment again does not add snapshots.
+pub async fn create_snapshot(
+    conn: &mut SqliteConnection,
+    audit_service: &AuditService,
+    election_id: ElectionId,
+    created_by: UserId,
+    apportionment: &ElectionApportionmentResponse,
+) -> Result<Option<ApportionmentSnapshot>, APIError> {
+    let (committee_session_id, state) = get_state(conn, election_id).await?;
+
+    let input = ApportionmentSnapshotInput {
+        election_totals: apportionment.election_totals.clone(),
+        excluded_candidates: state.get_excluded_candidates().to_vec(),
+        lists_drawn: state.get_lists_drawn().to_vec(),
+        candidates_drawn: state.get_candidates_drawn().to_vec(),
+    };
+    let output = ApportionmentSnapshotOutput {
+        seat_assignment: apportionment.seat_assignment.clone(),
+        candidate_nomination: apportionment.candidate_nomination.clone(),
+    };
+
+    if let Some(latest) = apportionment_snapshot_repo::get_latest(conn, election_id).await?
+        && latest.has_same_result(&input, &output)
+    {
+        return Ok(None);
+    }
+
+    let snapshot = apportionment_snapshot_repo::create(
+        conn,
+        committee_session_id,
+        created_by,
+        &input,
+        &output,
+    )
+    .await?;
+
+    audit_service
+        .log(
+            conn,
+            &ApportionmentSnapshotCreatedEvent(ApportionmentSnapshotAuditData {
+                election_id,
+                snapshot_id: snapshot.summary.id,
+                version: snapshot.summary.version,
+            }),
+            None,
+        )
+        .await?;
+
+    Ok(Some(snapshot))
+}
+
+/// Compare two snapshots of an election, identified by their version
+pub async fn diff_snapshots(
+    conn: &mut SqliteConnection,
+    election_id: ElectionId,
+    from_version: u32,
+    to_version: u32,
+) -> Result<ApportionmentSnapshotDiff, APIError> {
+    let from = apportionment_snapshot_repo::get_by_version(conn, election_id, from_version).await?;
+    let to = apportionment_snapshot_repo::get_by_version(conn, election_id, to_version).await?;
+
+    Ok(from.diff(&to))
+}
+
+/// Determine how close the result of the completed apportionment was to a different outcome,
+/// see [apportionment::seat_margins]
+pub async fn seat_margins(
+    conn: &mut SqliteConnection,
+    election: &ElectionWithPoliticalGroups,
+) -> Result<SeatMargins, APIError> {
+    if election.committee_category != CommitteeCategory::CSB {
+        return Err(ApportionmentApiError::NotCSBElection.into());
+    }
+
+    let (committee_session_id, state) = service::get_apportionment_state(conn, election.id).await?;
+
+    let data_entry_results =
+        data_entry_repo::list_results_for_committee_session(conn, committee_session_id).await?;
+    let election_totals = ElectionTotals::tabulate(election, &data_entry_results)?;
+    let number_of_seats = election.number_of_seats;
+    let political_groups = election.political_groups.clone();
+
+    // The seat assignment is redone many times, so do not block the async runtime
+    let seat_margins = tokio::task::spawn_blocking(move || {
+        let input = ApportionmentInputData::new(
+            number_of_seats,
+            &election_totals.political_group_votes,
+            state.get_excluded_candidates(),
+            state.get_lists_drawn(),
+            state.get_candidates_drawn(),
+        );
+        let ApportionmentOutput::Completed(output) = apportionment::process(&input)? else {
+            return Ok(None);
+        };
+
+        let seat_margins = apportionment::seat_margins(&input, &output)?;
+
+        Ok::<_, apportionment::ApportionmentError>(Some(map_seat_margins(
+            &seat_margins,
+            &output.candidate_nomination.preference_threshold,
+            &political_groups,
+        )))
+    })
+    .await
+    .map_err(|err| APIError::StdError(Box::new(err)))??;
+
+    seat_margins.ok_or_else(|| ApportionmentApiError::ApportionmentNotCompleted.into())
+}
+
 #[cfg(test)]
 mod tests {
     use sqlx::SqlitePool;
@@ -270,7 +429,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        domain::{apportionment_state::DeceasedCandidate, committee_session::CommitteeSessionId},
+        domain::{apportionment_state::ExcludedCandidate, committee_session::CommitteeSessionId},
         error::assert_delegated,
         infra::audit_log::assert_last_event,
     };
@@ -419,16 +578,16 @@ mod tests {
             ElectionId::from(ELECTION_ID),
             |state| {
                 assert_eq!(state, ApportionmentState::Uninitialised);
-                Ok(ApportionmentState::RegisteringDeceasedCandidates {
-                    deceased_candidates: vec![DeceasedCandidate::from(4, 4)],
+                Ok(ApportionmentState::RegisteringExcludedCandidates {
+                    excluded_candidates: vec![ExcludedCandidate::deceased(4, 4)],
                 })
             },
         )
         .await
         .expect("should update state");
 
-        let expected_state = ApportionmentState::RegisteringDeceasedCandidates {
-            deceased_candidates: vec![DeceasedCandidate::from(4, 4)],
+        let expected_state = ApportionmentState::RegisteringExcludedCandidates {
+            excluded_candidates: vec![ExcludedCandidate::deceased(4, 4)],
         };
 
         assert_eq!(returned_state, expected_state);
@@ -449,7 +608,7 @@ mod tests {
             AuditEventLevel::Success,
             serde_json::json!({
                 "election_id": ELECTION_ID,
-                "state": "RegisteringDeceasedCandidates",
+                "state": "RegisteringExcludedCandidates",
             }),
         )
         .await;
@@ -1,13 +1,18 @@
 use sqlx::SqliteConnection;
 
 use crate::{
+    APIError,
     api::data_entry::ElectionStatusResponseEntry,
     domain::{
         committee_session::CommitteeSession,
-        data_entry::{DataEntryStatus, DataEntryStatusWithSource},
+        data_entry::{DataEntryId, DataEntryRow, DataEntryStatus, DataEntryStatusWithSource},
+        data_entry_revision::{DataEntryRevisionAction, DataEntryRevisionDiff},
         election::{CommitteeCategory, Election},
     },
-    repository::{polling_station_repo, sub_committee_repo},
+    repository::{
+        data_entry_repo, data_entry_revision_repo, polling_station_repo, sub_committee_repo,
+        user_repo::UserId,
+    },
 };
 
 #[derive(Debug)]
@@ -81,6 +86,33 @@ pub async fn election_statuses(
     Ok(entries.into_iter().map(map_to_response_entry).collect())
 }
 
+/// Save the new state of a data entry and record it as a new revision of the data entry
+pub async fn update(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    state: &DataEntryStatus,
+    action: DataEntryRevisionAction,
+    user_id: UserId,
+) -> Result<DataEntryRow, sqlx::Error> {
+    let data_entry = data_entry_repo::update(conn, data_entry_id, state).await?;
+    data_entry_revision_repo::create(conn, data_entry_id, action, state, user_id).await?;
+
+    Ok(data_entry)
+}
+
+/// Compare two revisions of a data entry, identified by their revision number
+pub async fn diff_revisions(
+    conn: &mut SqliteConnection,
+    data_entry_id: DataEntryId,
+    from_revision: u32,
+    to_revision: u32,
+) -> Result<DataEntryRevisionDiff, APIError> {
+    let from = data_entry_revision_repo::get(conn, data_entry_id, from_revision).await?;
+    let to = data_entry_revision_repo::get(conn, data_entry_id, to_revision).await?;
+
+    Ok(from.diff(&to))
+}
+
 /// Create a data entry in the Definitive state with given results
 #[cfg(test)]
 pub async fn create_definitive_data_entry(
@@ -94,8 +126,10 @@ pub async fn create_definitive_data_entry(
     };
 
     let state = DataEntryStatus::Definitive(Definitive {
-        first_entry_user_id: UserId::from(5),
-        second_entry_user_id: UserId::from(6),
+        first_entry_user_id: Some(UserId::from(5)),
+        second_entry_user_id: Some(UserId::from(6)),
+        third_entry_user_id: None,
+        imported_by_user_id: None,
         finished_at: chrono::Utc::now(),
         finalised_with_warnings: false,
         results: results.clone(),
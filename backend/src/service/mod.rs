@@ -4,10 +4,13 @@ mod data_entry;
 mod investigation;
 mod polling_station;
 mod sub_committee;
+mod vacancy;
 
 pub use apportionment::{
-    ApportionmentResult, get_state as get_apportionment_state,
-    next_state as next_apportionment_state, process as process_apportionment,
+    ApportionmentResult, ExcludedCandidateAddedEvent, ExcludedCandidateAuditData,
+    ExcludedCandidateDeletedEvent, create_snapshot as create_apportionment_snapshot,
+    diff_snapshots as diff_apportionment_snapshots, get_state as get_apportionment_state,
+    next_state as next_apportionment_state, process as process_apportionment, seat_margins,
     update_state as update_apportionment_state,
 };
 pub use committee_session::{
@@ -16,7 +19,10 @@ pub use committee_session::{
 };
 #[cfg(test)]
 pub use data_entry::create_definitive_data_entry;
-pub use data_entry::{DataEntryServiceError, election_statuses};
+pub use data_entry::{
+    DataEntryServiceError, diff_revisions as diff_data_entry_revisions, election_statuses,
+    update as update_data_entry,
+};
 #[cfg(test)]
 pub use investigation::create_test_investigation;
 pub use polling_station::{
@@ -26,3 +32,4 @@ pub use sub_committee::{
     SubCommitteeServiceError, create as create_sub_committee,
     list_for_first_session as list_sub_committees_for_first_session,
 };
+pub use vacancy::{list as list_vacancies, register as register_vacancy};
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use apportionment::{CandidateRanking, SuccessorSkipReason as SkipReason};
+use serde::Serialize;
+use sqlx::SqliteConnection;
+
+use crate::{
+    APIError,
+    api::apportionment::{ApportionmentApiError, ElectionApportionmentResponse},
+    domain::{
+        apportionment_state::{ApportionmentState, ExcludedCandidate, ExclusionReason},
+        committee_session::CommitteeSessionId,
+        election::{CandidateNumber, ElectionId, ElectionWithPoliticalGroups},
+        vacancy::{SkippedCandidate, Vacancy, VacancyReason, VacancyRequest},
+    },
+    error::ErrorReference,
+    infra::audit_log::{AsAuditEvent, AuditEventLevel, AuditEventType, AuditService},
+    repository::vacancy_repo,
+    service::{self, ApportionmentResult},
+};
+
+#[derive(Serialize)]
+struct VacancyRegisteredAuditData {
+    election_id: ElectionId,
+    #[serde(flatten)]
+    vacancy: Vacancy,
+}
+
+impl AsAuditEvent for VacancyRegisteredAuditData {
+    const EVENT_TYPE: AuditEventType = AuditEventType::VacancyRegistered;
+    const EVENT_LEVEL: AuditEventLevel = AuditEventLevel::Success;
+}
+
+/// List the vacancies of an election, in the order they were registered
+pub async fn list(
+    conn: &mut SqliteConnection,
+    election_id: ElectionId,
+) -> Result<(CommitteeSessionId, Vec<Vacancy>), APIError> {
+    let (committee_session_id, _) = service::get_apportionment_state(conn, election_id).await?;
+    let vacancies = vacancy_repo::list(conn, committee_session_id).await?;
+    Ok((committee_session_id, vacancies))
+}
+
+/// Register a vacancy for a seat holder and designate the successor:
+/// - the apportionment must be finalised, the candidate ranking of the list is taken from it
+/// - candidates that hold a seat, vacated their seat, are excluded or declined are skipped
+/// - the vacancy is saved and logged to the audit log
+pub async fn register(
+    conn: &mut SqliteConnection,
+    audit_service: &AuditService,
+    election: &ElectionWithPoliticalGroups,
+    request: VacancyRequest,
+) -> Result<Vacancy, APIError> {
+    let (committee_session_id, state) = service::get_apportionment_state(conn, election.id).await?;
+    let ApportionmentState::Finalised {
+        excluded_candidates,
+        ..
+    } = state
+    else {
+        return Err(ApportionmentApiError::ApportionmentNotCompleted.into());
+    };
+    let ApportionmentResult::Ok(apportionment) =
+        service::process_apportionment(conn, election).await?
+    else {
+        return Err(ApportionmentApiError::ApportionmentNotCompleted.into());
+    };
+
+    let ranking = candidate_ranking(election, &apportionment, &request)?;
+    check_declined_candidates(election, &request)?;
+    let previous_vacancies = vacancy_repo::list(conn, committee_session_id).await?;
+
+    let mut unavailable = unavailable_candidates(
+        &request,
+        &apportionment,
+        &previous_vacancies,
+        &excluded_candidates,
+    );
+
+    if unavailable.get(&request.candidate_number) != Some(&SkipReason::SeatHolder) {
+        return Err(ApportionmentApiError::CandidateNotSeatHolder.into());
+    }
+    for candidate_number in &request.declined_candidates {
+        unavailable
+            .entry(*candidate_number)
+            .or_insert(SkipReason::Declined);
+    }
+
+    let succession =
+        apportionment::vacancy_successor(&ranking, request.candidate_number, &unavailable)?;
+    let skipped_candidates: Vec<SkippedCandidate> = succession
+        .skipped_candidates
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let vacancy = vacancy_repo::create(
+        conn,
+        committee_session_id,
+        request.pg_number,
+        request.candidate_number,
+        request.reason,
+        succession.successor,
+        &skipped_candidates,
+    )
+    .await?;
+
+    audit_service
+        .log(
+            conn,
+            &VacancyRegisteredAuditData {
+                election_id: election.id,
+                vacancy: vacancy.clone(),
+            },
+            None,
+        )
+        .await?;
+
+    Ok(vacancy)
+}
+
+/// The candidates that declined the appointment must be candidates on the list of the vacancy
+fn check_declined_candidates(
+    election: &ElectionWithPoliticalGroups,
+    request: &VacancyRequest,
+) -> Result<(), APIError> {
+    let candidates = election
+        .political_groups
+        .iter()
+        .find(|pg| pg.number == request.pg_number)
+        .map(|pg| pg.candidates.as_slice())
+        .unwrap_or_default();
+    match request
+        .declined_candidates
+        .iter()
+        .find(|number| !candidates.iter().any(|c| c.number == **number))
+    {
+        Some(number) => Err(APIError::BadRequest(
+            format!(
+                "Declined candidate {number} is not on list {}",
+                request.pg_number
+            ),
+            ErrorReference::InvalidData,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Collect the candidates on the list of the vacancy that cannot be designated as successor:
+/// current seat holders (including earlier successors), earlier vacated seat holders
+/// and excluded candidates
+fn unavailable_candidates(
+    request: &VacancyRequest,
+    apportionment: &ElectionApportionmentResponse,
+    previous_vacancies: &[Vacancy],
+    excluded_candidates: &[ExcludedCandidate],
+) -> HashMap<CandidateNumber, SkipReason> {
+    let mut unavailable = HashMap::new();
+    for candidate in &apportionment.candidate_nomination.chosen_candidates {
+        if candidate.list_number == request.pg_number {
+            unavailable.insert(candidate.number, SkipReason::SeatHolder);
+        }
+    }
+    for vacancy in previous_vacancies
+        .iter()
+        .filter(|v| v.pg_number == request.pg_number)
+    {
+        if let Some(successor) = vacancy.successor_candidate_number {
+            unavailable.insert(successor, SkipReason::SeatHolder);
+        }
+        let reason = match vacancy.reason {
+            VacancyReason::Resigned => SkipReason::Vacated,
+            VacancyReason::Deceased => SkipReason::Deceased,
+        };
+        unavailable.insert(vacancy.candidate_number, reason);
+    }
+    for candidate in excluded_candidates
+        .iter()
+        .filter(|c| c.pg_number == request.pg_number)
+    {
+        let reason = match candidate.reason {
+            ExclusionReason::Deceased => SkipReason::Deceased,
+            ExclusionReason::Declined => SkipReason::Declined,
+            ExclusionReason::Ineligible => SkipReason::Ineligible,
+        };
+        unavailable.insert(candidate.candidate_number, reason);
+    }
+
+    unavailable
+}
+
+/// Get the candidate ranking of the list from the apportionment result. The result only
+/// contains the ranking if it was updated, otherwise the original order of the list is used.
+fn candidate_ranking(
+    election: &ElectionWithPoliticalGroups,
+    apportionment: &ElectionApportionmentResponse,
+    request: &VacancyRequest,
+) -> Result<CandidateRanking<CandidateNumber>, APIError> {
+    let political_group = election
+        .political_groups
+        .iter()
+        .find(|pg| pg.number == request.pg_number)
+        .ok_or(ApportionmentApiError::CandidateNotSeatHolder)?;
+    let list_nomination = apportionment
+        .candidate_nomination
+        .list_candidate_nomination
+        .iter()
+        .find(|lcn| lcn.list_number == request.pg_number)
+        .ok_or(ApportionmentApiError::CandidateNotSeatHolder)?;
+
+    Ok(if list_nomination.updated_candidate_ranking.is_empty() {
+        CandidateRanking::Original(
+            political_group
+                .candidates
+                .iter()
+                .map(|c| c.number)
+                .collect(),
+        )
+    } else {
+        CandidateRanking::Updated(
+            list_nomination
+                .updated_candidate_ranking
+                .iter()
+                .map(|c| c.number)
+                .collect(),
+        )
+    })
+}
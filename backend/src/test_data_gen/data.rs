@@ -168,6 +168,21 @@ pub fn province(rng: &mut impl rand::RngExt) -> &'static str {
     PROVINCES.choose(rng).expect("Missing test data")
 }
 
+/// The public bodies in the Caribbean part of the Netherlands, with their
+/// domain id and the number of seats in their island council. These are not
+/// fictional, because the templates recognize public bodies by name.
+const PUBLIC_BODIES: &[(&str, &str, u32)] = &[
+    ("9001", "Bonaire", 9),
+    ("9002", "Sint Eustatius", 5),
+    ("9003", "Saba", 5),
+];
+
+/// Generate a random public body (domain id, name and number of seats), using
+/// the random number generator given
+pub fn public_body(rng: &mut impl rand::RngExt) -> (&'static str, &'static str, u32) {
+    *PUBLIC_BODIES.choose(rng).expect("Missing test data")
+}
+
 const WATER_AUTHORITIES: &[&str] = &[
     "Groot Heemland",
     "Vliet en Veenstreek",
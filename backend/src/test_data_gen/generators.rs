@@ -16,7 +16,8 @@ use crate::{
         election::{
             self, CandidateGender, CandidateNumber, CommitteeCategory, CommitteeDistrict,
             ElectionCategory, ElectionDomain, ElectionWithPoliticalGroups, NewElection, PGNumber,
-            RegisteredPoliticalGroup, VoteCountingMethod,
+            ReferendumQuestion, RegionCategory, RegionDetails, RegionKey, RegisteredPoliticalGroup,
+            VoteCountingMethod,
         },
         field_path::FieldPath,
         polling_station::{PollingStation, PollingStationRequest, PollingStationType},
@@ -32,6 +33,7 @@ use crate::{
             gsb_results::GSBResults,
             political_group_candidate_votes::{CandidateVotes, PoliticalGroupCandidateVotes},
             political_group_total_votes::PoliticalGroupTotalVotes,
+            referendum_results::{ReferendumResults, ReferendumVotesCounts},
             voters_counts::VotersCounts,
             votes_counts::VotesCounts,
             yes_no::YesNo,
@@ -155,15 +157,20 @@ async fn generate_csb_election_data(
     election: &ElectionWithPoliticalGroups,
     votes: Option<Vec<Vec<u32>>>,
 ) -> Result<(Vec<PollingStation>, bool), Box<dyn Error>> {
-    let data_entry_complete = if election.category == ElectionCategory::Municipal {
+    let data_entry_complete = if matches!(
+        election.category,
+        ElectionCategory::Municipal
+            | ElectionCategory::Island
+            | ElectionCategory::IslandElectoralCollege
+    ) {
         // TODO: We should use the same setup as with the import
         let number = election
             .domain
             .as_ref()
-            .expect("Municipal elections should have a domain")
+            .expect("Municipal and island elections should have a domain")
             .id
             .as_ref()
-            .expect("Municipal elections should have a domain id")
+            .expect("Municipal and island elections should have a domain id")
             .parse()
             .expect("domain_id should be numeric");
         let sub_committee_first_session = create_sub_committee(
@@ -279,6 +286,15 @@ fn format_election_name(
                 .expect("Missing test data"),
             super::data::water_authority(rng),
         ),
+        ElectionCategory::NationalParliament => return format!("Tweede Kamer {year}"),
+        ElectionCategory::EuropeanParliament => return format!("Europees Parlement {year}"),
+        ElectionCategory::Referendum => ("Referendum", locality),
+        ElectionCategory::Island => ("Eilandsraad", locality),
+        ElectionCategory::IslandElectoralCollege => ("Kiescollege", locality),
+        ElectionCategory::NonResidentElectoralCollege => {
+            return format!("Kiescollege niet-ingezetenen {year}");
+        }
+        ElectionCategory::Senate => return format!("Eerste Kamer {year}"),
     };
     format!("{election_type} {election_locality} {year}")
 }
@@ -290,9 +306,13 @@ fn generate_election(
     args: &GenerateElectionArgs,
     votes: Option<&Vec<Vec<u32>>>,
 ) -> NewElection {
-    // start by generating the political groups
+    // start by generating the political groups, referendums have none
     let mut political_groups = vec![];
-    let num_political_groups = rng.random_range(args.political_groups.clone());
+    let num_political_groups = if args.election_category == ElectionCategory::Referendum {
+        0
+    } else {
+        rng.random_range(args.political_groups.clone())
+    };
     info!("Generating {num_political_groups} political groups");
 
     for i in 0..num_political_groups {
@@ -319,11 +339,20 @@ fn generate_election(
 
     let category = args.election_category.to_eml_code();
     let year = election_date.year();
+    let mut public_body_seats = None;
     let domain = match args.election_category {
-        ElectionCategory::Municipal => ElectionDomain {
+        ElectionCategory::Municipal | ElectionCategory::Referendum => ElectionDomain {
             id: Some(super::data::domain_id(rng)),
             name: super::data::locality(rng).to_owned(),
         },
+        ElectionCategory::Island | ElectionCategory::IslandElectoralCollege => {
+            let (id, name, seats) = super::data::public_body(rng);
+            public_body_seats = Some(seats);
+            ElectionDomain {
+                id: Some(id.to_owned()),
+                name: name.to_owned(),
+            }
+        }
         ElectionCategory::Provincial => ElectionDomain {
             id: None,
             name: super::data::province(rng).to_owned(),
@@ -332,7 +361,16 @@ fn generate_election(
             id: Some(rng.random_range(1..23).to_string()),
             name: super::data::water_authority(rng).to_owned(),
         },
+        // national elections have no domain, we only use it to generate names
+        ElectionCategory::NationalParliament
+        | ElectionCategory::EuropeanParliament
+        | ElectionCategory::NonResidentElectoralCollege
+        | ElectionCategory::Senate => ElectionDomain {
+            id: None,
+            name: "Nederland".to_owned(),
+        },
     };
+    let is_national = args.election_category.is_national_election();
 
     // use the previous data to generate some identifiers and names
     let name: String = args
@@ -340,11 +378,22 @@ fn generate_election(
         .clone()
         .unwrap_or_else(|| format_election_name(rng, args.election_category, &domain.name, year));
     let cleaned_up_locality = domain.name.replace(" ", "_").replace("'", "");
-    let election_id = format!("{category}{year}_{cleaned_up_locality}");
+    let election_id = if is_national {
+        format!("{category}{year}")
+    } else {
+        format!("{category}{year}_{cleaned_up_locality}")
+    };
 
     info!("Election has name '{name}'");
 
-    let number_of_seats = rng.random_range(args.seats.clone());
+    // referendums don't assign any seats, public bodies have a fixed number of seats
+    let number_of_seats = match args.election_category {
+        ElectionCategory::Referendum => 0,
+        ElectionCategory::Island | ElectionCategory::IslandElectoralCollege => {
+            public_body_seats.expect("Public body should have been generated")
+        }
+        _ => rng.random_range(args.seats.clone()),
+    };
 
     let counting_method = match args.committee_category {
         CommitteeCategory::GSB => Some(args.counting_method.unwrap_or(VoteCountingMethod::CSO)),
@@ -356,7 +405,14 @@ fn generate_election(
         }
     };
 
-    let is_municipal = args.election_category == ElectionCategory::Municipal;
+    // referendums are held by a municipality as well, and public bodies act like one
+    let is_municipal = matches!(
+        args.election_category,
+        ElectionCategory::Municipal
+            | ElectionCategory::Referendum
+            | ElectionCategory::Island
+            | ElectionCategory::IslandElectoralCollege
+    );
 
     // only used when we generate for a GSB committee
     let gsb_committee_locality = super::data::locality(rng);
@@ -391,8 +447,27 @@ fn generate_election(
         },
         authority_name: authority_name.clone(),
         authority_region: authority_name.clone(),
-        district: CommitteeDistrict::None,
-        domain: Some(domain.clone()),
+        frisian_export_allowed: false,
+        district: if args.election_category == ElectionCategory::EuropeanParliament {
+            // European Parliament elections have a single national district
+            CommitteeDistrict::None
+        } else if is_national && args.committee_category == CommitteeCategory::GSB {
+            // GSBs in national elections are part of one of the electoral districts
+            CommitteeDistrict::Specific(RegionDetails {
+                name: super::data::locality(rng).to_owned(),
+                key: RegionKey {
+                    category: RegionCategory::ElectoralDistrict,
+                    number: Some(rng.random_range(1..=20)),
+                },
+                roman_numerals: false,
+                frisian_export_allowed: false,
+            })
+        } else if is_national {
+            CommitteeDistrict::All
+        } else {
+            CommitteeDistrict::None
+        },
+        domain: (!is_national).then(|| domain.clone()),
         election_id,
         location: if is_municipal {
             // Municipal elections take the location from their domain
@@ -416,6 +491,12 @@ fn generate_election(
         election_date,
         nomination_date,
         political_groups,
+        referendum: (args.election_category == ElectionCategory::Referendum).then(|| {
+            ReferendumQuestion::new(format!(
+                "Bent u voor of tegen het voorstel van de gemeenteraad van {}?",
+                domain.name
+            ))
+        }),
     }
 }
 
@@ -565,13 +646,21 @@ async fn generate_gsb_data_entry(
 
             let candidate_slope =
                 rng.random_range(args.candidate_distribution_slope.clone()) as f64 / 1000.0;
-            let results = Results::CSOFirstSession(generate_cso_first_session_results(
-                rng,
-                election,
-                voters_turned_out,
-                &group_weights,
-                candidate_slope,
-            ));
+            let results = if election.category == ElectionCategory::Referendum {
+                Results::Referendum(generate_referendum_results(
+                    rng,
+                    election,
+                    voters_turned_out,
+                ))
+            } else {
+                Results::CSOFirstSession(generate_cso_first_session_results(
+                    rng,
+                    election,
+                    voters_turned_out,
+                    &group_weights,
+                    candidate_slope,
+                ))
+            };
 
             // Validate the generated results to catch issues early
             let finalised_with_warnings =
@@ -596,8 +685,10 @@ async fn generate_gsb_data_entry(
             if rng.random_ratio(second_entry_chance, 100) {
                 // generate a definitive data entry
                 let state = DataEntryStatus::Definitive(Definitive {
-                    first_entry_user_id: UserId::from(5), // first typist from users in fixtures
-                    second_entry_user_id: UserId::from(6), // second typist from users in fixtures
+                    first_entry_user_id: Some(UserId::from(5)), // first typist from users in fixtures
+                    second_entry_user_id: Some(UserId::from(6)), // second typist from users in fixtures
+                    third_entry_user_id: None,
+                    imported_by_user_id: None,
                     finished_at: ts,
                     finalised_with_warnings,
                     results: results.clone(),
@@ -610,7 +701,8 @@ async fn generate_gsb_data_entry(
             } else {
                 // generate only a first data entry
                 let state = DataEntryStatus::FirstEntryFinalised(FirstEntryFinalised {
-                    first_entry_user_id: UserId::from(5), // first typist from users in fixtures
+                    first_entry_user_id: Some(UserId::from(5)), // first typist from users in fixtures
+                    imported_by_user_id: None,
                     finalised_first_entry: results.clone(),
                     first_entry_finished_at: ts,
                     finalised_with_warnings,
@@ -705,8 +797,10 @@ async fn generate_csb_data_entry(
         if rng.random_ratio(second_entry_chance, 100) {
             // generate a definitive data entry
             let state = DataEntryStatus::Definitive(Definitive {
-                first_entry_user_id: UserId::from(9), // first typist from users in fixtures
-                second_entry_user_id: UserId::from(10), // second typist from users in fixtures
+                first_entry_user_id: Some(UserId::from(9)), // first typist from users in fixtures
+                second_entry_user_id: Some(UserId::from(10)), // second typist from users in fixtures
+                third_entry_user_id: None,
+                imported_by_user_id: None,
                 finished_at: ts,
                 finalised_with_warnings,
                 results: results.clone(),
@@ -719,7 +813,8 @@ async fn generate_csb_data_entry(
         } else {
             // generate only a first data entry
             let state = DataEntryStatus::FirstEntryFinalised(FirstEntryFinalised {
-                first_entry_user_id: UserId::from(9), // first typist from users in fixtures
+                first_entry_user_id: Some(UserId::from(9)), // first typist from users in fixtures
+                imported_by_user_id: None,
                 finalised_first_entry: results.clone(),
                 first_entry_finished_at: ts,
                 finalised_with_warnings,
@@ -733,6 +828,49 @@ async fn generate_csb_data_entry(
     (generated_first_entries, generated_second_entries)
 }
 
+fn generate_referendum_results(
+    rng: &mut impl rand::RngExt,
+    election: &ElectionWithPoliticalGroups,
+    number_of_votes: u32,
+) -> ReferendumResults {
+    // generate a small percentage of blank and invalid votes
+    #[expect(clippy::cast_possible_truncation)]
+    let blank_votes = (number_of_votes as f64 * rng.random_range(0.0..0.02)) as u32;
+    #[expect(clippy::cast_possible_truncation)]
+    let invalid_votes =
+        ((number_of_votes - blank_votes) as f64 * rng.random_range(0.0..0.02)) as u32;
+    let remaining_votes = number_of_votes - blank_votes - invalid_votes;
+
+    // split the remaining votes between yes and no
+    let yes_votes = rng.random_range(0..=remaining_votes);
+
+    ReferendumResults {
+        voters_counts: VotersCounts {
+            poll_card_count: number_of_votes,
+            proxy_certificate_count: 0,
+            voter_card_count: (!election.category.is_local_election()).then_some(0),
+            total_admitted_voters_count: number_of_votes,
+        },
+        votes_counts: ReferendumVotesCounts {
+            yes_votes_count: yes_votes,
+            no_votes_count: remaining_votes - yes_votes,
+            blank_votes_count: blank_votes,
+            invalid_votes_count: invalid_votes,
+            total_votes_cast_count: number_of_votes,
+        },
+        differences_counts: DifferencesCounts {
+            compare_votes_cast_admitted_voters: DifferenceCountsCompareVotesCastAdmittedVoters {
+                admitted_voters_equal_votes_cast: true,
+                votes_cast_greater_than_admitted_voters: false,
+                votes_cast_smaller_than_admitted_voters: false,
+            },
+            more_ballots_count: 0,
+            fewer_ballots_count: 0,
+            difference_completely_accounted_for: YesNo::default(),
+        },
+    }
+}
+
 #[expect(clippy::too_many_lines)]
 fn generate_cso_first_session_results(
     rng: &mut impl rand::RngExt,
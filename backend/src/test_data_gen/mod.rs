@@ -32,7 +32,7 @@ pub struct GenerateElectionArgs {
     #[schema(nullable = false)]
     pub counting_method: Option<VoteCountingMethod>,
 
-    /// Municipal, Provincial or WaterAuthority
+    /// Municipal, Provincial, WaterAuthority, NationalParliament, EuropeanParliament, Referendum, Island, IslandElectoralCollege, NonResidentElectoralCollege or Senate
     pub election_category: ElectionCategory,
 
     /// Number of political groups to create
@@ -0,0 +1,82 @@
+#import "common/style.typ": conf
+#import "common/scripts.typ": *
+#let input = json("inputs/besluit-vacature.json")
+
+#let location_type = tr[centraal stembureau][sintraal stimburo]
+
+#show: doc => conf(
+  doc,
+  header-right: [#tr[Centraal stembureau][Sintraal stimburo] #input.election.location],
+  footer: [
+    Datum: #input.creation_date_time \
+    #tr[Besluit van het #location_type over de vervulling van een vacature][Beslút fan it #location_type oer de ferfulling fan in fakatuere]
+  ],
+)
+
+#set heading(numbering: none)
+
+#attachment_or_corrigendum_title(tr[Besluit vervulling vacature][Beslút ferfulling fakatuere])
+
+== #tr[Verkiezing][Ferkiezing]
+
+#input.election.name - #format_date(input.election.election_date)
+
+== #tr[Vacature][Fakatuere]
+
+#tr[Het #location_type heeft kennisgenomen van het vrijkomen van de zetel van:][It #location_type hat kennis naam fan it frijkommen fan de sit fan:]
+
+#light_table(
+  columns: (auto, 1fr, auto),
+  headers: tr(("Lijst", "Kandidaat", "Reden"), ("List", "Kandidaat", "Reden")),
+  values: (
+    format_political_group_name(input.list_number, input.list_name, with_prefix: "with_list_prefix"),
+    [#input.vacated_candidate.number. #candidate_name(input.vacated_candidate)],
+    if input.reason == "deceased" { tr[Overleden][Ferstoarn] } else { tr[Ontslag genomen][Ûntslach nommen] },
+  ),
+)
+
+== #tr[Aanwijzing opvolger][Oanwizing opfolger]
+
+#tr[
+  Op grond van artikel W 2 van de Kieswet wijst het #location_type als opvolger de kandidaat aan
+  die op de lijst als eerstvolgende in aanmerking komt. Daarbij wordt de rangschikking van de
+  kandidaten aangehouden zoals die bij de vaststelling van de uitslag is bepaald.
+][
+  Op grûn fan kêst W 2 fan de Kieswet wiist it #location_type as opfolger de kandidaat oan
+  dy't op de list as earstfolgjende yn oanmerking komt. Dêrby wurdt de rangskikking fan de
+  kandidaten oanhâlden sa't dy by de fêststelling fan de útslach bepaald is.
+]
+
+#if input.skipped_candidates.len() > 0 [
+  #tr[De volgende kandidaten komen niet in aanmerking:][De folgjende kandidaten komme net yn oanmerking:]
+
+  #light_table(
+    columns: (auto, 1fr, auto),
+    headers: ("Nr.", "Kandidaat", "Reden"),
+    values: input.skipped_candidates.map(skipped => (
+      str(skipped.candidate.number),
+      candidate_name(skipped.candidate),
+      if skipped.reason == "seat_holder" { tr[Heeft al een zetel][Hat al in sit] }
+      else if skipped.reason == "vacated" { tr[Zetel eerder vrijgekomen][Sit earder frijkommen] }
+      else if skipped.reason == "declined" { tr[Benoeming niet aanvaard][Beneaming net akseptearre] }
+      else if skipped.reason == "ineligible" { tr[Geschrapt van de lijst][Skrast fan de list] }
+      else { tr[Overleden][Ferstoarn] },
+    )).flatten(),
+  )
+]
+
+#if "successor" in input [
+  #emph_block[
+    #tr[Het #location_type wijst als opvolger aan:][It #location_type wiist as opfolger oan:]
+    *#input.successor.number. #candidate_name(input.successor)*
+    te #candidate_location(input.successor).
+  ]
+] else [
+  #emph_block[
+    #tr[
+      Er is op deze lijst geen kandidaat meer die in aanmerking komt. De zetel blijft onbezet.
+    ][
+      Der is op dizze list gjin kandidaat mear dy't yn oanmerking komt. De sit bliuwt ûnbeset.
+    ]
+  ]
+]
@@ -0,0 +1,104 @@
+#import "common/style.typ": conf
+#import "common/scripts.typ": *
+#let input = json("inputs/bijlage-zetelmarges.json")
+
+#let location_type = [centraal stembureau]
+
+#show: doc => conf(
+  doc,
+  header-right: [Centraal stembureau #input.election.location],
+  footer: [
+    Datum: #input.creation_date_time \
+    Bijlage bij de zetelverdeling over de marges van de uitslag
+  ],
+)
+
+#set heading(numbering: none)
+
+#let votes_or_dash(value) = if value == none [-] else [#fmt-number(value)]
+
+#let list_name(number) = {
+  let margin = input.seat_margins.list_seat_margins.find(margin => margin.list_number == number)
+  format_political_group_name(number, margin.list_name, with_prefix: "only_list_number")
+}
+
+#attachment_or_corrigendum_title([Bijlage: marges van de zetelverdeling])
+
+== Verkiezing
+
+#input.election.name - #format_date(input.election.election_date)
+
+Dit overzicht hoort bij de zetelverdeling van het #location_type en is alleen ter informatie.
+Het geeft aan hoeveel stemmen er anders hadden moeten zijn om tot een andere uitslag te komen.
+Daarbij veranderen steeds alleen de stemmen van één lijst of kandidaat, alle andere stemmen blijven
+gelijk. Een gelijke stand waarbij geloot moet worden telt niet als andere uitslag.
+
+== Zetels per lijst
+
+#table(
+  columns: (1.5fr, auto, 1fr, 1fr, 1fr),
+  stroke: (x, y) => (
+    left: if x > 0 { 0.5pt + gray },
+    top: if y > 0 { 0.5pt + gray },
+  ),
+  inset: (x: 4pt, y: 8pt),
+  table.header(
+    table.cell(stroke: none, header_text([Lijst])),
+    table.cell(stroke: none, align: right, header_text([Zetels])),
+    table.cell(stroke: none, align: right, header_text([Stemmen meer voor extra zetel])),
+    table.cell(stroke: none, header_text([Of stemmen minder voor andere lijst])),
+    table.cell(stroke: none, align: right, header_text([Stemmen minder voor verlies zetel])),
+  ),
+  table.hline(stroke: 1pt + black),
+  ..input.seat_margins.list_seat_margins.map(margin => (
+    table.cell(format_political_group_name(margin.list_number, margin.list_name, with_prefix: "with_list_prefix")),
+    table.cell(align: right, [#margin.total_seats]),
+    table.cell(align: right, votes_or_dash(margin.at("votes_to_gain_seat", default: none))),
+    table.cell(
+      if "other_list_votes_to_gain_seat" in margin [
+        #fmt-number(margin.other_list_votes_to_gain_seat.votes) (#list_name(margin.other_list_votes_to_gain_seat.list_number))
+      ] else [-],
+    ),
+    table.cell(align: right, votes_or_dash(margin.at("votes_to_lose_seat", default: none))),
+  )).flatten(),
+  table.hline(stroke: 0.5pt + gray),
+)
+
+#pagebreak(weak: true)
+
+== Voorkeursdrempel
+
+De voorkeursdrempel is #input.seat_margins.preference_threshold.percentage% van de kiesdeler:
+#format_fraction(input.seat_margins.preference_threshold.number_of_votes) stemmen. Omdat de kiesdeler
+meeverandert met het aantal stemmen, verandert ook de voorkeursdrempel mee. Per lijst met zetels staan
+hieronder de kandidaten boven de voorkeursdrempel en de kandidaat die er het dichtst onder bleef.
+
+#for list in input.seat_margins.list_preference_margins {
+  list_heading_text(format_political_group_name(list.list_number, list.list_name, with_prefix: "with_list_prefix"))
+
+  table(
+    columns: (4em, 1.5fr, 1fr, 1fr),
+    stroke: (x, y) => (
+      left: if x > 0 { 0.5pt + gray },
+      top: if y > 0 { 0.5pt + gray },
+    ),
+    inset: (x: 4pt, y: 8pt),
+    table.header(
+      table.cell(stroke: none, align: right, header_text([Nr.])),
+      table.cell(stroke: none, header_text([Naam])),
+      table.cell(stroke: none, align: right, header_text([Stemmen])),
+      table.cell(stroke: none, align: right, header_text([Marge])),
+    ),
+    table.hline(stroke: 1pt + black),
+    ..list.candidates.map(margin => (
+      table.cell(align: right, [#margin.candidate.number]),
+      table.cell([#candidate_name(margin.candidate)]),
+      table.cell(align: right, [#fmt-number(margin.votes, zero: "0")]),
+      table.cell(
+        align: right,
+        if margin.meets_preference_threshold [#fmt-number(margin.margin) minder] else [#fmt-number(margin.margin) meer],
+      ),
+    )).flatten(),
+    table.hline(stroke: 0.5pt + gray),
+  )
+}
@@ -1,11 +1,18 @@
+// Language of the model as passed by the PDF generator, either "nl" (Dutch) or "fy" (Frisian)
+#let language = sys.inputs.at("language", default: "nl")
+
+/// Select the Dutch or the Frisian variant of a text, based on the language of the model.
+/// Can be used in markup as `#tr[Dutch text][Frisian text]`.
+#let tr(nl, fy) = if language == "fy" { fy } else { nl }
+
 #let public_bodies = ("Bonaire", "Saba", "Sint Eustatius");
 #let is_municipality = (location, municipal, public_body) => if (
   location in public_bodies
 ) { public_body } else { municipal }
 
-// In the future category == "Island" will also be added
+// Matches `ElectionCategory::is_local_election`
 #let is_local_election = (category, local, other) => if (
-  category == "Municipal"
+  category in ("Municipal", "Referendum", "Island", "IslandElectoralCollege")
 ) { local } else { other }
 
 // A paragraph with a vertical line on the left
@@ -265,10 +272,10 @@
 
 /// Like empty_letterbox, but replaces the digit entry area (and the original
 /// value area when `with_original: true`) with a single merged cell containing
-/// "Niet invullen".
+/// "Niet invullen" (or its translation).
 #let no_entry_letterbox(letter, cells: 5, with_original: false, content) = {
   let stroke = 0.5pt + black
-  let no_entry_cell = grid.cell(align: center + horizon, stroke: stroke, [Niet invullen])
+  let no_entry_cell = grid.cell(align: center + horizon, stroke: stroke, tr[Niet invullen][Net ynfolje])
 
   // Note: `wide_cells` is not needed for `no_entry_letterbox` atm.
   let entry_width = cells * 2em
@@ -329,8 +336,8 @@
 #let correction_title_grid(correction_width: 8em, input_width: 8em) = {
   grid(
     columns: (correction_width, input_width, 3.5em, 1fr),
-    grid.cell(inset: 8pt, align(right, small_header_text[Oorspronkelijk])),
-    grid.cell(inset: 8pt, align(right, small_header_text[Gecorrigeerd])),
+    grid.cell(inset: 8pt, align(right, small_header_text(tr[Oorspronkelijk][Oarspronklik]))),
+    grid.cell(inset: 8pt, align(right, small_header_text(tr[Gecorrigeerd][Ferbettere]))),
     [],
     []
   )
@@ -358,29 +365,56 @@
   let dp = date.split("-")
   let date = datetime(year: int(dp.at(0)), month: int(dp.at(1)), day: int(dp.at(2)))
 
-  let weekdays = (
-    "maandag",
-    "dinsdag",
-    "woensdag",
-    "donderdag",
-    "vrijdag",
-    "zaterdag",
-    "zondag",
+  let weekdays = tr(
+    (
+      "maandag",
+      "dinsdag",
+      "woensdag",
+      "donderdag",
+      "vrijdag",
+      "zaterdag",
+      "zondag",
+    ),
+    (
+      "moandei",
+      "tiisdei",
+      "woansdei",
+      "tongersdei",
+      "freed",
+      "sneon",
+      "snein",
+    ),
   )
 
-  let months = (
-    "januari",
-    "februari",
-    "maart",
-    "april",
-    "mei",
-    "juni",
-    "juli",
-    "augustus",
-    "september",
-    "oktober",
-    "november",
-    "december",
+  let months = tr(
+    (
+      "januari",
+      "februari",
+      "maart",
+      "april",
+      "mei",
+      "juni",
+      "juli",
+      "augustus",
+      "september",
+      "oktober",
+      "november",
+      "december",
+    ),
+    (
+      "jannewaris",
+      "febrewaris",
+      "maart",
+      "april",
+      "maaie",
+      "juny",
+      "july",
+      "augustus",
+      "septimber",
+      "oktober",
+      "novimber",
+      "desimber",
+    ),
   )
 
   weekdays.at(date.weekday() - 1)
@@ -398,7 +432,7 @@
   format_date(date)
   " "
   time.slice(0, 5)
-  " uur"
+  tr(" uur", " oere")
 }
 
 #let format_fraction(fraction) = {
@@ -465,7 +499,7 @@
     if election_candidate.gender == "Male" {
       name += "(m)"
     } else if election_candidate.gender == "Female" {
-      name += "(v)"
+      name += tr("(v)", "(f)")
     } else if election_candidate.gender == "X" {
       name += "(x)"
     }
@@ -499,7 +533,7 @@
 
   let prefix = "";
   if (with_prefix == "with_list_prefix") {
-    prefix += "Lijst " + str(number) + " ";
+    prefix += tr("Lijst ", "List ") + str(number) + " ";
   } else if (with_prefix == "only_list_number") {
     prefix += str(number) + " ";
   }
@@ -613,7 +647,7 @@
   let column_index = 0;
 
   box[
-    #box(inset: (bottom: 10pt), text(size: 14pt, weight: "semibold")[Lijst #title])
+    #box(inset: (bottom: 10pt), text(size: 14pt, weight: "semibold")[#tr[Lijst][List] #title])
     #if explainer_text != none {
       box(width: 500pt, text(size: 9pt)[#explainer_text])
     }
@@ -708,7 +742,7 @@
 
         if (column_index == 2) {
           place(top + left, scope: "parent", float: true, box[
-            #box(inset: (bottom: 10pt), text(size: 14pt, weight: "semibold")[Vervolg lijst #title])
+            #box(inset: (bottom: 10pt), text(size: 14pt, weight: "semibold")[#tr[Vervolg lijst][Ferfolch list] #title])
             #if explainer_text != none {
               box(width: 500pt, text(size: 9pt)[#explainer_text])
             }
@@ -761,10 +795,10 @@
     ),
     inset: (x: 4pt, y: 8pt),
     table.header(
-      table.cell(stroke: none, header_text([Zetel])),
-      table.cell(stroke: none, header_text([Naam])),
-      table.cell(stroke: none, header_text([Woonplaats])),
-      if showVotes { table.cell(stroke: none, align: right, header_text([Aantal stemmen]))} else if showPosition { table.cell(stroke: none, align: right, header_text([Positie op lijst])) }
+      table.cell(stroke: none, header_text(tr[Zetel][Sit])),
+      table.cell(stroke: none, header_text(tr[Naam][Namme])),
+      table.cell(stroke: none, header_text(tr[Woonplaats][Wenplak])),
+      if showVotes { table.cell(stroke: none, align: right, header_text(tr[Aantal stemmen][Oantal stimmen]))} else if showPosition { table.cell(stroke: none, align: right, header_text(tr[Positie op lijst][Plak op list])) }
     ),
     table.hline(stroke: 1pt + black),
     ..columns.map((column) => {
@@ -796,9 +830,9 @@
       inset: (x: 4pt, y: 8pt),
       fill: (_, y) => if y > 1 and calc.even(y) { luma(245) },
       table.header(
-        table.cell(rowspan: 2, stroke: none, header_text([Lijst])),
-        table.cell(align: center, colspan: slice.len(), header_text([Restzetel])),
-        table.cell(rowspan: 2, header_text([Aantal\ restzetels])),
+        table.cell(rowspan: 2, stroke: none, header_text(tr[Lijst][List])),
+        table.cell(align: center, colspan: slice.len(), header_text(tr[Restzetel][Restsit])),
+        table.cell(rowspan: 2, header_text(tr[Aantal\ restzetels][Oantal\ restsitten])),
         ..slice.map((step) => {
           table.cell(rowspan: 1, str(step.residual_seat_number))
         }),
@@ -1,4 +1,4 @@
-#import "scripts.typ": emph_block
+#import "scripts.typ": emph_block, language, tr
 
 // Get the chapter defined in the current page or the last defined chapter
 #let current_chapter() = {
@@ -42,16 +42,19 @@
 
   let chapter_string = to-string(current_chapter())
 
-  if chapter_string.starts-with("Gecorrigeerde telresultaten van") {
-    return "Deel 1 - Gecorrigeerde telresultaten"
+  if chapter_string.starts-with(tr("Gecorrigeerde telresultaten van", "Ferbettere telresultaten fan")) {
+    return tr("Deel 1 - Gecorrigeerde telresultaten", "Diel 1 - Ferbettere telresultaten")
   }
 
-  if chapter_string.starts-with("Onderzoek naar telresultaten") {
-    return "Deel 1 - Verschillen met telresultaten stembureau"
+  if chapter_string.starts-with(tr("Onderzoek naar telresultaten", "Undersyk nei telresultaten")) {
+    return tr("Deel 1 - Verschillen met telresultaten stembureau", "Diel 1 - Ferskillen mei telresultaten stimburo")
   }
 
-  if chapter_string.starts-with("Gecorrigeerde telresultaten") or chapter_string.starts-with("Telresultaten"){
-    return "Deel 2 - Telresultaten"
+  if (
+    chapter_string.starts-with(tr("Gecorrigeerde telresultaten", "Ferbettere telresultaten"))
+      or chapter_string.starts-with("Telresultaten")
+  ) {
+    return tr("Deel 2 - Telresultaten", "Diel 2 - Telresultaten")
   }
 
   return current_chapter()
@@ -78,7 +81,7 @@
 // Default document styling
 #let conf(doc, header-left: none, header-right: none, footer: none, margin-bottom: 2.0cm, footer-descent: 0.6cm) = [
   #set text(
-    lang: "nl",
+    lang: language,
     region: "nl",
     font: "DM Sans",
     size: 9pt,
@@ -87,7 +90,7 @@
   #set page(
     paper: "a4",
     margin: (x: 1.5cm, top: 2.0cm, bottom: margin-bottom),
-    numbering: (current, total) => [Pagina #current van #total],
+    numbering: (current, total) => tr[Pagina #current van #total][Side #current fan #total],
     header: default_header(header-left, header-right),
     footer-descent: footer-descent,
     footer: context (
@@ -121,7 +124,7 @@
 #let document_numbering(doc) = [
   // Note: `supplement: none` removes the default "Hoofdstuk" prefix (`supplement: ""` will render an empty space)
   #set heading(numbering: "1.1", hanging-indent: 0pt, supplement: none)
-  #show heading.where(level: 1): set heading(numbering: "Deel 1.1 -", supplement: "Deel")
+  #show heading.where(level: 1): set heading(numbering: tr("Deel 1.1 -", "Diel 1.1 -"), supplement: tr("Deel", "Diel"))
   #show heading.where(level: 3): it => [
     #block(
       stroke: (left: 1pt),
@@ -170,8 +173,8 @@
     if it.level >= 4 {
       block(it.body)
     } else if it.level == 1 {
-      if it.body == [Ondertekening] {
-        [Deel #counter(heading).display(it.numbering) - #it.body]
+      if it.body == tr[Ondertekening][Ûndertekening] {
+        [#tr[Deel][Diel] #counter(heading).display(it.numbering) - #it.body]
       } else {
         [#counter(heading).display(it.numbering). #it.body]
       }
@@ -202,8 +205,8 @@
     header-right,
   ))
 
-  emph_block[Deze pagina is expres leeg]
-  [Zo komt het handtekeningen-blad altijd op een losse pagina, ook als het verslag dubbelzijdig is geprint.]
+  emph_block(tr[Deze pagina is expres leeg][Dizze side is mei opsetsin leech])
+  tr[Zo komt het handtekeningen-blad altijd op een losse pagina, ook als het verslag dubbelzijdig is geprint.][Sa komt it hântekeningeblêd altyd op in losse side, ek as it ferslach dûbelsidich printe is.]
 
   pagebreak(weak: true)
 }
@@ -3,55 +3,62 @@
 #let input = json("inputs/model-n-10-1-inlegvel.json")
 
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_id #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_id #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
 
-#let header-right = [Stembureau #input.polling_station.number]
+#let header-right = [#tr[Stembureau][Stimburo] #input.polling_station.number]
 
 #show: doc => conf(
   doc,
-  header-left: [
+  header-left: tr[
     Controles en correcties
+  ][
+    Kontrôles en korreksjes
   ],
   header-right: header-right,
-  footer: [
+  footer: tr[
     Proces-verbaal van een stembureau\
     Model N 10-1 decentrale stemopneming (versie 2027)
+  ][
+    Proses-ferbaal fan in stimburo\
+    Model N 10-1 desintrale stimopnimming (ferzje 2027)
   ]
 )
 
-#set page(numbering: (_, _) => [Later ingevoegde pagina])
+#set page(numbering: (_, _) => tr[Later ingevoegde pagina][Letter ynfoege side])
 #set heading(numbering: none)
 
-= Controles en correcties
+= #tr[Controles en correcties][Kontrôles en korreksjes]
 
-#emph_block[
+#emph_block(tr[
   Deze pagina is toegevoegd door de leden van het #location_type, *nadat er extra controles op de telresultaten van dit stembureau zijn uitgevoerd*. Voeg deze pagina toe na het voorblad van het oorspronkelijke proces-verbaal van het stembureau (N 10-1).
-]
+][
+  Dizze side is tafoege troch de leden fan it #location_type, *nei't der ekstra kontrôles op de telresultaten fan dit stimburo útfierd binne*. Foegje dizze side ta nei it foarblêd fan it oarspronklike proses-ferbaal fan it stimburo (N 10-1).
+])
 
-== Op eigen initiatief van het #location_type
+== #tr[Op eigen initiatief van het][Op eigen inisjatyf fan it] #location_type
 
-=== Waarom heeft het #location_type de telresultaten onderzocht?
+=== #tr[Waarom heeft het #location_type de telresultaten onderzocht?][Wêrom hat it #location_type de telresultaten ûndersocht?]
 
-#checkbox[Vanwege een onverklaard verschil]
-#checkbox[Vanwege (het vermoeden van) een andere fout]
+#checkbox[#tr[Vanwege een onverklaard verschil][Fanwegen in net ferklearre ferskil]]
+#checkbox[#tr[Vanwege (het vermoeden van) een andere fout][Fanwegen (it fermoeden fan) in oare flater]]
 
-=== Zijn er gecorrigeerde telresultaten?
+=== #tr[Zijn er gecorrigeerde telresultaten?][Binne der ferbettere telresultaten?]
 
-#checkbox[Nee, de oorspronkelijke telresultaten waren correct]
-#checkbox[Ja, er zijn gecorrigeerde telresultaten (de gecorrigeerde telresultaten zijn bij dit proces-verbaal gevoegd)]
+#checkbox[#tr[Nee, de oorspronkelijke telresultaten waren correct][Nee, de oarspronklike telresultaten wiene korrekt]]
+#checkbox[#tr[Ja, er zijn gecorrigeerde telresultaten (de gecorrigeerde telresultaten zijn bij dit proces-verbaal gevoegd)][Ja, der binne ferbettere telresultaten (de ferbettere telresultaten binne by dit proses-ferbaal foege)]]
 
-=== Opgesteld door het #location_type
+=== #tr[Opgesteld door het][Opsteld troch it] #location_type
 
-#textbox[Datum en tijd:]
+#textbox[#tr[Datum en tijd:][Datum en tiid:]]
 
-== Op verzoek van het centraal stembureau
+== #tr[Op verzoek van het centraal stembureau][Op fersyk fan it sintraal stimburo]
 
-=== Zijn er gecorrigeerde telresultaten?
+=== #tr[Zijn er gecorrigeerde telresultaten?][Binne der ferbettere telresultaten?]
 
-#checkbox[Nee, de oorspronkelijke telresultaten waren correct]
-#checkbox[Ja, er zijn gecorrigeerde telresultaten (de gecorrigeerde telresultaten zijn bij dit proces-verbaal gevoegd)]
+#checkbox[#tr[Nee, de oorspronkelijke telresultaten waren correct][Nee, de oarspronklike telresultaten wiene korrekt]]
+#checkbox[#tr[Ja, er zijn gecorrigeerde telresultaten (de gecorrigeerde telresultaten zijn bij dit proces-verbaal gevoegd)][Ja, der binne ferbettere telresultaten (de ferbettere telresultaten binne by dit proses-ferbaal foege)]]
 
-=== Opgesteld door het #location_type
+=== #tr[Opgesteld door het][Opsteld troch it] #location_type
 
-#textbox[Datum en tijd:]
+#textbox[#tr[Datum en tijd:][Datum en tiid:]]
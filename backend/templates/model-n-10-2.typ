@@ -7,31 +7,43 @@
 
 #let is_mobile = "polling_station_type" in input.polling_station and input.polling_station.polling_station_type == "Mobile"
 
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
-#let this_location = is_municipality[deze gemeente][dit openbaar lichaam]
-#let location = is_municipality[gemeente][openbaar lichaam]
-
-#let header-right = [Stembureau #input.polling_station.number]
-
-#show: doc => conf(doc, header-right: header-right, footer: [Proces-verbaal van een stembureau \
-  Model N 10-2 centrale stemopneming (versie 2027)])
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
+#let this_location = is_municipality(tr[deze gemeente][dizze gemeente], tr[dit openbaar lichaam][dit iepenbier lichem])
+#let location = is_municipality(tr[gemeente][gemeente], tr[openbaar lichaam][iepenbier lichem])
+
+#let header-right = [#tr[Stembureau][Stimburo] #input.polling_station.number]
+
+#show: doc => conf(
+  doc,
+  header-right: header-right,
+  footer: tr[
+    Proces-verbaal van een stembureau \
+    Model N 10-2 centrale stemopneming (versie 2027)
+  ][
+    Proses-ferbaal fan in stimburo \
+    Model N 10-2 sintrale stimopnimming (ferzje 2027)
+  ]
+)
 
 #set heading(numbering: none)
 
 #title_page(
-  [#input.election.authority_region Stembureau #input.polling_station.number #input.polling_station.name],
+  [#input.election.authority_region #header-right #input.polling_station.name],
   "",
   [#input.election.name - #format_date(input.election.election_date)],
-  [
+  tr[
     Verslag en telresultaten per lijst –
     Model N 10-2
-  ]
+  ][
+    Ferslach en telresultaten per list –
+    Model N 10-2
+  ],
 )
 
-== Details van het #if is_mobile { "mobiele stembureau" } else { "stembureau" }
+== #tr[Details van het][Details fan it] #if is_mobile { tr[mobiele stembureau][mobile stimburo] } else { tr[stembureau][stimburo] }
 
-#location_name #sym.arrow.r Stembureau #input.polling_station.number
+#location_name #sym.arrow.r #tr[Stembureau][Stimburo] #input.polling_station.number
 
 #v(0.5em)
 
@@ -45,109 +57,171 @@
 
 // *Op deze locatie waren meerdere stembureaus*
 
-== Proces-verbaal
+== #tr[Proces-verbaal][Proses-ferbaal]
 
-Elk stembureau maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het stemmen en het tellen van de stemmen is verlopen.
+#tr[
+  Elk stembureau maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het stemmen en het tellen van de stemmen is verlopen.
+][
+  Elk stimburo makket by in ferkiezing in ferslach: it proses-ferbaal. Hjiryn stiet hoe't it stimmen en it tellen fan de stimmen ferrûn is.
+]
 
-#emph_block[
+#emph_block(tr[
   In #this_location is gekozen voor *centrale stemopneming*. Het stembureau telt na het stemmen het aantal kiezers, en hoeveel stemmen elke lijst heeft gekregen. Het #location_type telt 1 of 2 dagen later de stemmen per kandidaat op een centrale tellocatie. Die telresultaten staan in het verslag van het #location_type.
-]
+][
+  Yn #this_location is keazen foar *sintrale stimopnimming*. It stimburo telt nei it stimmen it oantal kiezers, en hoefolle stimmen elke list krigen hat. It #location_type telt 1 of 2 dagen letter de stimmen per kandidaat op in sintrale tellokaasje. Dy telresultaten steane yn it ferslach fan it #location_type.
+])
 
-== Inhoudsopgave
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
 
-- Deel 1 - *Verslag van de zitting* (het verloop van het stemmen en tellen)
-- Deel 2 - *Telresultaten* van dit stembureau
-- Deel 3 - *Ondertekening* door de leden van het stembureau
+#tr[
+  - Deel 1 - *Verslag van de zitting* (het verloop van het stemmen en tellen)
+  - Deel 2 - *Telresultaten* van dit stembureau
+  - Deel 3 - *Ondertekening* door de leden van het stembureau
+][
+  - Diel 1 - *Ferslach fan de sitting* (it ferrin fan it stimmen en tellen)
+  - Diel 2 - *Telresultaten* fan dit stimburo
+  - Diel 3 - *Ûndertekening* troch de leden fan it stimburo
+]
 
 #pagebreak(weak: true)
 
 #show: doc => document_numbering(doc)
 
-= Verslag van de zitting
+= #tr[Verslag van de zitting][Ferslach fan de sitting]
 
-== Presentielijst
+== #tr[Presentielijst][Presinsjelist]
 
-#emph_block[Aanwezige leden van het stembureau]
+#emph_block(tr[Aanwezige leden van het stembureau][Oanwêzige leden fan it stimburo])
 
-De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter, lid of teller. Vink aan of iemand bij het stemmen en/of tellen aanwezig was.
+#tr[
+  De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter, lid of teller. Vink aan of iemand bij het stemmen en/of tellen aanwezig was.
+][
+  De folgjende rollen binne mooglik: foarsitter, plakferfangend foarsitter, lid of teller. Finkje oan oft immen by it stimmen en/of tellen oanwêzich wie.
+]
 
 #empty_table(
   columns: (8em, 1fr, 1fr, 1fr, 7em, 7em),
-  headers: ("Voorletters", "Achternaam", "Rol", "Aanwezig van - tot", "Aanwezig bij stemmen", "Aanwezig bij tellen"),
+  headers: tr(
+    ("Voorletters", "Achternaam", "Rol", "Aanwezig van - tot", "Aanwezig bij stemmen", "Aanwezig bij tellen"),
+    ("Foarletters", "Efternamme", "Rol", "Oanwêzich fan - oant", "Oanwêzich by stimmen", "Oanwêzich by tellen"),
+  ),
   values: ("", "", "", "-", checkbox(small: true)[], checkbox(small: true)[]),
   rows: 21,
 )
 
-== Tijdens het stemmen
+== #tr[Tijdens het stemmen][Tidens it stimmen]
 
-=== Schrijf alle *bezwaren van aanwezigen tijdens het stemmen* op.
+=== #tr[Schrijf alle *bezwaren van aanwezigen tijdens het stemmen* op.][Skriuw alle *beswieren fan oanwêzigen tidens it stimmen* op.]
 
-Bijvoorbeeld over toegankelijkheid, niet toegelaten worden of het stemgeheim.
+#tr[
+  Bijvoorbeeld over toegankelijkheid, niet toegelaten worden of het stemgeheim.
 
-Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u het er niet mee eens bent. Geef aan hoe het bezwaar door het stembureau is afgehandeld.
+  Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u het er niet mee eens bent. Geef aan hoe het bezwaar door het stembureau is afgehandeld.
+][
+  Bygelyks oer tagonklikens, net talitten wurde of it stimgeheim.
+
+  Skriuw gjin nammen of oare persoansgegevens op. Skriuw alle beswieren op, ek as jo it der net mei iens binne. Jou oan hoe't it beswier troch it stimburo ôfhannele is.
+]
 
 #empty_table(
   columns: (7em, 1fr, 1fr),
-  headers: ("Tijdstip", "Bezwaar", "Reactie stembureau"),
+  headers: tr(
+    ("Tijdstip", "Bezwaar", "Reactie stembureau"),
+    ("Tiidstip", "Beswier", "Reaksje stimburo"),
+  ),
   values: ("", "", ""),
   rows: 27,
 )
 
 #pagebreak(weak: true)
 
-=== Schrijf gedurende de dag alles op wat tijdens het tellen van de stemmen *verschillen tussen het aantal toegelaten kiezers en de uitgebrachte stemmen* kan verklaren.
+=== #tr[
+  Schrijf gedurende de dag alles op wat tijdens het tellen van de stemmen *verschillen tussen het aantal toegelaten kiezers en de uitgebrachte stemmen* kan verklaren.
+][
+  Skriuw yn de rin fan de dei alles op wat by it tellen fan de stimmen *ferskillen tusken it oantal talitten kiezers en de útbrochte stimmen* ferklearje kin.
+]
 
-Denk aan kiezers die het stembiljet niet in de stembus stoppen maar meenemen. Of kiezers die één stempas inleverden en twee stembiljetten kregen.
+#tr[
+  Denk aan kiezers die het stembiljet niet in de stembus stoppen maar meenemen. Of kiezers die één stempas inleverden en twee stembiljetten kregen.
+][
+  Tink oan kiezers dy't it stimbriefke net yn de stimbus stopje mar meinimme. Of kiezers dy't ien stimpas ynleveren en twa stimbriefkes krigen.
+]
 
 #empty_table(
   columns: (7em, 1fr),
-  headers: ("Tijdstip", "Gebeurtenis"),
+  headers: tr(("Tijdstip", "Gebeurtenis"), ("Tiidstip", "Barren")),
   values: ("", ""),
   rows: 14,
 )
 
-=== Andere *bijzonderheden* die mogelijk invloed hebben op het stemproces of de resultaten van dit stembureau.
+=== #tr[
+  Andere *bijzonderheden* die mogelijk invloed hebben op het stemproces of de resultaten van dit stembureau.
+][
+  Oare *bysûnderheden* dy't mooglik ynfloed hawwe op it stimproses of de resultaten fan dit stimburo.
+]
 
-Denk aan stembureauleden die te laat waren, niet werkende techniek of stembussen die vol waren. Of een stembureaulid dat met een stempas uit een andere #location in dit stembureau heeft gestemd.
+#tr[
+  Denk aan stembureauleden die te laat waren, niet werkende techniek of stembussen die vol waren. Of een stembureaulid dat met een stempas uit een andere #location in dit stembureau heeft gestemd.
+][
+  Tink oan stimburoleden dy't te let wiene, techyk dy't net wurke of stimbussen dy't fol wiene. Of in stimburolid dat mei in stimpas út in oare #location yn dit stimburo stimd hat.
+]
 
 #empty_table(
   columns: (7em, 1fr),
-  headers: ("Tijdstip", "Bijzonderheid"),
+  headers: tr(("Tijdstip", "Bijzonderheid"), ("Tiidstip", "Bysûnderheid")),
   values: ("", ""),
   rows: 8,
 )
 
 #pagebreak(weak: true)
 
-== Tijdens het tellen
+== #tr[Tijdens het tellen][Tidens it tellen]
 
-=== Wanneer en waar zijn de stemmen geteld?
+=== #tr[Wanneer en waar zijn de stemmen geteld?][Wannear en wêr binne de stimmen teld?]
 
 #empty_table(
   columns: (8em, 10em, 1fr),
-  headers: ("Datum", "Tijd van - tot", "Locatie (als anders dan stembureau)"),
+  headers: tr(
+    ("Datum", "Tijd van - tot", "Locatie (als anders dan stembureau)"),
+    ("Datum", "Tiid fan - oant", "Lokaasje (as oars as stimburo)"),
+  ),
   values: ("", "", ""),
   rows: 1,
 )
 
-=== Schrijf alle *bezwaren van aanwezigen tijdens het tellen* op
+=== #tr[Schrijf alle *bezwaren van aanwezigen tijdens het tellen* op][Skriuw alle *beswieren fan oanwêzigen tidens it tellen* op]
 
-Bijvoorbeeld als iemand het niet eens is met het ongeldig verklaren van een stembiljet. Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u het er niet mee eens bent. Geef aan hoe het bezwaar door het stembureau is behandeld.
+#tr[
+  Bijvoorbeeld als iemand het niet eens is met het ongeldig verklaren van een stembiljet. Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u het er niet mee eens bent. Geef aan hoe het bezwaar door het stembureau is behandeld.
+][
+  Bygelyks as immen it net iens is mei it ûnjildich ferklearjen fan in stimbriefke. Skriuw gjin nammen of oare persoansgegevens op. Skriuw alle beswieren op, ek as jo it der net mei iens binne. Jou oan hoe't it beswier troch it stimburo behannele is.
+]
 
 #empty_table(
   columns: (7em, 1fr, 1fr),
-  headers: ("Tijdstip", "Bezwaar", "Reactie stembureau"),
+  headers: tr(
+    ("Tijdstip", "Bezwaar", "Reactie stembureau"),
+    ("Tiidstip", "Beswier", "Reaksje stimburo"),
+  ),
   values: ("", "", ""),
   rows: 12,
 )
 
-=== Andere *bijzonderheden* die mogelijk invloed hebben op het telproces of de resultaten van dit stembureau.
+=== #tr[
+  Andere *bijzonderheden* die mogelijk invloed hebben op het telproces of de resultaten van dit stembureau.
+][
+  Oare *bysûnderheden* dy't mooglik ynfloed hawwe op it telproses of de resultaten fan dit stimburo.
+]
 
-Bijvoorbeeld als er meerdere verkiezingen tegelijk werden georganiseerd, en een stembiljet in de verkeerde stembus zat. Of een schorsing van de telling.
+#tr[
+  Bijvoorbeeld als er meerdere verkiezingen tegelijk werden georganiseerd, en een stembiljet in de verkeerde stembus zat. Of een schorsing van de telling.
+][
+  Bygelyks as der meardere ferkiezingen tagelyk organisearre waarden, en in stimbriefke yn de ferkearde stimbus siet. Of in skorsing fan de telling.
+]
 
 #empty_table(
   columns: (7em, 1fr),
-  headers: ("Tijdstip", "Bijzonderheid"),
+  headers: tr(("Tijdstip", "Bijzonderheid"), ("Tiidstip", "Bysûnderheid")),
   values: ("", ""),
   rows: 6,
 )
@@ -156,113 +230,125 @@ Bijvoorbeeld als er meerdere verkiezingen tegelijk werden georganiseerd, en een
 
 = Telresultaten
 
-== Toegelaten kiezers
+== #tr[Toegelaten kiezers][Talitten kiezers]
 
 #is_local_election[
-  Tel het aantal geldige stempassen en volmachtbewijzen
+  #tr[Tel het aantal geldige stempassen en volmachtbewijzen][Tel it oantal jildige stimpassen en folmachtbewizen]
 
   #sum(
-    empty_letterbox("A")[Stempassen],
-    empty_letterbox("B")[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)],
-    no_entry_letterbox("C")[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)],
+    empty_letterbox("A")[#tr[Stempassen][Stimpassen]],
+    empty_letterbox("B")[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas)]],
+    no_entry_letterbox("C")[#tr[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)][Kiezerspassen (net fan tapassing by gemeente- en eilânriedsferkiezingen)]],
     empty_letterbox(
       "D",
       light: false,
-    )[*Totaal toegelaten kiezers (A+B)*],
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*],
   )
 ][
-  Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen
+  #tr[Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen][Tel it oantal jildige stimpassen, folmachtbewizen en kiezerspassen]
 
   #sum(
-    empty_letterbox("A")[Stempassen],
+    empty_letterbox("A")[#tr[Stempassen][Stimpassen]],
     empty_letterbox(
       "B",
-    )[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)],
+    )[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas of kiezerspas)]],
     empty_letterbox("C")[Kiezerspassen],
     empty_letterbox(
       "D",
       light: false,
-    )[*Totaal toegelaten kiezers (A+B+C)*],
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Uitgebrachte stemmen <cast_votes>
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen] <cast_votes>
 
-=== Beoordeel de stembiljetten en tel het aantal stembiljetten per lijst. En tel de blanco en ongeldige stembiljetten.
+=== #tr[
+  Beoordeel de stembiljetten en tel het aantal stembiljetten per lijst. En tel de blanco en ongeldige stembiljetten.
+][
+  Beoardielje de stimbriefkes en tel it oantal stimbriefkes per list. En tel de blanko en ûnjildige stimbriefkes.
+]
 
 #if input.election.political_groups.len() > 0 [
   #sum(
     sum(
       ..input.election.political_groups.map(list => {
-        empty_letterbox([E.#list.number])[Totaal lijst #list.number - #list.name]
+        empty_letterbox([E.#list.number])[#tr[Totaal lijst][Totaal list] #list.number - #list.name]
       }),
       empty_letterbox(
         "E",
         light: false,
-      )[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.election.political_groups.last().number op)],
+      )[#tr[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.election.political_groups.last().number op)][*Totaal stimmen op kandidaten* (tel E.1 o/m E.#input.election.political_groups.last().number op)]],
     ),
-    empty_letterbox("F")[Blanco stemmen],
-    empty_letterbox("G")[Ongeldige stemmen],
+    empty_letterbox("F")[#tr[Blanco stemmen][Blanko stimmen]],
+    empty_letterbox("G")[#tr[Ongeldige stemmen][Unjildige stimmen]],
     empty_letterbox(
       "H",
       light: false,
-    )[*Totaal uitgebrachte stemmen (E+F+G)*],
+    )[*#tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen] (E+F+G)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Verschillen tussen aantal kiezers en uitgebrachte stemmen
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
 
-=== Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+=== #tr[
+  Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+][
+  Fergelykje D (totaal talitten kiezers) en H (totaal útbrochte stimmen)
+]
 
-#checkbox[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<signing>)*]
+#checkbox[#tr[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<signing>)*][D en H binne *gelyk* #sym.arrow.r *Gean troch nei #ref(<signing>)*]]
 
-#checkbox[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers)]
+#checkbox[#tr[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers)][H is *grutter* as D (mear útbrochte stimmen as talitten kiezers)]]
 
 #box(inset: (left: 3em, bottom: 1em), empty_letterbox(
   "I",
   cells: 3,
   light: false,
-)[Aantal méér getelde stemmen (bereken: H _min_ D)])
+)[#tr[Aantal méér getelde stemmen (bereken: H _min_ D)][Oantal mear telde stimmen (berekkenje: H _min_ D)]])
 
-#checkbox[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers)]
+#checkbox[#tr[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers)][H is *lytser* as D (minder útbrochte stimmen as talitten kiezers)]]
 
 #box(inset: (left: 3em, bottom: 1em), empty_letterbox(
   "J",
   cells: 3,
   light: false,
-)[Aantal minder getelde stemmen (bereken: D _min_ H)])
+)[#tr[Aantal minder getelde stemmen (bereken: D _min_ H)][Oantal minder telde stimmen (berekkenje: D _min_ H)]])
 
-=== Zijn er tijdens de stemming (rubriek 1.2.2) dingen opgeschreven die het verschil tussen D en H volledig verklaren?
+=== #tr[
+  Zijn er tijdens de stemming (rubriek 1.2.2) dingen opgeschreven die het verschil tussen D en H volledig verklaren?
+][
+  Binne der tidens de stimming (rubryk 1.2.2) dingen opskreaun dy't it ferskil tusken D en H hielendal ferklearje?
+]
 
 #checkbox[Ja]
-#checkbox[Nee, er is een onverklaard verschil]
+#checkbox[#tr[Nee, er is een onverklaard verschil][Nee, der is in net ferklearre ferskil]]
 
 #blank_page_before_signing(header-right)
 
-= Ondertekening <signing>
+= #tr[Ondertekening][Ûndertekening] <signing>
 
-#block(width: 100%, [Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een stembureau zijn dit er minimaal 3.])
+#block(width: 100%, tr[Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een stembureau zijn dit er minimaal 3.][It proses-ferbaal moat ûndertekene wurde troch alle oanwêzige leden. By in stimburo binne dat der minimaal 3.])
 
 #signing_form_label[Datum]
 
-#textbox_only_bottom_stroke[Datum en tijd:][Plaats:]
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
 
-== Voorzitter en twee leden van het stembureau
+== #tr[Voorzitter en twee leden van het stembureau][Foarsitter en twa leden fan it stimburo]
 
-#signing_form_label[Voorzitter van het stembureau:]
+#signing_form_label[#tr[Voorzitter van het stembureau:][Foarsitter fan it stimburo:]]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[2 leden van het stembureau:]
+#signing_form_label[#tr[2 leden van het stembureau:][2 leden fan it stimburo:]]
 
-#stack(spacing: 0.5em, ..range(0, 2).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, 2).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
 
-== Ondertekening door andere aanwezige leden van het stembureau
+== #tr[Ondertekening door andere aanwezige leden van het stembureau][Ûndertekening troch oare oanwêzige leden fan it stimburo]
 
-#signing_form_label[Extra ondertekening:]
+#signing_form_label[#tr[Extra ondertekening:][Ekstra ûndertekening:]]
 
-#stack(spacing: 0.5em, ..range(0, 4).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, 4).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
@@ -5,19 +5,22 @@
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
 #let is_local_election = (local, other) => is_local_election(input.election.category, local, other)
 
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
-#let this_location = is_municipality[deze gemeente][dit openbaar lichaam]
-#let location = is_municipality[gemeente][openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
+#let this_location = is_municipality(tr[deze gemeente][dizze gemeente], tr[dit openbaar lichaam][dit iepenbier lichem])
+#let location = is_municipality(tr[gemeente][gemeente], tr[openbaar lichaam][iepenbier lichem])
 
-#let header-right = [#location_name\ Stembureau #input.polling_station.number]
+#let header-right = [#location_name\ #tr[Stembureau][Stimburo] #input.polling_station.number]
 
 #show: doc => conf(
   doc,
   header-right: header-right,
-  footer: [
+  footer: tr[
     Corrigendum van een proces-verbaal van een stembureau \
     Model Na 14-1 decentrale stemopneming (versie 2027)
+  ][
+    Korrigindum fan in proses-ferbaal fan in stimburo \
+    Model Na 14-1 desintrale stimopnimming (ferzje 2027)
   ]
 )
 
@@ -25,29 +28,42 @@
 
 #title_page(
   is_municipality[#input.election.authority_id #input.election.authority_region][#input.election.authority_region],
-  is_municipality[Gemeentelijk stembureau][Stembureau voor het openbaar lichaam],
+  is_municipality(tr[Gemeentelijk stembureau][Gemeentlik stimburo], tr[Stembureau voor het openbaar lichaam][Stimburo foar it iepenbier lichem]),
   [#input.election.name - #format_date(input.election.election_date)],
-  [
+  tr[
     Verslag van telling van een door het #location_type herteld stembureau -
     Model Na 14-1 (eerste zitting)
+  ][
+    Ferslach fan telling fan in troch it #location_type op 'e nij teld stimburo -
+    Model Na 14-1 (earste sitting)
   ],
 )
 
-== Details van het #location_type
+== #tr[Details van het][Details fan it] #location_type
 
 #location_name
 
 #input.committee_session.location #format_date_time(input.committee_session.start_date_time)
 
-== Corrigendum van het proces-verbaal van een stembureau
+== #tr[Corrigendum van het proces-verbaal van een stembureau][Korrigindum fan it proses-ferbaal fan in stimburo]
 
-Het #location_type heeft de telresultaten van dit stembureau onderzocht en is tot een ander resultaat gekomen. De nieuwe telresultaten zijn opgenomen in dit corrigendum.
+#tr[
+  Het #location_type heeft de telresultaten van dit stembureau onderzocht en is tot een ander resultaat gekomen. De nieuwe telresultaten zijn opgenomen in dit corrigendum.
+][
+  It #location_type hat de telresultaten fan dit stimburo ûndersocht en is ta in oar resultaat kommen. De nije telresultaten binne opnommen yn dit korrigindum.
+]
 
-== Inhoudsopgave
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
 
-- Deel 1 – *Verschillen* met telresultaten stembureau
-- Deel 2 - *Telresultaten* van het stembureau
-- Deel 3 - *Ondertekening* door de leden van het #location_type
+#tr[
+  - Deel 1 – *Verschillen* met telresultaten stembureau
+  - Deel 2 - *Telresultaten* van het stembureau
+  - Deel 3 - *Ondertekening* door de leden van het #location_type
+][
+  - Diel 1 – *Ferskillen* mei telresultaten stimburo
+  - Diel 2 - *Telresultaten* fan it stimburo
+  - Diel 3 - *Ûndertekening* troch de leden fan it #location_type
+]
 
 #pagebreak(weak: true)
 
@@ -60,26 +76,31 @@ Het #location_type heeft de telresultaten van dit stembureau onderzocht en is to
   )
 )
 
-#attachment_or_corrigendum_title([Stembureau #input.polling_station.number\ #input.polling_station.name])
+#attachment_or_corrigendum_title([#tr[Stembureau][Stimburo] #input.polling_station.number\ #input.polling_station.name])
 
-= Onderzoek naar telresultaten
+= #tr[Onderzoek naar telresultaten][Undersyk nei telresultaten]
 
-== Aantallen kiezers en stemmen
+== #tr[Aantallen kiezers en stemmen][Oantallen kiezers en stimmen]
 
-#emph_block[
+#emph_block(tr[
   Is in de telresultaten van het #underline[*stembureau*] (rubriek 2.3 van het proces-verbaal van het stembureau) het verschil tussen het totaal aantal getelde stembiljetten en het aantal toegelaten kiezers volledig verklaard? Controleer daarbij ook of rubriek 2.3 van het proces-verbaal van het stembureau correct is ingevuld en de verklaringen het verschil daadwerkelijk verklaren.
-]
+][
+  Is yn de telresultaten fan it #underline[*stimburo*] (rubryk 2.3 fan it proses-ferbaal fan it stimburo) it ferskil tusken it totaal oantal telde stimbriefkes en it oantal talitten kiezers hielendal ferklearre? Kontrolearje dêrby ek oft rubryk 2.3 fan it proses-ferbaal fan it stimburo korrekt ynfolle is en de ferklearrings it ferskil echt ferklearje.
+])
 
 #checkbox[Ja]
 
-#checkbox[Nee #sym.arrow.r *Hertel het aantal toegelaten kiezers en de stembiljetten. Tot het verschil is opgelost of beide één keer
-zijn herteld.*]
+#checkbox[#tr[Nee #sym.arrow.r *Hertel het aantal toegelaten kiezers en de stembiljetten. Tot het verschil is opgelost of beide één keer
+zijn herteld.*][Nee #sym.arrow.r *Tel it oantal talitten kiezers en de stimbriefkes op 'e nij. Oant it ferskil oplost is of beide ien kear
+op 'e nij teld binne.*]]
 
-== Hertelling door #location_type
+== #tr[Hertelling door][Wertelling troch] #location_type
 
-#emph_block[
+#emph_block(tr[
   Is het stembureau herteld vanwege (het vermoeden van) een andere fout?
-]
+][
+  Is it stimburo op 'e nij teld fanwegen (it fermoeden fan) in oare flater?
+])
 
 #checkbox[Ja]
 
@@ -87,28 +108,36 @@ zijn herteld.*]
 
 #pagebreak(weak: true)
 
-== Lijsten met verschillen
+== #tr[Lijsten met verschillen][Listen mei ferskillen]
 
-#emph_block[
+#emph_block(tr[
   Noteer alle lijsten waar de telling door het #underline[*stembureau*] afwijkt van de telling door het #underline[*#location_type*].
-]
+][
+  Notearje alle listen dêr't de telling troch it #underline[*stimburo*] ôfwykt fan de telling troch it #underline[*#location_type*].
+])
 
 #empty_table(
   columns: (auto, auto, 9em, auto, 29em),
   headers: (
-    [Lijstnummer met verschil],
-    [Lijsttotaal vastgesteld door het stembureau],
-    [Lijsttotaal vastgesteld door het #location_type],
-    [Aantal stemmen verschil],
+    tr[Lijstnummer met verschil][Listnûmer mei ferskil],
+    tr[Lijsttotaal vastgesteld door het stembureau][Listtotaal fêststeld troch it stimburo],
+    tr[Lijsttotaal vastgesteld door het #location_type][Listtotaal fêststeld troch it #location_type],
+    tr[Aantal stemmen verschil][Oantal stimmen ferskil],
     [
-      Toelichting op het telverschil, bijvoorbeeld:
+      #tr[Toelichting op het telverschil, bijvoorbeeld:][Taljochting op it telferskil, bygelyks:]
       #[
         #show list: content => block(width: 125%, content)
         #set text(weight: "regular", size: 7pt)
         #set list(spacing: 0.75em)
-        - Stembiljet was toch blanco, ongeldig of andersom
-        - Stembiljet meegeteld bij verkeerde lijst
-        - Meer of minder stembiljetten geteld dan stembureau
+        #tr[
+          - Stembiljet was toch blanco, ongeldig of andersom
+          - Stembiljet meegeteld bij verkeerde lijst
+          - Meer of minder stembiljetten geteld dan stembureau
+        ][
+          - Stimbriefke wie dochs blanko, ûnjildich of oarsom
+          - Stimbriefke meiteld by ferkearde list
+          - Mear of minder stimbriefkes teld as stimburo
+        ]
       ]
     ],
   ),
@@ -120,135 +149,155 @@ zijn herteld.*]
 
 = Telresultaten
 
-Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.
+#tr[Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.][Folje allinnich de gegevens yn dy't oars binne as it oarspronklike proses-ferbaal.]
 
-== Toegelaten kiezers
+== #tr[Toegelaten kiezers][Talitten kiezers]
 
 #is_local_election[
-  Tel het aantal geldige stempassen en volmachtbewijzen
+  #tr[Tel het aantal geldige stempassen en volmachtbewijzen][Tel it oantal jildige stimpassen en folmachtbewizen]
 
   #sum(
-    empty_letterbox("A")[Stempassen],
-    empty_letterbox("B")[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)],
-    no_entry_letterbox("C")[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)],
-    empty_letterbox("D", light: false)[*Totaal toegelaten kiezers (A+B)*],
+    empty_letterbox("A")[#tr[Stempassen][Stimpassen]],
+    empty_letterbox("B")[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas)]],
+    no_entry_letterbox("C")[#tr[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)][Kiezerspassen (net fan tapassing by gemeente- en eilânriedsferkiezingen)]],
+    empty_letterbox("D", light: false)[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*],
   )
 ][
-  Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen
+  #tr[Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen][Tel it oantal jildige stimpassen, folmachtbewizen en kiezerspassen]
 
   #sum(
-    empty_letterbox("A")[Stempassen],
-    empty_letterbox("B")[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)],
+    empty_letterbox("A")[#tr[Stempassen][Stimpassen]],
+    empty_letterbox("B")[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas of kiezerspas)]],
     empty_letterbox("C")[Kiezerspassen],
-    empty_letterbox("D", light: false)[*Totaal toegelaten kiezers (A+B+C)*],
+    empty_letterbox("D", light: false)[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Uitgebrachte stemmen <cast_votes>
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen] <cast_votes>
 
-Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.
+#tr[Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.][Folje allinnich de gegevens yn dy't oars binne as it oarspronklike proses-ferbaal.]
 
 #if input.candidates_tables.len() > 0 [
   #sum(
     sum(
       ..input.candidates_tables.map(list => {
-        empty_letterbox([E.#list.number])[Totaal lijst #list.number - #list.name]
+        empty_letterbox([E.#list.number])[#tr[Totaal lijst][Totaal list] #list.number - #list.name]
       }),
       empty_letterbox(
         "E",
         light: false,
-      )[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.candidates_tables.last().number op)],
+      )[#tr[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.candidates_tables.last().number op)][*Totaal stimmen op kandidaten* (tel E.1 o/m E.#input.candidates_tables.last().number op)]],
     ),
-    empty_letterbox("F")[Blanco stemmen],
-    empty_letterbox("G")[Ongeldige stemmen],
+    empty_letterbox("F")[#tr[Blanco stemmen][Blanko stimmen]],
+    empty_letterbox("G")[#tr[Ongeldige stemmen][Unjildige stimmen]],
     empty_letterbox(
       "H",
       light: false,
-    )[*Totaal uitgebrachte stemmen (E+F+G)*],
+    )[*#tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen] (E+F+G)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Verschillen tussen aantal kiezers en uitgebrachte stemmen
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
 
-Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.
+#tr[Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.][Folje allinnich de gegevens yn dy't oars binne as it oarspronklike proses-ferbaal.]
 
-=== Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+=== #tr[
+  Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+][
+  Fergelykje D (totaal talitten kiezers) en H (totaal útbrochte stimmen)
+]
 
-#checkbox[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<candidate_votes>)*]
+#checkbox[#tr[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<candidate_votes>)*][D en H binne *gelyk* #sym.arrow.r *Gean troch nei #ref(<candidate_votes>)*]]
 
-#checkbox[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers)]
+#checkbox[#tr[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers)][H is *grutter* as D (mear útbrochte stimmen as talitten kiezers)]]
 
 #box(inset: (left: 3em, bottom: 1em), empty_letterbox(
   "I",
   cells: 3,
   light: false,
-)[Aantal méér getelde stemmen (bereken: H _min_ D)])
+)[#tr[Aantal méér getelde stemmen (bereken: H _min_ D)][Oantal mear telde stimmen (berekkenje: H _min_ D)]])
 
-#checkbox[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers)]
+#checkbox[#tr[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers)][H is *lytser* as D (minder útbrochte stimmen as talitten kiezers)]]
 
 #box(inset: (left: 3em, bottom: 1em), empty_letterbox(
   "J",
   cells: 3,
   light: false,
-)[Aantal minder getelde stemmen (bereken: D _min_ H)])
+)[#tr[Aantal minder getelde stemmen (bereken: D _min_ H)][Oantal minder telde stimmen (berekkenje: D _min_ H)]])
 
-=== Zijn er tijdens de stemming dingen opgeschreven die het bovenstaande verschil tussen D en H volledig verklaren?
+=== #tr[
+  Zijn er tijdens de stemming dingen opgeschreven die het bovenstaande verschil tussen D en H volledig verklaren?
+][
+  Binne der tidens de stimming dingen opskreaun dy't it boppesteande ferskil tusken D en H hielendal ferklearje?
+]
 
-(Gebruik het proces-verbaal van het stembureau #sym.arrow.r *Tijdens de stemming, vraag 1.2.2*)
+#tr[
+  (Gebruik het proces-verbaal van het stembureau #sym.arrow.r *Tijdens de stemming, vraag 1.2.2*)
+][
+  (Brûk it proses-ferbaal fan it stimburo #sym.arrow.r *Tidens de stimming, fraach 1.2.2*)
+]
 
-#checkbox[Ja #sym.arrow.r *Ga door naar #ref(<candidate_votes>)*]
-#checkbox[Nee, er is een onverklaard verschil]
+#checkbox[#tr[Ja #sym.arrow.r *Ga door naar #ref(<candidate_votes>)*][Ja #sym.arrow.r *Gean troch nei #ref(<candidate_votes>)*]]
+#checkbox[#tr[Nee, er is een onverklaard verschil][Nee, der is in net ferklearre ferskil]]
 
-=== Geef als dat mogelijk is, een toelichting op het nog niet verklaarde verschil tussen het aantal toegelaten kiezers en het aantal uitgebrachte stemmen:
+=== #tr[
+  Geef als dat mogelijk is, een toelichting op het nog niet verklaarde verschil tussen het aantal toegelaten kiezers en het aantal uitgebrachte stemmen:
+][
+  Jou as dat mooglik is, in taljochting op it noch net ferklearre ferskil tusken it oantal talitten kiezers en it oantal útbrochte stimmen:
+]
 
 #empty_lines(10)
 
 #pagebreak(weak: true)
 
-== Stemmen per lijst en per kandidaat <candidate_votes>
+== #tr[Stemmen per lijst en per kandidaat][Stimmen per list en per kandidaat] <candidate_votes>
 
 #pagebreak(weak: true)
 
 #for political_group in input.candidates_tables {
   votes_table(
     title: [#political_group.number #political_group.name],
-    headers: ("Kandidaat", "", "Stemmen"),
+    headers: tr(("Kandidaat", "", "Stemmen"), ("Kandidaat", "", "Stimmen")),
     total: political_group.total,
     votes_columns: political_group.columns,
-    continue_on_next_page: [#sym.arrow.r De lijst gaat verder op de volgende pagina],
+    continue_on_next_page: [#sym.arrow.r #tr[De lijst gaat verder op de volgende pagina][De list giet fierder op de folgjende side]],
     column_total: "Subtotaal kolom",
-    sum_total: columns => [Totaal lijst (kolom #columns)],
-    total_instruction: [Neem dit totaal over in rubriek #ref(<cast_votes>) bij de juiste lijst.],
-    explainer_text: [Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.]
+    sum_total: columns => [#tr[Totaal lijst][Totaal list] (kolom #columns)],
+    total_instruction: tr[Neem dit totaal over in rubriek #ref(<cast_votes>) bij de juiste lijst.][Nim dit totaal oer yn rubryk #ref(<cast_votes>) by de goede list.],
+    explainer_text: tr[Vul alleen de gegevens in die anders zijn dan het oorspronkelijke proces-verbaal.][Folje allinnich de gegevens yn dy't oars binne as it oarspronklike proses-ferbaal.]
   )
 }
 
 #blank_page_before_signing(header-right)
 
-= Ondertekening
+= #tr[Ondertekening][Ûndertekening]
 
-Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+#tr[
+  Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+][
+  It proses-ferbaal moat ûndertekene wurde troch alle oanwêzige leden. By in #location_type binne dat der minimaal #is_local_election[3][5].
+]
 
 #signing_form_label[Datum]
 
-#textbox_only_bottom_stroke[Datum en tijd:][Plaats:]
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
 
-== Voorzitter en #is_local_election[twee][vier] leden van het #location_type
+== #tr[Voorzitter en #is_local_election[twee][vier] leden van het #location_type][Foarsitter en #is_local_election[twa][fjouwer] leden fan it #location_type]
 
-#signing_form_label[Voorzitter van het #location_type:]
+#signing_form_label[#tr[Voorzitter van het #location_type:][Foarsitter fan it #location_type:]]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[#is_local_election[2][4] leden van het #location_type:]
+#signing_form_label[#is_local_election[2][4] #tr[leden van het][leden fan it] #location_type:]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
 
-== Ondertekening door andere aanwezige leden van het #location_type
+== #tr[Ondertekening door andere aanwezige leden van het][Ûndertekening troch oare oanwêzige leden fan it] #location_type
 
-#signing_form_label[Extra ondertekening:]
+#signing_form_label[#tr[Extra ondertekening:][Ekstra ûndertekening:]]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
@@ -5,19 +5,22 @@
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
 #let is_local_election = (local, other) => is_local_election(input.election.category, local, other)
 
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
-#let this_location = is_municipality[deze gemeente][dit openbaar lichaam]
-#let location = is_municipality[gemeente][openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
+#let this_location = is_municipality(tr[deze gemeente][dizze gemeente], tr[dit openbaar lichaam][dit iepenbier lichem])
+#let location = is_municipality(tr[gemeente][gemeente], tr[openbaar lichaam][iepenbier lichem])
 
-#let header-right = [#location_name\ Stembureau #input.polling_station.number]
+#let header-right = [#location_name\ #tr[Stembureau][Stimburo] #input.polling_station.number]
 
 #show: doc => conf(
   doc,
   header-right: header-right,
-  footer: [
+  footer: tr[
     Corrigendum van een proces-verbaal van een stembureau \
     Model Na 14-1 decentrale stemopneming (versie 2027)
+  ][
+    Korrigindum fan in proses-ferbaal fan in stimburo \
+    Model Na 14-1 desintrale stimopnimming (ferzje 2027)
   ]
 )
 
@@ -25,29 +28,42 @@
 
 #title_page(
   is_municipality[#input.election.authority_id #input.election.authority_region][#input.election.authority_region],
-  is_municipality[Gemeentelijk stembureau][Stembureau voor het openbaar lichaam],
+  is_municipality(tr[Gemeentelijk stembureau][Gemeentlik stimburo], tr[Stembureau voor het openbaar lichaam][Stimburo foar it iepenbier lichem]),
   [#input.election.name - #format_date(input.election.election_date)],
-  [
+  tr[
     Verslag van telling van een door het #location_type herteld stembureau -
     Model Na 14-1 (nieuwe zitting)
+  ][
+    Ferslach fan telling fan in troch it #location_type op 'e nij teld stimburo -
+    Model Na 14-1 (nije sitting)
   ],
 )
 
-== Details van het #location_type
+== #tr[Details van het][Details fan it] #location_type
 
 #location_name
 
 #input.committee_session.location #format_date_time(input.committee_session.start_date_time)
 
-== Corrigendum van het proces-verbaal van een stembureau
+== #tr[Corrigendum van het proces-verbaal van een stembureau][Korrigindum fan it proses-ferbaal fan in stimburo]
 
-Het #location_type heeft de telresultaten van dit stembureau onderzocht en is tot een ander resultaat gekomen. De nieuwe telresultaten zijn opgenomen in dit corrigendum.
+#tr[
+  Het #location_type heeft de telresultaten van dit stembureau onderzocht en is tot een ander resultaat gekomen. De nieuwe telresultaten zijn opgenomen in dit corrigendum.
+][
+  It #location_type hat de telresultaten fan dit stimburo ûndersocht en is ta in oar resultaat kommen. De nije telresultaten binne opnommen yn dit korrigindum.
+]
 
-== Inhoudsopgave
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
 
-- Deel 1 – *Verschillen* met telresultaten stembureau
-- Deel 2 - *Telresultaten* van het stembureau
-- Deel 3 - *Ondertekening* door de leden van het #location_type
+#tr[
+  - Deel 1 – *Verschillen* met telresultaten stembureau
+  - Deel 2 - *Telresultaten* van het stembureau
+  - Deel 3 - *Ondertekening* door de leden van het #location_type
+][
+  - Diel 1 – *Ferskillen* mei telresultaten stimburo
+  - Diel 2 - *Telresultaten* fan it stimburo
+  - Diel 3 - *Ûndertekening* troch de leden fan it #location_type
+]
 
 #pagebreak(weak: true)
 
@@ -60,21 +76,25 @@ Het #location_type heeft de telresultaten van dit stembureau onderzocht en is to
   )
 )
 
-#attachment_or_corrigendum_title([Stembureau #input.polling_station.number\ #input.polling_station.name])
+#attachment_or_corrigendum_title([#tr[Stembureau][Stimburo] #input.polling_station.number\ #input.polling_station.name])
 
-= Onderzoek naar telresultaten
+= #tr[Onderzoek naar telresultaten][Undersyk nei telresultaten]
 
-Geef aan *waarom* de resultaten van dit stembureau zijn onderzocht. Denk bijvoorbeeld aan een onverklaard telverschil, een andere (vermoeden van een) fout of een bezwaar. Als het onderzoek heeft plaatsgevonden in opdracht van het centraal stembureau, schrijf dit dan op. Schrijf zo concreet mogelijk op wat de opdracht van het centraal stembureau is. Bijvoorbeeld: hertel de stembiljetten van lijst 12.
+#tr[
+  Geef aan *waarom* de resultaten van dit stembureau zijn onderzocht. Denk bijvoorbeeld aan een onverklaard telverschil, een andere (vermoeden van een) fout of een bezwaar. Als het onderzoek heeft plaatsgevonden in opdracht van het centraal stembureau, schrijf dit dan op. Schrijf zo concreet mogelijk op wat de opdracht van het centraal stembureau is. Bijvoorbeeld: hertel de stembiljetten van lijst 12.
+][
+  Jou oan *wêrom* de resultaten fan dit stimburo ûndersocht binne. Tink bygelyks oan in net ferklearre telferskil, in oare (fermoeden fan in) flater of in beswier. As it ûndersyk plakfûn hat yn opdracht fan it sintraal stimburo, skriuw dat dan op. Skriuw sa konkreet mooglik op wat de opdracht fan it sintraal stimburo is. Bygelyks: tel de stimbriefkes fan list 12 op 'e nij.
+]
 
-==== Aanleiding van het onderzoek
+==== #tr[Aanleiding van het onderzoek][Oanlieding fan it ûndersyk]
 
 #text_area_with_content(input.investigation.reason)
 
 #block(below: 3em)
 
-Schrijf op wat de *uitkomst* van het onderzoek door het #location_type was.
+#tr[Schrijf op wat de *uitkomst* van het onderzoek door het #location_type was.][Skriuw op wat de *útkomst* fan it ûndersyk troch it #location_type wie.]
 
-==== Bevindingen
+==== #tr[Bevindingen][Befinings]
 #empty_lines(10)
 
 #block(below: 1.5em)
@@ -82,53 +102,55 @@ Schrijf op wat de *uitkomst* van het onderzoek door het #location_type was.
 #block(
   breakable: false,
   {
-    [Heeft het onderzoek geleid tot een *gecorrigeerde uitslag*?]
-    checkbox[Nee #sym.arrow.r *Neem de uitkomst van het onderzoek over in het proces-verbaal van het #location_type (nieuwe zitting: model P 2a). Dit corrigendum hoeft verder niet ingevuld te worden.*]
-    checkbox[Ja #sym.arrow.r *Ga verder met #ref(<corrected_results>)*]
+    tr[Heeft het onderzoek geleid tot een *gecorrigeerde uitslag*?][Hat it ûndersyk laat ta in *ferbettere útslach*?]
+    checkbox(tr[Nee #sym.arrow.r *Neem de uitkomst van het onderzoek over in het proces-verbaal van het #location_type (nieuwe zitting: model P 2a). Dit corrigendum hoeft verder niet ingevuld te worden.*][Nee #sym.arrow.r *Nim de útkomst fan it ûndersyk oer yn it proses-ferbaal fan it #location_type (nije sitting: model P 2a). Dit korrigindum hoecht fierder net ynfolle te wurden.*])
+    checkbox(tr[Ja #sym.arrow.r *Ga verder met #ref(<corrected_results>)*][Ja #sym.arrow.r *Gean fierder mei #ref(<corrected_results>)*])
   }
 )
 
 #pagebreak(weak: true)
 
-= Gecorrigeerde telresultaten <corrected_results>
+= #tr[Gecorrigeerde telresultaten][Ferbettere telresultaten] <corrected_results>
 
-#emph_block[
+#emph_block(tr[
   Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die door het stembureau of door het #location_type in een eerdere zitting zijn vastgesteld.
-]
+][
+  Folje allinnich de getallen yn dy't feroare binne ten opsichte fan in eardere telling. Getallen dy't net feroare binne, hoege net ynfolle te wurden yn de kolom ‘ferbettere’. Under ‘oarspronklik’ steane de getallen dy't troch it stimburo of troch it #location_type yn in eardere sitting fêststeld binne.
+])
 
-== Toegelaten kiezers
+== #tr[Toegelaten kiezers][Talitten kiezers]
 
 #if not is_local_election(true, false) and "voter_card_count" in input.previous_results.voters_counts [
-  Het totaal van alle getelde geldige stempassen, volmachtbewijzen en kiezerspassen
+  #tr[Het totaal van alle getelde geldige stempassen, volmachtbewijzen en kiezerspassen][It totaal fan alle telde jildige stimpassen, folmachtbewizen en kiezerspassen]
 
   #sum(
     with_correction_title: true,
-    empty_letterbox("A", cells: 4, original_value: input.previous_results.voters_counts.poll_card_count, bold_top_border: true)[Stempassen],
-    empty_letterbox("B", cells: 4, original_value: input.previous_results.voters_counts.proxy_certificate_count)[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)],
+    empty_letterbox("A", cells: 4, original_value: input.previous_results.voters_counts.poll_card_count, bold_top_border: true)[#tr[Stempassen][Stimpassen]],
+    empty_letterbox("B", cells: 4, original_value: input.previous_results.voters_counts.proxy_certificate_count)[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas of kiezerspas)]],
     empty_letterbox("C", cells: 4, original_value: input.previous_results.voters_counts.voter_card_count)[Kiezerspassen],
     empty_letterbox("D", cells: 4, original_value: input.previous_results.voters_counts.total_admitted_voters_count, light: false)[
-      *Totaal toegelaten kiezers (A+B+C)*
+      *#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*
     ]
   )
 ] else [
-  Het totaal van alle getelde geldige stempassen en volmachtbewijzen
+  #tr[Het totaal van alle getelde geldige stempassen en volmachtbewijzen][It totaal fan alle telde jildige stimpassen en folmachtbewizen]
 
   #sum(
     with_correction_title: true,
-    empty_letterbox("A", cells: 4, original_value: input.previous_results.voters_counts.poll_card_count, bold_top_border: true)[Stempassen],
-    empty_letterbox("B", cells: 4, original_value: input.previous_results.voters_counts.proxy_certificate_count)[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)],
-    no_entry_letterbox("C", cells: 4, with_original: true)[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)],
+    empty_letterbox("A", cells: 4, original_value: input.previous_results.voters_counts.poll_card_count, bold_top_border: true)[#tr[Stempassen][Stimpassen]],
+    empty_letterbox("B", cells: 4, original_value: input.previous_results.voters_counts.proxy_certificate_count)[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas)]],
+    no_entry_letterbox("C", cells: 4, with_original: true)[#tr[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)][Kiezerspassen (net fan tapassing by gemeente- en eilânriedsferkiezingen)]],
     empty_letterbox("D", cells: 4, original_value: input.previous_results.voters_counts.total_admitted_voters_count, light: false)[
-      *Totaal toegelaten kiezers (A+B)*
+      *#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*
     ]
   )
 ]
 
 #pagebreak(weak: true)
 
-== Uitgebrachte stemmen <cast_votes>
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen] <cast_votes>
 
-#block(width: 100%, [Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die door het stembureau of door het #location_type in een eerdere zitting zijn vastgesteld.])
+#block(width: 100%, tr[Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die door het stembureau of door het #location_type in een eerdere zitting zijn vastgesteld.][Folje allinnich de getallen yn dy't feroare binne ten opsichte fan in eardere telling. Getallen dy't net feroare binne, hoege net ynfolle te wurden yn de kolom ‘ferbettere’. Under ‘oarspronklik’ steane de getallen dy't troch it stimburo of troch it #location_type yn in eardere sitting fêststeld binne.])
 
 #if input.votes_tables.len() > 0 [
   #sum(
@@ -139,109 +161,131 @@ Schrijf op wat de *uitkomst* van het onderzoek door het #location_type was.
         if votes == none {
           return
         }
-        empty_letterbox(cells: 4, original_value: votes.total, bold_top_border: idx == 0, [E.#list.number])[Totaal lijst #list.number - #list.name]
+        empty_letterbox(cells: 4, original_value: votes.total, bold_top_border: idx == 0, [E.#list.number])[#tr[Totaal lijst][Totaal list] #list.number - #list.name]
       }),
       empty_letterbox(
         cells: 4,
         original_value: input.previous_results.votes_counts.total_votes_candidates_count,
         "E",
         light: false,
-      )[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.votes_tables.last().number op)],
+      )[#tr[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.votes_tables.last().number op)][*Totaal stimmen op kandidaten* (tel E.1 o/m E.#input.votes_tables.last().number op)]],
     ),
-    empty_letterbox(cells: 4, original_value: input.previous_results.votes_counts.blank_votes_count, "F")[Blanco stemmen],
-    empty_letterbox(cells: 4, original_value: input.previous_results.votes_counts.invalid_votes_count, "G")[Ongeldige stemmen],
+    empty_letterbox(cells: 4, original_value: input.previous_results.votes_counts.blank_votes_count, "F")[#tr[Blanco stemmen][Blanko stimmen]],
+    empty_letterbox(cells: 4, original_value: input.previous_results.votes_counts.invalid_votes_count, "G")[#tr[Ongeldige stemmen][Unjildige stimmen]],
     empty_letterbox(
       cells: 4,
       original_value: input.previous_results.votes_counts.total_votes_cast_count,
       "H",
       light: false,
-    )[*Totaal uitgebrachte stemmen (E+F+G)*],
+    )[*#tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen] (E+F+G)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Verschillen tussen aantal kiezers en uitgebrachte stemmen
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
 
-#block(width: 100%, [Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die door het stembureau of door het #location_type in een eerdere zitting zijn vastgesteld. Is het getal bij I én J gewijzigd? Vul dan bij beiden het gecorrigeerde getal in. Vink alléén het selectievakje aan dat van toepassing is.])
+#block(width: 100%, tr[Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die door het stembureau of door het #location_type in een eerdere zitting zijn vastgesteld. Is het getal bij I én J gewijzigd? Vul dan bij beiden het gecorrigeerde getal in. Vink alléén het selectievakje aan dat van toepassing is.][Folje allinnich de getallen yn dy't feroare binne ten opsichte fan in eardere telling. Getallen dy't net feroare binne, hoege net ynfolle te wurden yn de kolom ‘ferbettere’. Under ‘oarspronklik’ steane de getallen dy't troch it stimburo of troch it #location_type yn in eardere sitting fêststeld binne. Is it getal by I én J feroare? Folje dan by beide it ferbettere getal yn. Finkje allinnich it seleksjefakje oan dat fan tapassing is.])
 
-=== Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+=== #tr[
+  Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+][
+  Fergelykje D (totaal talitten kiezers) en H (totaal útbrochte stimmen)
+]
 
-#checkbox[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<per_list_and_candidate>)*]
+#checkbox[#tr[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<per_list_and_candidate>)*][D en H binne *gelyk* #sym.arrow.r *Gean troch nei #ref(<per_list_and_candidate>)*]]
 
-#checkbox[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers).\ Noteer onder ‘gecorrigeerd’ het nieuwe
-verschil.]
+#checkbox[#tr[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers).\ Noteer onder ‘gecorrigeerd’ het nieuwe
+verschil.][H is *grutter* as D (mear útbrochte stimmen as talitten kiezers).\ Notearje ûnder ‘ferbettere’ it nije
+ferskil.]]
 #box(width: 500pt, inset: (left: 3em, bottom: 1em))[
     #grid(
       correction_title_grid(correction_width: 6em, input_width: 6em),
-      empty_letterbox(cells: 3, original_value: input.previous_results.differences_counts.more_ballots_count, light: false, "I")[Aantal méér getelde stemmen (bereken: H _min_ D)],
+      empty_letterbox(cells: 3, original_value: input.previous_results.differences_counts.more_ballots_count, light: false, "I")[#tr[Aantal méér getelde stemmen (bereken: H _min_ D)][Oantal mear telde stimmen (berekkenje: H _min_ D)]],
     )
 ]
 
-#checkbox[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers).\ Noteer onder ‘gecorrigeerd’ het nieuwe
-verschil.]
+#checkbox[#tr[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers).\ Noteer onder ‘gecorrigeerd’ het nieuwe
+verschil.][H is *lytser* as D (minder útbrochte stimmen as talitten kiezers).\ Notearje ûnder ‘ferbettere’ it nije
+ferskil.]]
 #box(width: 500pt, inset: (left: 3em, bottom: 1em))[
   #grid(
     correction_title_grid(correction_width: 6em, input_width: 6em),
-    empty_letterbox(cells: 3, original_value: input.previous_results.differences_counts.fewer_ballots_count, light: false, "J")[Aantal minder getelde stemmen (bereken: D _min_ H)]
+    empty_letterbox(cells: 3, original_value: input.previous_results.differences_counts.fewer_ballots_count, light: false, "J")[#tr[Aantal minder getelde stemmen (bereken: D _min_ H)][Oantal minder telde stimmen (berekkenje: D _min_ H)]]
   )
 ]
 
-=== Zijn er tijdens de stemming dingen opgeschreven die het bovenstaande verschil tussen D en H volledig verklaren?
+=== #tr[
+  Zijn er tijdens de stemming dingen opgeschreven die het bovenstaande verschil tussen D en H volledig verklaren?
+][
+  Binne der tidens de stimming dingen opskreaun dy't it boppesteande ferskil tusken D en H hielendal ferklearje?
+]
 
-(Gebruik het proces-verbaal van het stembureau #sym.arrow.r *Tijdens de stemming, vraag 1.2.2*)
+#tr[
+  (Gebruik het proces-verbaal van het stembureau #sym.arrow.r *Tijdens de stemming, vraag 1.2.2*)
+][
+  (Brûk it proses-ferbaal fan it stimburo #sym.arrow.r *Tidens de stimming, fraach 1.2.2*)
+]
 
-#checkbox[Ja #sym.arrow.r *Ga door naar #ref(<per_list_and_candidate>)*]
-#checkbox[Nee, er is een onverklaard verschil]
+#checkbox[#tr[Ja #sym.arrow.r *Ga door naar #ref(<per_list_and_candidate>)*][Ja #sym.arrow.r *Gean troch nei #ref(<per_list_and_candidate>)*]]
+#checkbox[#tr[Nee, er is een onverklaard verschil][Nee, der is in net ferklearre ferskil]]
 
-=== Geef als dat mogelijk is, een toelichting op het nog niet verklaarde verschil tussen het aantal toegelaten kiezers en het aantal uitgebrachte stemmen:
+=== #tr[
+  Geef als dat mogelijk is, een toelichting op het nog niet verklaarde verschil tussen het aantal toegelaten kiezers en het aantal uitgebrachte stemmen:
+][
+  Jou as dat mooglik is, in taljochting op it noch net ferklearre ferskil tusken it oantal talitten kiezers en it oantal útbrochte stimmen:
+]
 #empty_lines(10)
 
 #pagebreak(weak: true)
 
-== Stemmen per lijst en per kandidaat <per_list_and_candidate>
+== #tr[Stemmen per lijst en per kandidaat][Stimmen per list en per kandidaat] <per_list_and_candidate>
 
-Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die door het stembureau of door het #location_type in een eerdere zitting zijn vastgesteld.
+#tr[
+  Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die door het stembureau of door het #location_type in een eerdere zitting zijn vastgesteld.
+][
+  Folje allinnich de getallen yn dy't feroare binne ten opsichte fan in eardere telling. Getallen dy't net feroare binne, hoege net ynfolle te wurden yn de kolom ‘ferbettere’. Under ‘oarspronklik’ steane de getallen dy't troch it stimburo of troch it #location_type yn in eardere sitting fêststeld binne.
+]
 
 #pagebreak(weak: true)
 
 #for political_group in input.votes_tables {
   votes_table(
     title: [#political_group.number #political_group.name],
-    headers: ("Kandidaat", "", "Oorspronkelijk", "Gecorrigeerd"),
+    headers: tr(("Kandidaat", "", "Oorspronkelijk", "Gecorrigeerd"), ("Kandidaat", "", "Oarspronklik", "Ferbettere")),
     total: political_group.total,
     previous_total: political_group.previous_total,
     votes_columns: political_group.columns,
-    continue_on_next_page: [#sym.arrow.r De lijst gaat verder op de volgende pagina],
+    continue_on_next_page: [#sym.arrow.r #tr[De lijst gaat verder op de volgende pagina][De list giet fierder op de folgjende side]],
     column_total: "Subtotaal kolom",
-    sum_total: columns => [Totaal lijst (kolom #columns)],
-    total_instruction: [Neem dit totaal over in rubriek #ref(<cast_votes>) bij de juiste lijst.],
-    explainer_text: [Vul alléén de getallen in die veranderd zijn ten opzichte van de oorspronkelijke telling.]
+    sum_total: columns => [#tr[Totaal lijst][Totaal list] (kolom #columns)],
+    total_instruction: tr[Neem dit totaal over in rubriek #ref(<cast_votes>) bij de juiste lijst.][Nim dit totaal oer yn rubryk #ref(<cast_votes>) by de goede list.],
+    explainer_text: tr[Vul alléén de getallen in die veranderd zijn ten opzichte van de oorspronkelijke telling.][Folje allinnich de getallen yn dy't feroare binne ten opsichte fan de oarspronklike telling.]
   )
 }
 
 #blank_page_before_signing(header-right)
 
-= Ondertekening
+= #tr[Ondertekening][Ûndertekening]
 
-#block(width: 100%, [Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].])
+#block(width: 100%, tr[Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].][It proses-ferbaal moat ûndertekene wurde troch alle oanwêzige leden. By in #location_type binne dat der minimaal #is_local_election[3][5].])
 
 #signing_form_label[Datum]
 
-#textbox_only_bottom_stroke[Datum en tijd:][Plaats:]
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
 
-== Voorzitter en #is_local_election[twee][vier] leden van het #location_type
+== #tr[Voorzitter en #is_local_election[twee][vier] leden van het #location_type][Foarsitter en #is_local_election[twa][fjouwer] leden fan it #location_type]
 
-#signing_form_label[Voorzitter van het #location_type:]
+#signing_form_label[#tr[Voorzitter van het #location_type:][Foarsitter fan it #location_type:]]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[#is_local_election[2][4] leden van het #location_type:]
+#signing_form_label[#is_local_election[2][4] #tr[leden van het][leden fan it] #location_type:]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
 
-== Ondertekening door andere aanwezige leden van het #location_type
+== #tr[Ondertekening door andere aanwezige leden van het][Ûndertekening troch oare oanwêzige leden fan it] #location_type
 
-#signing_form_label[Extra ondertekening:]
+#signing_form_label[#tr[Extra ondertekening:][Ekstra ûndertekening:]]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
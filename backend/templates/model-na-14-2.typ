@@ -4,114 +4,151 @@
 
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
 #let is_local_election = (local, other) => is_local_election(input.election.category, local, other)
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
+
+// Explanation of the 'original' and 'corrected' columns, repeated for every section with corrections
+#let corrections_explanation = tr[
+  Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet
+  ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die in een eerdere zitting door het #location_type zijn vastgesteld.
+][
+  Folje allinnich de getallen yn dy't feroare binne ten opsichte fan in eardere telling. Getallen dy't net feroare binne, hoege net
+  ynfolle te wurden yn de kolom ‘ferbettere’. Under ‘oarspronklik’ steane de getallen dy't yn in eardere sitting troch it #location_type fêststeld binne.
+]
 
 #show: doc => conf(
   doc,
   header-right: location_name,
-  footer: [
+  footer: tr[
     Datum: #input.creation_date_time. Digitale vingerafdruk van EML-telbestand bij dit PV: \
-    #input.hash
+    #input.hash \
+    Digitale vingerafdruk van het auditlog: \
+    #input.audit_log_hash
 
     Corrigendum van een #location_type \
     Model Na 14-2 (versie 2027)
-  ], margin-bottom: 2.9cm, footer-descent: 0.45cm
+  ][
+    Datum: #input.creation_date_time. Digitale fingerôfdruk fan EML-telbestân by dit PV: \
+    #input.hash \
+    Digitale fingerôfdruk fan it auditlog: \
+    #input.audit_log_hash
+
+    Korrigindum fan in #location_type \
+    Model Na 14-2 (ferzje 2027)
+  ], margin-bottom: 3.6cm, footer-descent: 0.45cm
 )
 
 #set heading(numbering: none)
 
 #title_page(
   is_municipality[#input.election.authority_id #input.election.authority_region][#input.election.authority_region],
-  is_municipality[Gemeentelijk stembureau][Stembureau voor het openbaar lichaam],
+  is_municipality(tr[Gemeentelijk stembureau][Gemeentlik stimburo], tr[Stembureau voor het openbaar lichaam][Stimburo foar it iepenbier lichem]),
   [#input.election.name - #format_date(input.election.election_date)],
-  [
+  tr[
     Gecorrigeerde telresultaten per lijst en kandidaat –
     Model Na 14-2
+  ][
+    Ferbettere telresultaten per list en kandidaat –
+    Model Na 14-2
   ],
 )
 
-== Details van het #location_type
+== #tr[Details van het][Details fan it] #location_type
 
 #location_name
 
 #input.committee_session.location #format_date_time(input.committee_session.start_date_time)
 
-== Corrigendum
+== #tr[Corrigendum][Korrigindum]
 
-#is_municipality[Elke gemeente][Elk openbaar lichaam] maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het tellen van de stemmen is verlopen en wat de uitslag van de stemming was. In dat proces-verbaal kunnen fouten staan. Het corrigendum
-corrigeert de fouten in het proces-verbaal. De aantallen in het corrigendum vervangen
-de aantallen in het proces-verbaal.
+#tr[
+  #is_municipality[Elke gemeente][Elk openbaar lichaam] maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het tellen van de stemmen is verlopen en wat de uitslag van de stemming was. In dat proces-verbaal kunnen fouten staan. Het corrigendum
+  corrigeert de fouten in het proces-verbaal. De aantallen in het corrigendum vervangen
+  de aantallen in het proces-verbaal.
+][
+  #is_municipality[Elke gemeente][Elk iepenbier lichem] makket by in ferkiezing in ferslach: it proses-ferbaal. Hjiryn stiet hoe't it tellen fan de stimmen ferrûn is en wat de útslach fan de stimming wie. Yn dat proses-ferbaal kinne flaters stean. It korrigindum
+  ferbetteret de flaters yn it proses-ferbaal. De oantallen yn it korrigindum ferfange
+  de oantallen yn it proses-ferbaal.
+]
 
-#emph_block[
+#emph_block(tr[
   Dit corrigendum gaat over onderzoeken en eventuele hertellingen. Ook staan hierin
   correcties op (tel)fouten die in eerdere verslagen zijn gevonden. Het #location_type heeft dit onderzoek gedaan op
   verzoek van het centraal stembureau. Het corrigendum wordt ingevuld door het #location_type.
-]
+][
+  Dit korrigindum giet oer ûndersiken en eventuele wertellingen. Ek steane hjiryn
+  korreksjes op (tel)flaters dy't yn eardere ferslaggen fûn binne. It #location_type hat dit ûndersyk dien op
+  fersyk fan it sintraal stimburo. It korrigindum wurdt ynfolle troch it #location_type.
+])
+
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
 
-== Inhoudsopgave
+#tr[
+  - Deel 1 - *Gecorrigeerde telresultaten* van #is_municipality[de hele gemeente][het hele openbaar lichaam]
+  - Deel 2 - *Ondertekening* door de leden van het #location_type
 
-- Deel 1 - *Gecorrigeerde telresultaten* van #is_municipality[de hele gemeente][het hele openbaar lichaam]
-- Deel 2 - *Ondertekening* door de leden van het #location_type
+  \
 
-\
+  - Bijlage 1: Gecorrigeerde telresultaten van losse stembureaus (centrale stemopneming)
+][
+  - Diel 1 - *Ferbettere telresultaten* fan #is_municipality[de hiele gemeente][it hiele iepenbier lichem]
+  - Diel 2 - *Ûndertekening* troch de leden fan it #location_type
 
-- Bijlage 1: Gecorrigeerde telresultaten van losse stembureaus (centrale stemopneming)
+  \
+
+  - Taheakke 1: Ferbettere telresultaten fan losse stimburo's (sintrale stimopnimming)
+]
 
 #pagebreak(weak: true)
 
 #show: doc => document_numbering(doc)
 
-= Gecorrigeerde telresultaten van #is_municipality[de gemeente][het openbaar lichaam]
+= #tr[Gecorrigeerde telresultaten van][Ferbettere telresultaten fan] #is_municipality(tr[de gemeente][de gemeente], tr[het openbaar lichaam][it iepenbier lichem])
 
-Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn
-veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen
-die in een eerdere zitting door het #location_type zijn
-vastgesteld.
+#corrections_explanation
 
-== Aantal kiesgerechtigden
+== #tr[Aantal kiesgerechtigden][Oantal kiesgerjochtigen]
 
-#letterbox("Z", value: input.election.number_of_voters)[Kiesgerechtigden]
+#letterbox("Z", value: input.election.number_of_voters)[#tr[Kiesgerechtigden][Kiesgerjochtigen]]
 
-== Toegelaten kiezers
+== #tr[Toegelaten kiezers][Talitten kiezers]
 
 #if not is_local_election(true, false) and "voter_card_count" in input.summary.voters_counts [
-  Het totaal van alle getelde geldige stempassen, volmachtbewijzen en kiezerspassen.
+  #tr[Het totaal van alle getelde geldige stempassen, volmachtbewijzen en kiezerspassen.][It totaal fan alle telde jildige stimpassen, folmachtbewizen en kiezerspassen.]
 
   #sum(
     with_correction_title: true,
-    letterbox("A", original_value: input.previous_summary.voters_counts.poll_card_count, value: input.summary.voters_counts.poll_card_count, bold_top_border: true, wide_cells: true)[Stempassen],
-    letterbox("B", original_value: input.previous_summary.voters_counts.proxy_certificate_count, value: input.summary.voters_counts.proxy_certificate_count, wide_cells: true)[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)],
+    letterbox("A", original_value: input.previous_summary.voters_counts.poll_card_count, value: input.summary.voters_counts.poll_card_count, bold_top_border: true, wide_cells: true)[#tr[Stempassen][Stimpassen]],
+    letterbox("B", original_value: input.previous_summary.voters_counts.proxy_certificate_count, value: input.summary.voters_counts.proxy_certificate_count, wide_cells: true)[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas of kiezerspas)]],
     letterbox("C", original_value: input.previous_summary.voters_counts.voter_card_count, value: input.summary.voters_counts.voter_card_count, wide_cells: true)[Kiezerspassen],
     letterbox("D", original_value: input.previous_summary.voters_counts.total_admitted_voters_count, value: input.summary.voters_counts.total_admitted_voters_count, wide_cells: true, light: false)[
-      *Totaal toegelaten kiezers (A+B+C)*
+      *#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*
     ]
   )
 ] else [
-  Het totaal van alle getelde geldige stempassen en volmachtbewijzen.
+  #tr[Het totaal van alle getelde geldige stempassen en volmachtbewijzen.][It totaal fan alle telde jildige stimpassen en folmachtbewizen.]
 
   #sum(
     with_correction_title: true,
-    letterbox("A", original_value: input.previous_summary.voters_counts.poll_card_count, value: input.summary.voters_counts.poll_card_count, bold_top_border: true, wide_cells: true)[Stempassen],
-    letterbox("B", original_value: input.previous_summary.voters_counts.proxy_certificate_count, value: input.summary.voters_counts.proxy_certificate_count, wide_cells: true)[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)],
+    letterbox("A", original_value: input.previous_summary.voters_counts.poll_card_count, value: input.summary.voters_counts.poll_card_count, bold_top_border: true, wide_cells: true)[#tr[Stempassen][Stimpassen]],
+    letterbox("B", original_value: input.previous_summary.voters_counts.proxy_certificate_count, value: input.summary.voters_counts.proxy_certificate_count, wide_cells: true)[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas)]],
     letterbox("D", original_value: input.previous_summary.voters_counts.total_admitted_voters_count, value: input.summary.voters_counts.total_admitted_voters_count, wide_cells: true, light: false)[
-      *Totaal toegelaten kiezers (A+B)*
+      *#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*
     ]
   )
 ]
 
 #pagebreak(weak: true)
 
-== Uitgebrachte stemmen
-Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet
-ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die in een eerdere zitting door het #location_type zijn vastgesteld.
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen]
+#corrections_explanation
 
 #if input.votes_tables.len() > 0 [
   #sum(
     with_correction_title: true,
     sum(
       ..input.votes_tables.enumerate().map(((idx, list)) => {
-        letterbox(original_value: list.previous_total, value: list.total, bold_top_border: idx == 0, [E.#list.number], wide_cells: true)[Totaal lijst #list.number - #list.name]
+        letterbox(original_value: list.previous_total, value: list.total, bold_top_border: idx == 0, [E.#list.number], wide_cells: true)[#tr[Totaal lijst][Totaal list] #list.number - #list.name]
       }),
       letterbox(
         original_value: input.previous_summary.votes_counts.total_votes_candidates_count,
@@ -119,95 +156,107 @@ ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ st
         "E",
         light: false,
         wide_cells: true
-      )[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.votes_tables.last().number op)],
+      )[#tr[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.votes_tables.last().number op)][*Totaal stimmen op kandidaten* (tel E.1 o/m E.#input.votes_tables.last().number op)]],
     ),
-    letterbox(original_value: input.previous_summary.votes_counts.blank_votes_count, value: input.summary.votes_counts.blank_votes_count, "F", wide_cells: true)[Blanco stemmen],
-    letterbox(original_value: input.previous_summary.votes_counts.invalid_votes_count, value: input.summary.votes_counts.invalid_votes_count, "G", wide_cells: true)[Ongeldige stemmen],
+    letterbox(original_value: input.previous_summary.votes_counts.blank_votes_count, value: input.summary.votes_counts.blank_votes_count, "F", wide_cells: true)[#tr[Blanco stemmen][Blanko stimmen]],
+    letterbox(original_value: input.previous_summary.votes_counts.invalid_votes_count, value: input.summary.votes_counts.invalid_votes_count, "G", wide_cells: true)[#tr[Ongeldige stemmen][Unjildige stimmen]],
     letterbox(
       original_value: input.previous_summary.votes_counts.total_votes_cast_count,
       value: input.summary.votes_counts.total_votes_cast_count,
       "H",
       light: false,
       wide_cells: true
-    )[*Totaal uitgebrachte stemmen (E+F+G)*],
+    )[*#tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen] (E+F+G)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Verschillen tussen aantal kiezers en uitgebrachte stemmen
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
 
-Vul alléén de getallen in die veranderd zijn ten opzichte van een eerdere telling. Getallen die niet zijn veranderd, hoeven niet ingevuld te worden in de kolom ‘gecorrigeerd’. Onder ‘oorspronkelijk’ staan de getallen die in een eerdere zitting door het #location_type zijn vastgesteld.
+#corrections_explanation
 
-=== Is bij *alle afzonderlijke stembureaus* in #is_municipality[deze gemeente][dit openbaar lichaam] het aantal uitgebrachte stemmen en het aantal toegelaten kiezers gelijk?
+=== #tr[
+  Is bij *alle afzonderlijke stembureaus* in #is_municipality[deze gemeente][dit openbaar lichaam] het aantal uitgebrachte stemmen en het aantal toegelaten kiezers gelijk?
+][
+  Is by *alle ôfsûnderlike stimburo's* yn #is_municipality[dizze gemeente][dit iepenbier lichem] it oantal útbrochte stimmen en it oantal talitten kiezers gelyk?
+]
 
 #let differences = input.summary.differences_counts.more_ballots_count.count > 0 or input.summary.differences_counts.fewer_ballots_count.count > 0
 
 #checkbox(checked: not differences)[Ja]
 
-#checkbox(checked: differences)[Nee, er zijn stembureaus met een verschil]
+#checkbox(checked: differences)[#tr[Nee, er zijn stembureaus met een verschil][Nee, der binne stimburo's mei in ferskil]]
 
-=== #if input.summary.differences_counts.more_ballots_count.count > 0 [Voor de stembureaus met de nummers #comma_list(input.summary.differences_counts.more_ballots_count.data_entry_sources.map(p => p.number))] else [In geen van de stembureaus] zijn er *méér* uitgebrachte stemmen dan toegelaten kiezers geteld. Noteer onder ‘gecorrigeerd’ het nieuwe verschil.
+=== #if input.summary.differences_counts.more_ballots_count.count > 0 [#tr[Voor de stembureaus met de nummers][Foar de stimburo's mei de nûmers] #comma_list(input.summary.differences_counts.more_ballots_count.data_entry_sources.map(p => p.number))] else [#tr[In geen van de stembureaus][Yn gjin fan de stimburo's]] #tr[zijn er *méér* uitgebrachte stemmen dan toegelaten kiezers geteld. Noteer onder ‘gecorrigeerd’ het nieuwe verschil.][binne der *mear* útbrochte stimmen as talitten kiezers teld. Notearje ûnder ‘ferbettere’ it nije ferskil.]
 
 #grid(
   rows: auto,
   correction_title_grid(),
-  letterbox("I", original_value: input.previous_summary.differences_counts.more_ballots_count.count, value: input.summary.differences_counts.more_ballots_count.count, bold_top_border: true, wide_cells: true)[Totaal aantal méér getelde stemmen]
+  letterbox("I", original_value: input.previous_summary.differences_counts.more_ballots_count.count, value: input.summary.differences_counts.more_ballots_count.count, bold_top_border: true, wide_cells: true)[#tr[Totaal aantal méér getelde stemmen][Totaal oantal mear telde stimmen]]
 )
 
-=== #if input.summary.differences_counts.fewer_ballots_count.count > 0 [Voor de stembureaus met de nummers #comma_list(input.summary.differences_counts.fewer_ballots_count.data_entry_sources.map(p => p.number))] else [In geen van de stembureaus] zijn er *minder* uitgebrachte stemmen dan toegelaten kiezers geteld. Noteer onder ‘gecorrigeerd’ het nieuwe verschil.
+=== #if input.summary.differences_counts.fewer_ballots_count.count > 0 [#tr[Voor de stembureaus met de nummers][Foar de stimburo's mei de nûmers] #comma_list(input.summary.differences_counts.fewer_ballots_count.data_entry_sources.map(p => p.number))] else [#tr[In geen van de stembureaus][Yn gjin fan de stimburo's]] #tr[zijn er *minder* uitgebrachte stemmen dan toegelaten kiezers geteld. Noteer onder ‘gecorrigeerd’ het nieuwe verschil.][binne der *minder* útbrochte stimmen as talitten kiezers teld. Notearje ûnder ‘ferbettere’ it nije ferskil.]
 
 #grid(
   rows: auto,
   correction_title_grid(),
-  letterbox("J", original_value: input.previous_summary.differences_counts.fewer_ballots_count.count, value: input.summary.differences_counts.fewer_ballots_count.count, bold_top_border: true, wide_cells: true)[Totaal aantal minder getelde stemmen]
+  letterbox("J", original_value: input.previous_summary.differences_counts.fewer_ballots_count.count, value: input.summary.differences_counts.fewer_ballots_count.count, bold_top_border: true, wide_cells: true)[#tr[Totaal aantal minder getelde stemmen][Totaal oantal minder telde stimmen]]
 )
 
 #pagebreak(weak: true)
 
-== Stemmen per lijst en per kandidaat
+== #tr[Stemmen per lijst en per kandidaat][Stimmen per list en per kandidaat]
 
-De kolom ‘gecorrigeerd’ toont alléén de getallen die veranderd zijn ten opzichte van een eerdere telling. Onder ‘oorspronkelijk’ staan de getallen die in een eerdere zitting door het #location_type zijn vastgesteld.
+#tr[
+  De kolom ‘gecorrigeerd’ toont alléén de getallen die veranderd zijn ten opzichte van een eerdere telling. Onder ‘oorspronkelijk’ staan de getallen die in een eerdere zitting door het #location_type zijn vastgesteld.
+][
+  De kolom ‘ferbettere’ toant allinnich de getallen dy't feroare binne ten opsichte fan in eardere telling. Under ‘oarspronklik’ steane de getallen dy't yn in eardere sitting troch it #location_type fêststeld binne.
+]
 
 #pagebreak(weak: true)
 
 #for political_group in input.votes_tables {
   votes_table(
     title: [#political_group.number #political_group.name],
-    headers: ("Kandidaat", "", "Oorspronkelijk", "Gecorrigeerd"),
+    headers: tr(("Kandidaat", "", "Oorspronkelijk", "Gecorrigeerd"), ("Kandidaat", "", "Oarspronklik", "Ferbettere")),
     corrected_cells: 1,
     total: political_group.total,
     previous_total: political_group.previous_total,
     votes_columns: political_group.columns,
-    continue_on_next_page: [#sym.arrow.r De lijst gaat verder op de volgende pagina],
+    continue_on_next_page: [#sym.arrow.r #tr[De lijst gaat verder op de volgende pagina][De list giet fierder op de folgjende side]],
     column_total: "Subtotaal kolom",
-    sum_total: columns => [Totaal lijst (kolom #columns)],
-    explainer_text: [De kolom ‘gecorrigeerd’ toont alléén de getallen die veranderd zijn ten opzichte van de oorspronkelijke telling.],
+    sum_total: columns => [#tr[Totaal lijst][Totaal list] (kolom #columns)],
+    explainer_text: tr[De kolom ‘gecorrigeerd’ toont alléén de getallen die veranderd zijn ten opzichte van de oorspronkelijke telling.][De kolom ‘ferbettere’ toant allinnich de getallen dy't feroare binne ten opsichte fan de oarspronklike telling.],
   )
 }
 
 #blank_page_before_signing(location_name)
 
-= Ondertekening
+= #tr[Ondertekening][Ûndertekening]
 
-Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+#tr[
+  Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+][
+  It proses-ferbaal moat ûndertekene wurde troch alle oanwêzige leden. By in #location_type binne dat der minimaal #is_local_election[3][5].
+]
 
 #signing_form_label[Datum]
 
-#textbox_only_bottom_stroke[Datum en tijd:][Plaats:]
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
 
-== Voorzitter en #is_local_election[twee][vier] leden van het #location_type
+== #tr[Voorzitter en #is_local_election[twee][vier] leden van het][Foarsitter en #is_local_election[twa][fjouwer] leden fan it] #location_type
 
-#signing_form_label[Voorzitter van het #location_type:]
+#signing_form_label[#tr[Voorzitter van het][Foarsitter fan it] #location_type:]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[#is_local_election[2][4] leden van het #location_type:]
+#signing_form_label[#is_local_election[2][4] #tr[leden van het][leden fan it] #location_type:]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
 
-== Ondertekening door andere aanwezige leden van het #location_type
+== #tr[Ondertekening door andere aanwezige leden van het][Ûndertekening troch oare oanwêzige leden fan it] #location_type
 
-#signing_form_label[Extra ondertekening:]
+#signing_form_label[#tr[Extra ondertekening:][Ekstra ûndertekening:]]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
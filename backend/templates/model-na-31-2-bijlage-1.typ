@@ -4,125 +4,167 @@
 
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
 #let is_local_election = (local, other) => is_local_election(input.election.category, local, other)
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
 
 #show: doc => conf(
   doc,
-  header-left: [
+  header-left: tr[
     Bijlage 1 - verslag telling stembureau
+  ][
+    Taheakke 1 - ferslach telling stimburo
   ],
   header-right: [
     #location_name \
-    Stembureau #input.polling_station.number
+    #tr[Stembureau][Stimburo] #input.polling_station.number
   ],
-  footer: [
+  footer: tr[
     Proces-verbaal van een #location_type \
     Model Na 31-2 centrale stemopneming (versie 2027)
+  ][
+    Proses-ferbaal fan in #location_type \
+    Model Na 31-2 sintrale stimopnimming (ferzje 2027)
   ]
 )
 
 #set heading(numbering: none)
 
-= Bijlage 1
+= #tr[Bijlage 1][Taheakke 1]
 
-#attachment_or_corrigendum_title([Verslagen van tellingen van stembureaus])
+#attachment_or_corrigendum_title(tr[Verslagen van tellingen van stembureaus][Ferslaggen fan tellingen fan stimburo's])
 
-_De bijlagen worden separaat gepubliceerd bij het betreffende stembureau._
+#tr[
+  _De bijlagen worden separaat gepubliceerd bij het betreffende stembureau._
+][
+  _De taheakken wurde apart publisearre by it oanbelangjende stimburo._
+]
 
 #pagebreak(weak: true)
 
-#attachment_or_corrigendum_title([Stembureau #input.polling_station.number\ #input.polling_station.name])
+#attachment_or_corrigendum_title([#tr[Stembureau][Stimburo] #input.polling_station.number\ #input.polling_station.name])
 
-== Over deze bijlage
+== #tr[Over deze bijlage][Oer dizze taheakke]
 
-Het stembureau heeft op de dag van de verkiezingen de stemmen per lijst geteld. Het #location_type heeft later op een centrale tellocatie geteld hoeveel stemmen elke kandidaat heeft gekregen. De telresultaten van het #location_type zijn vergeleken met de eerdere tellingen door het stembureau. Alle telresultaten staan in deze bijlage.
+#tr[
+  Het stembureau heeft op de dag van de verkiezingen de stemmen per lijst geteld. Het #location_type heeft later op een centrale tellocatie geteld hoeveel stemmen elke kandidaat heeft gekregen. De telresultaten van het #location_type zijn vergeleken met de eerdere tellingen door het stembureau. Alle telresultaten staan in deze bijlage.
+][
+  It stimburo hat op de dei fan de ferkiezingen de stimmen per list teld. It #location_type hat letter op in sintrale tellokaasje teld hoefolle stimmen elke kandidaat krigen hat. De telresultaten fan it #location_type binne fergelike mei de eardere tellingen troch it stimburo. Alle telresultaten steane yn dizze taheakke.
+]
 
 #show: doc => attachment_numbering(doc, "B1")
 
-= Extra onderzoek: opmerkingen #location_type
+= #tr[Extra onderzoek: opmerkingen][Ekstra ûndersyk: opmerkings] #location_type
 
-#emph_block[
+#emph_block(tr[
   Het #location_type kan tijdens de zitting nadat zij de telling van het stembureau heeft afgerond, extra onderzoek uitvoeren.
   Bijvoorbeeld als er sprake is van een opvallend hoog aantal ongeldige of blanco uitgebrachte
   stemmen. Geef in dit onderdeel aan of, en zo ja, welk extra onderzoek #location_type heeft uitgevoerd.
-]
+][
+  It #location_type kin tidens de sitting neidat it de telling fan it stimburo ôfrûne hat, ekstra ûndersyk útfiere.
+  Bygelyks as der sprake is fan in opfallend heech oantal ûnjildige of blanko útbrochte
+  stimmen. Jou yn dit ûnderdiel oan oft, en sa ja, hokker ekstra ûndersyk #location_type útfierd hat.
+])
 
 #block(below: 1em)
 
-Heeft het #location_type *extra* onderzoek gedaan?
+#tr[Heeft het #location_type *extra* onderzoek gedaan?][Hat it #location_type *ekstra* ûndersyk dien?]
 
 #checkbox[Ja]
 #checkbox[Nee]
 
 #block(below: 1em)
 
-Zijn de stembiljetten naar aanleiding van het *extra* onderzoek (gedeeltelijk) herteld?
+#tr[
+  Zijn de stembiljetten naar aanleiding van het *extra* onderzoek (gedeeltelijk) herteld?
+][
+  Binne de stimbriefkes nei oanlieding fan it *ekstra* ûndersyk (foar in part) op 'e nij teld?
+]
 
 #checkbox[Ja]
 #checkbox[Nee]
 
 #block(below: 1em)
 
-Licht hieronder toe wat de reden van het extra onderzoek was
+#tr[Licht hieronder toe wat de reden van het extra onderzoek was][Ljochtsje hjirûnder ta wat de reden fan it ekstra ûndersyk wie]
 
 #empty_lines(3)
 
 #pagebreak(weak: true)
 
-= Verschillen met telresultaten stembureau
+= #tr[Verschillen met telresultaten stembureau][Ferskillen mei telresultaten stimburo]
 
-== Aantallen kiezers en stemmen
+== #tr[Aantallen kiezers en stemmen][Oantallen kiezers en stimmen]
 
-#emph_block[
+#emph_block(tr[
   Is in de telresultaten van het stembureau (rubriek 2.3 van het proces-verbaal van
   het stembureau) het verschil tussen het totaal aantal getelde stemmen en het aantal
   toegelaten kiezers volledig verklaard? Controleer daarbij ook of rubriek 2.3 van het
   proces-verbaal van het stembureau correct is ingevuld en de verklaringen het verschil
   daadwerkelijk verklaren.
-]
+][
+  Is yn de telresultaten fan it stimburo (rubryk 2.3 fan it proses-ferbaal fan
+  it stimburo) it ferskil tusken it totaal oantal telde stimmen en it oantal
+  talitten kiezers hielendal ferklearre? Kontrolearje dêrby ek oft rubryk 2.3 fan it
+  proses-ferbaal fan it stimburo korrekt ynfolle is en de ferklearrings it ferskil
+  echt ferklearje.
+])
 
 #checkbox[Ja]
-#checkbox[*Nee #sym.arrow.r Hertel het aantal toegelaten kiezers (#is_local_election[stempassen en volmachten][stempassen, kiezerspassen en volmachten])*, en noteer de uitkomsten bij rubriek 3.1]
+#checkbox[#tr[*Nee #sym.arrow.r Hertel het aantal toegelaten kiezers (#is_local_election[stempassen en volmachten][stempassen, kiezerspassen en volmachten])*, en noteer de uitkomsten bij rubriek 3.1][*Nee #sym.arrow.r Tel it oantal talitten kiezers op 'e nij (#is_local_election[stimpassen en folmachten][stimpassen, kiezerspassen en folmachten])*, en notearje de útkomsten by rubryk 3.1]]
 
-== Tel de stembiljetten
+== #tr[Tel de stembiljetten][Tel de stimbriefkes]
 
-#emph_block[
+#emph_block(tr[
   Tel nu de stembiljetten per kandidaat en noteer de uitkomsten bij rubrieken *3.2* en *3.5* van deze bijlage.
-]
+][
+  Tel no de stimbriefkes per kandidaat en notearje de útkomsten by rubriken *3.2* en *3.5* fan dizze taheakke.
+])
 
-== Tellingen op lijstniveau
+== #tr[Tellingen op lijstniveau][Tellingen op listnivo]
 
-#emph_block[
+#emph_block(tr[
   Is er een verschil tussen het totaal aantal getelde stemmen zoals eerder vastgesteld
   door het #underline[*stembureau*] (vak H van rubriek 2.2) en zoals door u geteld op het #underline[*#location_type*] (vak H van rubriek 3.2)?
-]
+][
+  Is der in ferskil tusken it totaal oantal telde stimmen sa't earder fêststeld
+  troch it #underline[*stimburo*] (fak H fan rubryk 2.2) en sa't troch jo teld op it #underline[*#location_type*] (fak H fan rubryk 3.2)?
+])
 
-#checkbox[Ja #sym.arrow.r *Hertel het aantal toegelaten kiezers (tenzij dat bij vraag 2.1 al gedaan is)*, en vul de resultaten in rubriek 3.1 in]
+#checkbox[#tr[Ja #sym.arrow.r *Hertel het aantal toegelaten kiezers (tenzij dat bij vraag 2.1 al gedaan is)*, en vul de resultaten in rubriek 3.1 in][Ja #sym.arrow.r *Tel it oantal talitten kiezers op 'e nij (útsein as dat by fraach 2.1 al dien is)*, en folje de resultaten yn rubryk 3.1 yn]]
 #checkbox[Nee]
 
 #pagebreak(weak: true)
 
-== Lijsten met verschillen
+== #tr[Lijsten met verschillen][Listen mei ferskillen]
 
-=== Noteer alle lijsten waar de telling door het *stembureau* afwijkt van de telling van vandaag door het *#location_type*.
+=== #tr[
+  Noteer alle lijsten waar de telling door het *stembureau* afwijkt van de telling van vandaag door het *#location_type*.
+][
+  Notearje alle listen dêr't de telling troch it *stimburo* ôfwykt fan de telling fan hjoed troch it *#location_type*.
+]
 
 #empty_table(
   columns: (auto, auto, 9em, auto, 29em),
   headers: (
-    [Lijstnummer met verschil],
-    [Lijsttotaal vastgesteld door het stembureau],
-    [Lijsttotaal vastgesteld door het #location_type],
-    [Aantal stemmen verschil],
+    tr[Lijstnummer met verschil][Listnûmer mei ferskil],
+    tr[Lijsttotaal vastgesteld door het stembureau][Listtotaal fêststeld troch it stimburo],
+    tr[Lijsttotaal vastgesteld door het #location_type][Listtotaal fêststeld troch it #location_type],
+    tr[Aantal stemmen verschil][Oantal stimmen ferskil],
     [
-      Toelichting op het telverschil, bijvoorbeeld:
+      #tr[Toelichting op het telverschil, bijvoorbeeld:][Taljochting op it telferskil, bygelyks:]
       #[
         #show list: content => block(width: 125%, content)
         #set text(weight: "regular", size: 7pt)
         #set list(spacing: 0.75em)
-        - Stembiljet was toch blanco, ongeldig of andersom
-        - Stembiljet meegeteld bij verkeerde lijst
-        - Meer of minder stembiljetten geteld dan stembureau
+        #tr[
+          - Stembiljet was toch blanco, ongeldig of andersom
+          - Stembiljet meegeteld bij verkeerde lijst
+          - Meer of minder stembiljetten geteld dan stembureau
+        ][
+          - Stimbriefke wie dochs blanko, ûnjildich of oarsom
+          - Stimbriefke meiteld by ferkearde list
+          - Mear of minder stimbriefkes teld as stimburo
+        ]
       ]
     ],
   ),
@@ -134,104 +176,120 @@ Licht hieronder toe wat de reden van het extra onderzoek was
 
 = Telresultaten
 
-== Toegelaten kiezers <admitted_voters>
+== #tr[Toegelaten kiezers][Talitten kiezers] <admitted_voters>
 
-#emph_block[
+#emph_block(tr[
   Heeft het #location_type het aantal toegelaten kiezers opnieuw geteld? Schrijf dan die aantallen op. Neem anders de aantallen over die het stembureau heeft opgeschreven in het proces-verbaal.
-]
+][
+  Hat it #location_type it oantal talitten kiezers op 'e nij teld? Skriuw dan dy oantallen op. Nim oars de oantallen oer dy't it stimburo opskreaun hat yn it proses-ferbaal.
+])
 
 #is_local_election[
   #sum(
-    empty_letterbox("A")[Stempassen],
-    empty_letterbox("B")[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)],
-    no_entry_letterbox("C")[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)],
-    empty_letterbox("D", light: false)[*Totaal toegelaten kiezers (A+B)*],
+    empty_letterbox("A")[#tr[Stempassen][Stimpassen]],
+    empty_letterbox("B")[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas)]],
+    no_entry_letterbox("C")[#tr[Kiezerspassen (niet van toepassing bij gemeente- en eilandraadsverkiezingen)][Kiezerspassen (net fan tapassing by gemeente- en eilânriedsferkiezingen)]],
+    empty_letterbox("D", light: false)[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*],
   )
 ][
   #sum(
-    empty_letterbox("A")[Stempassen],
-    empty_letterbox("B")[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)],
+    empty_letterbox("A")[#tr[Stempassen][Stimpassen]],
+    empty_letterbox("B")[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas of kiezerspas)]],
     empty_letterbox("C")[Kiezerspassen],
-    empty_letterbox("D", light: false)[*Totaal toegelaten kiezers (A+B+C)*],
+    empty_letterbox("D", light: false)[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Uitgebrachte stemmen <cast_votes>
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen] <cast_votes>
 
 #if input.candidates_tables.len() > 0 [
   #sum(
     sum(
       ..input.candidates_tables.map(list => {
-        empty_letterbox([E.#list.number])[Totaal lijst #list.number - #list.name]
+        empty_letterbox([E.#list.number])[#tr[Totaal lijst][Totaal list] #list.number - #list.name]
       }),
       empty_letterbox(
         "E",
         light: false,
-      )[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.candidates_tables.last().number op)],
+      )[#tr[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.candidates_tables.last().number op)][*Totaal stimmen op kandidaten* (tel E.1 o/m E.#input.candidates_tables.last().number op)]],
     ),
-    empty_letterbox("F")[Blanco stemmen],
-    empty_letterbox("G")[Ongeldige stemmen],
+    empty_letterbox("F")[#tr[Blanco stemmen][Blanko stimmen]],
+    empty_letterbox("G")[#tr[Ongeldige stemmen][Unjildige stimmen]],
     empty_letterbox(
       "H",
       light: false,
-    )[*Totaal uitgebrachte stemmen (E+F+G)*],
+    )[*#tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen] (E+F+G)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Verschillen tussen aantal kiezers en uitgebrachte stemmen
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
 
-=== Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+=== #tr[
+  Vergelijk D (totaal toegelaten kiezers) en H (totaal uitgebrachte stemmen)
+][
+  Fergelykje D (totaal talitten kiezers) en H (totaal útbrochte stimmen)
+]
 
-#checkbox[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<polling_station_declaration>)*]
+#checkbox[#tr[D en H zijn *gelijk* #sym.arrow.r *Ga door naar #ref(<polling_station_declaration>)*][D en H binne *gelyk* #sym.arrow.r *Gean troch nei #ref(<polling_station_declaration>)*]]
 
-#checkbox[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers)]
+#checkbox[#tr[H is *groter* dan D (meer uitgebrachte stemmen dan toegelaten kiezers)][H is *grutter* as D (mear útbrochte stimmen as talitten kiezers)]]
 
 #box(inset: (left: 3em, bottom: 1em), empty_letterbox(
   "I",
   cells: 3,
   light: false,
-)[Aantal méér getelde stemmen (bereken: H _min_ D)])
+)[#tr[Aantal méér getelde stemmen (bereken: H _min_ D)][Oantal mear telde stimmen (berekkenje: H _min_ D)]])
 
-#checkbox[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers)]
+#checkbox[#tr[H is *kleiner* dan D (minder uitgebrachte stemmen dan toegelaten kiezers)][H is *lytser* as D (minder útbrochte stimmen as talitten kiezers)]]
 
 #box(inset: (left: 3em, bottom: 1em), empty_letterbox(
   "J",
   cells: 3,
   light: false,
-)[Aantal minder getelde stemmen (bereken: D _min_ H)])
+)[#tr[Aantal minder getelde stemmen (bereken: D _min_ H)][Oantal minder telde stimmen (berekkenje: D _min_ H)]])
 
-=== Zijn er tijdens de stemming dingen opgeschreven die het bovenstaande verschil tussen D en H *volledig* verklaren?
+=== #tr[
+  Zijn er tijdens de stemming dingen opgeschreven die het bovenstaande verschil tussen D en H *volledig* verklaren?
+][
+  Binne der tidens de stimming dingen opskreaun dy't it boppesteande ferskil tusken D en H *hielendal* ferklearje?
+]
 
-(Gebruik het proces-verbaal van het stembureau #sym.arrow.r *Tijdens de stemming, vraag 1.2.2*)
+#tr[
+  (Gebruik het proces-verbaal van het stembureau #sym.arrow.r *Tijdens de stemming, vraag 1.2.2*)
+][
+  (Brûk it proses-ferbaal fan it stimburo #sym.arrow.r *Tidens de stimming, fraach 1.2.2*)
+]
 
 #checkbox[Ja]
-#checkbox[Nee, er is een onverklaard verschil #sym.arrow.r Hertel het aantal toegelaten kiezers (tenzij dat bij rubriek 2 al gedaan is) en noteer dit bij #ref(<admitted_voters>)]
+#checkbox[#tr[Nee, er is een onverklaard verschil #sym.arrow.r Hertel het aantal toegelaten kiezers (tenzij dat bij rubriek 2 al gedaan is) en noteer dit bij #ref(<admitted_voters>)][Nee, der is in net ferklearre ferskil #sym.arrow.r Tel it oantal talitten kiezers op 'e nij (útsein as dat by rubryk 2 al dien is) en notearje dat by #ref(<admitted_voters>)]]
 
-== Verklaringen vanuit het stembureau <polling_station_declaration>
+== #tr[Verklaringen vanuit het stembureau][Ferklearrings fan it stimburo] <polling_station_declaration>
 
-#emph_block[
+#emph_block(tr[
   Neem de verklaringen over die in het *proces-verbaal van het stembureau bij vraag 1.2.2* staan. Ook als er nu geen telverschil meer is of als het telverschil kleiner is.
-]
+][
+  Nim de ferklearrings oer dy't yn it *proses-ferbaal fan it stimburo by fraach 1.2.2* steane. Ek as der no gjin telferskil mear is of as it telferskil lytser is.
+])
 
 #empty_lines(12)
 
 #pagebreak(weak: true)
 
-== Stemmen per lijst en per kandidaat
+== #tr[Stemmen per lijst en per kandidaat][Stimmen per list en per kandidaat]
 
 #for political_group in input.candidates_tables {
   votes_table(
     title: [#political_group.number #political_group.name],
-    headers: ("Kandidaat", "", "Stemmen"),
+    headers: tr(("Kandidaat", "", "Stemmen"), ("Kandidaat", "", "Stimmen")),
     total: political_group.total,
     votes_columns: political_group.columns,
-    continue_on_next_page: [#sym.arrow.r De lijst gaat verder op de volgende pagina],
+    continue_on_next_page: [#sym.arrow.r #tr[De lijst gaat verder op de volgende pagina][De list giet fierder op de folgjende side]],
     column_total: "Subtotaal kolom",
-    sum_total: columns => [Totaal lijst (kolom #columns)],
-    total_instruction: [Neem dit totaal over in rubriek #ref(<cast_votes>) van deze bijlage bij de juiste lijst.],
+    sum_total: columns => [#tr[Totaal lijst][Totaal list] (kolom #columns)],
+    total_instruction: tr[Neem dit totaal over in rubriek #ref(<cast_votes>) van deze bijlage bij de juiste lijst.][Nim dit totaal oer yn rubryk #ref(<cast_votes>) fan dizze taheakke by de goede list.],
   )
 }
@@ -3,36 +3,43 @@
 #let input = json("inputs/model-na-31-2-inlegvel.json")
 
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
 
 #show: doc => conf(
   doc,
-  header-left: [
+  header-left: tr[
     Controles en correcties
+  ][
+    Kontrôles en korreksjes
   ],
   header-right: location_name,
-  footer: [
+  footer: tr[
     Proces-verbaal van een #location_type\
     Model Na 31-2 centrale stemopneming (versie 2027)
+  ][
+    Proses-ferbaal fan in #location_type\
+    Model Na 31-2 sintrale stimopnimming (ferzje 2027)
   ]
 )
 
-#set page(numbering: (_, _) => [Later ingevoegde pagina])
+#set page(numbering: (_, _) => tr[Later ingevoegde pagina][Letter ynfoege side])
 #set heading(numbering: none)
 
-= Controles in opdracht van het centraal stembureau
+= #tr[Controles in opdracht van het centraal stembureau][Kontrôles yn opdracht fan it sintraal stimburo]
 
-#emph_block[
+#emph_block(tr[
   Deze pagina is toegevoegd door de leden van het #location_type. In opdracht van het centraal stembureau heeft het #location_type de in dit proces-verbaal opgenomen aantallen (opnieuw) onderzocht. Voeg deze pagina toe na het voorblad van het oorspronkelijke proces-verbaal van het #location_type (Na 31-2).
-]
+][
+  Dizze side is tafoege troch de leden fan it #location_type. Yn opdracht fan it sintraal stimburo hat it #location_type de yn dit proses-ferbaal opnommen oantallen (opnij) ûndersocht. Foegje dizze side ta nei it foarblêd fan it oarspronklike proses-ferbaal fan it #location_type (Na 31-2).
+])
 
-=== Zijn er gecorrigeerde telresultaten?
+=== #tr[Zijn er gecorrigeerde telresultaten?][Binne der ferbettere telresultaten?]
 
-#checkbox[Nee, de oorspronkelijke telresultaten waren correct]
+#checkbox[#tr[Nee, de oorspronkelijke telresultaten waren correct][Nee, de oarspronklike telresultaten wiene korrekt]]
 
-#checkbox[Ja, er zijn gecorrigeerde telresultaten (zie voor de correcties het corrigendum dat bij dit proces-verbaal is gevoegd)]
+#checkbox[#tr[Ja, er zijn gecorrigeerde telresultaten (zie voor de correcties het corrigendum dat bij dit proces-verbaal is gevoegd)][Ja, der binne ferbettere telresultaten (sjoch foar de korreksjes it korrigindum dat by dit proses-ferbaal foege is)]]
 
-=== Opgesteld door het #location_type
+=== #tr[Opgesteld door het][Opsteld troch it] #location_type
 
-#textbox[Datum en tijd:]
+#textbox[#tr[Datum en tijd:][Datum en tiid:]]
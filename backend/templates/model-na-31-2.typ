@@ -4,96 +4,149 @@
 
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
 #let is_local_election = (local, other) => is_local_election(input.election.category, local, other)
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
-#let this_location = is_municipality[deze gemeente][dit openbaar lichaam]
-#let the_location = is_municipality[de gemeente][het openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
+#let this_location = is_municipality(tr[deze gemeente][dizze gemeente], tr[dit openbaar lichaam][dit iepenbier lichem])
+#let the_location = is_municipality(tr[de gemeente][de gemeente], tr[het openbaar lichaam][it iepenbier lichem])
 
 #show: doc => conf(
   doc,
   header-right: location_name,
-  footer: [
+  footer: tr[
     Datum: #input.creation_date_time. Digitale vingerafdruk van EML-telbestand bij dit PV: \
-    #input.hash
+    #input.hash \
+    Digitale vingerafdruk van het auditlog: \
+    #input.audit_log_hash
 
     Proces-verbaal van een #location_type \
     Model Na 31-2 centrale stemopneming (versie 2027)
-  ], margin-bottom: 2.9cm, footer-descent: 0.45cm
+  ][
+    Datum: #input.creation_date_time. Digitale fingerôfdruk fan EML-telbestân by dit PV: \
+    #input.hash \
+    Digitale fingerôfdruk fan it auditlog: \
+    #input.audit_log_hash
+
+    Proses-ferbaal fan in #location_type \
+    Model Na 31-2 sintrale stimopnimming (ferzje 2027)
+  ], margin-bottom: 3.6cm, footer-descent: 0.45cm
 )
 
 #set heading(numbering: none)
 
 #title_page(
   is_municipality[#input.election.authority_id #input.election.authority_region][#input.election.authority_region],
-  is_municipality[Gemeentelijk stembureau][Stembureau voor het openbaar lichaam],
+  is_municipality(tr[Gemeentelijk stembureau][Gemeentlik stimburo], tr[Stembureau voor het openbaar lichaam][Stimburo foar it iepenbier lichem]),
   [#input.election.name - #format_date(input.election.election_date)],
-  [
+  tr[
     Verslag en telresultaten per lijst en kandidaat -
     Model Na 31-2
+  ][
+    Ferslach en telresultaten per list en kandidaat -
+    Model Na 31-2
   ],
 )
 
-== Details van het #location_type
+== #tr[Details van het][Details fan it] #location_type
 
 #location_name
 
 #input.committee_session.location #format_date_time(input.committee_session.start_date_time)
 
-== Proces-verbaal
+== #tr[Proces-verbaal][Proses-ferbaal]
 
-#is_municipality[Elke gemeente][Elk openbaar lichaam] maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het tellen van de stemmen is verlopen en wat de uitslag van de stemming was.
+#tr[
+  #is_municipality[Elke gemeente][Elk openbaar lichaam] maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het tellen van de stemmen is verlopen en wat de uitslag van de stemming was.
+][
+  #is_municipality[Elke gemeente][Elk iepenbier lichem] makket by in ferkiezing in ferslach: it proses-ferbaal. Hjiryn stiet hoe't it tellen fan de stimmen ferrûn is en wat de útslach fan de stimming wie.
+]
 
-#emph_block[
+#emph_block(tr[
   In #this_location is gekozen voor *centrale stemopneming*.
   Ieder stembureau heeft direct na het stemmen geteld hoeveel stemmen elke lijst
   kreeg. Het *#location_type* telt de stemmen per kandidaat en telt daarna de resultaten van alle stembureaus bij elkaar op.
-]
+][
+  Yn #this_location is keazen foar *sintrale stimopnimming*.
+  Elk stimburo hat daliks nei it stimmen teld hoefolle stimmen elke list
+  krige. It *#location_type* telt de stimmen per kandidaat en telt dêrnei de resultaten fan alle stimburo's byinoar op.
+])
 
-== Inhoudsopgave
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
 
-- Deel 1 - *Verslag van de zitting* (het verloop van het tellen en optellen)
-- Deel 2 - *Telresultaten* van #is_municipality[de hele gemeente][het hele openbaar lichaam]
-- Deel 3 - *Ondertekening* door de leden van het #location_type
+#tr[
+  - Deel 1 - *Verslag van de zitting* (het verloop van het tellen en optellen)
+  - Deel 2 - *Telresultaten* van #is_municipality[de hele gemeente][het hele openbaar lichaam]
+  - Deel 3 - *Ondertekening* door de leden van het #location_type
 
-\
+  \
 
-- Bijlage 1: Telresultaten van alle stembureaus in #the_location
-- Bijlage 2: Overzicht van alle bezwaren die op de stembureaus zijn gemaakt
+  - Bijlage 1: Telresultaten van alle stembureaus in #the_location
+  - Bijlage 2: Overzicht van alle bezwaren die op de stembureaus zijn gemaakt
+][
+  - Diel 1 - *Ferslach fan de sitting* (it ferrin fan it tellen en optellen)
+  - Diel 2 - *Telresultaten* fan #is_municipality[de hiele gemeente][it hiele iepenbier lichem]
+  - Diel 3 - *Ûndertekening* troch de leden fan it #location_type
+
+  \
+
+  - Taheakke 1: Telresultaten fan alle stimburo's yn #the_location
+  - Taheakke 2: Oersjoch fan alle beswieren dy't op de stimburo's makke binne
+]
 
 #pagebreak(weak: true)
 
 #show: doc => document_numbering(doc)
 
-= Verslag van de zitting
+= #tr[Verslag van de zitting][Ferslach fan de sitting]
 
-== Presentielijst
+== #tr[Presentielijst][Presinsjelist]
 
-=== Aanwezige leden van het #location_type
+=== #tr[Aanwezige leden van het][Oanwêzige leden fan it] #location_type
 
-De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid.
+#tr[
+  De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid.
+][
+  De folgjende rollen binne mooglik: foarsitter, plakferfangend foarsitter of lid.
+]
 
 #empty_table(
   columns: (8em, 1fr, 1fr, 1fr, 8em),
-  headers: ("Voorletters", "Achternaam", "Rol", "Aanwezig van - tot", "Locatie"),
+  headers: tr(
+    ("Voorletters", "Achternaam", "Rol", "Aanwezig van - tot", "Locatie"),
+    ("Foarletters", "Efternamme", "Rol", "Oanwêzich fan - oant", "Lokaasje"),
+  ),
   values: ("", "", "", "-", ""),
   rows: 24,
 )
 
 #pagebreak(weak: true)
 
-== Getelde stembureaus
+== #tr[Getelde stembureaus][Telde stimburo's]
 
-=== De resultaten van onderstaande stembureaus zijn door het #location_type gecontroleerd en opgeteld tot het totaal van #the_location. Als er extra onderzoeken hebben plaatsgevonden, dan kan dat in de laatste drie kolommen worden aangegeven.
+=== #tr[
+  De resultaten van onderstaande stembureaus zijn door het #location_type gecontroleerd en opgeteld tot het totaal van #the_location. Als er extra onderzoeken hebben plaatsgevonden, dan kan dat in de laatste drie kolommen worden aangegeven.
+][
+  De resultaten fan de ûndersteande stimburo's binne troch it #location_type kontrolearre en optelle ta it totaal fan #the_location. As der ekstra ûndersiken west hawwe, dan kin dat yn de lêste trije kolommen oanjûn wurde.
+]
 
 #light_table(
   columns: (5em, 1fr, 1fr, 6em, 6em, 6em),
-  headers: (
-    [Nr.],
-    [Naam locatie],
-    [Postcode + Adres],
-    [Toegelaten kiezers opnieuw vastgesteld?],
-    [Onderzocht vanwege andere reden dan onverklaard verschil?],
-    [Stembiljetten (deels) herteld?],
+  headers: tr(
+    (
+      [Nr.],
+      [Naam locatie],
+      [Postcode + Adres],
+      [Toegelaten kiezers opnieuw vastgesteld?],
+      [Onderzocht vanwege andere reden dan onverklaard verschil?],
+      [Stembiljetten (deels) herteld?],
+    ),
+    (
+      [Nr.],
+      [Namme lokaasje],
+      [Postkoade + Adres],
+      [Talitten kiezers opnij fêststeld?],
+      [Undersocht fanwegen in oare reden as in net ferklearre ferskil?],
+      [Stimbriefkes (foar in part) opnij teld?],
+    ),
   ),
   values: input
     .polling_stations
@@ -103,7 +156,7 @@ De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid
         [#polling_station.name],
         [
           #if "polling_station_type" in polling_station and polling_station.polling_station_type == "Mobile" [
-            _(Mobiel stembureau)_
+            _(#tr[Mobiel stembureau][Mobyl stimburo])_
           ] else [
             #polling_station.address \
             #polling_station.postal_code #polling_station.locality
@@ -117,87 +170,106 @@ De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid
     .flatten(),
 )
 
-=== Voor de stembureaus waar onderzoek naar is gedaan kan hieronder een toelichting worden gegeven. Het geven van een toelichting is niet verplicht.
+=== #tr[
+  Voor de stembureaus waar onderzoek naar is gedaan kan hieronder een toelichting worden gegeven. Het geven van een toelichting is niet verplicht.
+][
+  Foar de stimburo's dêr't ûndersyk nei dien is, kin hjirûnder in taljochting jûn wurde. It jaan fan in taljochting is net ferplicht.
+]
 
 #empty_table(
   columns: (7em, 1fr),
-  headers: ("Nummer stembureau", "Toelichting"),
+  headers: tr(("Nummer stembureau", "Toelichting"), ("Nûmer stimburo", "Taljochting")),
   values: ("", ""),
   rows: 16,
 )
 
 #pagebreak(weak: true)
 
-== Tijdens de zitting
+== #tr[Tijdens de zitting][Tidens de sitting]
 
-=== Schrijf alle *bezwaren* van aanwezigen op.
+=== #tr[Schrijf alle *bezwaren* van aanwezigen op.][Skriuw alle *beswieren* fan oanwêzigen op.]
 
-Bijvoorbeeld over het ongeldig verklaren van een stembiljet. Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u het er niet mee eens bent. Geef aan hoe het bezwaar door het #location_type is behandeld.
+#tr[
+  Bijvoorbeeld over het ongeldig verklaren van een stembiljet. Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u het er niet mee eens bent. Geef aan hoe het bezwaar door het #location_type is behandeld.
+][
+  Bygelyks oer it ûnjildich ferklearjen fan in stimbriefke. Skriuw gjin nammen of oare persoansgegevens op. Skriuw alle beswieren op, ek as jo it der net mei iens binne. Jou oan hoe't it beswier troch it #location_type behannele is.
+]
 
 #empty_table(
   columns: (7em, 1fr, 1fr),
-  headers: ("Tijdstip", "Bezwaar", [Reactie #location_type]),
+  headers: tr(
+    ("Tijdstip", "Bezwaar", [Reactie #location_type]),
+    ("Tiidstip", "Beswier", [Reaksje #location_type]),
+  ),
   values: ("", "", ""),
   rows: 10,
 )
 
-=== Andere *bijzonderheden* die mogelijk invloed hebben op het telproces of de resultaten van het #location_type.
+=== #tr[
+  Andere *bijzonderheden* die mogelijk invloed hebben op het telproces of de resultaten van het #location_type.
+][
+  Oare *bysûnderheden* dy't mooglik ynfloed hawwe op it telproses of de resultaten fan it #location_type.
+]
 
-Bijvoorbeeld een schorsing of als er meerdere verkiezingen tegelijk werden georganiseerd en een stembiljet in de verkeerde stembus zat.
+#tr[
+  Bijvoorbeeld een schorsing of als er meerdere verkiezingen tegelijk werden georganiseerd en een stembiljet in de verkeerde stembus zat.
+][
+  Bygelyks in skorsing of as der meardere ferkiezingen tagelyk organisearre waarden en in stimbriefke yn de ferkearde stimbus siet.
+]
 
 #empty_table(
   columns: (7em, 1fr),
-  headers: ("Tijdstip", "Bijzonderheid"),
+  headers: tr(("Tijdstip", "Bijzonderheid"), ("Tiidstip", "Bysûnderheid")),
   values: ("", ""),
   rows: 5,
 )
 
 #pagebreak(weak: true)
 
-= Telresultaten van #the_location
+= #tr[Telresultaten van][Telresultaten fan] #the_location
 
-== Aantal kiesgerechtigden
+== #tr[Aantal kiesgerechtigden][Oantal kiesgerjochtigen]
 
-#letterbox("Z", value: input.election.number_of_voters)[Kiesgerechtigden]
+#letterbox("Z", value: input.election.number_of_voters)[#tr[Kiesgerechtigden][Kiesgerjochtigen]]
 
-== Toegelaten kiezers
+== #tr[Toegelaten kiezers][Talitten kiezers]
 
 #if not is_local_election(true, false) and "voter_card_count" in input.summary.voters_counts [
-  Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen
+  #tr[Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen][Tel it oantal jildige stimpassen, folmachtbewizen en kiezerspassen]
 
   #sum(
-    letterbox("A", value: input.summary.voters_counts.poll_card_count)[Stempassen],
+    letterbox("A", value: input.summary.voters_counts.poll_card_count)[#tr[Stempassen][Stimpassen]],
     letterbox(
       "B",
       value: input.summary.voters_counts.proxy_certificate_count,
-    )[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)],
+    )[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas of kiezerspas)]],
     letterbox("C", value: input.summary.voters_counts.voter_card_count)[Kiezerspassen],
     letterbox(
       "D",
       light: false,
       value: input.summary.voters_counts.total_admitted_voters_count,
-    )[*Totaal toegelaten kiezers (A+B+C)*],
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*],
   )
 ] else [
-  Tel het aantal geldige stempassen en volmachtbewijzen
+  #tr[Tel het aantal geldige stempassen en volmachtbewijzen][Tel it oantal jildige stimpassen en folmachtbewizen]
 
   #sum(
-    letterbox("A", value: input.summary.voters_counts.poll_card_count)[Stempassen],
+    letterbox("A", value: input.summary.voters_counts.poll_card_count)[#tr[Stempassen][Stimpassen]],
     letterbox(
       "B",
       value: input.summary.voters_counts.proxy_certificate_count,
-    )[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)],
+    )[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas)]],
     letterbox(
       "D",
       light: false,
       value: input.summary.voters_counts.total_admitted_voters_count,
-    )[*Totaal toegelaten kiezers (A+B)*],
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Uitgebrachte stemmen
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen]
 
 #if input.votes_tables.len() > 0 [
   #sum(
@@ -209,103 +281,111 @@ Bijvoorbeeld een schorsing of als er meerdere verkiezingen tegelijk werden georg
           return
         }
 
-        letterbox([E.#list.number], value: votes.total)[Totaal lijst #list.number - #list.name]
+        letterbox([E.#list.number], value: votes.total)[#tr[Totaal lijst][Totaal list] #list.number - #list.name]
       }),
       letterbox(
         "E",
         light: false,
         value: input.summary.votes_counts.total_votes_candidates_count,
-      )[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.votes_tables.last().number op)],
+      )[#tr[*Totaal stemmen op kandidaten* (tel E.1 t/m E.#input.votes_tables.last().number op)][*Totaal stimmen op kandidaten* (tel E.1 o/m E.#input.votes_tables.last().number op)]],
     ),
-    letterbox("F", value: input.summary.votes_counts.blank_votes_count)[Blanco stemmen],
-    letterbox("G", value: input.summary.votes_counts.invalid_votes_count)[Ongeldige stemmen],
+    letterbox("F", value: input.summary.votes_counts.blank_votes_count)[#tr[Blanco stemmen][Blanko stimmen]],
+    letterbox("G", value: input.summary.votes_counts.invalid_votes_count)[#tr[Ongeldige stemmen][Unjildige stimmen]],
     letterbox(
       "H",
       light: false,
       value: input.summary.votes_counts.total_votes_cast_count,
-    )[*Totaal uitgebrachte stemmen (E+F+G)*],
+    )[*#tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen] (E+F+G)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Verschillen tussen aantal kiezers en uitgebrachte stemmen
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
 
-=== Is bij *alle afzonderlijke stembureaus* in #this_location het aantal uitgebrachte stemmen en het aantal toegelaten kiezers gelijk?
+=== #tr[
+  Is bij *alle afzonderlijke stembureaus* in #this_location het aantal uitgebrachte stemmen en het aantal toegelaten kiezers gelijk?
+][
+  Is by *alle ôfsûnderlike stimburo's* yn #this_location it oantal útbrochte stimmen en it oantal talitten kiezers gelyk?
+]
 
 #let differences = input.summary.differences_counts.more_ballots_count.count > 0 or input.summary.differences_counts.fewer_ballots_count.count > 0
 
 #checkbox(checked: not differences)[Ja]
 
-#checkbox(checked: differences)[Nee, er zijn stembureaus met een verschil]
+#checkbox(checked: differences)[#tr[Nee, er zijn stembureaus met een verschil][Nee, der binne stimburo's mei in ferskil]]
 
-=== #if input.summary.differences_counts.more_ballots_count.count > 0 [Voor de stembureaus met de nummers #comma_list(input.summary.differences_counts.more_ballots_count.data_entry_sources.map(p => p.number))] else [In geen van de stembureaus] zijn er *méér* uitgebrachte stemmen dan toegelaten kiezers geteld.
+=== #if input.summary.differences_counts.more_ballots_count.count > 0 [#tr[Voor de stembureaus met de nummers][Foar de stimburo's mei de nûmers] #comma_list(input.summary.differences_counts.more_ballots_count.data_entry_sources.map(p => p.number))] else [#tr[In geen van de stembureaus][Yn gjin fan de stimburo's]] #tr[zijn er *méér* uitgebrachte stemmen dan toegelaten kiezers geteld.][binne der *mear* útbrochte stimmen as talitten kiezers teld.]
 
 #letterbox(
   "I",
   value: input.summary.differences_counts.more_ballots_count.count,
-)[Totaal aantal méér getelde stemmen]
+)[#tr[Totaal aantal méér getelde stemmen][Totaal oantal mear telde stimmen]]
 
-=== #if input.summary.differences_counts.fewer_ballots_count.count > 0 [Voor de stembureaus met de nummers #comma_list(input.summary.differences_counts.fewer_ballots_count.data_entry_sources.map(p => p.number))] else [In geen van de stembureaus] zijn er *minder* uitgebrachte stemmen dan toegelaten kiezers geteld.
+=== #if input.summary.differences_counts.fewer_ballots_count.count > 0 [#tr[Voor de stembureaus met de nummers][Foar de stimburo's mei de nûmers] #comma_list(input.summary.differences_counts.fewer_ballots_count.data_entry_sources.map(p => p.number))] else [#tr[In geen van de stembureaus][Yn gjin fan de stimburo's]] #tr[zijn er *minder* uitgebrachte stemmen dan toegelaten kiezers geteld.][binne der *minder* útbrochte stimmen as talitten kiezers teld.]
 
 #letterbox(
   "J",
   value: input.summary.differences_counts.fewer_ballots_count.count,
-)[Totaal aantal minder getelde stemmen]
+)[#tr[Totaal aantal minder getelde stemmen][Totaal oantal minder telde stimmen]]
 
-== Uitkomst controleprotocol <monitoring_protocol>
+== #tr[Uitkomst controleprotocol][Útkomst kontrôleprotokol] <monitoring_protocol>
 
-Voer de controle uit volgens de stappen in het controleprotocol.
+#tr[Voer de controle uit volgens de stappen in het controleprotocol.][Fier de kontrôle út neffens de stappen yn it kontrôleprotokol.]
 
-=== Kruis aan wat van toepassing is:
+=== #tr[Kruis aan wat van toepassing is:][Krúsje oan wat fan tapassing is:]
 
-#checkbox(checked: false)[Er zijn geen verschillen geconstateerd.]
+#checkbox(checked: false)[#tr[Er zijn geen verschillen geconstateerd.][Der binne gjin ferskillen konstatearre.]]
 
-#checkbox(checked: false)[Er zijn verschillen geconstateerd. Er is contact opgenomen met de Kiesraad. Noteer hieronder wat daarvan de uitkomst is:]
+#checkbox(checked: false)[#tr[Er zijn verschillen geconstateerd. Er is contact opgenomen met de Kiesraad. Noteer hieronder wat daarvan de uitkomst is:][Der binne ferskillen konstatearre. Der is kontakt opnommen mei de Kiesried. Notearje hjirûnder wat dêrfan de útkomst is:]]
 
 #empty_lines(5)
 
 #pagebreak(weak: true)
 
-== Stemmen per lijst en per kandidaat
+== #tr[Stemmen per lijst en per kandidaat][Stimmen per list en per kandidaat]
 
 #pagebreak(weak: true)
 
 #for political_group in input.votes_tables {
   votes_table(
     title: [#political_group.number #political_group.name],
-    headers: ("Kandidaat", "", "Stemmen"),
+    headers: tr(("Kandidaat", "", "Stemmen"), ("Kandidaat", "", "Stimmen")),
     total: political_group.total,
     votes_columns: political_group.columns,
-    continue_on_next_page: [#sym.arrow.r De lijst gaat verder op de volgende pagina],
+    continue_on_next_page: [#sym.arrow.r #tr[De lijst gaat verder op de volgende pagina][De list giet fierder op de folgjende side]],
     column_total: "Subtotaal kolom",
     column_total_with_border: false,
-    sum_total: columns => [Totaal lijst (kolom #columns)],
+    sum_total: columns => [#tr[Totaal lijst][Totaal list] (kolom #columns)],
   )
 }
 
 #blank_page_before_signing(location_name)
 
-= Ondertekening
+= #tr[Ondertekening][Ûndertekening]
 
-Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+#tr[
+  Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+][
+  It proses-ferbaal moat ûndertekene wurde troch alle oanwêzige leden. By in #location_type binne dat der minimaal #is_local_election[3][5].
+]
 
 #signing_form_label[Datum]
 
-#textbox_only_bottom_stroke[Datum en tijd:][Plaats:]
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
 
-== Voorzitter en #is_local_election[twee][vier] leden van het #location_type
+== #tr[Voorzitter en #is_local_election[twee][vier] leden van het][Foarsitter en #is_local_election[twa][fjouwer] leden fan it] #location_type
 
-#signing_form_label[Voorzitter van het #location_type:]
+#signing_form_label[#tr[Voorzitter van het][Foarsitter fan it] #location_type:]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[#is_local_election[2][4] leden van het #location_type:]
+#signing_form_label[#is_local_election[2][4] #tr[leden van het][leden fan it] #location_type:]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
 
-== Ondertekening door andere aanwezige leden van het #location_type
+== #tr[Ondertekening door andere aanwezige leden van het][Ûndertekening troch oare oanwêzige leden fan it] #location_type
 
-#signing_form_label[Extra ondertekening:]
+#signing_form_label[#tr[Extra ondertekening:][Ekstra ûndertekening:]]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
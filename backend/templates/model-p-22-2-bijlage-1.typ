@@ -2,36 +2,46 @@
 #import "common/scripts.typ": *
 #let input = json("inputs/model-p-22-2-bijlage-1.json")
 
-#let location_type = [centraal stembureau]
+#let location_type = tr[centraal stembureau][sintraal stimburo]
 
 #show: doc => conf(
   doc,
-  header-left: [Bijlage 1 – Stemmen per lijst en per kandidaat],
-  header-right: [Centraal stembureau #input.election.authority_region],
-  footer: [
+  header-left: tr[Bijlage 1 – Stemmen per lijst en per kandidaat][Taheakke 1 – Stimmen per list en per kandidaat],
+  header-right: [#tr[Centraal stembureau][Sintraal stimburo] #input.election.authority_region],
+  footer: tr[
     Datum: #input.creation_date_time. Digitale vingerafdruk van EML-telbestand bij dit PV: \
-    #input.hash
+    #input.hash \
+    Digitale vingerafdruk van het auditlog: \
+    #input.audit_log_hash
 
     Proces-verbaal van het #location_type \
     Model P 22-2 (versie 2027)
-  ], margin-bottom: 2.9cm, footer-descent: 0.45cm
+  ][
+    Datum: #input.creation_date_time. Digitale fingerôfdruk fan EML-telbestân by dit PV: \
+    #input.hash \
+    Digitale fingerôfdruk fan it auditlog: \
+    #input.audit_log_hash
+
+    Proses-ferbaal fan it #location_type \
+    Model P 22-2 (ferzje 2027)
+  ], margin-bottom: 3.6cm, footer-descent: 0.45cm
 )
 
-= Bijlage 1
+= #tr[Bijlage 1][Taheakke 1]
 
-#attachment_or_corrigendum_title([Stemmen per lijst en per kandidaat])
+#attachment_or_corrigendum_title(tr[Stemmen per lijst en per kandidaat][Stimmen per list en per kandidaat])
 
 #pagebreak(weak: true)
 
 #for political_group in input.votes_tables {
   votes_table(
     title: [#political_group.number #political_group.name],
-    headers: ("Kandidaat", "", "Stemmen"),
+    headers: tr(("Kandidaat", "", "Stemmen"), ("Kandidaat", "", "Stimmen")),
     total: political_group.total,
     votes_columns: political_group.columns,
-    continue_on_next_page: [#sym.arrow.r De lijst gaat verder op de volgende pagina],
+    continue_on_next_page: [#sym.arrow.r #tr[De lijst gaat verder op de volgende pagina][De list giet fierder op de folgjende side]],
     column_total: "Subtotaal kolom",
     column_total_with_border: false,
-    sum_total: columns => [Totaal lijst (kolom #columns)],
+    sum_total: columns => tr[Totaal lijst (kolom #columns)][Totaal list (kolom #columns)],
   )
 }
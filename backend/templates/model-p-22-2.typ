@@ -3,56 +3,86 @@
 #let input = json("inputs/model-p-22-2-variations/lt-19-seats-and-p9-and-p10.json")
 
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = [centraal stembureau]
-#let subcommittee_type = [gemeentelijk stembureau]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = tr[centraal stembureau][sintraal stimburo]
+#let subcommittee_type = tr[gemeentelijk stembureau][gemeentlik stimburo]
 #let LARGE_COUNCIL_THRESHOLD = 19
 
-#let header-right = [Centraal stembureau #input.election.location]
+#let header-right = [#tr[Centraal stembureau][Sintraal stimburo] #input.election.location]
 
 #show: doc => conf(
   doc,
   header-right: header-right,
-  footer: [
+  footer: tr[
     Datum: #input.creation_date_time. Digitale vingerafdruk van EML-telbestand bij dit PV: \
-    #input.hash
+    #input.hash \
+    Digitale vingerafdruk van het auditlog: \
+    #input.audit_log_hash
 
     Proces-verbaal van het #location_type \
     Model P 22-2 (versie 2027)
-  ], margin-bottom: 2.9cm, footer-descent: 0.45cm
+  ][
+    Datum: #input.creation_date_time. Digitale fingerôfdruk fan EML-telbestân by dit PV: \
+    #input.hash \
+    Digitale fingerôfdruk fan it auditlog: \
+    #input.audit_log_hash
+
+    Proses-ferbaal fan it #location_type \
+    Model P 22-2 (ferzje 2027)
+  ], margin-bottom: 3.6cm, footer-descent: 0.45cm
 )
 
 #set heading(numbering: none)
 
 #title_page(
   is_municipality[#input.election.authority_id #input.election.authority_region][#input.election.authority_region],
-  [Centraal Stembureau],
+  tr[Centraal Stembureau][Sintraal Stimburo],
   [#input.election.name - #format_date(input.election.election_date)],
-  [
+  tr[
     Verslag, uitslag en zetelverdeling – Model P 22-2
+  ][
+    Ferslach, útslach en sitferdieling – Model P 22-2
   ],
 )
 
-== Details van het #location_type
+== #tr[Details van het][Details fan it] #location_type
 
 #input.committee_session.location #format_date_time(input.committee_session.start_date_time).
 
-== Proces-verbaal
+== #tr[Proces-verbaal][Proses-ferbaal]
 
-Het #location_type maakt bij een verkiezing een verslag van de controlewerkzaamheden, de uitslag en de zetelverdeling. Dit heet een proces-verbaal.
-
-== Inhoudsopgave
-- Deel 1 - *Verslag van de zitting*
-- Deel 2 - *Uitslag en zetelverdeling*
-- Deel 3 - *Ondertekening* door de leden van het #location_type
-
-\
+#tr[
+  Het #location_type maakt bij een verkiezing een verslag van de controlewerkzaamheden, de uitslag en de zetelverdeling. Dit heet een proces-verbaal.
+][
+  It #location_type makket by in ferkiezing in ferslach fan de kontrôlewurksumheden, de útslach en de sitferdieling. Dit hjit in proses-ferbaal.
+]
 
-- Bijlage 1: Stemmen per lijst en per kandidaat
-- Bijlage 2: Meldingen van belangstellenden
-- Bijlage 3: Verslag van de controlewerkzaamheden
-- Bijlage 4: Bezwaren van belangstellenden tijdens de zitting
-- Bijlage 5: Bijzonderheden
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
+#tr[
+  - Deel 1 - *Verslag van de zitting*
+  - Deel 2 - *Uitslag en zetelverdeling*
+  - Deel 3 - *Ondertekening* door de leden van het #location_type
+
+  \
+
+  - Bijlage 1: Stemmen per lijst en per kandidaat
+  - Bijlage 2: Meldingen van belangstellenden
+  - Bijlage 3: Verslag van de controlewerkzaamheden
+  - Bijlage 4: Bezwaren van belangstellenden tijdens de zitting
+  - Bijlage 5: Bijzonderheden
+][
+  - Diel 1 - *Ferslach fan de sitting*
+  - Diel 2 - *Útslach en sitferdieling*
+  - Diel 3 - *Ûndertekening* troch de leden fan it #location_type
+
+  \
+
+  - Taheakke 1: Stimmen per list en per kandidaat
+  - Taheakke 2: Meldingen fan belangstellenden
+  - Taheakke 3: Ferslach fan de kontrôlewurksumheden
+  - Taheakke 4: Beswieren fan belangstellenden tidens de sitting
+  - Taheakke 5: Bysûnderheden
+]
 
 #pagebreak(weak: true)
 
@@ -60,85 +90,89 @@ Het #location_type maakt bij een verkiezing een verslag van de controlewerkzaamh
 
 #show heading.where(level: 3): it => [#block(it.body)]
 
-= Verslag van de zitting
+= #tr[Verslag van de zitting][Ferslach fan de sitting]
 
-== Presentielijst
+== #tr[Presentielijst][Presinsjelist]
 
-=== Aanwezige leden van het #location_type
+=== #tr[Aanwezige leden van het][Oanwêzige leden fan it] #location_type
 
-De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid.
+#tr[
+  De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid.
+][
+  De folgjende rollen binne mooglik: foarsitter, plakferfangend foarsitter of lid.
+]
 
 #empty_table(
   columns: (8em, 1fr, 1fr),
-  headers: ("Voorletters", "Achternaam", "Rol"),
+  headers: tr(("Voorletters", "Achternaam", "Rol"), ("Foarletters", "Efternamme", "Rol")),
   values: ("", "", ""),
   rows: 24,
 )
 
 #pagebreak(weak: true)
 
-== Verslag van controlewerkzaamheden
+== #tr[Verslag van controlewerkzaamheden][Ferslach fan kontrôlewurksumheden]
 
-=== Zijn er controlewerkzaamheden uitgevoerd vanwege telverschillen of andere mogelijke fouten?
+=== #tr[Zijn er controlewerkzaamheden uitgevoerd vanwege telverschillen of andere mogelijke fouten?][Binne der kontrôlewurksumheden útfierd fanwegen telferskillen of oare mooglike flaters?]
 
 #checkbox()[Nee]
 
-#checkbox()[Ja, *#sym.arrow.r Zie bijlage 2*]
+#checkbox()[#tr[Ja, *#sym.arrow.r Zie bijlage 2*][Ja, *#sym.arrow.r Sjoch taheakke 2*]]
 
-== Bezwaren
+== #tr[Bezwaren][Beswieren]
 
-=== Tijdens de zitting zijn:
+=== #tr[Tijdens de zitting zijn:][Tidens de sitting binne:]
 
-#checkbox()[Geen bezwaren ingebracht]
+#checkbox()[#tr[Geen bezwaren ingebracht][Gjin beswieren ynbrocht]]
 
-#checkbox()[Bezwaren ingebracht. Deze staan in bijlage 3 bij dit proces-verbaal. De reactie van het #location_type staat daar ook bij.]
+#checkbox()[#tr[Bezwaren ingebracht. Deze staan in bijlage 3 bij dit proces-verbaal. De reactie van het #location_type staat daar ook bij.][Beswieren ynbrocht. Dy steane yn taheakke 3 by dit proses-ferbaal. De reaksje fan it #location_type stiet dêr ek by.]]
 
 #pagebreak(weak: true)
 
-= Uitslag en zetelverdeling
+= #tr[Uitslag en zetelverdeling][Útslach en sitferdieling]
 
-== Aantal kiesgerechtigden
+== #tr[Aantal kiesgerechtigden][Oantal kiesgerjochtigen]
 
-#letterbox("Z", value: input.summary.number_of_voters)[Kiesgerechtigden]
+#letterbox("Z", value: input.summary.number_of_voters)[#tr[Kiesgerechtigden][Kiesgerjochtigen]]
 
-== Toegelaten kiezers
+== #tr[Toegelaten kiezers][Talitten kiezers]
 
 #if not "voter_card_count" in input.summary.voters_counts [
-  Tel het aantal geldige stempassen en volmachtbewijzen
+  #tr[Tel het aantal geldige stempassen en volmachtbewijzen][Tel it oantal jildige stimpassen en folmachtbewizen]
 
   #sum(
-    letterbox("A", value: input.summary.voters_counts.poll_card_count)[Stempassen],
+    letterbox("A", value: input.summary.voters_counts.poll_card_count)[#tr[Stempassen][Stimpassen]],
     letterbox(
       "B",
       value: input.summary.voters_counts.proxy_certificate_count,
-    )[Volmachtbewijzen (schriftelijk of via ingevulde stempas)],
+    )[#tr[Volmachtbewijzen (schriftelijk of via ingevulde stempas)][Folmachtbewizen (skriftlik of fia ynfolde stimpas)]],
     letterbox(
       "D",
       light: false,
       value: input.summary.voters_counts.total_admitted_voters_count,
-    )[*Totaal toegelaten kiezers (A+B)*],
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*],
   )
 ] else [
-  Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen
+  #tr[Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen][Tel it oantal jildige stimpassen, folmachtbewizen en kiezerspassen]
 
   #sum(
-    letterbox("A", value: input.summary.voters_counts.poll_card_count)[Stempassen],
+    letterbox("A", value: input.summary.voters_counts.poll_card_count)[#tr[Stempassen][Stimpassen]],
     letterbox(
       "B",
       value: input.summary.voters_counts.proxy_certificate_count,
-    )[Volmachtbewijzen (schriftelijk of via ingevulde stempas of kiezerspas)],
+    )[#tr[Volmachtbewijzen (schriftelijk of via ingevulde stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde stimpas of kiezerspas)]],
     letterbox("C", value: input.summary.voters_counts.voter_card_count)[Kiezerspassen],
     letterbox(
       "D",
       light: false,
       value: input.summary.voters_counts.total_admitted_voters_count,
-    )[*Totaal toegelaten kiezers (A+B+C)*],
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*],
   )
 ]
 
 #pagebreak(weak: true)
 
-== Uitgebrachte stemmen
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen]
 
 #table(
   columns: (1fr, 10em),
@@ -149,7 +183,7 @@ De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid
   inset: (x: 4pt, y: 8pt),
   fill: (_, y) => if y > 1 and y <= input.summary.votes_counts.political_group_total_votes.len() and calc.even(y) { luma(245) },
   table.header(
-    ..([Lijst], [Stemmen]).enumerate().map(((idx, h)) => {
+    ..(tr[Lijst][List], tr[Stemmen][Stimmen]).enumerate().map(((idx, h)) => {
       table.cell(
         align: bottom + if idx == 0 { left } else { right }, header_text(h)
       )
@@ -163,14 +197,14 @@ De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid
     )
   }.flatten(),
   table.hline(stroke: 1pt + black),
-  table.cell(header_text([Stemmen op kandidaten])),
+  table.cell(header_text(tr[Stemmen op kandidaten][Stimmen op kandidaten])),
   table.cell(align: right, header_text(fmt-number-str(input.summary.votes_counts.total_votes_candidates_count))),
-  table.cell(fill: luma(245), [Blanco stemmen]),
+  table.cell(fill: luma(245), tr[Blanco stemmen][Blanko stimmen]),
   table.cell(fill: luma(245), align: right, fmt-number-str(input.summary.votes_counts.blank_votes_count)),
-  table.cell([Ongeldige stemmen]),
+  table.cell(tr[Ongeldige stemmen][Unjildige stimmen]),
   table.cell(align: right, fmt-number-str(input.summary.votes_counts.invalid_votes_count)),
   table.hline(stroke: 1pt + black),
-  table.cell(header_text([Totaal uitgebrachte stemmen])),
+  table.cell(header_text(tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen])),
   table.cell(align: right, header_text(fmt-number-str(input.summary.votes_counts.total_votes_cast_count))),
 )
 
@@ -181,44 +215,48 @@ De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid
   #it.body
 ]
 
-== Verschillen tussen aantal kiezers en uitgebrachte stemmen
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
 
-=== Is het aantal uitgebrachte stemmen en het aantal toegelaten kiezers gelijk?
+=== #tr[Is het aantal uitgebrachte stemmen en het aantal toegelaten kiezers gelijk?][Is it oantal útbrochte stimmen en it oantal talitten kiezers gelyk?]
 
 #let differences = input.summary.differences_counts.more_ballots_count.count > 0 or input.summary.differences_counts.fewer_ballots_count.count > 0
 
 #checkbox(checked: not differences)[Ja]
 
-#checkbox(checked: differences)[Nee, er zijn verschillen]
+#checkbox(checked: differences)[#tr[Nee, er zijn verschillen][Nee, der binne ferskillen]]
 
-=== In de stembureaus zijn in totaal *méér* uitgebrachte stemmen dan toegelaten kiezers geteld.
+=== #tr[In de stembureaus zijn in totaal *méér* uitgebrachte stemmen dan toegelaten kiezers geteld.][Yn de stimburo's binne yn totaal *mear* útbrochte stimmen as talitten kiezers teld.]
 
 #letterbox(
   "I",
   value: input.summary.differences_counts.more_ballots_count.count,
-)[Totaal aantal méér getelde stemmen]
+)[#tr[Totaal aantal méér getelde stemmen][Totaal oantal mear telde stimmen]]
 
-=== In de stembureaus zijn in totaal *minder* uitgebrachte stemmen dan toegelaten kiezers geteld.
+=== #tr[In de stembureaus zijn in totaal *minder* uitgebrachte stemmen dan toegelaten kiezers geteld.][Yn de stimburo's binne yn totaal *minder* útbrochte stimmen as talitten kiezers teld.]
 
 #letterbox(
   "J",
   value: input.summary.differences_counts.fewer_ballots_count.count,
-)[Totaal aantal minder getelde stemmen]
+)[#tr[Totaal aantal minder getelde stemmen][Totaal oantal minder telde stimmen]]
 
-== Kiesdeler
+== #tr[Kiesdeler][Kiesdieler]
 
-Met de kiesdeler wordt de zetelverdeling bepaald. De kiesdeler is het aantal stemmen dat nodig is voor een zetel. De kiesdeler is het totaal aantal uitgebrachte stemmen op een kandidaat gedeeld door het aantal te verdelen zetels.
+#tr[
+  Met de kiesdeler wordt de zetelverdeling bepaald. De kiesdeler is het aantal stemmen dat nodig is voor een zetel. De kiesdeler is het totaal aantal uitgebrachte stemmen op een kandidaat gedeeld door het aantal te verdelen zetels.
+][
+  Mei de kiesdieler wurdt de sitferdieling bepaald. De kiesdieler is it oantal stimmen dat nedich is foar in sit. De kiesdieler is it totaal oantal útbrochte stimmen op in kandidaat dield troch it oantal te ferdielen sitten.
+]
 
 #table(
   columns: 5,
   stroke: none,
   inset: (x: 4pt, y: 8pt),
   table.header(
-    table.cell(header_text([Geldige stemmen op kandidaten])),
+    table.cell(header_text(tr[Geldige stemmen op kandidaten][Jildige stimmen op kandidaten])),
     table.cell([]),
-    table.cell(align: center, header_text([Aantal zetels])),
+    table.cell(align: center, header_text(tr[Aantal zetels][Oantal sitten])),
     table.cell([]),
-    table.cell(header_text([Kiesdeler])),
+    table.cell(header_text(tr[Kiesdeler][Kiesdieler])),
   ),
   table.hline(stroke: 1pt + black),
   table.cell(align: right, fmt-number-str(input.summary.votes_counts.total_votes_candidates_count)),
@@ -230,9 +268,13 @@ Met de kiesdeler wordt de zetelverdeling bepaald. De kiesdeler is het aantal ste
 
 #pagebreak(weak: true)
 
-== Aantal volle zetels per lijst
+== #tr[Aantal volle zetels per lijst][Oantal folle sitten per list]
 
-Hieronder is berekend hoe vaak elke lijst qua stemmenaantal de kiesdeler heeft gehaald. Het resultaat van deze deling geeft het aantal volle zetels dat per lijst is behaald.
+#tr[
+  Hieronder is berekend hoe vaak elke lijst qua stemmenaantal de kiesdeler heeft gehaald. Het resultaat van deze deling geeft het aantal volle zetels dat per lijst is behaald.
+][
+  Hjirûnder is berekkene hoe faak elke list wat it stimmenoantal oanbelanget de kiesdieler helle hat. It resultaat fan dizze dieling jout it oantal folle sitten dat per list helle is.
+]
 
 #table(
   columns: (1fr, 10em, 9em, 9em),
@@ -243,10 +285,10 @@ Hieronder is berekend hoe vaak elke lijst qua stemmenaantal de kiesdeler heeft g
   inset: (x: 4pt, y: 8pt),
   fill: (_, y) => if y > 1 and y <= input.seat_assignment.list_seat_assignment.len() and calc.even(y) { luma(245) },
   table.header(
-    table.cell(header_text([Lijst])),
-    table.cell(align: right, header_text([Aantal stemmen])),
-    table.cell(stroke: none, align: center, header_text([÷ Kiesdeler])),
-    table.cell(stroke: none, align: right, header_text([Volle zetels])),
+    table.cell(header_text(tr[Lijst][List])),
+    table.cell(align: right, header_text(tr[Aantal stemmen][Oantal stimmen])),
+    table.cell(stroke: none, align: center, header_text(tr[÷ Kiesdeler][÷ Kiesdieler])),
+    table.cell(stroke: none, align: right, header_text(tr[Volle zetels][Folle sitten])),
   ),
   table.hline(stroke: 1pt + black),
   ..for column in input.seat_assignment.list_seat_assignment {
@@ -266,34 +308,45 @@ Hieronder is berekend hoe vaak elke lijst qua stemmenaantal de kiesdeler heeft g
 
 #pagebreak(weak: true)
 
-== Restzetels
+== #tr[Restzetels][Restsitten]
 
-=== Berekening aantal restzetels
+=== #tr[Berekening aantal restzetels][Berekkening oantal restsitten]
 
-Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit zijn de restzetels.
+#tr[
+  Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit zijn de restzetels.
+][
+  Nei tawizing fan de folle sitten bliuwt in oantal te ferdielen sitten oer. Dat binne de restsitten.
+]
 
 #sum(
-  operator_label: "- Verschil",
+  operator_label: tr("- Verschil", "- Ferskil"),
   number_box(
     value: input.election.number_of_seats,
-  )[Totaal aantal te verdelen zetels],
+  )[#tr[Totaal aantal te verdelen zetels][Totaal oantal te ferdielen sitten]],
   number_box(
     value: input.seat_assignment.initial_total_full_seats,
-  )[Toegewezen volle zetels],
+  )[#tr[Toegewezen volle zetels][Tawiisde folle sitten]],
   number_box(
     value: input.seat_assignment.initial_total_residual_seats,
-  )[*Aantal restzetels*],
+  )[*#tr[Aantal restzetels][Oantal restsitten]*],
 )
 
-=== Verdeling van de restzetels
+=== #tr[Verdeling van de restzetels][Ferdieling fan de restsitten]
 
 #let initial_highest_average_steps = if input.seat_assignment.keys().contains("initial_highest_average_steps") { input.seat_assignment.initial_highest_average_steps } else { () };
 #if input.seat_assignment.initial_total_residual_seats > 0 [
   #if input.election.number_of_seats < LARGE_COUNCIL_THRESHOLD [
-    - Het #location_type berekent hoeveel stemmen elke lijst overhoudt na toekenning van de volle zetels. Dat is het ‘overschot’ aan stemmen voor die lijst.
-    - Het #location_type verdeelt de restzetels, in volgorde van de grootste overschotten. Elke lijst kan maar één restzetel krijgen. Alleen lijsten die ten minste 75% van de kiesdeler hebben behaald kunnen een restzetel krijgen.
-    - Als er daarna nog restzetels over zijn, verdeelt het #location_type die volgens het systeem van de grootste gemiddelden. Ook bij deze verdeling mag iedere lijst maar één restzetel krijgen.
-    - Als lijsten precies evenveel stemmen behalen en er niet voldoende restzetels zijn voor die lijsten, dan wordt geloot welke lijst de restzetel krijgt.
+    #tr[
+      - Het #location_type berekent hoeveel stemmen elke lijst overhoudt na toekenning van de volle zetels. Dat is het ‘overschot’ aan stemmen voor die lijst.
+      - Het #location_type verdeelt de restzetels, in volgorde van de grootste overschotten. Elke lijst kan maar één restzetel krijgen. Alleen lijsten die ten minste 75% van de kiesdeler hebben behaald kunnen een restzetel krijgen.
+      - Als er daarna nog restzetels over zijn, verdeelt het #location_type die volgens het systeem van de grootste gemiddelden. Ook bij deze verdeling mag iedere lijst maar één restzetel krijgen.
+      - Als lijsten precies evenveel stemmen behalen en er niet voldoende restzetels zijn voor die lijsten, dan wordt geloot welke lijst de restzetel krijgt.
+    ][
+      - It #location_type berekkenet hoefolle stimmen elke list oerhâldt nei tawizing fan de folle sitten. Dat is it ‘oerskot’ oan stimmen foar dy list.
+      - It #location_type ferdielt de restsitten, yn folchoarder fan de grutste oerskotten. Elke list kin mar ien restsit krije. Allinnich listen dy't op syn minst 75% fan de kiesdieler helle hawwe, kinne in restsit krije.
+      - As der dêrnei noch restsitten oer binne, ferdielt it #location_type dy neffens it systeem fan de grutste gemiddelden. Ek by dizze ferdieling mei elke list mar ien restsit krije.
+      - As listen krekt likefolle stimmen helje en der net genôch restsitten binne foar dy listen, dan wurdt der lotte hokker list de restsit kriget.
+    ]
 
     #pagebreak(weak: true)
 
@@ -309,10 +362,10 @@ Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit
       inset: (x: 4pt, y: 8pt),
       fill: (_, y) => if y > 1 and calc.even(y) { luma(245) },
       table.header(
-        table.cell(stroke: none, header_text([Lijst])),
-        table.cell(header_text([Aantal volle zetels])),
-        table.cell(align: right, stroke: none, header_text([Overschot])),
-        table.cell(align: right, header_text([Aantal restzetels])),
+        table.cell(stroke: none, header_text(tr[Lijst][List])),
+        table.cell(header_text(tr[Aantal volle zetels][Oantal folle sitten])),
+        table.cell(align: right, stroke: none, header_text(tr[Overschot][Oerskot])),
+        table.cell(align: right, header_text(tr[Aantal restzetels][Oantal restsitten])),
       ),
       table.hline(stroke: 1pt + black),
       ..pgs_meeting_threshold.map((list_seat_assignment) =>
@@ -326,13 +379,23 @@ Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit
       table.hline(stroke: 1pt + black),
     )
   ] else [
-    - Eerst wordt voor alle lijsten berekend hoeveel stemmen per zetel op een bepaalde lijst zouden zijn uitgebracht als die lijst één zetel extra zou krijgen: de op de lijst uitgebrachte stemmen worden gedeeld door het aantal volle zetels plus 1.
-    - De uitkomsten van deze berekening zijn gemiddelden per zetel; zij worden naar grootte gerangschikt.
-    - De eerste restzetel gaat naar de lijst met het grootste gemiddelde per zetel. Voor deze lijst wordt opnieuw berekend wat het gemiddelde nu is, uitgaande van het aantal volle zetels, de toegewezen restzetel en weer één extra zetel.
-    - Als er nog een restzetel te verdelen is, wordt deze toegewezen aan de lijst met nu het grootste gemiddelde.
-    - Het #location_type herhaalt de procedure totdat alle restzetels verdeeld zijn.
-
-    Als meerdere lijsten gelijke gemiddelden hebben en er niet voldoende restzetels zijn voor toekenning ervan aan die lijsten, wordt geloot welke lijst de restzetel krijgt.
+    #tr[
+      - Eerst wordt voor alle lijsten berekend hoeveel stemmen per zetel op een bepaalde lijst zouden zijn uitgebracht als die lijst één zetel extra zou krijgen: de op de lijst uitgebrachte stemmen worden gedeeld door het aantal volle zetels plus 1.
+      - De uitkomsten van deze berekening zijn gemiddelden per zetel; zij worden naar grootte gerangschikt.
+      - De eerste restzetel gaat naar de lijst met het grootste gemiddelde per zetel. Voor deze lijst wordt opnieuw berekend wat het gemiddelde nu is, uitgaande van het aantal volle zetels, de toegewezen restzetel en weer één extra zetel.
+      - Als er nog een restzetel te verdelen is, wordt deze toegewezen aan de lijst met nu het grootste gemiddelde.
+      - Het #location_type herhaalt de procedure totdat alle restzetels verdeeld zijn.
+
+      Als meerdere lijsten gelijke gemiddelden hebben en er niet voldoende restzetels zijn voor toekenning ervan aan die lijsten, wordt geloot welke lijst de restzetel krijgt.
+    ][
+      - Earst wurdt foar alle listen berekkene hoefolle stimmen per sit op in bepaalde list útbrocht wêze soene as dy list ien sit ekstra krije soe: de op de list útbrochte stimmen wurde dield troch it oantal folle sitten plus 1.
+      - De útkomsten fan dizze berekkening binne gemiddelden per sit; se wurde op grutte rangskikt.
+      - De earste restsit giet nei de list mei it grutste gemiddelde per sit. Foar dizze list wurdt opnij berekkene wat it gemiddelde no is, útgeande fan it oantal folle sitten, de tawiisde restsit en wer ien ekstra sit.
+      - As der noch in restsit te ferdielen is, wurdt dy tawiisd oan de list mei no it grutste gemiddelde.
+      - It #location_type werhellet de proseduere oant alle restsitten ferdield binne.
+
+      As meardere listen gelikense gemiddelden hawwe en der net genôch restsitten binne foar tawizing dêrfan oan dy listen, wurdt der lotte hokker list de restsit kriget.
+    ]
 
     #pagebreak(weak: true)
 
@@ -349,16 +412,19 @@ Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit
 
     set enum(spacing: 12pt, numbering: numbering("I", 1))
     for drawn_lot in drawn_lots {
-      let lot_variant = if drawn_lot.variant == "HighestAverageResidualSeat" { [gemiddelden] } else { [overschotten] };
+      let lot_variant = if drawn_lot.variant == "HighestAverageResidualSeat" { [gemiddelden] } else { tr[overschotten][oerskotten] };
       let list_names = drawn_lot.lists.map((list) => format_political_group_name(list.number, list.name, with_prefix: "with_list_prefix"));
       let list_names_formatted = comma_list(list_names, last_separator: "en");
-      [+ De lijsten #list_names_formatted hebben gelijke #lot_variant, maar er zijn niet voldoende restzetels voor toekenning ervan aan die lijsten. Er is daarom geloot welke lijst de restzetel krijgt.]
+      tr[+ De lijsten #list_names_formatted hebben gelijke #lot_variant, maar er zijn niet voldoende restzetels voor toekenning ervan aan die lijsten. Er is daarom geloot welke lijst de restzetel krijgt.][+ De listen #list_names_formatted hawwe gelikense #lot_variant, mar der binne net genôch restsitten foar tawizing dêrfan oan dy listen. Der is dêrom lotte hokker list de restsit kriget.]
     }
     if absolute_majority != none {
-      [+ #format_political_group_name(absolute_majority.number, absolute_majority.name, with_prefix: "with_list_prefix") heeft meer dan de helft van de stemmen behaald en heeft daardoor een volstrekte meerderheid. Omdat de lijst op basis van de zetelverdeling niet meer dan de helft van de zetels heeft gekregen, heeft de lijst via de restzetelverdeling een extra (rest)zetel gekregen.]
+      let list_name = format_political_group_name(absolute_majority.number, absolute_majority.name, with_prefix: "with_list_prefix")
+      tr[+ #list_name heeft meer dan de helft van de stemmen behaald en heeft daardoor een volstrekte meerderheid. Omdat de lijst op basis van de zetelverdeling niet meer dan de helft van de zetels heeft gekregen, heeft de lijst via de restzetelverdeling een extra (rest)zetel gekregen.][+ #list_name hat mear as de helte fan de stimmen helle en hat dêrtroch in folsleine mearderheid. Omdat de list op basis fan de sitferdieling net mear as de helte fan de sitten krigen hat, hat de list fia de restsitferdieling in ekstra (rest)sit krigen.]
     }
     for exhausted_list in exhausted_lists {
-      [+ #format_political_group_name(exhausted_list.number, exhausted_list.name, with_prefix: "with_list_prefix") heeft niet voldoende kandidaten beschikbaar om de haar toegewezen zetels te bezetten. De ‘overtollige’ zetels gaan over op andere lijsten door toepassing van het systeem van de grootste #if input.election.number_of_seats < LARGE_COUNCIL_THRESHOLD { [overschotten en/of gemiddelden] } else { [gemiddelden] }.]
+      let list_name = format_political_group_name(exhausted_list.number, exhausted_list.name, with_prefix: "with_list_prefix")
+      let system = if input.election.number_of_seats < LARGE_COUNCIL_THRESHOLD { tr[overschotten en/of gemiddelden][oerskotten en/of gemiddelden] } else { [gemiddelden] }
+      tr[+ #list_name heeft niet voldoende kandidaten beschikbaar om de haar toegewezen zetels te bezetten. De ‘overtollige’ zetels gaan over op andere lijsten door toepassing van het systeem van de grootste #system.][+ #list_name hat net genôch kandidaten beskikber om de har tawiisde sitten te besetten. De ‘tefolle’ sitten geane oer op oare listen troch tapassing fan it systeem fan de grutste #system.]
     }
   }
 
@@ -368,9 +434,13 @@ Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit
   #if input.election.number_of_seats < LARGE_COUNCIL_THRESHOLD and list_seat_assignment_with_unique_highest_average.len() > 0 [
     #pagebreak(weak: true)
 
-    === Verdeling van de restzetels
+    === #tr[Verdeling van de restzetels][Ferdieling fan de restsitten]
 
-    De resterende restzetels zijn verdeeld via het systeem van de grootste gemiddelden. De lijst die na toewijzing van een restzetel het hoogste gemiddeld aantal stemmen per zetel zou hebben, krijgt een restzetel. Ook bij deze verdeling mag iedere lijst maar één restzetel krijgen.
+    #tr[
+      De resterende restzetels zijn verdeeld via het systeem van de grootste gemiddelden. De lijst die na toewijzing van een restzetel het hoogste gemiddeld aantal stemmen per zetel zou hebben, krijgt een restzetel. Ook bij deze verdeling mag iedere lijst maar één restzetel krijgen.
+    ][
+      De oerbleaune restsitten binne ferdield fia it systeem fan de grutste gemiddelden. De list dy't nei tawizing fan in restsit it heechste gemiddeld oantal stimmen per sit hawwe soe, kriget in restsit. Ek by dizze ferdieling mei elke list mar ien restsit krije.
+    ]
 
     #table(
       columns: (1fr, 9em, 13em, 8em),
@@ -380,10 +450,10 @@ Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit
       ),
       inset: (x: 4pt, y: 8pt),
       table.header(
-        table.cell(stroke: none, header_text([Lijst])),
-        table.cell(align: right, header_text([Reeds toegewezen zetels])),
-        table.cell(align: right, stroke: none, header_text([Gemiddeld aantal stemmen per zetel bij toewijzing restzetels])),
-        table.cell(align: right, header_text([Toegekende restzetels])),
+        table.cell(stroke: none, header_text(tr[Lijst][List])),
+        table.cell(align: right, header_text(tr[Reeds toegewezen zetels][Al tawiisde sitten])),
+        table.cell(align: right, stroke: none, header_text(tr[Gemiddeld aantal stemmen per zetel bij toewijzing restzetels][Gemiddeld oantal stimmen per sit by tawizing restsitten])),
+        table.cell(align: right, header_text(tr[Toegekende restzetels][Tawiisde restsitten])),
       ),
       table.hline(stroke: 1pt + black),
       ..list_seat_assignment_with_unique_highest_average.map((list_seat_assignment) => {
@@ -401,22 +471,77 @@ Na toewijzing van de volle zetels blijft een aantal te verdelen zetels over. Dit
     #if initial_highest_average_steps.len() > 0 [
       #v(8pt)
       #if initial_highest_average_steps.len() == 1 {
-        [Nadat de restzetels zijn verdeeld via het systeem van de grootste gemiddelden, is er nog #initial_highest_average_steps.len() zetel te verdelen. Deze zetel wordt nogmaals verdeeld via het systeem van de grootste gemiddelden, met dien verstande dat de bepaling dat aan iedere lijst maar eenmaal een zetel mag worden toegekend buiten beschouwing wordt gelaten.]
+        tr[Nadat de restzetels zijn verdeeld via het systeem van de grootste gemiddelden, is er nog #initial_highest_average_steps.len() zetel te verdelen. Deze zetel wordt nogmaals verdeeld via het systeem van de grootste gemiddelden, met dien verstande dat de bepaling dat aan iedere lijst maar eenmaal een zetel mag worden toegekend buiten beschouwing wordt gelaten.][Neidat de restsitten ferdield binne fia it systeem fan de grutste gemiddelden, is der noch #initial_highest_average_steps.len() sit te ferdielen. Dizze sit wurdt nochris ferdield fia it systeem fan de grutste gemiddelden, mei dien ferstânde dat de bepaling dat oan elke list mar ien kear in sit tawiisd wurde mei bûten beskôging litten wurdt.]
       } else {
-        [Nadat de restzetels zijn verdeeld via het systeem van de grootste gemiddelden, zijn er nog #initial_highest_average_steps.len() zetels te verdelen. Deze zetels worden nogmaals verdeeld via het systeem van de grootste gemiddelden, met dien verstande dat de bepaling dat aan iedere lijst maar eenmaal een zetel mag worden toegekend buiten beschouwing wordt gelaten.]
+        tr[Nadat de restzetels zijn verdeeld via het systeem van de grootste gemiddelden, zijn er nog #initial_highest_average_steps.len() zetels te verdelen. Deze zetels worden nogmaals verdeeld via het systeem van de grootste gemiddelden, met dien verstande dat de bepaling dat aan iedere lijst maar eenmaal een zetel mag worden toegekend buiten beschouwing wordt gelaten.][Neidat de restsitten ferdield binne fia it systeem fan de grutste gemiddelden, binne der noch #initial_highest_average_steps.len() sitten te ferdielen. Dizze sitten wurde nochris ferdield fia it systeem fan de grutste gemiddelden, mei dien ferstânde dat de bepaling dat oan elke list mar ien kear in sit tawiisd wurde mei bûten beskôging litten wurdt.]
       }
       #highest_averages_table(initial_highest_average_steps, input.seat_assignment.list_seat_assignment)
     ]
   ]
 ] else [
-  Er zijn geen restzetels te verdelen.
+  #tr[Er zijn geen restzetels te verdelen.][Der binne gjin restsitten te ferdielen.]
+]
+
+#let explanations = if input.keys().contains("explanations") { input.explanations } else { () }
+#if explanations.len() > 0 [
+  #pagebreak(weak: true)
+
+  === #tr[Toelichting op de verdeling van de restzetels][Taljochting op de ferdieling fan de restsitten]
+
+  #tr[
+    Hieronder staat per stap van de verdeling van de restzetels welke lijst een zetel kreeg of verloor, waarom, en op welk artikel van de Kieswet dit is gebaseerd.
+  ][
+    Hjirûnder stiet per stap fan de ferdieling fan de restsitten hokker list in sit krige of ferlear, wêrom, en op hokker kêst fan de Kieswet dit basearre is.
+  ]
+
+  #let list_name = (number) => {
+    let list = input.seat_assignment.list_seat_assignment.find((list) => list.number == number)
+    format_political_group_name(number, if list != none { list.name } else { "" }, with_prefix: "with_list_prefix")
+  }
+  #let article_title = (article) => tr("Artikel ", "Kêst ") + article.slice(0, 1) + " " + article.slice(1) + " Kieswet"
+
+  #set enum(spacing: 12pt)
+  #for explanation in explanations {
+    let reason = explanation.reason
+    let assigned = if explanation.keys().contains("list_assigned_seat") { list_name(explanation.list_assigned_seat) } else { none }
+    let retracted = if explanation.keys().contains("list_retracted_seat") { list_name(explanation.list_retracted_seat) } else { none }
+    let seat = if explanation.keys().contains("residual_seat_number") { explanation.residual_seat_number } else { none }
+
+    let sentence = if reason.reason == "HighestAverage" {
+      tr[Restzetel #seat gaat naar #assigned, met het grootste gemiddelde van #format_fraction(reason.votes_per_seat) stemmen per zetel.][Restsit #seat giet nei #assigned, mei it grutste gemiddelde fan #format_fraction(reason.votes_per_seat) stimmen per sit.]
+    } else if reason.reason == "UniqueHighestAverage" {
+      tr[Restzetel #seat gaat naar #assigned, met het grootste gemiddelde van #format_fraction(reason.votes_per_seat) stemmen per zetel van de lijsten die via dit systeem nog geen restzetel hebben gekregen.][Restsit #seat giet nei #assigned, mei it grutste gemiddelde fan #format_fraction(reason.votes_per_seat) stimmen per sit fan de listen dy't fia dit systeem noch gjin restsit krigen hawwe.]
+    } else if reason.reason == "LargestRemainder" {
+      tr[Restzetel #seat gaat naar #assigned, met het grootste overschot van #format_fraction(reason.remainder_votes) stemmen.][Restsit #seat giet nei #assigned, mei it grutste oerskot fan #format_fraction(reason.remainder_votes) stimmen.]
+    } else if reason.reason == "AbsoluteMajority" {
+      let method = if reason.retracted_seat_method == "LargestRemainder" { tr[overschotten][oerskotten] } else { [gemiddelden] }
+      tr[#assigned heeft #fmt-number-str(reason.votes_cast) van de #fmt-number-str(reason.total_votes_cast) stemmen behaald, meer dan de helft, maar kreeg met #reason.list_seats van de #reason.seats zetels niet meer dan de helft van de zetels. De laatste restzetel die via het systeem van de grootste #method is toegewezen, gaat daarom van #retracted naar #assigned.][#assigned hat #fmt-number-str(reason.votes_cast) fan de #fmt-number-str(reason.total_votes_cast) stimmen helle, mear as de helte, mar krige mei #reason.list_seats fan de #reason.seats sitten net mear as de helte fan de sitten. De lêste restsit dy't fia it systeem fan de grutste #method tawiisd is, giet dêrom fan #retracted nei #assigned.]
+    } else if reason.reason == "ListExhaustion" {
+      let removed_seat = if reason.full_seat { tr[volle zetel][folle sit] } else { tr[restzetel][restsit] }
+      tr[#retracted heeft minder kandidaten dan zetels. Daarom wordt een #removed_seat van deze lijst ingetrokken.][#retracted hat minder kandidaten as sitten. Dêrom wurdt in #removed_seat fan dizze list ynlutsen.]
+    }
+
+    if reason.reason != "ListExhaustion" and explanation.articles.contains("P10") {
+      sentence += tr[ Deze restzetel vervangt een ingetrokken zetel van een lijst met te weinig kandidaten.][ Dizze restsit ferfangt in ynlutsen sit fan in list mei te min kandidaten.]
+    }
+    if explanation.drawn_lots {
+      let tied_lists = comma_list(explanation.tied_lists.map(list_name), last_separator: "en")
+      sentence += tr[ Omdat #tied_lists gelijk stonden, is er geloot welke lijst de zetel krijgt.][ Omdat #tied_lists gelyk stiene, is der lotte hokker list de sit kriget.]
+    }
+
+    [+ #sentence (#comma_list(explanation.articles.map(article_title), last_separator: "en"))]
+  }
 ]
 
 #pagebreak(weak: true)
 
-== Verdeling van de zetels
+== #tr[Verdeling van de zetels][Ferdieling fan de sitten]
 
-De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld. De verdeling van alle zetels ziet er als volgt uit:
+#tr[
+  De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld. De verdeling van alle zetels ziet er als volgt uit:
+][
+  De oan de listen tawiisde folle sitten en restsitten binne byinoar optelle. De ferdieling fan alle sitten sjocht der sa út:
+]
 
 #table(
   columns: (1fr, 11em),
@@ -428,7 +553,7 @@ De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld
   fill: (_, y) => if y > 1 and calc.even(y) { luma(245) },
   table.hline(stroke: none),
   table.header(
-    ..([Lijst], [Toegewezen zetels]).enumerate().map(((idx, h)) => table.cell(stroke: none, align: bottom + if idx == 0 { left } else { right }, header_text(h))),
+    ..(tr[Lijst][List], tr[Toegewezen zetels][Tawiisde sitten]).enumerate().map(((idx, h)) => table.cell(stroke: none, align: bottom + if idx == 0 { left } else { right }, header_text(h))),
   ),
   table.hline(stroke: 1pt + black),
 
@@ -443,41 +568,45 @@ De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld
 
 #pagebreak(weak: true)
 
-== Toewijzing van zetels aan kandidaten
+== #tr[Toewijzing van zetels aan kandidaten][Tawizing fan sitten oan kandidaten]
 
 #for list_candidate_nomination in input.candidate_nomination.list_candidate_nomination.filter((lcn) => lcn.list_seats > 0) {  list_heading_text(format_political_group_name(list_candidate_nomination.list_number, list_candidate_nomination.list_name, with_prefix: "with_list_prefix"))
   v(4pt)
-  [Aantal zetels: #list_candidate_nomination.list_seats]
+  [#tr[Aantal zetels:][Oantal sitten:] #list_candidate_nomination.list_seats]
 
-  emph_block[*Met voorkeursstemmen gekozen kandidaten*]
+  emph_block[*#tr[Met voorkeursstemmen gekozen kandidaten][Mei foarkarsstimmen keazen kandidaten]*]
 
   if list_candidate_nomination.preferential_nomination_columns.len() > 0 {
-    [
+    tr[
       Het overzicht met de stemmen per kandidaat is te vinden in bijlage 1 bij dit proces-verbaal.
       Deze kandidaten hebben als gevolg van het aantal voorkeursstemmen direct een zetel gekregen.
+    ][
+      It oersjoch mei de stimmen per kandidaat is te finen yn taheakke 1 by dit proses-ferbaal.
+      Dizze kandidaten hawwe as gefolch fan it oantal foarkarsstimmen daliks in sit krigen.
     ]
     v(4pt)
-    [Deze kandidaten hebben meer dan #if input.election.number_of_seats < LARGE_COUNCIL_THRESHOLD [50%] else [25%] van de kiesdeler gehaald.]
+    let threshold = if input.election.number_of_seats < LARGE_COUNCIL_THRESHOLD [50%] else [25%]
+    tr[Deze kandidaten hebben meer dan #threshold van de kiesdeler gehaald.][Dizze kandidaten hawwe mear as #threshold fan de kiesdieler helle.]
 
     candidates_with_seat_table(false, true, list_candidate_nomination.preferential_nomination_columns)
   } else {
-    [Er is geen enkele kandidaat met voorkeursstemmen gekozen.]
+    tr[Er is geen enkele kandidaat met voorkeursstemmen gekozen.][Der is gjin inkele kandidaat mei foarkarsstimmen keazen.]
   }
 
-  emph_block[*Kandidaten die gekozen zijn vanwege hun positie op de lijst*]
+  emph_block[*#tr[Kandidaten die gekozen zijn vanwege hun positie op de lijst][Kandidaten dy't keazen binne fanwegen harren plak op de list]*]
   if list_candidate_nomination.other_nomination_columns.len() > 0 {
-    [Deze kandidaten hebben zelfstandig niet voldoende stemmen gehaald voor een zetel, maar hebben een zetel toegewezen vanwege hun positie op de lijst.]
+    tr[Deze kandidaten hebben zelfstandig niet voldoende stemmen gehaald voor een zetel, maar hebben een zetel toegewezen vanwege hun positie op de lijst.][Dizze kandidaten hawwe op harsels net genôch stimmen helle foar in sit, mar hawwe in sit tawiisd krigen fanwegen harren plak op de list.]
 
     candidates_with_seat_table(true, false, list_candidate_nomination.other_nomination_columns)
   } else {
-    [Geen enkele kandidaat is zonder voorkeursstemmen gekozen.]
+    tr[Geen enkele kandidaat is zonder voorkeursstemmen gekozen.][Gjin inkele kandidaat is sûnder foarkarsstimmen keazen.]
   }
 
-  emph_block[*Rangschikking van kandidaten voor opvolging*]
+  emph_block[*#tr[Rangschikking van kandidaten voor opvolging][Rangskikking fan kandidaten foar opfolging]*]
   let unelected_candidates_ranking = list_candidate_nomination.updated_candidate_ranking.slice(list_candidate_nomination.list_seats)
 
   if unelected_candidates_ranking.len() > 0 {
-    [De volgende kandidaten hebben geen zetel toegewezen gekregen. Als een zetel vrijkomt wordt deze via de onderstaande volgorde aan opvolgers toegewezen.]
+    tr[De volgende kandidaten hebben geen zetel toegewezen gekregen. Als een zetel vrijkomt wordt deze via de onderstaande volgorde aan opvolgers toegewezen.][De folgjende kandidaten hawwe gjin sit tawiisd krigen. As der in sit frijkomt, wurdt dy fia de ûndersteande folchoarder oan opfolgers tawiisd.]
 
     table(
       columns: (4em, 1.5fr, 1fr, 10em),
@@ -488,9 +617,9 @@ De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld
       inset: (x: 4pt, y: 8pt),
       table.header(
         table.cell(stroke: none, header_text([Rang])),
-        table.cell(stroke: none, header_text([Naam])),
-        table.cell(stroke: none, header_text([Woonplaats])),
-        table.cell(stroke: none, align: right, header_text([Positie op lijst]))
+        table.cell(stroke: none, header_text(tr[Naam][Namme])),
+        table.cell(stroke: none, header_text(tr[Woonplaats][Wenplak])),
+        table.cell(stroke: none, align: right, header_text(tr[Positie op lijst][Plak op list]))
       ),
       table.hline(stroke: 1pt + black),
       ..unelected_candidates_ranking.enumerate().map(((idx, unelected_candidate)) => {
@@ -504,13 +633,13 @@ De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld
       table.hline(stroke: 0.5pt + gray),
     )
   } else {
-    [Geen enkele kandidaat is niet gekozen.]
+    tr[Geen enkele kandidaat is niet gekozen.][Der is gjin inkele kandidaat dy't net keazen is.]
   }
 
   pagebreak(weak: true)
 }
 
-== Gekozen kandidaten in alfabetische volgorde
+== #tr[Gekozen kandidaten in alfabetische volgorde][Keazen kandidaten yn alfabetyske folchoarder]
 
 #table(
   columns: (1.5fr, 1fr, 1.5fr),
@@ -520,9 +649,9 @@ De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld
   ),
   inset: (x: 4pt, y: 8pt),
   table.header(
-    table.cell(stroke: none, header_text([Naam])),
-    table.cell(stroke: none, header_text([Woonplaats])),
-    table.cell(stroke: none, header_text([Lijst]))
+    table.cell(stroke: none, header_text(tr[Naam][Namme])),
+    table.cell(stroke: none, header_text(tr[Woonplaats][Wenplak])),
+    table.cell(stroke: none, header_text(tr[Lijst][List]))
   ),
   table.hline(stroke: 1pt + black),
   ..input.candidate_nomination.chosen_candidates.map(((chosen_candidate)) => {
@@ -537,43 +666,43 @@ De aan de lijsten toegewezen volle zetels en restzetels zijn bij elkaar opgeteld
 
 #pagebreak(weak: true)
 
-== Uitkomst controleprotocol
+== #tr[Uitkomst controleprotocol][Útkomst kontrôleprotokol]
 
-=== Voer de controle uit volgens de stappen in het controleprotocol. Kruis aan wat van toepassing is:
+=== #tr[Voer de controle uit volgens de stappen in het controleprotocol. Kruis aan wat van toepassing is:][Fier de kontrôle út neffens de stappen yn it kontrôleprotokol. Krúsje oan wat fan tapassing is:]
 
-#checkbox()[Er zijn geen verschillen geconstateerd.]
-#checkbox()[Er zijn verschillen geconstateerd. Er is contact opgenomen met de Kiesraad. Noteer hieronder wat daarvan de uitkomst is:]
+#checkbox()[#tr[Er zijn geen verschillen geconstateerd.][Der binne gjin ferskillen konstatearre.]]
+#checkbox()[#tr[Er zijn verschillen geconstateerd. Er is contact opgenomen met de Kiesraad. Noteer hieronder wat daarvan de uitkomst is:][Der binne ferskillen konstatearre. Der is kontakt opnommen mei de Kiesried. Notearje hjirûnder wat dêrfan de útkomst is:]]
 
 #empty_lines(5)
 
-=== Is voor de invoer gebruik gemaakt van de bestanden die zijn uitgewisseld via het platform ‘Teluitslagen’?
+=== #tr[Is voor de invoer gebruik gemaakt van de bestanden die zijn uitgewisseld via het platform ‘Teluitslagen’?][Is foar de ynfier gebrûk makke fan de bestannen dy't útwiksele binne fia it platfoarm ‘Teluitslagen’?]
 
 #checkbox()[Ja]
-#checkbox()[Nee, de resultaten van de papieren processen-verbaal twee keer handmatig ingevoerd in de uitslagensoftware]
+#checkbox()[#tr[Nee, de resultaten van de papieren processen-verbaal twee keer handmatig ingevoerd in de uitslagensoftware][Nee, de resultaten fan de papieren prosessen-ferbaal twa kear mei de hân ynfierd yn de útslachsoftware]]
 
-=== Heeft het #subcommittee_type verschillen geconstateerd bij de uitvoering van het controleprotocol?
+=== #tr[Heeft het #subcommittee_type verschillen geconstateerd bij de uitvoering van het controleprotocol?][Hat it #subcommittee_type ferskillen konstatearre by de útfiering fan it kontrôleprotokol?]
 
 #checkbox()[Nee]
-#checkbox()[Ja, noteer hieronder wat het #subcommittee_type daarover heeft opgeschreven.]
+#checkbox()[#tr[Ja, noteer hieronder wat het #subcommittee_type daarover heeft opgeschreven.][Ja, notearje hjirûnder wat it #subcommittee_type dêroer opskreaun hat.]]
 
 #empty_lines(6)
 
 #blank_page_before_signing(header-right)
 
-= Ondertekening
+= #tr[Ondertekening][Ûndertekening]
 
 #signing_form_label[Datum]
 
-#textbox_only_bottom_stroke[Datum en tijd:][Plaats:]
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
 
-#signing_form_label[Voorzitter van het #location_type:]
+#signing_form_label[#tr[Voorzitter van het][Foarsitter fan it] #location_type:]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[Plaatsvervangend voorzitter van het #location_type:]
+#signing_form_label[#tr[Plaatsvervangend voorzitter van het][Plakferfangend foarsitter fan it] #location_type:]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[De andere leden van het #location_type:]
+#signing_form_label[#tr[De andere leden van het][De oare leden fan it] #location_type:]
 
-#stack(spacing: 0.5em, ..range(0, 5).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, 5).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
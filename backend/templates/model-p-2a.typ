@@ -4,49 +4,71 @@
 
 #let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
 #let is_local_election = (local, other) => is_local_election(input.election.category, local, other)
-#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][Openbaar lichaam #input.election.authority_region]
-#let location_type = is_municipality[gemeentelijk stembureau][stembureau voor het openbaar lichaam]
-#let this_location = is_municipality[deze gemeente][dit openbaar lichaam]
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
+#let this_location = is_municipality(tr[deze gemeente][dizze gemeente], tr[dit openbaar lichaam][dit iepenbier lichem])
 
-#show: doc => conf(doc, header-right: location_name, footer: [
+#show: doc => conf(doc, header-right: location_name, footer: tr[
   Proces-verbaal van een #location_type (nieuwe zitting)\
   Model P 2a (versie 2027)
+][
+  Proses-ferbaal fan in #location_type (nije sitting)\
+  Model P 2a (ferzje 2027)
 ])
 
 #set heading(numbering: none)
 
 #title_page(
   is_municipality[#input.election.authority_id #input.election.authority_region][#input.election.authority_region],
-  is_municipality[Gemeentelijk stembureau][Stembureau voor het openbaar lichaam],
+  is_municipality(tr[Gemeentelijk stembureau][Gemeentlik stimburo], tr[Stembureau voor het openbaar lichaam][Stimburo foar it iepenbier lichem]),
   [#input.election.name - #format_date(input.election.election_date)],
-  [
+  tr[
     Verslag en gecorrigeerde telresultaten per lijst en
     kandidaat – Model P 2a
+  ][
+    Ferslach en ferbettere telresultaten per list en
+    kandidaat – Model P 2a
   ],
 )
 
-== Details van het #location_type
+== #tr[Details van het][Details fan it] #location_type
 
 #location_name
 
 #input.committee_session.location #format_date_time(input.committee_session.start_date_time)
 
-== Proces-verbaal
+== #tr[Proces-verbaal][Proses-ferbaal]
 
-#is_municipality[Elke gemeente][Elk openbaar lichaam] maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het tellen van de stemmen is verlopen en wat de uitslag van de stemming was.
+#tr[
+  #is_municipality[Elke gemeente][Elk openbaar lichaam] maakt bij een verkiezing een verslag: het proces-verbaal. Hierin staat hoe het tellen van de stemmen is verlopen en wat de uitslag van de stemming was.
+][
+  #is_municipality[Elke gemeente][Elk iepenbier lichem] makket by in ferkiezing in ferslach: it proses-ferbaal. Hjiryn stiet hoe't it tellen fan de stimmen ferrûn is en wat de útslach fan de stimming wie.
+]
 
-#emph_block[
+#emph_block(tr[
   Het centraal stembureau vermoedt dat er één of meer fouten staan in het
   proces-verbaal van een eerdere zitting van het #location_type. Het centraal stembureau heeft daarom
   aan het #location_type gevraagd de vermeende fouten te onderzoeken in een nieuwe openbare zitting.
   Dit document geeft een verslag van deze nieuwe zitting. Als er inderdaad fouten
   staan in het proces-verbaal van de eerdere zitting, dan verbetert het #location_type deze fouten
   in een corrigendum. Dat corrigendum wordt bij het proces-verbaal van de eerdere zitting gevoegd.
-]
+][
+  It sintraal stimburo tinkt dat der ien of mear flaters steane yn it
+  proses-ferbaal fan in eardere sitting fan it #location_type. It sintraal stimburo hat dêrom
+  it #location_type frege de fermoedlike flaters te ûndersykjen yn in nije iepenbiere sitting.
+  Dit dokumint jout in ferslach fan dizze nije sitting. As der yndied flaters
+  steane yn it proses-ferbaal fan de eardere sitting, dan ferbetteret it #location_type dy flaters
+  yn in korrigindum. Dat korrigindum wurdt by it proses-ferbaal fan de eardere sitting foege.
+])
 
-== Inhoudsopgave
-- Deel 1 - *Verslag van de zitting* (het verloop van het onderzoek)
-- Deel 2 - *Ondertekening* door de leden van het #location_type
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
+#tr[
+  - Deel 1 - *Verslag van de zitting* (het verloop van het onderzoek)
+  - Deel 2 - *Ondertekening* door de leden van het #location_type
+][
+  - Diel 1 - *Ferslach fan de sitting* (it ferrin fan it ûndersyk)
+  - Diel 2 - *Ûndertekening* troch de leden fan it #location_type
+]
 
 #pagebreak(weak: true)
 
@@ -56,47 +78,65 @@
     #block(it.body)
 ]
 
-= Verslag van de zitting
+= #tr[Verslag van de zitting][Ferslach fan de sitting]
 
-== Presentielijst
+== #tr[Presentielijst][Presinsjelist]
 
-=== Aanwezige leden van het #location_type
+=== #tr[Aanwezige leden van het][Oanwêzige leden fan it] #location_type
 
-De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid.
+#tr[
+  De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid.
+][
+  De folgjende rollen binne mooglik: foarsitter, plakferfangend foarsitter of lid.
+]
 
 #empty_table(
   columns: (8em, 1fr, 1fr, 1fr, 8em),
-  headers: ("Voorletters", "Achternaam", "Rol", "Aanwezig van - tot", "Locatie"),
+  headers: tr(
+    ("Voorletters", "Achternaam", "Rol", "Aanwezig van - tot", "Locatie"),
+    ("Foarletters", "Efternamme", "Rol", "Oanwêzich fan - oant", "Lokaasje"),
+  ),
   values: ("", "", "", "-", ""),
   rows: 24,
 )
 
 #pagebreak(weak: true)
 
-== Onderzochte stembureaus
-
-=== De resultaten van onderstaande stembureaus zijn door het #location_type gecontroleerd.
-
-Noteer per onderzocht stembureau: \
-\
-- Nummer van het stembureau en de naam van de locatie
-- Aanleiding van het onderzoek (wat was er opvallend aan de telresultaten?)
-- Opdracht van het centraal stembureau (wat is er onderzocht?)
-- Bevindingen van het onderzoek
-- Of er als gevolg van het onderzoek een nieuw telresultaat is
-\
+== #tr[Onderzochte stembureaus][Undersochte stimburo's]
+
+=== #tr[De resultaten van onderstaande stembureaus zijn door het #location_type gecontroleerd.][De resultaten fan de ûndersteande stimburo's binne troch it #location_type kontrolearre.]
+
+#tr[
+  Noteer per onderzocht stembureau: \
+  \
+  - Nummer van het stembureau en de naam van de locatie
+  - Aanleiding van het onderzoek (wat was er opvallend aan de telresultaten?)
+  - Opdracht van het centraal stembureau (wat is er onderzocht?)
+  - Bevindingen van het onderzoek
+  - Of er als gevolg van het onderzoek een nieuw telresultaat is
+  \
+][
+  Notearje per ûndersocht stimburo: \
+  \
+  - Nûmer fan it stimburo en de namme fan de lokaasje
+  - Oanlieding fan it ûndersyk (wat wie der opfallend oan de telresultaten?)
+  - Opdracht fan it sintraal stimburo (wat is der ûndersocht?)
+  - Befinings fan it ûndersyk
+  - Oft der as gefolch fan it ûndersyk in nij telresultaat is
+  \
+]
 
 #line(length: 100%)
 #for (polling_station, investigation) in input.investigations [
   #block(breakable: false)[
-    *Stembureau #polling_station.number (#polling_station.name)*
-    - *Aanleiding en opdracht:* #investigation.reason
-    - *Bevindingen van het onderzoek:* #investigation.findings
-    - *#if investigation.corrected_results [
-        Er is een gecorrigeerde uitslag
-      ] else [
-        De uitslag is ongewijzigd
-      ]*
+    *#tr[Stembureau][Stimburo] #polling_station.number (#polling_station.name)*
+    - *#tr[Aanleiding en opdracht:][Oanlieding en opdracht:]* #investigation.reason
+    - *#tr[Bevindingen van het onderzoek:][Befinings fan it ûndersyk:]* #investigation.findings
+    - *#if investigation.corrected_results {
+        tr[Er is een gecorrigeerde uitslag][Der is in ferbettere útslach]
+      } else {
+        tr[De uitslag is ongewijzigd][De útslach is net feroare]
+      }*
     #line(length: 100%, stroke: 0.25pt)
   ]
 ]
@@ -105,54 +145,70 @@ Noteer per onderzocht stembureau: \
 
 #show: doc => document_numbering(doc)
 
-== Tijdens de zitting
+== #tr[Tijdens de zitting][Tidens de sitting]
 
-=== Noteer alle *bezwaren* van aanwezigen
+=== #tr[Noteer alle *bezwaren* van aanwezigen][Notearje alle *beswieren* fan oanwêzigen]
 
-Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u
-het er niet mee eens bent. Geef aan hoe het bezwaar door het #location_type is afgehandeld.
+#tr[
+  Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u
+  het er niet mee eens bent. Geef aan hoe het bezwaar door het #location_type is afgehandeld.
+][
+  Skriuw gjin nammen of oare persoansgegevens op. Skriuw alle beswieren op, ek as jo
+  it der net mei iens binne. Jou oan hoe't it beswier troch it #location_type ôfhannele is.
+]
 
 #empty_table(
   columns: (7em, 1fr, 1fr),
-  headers: ("Tijdstip", "Bezwaar", [Reactie #location_type]),
+  headers: tr(
+    ("Tijdstip", "Bezwaar", [Reactie #location_type]),
+    ("Tiidstip", "Beswier", [Reaksje #location_type]),
+  ),
   values: ("", "", ""),
   rows: 10,
 )
 
 
-=== Andere *bijzonderheden* die mogelijk invloed hebben op het telproces of de resultaten van het #location_type.
+=== #tr[Andere *bijzonderheden* die mogelijk invloed hebben op het telproces of de resultaten van het #location_type.][Oare *bysûnderheden* dy't mooglik ynfloed hawwe op it telproses of de resultaten fan it #location_type.]
 
-Bijvoorbeeld een schorsing of als er meerdere verkiezingen tegelijk werden georganiseerd en een stembiljet in de verkeerde stembus zat.
+#tr[
+  Bijvoorbeeld een schorsing of als er meerdere verkiezingen tegelijk werden georganiseerd en een stembiljet in de verkeerde stembus zat.
+][
+  Bygelyks in skorsing of as der meardere ferkiezings tagelyk organisearre waarden en in stimbiljet yn de ferkearde stimbus siet.
+]
 
 #empty_table(
   columns: (7em, 1fr),
-  headers: ("Tijdstip", "Bijzonderheid"),
+  headers: tr(("Tijdstip", "Bijzonderheid"), ("Tiidstip", "Bysûnderheid")),
   values: ("", ""),
   rows: 12,
 )
 
 #blank_page_before_signing(location_name)
 
-= Ondertekening
+= #tr[Ondertekening][Ûndertekening]
 
-Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+#tr[
+  Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal #is_local_election[3][5].
+][
+  It proses-ferbaal moat ûndertekene wurde troch alle oanwêzige leden. By in #location_type binne dat der minimaal #is_local_election[3][5].
+]
 
 #signing_form_label[Datum]
 
-#textbox_only_bottom_stroke[Datum en tijd:][Plaats:]
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
 
-== Voorzitter en #is_local_election[twee][vier] leden van het #location_type
+== #tr[Voorzitter en #is_local_election[twee][vier] leden van het][Foarsitter en #is_local_election[twa][fjouwer] leden fan it] #location_type
 
-#signing_form_label[Voorzitter van het #location_type:]
+#signing_form_label[#tr[Voorzitter van het][Foarsitter fan it] #location_type:]
 
-#textbox[Naam:][Handtekening:]
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
 
-#signing_form_label[#is_local_election[2][4] leden van het #location_type:]
+#signing_form_label[#is_local_election[2][4] #tr[leden van het][leden fan it] #location_type:]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(2, 4)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
 
-== Ondertekening door andere aanwezige leden van het #location_type
+== #tr[Ondertekening door andere aanwezige leden van het][Ûndertekening troch oare oanwêzige leden fan it] #location_type
 
-#signing_form_label[Extra ondertekening:]
+#signing_form_label[#tr[Extra ondertekening:][Ekstra ûndertekening:]]
 
-#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[Naam:][Handtekening:]))
+#stack(spacing: 0.5em, ..range(0, is_local_election(3, 1)).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
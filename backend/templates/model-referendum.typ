@@ -0,0 +1,291 @@
+#import "common/style.typ": conf, document_numbering, blank_page_before_signing
+#import "common/scripts.typ": *
+#let input = json("inputs/model-referendum.json")
+
+#let is_municipality = (municipal, public_body) => is_municipality(input.election.authority_region, municipal, public_body)
+#let location_name = is_municipality[Gemeente #input.election.authority_id #input.election.authority_region][#tr[Openbaar lichaam][Iepenbier lichem] #input.election.authority_region]
+#let location_type = is_municipality(tr[gemeentelijk stembureau][gemeentlik stimburo], tr[stembureau voor het openbaar lichaam][stimburo foar it iepenbier lichem])
+#let this_location = is_municipality(tr[deze gemeente][dizze gemeente], tr[dit openbaar lichaam][dit iepenbier lichem])
+#let the_location = is_municipality([de gemeente], tr[het openbaar lichaam][it iepenbier lichem])
+
+#show: doc => conf(
+  doc,
+  header-right: location_name,
+  footer: tr[
+    Datum: #input.creation_date_time. Digitale vingerafdruk van EML-telbestand bij dit PV: \
+    #input.hash \
+    Digitale vingerafdruk van het auditlog: \
+    #input.audit_log_hash
+
+    Proces-verbaal van een #location_type \
+    Referendum
+  ][
+    Datum: #input.creation_date_time. Digitale fingerôfdruk fan EML-telbestân by dit PV: \
+    #input.hash \
+    Digitale fingerôfdruk fan it auditlog: \
+    #input.audit_log_hash
+
+    Proses-ferbaal fan in #location_type \
+    Referindum
+  ], margin-bottom: 3.6cm, footer-descent: 0.45cm
+)
+
+#set heading(numbering: none)
+
+#title_page(
+  is_municipality[#input.election.authority_id #input.election.authority_region][#input.election.authority_region],
+  is_municipality(tr[Gemeentelijk stembureau][Gemeentlik stimburo], tr[Stembureau voor het openbaar lichaam][Stimburo foar it iepenbier lichem]),
+  [#input.election.name - #format_date(input.election.election_date)],
+  tr[
+    Verslag en telresultaten van het referendum
+  ][
+    Ferslach en telresultaten fan it referindum
+  ],
+)
+
+== #tr[Details van het][Details fan it] #location_type
+
+#location_name
+
+#input.committee_session.location #format_date_time(input.committee_session.start_date_time)
+
+== #tr[Proces-verbaal][Proses-ferbaal]
+
+#tr[
+  #is_municipality[Elke gemeente][Elk openbaar lichaam] maakt bij een referendum een verslag: het proces-verbaal. Hierin staat hoe het tellen van de stemmen is verlopen en wat de uitslag van de stemming was.
+][
+  #is_municipality[Elke gemeente][Elk iepenbier lichem] makket by in referindum in ferslach: it proses-ferbaal. Hjiryn stiet hoe't it tellen fan de stimmen ferrûn is en wat de útslach fan de stimming wie.
+]
+
+#emph_block(tr[
+  Ieder stembureau heeft direct na het stemmen geteld hoeveel stemmen er vóór en tegen zijn uitgebracht.
+  Het *#location_type* telt daarna de resultaten van alle stembureaus bij elkaar op.
+][
+  Elk stimburo hat daliks nei it stimmen teld hoefolle stimmen der foar en tsjin útbrocht binne.
+  It *#location_type* telt dêrnei de resultaten fan alle stimburo's byinoar op.
+])
+
+== #tr[Inhoudsopgave][Ynhâldsopjefte]
+
+#tr[
+  - Deel 1 - *Verslag van de zitting* (het verloop van het optellen)
+  - Deel 2 - *Telresultaten* van #is_municipality[de hele gemeente][het hele openbaar lichaam]
+  - Deel 3 - *Ondertekening* door de leden van het #location_type
+][
+  - Diel 1 - *Ferslach fan de sitting* (it ferrin fan it optellen)
+  - Diel 2 - *Telresultaten* fan #is_municipality[de hiele gemeente][it hiele iepenbier lichem]
+  - Diel 3 - *Ûndertekening* troch de leden fan it #location_type
+]
+
+#pagebreak(weak: true)
+
+#show: doc => document_numbering(doc)
+
+= #tr[Verslag van de zitting][Ferslach fan de sitting]
+
+== #tr[Presentielijst][Presinsjelist]
+
+=== #tr[Aanwezige leden van het][Oanwêzige leden fan it] #location_type
+
+#tr[
+  De volgende rollen zijn mogelijk: voorzitter, plaatsvervangend voorzitter of lid.
+][
+  De folgjende rollen binne mooglik: foarsitter, plakferfangend foarsitter of lid.
+]
+
+#empty_table(
+  columns: (8em, 1fr, 1fr, 1fr, 8em),
+  headers: tr(
+    ("Voorletters", "Achternaam", "Rol", "Aanwezig van - tot", "Locatie"),
+    ("Foarletters", "Efternamme", "Rol", "Oanwêzich fan - oant", "Lokaasje"),
+  ),
+  values: ("", "", "", "-", ""),
+  rows: 12,
+)
+
+== #tr[Getelde stembureaus][Telde stimburo's]
+
+=== #tr[De resultaten van onderstaande stembureaus zijn door het #location_type gecontroleerd en opgeteld tot het totaal van #the_location.][De resultaten fan de ûndersteande stimburo's binne troch it #location_type kontrolearre en optelle ta it totaal fan #the_location.]
+
+#light_table(
+  columns: (5em, 1fr, 1fr),
+  headers: (
+    [Nr.],
+    tr[Naam locatie][Namme lokaasje],
+    tr[Postcode + Adres][Postkoade + Adres],
+  ),
+  values: input
+    .polling_stations
+    .map(polling_station => {
+      (
+        [#polling_station.number],
+        [#polling_station.name],
+        [
+          #if "polling_station_type" in polling_station and polling_station.polling_station_type == "Mobile" [
+            _#tr[(Mobiel stembureau)][(Mobyl stimburo)]_
+          ] else [
+            #polling_station.address \
+            #polling_station.postal_code #polling_station.locality
+          ]
+        ],
+      )
+    })
+    .flatten(),
+)
+
+#pagebreak(weak: true)
+
+== #tr[Tijdens de zitting][Tidens de sitting]
+
+=== #tr[Schrijf alle *bezwaren* van aanwezigen op.][Skriuw alle *beswieren* fan oanwêzigen op.]
+
+#tr[
+  Bijvoorbeeld over het ongeldig verklaren van een stembiljet. Schrijf geen namen of andere persoonsgegevens op. Schrijf alle bezwaren op, ook als u het er niet mee eens bent. Geef aan hoe het bezwaar door het #location_type is behandeld.
+][
+  Bygelyks oer it ûnjildich ferklearjen fan in stimbiljet. Skriuw gjin nammen of oare persoansgegevens op. Skriuw alle beswieren op, ek as jo it der net mei iens binne. Jou oan hoe't it beswier troch it #location_type behannele is.
+]
+
+#empty_table(
+  columns: (7em, 1fr, 1fr),
+  headers: tr(
+    ("Tijdstip", "Bezwaar", [Reactie #location_type]),
+    ("Tiidstip", "Beswier", [Reaksje #location_type]),
+  ),
+  values: ("", "", ""),
+  rows: 10,
+)
+
+=== #tr[Andere *bijzonderheden* die mogelijk invloed hebben op het telproces of de resultaten van het #location_type.][Oare *bysûnderheden* dy't mooglik ynfloed hawwe op it telproses of de resultaten fan it #location_type.]
+
+#tr[
+  Bijvoorbeeld een schorsing of als er tegelijk een verkiezing werd georganiseerd en een stembiljet in de verkeerde stembus zat.
+][
+  Bygelyks in skorsing of as der tagelyk in ferkiezing organisearre waard en in stimbiljet yn de ferkearde stimbus siet.
+]
+
+#empty_table(
+  columns: (7em, 1fr),
+  headers: tr(("Tijdstip", "Bijzonderheid"), ("Tiidstip", "Bysûnderheid")),
+  values: ("", ""),
+  rows: 5,
+)
+
+#pagebreak(weak: true)
+
+= #tr[Telresultaten van][Telresultaten fan] #the_location
+
+== #tr[Aantal kiesgerechtigden][Oantal kiesgerjochtigen]
+
+#letterbox("Z", value: input.election.number_of_voters)[#tr[Kiesgerechtigden][Kiesgerjochtigen]]
+
+== #tr[Toegelaten kiezers][Talitten kiezers]
+
+#if "voter_card_count" in input.summary.voters_counts [
+  #tr[Tel het aantal geldige stempassen, volmachtbewijzen en kiezerspassen][Tel it oantal jildige stimpassen, folmachtbewizen en kiezerspassen]
+
+  #sum(
+    letterbox("A", value: input.summary.voters_counts.poll_card_count)[#tr[Stempassen][Stimpassen]],
+    letterbox(
+      "B",
+      value: input.summary.voters_counts.proxy_certificate_count,
+    )[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas of kiezerspas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas of kiezerspas)]],
+    letterbox("C", value: input.summary.voters_counts.voter_card_count)[Kiezerspassen],
+    letterbox(
+      "D",
+      light: false,
+      value: input.summary.voters_counts.total_admitted_voters_count,
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B+C)*],
+  )
+] else [
+  #tr[Tel het aantal geldige stempassen en volmachtbewijzen][Tel it oantal jildige stimpassen en folmachtbewizen]
+
+  #sum(
+    letterbox("A", value: input.summary.voters_counts.poll_card_count)[#tr[Stempassen][Stimpassen]],
+    letterbox(
+      "B",
+      value: input.summary.voters_counts.proxy_certificate_count,
+    )[#tr[Volmachtbewijzen (schriftelijk of via ingevulde achterkant stempas)][Folmachtbewizen (skriftlik of fia ynfolde efterkant stimpas)]],
+    letterbox(
+      "D",
+      light: false,
+      value: input.summary.voters_counts.total_admitted_voters_count,
+    )[*#tr[Totaal toegelaten kiezers][Totaal talitten kiezers] (A+B)*],
+  )
+]
+
+== #tr[Uitgebrachte stemmen][Útbrochte stimmen]
+
+#sum(
+  letterbox("E", value: input.summary.votes_counts.yes_votes_count)[#tr[Stemmen vóór][Stimmen foar]],
+  letterbox("F", value: input.summary.votes_counts.no_votes_count)[#tr[Stemmen tegen][Stimmen tsjin]],
+  letterbox("G", value: input.summary.votes_counts.blank_votes_count)[#tr[Blanco stemmen][Blanko stimmen]],
+  letterbox("H", value: input.summary.votes_counts.invalid_votes_count)[#tr[Ongeldige stemmen][Unjildige stimmen]],
+  letterbox(
+    "I",
+    light: false,
+    value: input.summary.votes_counts.total_votes_cast_count,
+  )[*#tr[Totaal uitgebrachte stemmen][Totaal útbrochte stimmen] (E+F+G+H)*],
+)
+
+#pagebreak(weak: true)
+
+== #tr[Verschillen tussen aantal kiezers en uitgebrachte stemmen][Ferskillen tusken oantal kiezers en útbrochte stimmen]
+
+=== #tr[Is bij *alle afzonderlijke stembureaus* in #this_location het aantal uitgebrachte stemmen en het aantal toegelaten kiezers gelijk?][Is by *alle yndividuele stimburo's* yn #this_location it oantal útbrochte stimmen en it oantal talitten kiezers gelyk?]
+
+#let differences = input.summary.differences_counts.more_ballots_count.count > 0 or input.summary.differences_counts.fewer_ballots_count.count > 0
+
+#checkbox(checked: not differences)[Ja]
+
+#checkbox(checked: differences)[#tr[Nee, er zijn stembureaus met een verschil][Nee, der binne stimburo's mei in ferskil]]
+
+#let stations_with_difference(differences_count) = if differences_count.count > 0 {
+  let numbers = comma_list(differences_count.data_entry_sources.map(p => p.number))
+  tr[Voor de stembureaus met de nummers #numbers][Foar de stimburo's mei de nûmers #numbers]
+} else {
+  tr[In geen van de stembureaus][Yn gjin fan de stimburo's]
+}
+
+=== #stations_with_difference(input.summary.differences_counts.more_ballots_count) #tr[zijn er *méér* uitgebrachte stemmen dan toegelaten kiezers geteld.][binne der *mear* útbrochte stimmen as talitten kiezers teld.]
+
+#letterbox(
+  "J",
+  value: input.summary.differences_counts.more_ballots_count.count,
+)[#tr[Totaal aantal méér getelde stemmen][Totaal oantal mear telde stimmen]]
+
+=== #stations_with_difference(input.summary.differences_counts.fewer_ballots_count) #tr[zijn er *minder* uitgebrachte stemmen dan toegelaten kiezers geteld.][binne der *minder* útbrochte stimmen as talitten kiezers teld.]
+
+#letterbox(
+  "K",
+  value: input.summary.differences_counts.fewer_ballots_count.count,
+)[#tr[Totaal aantal minder getelde stemmen][Totaal oantal minder telde stimmen]]
+
+#blank_page_before_signing(location_name)
+
+= #tr[Ondertekening][Ûndertekening]
+
+#tr[
+  Het proces-verbaal moet worden ondertekend door alle aanwezige leden. Bij een #location_type zijn dit er minimaal 3.
+][
+  It proses-ferbaal moat ûndertekene wurde troch alle oanwêzige leden. By in #location_type binne dat der minimaal 3.
+]
+
+#signing_form_label[Datum]
+
+#textbox_only_bottom_stroke[#tr[Datum en tijd:][Datum en tiid:]][#tr[Plaats:][Plak:]]
+
+== #tr[Voorzitter en twee leden van het][Foarsitter en twa leden fan it] #location_type
+
+#signing_form_label[#tr[Voorzitter van het][Foarsitter fan it] #location_type:]
+
+#textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]
+
+#signing_form_label[2 #tr[leden van het][leden fan it] #location_type:]
+
+#stack(spacing: 0.5em, ..range(0, 2).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
+
+== #tr[Ondertekening door andere aanwezige leden van het][Ûndertekening troch oare oanwêzige leden fan it] #location_type
+
+#signing_form_label[#tr[Extra ondertekening:][Ekstra ûndertekening:]]
+
+#stack(spacing: 0.5em, ..range(0, 3).map(_ => textbox[#tr[Naam:][Namme:]][#tr[Handtekening:][Hântekening:]]))
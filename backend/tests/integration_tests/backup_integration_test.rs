@@ -63,3 +63,103 @@ async fn backup_success_as_coordinator_csb(pool: SqlitePool) {
         .unwrap();
     assert_eq!(response.status(), StatusCode::CREATED);
 }
+
+#[test(sqlx::test(fixtures(path = "../../fixtures", scripts("users"))))]
+async fn list_backups_as_admin(pool: SqlitePool) {
+    let (addr, _backup_dir) = serve_api_with_backup_dir(pool).await;
+    let cookie = login(&addr, Admin).await;
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("http://{addr}/api/backup"))
+        .header("cookie", &cookie)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: serde_json::Value = response.json().await.unwrap();
+
+    let response = client
+        .get(format!("http://{addr}/api/backup"))
+        .header("cookie", &cookie)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    let backups = body["backups"].as_array().unwrap();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(backups[0]["filename"], created["filename"]);
+}
+
+#[test(sqlx::test(fixtures(path = "../../fixtures", scripts("users"))))]
+async fn restore_forbidden_coordinator(pool: SqlitePool) {
+    let (addr, _backup_dir) = serve_api_with_backup_dir(pool).await;
+    let cookie = login(&addr, CoordinatorCSB).await;
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/api/backup/restore"))
+        .header("cookie", cookie)
+        .json(&serde_json::json!({ "filename": "db_backup_2020-01-01_12-00-00.sqlite" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[test(sqlx::test(fixtures(path = "../../fixtures", scripts("users"))))]
+async fn restore_not_found(pool: SqlitePool) {
+    let (addr, _backup_dir) = serve_api_with_backup_dir(pool).await;
+    let cookie = login(&addr, Admin).await;
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/api/backup/restore"))
+        .header("cookie", cookie)
+        .json(&serde_json::json!({ "filename": "../db.sqlite" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[test(sqlx::test(fixtures(path = "../../fixtures", scripts("users"))))]
+async fn restore_success_as_admin(pool: SqlitePool) {
+    let (addr, backup_dir) = serve_api_with_backup_dir(pool.clone()).await;
+
+    // a backup from before anyone logged in
+    let filename = "db_backup_2020-01-01_12-00-00.sqlite";
+    sqlx::query("VACUUM INTO ?")
+        .bind(backup_dir.path().join(filename).to_str().unwrap())
+        .execute(&pool)
+        .await
+        .unwrap();
+    let cookie = login(&addr, Admin).await;
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("http://{addr}/api/backup/restore"))
+        .header("cookie", &cookie)
+        .json(&serde_json::json!({ "filename": filename }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["filename"], filename);
+    let pre_restore_backup = body["pre_restore_backup"]["filename"].as_str().unwrap();
+    assert!(backup_dir.path().join(pre_restore_backup).exists());
+
+    // the restored database does not contain the session of the admin
+    let response = client
+        .get(format!("http://{addr}/api/backup"))
+        .header("cookie", &cookie)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let restored_events: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM audit_log WHERE event_name = 'DatabaseBackupRestored'",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(restored_events, 1);
+}
@@ -279,6 +279,71 @@ async fn test_election_number_of_voters_change_not_found(pool: SqlitePool) {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+async fn test_election_model_language_change_frisian_not_allowed(pool: SqlitePool) {
+    let addr = serve_api(pool).await;
+    let coordinator_cookie = login(&addr, CoordinatorGSB).await;
+
+    let url = format!("http://{addr}/api/elections/2/model_language");
+    let response = reqwest::Client::new()
+        .put(&url)
+        .header("cookie", coordinator_cookie)
+        .json(&serde_json::json!({
+            "model_language": "fy",
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
+async fn test_election_model_language_change_works(pool: SqlitePool) {
+    sqlx::query("UPDATE elections SET frisian_export_allowed = 1 WHERE id = 2")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let addr = serve_api(pool).await;
+    let coordinator_cookie = login(&addr, CoordinatorGSB).await;
+    let client = reqwest::Client::new();
+
+    // The models are produced in Dutch by default, even when Frisian is allowed
+    let url = format!("http://{addr}/api/elections/2");
+    let body: serde_json::Value = client
+        .get(&url)
+        .header("cookie", &coordinator_cookie)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["election"]["model_language"], "nl");
+
+    let response = client
+        .put(format!("{url}/model_language"))
+        .header("cookie", &coordinator_cookie)
+        .json(&serde_json::json!({
+            "model_language": "fy",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let body: serde_json::Value = client
+        .get(&url)
+        .header("cookie", &coordinator_cookie)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["election"]["model_language"], "fy");
+}
+
 #[test(sqlx::test(fixtures(path = "../../fixtures", scripts("election_2", "users"))))]
 async fn test_election_n_10_2_download(pool: SqlitePool) {
     let addr = serve_api(pool).await;
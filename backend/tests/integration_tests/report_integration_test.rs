@@ -229,7 +229,7 @@ async fn test_csb_election_zip_download_results_works(pool: SqlitePool) {
         &addr,
         &cookie,
         election_id,
-        ApportionmentAction::SkipDeceasedCandidates,
+        ApportionmentAction::SkipExcludedCandidates,
     )
     .await;
 
@@ -322,7 +322,7 @@ async fn test_csb_election_zip_download_attachment_works(pool: SqlitePool) {
         &addr,
         &cookie,
         election_id,
-        ApportionmentAction::SkipDeceasedCandidates,
+        ApportionmentAction::SkipExcludedCandidates,
     )
     .await;
 
@@ -404,7 +404,7 @@ async fn test_csb_election_zip_download_total_counts_works(pool: SqlitePool) {
         &addr,
         &cookie,
         election_id,
-        ApportionmentAction::SkipDeceasedCandidates,
+        ApportionmentAction::SkipExcludedCandidates,
     )
     .await;
 
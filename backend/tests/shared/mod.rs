@@ -531,17 +531,19 @@ pub async fn get_statuses(
 #[serde(untagged)]
 #[strum(serialize_all = "snake_case")]
 pub enum ApportionmentAction {
-    SkipDeceasedCandidates,
-    RegisterDeceasedCandidates,
-    AddDeceasedCandidate {
+    SkipExcludedCandidates,
+    RegisterExcludedCandidates,
+    AddExcludedCandidate {
         pg_number: u32,
         candidate_number: u32,
+        reason: &'static str,
     },
-    DeleteDeceasedCandidate {
+    DeleteExcludedCandidate {
         pg_number: u32,
         candidate_number: u32,
+        reason: &'static str,
     },
-    FinaliseDeceasedCandidates,
+    FinaliseExcludedCandidates,
     Reset,
 }
 
@@ -4,7 +4,7 @@ use std::net::SocketAddr;
 
 use abacus::{
     api::middleware::airgap::AirgapDetection,
-    infra::{backup::BackupConfig, router},
+    infra::{backup::BackupConfig, claim_expiry::ClaimTimeout, router},
     shutdown_signal,
 };
 use sqlx::SqlitePool;
@@ -18,9 +18,14 @@ pub async fn serve_api(pool: SqlitePool) -> SocketAddr {
 pub async fn serve_api_with_backup_dir(pool: SqlitePool) -> (SocketAddr, TempDir) {
     let backup_dir = tempfile::tempdir().unwrap();
     let backup_config = BackupConfig::new(backup_dir.path().to_path_buf());
-    let app = router::create_router(pool, AirgapDetection::nop(), backup_config)
-        .unwrap()
-        .into_make_service_with_connect_info::<SocketAddr>();
+    let app = router::create_router(
+        pool,
+        AirgapDetection::nop(),
+        backup_config,
+        ClaimTimeout::default(),
+    )
+    .unwrap()
+    .into_make_service_with_connect_info::<SocketAddr>();
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
 
@@ -33,6 +33,8 @@ function typeToLabel(badgeType: BadgeType): LabelProps {
       return { label: t("data_entry.second_entry"), icon: <Icon size="sm" icon={<IconEdit />} /> };
     case "entries_different":
       return { label: t("data_entry.second_entry") };
+    case "entries_arbitration":
+      return { label: t("data_entry.third_entry") };
     case "definitive":
       return { label: t("data_entry.definitive") };
   }
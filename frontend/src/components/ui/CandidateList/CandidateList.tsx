@@ -1,6 +1,6 @@
 import type { ReactNode } from "react";
 import { t } from "@/i18n/translate";
-import type { Candidate, DeceasedCandidate, PoliticalGroup } from "@/types/generated/openapi";
+import type { Candidate, ExcludedCandidate, PoliticalGroup } from "@/types/generated/openapi";
 import { getCandidateFullName } from "@/utils/candidate";
 import { formatPoliticalGroupName } from "@/utils/politicalGroup";
 import { Table } from "../Table/Table";
@@ -53,7 +53,7 @@ function CandidateListRow({ candidate, pgNumber, isDeceased, onClick }: Candidat
 
 interface CandidateListTableProps {
   politicalGroup: PoliticalGroup;
-  deceasedCandidates?: DeceasedCandidate[];
+  deceasedCandidates?: ExcludedCandidate[];
   onClick?: (candidateNumber: number, pgNumber: number) => void;
 }
 
@@ -85,7 +85,7 @@ function CandidateListTable({ politicalGroup, deceasedCandidates, onClick }: Can
 
 export interface CandidateListsProps {
   politicalGroup: PoliticalGroup;
-  deceasedCandidates?: DeceasedCandidate[];
+  deceasedCandidates?: ExcludedCandidate[];
   onClick?: (candidateNumber: number, pgNumber: number) => void;
 }
 
@@ -61,14 +61,14 @@ describe("ApportionmentPage", () => {
         expectAlert: false,
         expectRedirectTo: "/elections/3/apportionment/include-all-candidates",
       },
-      RegisteringDeceasedCandidates: {
-        state: { deceased_candidates: [], type: "RegisteringDeceasedCandidates" },
+      RegisteringExcludedCandidates: {
+        state: { excluded_candidates: [], type: "RegisteringExcludedCandidates" },
         expectAlert: false,
         expectRedirectTo: "/elections/3/apportionment/deceased-candidates",
       },
       DrawingLots: {
         state: {
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             variant: "AbsoluteMajorityLargestRemainder",
             assign_to: 1,
@@ -83,7 +83,7 @@ describe("ApportionmentPage", () => {
         expectRedirectTo: undefined,
       },
       Finalised: {
-        state: { deceased_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
+        state: { excluded_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
         expectAlert: true,
         expectRedirectTo: undefined,
       },
@@ -145,7 +145,7 @@ describe("ApportionmentPage", () => {
     server.use(GetApportionmentStateRequestHandler);
     server.use(RegisterDeceasedCandidatesRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [{ pg_number: 1, candidate_number: 1 }],
+      excluded_candidates: [{ pg_number: 1, candidate_number: 1, reason: "deceased" }],
       lists_drawn: [],
       candidates_drawn: [],
       type: "Finalised",
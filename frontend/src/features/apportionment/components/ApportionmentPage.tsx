@@ -441,7 +441,7 @@ export function ApportionmentPage() {
                   seatAssignment={seatAssignment}
                   election={election}
                   preferenceThreshold={candidateNomination?.preference_threshold}
-                  numberOfDeceasedCandidates={state.deceased_candidates.length}
+                  numberOfDeceasedCandidates={state.excluded_candidates.length}
                 />
                 <ApportionmentTableSection state={state} seatAssignment={seatAssignment} election={election} />
                 {candidateNomination?.chosen_candidates && (
@@ -55,8 +55,8 @@ describe("AddDeceasedCandidatesPage", () => {
     );
     server.use(GetApportionmentStateRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [{ pg_number: 1, candidate_number: 1 }],
-      type: "RegisteringDeceasedCandidates",
+      excluded_candidates: [{ pg_number: 1, candidate_number: 1, reason: "deceased" }],
+      type: "RegisteringExcludedCandidates",
     });
   });
 
@@ -66,13 +66,13 @@ describe("AddDeceasedCandidatesPage", () => {
         state: { type: "Uninitialised" },
         expectRedirectTo: "/elections/3/apportionment/include-all-candidates",
       },
-      RegisteringDeceasedCandidates: {
-        state: { deceased_candidates: [], type: "RegisteringDeceasedCandidates" },
+      RegisteringExcludedCandidates: {
+        state: { excluded_candidates: [], type: "RegisteringExcludedCandidates" },
         expectRedirectTo: undefined,
       },
       DrawingLots: {
         state: {
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             variant: "AbsoluteMajorityLargestRemainder",
             assign_to: 1,
@@ -86,7 +86,7 @@ describe("AddDeceasedCandidatesPage", () => {
         expectRedirectTo: "/elections/3/apportionment",
       },
       Finalised: {
-        state: { deceased_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
+        state: { excluded_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
         expectRedirectTo: "/elections/3/apportionment",
       },
     } satisfies Record<
@@ -143,7 +143,7 @@ describe("AddDeceasedCandidatesPage", () => {
     const rows = within(table).getAllByRole("row");
     if (rows[2]) {
       await user.click(rows[2]);
-      expect(addDeceasedCandidate).toHaveBeenCalledWith({ pg_number: 1, candidate_number: 2 });
+      expect(addDeceasedCandidate).toHaveBeenCalledWith({ pg_number: 1, candidate_number: 2, reason: "deceased" });
       expect(getApportionmentState).toHaveBeenCalled();
       await waitFor(() => {
         expect(navigate).toHaveBeenCalledWith("/elections/3/apportionment/deceased-candidates");
@@ -234,7 +234,7 @@ describe("AddDeceasedCandidatesPage", () => {
     const rows = within(table).getAllByRole("row");
     if (rows[6]) {
       await user.click(rows[6]);
-      expect(addDeceasedCandidate).toHaveBeenCalledWith({ pg_number: 2, candidate_number: 6 });
+      expect(addDeceasedCandidate).toHaveBeenCalledWith({ pg_number: 2, candidate_number: 6, reason: "deceased" });
       expect(getApportionmentState).toHaveBeenCalled();
       await waitFor(() => {
         expect(navigate).toHaveBeenCalledWith("/elections/3/apportionment/deceased-candidates");
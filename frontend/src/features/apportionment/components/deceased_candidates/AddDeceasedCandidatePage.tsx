@@ -9,10 +9,10 @@ import { ProgressList } from "@/components/ui/ProgressList/ProgressList";
 import { useElection } from "@/hooks/election/useElection";
 import { t } from "@/i18n/translate";
 import type {
-  ADD_DECEASED_CANDIDATE_REQUEST_BODY,
-  ADD_DECEASED_CANDIDATE_REQUEST_PATH,
+  ADD_EXCLUDED_CANDIDATE_REQUEST_BODY,
+  ADD_EXCLUDED_CANDIDATE_REQUEST_PATH,
   ApportionmentState,
-  DeceasedCandidate,
+  ExcludedCandidate,
   PoliticalGroup,
 } from "@/types/generated/openapi";
 import { formatPoliticalGroupName } from "@/utils/politicalGroup";
@@ -49,14 +49,18 @@ export function AddDeceasedCandidatePage() {
     return <Loader />;
   }
 
-  let deceasedCandidates: DeceasedCandidate[] | undefined;
+  let deceasedCandidates: ExcludedCandidate[] | undefined;
   if (state && state.type !== "Uninitialised" && selectedList) {
-    deceasedCandidates = state.deceased_candidates.filter((dc) => dc.pg_number === selectedList.number);
+    deceasedCandidates = state.excluded_candidates.filter((dc) => dc.pg_number === selectedList.number);
   }
 
   async function handleAddDeceasedCandidate(candidateNumber: number, pgNumber: number) {
-    const path: ADD_DECEASED_CANDIDATE_REQUEST_PATH = `/api/elections/${election.id}/apportionment/add_deceased_candidate`;
-    const body: ADD_DECEASED_CANDIDATE_REQUEST_BODY = { candidate_number: candidateNumber, pg_number: pgNumber };
+    const path: ADD_EXCLUDED_CANDIDATE_REQUEST_PATH = `/api/elections/${election.id}/apportionment/add_excluded_candidate`;
+    const body: ADD_EXCLUDED_CANDIDATE_REQUEST_BODY = {
+      candidate_number: candidateNumber,
+      pg_number: pgNumber,
+      reason: "deceased",
+    };
     const response: ApiResult<ApportionmentState> = await client.postRequest(path, body);
 
     if (isSuccess(response)) {
@@ -74,7 +78,7 @@ export function AddDeceasedCandidatePage() {
         {error ? (
           <ApportionmentError error={error} />
         ) : (
-          state?.type === "RegisteringDeceasedCandidates" && (
+          state?.type === "RegisteringExcludedCandidates" && (
             <div className={cls.container}>
               <div className="w-39">{t("apportionment.indicate_deceased_candidate")}</div>
               <div className={cls.section}>
@@ -72,8 +72,8 @@ describe("DeceasedCandidatesPage", () => {
     );
     server.use(GetApportionmentStateRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [{ pg_number: 1, candidate_number: 1 }],
-      type: "RegisteringDeceasedCandidates",
+      excluded_candidates: [{ pg_number: 1, candidate_number: 1, reason: "deceased" }],
+      type: "RegisteringExcludedCandidates",
     });
   });
 
@@ -83,13 +83,13 @@ describe("DeceasedCandidatesPage", () => {
         state: { type: "Uninitialised" },
         expectRedirectTo: "/elections/3/apportionment/include-all-candidates",
       },
-      RegisteringDeceasedCandidates: {
-        state: { deceased_candidates: [], type: "RegisteringDeceasedCandidates" },
+      RegisteringExcludedCandidates: {
+        state: { excluded_candidates: [], type: "RegisteringExcludedCandidates" },
         expectRedirectTo: undefined,
       },
       DrawingLots: {
         state: {
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             variant: "AbsoluteMajorityLargestRemainder",
             assign_to: 1,
@@ -103,7 +103,7 @@ describe("DeceasedCandidatesPage", () => {
         expectRedirectTo: undefined,
       },
       Finalised: {
-        state: { deceased_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
+        state: { excluded_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
         expectRedirectTo: undefined,
       },
     } satisfies Record<
@@ -153,7 +153,7 @@ describe("DeceasedCandidatesPage", () => {
     if (rows[1]) {
       const deleteLink = await within(rows[1]).findByRole("button", { name: "Verwijderen" });
       await user.click(deleteLink);
-      expect(deleteDeceasedCandidate).toHaveBeenCalledWith({ pg_number: 1, candidate_number: 1 });
+      expect(deleteDeceasedCandidate).toHaveBeenCalledWith({ pg_number: 1, candidate_number: 1, reason: "deceased" });
     }
   });
 
@@ -163,7 +163,7 @@ describe("DeceasedCandidatesPage", () => {
     const resetApportionmentState = spyOnHandler(ResetApportionmentStateRequestHandler);
     const getApportionmentState = spyOnHandler(GetApportionmentStateRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [{ pg_number: 1, candidate_number: 1 }],
+      excluded_candidates: [{ pg_number: 1, candidate_number: 1, reason: "deceased" }],
       drawing_lots_required: {
         variant: "AbsoluteMajorityLargestRemainder",
         assign_to: 1,
@@ -212,7 +212,7 @@ describe("DeceasedCandidatesPage", () => {
     const resetApportionmentState = spyOnHandler(ResetApportionmentStateRequestHandler);
     const getApportionmentState = spyOnHandler(GetApportionmentStateRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [],
+      excluded_candidates: [],
       drawing_lots_required: {
         variant: "AbsoluteMajorityLargestRemainder",
         assign_to: 1,
@@ -255,7 +255,7 @@ describe("DeceasedCandidatesPage", () => {
     const resetApportionmentState = spyOnHandler(ResetApportionmentStateRequestHandler);
     const getApportionmentState = spyOnHandler(GetApportionmentStateRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [{ pg_number: 1, candidate_number: 1 }],
+      excluded_candidates: [{ pg_number: 1, candidate_number: 1, reason: "deceased" }],
       type: "Finalised",
     });
     const user = userEvent.setup();
@@ -296,7 +296,7 @@ describe("DeceasedCandidatesPage", () => {
     const resetApportionmentState = spyOnHandler(ResetApportionmentStateRequestHandler);
     const getApportionmentState = spyOnHandler(GetApportionmentStateRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [],
+      excluded_candidates: [],
       type: "Finalised",
     });
     const user = userEvent.setup();
@@ -366,8 +366,8 @@ describe("DeceasedCandidatesPage", () => {
     const finaliseDeceasedCandidates = spyOnHandler(FinaliseDeceasedCandidatesRequestHandler);
     const getApportionmentState = spyOnHandler(GetApportionmentStateRequestHandler);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [],
-      type: "RegisteringDeceasedCandidates",
+      excluded_candidates: [],
+      type: "RegisteringExcludedCandidates",
     });
     const user = userEvent.setup();
 
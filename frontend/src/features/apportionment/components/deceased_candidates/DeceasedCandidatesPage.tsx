@@ -13,10 +13,10 @@ import type {
   ApportionmentState,
   Candidate,
   CandidateNumber,
-  DELETE_DECEASED_CANDIDATE_REQUEST_BODY,
-  DELETE_DECEASED_CANDIDATE_REQUEST_PATH,
-  DeceasedCandidate,
-  FINALISE_DECEASED_CANDIDATES_REQUEST_PATH,
+  DELETE_EXCLUDED_CANDIDATE_REQUEST_BODY,
+  DELETE_EXCLUDED_CANDIDATE_REQUEST_PATH,
+  ExcludedCandidate,
+  FINALISE_EXCLUDED_CANDIDATES_REQUEST_PATH,
   PGNumber,
   PoliticalGroup,
   RESET_APPORTIONMENT_STATE_REQUEST_PATH,
@@ -37,7 +37,7 @@ interface DetailedDeceasedCandidate {
 function renderPageDescription(state: ApportionmentState, deceasedCandidates: DetailedDeceasedCandidate[]) {
   return (
     <>
-      {state.type === "RegisteringDeceasedCandidates"
+      {state.type === "RegisteringExcludedCandidates"
         ? t("apportionment.which_candidates_are_deceased")
         : `${t("apportionment.apportionment_already_calculated")} ${deceasedCandidates.length === 0 ? t("apportionment.no_candidates_are_deceased") : t("apportionment.below_candidates_are_deceased")}`}
     </>
@@ -94,7 +94,7 @@ function renderDeceasedCandidatesTable(
 }
 
 function get_detailed_deceased_candidates(
-  deceasedCandidates: DeceasedCandidate[],
+  deceasedCandidates: ExcludedCandidate[],
   politicalGroups: PoliticalGroup[],
 ): DetailedDeceasedCandidate[] {
   const detailedDeceasedCandidates: DetailedDeceasedCandidate[] = [];
@@ -138,12 +138,16 @@ export function DeceasedCandidatesPage() {
 
   let deceasedCandidates: DetailedDeceasedCandidate[] = [];
   if (state && state.type !== "Uninitialised") {
-    deceasedCandidates = get_detailed_deceased_candidates(state.deceased_candidates, election.political_groups);
+    deceasedCandidates = get_detailed_deceased_candidates(state.excluded_candidates, election.political_groups);
   }
 
   async function handleDeleteDeceasedCandidate(candidateNumber: number, pgNumber: number) {
-    const path: DELETE_DECEASED_CANDIDATE_REQUEST_PATH = `/api/elections/${election.id}/apportionment/delete_deceased_candidate`;
-    const body: DELETE_DECEASED_CANDIDATE_REQUEST_BODY = { candidate_number: candidateNumber, pg_number: pgNumber };
+    const path: DELETE_EXCLUDED_CANDIDATE_REQUEST_PATH = `/api/elections/${election.id}/apportionment/delete_excluded_candidate`;
+    const body: DELETE_EXCLUDED_CANDIDATE_REQUEST_BODY = {
+      candidate_number: candidateNumber,
+      pg_number: pgNumber,
+      reason: "deceased",
+    };
     const response: ApiResult<ApportionmentState> = await client.postRequest(path, body);
 
     if (isSuccess(response)) {
@@ -154,7 +158,7 @@ export function DeceasedCandidatesPage() {
   }
 
   async function handleFinaliseDeceasedCandidates() {
-    const path: FINALISE_DECEASED_CANDIDATES_REQUEST_PATH = `/api/elections/${election.id}/apportionment/finalise_deceased_candidates`;
+    const path: FINALISE_EXCLUDED_CANDIDATES_REQUEST_PATH = `/api/elections/${election.id}/apportionment/finalise_excluded_candidates`;
     const response: ApiResult<ApportionmentState> = await client.postRequest(path);
 
     if (isSuccess(response)) {
@@ -184,7 +188,7 @@ export function DeceasedCandidatesPage() {
           {error ? (
             <ApportionmentError error={error} />
           ) : (
-            (state?.type === "RegisteringDeceasedCandidates" ||
+            (state?.type === "RegisteringExcludedCandidates" ||
               state?.type === "DrawingLots" ||
               state?.type === "Finalised") && (
               <div className={cls.container}>
@@ -194,11 +198,11 @@ export function DeceasedCandidatesPage() {
                     {renderDeceasedCandidatesTable(
                       deceasedCandidates,
                       (candidateNumber, pgNumber) => void handleDeleteDeceasedCandidate(candidateNumber, pgNumber),
-                      state.type === "RegisteringDeceasedCandidates",
+                      state.type === "RegisteringExcludedCandidates",
                     )}
                   </div>
                 )}
-                {state.type === "RegisteringDeceasedCandidates" && (
+                {state.type === "RegisteringExcludedCandidates" && (
                   <>
                     <div className="ml-md-lg">
                       <Button.Link
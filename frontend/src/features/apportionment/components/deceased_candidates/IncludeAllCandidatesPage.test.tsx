@@ -62,13 +62,13 @@ describe("IncludeAllCandidatesPage", () => {
         state: { type: "Uninitialised" },
         expectRedirectTo: undefined,
       },
-      RegisteringDeceasedCandidates: {
-        state: { deceased_candidates: [], type: "RegisteringDeceasedCandidates" },
+      RegisteringExcludedCandidates: {
+        state: { excluded_candidates: [], type: "RegisteringExcludedCandidates" },
         expectRedirectTo: "/elections/3/apportionment/deceased-candidates/add",
       },
       DrawingLots: {
         state: {
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             variant: "AbsoluteMajorityLargestRemainder",
             assign_to: 1,
@@ -82,7 +82,7 @@ describe("IncludeAllCandidatesPage", () => {
         expectRedirectTo: "/elections/3/apportionment",
       },
       Finalised: {
-        state: { deceased_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
+        state: { excluded_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
         expectRedirectTo: "/elections/3/apportionment",
       },
     } satisfies Record<
@@ -105,11 +105,11 @@ describe("IncludeAllCandidatesPage", () => {
     }
   });
 
-  test("Redirects to DeceasedCandidatesPage when state RegisteringDeceasedCandidates and any deceased candidates", async () => {
+  test("Redirects to DeceasedCandidatesPage when state RegisteringExcludedCandidates and any deceased candidates", async () => {
     vi.spyOn(ReactRouter, "useNavigate").mockImplementation(() => navigate);
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [{ pg_number: 1, candidate_number: 1 }],
-      type: "RegisteringDeceasedCandidates",
+      excluded_candidates: [{ pg_number: 1, candidate_number: 1, reason: "deceased" }],
+      type: "RegisteringExcludedCandidates",
     });
 
     renderIncludeAllCandidatesPage(3);
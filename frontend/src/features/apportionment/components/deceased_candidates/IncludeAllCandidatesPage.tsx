@@ -12,8 +12,8 @@ import { useElection } from "@/hooks/election/useElection";
 import { t } from "@/i18n/translate";
 import type {
   ApportionmentState,
-  REGISTER_DECEASED_CANDIDATES_REQUEST_PATH,
-  SKIP_DECEASED_CANDIDATES_REQUEST_PATH,
+  REGISTER_EXCLUDED_CANDIDATES_REQUEST_PATH,
+  SKIP_EXCLUDED_CANDIDATES_REQUEST_PATH,
 } from "@/types/generated/openapi";
 import { StringFormData } from "@/utils/stringFormData";
 import { useApportionmentContext } from "../../hooks/useApportionmentContext";
@@ -72,8 +72,8 @@ export function IncludeAllCandidatesPage() {
   }
 
   useEffect(() => {
-    if (state?.type === "RegisteringDeceasedCandidates") {
-      if (state.deceased_candidates.length === 0) {
+    if (state?.type === "RegisteringExcludedCandidates") {
+      if (state.excluded_candidates.length === 0) {
         void navigate(`/elections/${election.id}/apportionment/deceased-candidates/add`);
       } else {
         void navigate(`/elections/${election.id}/apportionment/deceased-candidates`);
@@ -99,8 +99,8 @@ export function IncludeAllCandidatesPage() {
       return;
     }
 
-    const path: REGISTER_DECEASED_CANDIDATES_REQUEST_PATH | SKIP_DECEASED_CANDIDATES_REQUEST_PATH =
-      `/api/elections/${election.id}/apportionment/${includeAllCandidatesChoice === "no_deceased" ? "skip_deceased_candidates" : "register_deceased_candidates"}`;
+    const path: REGISTER_EXCLUDED_CANDIDATES_REQUEST_PATH | SKIP_EXCLUDED_CANDIDATES_REQUEST_PATH =
+      `/api/elections/${election.id}/apportionment/${includeAllCandidatesChoice === "no_deceased" ? "skip_excluded_candidates" : "register_excluded_candidates"}`;
     const response: ApiResult<ApportionmentState> = await client.postRequest(path);
 
     if (isSuccess(response)) {
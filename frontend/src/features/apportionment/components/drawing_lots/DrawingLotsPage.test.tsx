@@ -39,13 +39,13 @@ describe("DrawingLotsPage", () => {
         state: { type: "Uninitialised" },
         expectRedirectTo: "/elections/3/apportionment/include-all-candidates",
       },
-      RegisteringDeceasedCandidates: {
-        state: { deceased_candidates: [], type: "RegisteringDeceasedCandidates" },
+      RegisteringExcludedCandidates: {
+        state: { excluded_candidates: [], type: "RegisteringExcludedCandidates" },
         expectRedirectTo: "/elections/3/apportionment/deceased-candidates",
       },
       DrawingLots: {
         state: {
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             variant: "HighestAverageResidualSeat",
             options: [1, 2],
@@ -82,7 +82,7 @@ describe("DrawingLotsPage", () => {
         expectRedirectTo: undefined,
       },
       Finalised: {
-        state: { deceased_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
+        state: { excluded_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
         expectRedirectTo: "/elections/3/apportionment",
       },
     } satisfies Record<
@@ -118,7 +118,7 @@ describe("DrawingLotsPage", () => {
       HttpResponse.json(
         {
           candidates_drawn: [],
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             type: "ListDrawingLotsRequired",
             ...gte19SeatsAndP7DrawingLots.drawing_lots_required,
@@ -208,7 +208,7 @@ describe("DrawingLotsPage", () => {
       HttpResponse.json(
         {
           candidates_drawn: [],
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             type: "ListDrawingLotsRequired",
             ...lt19SeatsAndP7DrawingLots.drawing_lots_required,
@@ -296,7 +296,7 @@ describe("DrawingLotsPage", () => {
       HttpResponse.json(
         {
           candidates_drawn: [],
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             type: "ListDrawingLotsRequired",
             ...gte19SeatsAndP9DrawingLots.drawing_lots_required,
@@ -387,7 +387,7 @@ describe("DrawingLotsPage", () => {
       HttpResponse.json(
         {
           candidates_drawn: [],
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             type: "ListDrawingLotsRequired",
             ...lt19SeatsAndP9DrawingLots.drawing_lots_required,
@@ -478,7 +478,7 @@ describe("DrawingLotsPage", () => {
       HttpResponse.json(
         {
           candidates_drawn: [],
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             type: "CandidateDrawingLotsRequired",
             ...lt19SeatsAndP15DrawingLots.drawing_lots_required,
@@ -571,7 +571,7 @@ describe("DrawingLotsPage", () => {
       HttpResponse.json(
         {
           candidates_drawn: [],
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             type: "ListDrawingLotsRequired",
             ...lt19SeatsAndP7DrawingLots.drawing_lots_required,
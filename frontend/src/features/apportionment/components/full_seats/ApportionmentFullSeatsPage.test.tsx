@@ -33,13 +33,13 @@ describe("ApportionmentFullSeatsPage", () => {
         state: { type: "Uninitialised" },
         expectRedirectTo: "/elections/3/apportionment/include-all-candidates",
       },
-      RegisteringDeceasedCandidates: {
-        state: { deceased_candidates: [], type: "RegisteringDeceasedCandidates" },
+      RegisteringExcludedCandidates: {
+        state: { excluded_candidates: [], type: "RegisteringExcludedCandidates" },
         expectRedirectTo: "/elections/3/apportionment/deceased-candidates",
       },
       DrawingLots: {
         state: {
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             variant: "AbsoluteMajorityLargestRemainder",
             assign_to: 1,
@@ -53,7 +53,7 @@ describe("ApportionmentFullSeatsPage", () => {
         expectRedirectTo: undefined,
       },
       Finalised: {
-        state: { deceased_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
+        state: { excluded_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
         expectRedirectTo: undefined,
       },
     } satisfies Record<
@@ -31,10 +31,10 @@ describe("ApportionmentListDetailsPage", () => {
   beforeEach(() => {
     overrideOnce("get", "/api/elections/3", 200, getElectionMockData(lt19Seats.election, lt19Seats.committee_session));
     overrideOnce("get", "/api/elections/3/apportionment/state", 200, {
-      deceased_candidates: [
-        { pg_number: 1, candidate_number: 12 },
-        { pg_number: 2, candidate_number: 3 },
-        { pg_number: 1, candidate_number: 5 },
+      excluded_candidates: [
+        { pg_number: 1, candidate_number: 12, reason: "deceased" },
+        { pg_number: 2, candidate_number: 3, reason: "deceased" },
+        { pg_number: 1, candidate_number: 5, reason: "deceased" },
       ],
       lists_drawn: [],
       candidates_drawn: [],
@@ -54,13 +54,13 @@ describe("ApportionmentListDetailsPage", () => {
         state: { type: "Uninitialised" },
         expectRedirectTo: "/elections/3/apportionment/include-all-candidates",
       },
-      RegisteringDeceasedCandidates: {
-        state: { deceased_candidates: [], type: "RegisteringDeceasedCandidates" },
+      RegisteringExcludedCandidates: {
+        state: { excluded_candidates: [], type: "RegisteringExcludedCandidates" },
         expectRedirectTo: "/elections/3/apportionment/deceased-candidates",
       },
       DrawingLots: {
         state: {
-          deceased_candidates: [],
+          excluded_candidates: [],
           drawing_lots_required: {
             variant: "AbsoluteMajorityLargestRemainder",
             assign_to: 1,
@@ -74,7 +74,7 @@ describe("ApportionmentListDetailsPage", () => {
         expectRedirectTo: "/elections/3/apportionment",
       },
       Finalised: {
-        state: { deceased_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
+        state: { excluded_candidates: [], lists_drawn: [], candidates_drawn: [], type: "Finalised" },
         expectRedirectTo: undefined,
       },
     } satisfies Record<
@@ -258,7 +258,7 @@ export function ApportionmentListDetailsPage() {
   const listName = formatPoliticalGroupName(list);
   let listDeceasedCandidateNumbers: number[] = [];
   if (state?.type === "Finalised") {
-    listDeceasedCandidateNumbers = state.deceased_candidates
+    listDeceasedCandidateNumbers = state.excluded_candidates
       .filter((dc) => list.number === dc.pg_number)
       .map((dc) => dc.candidate_number);
   }
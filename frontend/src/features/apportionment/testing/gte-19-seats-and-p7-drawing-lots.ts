@@ -77,7 +77,7 @@ export const state: ApportionmentState = {
     type: "ListDrawingLotsRequired",
     ...drawing_lots_required,
   },
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [],
   candidates_drawn: [],
 };
@@ -145,7 +145,7 @@ export const state_after_one_drawing_lots_seat_assigned: ApportionmentState = {
       },
     ],
   },
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [
     {
       variant: {
@@ -277,7 +277,7 @@ export const state_after_two_drawing_lots_seats_assigned: ApportionmentState = {
       },
     ],
   },
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [
     {
       variant: {
@@ -2460,6 +2460,7 @@ export const election: ElectionWithPoliticalGroups = {
   authority_id: "0035",
   authority_name: "Juinen",
   authority_region: "Juinen",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0035", name: "Juinen" },
   category: "Municipal",
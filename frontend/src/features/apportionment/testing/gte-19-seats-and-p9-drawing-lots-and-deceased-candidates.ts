@@ -23,14 +23,16 @@ export const state: ApportionmentState = {
     type: "ListDrawingLotsRequired",
     ...drawing_lots_required,
   },
-  deceased_candidates: [
+  excluded_candidates: [
     {
       pg_number: 1,
       candidate_number: 1,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 5,
+      reason: "deceased",
     },
   ],
   lists_drawn: [],
@@ -39,14 +41,16 @@ export const state: ApportionmentState = {
 
 export const state_after_drawing_lots_seat_reassigned: ApportionmentState = {
   type: "Finalised",
-  deceased_candidates: [
+  excluded_candidates: [
     {
       pg_number: 1,
       candidate_number: 1,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 5,
+      reason: "deceased",
     },
   ],
   lists_drawn: [
@@ -3089,6 +3093,7 @@ export const election: ElectionWithPoliticalGroups = {
   authority_id: "0035",
   authority_name: "Juinen",
   authority_region: "Juinen",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0035", name: "Juinen" },
   category: "Municipal",
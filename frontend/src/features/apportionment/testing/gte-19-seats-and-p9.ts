@@ -9,7 +9,7 @@ import type {
 
 export const state: ApportionmentState = {
   type: "Finalised",
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [],
   candidates_drawn: [],
 };
@@ -2075,6 +2075,7 @@ export const election: ElectionWithPoliticalGroups = {
   authority_id: "0035",
   authority_name: "Juinen",
   authority_region: "Juinen",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0035", name: "Juinen" },
   category: "Municipal",
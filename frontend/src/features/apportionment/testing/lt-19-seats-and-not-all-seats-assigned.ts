@@ -10,10 +10,11 @@ import type {
 
 export const state: ApportionmentState = {
   type: "Finalised",
-  deceased_candidates: [
+  excluded_candidates: [
     {
       pg_number: 1,
       candidate_number: 1,
+      reason: "deceased",
     },
   ],
   lists_drawn: [],
@@ -808,6 +809,7 @@ export const election: ElectionWithPoliticalGroups = {
   authority_id: "0035",
   authority_name: "Juinen",
   authority_region: "Juinen",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0035", name: "Juinen" },
   category: "Municipal",
@@ -9,166 +9,206 @@ import type {
 
 export const state: ApportionmentState = {
   type: "Finalised",
-  deceased_candidates: [
+  excluded_candidates: [
     {
       pg_number: 1,
       candidate_number: 11,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 12,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 13,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 14,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 15,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 16,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 17,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 18,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 19,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 20,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 21,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 22,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 23,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 24,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 25,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 26,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 27,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 28,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 29,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 30,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 31,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 32,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 33,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 34,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 35,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 36,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 37,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 38,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 39,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 40,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 41,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 42,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 43,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 44,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 45,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 46,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 47,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 48,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 49,
+      reason: "deceased",
     },
     {
       pg_number: 1,
       candidate_number: 50,
+      reason: "deceased",
     },
   ],
   lists_drawn: [],
@@ -2314,6 +2354,7 @@ export const election: ElectionWithPoliticalGroups = {
   authority_id: "0035",
   authority_name: "Juinen",
   authority_region: "Juinen",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0035", name: "Juinen" },
   category: "Municipal",
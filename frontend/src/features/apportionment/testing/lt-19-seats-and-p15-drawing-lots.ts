@@ -24,7 +24,7 @@ export const state: ApportionmentState = {
     type: "CandidateDrawingLotsRequired",
     ...drawing_lots_required,
   },
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [],
   candidates_drawn: [],
 };
@@ -46,7 +46,7 @@ export const state_after_one_drawing_lots_candidate_assigned: ApportionmentState
     type: "CandidateDrawingLotsRequired",
     ...drawing_lots_required_after_one_drawing_lots_candidate_assigned,
   },
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [],
   candidates_drawn: [
     {
@@ -79,7 +79,7 @@ export const state_after_two_drawing_lots_candidates_assigned: ApportionmentStat
     type: "CandidateDrawingLotsRequired",
     ...drawing_lots_required_after_two_drawing_lots_candidate_assigned,
   },
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [],
   candidates_drawn: [
     {
@@ -107,7 +107,7 @@ export const state_after_two_drawing_lots_candidates_assigned: ApportionmentStat
 
 export const state_after_three_drawing_lots_candidates_assigned: ApportionmentState = {
   type: "Finalised",
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [],
   candidates_drawn: [
     {
@@ -1120,6 +1120,7 @@ export const election: ElectionWithPoliticalGroups = {
   authority_id: "0035",
   authority_name: "Juinen",
   authority_region: "Juinen",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0035", name: "Juinen" },
   category: "Municipal",
@@ -22,14 +22,14 @@ export const state: ApportionmentState = {
     type: "ListDrawingLotsRequired",
     ...drawing_lots_required,
   },
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [],
   candidates_drawn: [],
 };
 
 export const state_after_drawing_lots_seat_reassigned: ApportionmentState = {
   type: "Finalised",
-  deceased_candidates: [],
+  excluded_candidates: [],
   lists_drawn: [
     {
       variant: {
@@ -2000,6 +2000,7 @@ export const election: ElectionWithPoliticalGroups = {
   authority_id: "0035",
   authority_name: "Juinen",
   authority_region: "Juinen",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0035", name: "Juinen" },
   category: "Municipal",
@@ -140,7 +140,7 @@ export function apportionmentCheckStateAndRedirect(
 ) {
   if (state?.type === "Uninitialised") {
     void navigate(`/elections/${electionId}/apportionment/include-all-candidates`);
-  } else if (state?.type === "RegisteringDeceasedCandidates") {
+  } else if (state?.type === "RegisteringExcludedCandidates") {
     void navigate(`/elections/${electionId}/apportionment/deceased-candidates`);
   }
 }
@@ -22,6 +22,7 @@ const CreateBackupRequestHandler = http.post<CREATE_BACKUP_REQUEST_PARAMS, null,
     HttpResponse.json(
       {
         created_at: createdAt,
+        encrypted: false,
         filename: "db_backup_2026-07-07_00-00-00.sqlite",
       },
       { status: 201 },
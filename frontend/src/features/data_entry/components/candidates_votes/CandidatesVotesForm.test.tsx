@@ -307,6 +307,7 @@ describe("Test CandidatesVotesForm", () => {
         authority_id: "0000",
         authority_name: "Heemdamseburg",
         authority_region: "Heemdamseburg",
+        frisian_export_allowed: false,
         district: { district: "None" },
         domain: { id: "0000", name: "Heemdamseburg" },
         category: "Municipal",
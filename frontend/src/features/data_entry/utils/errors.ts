@@ -3,6 +3,7 @@ import type { ErrorReference } from "@/types/generated/openapi";
 export const redirectToHomePageErrorReferences: readonly ErrorReference[] = [
   "DataEntryAlreadyClaimed",
   "DataEntryAlreadyFinalised",
+  "DataEntryAssignedToOtherUser",
   "DataEntryNotAllowed",
   "InvalidStateTransition",
 ];
@@ -28,6 +28,7 @@ function entriesFinishedInStatus(status: DataEntryStatusName): number {
     case "second_entry_correction":
       return 1;
     case "entries_different":
+    case "entries_arbitration":
     case "definitive":
       return 2;
   }
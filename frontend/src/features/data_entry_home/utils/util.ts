@@ -43,6 +43,12 @@ function determineUserStatus(
       }
     case "first_entry_has_errors":
       return DataEntryUserStatus.HasErrors;
+    case "entries_arbitration":
+      if (statusEntry.first_entry_user_id === userId || statusEntry.second_entry_user_id === userId) {
+        return DataEntryUserStatus.Finished;
+      } else {
+        return DataEntryUserStatus.Available;
+      }
     case "entries_different":
     case "definitive":
       return DataEntryUserStatus.Finished;
@@ -30,6 +30,7 @@ const renderGSBTable = (
           authority_id: "0035",
           authority_name: "Heemdamseburg",
           authority_region: "Heemdamseburg",
+          frisian_export_allowed: false,
           district: { district: "None" },
           domain: { id: "0035", name: "Heemdamseburg" },
           category: "Municipal",
@@ -68,8 +68,8 @@ describe("CSBElectionReportSection", () => {
       getCSBElectionMockData({}, { status: "completed", location: "Den Haag", start_date_time: "2026-03-18T21:36:00" }),
     );
     overrideOnce("get", "/api/elections/2/apportionment/state", 200, {
-      type: "RegisteringDeceasedCandidates",
-      deceased_candidates: [],
+      type: "RegisteringExcludedCandidates",
+      excluded_candidates: [],
     } satisfies ApportionmentState);
 
     const router = renderPage();
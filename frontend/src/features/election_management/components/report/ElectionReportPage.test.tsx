@@ -317,7 +317,7 @@ describe("ElectionReportPage", () => {
     server.use(CSBElectionRequestHandler, CSBElectionStatusRequestHandler);
     vi.spyOn(ReactRouter, "useParams").mockReturnValue({ committeeSessionId: "2" });
     overrideOnce("get", "/api/elections/2/apportionment/state", 200, {
-      deceased_candidates: [],
+      excluded_candidates: [],
       lists_drawn: [],
       candidates_drawn: [],
       type: "Finalised",
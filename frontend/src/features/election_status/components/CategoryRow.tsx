@@ -25,6 +25,7 @@ const SHOW_BADGE: DataEntryStatusName[] = [
   "second_entry_in_progress",
   "second_entry_correction",
   "entries_different",
+  "entries_arbitration",
   "first_entry_has_errors",
 ];
 
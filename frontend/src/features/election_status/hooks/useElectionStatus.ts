@@ -35,6 +35,7 @@ export const statusesForCategory: Record<StatusCategory, DataEntryStatusName[]>
     "second_entry_in_progress",
     "first_entry_correction",
     "second_entry_correction",
+    "entries_arbitration",
   ],
   first_entry_finished: ["first_entry_finalised"],
   definitive: ["definitive"],
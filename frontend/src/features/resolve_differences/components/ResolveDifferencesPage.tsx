@@ -109,7 +109,8 @@ export function ResolveDifferencesPage() {
     return <Loader />;
   }
 
-  const { first_entry, first_entry_user_id, second_entry, second_entry_user_id, source } = differences;
+  const { first_entry, first_entry_user_id, imported_by_user_id, second_entry, second_entry_user_id, source } =
+    differences;
 
   return (
     <>
@@ -134,7 +135,7 @@ export function ResolveDifferencesPage() {
             correctEntry={formState.correctEntry}
           />
           <ResolveDifferencesForm
-            firstEntryName={getName(first_entry_user_id)}
+            firstEntryName={getName(first_entry_user_id ?? imported_by_user_id)}
             secondEntryName={getName(second_entry_user_id)}
             formState={formState}
             onSubmit={onSubmit}
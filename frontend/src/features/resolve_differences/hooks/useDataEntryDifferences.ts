@@ -49,7 +49,10 @@ function resolvedUserIds(
   correctEntry: CorrectEntry | undefined,
 ): { keptUserId: number | undefined; wrongUserId: number | undefined } {
   if (differences && correctEntry === "first") {
-    return { keptUserId: differences.first_entry_user_id, wrongUserId: differences.second_entry_user_id };
+    return {
+      keptUserId: differences.first_entry_user_id ?? differences.imported_by_user_id,
+      wrongUserId: differences.second_entry_user_id,
+    };
   }
   if (differences && correctEntry === "second") {
     return { keptUserId: differences.second_entry_user_id, wrongUserId: differences.first_entry_user_id };
@@ -266,6 +266,7 @@ export const electionListMockResponse: ElectionListResponse = {
       authority_id: "0035",
       authority_name: "Heemdamseburg",
       authority_region: "Heemdamseburg",
+      frisian_export_allowed: false,
       district: { district: "None" },
       domain: { id: "0035", name: "Heemdamseburg" },
       category: "Municipal",
@@ -284,6 +285,7 @@ export const electionListMockResponse: ElectionListResponse = {
       authority_id: "0035",
       authority_name: "Heemdamseburg",
       authority_region: "Heemdamseburg",
+      frisian_export_allowed: false,
       district: { district: "None" },
       domain: { id: "0035", name: "Heemdamseburg" },
       category: "Municipal",
@@ -423,6 +425,7 @@ export const electionImportMockResponse: ElectionWithPoliticalGroups = {
   authority_id: "0000",
   authority_name: "Test",
   authority_region: "Test",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0000", name: "Test" },
   category: "Municipal",
@@ -443,6 +446,7 @@ export const csbElectionImportMockResponse: ElectionWithPoliticalGroups = {
   authority_id: "0000",
   authority_name: "Test",
   authority_region: "Test",
+  frisian_export_allowed: false,
   district: { district: "None" },
   domain: { id: "0000", name: "Test" },
   category: "Municipal",
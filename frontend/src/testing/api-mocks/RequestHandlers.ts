@@ -6,9 +6,9 @@ import type {
   ACCOUNT_UPDATE_REQUEST_BODY,
   ACCOUNT_UPDATE_REQUEST_PARAMS,
   ACCOUNT_UPDATE_REQUEST_PATH,
-  ADD_DECEASED_CANDIDATE_REQUEST_BODY,
-  ADD_DECEASED_CANDIDATE_REQUEST_PARAMS,
-  ADD_DECEASED_CANDIDATE_REQUEST_PATH,
+  ADD_EXCLUDED_CANDIDATE_REQUEST_BODY,
+  ADD_EXCLUDED_CANDIDATE_REQUEST_PARAMS,
+  ADD_EXCLUDED_CANDIDATE_REQUEST_PATH,
   ADMIN_EXISTS_REQUEST_PARAMS,
   ADMIN_EXISTS_REQUEST_PATH,
   ApportionmentState,
@@ -58,9 +58,9 @@ import type {
   DataEntryGetDifferencesResponse,
   DataEntryGetResponse,
   DataEntryStatusResponse,
-  DELETE_DECEASED_CANDIDATE_REQUEST_BODY,
-  DELETE_DECEASED_CANDIDATE_REQUEST_PARAMS,
-  DELETE_DECEASED_CANDIDATE_REQUEST_PATH,
+  DELETE_EXCLUDED_CANDIDATE_REQUEST_BODY,
+  DELETE_EXCLUDED_CANDIDATE_REQUEST_PARAMS,
+  DELETE_EXCLUDED_CANDIDATE_REQUEST_PATH,
   ELECTION_DETAILS_REQUEST_PARAMS,
   ELECTION_DETAILS_REQUEST_PATH,
   ELECTION_IMPORT_REQUEST_BODY,
@@ -82,8 +82,8 @@ import type {
   ElectionStatusResponse,
   ElectionWithPoliticalGroups,
   ErrorResponse,
-  FINALISE_DECEASED_CANDIDATES_REQUEST_PARAMS,
-  FINALISE_DECEASED_CANDIDATES_REQUEST_PATH,
+  FINALISE_EXCLUDED_CANDIDATES_REQUEST_PARAMS,
+  FINALISE_EXCLUDED_CANDIDATES_REQUEST_PATH,
   GET_APPORTIONMENT_STATE_REQUEST_PARAMS,
   GET_APPORTIONMENT_STATE_REQUEST_PATH,
   INITIALISED_REQUEST_PARAMS,
@@ -118,13 +118,13 @@ import type {
   PollingStation,
   PollingStationInvestigation,
   PollingStationListResponse,
-  REGISTER_DECEASED_CANDIDATES_REQUEST_PARAMS,
-  REGISTER_DECEASED_CANDIDATES_REQUEST_PATH,
+  REGISTER_EXCLUDED_CANDIDATES_REQUEST_PARAMS,
+  REGISTER_EXCLUDED_CANDIDATES_REQUEST_PATH,
   RESET_APPORTIONMENT_STATE_REQUEST_PARAMS,
   RESET_APPORTIONMENT_STATE_REQUEST_PATH,
   SaveDataEntryResponse,
-  SKIP_DECEASED_CANDIDATES_REQUEST_PARAMS,
-  SKIP_DECEASED_CANDIDATES_REQUEST_PATH,
+  SKIP_EXCLUDED_CANDIDATES_REQUEST_PARAMS,
+  SKIP_EXCLUDED_CANDIDATES_REQUEST_PATH,
   USER_CREATE_REQUEST_BODY,
   USER_CREATE_REQUEST_PARAMS,
   USER_CREATE_REQUEST_PATH,
@@ -560,21 +560,21 @@ export const ResetApportionmentStateRequestHandler = http.post<
 );
 
 export const RegisterDeceasedCandidatesRequestHandler = http.post<
-  ParamsToString<REGISTER_DECEASED_CANDIDATES_REQUEST_PARAMS>
+  ParamsToString<REGISTER_EXCLUDED_CANDIDATES_REQUEST_PARAMS>
 >(
-  "/api/elections/3/apportionment/register_deceased_candidates" satisfies REGISTER_DECEASED_CANDIDATES_REQUEST_PATH,
+  "/api/elections/3/apportionment/register_excluded_candidates" satisfies REGISTER_EXCLUDED_CANDIDATES_REQUEST_PATH,
   () =>
-    HttpResponse.json({ deceased_candidates: [], type: "RegisteringDeceasedCandidates" } satisfies ApportionmentState, {
+    HttpResponse.json({ excluded_candidates: [], type: "RegisteringExcludedCandidates" } satisfies ApportionmentState, {
       status: 200,
     }),
 );
 
-export const SkipDeceasedCandidatesRequestHandler = http.post<ParamsToString<SKIP_DECEASED_CANDIDATES_REQUEST_PARAMS>>(
-  "/api/elections/3/apportionment/skip_deceased_candidates" satisfies SKIP_DECEASED_CANDIDATES_REQUEST_PATH,
+export const SkipDeceasedCandidatesRequestHandler = http.post<ParamsToString<SKIP_EXCLUDED_CANDIDATES_REQUEST_PARAMS>>(
+  "/api/elections/3/apportionment/skip_excluded_candidates" satisfies SKIP_EXCLUDED_CANDIDATES_REQUEST_PATH,
   () =>
     HttpResponse.json(
       {
-        deceased_candidates: [],
+        excluded_candidates: [],
         lists_drawn: [],
         candidates_drawn: [],
         type: "Finalised",
@@ -584,16 +584,16 @@ export const SkipDeceasedCandidatesRequestHandler = http.post<ParamsToString<SKI
 );
 
 export const AddDeceasedCandidateRequestHandler = http.post<
-  ParamsToString<ADD_DECEASED_CANDIDATE_REQUEST_PARAMS>,
-  ADD_DECEASED_CANDIDATE_REQUEST_BODY
->("/api/elections/3/apportionment/add_deceased_candidate" satisfies ADD_DECEASED_CANDIDATE_REQUEST_PATH, () =>
+  ParamsToString<ADD_EXCLUDED_CANDIDATE_REQUEST_PARAMS>,
+  ADD_EXCLUDED_CANDIDATE_REQUEST_BODY
+>("/api/elections/3/apportionment/add_excluded_candidate" satisfies ADD_EXCLUDED_CANDIDATE_REQUEST_PATH, () =>
   HttpResponse.json(
     {
-      deceased_candidates: [
-        { pg_number: 1, candidate_number: 1 },
-        { pg_number: 1, candidate_number: 2 },
+      excluded_candidates: [
+        { pg_number: 1, candidate_number: 1, reason: "deceased" },
+        { pg_number: 1, candidate_number: 2, reason: "deceased" },
       ],
-      type: "RegisteringDeceasedCandidates",
+      type: "RegisteringExcludedCandidates",
     } satisfies ApportionmentState,
     {
       status: 200,
@@ -602,22 +602,22 @@ export const AddDeceasedCandidateRequestHandler = http.post<
 );
 
 export const DeleteDeceasedCandidateRequestHandler = http.post<
-  ParamsToString<DELETE_DECEASED_CANDIDATE_REQUEST_PARAMS>,
-  DELETE_DECEASED_CANDIDATE_REQUEST_BODY
->("/api/elections/3/apportionment/delete_deceased_candidate" satisfies DELETE_DECEASED_CANDIDATE_REQUEST_PATH, () =>
-  HttpResponse.json({ deceased_candidates: [], type: "RegisteringDeceasedCandidates" } satisfies ApportionmentState, {
+  ParamsToString<DELETE_EXCLUDED_CANDIDATE_REQUEST_PARAMS>,
+  DELETE_EXCLUDED_CANDIDATE_REQUEST_BODY
+>("/api/elections/3/apportionment/delete_excluded_candidate" satisfies DELETE_EXCLUDED_CANDIDATE_REQUEST_PATH, () =>
+  HttpResponse.json({ excluded_candidates: [], type: "RegisteringExcludedCandidates" } satisfies ApportionmentState, {
     status: 200,
   }),
 );
 
 export const FinaliseDeceasedCandidatesRequestHandler = http.post<
-  ParamsToString<FINALISE_DECEASED_CANDIDATES_REQUEST_PARAMS>
+  ParamsToString<FINALISE_EXCLUDED_CANDIDATES_REQUEST_PARAMS>
 >(
-  "/api/elections/3/apportionment/finalise_deceased_candidates" satisfies FINALISE_DECEASED_CANDIDATES_REQUEST_PATH,
+  "/api/elections/3/apportionment/finalise_excluded_candidates" satisfies FINALISE_EXCLUDED_CANDIDATES_REQUEST_PATH,
   () =>
     HttpResponse.json(
       {
-        deceased_candidates: [],
+        excluded_candidates: [],
         lists_drawn: [],
         candidates_drawn: [],
         type: "Finalised",
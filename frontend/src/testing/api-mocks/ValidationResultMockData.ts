@@ -46,6 +46,16 @@ export const validationResultMockData: ErrorWarningsMap<ValidationResultCode> =
     fields: ["data.votes_counts.total_votes_candidates_count"],
     code: "F204",
   },
+  F205: {
+    fields: [
+      "data.votes_counts.yes_votes_count",
+      "data.votes_counts.no_votes_count",
+      "data.votes_counts.blank_votes_count",
+      "data.votes_counts.invalid_votes_count",
+      "data.votes_counts.total_votes_cast_count",
+    ],
+    code: "F205",
+  },
   F301: {
     fields: ["data.differences_counts.compare_votes_cast_admitted_voters.admitted_voters_equal_votes_cast"],
     code: "F301",
@@ -116,4 +126,7 @@ export const validationResultMockData: ErrorWarningsMap<ValidationResultCode> =
   W204: { fields: ["data.votes_counts.total_votes_cast_count"], code: "W204" },
   W205: { fields: ["data.number_of_voters"], code: "W205" },
   W206: { fields: ["data.number_of_voters"], code: "W206" },
+  W207: { fields: ["data.votes_counts.blank_votes_count"], code: "W207" },
+  W208: { fields: ["data.votes_counts.invalid_votes_count"], code: "W208" },
+  W209: { fields: ["data.votes_counts.total_votes_cast_count"], code: "W209" },
 };
@@ -10,9 +10,16 @@ export type ACCOUNT_UPDATE_REQUEST_PATH = `/api/account`;
 export type ACCOUNT_UPDATE_REQUEST_BODY = AccountUpdateRequest;
 
 // /api/backup
+export type LIST_BACKUPS_REQUEST_PARAMS = Record<string, never>;
+export type LIST_BACKUPS_REQUEST_PATH = `/api/backup`;
 export type CREATE_BACKUP_REQUEST_PARAMS = Record<string, never>;
 export type CREATE_BACKUP_REQUEST_PATH = `/api/backup`;
 
+// /api/backup/restore
+export type RESTORE_BACKUP_REQUEST_PARAMS = Record<string, never>;
+export type RESTORE_BACKUP_REQUEST_PATH = `/api/backup/restore`;
+export type RESTORE_BACKUP_REQUEST_BODY = BackupRestoreRequest;
+
 // /api/data_entries/{data_entry_id}
 export interface DATA_ENTRY_RESET_REQUEST_PARAMS {
   data_entry_id: DataEntryId;
@@ -25,6 +32,13 @@ export interface DATA_ENTRY_GET_REQUEST_PARAMS {
 }
 export type DATA_ENTRY_GET_REQUEST_PATH = `/api/data_entries/${DataEntryId}/get`;
 
+// /api/data_entries/{data_entry_id}/import
+export interface DATA_ENTRY_IMPORT_REQUEST_PARAMS {
+  data_entry_id: DataEntryId;
+}
+export type DATA_ENTRY_IMPORT_REQUEST_PATH = `/api/data_entries/${DataEntryId}/import`;
+export type DATA_ENTRY_IMPORT_REQUEST_BODY = DataEntryImportRequest;
+
 // /api/data_entries/{data_entry_id}/resolve_differences
 export interface DATA_ENTRY_GET_DIFFERENCES_REQUEST_PARAMS {
   data_entry_id: DataEntryId;
@@ -43,6 +57,28 @@ export interface DATA_ENTRY_RESOLVE_ERRORS_REQUEST_PARAMS {
 export type DATA_ENTRY_RESOLVE_ERRORS_REQUEST_PATH = `/api/data_entries/${DataEntryId}/resolve_errors`;
 export type DATA_ENTRY_RESOLVE_ERRORS_REQUEST_BODY = ResolveErrorsAction;
 
+// /api/data_entries/{data_entry_id}/revisions
+export interface DATA_ENTRY_LIST_REVISIONS_REQUEST_PARAMS {
+  data_entry_id: DataEntryId;
+}
+export type DATA_ENTRY_LIST_REVISIONS_REQUEST_PATH = `/api/data_entries/${DataEntryId}/revisions`;
+
+// /api/data_entries/{data_entry_id}/revisions/{from_revision}/diff/{to_revision}
+export interface DATA_ENTRY_DIFF_REVISIONS_REQUEST_PARAMS {
+  data_entry_id: DataEntryId;
+  from_revision: number;
+  to_revision: number;
+}
+export type DATA_ENTRY_DIFF_REVISIONS_REQUEST_PATH =
+  `/api/data_entries/${DataEntryId}/revisions/${number}/diff/${number}`;
+
+// /api/data_entries/{data_entry_id}/revisions/{revision}
+export interface DATA_ENTRY_GET_REVISION_REQUEST_PARAMS {
+  data_entry_id: DataEntryId;
+  revision: number;
+}
+export type DATA_ENTRY_GET_REVISION_REQUEST_PATH = `/api/data_entries/${DataEntryId}/revisions/${number}`;
+
 // /api/data_entries/{data_entry_id}/{entry_number}
 export interface DATA_ENTRY_SAVE_REQUEST_PARAMS {
   data_entry_id: DataEntryId;
@@ -56,6 +92,13 @@ export interface DATA_ENTRY_DISCARD_REQUEST_PARAMS {
 }
 export type DATA_ENTRY_DISCARD_REQUEST_PATH = `/api/data_entries/${DataEntryId}/${number}`;
 
+// /api/data_entries/{data_entry_id}/{entry_number}/assignment
+export interface DATA_ENTRY_UNASSIGN_REQUEST_PARAMS {
+  data_entry_id: DataEntryId;
+  entry_number: number;
+}
+export type DATA_ENTRY_UNASSIGN_REQUEST_PATH = `/api/data_entries/${DataEntryId}/${number}/assignment`;
+
 // /api/data_entries/{data_entry_id}/{entry_number}/claim
 export interface DATA_ENTRY_CLAIM_REQUEST_PARAMS {
   data_entry_id: DataEntryId;
@@ -70,6 +113,14 @@ export interface DATA_ENTRY_FINALISE_REQUEST_PARAMS {
 }
 export type DATA_ENTRY_FINALISE_REQUEST_PATH = `/api/data_entries/${DataEntryId}/${number}/finalise`;
 
+// /api/data_entries/{data_entry_id}/{entry_number}/take_over
+export interface DATA_ENTRY_TAKE_OVER_REQUEST_PARAMS {
+  data_entry_id: DataEntryId;
+  entry_number: number;
+}
+export type DATA_ENTRY_TAKE_OVER_REQUEST_PATH = `/api/data_entries/${DataEntryId}/${number}/take_over`;
+export type DATA_ENTRY_TAKE_OVER_REQUEST_BODY = DataEntryTakeOverRequest;
+
 // /api/elections
 export type ELECTION_LIST_REQUEST_PARAMS = Record<string, never>;
 export type ELECTION_LIST_REQUEST_PATH = `/api/elections`;
@@ -103,12 +154,12 @@ export interface ADD_CANDIDATE_DRAWN_REQUEST_PARAMS {
 export type ADD_CANDIDATE_DRAWN_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/add_candidate_drawn`;
 export type ADD_CANDIDATE_DRAWN_REQUEST_BODY = CandidateDrawn;
 
-// /api/elections/{election_id}/apportionment/add_deceased_candidate
-export interface ADD_DECEASED_CANDIDATE_REQUEST_PARAMS {
+// /api/elections/{election_id}/apportionment/add_excluded_candidate
+export interface ADD_EXCLUDED_CANDIDATE_REQUEST_PARAMS {
   election_id: ElectionId;
 }
-export type ADD_DECEASED_CANDIDATE_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/add_deceased_candidate`;
-export type ADD_DECEASED_CANDIDATE_REQUEST_BODY = DeceasedCandidate;
+export type ADD_EXCLUDED_CANDIDATE_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/add_excluded_candidate`;
+export type ADD_EXCLUDED_CANDIDATE_REQUEST_BODY = ExcludedCandidate;
 
 // /api/elections/{election_id}/apportionment/add_list_drawn
 export interface ADD_LIST_DRAWN_REQUEST_PARAMS {
@@ -117,27 +168,27 @@ export interface ADD_LIST_DRAWN_REQUEST_PARAMS {
 export type ADD_LIST_DRAWN_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/add_list_drawn`;
 export type ADD_LIST_DRAWN_REQUEST_BODY = ListDrawn;
 
-// /api/elections/{election_id}/apportionment/delete_deceased_candidate
-export interface DELETE_DECEASED_CANDIDATE_REQUEST_PARAMS {
+// /api/elections/{election_id}/apportionment/delete_excluded_candidate
+export interface DELETE_EXCLUDED_CANDIDATE_REQUEST_PARAMS {
   election_id: ElectionId;
 }
-export type DELETE_DECEASED_CANDIDATE_REQUEST_PATH =
-  `/api/elections/${ElectionId}/apportionment/delete_deceased_candidate`;
-export type DELETE_DECEASED_CANDIDATE_REQUEST_BODY = DeceasedCandidate;
+export type DELETE_EXCLUDED_CANDIDATE_REQUEST_PATH =
+  `/api/elections/${ElectionId}/apportionment/delete_excluded_candidate`;
+export type DELETE_EXCLUDED_CANDIDATE_REQUEST_BODY = ExcludedCandidate;
 
-// /api/elections/{election_id}/apportionment/finalise_deceased_candidates
-export interface FINALISE_DECEASED_CANDIDATES_REQUEST_PARAMS {
+// /api/elections/{election_id}/apportionment/finalise_excluded_candidates
+export interface FINALISE_EXCLUDED_CANDIDATES_REQUEST_PARAMS {
   election_id: ElectionId;
 }
-export type FINALISE_DECEASED_CANDIDATES_REQUEST_PATH =
-  `/api/elections/${ElectionId}/apportionment/finalise_deceased_candidates`;
+export type FINALISE_EXCLUDED_CANDIDATES_REQUEST_PATH =
+  `/api/elections/${ElectionId}/apportionment/finalise_excluded_candidates`;
 
-// /api/elections/{election_id}/apportionment/register_deceased_candidates
-export interface REGISTER_DECEASED_CANDIDATES_REQUEST_PARAMS {
+// /api/elections/{election_id}/apportionment/register_excluded_candidates
+export interface REGISTER_EXCLUDED_CANDIDATES_REQUEST_PARAMS {
   election_id: ElectionId;
 }
-export type REGISTER_DECEASED_CANDIDATES_REQUEST_PATH =
-  `/api/elections/${ElectionId}/apportionment/register_deceased_candidates`;
+export type REGISTER_EXCLUDED_CANDIDATES_REQUEST_PATH =
+  `/api/elections/${ElectionId}/apportionment/register_excluded_candidates`;
 
 // /api/elections/{election_id}/apportionment/reset
 export interface RESET_APPORTIONMENT_STATE_REQUEST_PARAMS {
@@ -145,12 +196,40 @@ export interface RESET_APPORTIONMENT_STATE_REQUEST_PARAMS {
 }
 export type RESET_APPORTIONMENT_STATE_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/reset`;
 
-// /api/elections/{election_id}/apportionment/skip_deceased_candidates
-export interface SKIP_DECEASED_CANDIDATES_REQUEST_PARAMS {
+// /api/elections/{election_id}/apportionment/seat_margins
+export interface GET_SEAT_MARGINS_REQUEST_PARAMS {
   election_id: ElectionId;
 }
-export type SKIP_DECEASED_CANDIDATES_REQUEST_PATH =
-  `/api/elections/${ElectionId}/apportionment/skip_deceased_candidates`;
+export type GET_SEAT_MARGINS_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/seat_margins`;
+
+// /api/elections/{election_id}/apportionment/seat_margins/download_pdf
+export interface DOWNLOAD_SEAT_MARGINS_PDF_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type DOWNLOAD_SEAT_MARGINS_PDF_REQUEST_PATH =
+  `/api/elections/${ElectionId}/apportionment/seat_margins/download_pdf`;
+
+// /api/elections/{election_id}/apportionment/skip_excluded_candidates
+export interface SKIP_EXCLUDED_CANDIDATES_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type SKIP_EXCLUDED_CANDIDATES_REQUEST_PATH =
+  `/api/elections/${ElectionId}/apportionment/skip_excluded_candidates`;
+
+// /api/elections/{election_id}/apportionment/snapshots
+export interface LIST_APPORTIONMENT_SNAPSHOTS_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type LIST_APPORTIONMENT_SNAPSHOTS_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/snapshots`;
+
+// /api/elections/{election_id}/apportionment/snapshots/{from_version}/diff/{to_version}
+export interface DIFF_APPORTIONMENT_SNAPSHOTS_REQUEST_PARAMS {
+  election_id: ElectionId;
+  from_version: number;
+  to_version: number;
+}
+export type DIFF_APPORTIONMENT_SNAPSHOTS_REQUEST_PATH =
+  `/api/elections/${ElectionId}/apportionment/snapshots/${number}/diff/${number}`;
 
 // /api/elections/{election_id}/apportionment/state
 export interface GET_APPORTIONMENT_STATE_REQUEST_PARAMS {
@@ -158,6 +237,25 @@ export interface GET_APPORTIONMENT_STATE_REQUEST_PARAMS {
 }
 export type GET_APPORTIONMENT_STATE_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/state`;
 
+// /api/elections/{election_id}/apportionment/vacancies
+export interface LIST_VACANCIES_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type LIST_VACANCIES_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/vacancies`;
+export interface REGISTER_VACANCY_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type REGISTER_VACANCY_REQUEST_PATH = `/api/elections/${ElectionId}/apportionment/vacancies`;
+export type REGISTER_VACANCY_REQUEST_BODY = VacancyRequest;
+
+// /api/elections/{election_id}/apportionment/vacancies/{vacancy_id}/download_pdf
+export interface DOWNLOAD_VACANCY_DECISION_PDF_REQUEST_PARAMS {
+  election_id: ElectionId;
+  vacancy_id: VacancyId;
+}
+export type DOWNLOAD_VACANCY_DECISION_PDF_REQUEST_PATH =
+  `/api/elections/${ElectionId}/apportionment/vacancies/${VacancyId}/download_pdf`;
+
 // /api/elections/{election_id}/committee_sessions
 export interface COMMITTEE_SESSION_CREATE_REQUEST_PARAMS {
   election_id: ElectionId;
@@ -228,6 +326,23 @@ export type COMMITTEE_SESSION_STATUS_CHANGE_REQUEST_PATH =
   `/api/elections/${ElectionId}/committee_sessions/${CommitteeSessionId}/status`;
 export type COMMITTEE_SESSION_STATUS_CHANGE_REQUEST_BODY = CommitteeSessionStatusChangeRequest;
 
+// /api/elections/{election_id}/data_entries/claim_next
+export interface DATA_ENTRY_CLAIM_NEXT_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type DATA_ENTRY_CLAIM_NEXT_REQUEST_PATH = `/api/elections/${ElectionId}/data_entries/claim_next`;
+
+// /api/elections/{election_id}/data_entry_assignments
+export interface DATA_ENTRY_ASSIGNMENT_LIST_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type DATA_ENTRY_ASSIGNMENT_LIST_REQUEST_PATH = `/api/elections/${ElectionId}/data_entry_assignments`;
+export interface DATA_ENTRY_ASSIGN_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type DATA_ENTRY_ASSIGN_REQUEST_PATH = `/api/elections/${ElectionId}/data_entry_assignments`;
+export type DATA_ENTRY_ASSIGN_REQUEST_BODY = AssignDataEntriesRequest;
+
 // /api/elections/{election_id}/download_n_10_2
 export interface ELECTION_DOWNLOAD_N_10_2_REQUEST_PARAMS {
   election_id: ElectionId;
@@ -246,6 +361,13 @@ export interface ELECTION_DOWNLOAD_NA_31_2_INLEGVEL_REQUEST_PARAMS {
 }
 export type ELECTION_DOWNLOAD_NA_31_2_INLEGVEL_REQUEST_PATH = `/api/elections/${ElectionId}/download_na_31_2_inlegvel`;
 
+// /api/elections/{election_id}/model_language
+export interface ELECTION_MODEL_LANGUAGE_CHANGE_REQUEST_PARAMS {
+  election_id: ElectionId;
+}
+export type ELECTION_MODEL_LANGUAGE_CHANGE_REQUEST_PATH = `/api/elections/${ElectionId}/model_language`;
+export type ELECTION_MODEL_LANGUAGE_CHANGE_REQUEST_BODY = ElectionModelLanguageChangeRequest;
+
 // /api/elections/{election_id}/polling_stations
 export interface POLLING_STATION_LIST_REQUEST_PARAMS {
   election_id: ElectionId;
@@ -336,6 +458,10 @@ export type AUDIT_LOG_LIST_REQUEST_PATH = `/api/log`;
 export type AUDIT_LOG_LIST_USERS_REQUEST_PARAMS = Record<string, never>;
 export type AUDIT_LOG_LIST_USERS_REQUEST_PATH = `/api/log-users`;
 
+// /api/log/verify
+export type AUDIT_LOG_VERIFY_REQUEST_PARAMS = Record<string, never>;
+export type AUDIT_LOG_VERIFY_REQUEST_PATH = `/api/log/verify`;
+
 // /api/login
 export type LOGIN_REQUEST_PARAMS = Record<string, never>;
 export type LOGIN_REQUEST_PATH = `/api/login`;
@@ -434,19 +560,58 @@ export interface AccountUpdateRequest {
   username: string;
 }
 
+/**
+ * Differences between two apportionment snapshots of an election
+ */
+export interface ApportionmentSnapshotDiff {
+  /** Candidates that are elected in `to`, but were not elected in `from` */
+  elected_candidates: SnapshotCandidate[];
+  /** Candidates that are excluded in `to`, but were not excluded (for the same reason) in `from` */
+  excluded_candidates_added: ExcludedCandidate[];
+  /** Candidates that were excluded in `from`, but are not excluded (for the same reason) in `to` */
+  excluded_candidates_removed: ExcludedCandidate[];
+  from: ApportionmentSnapshotSummary;
+  /** Lists of which the number of votes or seats changed */
+  lists: ListSnapshotDiff[];
+  /** Candidates that were elected in `from`, but are not elected in `to` */
+  no_longer_elected_candidates: SnapshotCandidate[];
+  to: ApportionmentSnapshotSummary;
+}
+
+export type ApportionmentSnapshotId = number;
+
+/**
+ * List of apportionment snapshots of an election
+ */
+export interface ApportionmentSnapshotListResponse {
+  snapshots: ApportionmentSnapshotSummary[];
+}
+
+/**
+ * Details of a stored apportionment snapshot, without its input and output
+ */
+export interface ApportionmentSnapshotSummary {
+  committee_session_id: CommitteeSessionId;
+  created_at: string;
+  created_by: UserId;
+  id: ApportionmentSnapshotId;
+  /** Version of the snapshot, counting from 1 for each election */
+  version: number;
+}
+
 export type ApportionmentState =
   | { type: "Uninitialised" }
-  | { deceased_candidates: DeceasedCandidate[]; type: "RegisteringDeceasedCandidates" }
+  | { excluded_candidates: ExcludedCandidate[]; type: "RegisteringExcludedCandidates" }
   | {
       candidates_drawn: CandidateDrawn[];
-      deceased_candidates: DeceasedCandidate[];
       drawing_lots_required: DrawingLotsRequired;
+      excluded_candidates: ExcludedCandidate[];
       lists_drawn: ListDrawn[];
       type: "DrawingLots";
     }
   | {
       candidates_drawn: CandidateDrawn[];
-      deceased_candidates: DeceasedCandidate[];
+      excluded_candidates: ExcludedCandidate[];
       lists_drawn: ListDrawn[];
       type: "Finalised";
     };
@@ -454,6 +619,17 @@ export type ApportionmentState =
 export const apportionmentWarningValues = ["AbsoluteMajorityAndListExhaustion", "NotAllSeatsAssigned"] as const;
 export type ApportionmentWarning = (typeof apportionmentWarningValues)[number];
 
+/**
+ * Request structure for assigning data entries to a typist
+ */
+export interface AssignDataEntriesRequest {
+  /** Entry number (first, second or third data entry) */
+  entry_number: number;
+  target: DataEntryAssignmentTarget;
+  /** Typist to assign the data entries to */
+  user_id: UserId;
+}
+
 export const auditEventLevelValues = ["info", "success", "warning", "error"] as const;
 export type AuditEventLevel = (typeof auditEventLevelValues)[number];
 
@@ -475,6 +651,10 @@ export const auditEventTypeValues = [
   "FileDeleted",
   "ApportionmentProcessed",
   "ApportionmentStateUpdated",
+  "ApportionmentSnapshotCreated",
+  "ExcludedCandidateAdded",
+  "ExcludedCandidateDeleted",
+  "VacancyRegistered",
   "InvestigationCreated",
   "InvestigationConcluded",
   "InvestigationUpdated",
@@ -490,6 +670,7 @@ export const auditEventTypeValues = [
   "DataEntryDiscarded",
   "DataEntryReset",
   "DataEntryFinalised",
+  "DataEntryImported",
   "DataEntryDiscardedFirst",
   "DataEntryReturnedFirst",
   "DataEntryKeptFirst",
@@ -497,10 +678,19 @@ export const auditEventTypeValues = [
   "DataEntryKeptFirstReturnedSecond",
   "DataEntryKeptSecondReturnedFirst",
   "DataEntryDiscardedBoth",
+  "DataEntrySentToArbitration",
+  "DataEntryArbitrationSucceeded",
+  "DataEntryArbitrationFailed",
+  "DataEntryAssigned",
+  "DataEntryUnassigned",
+  "DataEntryClaimExpired",
+  "DataEntryTakenOver",
   "AirGapViolationDetected",
   "AirGapViolationResolved",
   "ApplicationStarted",
   "DatabaseBackupCreated",
+  "DatabaseBackupCreatedAutomatically",
+  "DatabaseBackupRestored",
   "ApiError",
   "ApiWarning",
   "UnknownEvent",
@@ -537,9 +727,45 @@ export interface AuditLogUser {
   username: string;
 }
 
+/**
+ * Result of verifying the audit log hash chain
+ */
+export interface AuditLogVerification {
+  /** Hash of the last event in the audit log */
+  chain_head: string;
+  /** Number of events in the audit log */
+  event_count: number;
+  /** Id of the first event that does not match the hash chain */
+  first_invalid_event_id?: AuditLogEventId;
+  /** Number of events logged before the hash chain was introduced, these cannot be verified */
+  unchained_event_count: number;
+  /** Whether the hash of every chained event matches its content and the previous event */
+  valid: boolean;
+}
+
+/**
+ * List of available backups, newest first
+ */
+export interface BackupListResponse {
+  backups: BackupResponse[];
+}
+
 export interface BackupResponse {
   created_at: string;
+  /** Whether the backup is encrypted with the backup passphrase */
+  encrypted: boolean;
+  filename: string;
+}
+
+export interface BackupRestoreRequest {
+  filename: string;
+}
+
+export interface BackupRestoreResponse {
+  /** The backup that was restored */
   filename: string;
+  /** Backup of the database as it was right before the restore */
+  pre_restore_backup: BackupResponse;
 }
 
 /**
@@ -666,6 +892,15 @@ export interface CandidateNomination {
 
 export type CandidateNumber = number;
 
+export interface CandidatePreferenceMargin {
+  candidate: Candidate;
+  /** Number of votes the candidate needs to gain to get above the preference threshold,
+or to lose to no longer be above it */
+  margin: number;
+  meets_preference_threshold: boolean;
+  votes: number;
+}
+
 export interface CandidateVotes {
   number: number;
   votes: number;
@@ -714,6 +949,8 @@ export interface ChosenCandidate {
  * Response structure for getting data entry results
  */
 export interface ClaimDataEntryResponse {
+  /** Fields that differ between the first and second entry, which the third typist has to enter */
+  arbitration_fields?: string[];
   client_state: unknown;
   /** Fields for which a warning should be shown that a difference should be corrected */
   correction_warnings?: string[];
@@ -726,6 +963,16 @@ export interface ClaimDataEntryResponse {
   validation_results: ValidationResults;
 }
 
+/**
+ * Response structure for claiming the next assigned data entry
+ */
+export interface ClaimNextDataEntryResponse {
+  data_entry: ClaimDataEntryResponse;
+  data_entry_id: DataEntryId;
+  /** Entry number (first, second or third data entry) */
+  entry_number: number;
+}
+
 /**
  * Committee category
  */
@@ -884,10 +1131,42 @@ export interface DataEntry {
   progress: number;
 }
 
+/**
+ * Assignment of an entry of a data entry to a specific typist
+ */
+export interface DataEntryAssignment {
+  assigned_at: string;
+  /** Coordinator who assigned the entry */
+  assigned_by: UserId;
+  data_entry_id: DataEntryId;
+  /** Entry number (first, second or third data entry) */
+  entry_number: number;
+  /** Typist who is assigned to the entry */
+  user_id: UserId;
+}
+
+/**
+ * Data entry assignments response
+ */
+export interface DataEntryAssignmentListResponse {
+  assignments: DataEntryAssignment[];
+}
+
+/**
+ * Polling stations or sub-committees of which the data entries are assigned
+ */
+export type DataEntryAssignmentTarget =
+  | { numbers: u32[]; type: "polling_stations" }
+  | { from: u32; to: u32; type: "polling_station_range" }
+  | { numbers: u32[]; type: "sub_committees" };
+
 export interface DataEntryGetDifferencesResponse {
   first_entry: Results;
   first_entry_has_errors: boolean;
-  first_entry_user_id: UserId;
+  /** Not set when the first entry was imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the first entry from a counting file */
+  imported_by_user_id?: UserId;
   second_entry: Results;
   second_entry_has_errors: boolean;
   second_entry_user_id: UserId;
@@ -904,12 +1183,103 @@ export interface DataEntryGetResponse {
 
 export type DataEntryId = number;
 
+/**
+ * Request structure for importing a data entry from an EML_NL 510b file of a GSB
+ */
+export interface DataEntryImportRequest {
+  /** Contents of the EML_NL 510b file */
+  data: string;
+  /** Hash of the EML_NL 510b file, as printed on the official report of the GSB */
+  hash: string[];
+  /** Whether the imported results are the first entry or the definitive results */
+  target: ImportEntryTarget;
+}
+
+/**
+ * Immutable record of the state of a data entry after an action of a user
+ */
+export interface DataEntryRevision {
+  /** State of the data entry after the action, including the entries and client state */
+  state: DataEntryStatus;
+  summary: DataEntryRevisionSummary;
+}
+
+/**
+ * Action of a user that changed the state of a data entry
+ */
+export const dataEntryRevisionActionValues = [
+  "claim",
+  "save",
+  "discard",
+  "finalise",
+  "resolve_errors",
+  "resolve_differences",
+  "import",
+  "reset",
+  "take_over",
+] as const;
+export type DataEntryRevisionAction = (typeof dataEntryRevisionActionValues)[number];
+
+/**
+ * Differences between two revisions of a data entry
+ */
+export interface DataEntryRevisionDiff {
+  /** Whether the client state differs between the revisions */
+  client_state_changed: boolean;
+  /** Fields of the definitive results that differ between the revisions */
+  definitive: string[];
+  /** Fields of the first entry that differ between the revisions */
+  first_entry: string[];
+  from: DataEntryRevisionSummary;
+  /** Fields of the second entry that differ between the revisions */
+  second_entry: string[];
+  /** Fields of the third entry that differ between the revisions */
+  third_entry: string[];
+  to: DataEntryRevisionSummary;
+}
+
+export type DataEntryRevisionId = number;
+
+/**
+ * List of revisions of a data entry
+ */
+export interface DataEntryRevisionListResponse {
+  revisions: DataEntryRevisionSummary[];
+}
+
+/**
+ * Details of a data entry revision, without its state
+ */
+export interface DataEntryRevisionSummary {
+  action: DataEntryRevisionAction;
+  created_at: string;
+  created_by: UserId;
+  data_entry_id: DataEntryId;
+  id: DataEntryRevisionId;
+  /** Number of the revision, counting from 1 for each data entry */
+  revision: number;
+  /** Status of the data entry after the action */
+  status: DataEntryStatusName;
+}
+
 export type DataEntrySource =
   | (PollingStationForSession & { type: "PollingStation" })
   | (SubCommitteeFirstSession & { type: "SubCommittee" });
 
 export type DataEntrySourceNumber = { number: u32; type: "PollingStation" } | { number: u32; type: "SubCommittee" };
 
+export type DataEntryStatus =
+  | { status: "Empty" }
+  | { state: FirstEntryInProgress; status: "FirstEntryInProgress" }
+  | { state: FirstEntryHasErrors; status: "FirstEntryHasErrors" }
+  | { state: FirstEntryFinalised; status: "FirstEntryFinalised" }
+  | { state: SecondEntryInProgress; status: "SecondEntryInProgress" }
+  | { state: EntriesDifferent; status: "EntriesDifferent" }
+  | { state: EntriesArbitration; status: "EntriesArbitration" }
+  | { state: FirstEntryCorrection; status: "FirstEntryCorrection" }
+  | { state: SecondEntryCorrection; status: "SecondEntryCorrection" }
+  | { state: Definitive; status: "Definitive" };
+
 export const dataEntryStatusNameValues = [
   "empty",
   "first_entry_in_progress",
@@ -917,6 +1287,7 @@ export const dataEntryStatusNameValues = [
   "first_entry_finalised",
   "second_entry_in_progress",
   "entries_different",
+  "entries_arbitration",
   "first_entry_correction",
   "second_entry_correction",
   "definitive",
@@ -927,9 +1298,29 @@ export interface DataEntryStatusResponse {
   status: DataEntryStatusName;
 }
 
-export interface DeceasedCandidate {
-  candidate_number: CandidateNumber;
-  pg_number: PGNumber;
+/**
+ * Request structure for handing over a data entry in progress to another typist
+ */
+export interface DataEntryTakeOverRequest {
+  /** Typist who continues the data entry */
+  user_id: UserId;
+}
+
+export interface Definitive {
+  /** Whether the result has warnings */
+  finalised_with_warnings: boolean;
+  /** When the result was finalised */
+  finished_at: string;
+  /** User who did the first data entry, not set when the results were imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the definitive results, or the first entry, from a counting file */
+  imported_by_user_id?: UserId;
+  /** The definitive results data */
+  results: Results;
+  /** User who did the second data entry, not set when the results were imported */
+  second_entry_user_id?: UserId;
+  /** User who did the third data entry, only set when the differences were arbitrated */
+  third_entry_user_id?: UserId;
 }
 
 /**
@@ -1017,10 +1408,14 @@ export interface Election {
   election_date: string;
   /** See [`ElectionWithPoliticalGroups::election_id`] */
   election_id: string;
+  /** See [`ElectionWithPoliticalGroups::frisian_export_allowed`] */
+  frisian_export_allowed: boolean;
   /** See [`ElectionWithPoliticalGroups::id`] */
   id: ElectionId;
   /** See [`ElectionWithPoliticalGroups::location`] */
   location: string;
+  /** See [`ElectionWithPoliticalGroups::model_language`] */
+  model_language?: ModelLanguage;
   /** See [`ElectionWithPoliticalGroups::name`] */
   name: string;
   /** See [`ElectionWithPoliticalGroups::nomination_date`] */
@@ -1036,6 +1431,8 @@ export interface Election {
 export interface ElectionApportionmentResponse {
   candidate_nomination: CandidateNomination;
   election_totals: ElectionTotals;
+  /** Explanation of every step of the seat assignment, absent if there were no steps */
+  explanations?: SeatChangeExplanation[];
   seat_assignment: SeatAssignment;
   warnings: ApportionmentWarning[];
 }
@@ -1043,7 +1440,18 @@ export interface ElectionApportionmentResponse {
 /**
  * Election category (limited for now)
  */
-export const electionCategoryValues = ["Municipal", "Provincial", "WaterAuthority"] as const;
+export const electionCategoryValues = [
+  "Municipal",
+  "Provincial",
+  "WaterAuthority",
+  "NationalParliament",
+  "EuropeanParliament",
+  "Referendum",
+  "Island",
+  "IslandElectoralCollege",
+  "NonResidentElectoralCollege",
+  "Senate",
+] as const;
 export type ElectionCategory = (typeof electionCategoryValues)[number];
 
 export type ElectionCreationRequest =
@@ -1093,6 +1501,13 @@ export interface ElectionListResponse {
   elections: Election[];
 }
 
+/**
+ * Election model language change request
+ */
+export interface ElectionModelLanguageChangeRequest {
+  model_language: ModelLanguage;
+}
+
 /**
  * Election number of voters change request
  */
@@ -1132,7 +1547,21 @@ export interface ElectionStatusResponseEntry {
 /**
  * Election sub category (limited for now)
  */
-export const electionSubCategoryValues = ["AB1", "AB2", "GR1", "GR2", "PS1", "PS2"] as const;
+export const electionSubCategoryValues = [
+  "AB1",
+  "AB2",
+  "GR1",
+  "GR2",
+  "PS1",
+  "PS2",
+  "TK",
+  "EP",
+  "LR",
+  "ER1",
+  "IR",
+  "NR",
+  "EK",
+] as const;
 export type ElectionSubCategory = (typeof electionSubCategoryValues)[number];
 
 /**
@@ -1145,6 +1574,8 @@ export interface ElectionTotals {
   differences_counts: DifferencesTotals;
   /** The total votes for each political group (and each candidate within) */
   political_group_votes: PoliticalGroupCandidateVotes[];
+  /** The total yes/no votes, only for referendums */
+  referendum_votes_counts?: ReferendumVotesCounts;
   /** The total number of voters */
   voters_counts: VotersCounts;
   /** The total number of votes */
@@ -1198,6 +1629,9 @@ the date that the committee is in session. */
   election_date: string;
   /** The election identifier as defined in the EML_NL election definition */
   election_id: string;
+  /** Whether the region that the committee is responsible for allows its
+models to be produced in Frisian. */
+  frisian_export_allowed: boolean;
   /** Identifier of the election within Abacus */
   id: ElectionId;
   /** The location of the committee.
@@ -1206,6 +1640,9 @@ For GSB committees this will be the same as the authority region, but
 for HSBs and CSBs this may also be a specific town or city within the
 authority region. */
   location: string;
+  /** The language the models of this election are produced in. Defaults to
+Dutch, Frisian can only be chosen when the region allows Frisian export. */
+  model_language?: ModelLanguage;
   /** Name of the election, as defined in the EML_NL election definition. */
   name: string;
   /** The date when candidate nominations for this election are/were closed. */
@@ -1218,11 +1655,52 @@ for elections for the House of Representatives (Tweede Kamer). */
   /** The political groups and their candidates that are registered for this
 election. */
   political_groups: PoliticalGroup[];
+  /** For referendums this contains the question that is put to the voters
+and its answer options. For other elections this field is None. */
+  referendum?: ReferendumQuestion;
   /** The sub-category of the election, as defined by the EML_NL election
 definition. */
   sub_category: ElectionSubCategory;
 }
 
+export interface EntriesArbitration {
+  /** Fields that differ between the first and second entry, to be entered by a third typist */
+  arbitration_fields: string[];
+  /** First data entry */
+  first_entry: Results;
+  /** When the first data entry was finalised */
+  first_entry_finished_at: string;
+  /** User who did the first data entry, not set when the first entry was imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the first entry from a counting file */
+  imported_by_user_id?: UserId;
+  /** Second data entry */
+  second_entry: Results;
+  /** When the second data entry was finalised */
+  second_entry_finished_at: string;
+  /** User who did the second data entry */
+  second_entry_user_id: UserId;
+  /** Third data entry, once it is claimed */
+  third_entry?: ThirdEntry;
+}
+
+export interface EntriesDifferent {
+  /** First data entry */
+  first_entry: Results;
+  /** When the first data entry was finalised */
+  first_entry_finished_at: string;
+  /** User who did the first data entry, not set when the first entry was imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the first entry from a counting file */
+  imported_by_user_id?: UserId;
+  /** Second data entry */
+  second_entry: Results;
+  /** When the second data entry was finalised */
+  second_entry_finished_at: string;
+  /** User who did the second data entry */
+  second_entry_user_id: UserId;
+}
+
 /**
  * Error reference used to show the corresponding error message to the end-user
  */
@@ -1230,14 +1708,21 @@ export const errorReferenceValues = [
   "AirgapViolation",
   "AlreadyInitialised",
   "BackupAlreadyExists",
+  "BackupDecryptionFailed",
+  "BackupMigrationVersionMismatch",
+  "BackupPassphraseRequired",
   "ApportionmentNotCompleted",
   "ApportionmentCommitteeSessionNotCompleted",
   "ApportionmentInvalidLotDrawing",
+  "ApportionmentCandidateNotSeatHolder",
   "CommitteeSessionPaused",
   "DatabaseError",
   "DataEntryAlreadyClaimed",
   "DataEntryAlreadyFinalised",
+  "DataEntryAssignedToOtherUser",
+  "DataEntryAssignmentSameUser",
   "DataEntryCannotBeReset",
+  "DataEntryClaimNotAbandoned",
   "DataEntryGetNotAllowed",
   "DataEntryNotAllowed",
   "EmlImportError",
@@ -1285,6 +1770,21 @@ export interface ErrorResponse {
   reference: ErrorReference;
 }
 
+/**
+ * Candidate that is skipped in list exhaustion and candidate nomination
+ */
+export interface ExcludedCandidate {
+  candidate_number: CandidateNumber;
+  pg_number: PGNumber;
+  reason: ExclusionReason;
+}
+
+/**
+ * Reason why a candidate is excluded from the list
+ */
+export const exclusionReasonValues = ["deceased", "declined", "ineligible"] as const;
+export type ExclusionReason = (typeof exclusionReasonValues)[number];
+
 /**
  * Extra investigation, part of the results ("B1-1 Alleen bij extra onderzoek")
  */
@@ -1297,6 +1797,55 @@ export interface ExtraInvestigation {
   extra_investigation_other_reason: YesNo;
 }
 
+export interface FirstEntryCorrection {
+  /** Client state for the data entry (arbitrary JSON) */
+  client_state: unknown;
+  finalised_second_entry: Results;
+  first_entry: Results;
+  first_entry_user_id: UserId;
+  /** Data entry progress between 0 and 100 */
+  progress: number;
+  second_entry_finished_at: string;
+  second_entry_user_id: UserId;
+}
+
+export interface FirstEntryFinalised {
+  /** First data entry */
+  finalised_first_entry: Results;
+  /** Whether the first data entry was finalised with warnings */
+  finalised_with_warnings: boolean;
+  /** When the first data entry was finalised */
+  first_entry_finished_at: string;
+  /** User who did the first data entry, not set when the first entry was imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the first entry from a counting file */
+  imported_by_user_id?: UserId;
+}
+
+export interface FirstEntryHasErrors {
+  /** First data entry */
+  finalised_first_entry: Results;
+  /** When the first data entry was finalised */
+  first_entry_finished_at: string;
+  /** User who did the first data entry, not set when the first entry was imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the first entry from a counting file */
+  imported_by_user_id?: UserId;
+}
+
+export interface FirstEntryInProgress {
+  /** Client state for the data entry (arbitrary JSON) */
+  client_state: unknown;
+  /** First data entry */
+  first_entry: Results;
+  /** User who is doing the first data entry */
+  first_entry_user_id: UserId;
+  /** Whether this entry was returned for correction */
+  is_correction: boolean;
+  /** Data entry progress between 0 and 100 */
+  progress: number;
+}
+
 /**
  * Differences counts for GSB, part of the results.
  * (1.4 "Verschillen tussen aantal kiezers en uitgebrachte stemmen")
@@ -1378,7 +1927,7 @@ export interface GenerateElectionArgs {
   counting_method?: VoteCountingMethod;
   /** Custom election name */
   custom_name?: string;
-  /** Municipal, Provincial or WaterAuthority */
+  /** Municipal, Provincial, WaterAuthority, NationalParliament, EuropeanParliament, Referendum, Island, IslandElectoralCollege, NonResidentElectoralCollege or Senate */
   election_category: ElectionCategory;
   /** Percentage of the first data entry to complete if data entry is included */
   first_data_entry: RandomRange;
@@ -1419,6 +1968,12 @@ export interface HighestAverageResidualSeatDrawingLots {
   residual_seat_numbers: number[];
 }
 
+/**
+ * How imported results are recorded in the data entry
+ */
+export const importEntryTargetValues = ["first_entry", "definitive"] as const;
+export type ImportEntryTarget = (typeof importEntryTargetValues)[number];
+
 export interface InvestigationConcludedWithNewResults {
   findings: string;
   reason: string;
@@ -1445,6 +2000,12 @@ export type InvestigationStatus =
   | { state: InvestigationConcludedWithoutNewResults; status: "ConcludedWithoutNewResults" }
   | { state: InvestigationConcludedWithNewResults; status: "ConcludedWithNewResults" };
 
+/**
+ * Article of the Kieswet that a seat change is based on
+ */
+export const kieswetArticleValues = ["P7", "P8", "P9", "P10"] as const;
+export type KieswetArticle = (typeof kieswetArticleValues)[number];
+
 export interface LargestRemainderAssignedSeat {
   drawing_lots?: ListDrawingLotsVariant;
   list_assigned: PGNumber[];
@@ -1495,6 +2056,16 @@ export interface ListExhaustionRemovedSeat {
   list_retracted_seat: PGNumber;
 }
 
+/**
+ * Preference threshold margins of the candidates of a list with seats: the candidates
+above the threshold and the candidate below the threshold with the most votes
+ */
+export interface ListPreferenceMargins {
+  candidates: CandidatePreferenceMargin[];
+  list_name: string;
+  list_number: PGNumber;
+}
+
 export interface ListRemainder {
   pg_number: PGNumber;
   remainder: DisplayFraction;
@@ -1510,6 +2081,33 @@ export interface ListSeatAssignment {
   votes_cast: number;
 }
 
+/**
+ * Smallest numbers of votes with which the number of seats of a list changes,
+absent if no number of votes changes it
+ */
+export interface ListSeatMargin {
+  list_name: string;
+  list_number: PGNumber;
+  /** Other list that needs to lose the fewest votes for this list to get an additional seat */
+  other_list_votes_to_gain_seat?: OtherListMargin;
+  total_seats: number;
+  /** Number of votes the list needs to gain to get an additional seat */
+  votes_to_gain_seat?: number;
+  /** Number of votes the list needs to lose to lose one of its seats */
+  votes_to_lose_seat?: number;
+}
+
+/**
+ * Change of the votes or seats of a list between two apportionment snapshots
+ */
+export interface ListSnapshotDiff {
+  pg_number: PGNumber;
+  seats_after: number;
+  seats_before: number;
+  votes_after: number;
+  votes_before: number;
+}
+
 export interface ListStanding {
   full_seats: number;
   list_number: PGNumber;
@@ -1528,6 +2126,13 @@ export interface LoginResponse {
   username: string;
 }
 
+/**
+ * Language in which the models of an election are produced. Municipalities
+ * in Fryslân are allowed to produce their official models in Frisian.
+ */
+export const modelLanguageValues = ["nl", "fy"] as const;
+export type ModelLanguage = (typeof modelLanguageValues)[number];
+
 /**
  * Struct for creating a new election in Abacus.
  *
@@ -1563,6 +2168,8 @@ export interface NewElection {
   election_date: string;
   /** See [`ElectionWithPoliticalGroups::election_id`] */
   election_id: string;
+  /** See [`ElectionWithPoliticalGroups::frisian_export_allowed`] */
+  frisian_export_allowed: boolean;
   /** See [`ElectionWithPoliticalGroups::location`] */
   location: string;
   /** See [`ElectionWithPoliticalGroups::name`] */
@@ -1575,6 +2182,8 @@ export interface NewElection {
   number_of_voters: number;
   /** See [`ElectionWithPoliticalGroups::political_groups`] */
   political_groups: RegisteredPoliticalGroup[];
+  /** See [`ElectionWithPoliticalGroups::referendum`] */
+  referendum?: ReferendumQuestion;
   /** See [`ElectionWithPoliticalGroups::sub_category`] */
   sub_category: ElectionSubCategory;
 }
@@ -1599,6 +2208,11 @@ export interface NextSessionResults {
   votes_counts: VotesCounts;
 }
 
+export interface OtherListMargin {
+  list_number: PGNumber;
+  votes: number;
+}
+
 export type PGNumber = number;
 
 /**
@@ -1763,6 +2377,50 @@ export interface RedactedEmlHash {
   redacted_indexes: number[];
 }
 
+/**
+ * The question of a referendum and its answer options
+ */
+export interface ReferendumQuestion {
+  /** The answer option whose votes are counted as no votes */
+  no_option: string;
+  /** The question that is put to the voters */
+  question: string;
+  /** The answer option whose votes are counted as yes votes */
+  yes_option: string;
+}
+
+/**
+ * ReferendumResults, the results of a polling station for a referendum.
+ *
+ * A referendum has a single yes/no question instead of candidate lists, so
+ * the votes are counted per answer instead of per list and candidate. The
+ * voters counts and differences counts are the same as for other elections.
+ */
+export interface ReferendumResults {
+  /** Differences counts ("Verschil tussen het aantal toegelaten kiezers en het aantal getelde stembiljetten") */
+  differences_counts: DifferencesCounts;
+  /** Voters counts ("Aantal toegelaten kiezers") */
+  voters_counts: VotersCounts;
+  /** Votes counts ("Aantal getelde stembiljetten") */
+  votes_counts: ReferendumVotesCounts;
+}
+
+/**
+ * Votes counts for a referendum, part of the referendum results.
+ */
+export interface ReferendumVotesCounts {
+  /** Number of blank votes ("Blanco stembiljetten") */
+  blank_votes_count: number;
+  /** Number of invalid votes ("Ongeldige stembiljetten") */
+  invalid_votes_count: number;
+  /** Number of votes against ("Stemmen tegen") */
+  no_votes_count: number;
+  /** Total number of votes cast ("Totaal uitgebrachte stemmen") */
+  total_votes_cast_count: number;
+  /** Number of votes in favour ("Stemmen voor") */
+  yes_votes_count: number;
+}
+
 /**
  * Category of a region, as defined by EML-NL
  */
@@ -1812,12 +2470,19 @@ export interface RegisteredPoliticalGroup {
   registered_name: string;
 }
 
+/**
+ * Method with which a residual seat was assigned
+ */
+export const residualSeatMethodValues = ["HighestAverage", "UniqueHighestAverage", "LargestRemainder"] as const;
+export type ResidualSeatMethod = (typeof residualSeatMethodValues)[number];
+
 export const resolveDifferencesActionValues = [
   "keep_first_and_discard_second",
   "keep_first_and_correct_second",
   "keep_second_and_discard_first",
   "keep_second_and_correct_first",
   "discard_both",
+  "arbitrate",
 ] as const;
 export type ResolveDifferencesAction = (typeof resolveDifferencesActionValues)[number];
 
@@ -1835,7 +2500,8 @@ export type Results =
   | (NextSessionResults & { model: "DSONextSession" })
   | (CSOFirstSessionResults & { model: "CSOFirstSession" })
   | (NextSessionResults & { model: "CSONextSession" })
-  | (GSBResults & { model: "GSB" });
+  | (GSBResults & { model: "GSB" })
+  | (ReferendumResults & { model: "Referendum" });
 
 export const roleValues = ["administrator", "coordinator_gsb", "coordinator_csb", "typist_gsb", "typist_csb"] as const;
 export type Role = (typeof roleValues)[number];
@@ -1863,12 +2529,107 @@ export type SeatChange =
   | (AbsoluteMajorityReassignedSeat & { changed_by: "AbsoluteMajorityReassignment" })
   | (ListExhaustionRemovedSeat & { changed_by: "ListExhaustionRemoval" });
 
+/**
+ * Explanation of a step of the seat assignment: which seat moved where, why,
+and on which articles of the Kieswet this is based
+ */
+export interface SeatChangeExplanation {
+  articles: KieswetArticle[];
+  /** Whether lots were drawn between the tied lists */
+  drawn_lots: boolean;
+  list_assigned_seat?: PGNumber;
+  list_retracted_seat?: PGNumber;
+  reason: SeatChangeReason;
+  residual_seat_number?: number;
+  /** The lists that had an equal claim in this step, including the selected list.
+Empty if no other list had an equal claim. */
+  tied_lists: PGNumber[];
+}
+
+/**
+ * The reason for a seat change
+ */
+export type SeatChangeReason =
+  | { reason: "HighestAverage"; votes_per_seat: DisplayFraction }
+  | { reason: "UniqueHighestAverage"; votes_per_seat: DisplayFraction }
+  | { reason: "LargestRemainder"; remainder_votes: DisplayFraction }
+  | {
+      /** The number of seats of the list before the reassignment */
+      list_seats: number;
+      reason: "AbsoluteMajority";
+      /** The method with which the retracted residual seat was assigned */
+      retracted_seat_method: ResidualSeatMethod;
+      seats: number;
+      total_votes_cast: number;
+      votes_cast: number;
+    }
+  | { full_seat: boolean; reason: "ListExhaustion" };
+
 export interface SeatChangeStep {
   change: SeatChange;
   residual_seat_number?: number;
   standings: ListStanding[];
 }
 
+/**
+ * Margins of a completed apportionment, how close the result was to a different outcome
+ */
+export interface SeatMargins {
+  list_preference_margins: ListPreferenceMargins[];
+  list_seat_margins: ListSeatMargin[];
+  preference_threshold: PreferenceThreshold;
+}
+
+export interface SecondEntryCorrection {
+  /** Client state for the data entry (arbitrary JSON) */
+  client_state: unknown;
+  finalised_first_entry: Results;
+  first_entry_finished_at: string;
+  /** User who did the first data entry, not set when the first entry was imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the first entry from a counting file */
+  imported_by_user_id?: UserId;
+  /** Data entry progress between 0 and 100 */
+  progress: number;
+  second_entry: Results;
+  second_entry_user_id: UserId;
+}
+
+export interface SecondEntryInProgress {
+  /** Client state for the data entry (arbitrary JSON) */
+  client_state: unknown;
+  /** First data entry */
+  finalised_first_entry: Results;
+  /** When the first data entry was finalised */
+  first_entry_finished_at: string;
+  /** User who did the first data entry, not set when the first entry was imported */
+  first_entry_user_id?: UserId;
+  /** User who imported the first entry from a counting file */
+  imported_by_user_id?: UserId;
+  /** Data entry progress between 0 and 100 */
+  progress: number;
+  /** Second data entry */
+  second_entry: Results;
+  /** User who is doing the second data entry */
+  second_entry_user_id: UserId;
+}
+
+/**
+ * Candidate that ranks before the successor, but could not be designated
+ */
+export interface SkippedCandidate {
+  candidate_number: CandidateNumber;
+  reason: SuccessorSkipReason;
+}
+
+/**
+ * Candidate of a list in an apportionment snapshot
+ */
+export interface SnapshotCandidate {
+  candidate_number: CandidateNumber;
+  pg_number: PGNumber;
+}
+
 /**
  * Sub electoral committee base entity, independent
  * of the election, committee session and data entry.
@@ -1890,6 +2651,12 @@ export type SubCommitteeFirstSession = SubCommittee & {
 
 export type SubCommitteeId = number;
 
+/**
+ * Reason why a candidate could not be designated as successor
+ */
+export const successorSkipReasonValues = ["seat_holder", "vacated", "declined", "deceased", "ineligible"] as const;
+export type SuccessorSkipReason = (typeof successorSkipReasonValues)[number];
+
 /**
  * Contains a sum count, containing both the count and a list of polling
  * stations that contributed to it.
@@ -1899,6 +2666,17 @@ export interface SumCount {
   data_entry_sources: DataEntrySourceNumber[];
 }
 
+export interface ThirdEntry {
+  /** Client state for the data entry (arbitrary JSON) */
+  client_state: unknown;
+  /** Third data entry */
+  entry: Results;
+  /** Data entry progress between 0 and 100 */
+  progress: number;
+  /** User who is doing the third data entry */
+  user_id: UserId;
+}
+
 export interface UpdateUserRequest {
   fullname?: string;
   temp_password?: string;
@@ -1924,6 +2702,48 @@ export interface UserListResponse {
   users: User[];
 }
 
+/**
+ * Vacancy of a seat after the result of the election was determined,
+ * with the successor that was designated for it
+ */
+export interface Vacancy {
+  candidate_number: CandidateNumber;
+  committee_session_id: CommitteeSessionId;
+  created_at: string;
+  id: VacancyId;
+  pg_number: PGNumber;
+  reason: VacancyReason;
+  skipped_candidates: SkippedCandidate[];
+  /** Candidate designated as successor, absent if no candidate on the list is eligible */
+  successor_candidate_number?: CandidateNumber;
+}
+
+export type VacancyId = number;
+
+/**
+ * List of vacancies of an election
+ */
+export interface VacancyListResponse {
+  vacancies: Vacancy[];
+}
+
+/**
+ * Reason why the seat of an elected candidate became vacant
+ */
+export const vacancyReasonValues = ["resigned", "deceased"] as const;
+export type VacancyReason = (typeof vacancyReasonValues)[number];
+
+/**
+ * Request to register a vacancy for an elected candidate
+ */
+export interface VacancyRequest {
+  candidate_number: CandidateNumber;
+  /** Candidates that declined the appointment for this vacancy */
+  declined_candidates?: CandidateNumber[];
+  pg_number: PGNumber;
+  reason: VacancyReason;
+}
+
 export interface ValidationResult {
   code: ValidationResultCode;
   context?: ValidationResultContext;
@@ -1939,6 +2759,7 @@ export const validationResultCodeValues = [
   "F202",
   "F203",
   "F204",
+  "F205",
   "F301",
   "F302",
   "F303",
@@ -1961,6 +2782,9 @@ export const validationResultCodeValues = [
   "W204",
   "W205",
   "W206",
+  "W207",
+  "W208",
+  "W209",
 ] as const;
 export type ValidationResultCode = (typeof validationResultCodeValues)[number];
 
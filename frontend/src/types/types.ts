@@ -113,7 +113,7 @@ export type DataEntryModel = Results["model"];
 // All models, includes exhaustive check if all models are defined
 export const dataEntryModelValues = (<T extends DataEntryModel[]>(
   models: [Exclude<DataEntryModel, T[number]>] extends [never] ? T : never,
-) => models)(["DSOFirstSession", "CSOFirstSession", "DSONextSession", "CSONextSession", "GSB"]);
+) => models)(["DSOFirstSession", "CSOFirstSession", "DSONextSession", "CSONextSession", "GSB", "Referendum"]);
 
 export type DataEntryStructure = DataEntrySection[];
 
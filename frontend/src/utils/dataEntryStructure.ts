@@ -17,7 +17,10 @@ import { getCandidateFullName } from "@/utils/candidate";
 import { formatPoliticalGroupName } from "@/utils/politicalGroup";
 import { isLocalElection } from "./election";
 
-type ModelForGSB = Extract<DataEntryModel, "DSOFirstSession" | "CSOFirstSession" | "DSONextSession" | "CSONextSession">;
+type ModelForGSB = Extract<
+  DataEntryModel,
+  "DSOFirstSession" | "CSOFirstSession" | "DSONextSession" | "CSONextSession" | "Referendum"
+>;
 type ModelForCSB = Extract<DataEntryModel, "GSB">;
 
 const isEntryForGSB = (model: DataEntryModel): model is ModelForGSB => {
@@ -26,6 +29,7 @@ const isEntryForGSB = (model: DataEntryModel): model is ModelForGSB => {
     case "CSOFirstSession":
     case "DSONextSession":
     case "CSONextSession":
+    case "Referendum":
       return true;
     case "GSB":
       return false;
@@ -36,10 +40,72 @@ const isEntryForCSB = (model: DataEntryModel): model is ModelForCSB => {
   return model === "GSB";
 };
 
+const createVotersRows = (election: ElectionWithPoliticalGroups, model: DataEntryModel): InputGridSubsectionRow[] => [
+  {
+    code: "A",
+    path: "voters_counts.poll_card_count",
+    title: t("voters_votes_counts.voters_counts.poll_card_count"),
+    autoFocusInput: isEntryForGSB(model),
+  },
+  {
+    code: "B",
+    path: "voters_counts.proxy_certificate_count",
+    title: t("voters_votes_counts.voters_counts.proxy_certificate_count"),
+  },
+  {
+    code: "C",
+    path: "voters_counts.voter_card_count",
+    title: t("voters_votes_counts.voters_counts.voter_card_count"),
+    isDisabled: isLocalElection(election.category),
+    addSeparator: true,
+  },
+  {
+    code: "D",
+    path: "voters_counts.total_admitted_voters_count",
+    title: t("voters_votes_counts.voters_counts.total_admitted_voters_count"),
+    isTotal: true,
+    addSeparator: true,
+  },
+];
+
+const createReferendumVotesRows = (): InputGridSubsectionRow[] => [
+  {
+    code: "E",
+    path: "votes_counts.yes_votes_count",
+    title: t("voters_votes_counts.votes_counts.yes_votes_count"),
+  },
+  {
+    code: "F",
+    path: "votes_counts.no_votes_count",
+    title: t("voters_votes_counts.votes_counts.no_votes_count"),
+  },
+  {
+    code: "G",
+    path: "votes_counts.blank_votes_count",
+    title: t("voters_votes_counts.votes_counts.blank_votes_count"),
+  },
+  {
+    code: "H",
+    path: "votes_counts.invalid_votes_count",
+    title: t("voters_votes_counts.votes_counts.invalid_votes_count"),
+    addSeparator: true,
+  },
+  {
+    code: "I",
+    path: "votes_counts.total_votes_cast_count",
+    title: t("voters_votes_counts.votes_counts.total_votes_cast_count"),
+    isTotal: true,
+  },
+];
+
 const createVotersAndVotesRows = (
   election: ElectionWithPoliticalGroups,
   model: DataEntryModel,
 ): InputGridSubsectionRow[] => {
+  if (model === "Referendum") {
+    return [...createVotersRows(election, model), ...createReferendumVotesRows()];
+  }
+
   const rowsPerPoliticalGroup: InputGridSubsectionRow[] = election.political_groups.map((politicalGroup, index) => ({
     code: `E.${politicalGroup.number}`,
     path: `votes_counts.political_group_total_votes.${index}.total`,
@@ -48,31 +114,7 @@ const createVotersAndVotesRows = (
   }));
 
   return [
-    {
-      code: "A",
-      path: "voters_counts.poll_card_count",
-      title: t("voters_votes_counts.voters_counts.poll_card_count"),
-      autoFocusInput: isEntryForGSB(model),
-    },
-    {
-      code: "B",
-      path: "voters_counts.proxy_certificate_count",
-      title: t("voters_votes_counts.voters_counts.proxy_certificate_count"),
-    },
-    {
-      code: "C",
-      path: "voters_counts.voter_card_count",
-      title: t("voters_votes_counts.voters_counts.voter_card_count"),
-      isDisabled: isLocalElection(election.category),
-      addSeparator: true,
-    },
-    {
-      code: "D",
-      path: "voters_counts.total_admitted_voters_count",
-      title: t("voters_votes_counts.voters_counts.total_admitted_voters_count"),
-      isTotal: true,
-      addSeparator: true,
-    },
+    ...createVotersRows(election, model),
     ...rowsPerPoliticalGroup,
     {
       code: "E",
@@ -177,17 +219,19 @@ const createDifferenceAccountedForCheckboxes = (model: ModelForGSB): CheckboxesS
 };
 
 export const createDifferencesSection = (model: DataEntryModel): DataEntrySection => {
+  // The referendum form has one more votes field, so the differences fields shift one letter
+  const [moreBallotsCode, fewerBallotsCode] = model === "Referendum" ? ["J", "K"] : ["I", "J"];
   const differencesInputGrid: InputGridSubsection = {
     type: "inputGrid",
     headers: [t("field"), t("counted_number"), t("description")],
     rows: [
       {
-        code: "I",
+        code: moreBallotsCode,
         path: "differences_counts.more_ballots_count",
         title: t(`differences_counts.differences_counts.more_ballots_count.${model}`),
       },
       {
-        code: "J",
+        code: fewerBallotsCode,
         path: "differences_counts.fewer_ballots_count",
         title: t(`differences_counts.differences_counts.fewer_ballots_count.${model}`),
       },
@@ -534,6 +578,8 @@ function buildDataEntryStructure(model: DataEntryModel, election: ElectionWithPo
         createDifferencesSection(model),
         ...createPoliticalGroupSections(election),
       ];
+    case "Referendum":
+      return [createVotersAndVotesSection(model, election), createDifferencesSection(model)];
   }
 }
 
@@ -3,9 +3,16 @@ import type { ElectionCategory } from "@/types/generated/openapi";
 export function isLocalElection(electionCategory: ElectionCategory): boolean {
   switch (electionCategory) {
     case "Municipal":
+    case "Referendum":
+    case "Island":
+    case "IslandElectoralCollege":
       return true;
     case "Provincial":
     case "WaterAuthority":
+    case "NationalParliament":
+    case "EuropeanParliament":
+    case "NonResidentElectoralCollege":
+    case "Senate":
       return false;
   }
 }